@@ -12,6 +12,7 @@ forc fmt --check --path packages/fuels &&
 forc build --release --terse --path packages/fuels &&
 forc build --release --terse --json-abi-with-callpaths --path packages/fuels &&
 cargo clippy --all-targets &&
+cargo check -p fuels-signing --no-default-features --target thumbv7em-none-eabi &&
 cargo clippy --all-targets --all-features &&
 cargo test --all-targets --all-features &&
 cargo test --all-targets --all-features --workspace &&
@@ -111,6 +111,7 @@ mod tests {
             max_depth: 5,
             max_tokens: 100,
             max_total_enum_width: 10_000,
+            initial_capacity: 1_024,
         });
         // ANCHOR_END: configuring_the_encoder
 
@@ -116,6 +116,8 @@ mod tests {
         let expected_gas = 2206;
 
         assert_eq!(transaction_cost.gas_used, expected_gas);
+        assert!(transaction_cost.breakdown.predicate_gas.is_empty());
+        assert!(transaction_cost.breakdown.script_gas > 0);
 
         Ok(())
     }
@@ -899,6 +901,7 @@ mod tests {
                 max_depth: 10,
                 max_tokens: 2_000,
                 max_total_enum_width: 10_000,
+                ..Default::default()
             })
             .methods()
             .initialize_counter(42)
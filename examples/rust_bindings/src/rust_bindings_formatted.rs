@@ -54,6 +54,11 @@ pub mod abigen_bindings {
                     .await
                     .map_err(::std::convert::Into::into)
             }
+            pub async fn get_asset_balance(&self, asset_id: &AssetId) -> Result<u64> {
+                ViewOnlyAccount::try_provider(&self.account)?
+                    .get_contract_asset_balance(&self.contract_id, *asset_id)
+                    .await
+            }
             pub fn methods(&self) -> MyContractMethods<T> {
                 MyContractMethods {
                     contract_id: self.contract_id.clone(),
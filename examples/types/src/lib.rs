@@ -210,6 +210,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn amounts() -> Result<()> {
+        // ANCHOR: amounts
+        use fuels::types::{
+            units::{checked_mul_div, format_units, parse_units},
+            U256,
+        };
+
+        // `1.5` whole units of an asset with 9 decimals
+        let amount = parse_units("1.5", 9)?;
+        assert_eq!(amount, U256::from(1_500_000_000u64));
+        assert_eq!(format_units(amount, 9), "1.5");
+
+        // Transactions take `u64` amounts, the conversion fails if the amount doesn't fit
+        let amount = u64::try_from(amount)?;
+
+        // A fee of 30 basis points, without overflowing on the intermediate product
+        let fee = checked_mul_div(amount, 30, 10_000).expect("no overflow");
+        assert_eq!(fee, 4_500_000);
+
+        // Checked and saturating arithmetic on `U256`
+        assert_eq!(U256::MAX.checked_add(U256::one()), None);
+        assert_eq!(U256::MAX.saturating_add(U256::one()), U256::MAX);
+        // ANCHOR_END: amounts
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn type_conversion() -> Result<()> {
         // ANCHOR: type_conversion
@@ -210,6 +210,75 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn wallet_contract_transfer_in_custom_tx() -> Result<()> {
+        use fuels::{
+            prelude::*,
+            tx::{TxPointer, UtxoId},
+            types::{input::Input, output::Output, Bytes32},
+        };
+
+        let wallet = launch_provider_and_get_wallet().await?;
+        let provider = wallet.try_provider()?.clone();
+        let receiver = WalletUnlocked::new_random(Some(provider.clone()));
+        let base_asset_id = *provider.base_asset_id();
+
+        let contract_id = Contract::load_from(
+            "../../packages/fuels/tests/contracts/contract_test/out/release/contract_test.bin",
+            LoadConfiguration::default(),
+        )?
+        .deploy(&wallet, TxPolicies::default())
+        .await?;
+
+        // ANCHOR: contract_transfer_custom_tx
+        let top_up = 300;
+        let payment = 200;
+
+        // the contract input has to come first, its output points to it
+        let mut inputs = vec![Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            (&contract_id).into(),
+        )];
+        inputs.extend(
+            wallet
+                .get_asset_inputs_for_amount(base_asset_id, top_up + payment)
+                .await?,
+        );
+
+        let mut outputs = vec![Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed())];
+        outputs.extend(wallet.get_asset_outputs_for_amount(
+            receiver.address(),
+            base_asset_id,
+            payment,
+        ));
+
+        let mut tb = ScriptTransactionBuilder::prepare_contract_transfer(
+            (&contract_id).into(),
+            top_up,
+            base_asset_id,
+            inputs,
+            outputs,
+            TxPolicies::default(),
+        );
+        tb.add_signer(wallet.clone())?;
+        wallet.adjust_for_fee(&mut tb, top_up + payment).await?;
+
+        let tx = tb.build(&provider).await?;
+        provider.send_transaction_and_await_commit(tx).await?;
+        // ANCHOR_END: contract_transfer_custom_tx
+
+        let contract_balance = provider
+            .get_contract_asset_balance(&contract_id, base_asset_id)
+            .await?;
+        assert_eq!(contract_balance, top_up);
+        assert_eq!(receiver.get_asset_balance(&base_asset_id).await?, payment);
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[allow(unused_variables)]
     async fn setup_multiple_wallets() -> Result<()> {
@@ -380,4 +449,51 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn wallet_withdraw_and_get_relay_payload() -> Result<()> {
+        use std::{str::FromStr, time::Duration};
+
+        use fuels::prelude::*;
+
+        let node_config = NodeConfig {
+            block_production: Trigger::Interval {
+                block_time: Duration::from_secs(1),
+            },
+            ..NodeConfig::default()
+        };
+        let wallets = launch_custom_provider_and_get_wallets(
+            WalletsConfig::new(Some(1), None, None),
+            Some(node_config),
+            None,
+        )
+        .await?;
+        let wallet = wallets.first().unwrap();
+
+        // ANCHOR: wallet_withdraw_and_get_relay_payload
+        let base_layer_address = Bech32Address::from(Address::from_str(
+            "0x4710162c2e3a95a6faff05139150017c9e38e5e280432d546fae345d6ce6d8fe",
+        )?);
+
+        // Waits up to 30 seconds for a block committing to the withdrawal, then encodes the proof
+        let payload = wallet
+            .withdraw_and_get_relay_payload(
+                &base_layer_address,
+                1000,
+                TxPolicies::default(),
+                Duration::from_millis(200),
+                Duration::from_secs(30),
+            )
+            .await?;
+        // ANCHOR_END: wallet_withdraw_and_get_relay_payload
+
+        // The recipient is the second word of the encoded message
+        let message_offset = u64::from_be_bytes(payload[24..32].try_into().unwrap()) as usize;
+        assert_eq!(
+            &payload[message_offset + 32..message_offset + 64],
+            base_layer_address.hash().as_slice()
+        );
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr, time::Duration};
 
 use async_trait::async_trait;
 use fuel_core_client::client::pagination::{PaginatedResult, PaginationRequest};
@@ -8,7 +8,8 @@ use fuels_core::types::{
     bech32::{Bech32Address, Bech32ContractId},
     coin::Coin,
     coin_type::CoinType,
-    errors::Result,
+    coin_type_id::CoinTypeId,
+    errors::{error, Result},
     input::Input,
     message::Message,
     transaction::{Transaction, TxPolicies},
@@ -18,9 +19,71 @@ use fuels_core::types::{
 
 use crate::{
     accounts_utils::{adjust_inputs_outputs, calculate_missing_base_amount, extract_message_nonce},
-    provider::{Provider, ResourceFilter},
+    portfolio::{Portfolio, PriceOracle},
+    provider::{Provider, Recipient, ResourceFilter},
 };
 
+/// Restricts the resources an account selects to cover an amount, e.g. to avoid dust, to leave
+/// locked coins alone or to spend specific coins first by excluding the others.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CoinFilter {
+    /// Only select resources worth at least this amount.
+    pub min_amount: Option<u64>,
+    /// Only select resources worth at most this amount.
+    pub max_amount: Option<u64>,
+    /// Never select these resources.
+    pub excluded: Vec<CoinTypeId>,
+}
+
+impl CoinFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_min_amount(mut self, min_amount: u64) -> Self {
+        self.min_amount = Some(min_amount);
+        self
+    }
+
+    pub fn with_max_amount(mut self, max_amount: u64) -> Self {
+        self.max_amount = Some(max_amount);
+        self
+    }
+
+    pub fn with_excluded(mut self, excluded: impl IntoIterator<Item = CoinTypeId>) -> Self {
+        self.excluded.extend(excluded);
+        self
+    }
+
+    fn resource_filter(
+        self,
+        from: Bech32Address,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> ResourceFilter {
+        let (excluded_utxos, excluded_message_nonces) =
+            self.excluded
+                .into_iter()
+                .fold((vec![], vec![]), |mut acc, id| {
+                    match id {
+                        CoinTypeId::UtxoId(utxo_id) => acc.0.push(utxo_id),
+                        CoinTypeId::Nonce(nonce) => acc.1.push(nonce),
+                    }
+                    acc
+                });
+
+        ResourceFilter {
+            from,
+            asset_id: Some(asset_id),
+            amount,
+            excluded_utxos,
+            excluded_message_nonces,
+            min_coin_amount: self.min_amount,
+            max_coin_amount: self.max_amount,
+        }
+    }
+}
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
     fn address(&self) -> &Bech32Address;
@@ -74,15 +137,40 @@ pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync + Clone {
         asset_id: AssetId,
         amount: u64,
     ) -> Result<Vec<CoinType>> {
-        let filter = ResourceFilter {
-            from: self.address().clone(),
-            asset_id: Some(asset_id),
-            amount,
-            ..Default::default()
-        };
+        self.get_spendable_resources_filtered(asset_id, amount, CoinFilter::default())
+            .await
+    }
+
+    /// Same as [`ViewOnlyAccount::get_spendable_resources`] but only selects among the resources
+    /// matching `filter`.
+    async fn get_spendable_resources_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<CoinType>> {
+        let filter = filter.resource_filter(self.address().clone(), asset_id, amount);
 
         self.try_provider()?.get_spendable_resources(filter).await
     }
+
+    /// Values every asset held by the account with the prices of `oracle`, e.g. to show the
+    /// account's holdings and their total value on a dashboard.
+    async fn portfolio<O: PriceOracle>(&self, oracle: &O) -> Result<Portfolio> {
+        let balances = self
+            .get_balances()
+            .await?
+            .into_iter()
+            .map(|(asset_id, amount)| {
+                let asset_id = AssetId::from_str(&asset_id)
+                    .map_err(|_| error!(Other, "invalid asset id `{asset_id}` in the balances"))?;
+
+                Ok((asset_id, amount))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Portfolio::from_balances(balances, oracle).await
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -97,6 +185,21 @@ pub trait Account: ViewOnlyAccount {
         amount: u64,
     ) -> Result<Vec<Input>>;
 
+    /// Same as [`Account::get_asset_inputs_for_amount`] but only selects among the resources
+    /// matching `filter`. Accounts that can't filter their resources return an error.
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        _asset_id: AssetId,
+        _amount: u64,
+        _filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        Err(error!(
+            Other,
+            "account `{}` does not support filtering its resources",
+            self.address()
+        ))
+    }
+
     /// Returns a vector containing the output coin and change output given an asset and amount
     fn get_asset_outputs_for_amount(
         &self,
@@ -183,6 +286,100 @@ pub trait Account: ViewOnlyAccount {
         Ok((tx_id, receipts))
     }
 
+    /// Like [`Account::transfer`], but `to` can also be a name, resolved with the provider's
+    /// [`NameResolver`](crate::provider::NameResolver) before building the transaction.
+    async fn transfer_to(
+        &self,
+        to: impl Into<Recipient> + Send,
+        amount: u64,
+        asset_id: AssetId,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        let to = self.try_provider()?.resolve_recipient(to).await?;
+
+        self.transfer(&to, amount, asset_id, tx_policies).await
+    }
+
+    /// Transfers several assets to `to` in a single transaction. Amounts of the same asset are
+    /// added up, so the recipient gets one coin and this account one change output per asset.
+    /// The fee is paid in the base asset, which doesn't have to be among the transferred assets.
+    /// Returns the transaction ID that was sent and the list of receipts.
+    async fn transfer_assets(
+        &self,
+        to: &Bech32Address,
+        assets: &[(AssetId, u64)],
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, Vec<Receipt>)> {
+        let provider = self.try_provider()?;
+
+        let amounts = sum_amounts_per_asset(assets)?;
+
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        for &(asset_id, amount) in &amounts {
+            inputs.extend(self.get_asset_inputs_for_amount(asset_id, amount).await?);
+            outputs.extend(self.get_asset_outputs_for_amount(to, asset_id, amount));
+        }
+
+        let mut tx_builder =
+            ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+        self.add_witnesses(&mut tx_builder)?;
+
+        let used_base_amount = amounts
+            .iter()
+            .find(|(asset_id, _)| asset_id == provider.base_asset_id())
+            .map_or(0, |(_, amount)| *amount);
+        self.adjust_for_fee(&mut tx_builder, used_base_amount)
+            .await?;
+
+        let tx = tx_builder.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, receipts))
+    }
+
+    /// Transfers the whole spendable balance of `asset_id` to `to`. When sweeping the base asset,
+    /// the fee is deducted from the transferred amount, which is re-estimated until it no longer
+    /// changes. Otherwise the fee is paid with the base asset as usual.
+    ///
+    /// The fee is charged up front based on the transaction's `max_fee`, so any unused part of
+    /// it is refunded to this account as change.
+    ///
+    /// Returns the transaction ID, the transferred amount and the list of receipts.
+    async fn sweep(
+        &self,
+        to: &Bech32Address,
+        asset_id: AssetId,
+        tx_policies: TxPolicies,
+    ) -> Result<(TxId, u64, Vec<Receipt>)> {
+        let provider = self.try_provider()?;
+
+        let (tx_builder, amount) = prepare_sweep(self, to, asset_id, tx_policies).await?;
+
+        let tx = tx_builder.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+
+        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+        let receipts = tx_status.take_receipts_checked(None)?;
+
+        Ok((tx_id, amount, receipts))
+    }
+
+    /// Returns the amount of `asset_id` that [`Account::sweep`] would transfer with
+    /// `tx_policies`, i.e. the whole spendable balance, minus the fee for the base asset. Fails
+    /// if the balance doesn't cover the fee.
+    async fn max_transferable(&self, asset_id: AssetId, tx_policies: TxPolicies) -> Result<u64> {
+        let (_, amount) = prepare_sweep(self, self.address(), asset_id, tx_policies).await?;
+
+        Ok(amount)
+    }
+
     /// Unconditionally transfers `balance` of type `asset_id` to
     /// the contract at `to`.
     /// Fails if balance for `asset_id` is larger than this account's spendable balance.
@@ -280,6 +477,138 @@ pub trait Account: ViewOnlyAccount {
 
         Ok((tx_id, nonce, receipts))
     }
+
+    /// Withdraws like [`Account::withdraw_to_base_layer`], waits for the block committing to the
+    /// withdrawal, polling every `poll_interval` for at most `timeout`, and returns the arguments
+    /// to relay the message to the base layer portal, see
+    /// [`MessageProof::relay_payload`](fuels_core::types::message_proof::MessageProof::relay_payload).
+    async fn withdraw_and_get_relay_payload(
+        &self,
+        to: &Bech32Address,
+        amount: u64,
+        tx_policies: TxPolicies,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<Vec<u8>> {
+        let (tx_id, nonce, _) = self.withdraw_to_base_layer(to, amount, tx_policies).await?;
+
+        let proof = self
+            .try_provider()?
+            .await_message_proof(&tx_id, &nonce, poll_interval, timeout)
+            .await?;
+
+        Ok(proof.relay_payload())
+    }
+}
+
+/// Builds a transaction transferring the whole spendable balance of `asset_id` to `to`, see
+/// [`Account::sweep`]. Returns the builder and the transferred amount.
+async fn prepare_sweep(
+    account: &impl Account,
+    to: &Bech32Address,
+    asset_id: AssetId,
+    tx_policies: TxPolicies,
+) -> Result<(ScriptTransactionBuilder, u64)> {
+    let provider = account.try_provider()?;
+
+    let balance = account.get_asset_balance(&asset_id).await?;
+    if balance == 0 {
+        return Err(error!(
+            Other,
+            "no spendable balance of asset `{asset_id}` to sweep"
+        ));
+    }
+
+    let inputs = account
+        .get_asset_inputs_for_amount(asset_id, balance)
+        .await?;
+    let available: u64 = inputs.iter().filter_map(Input::amount).sum();
+    let outputs = account.get_asset_outputs_for_amount(to, asset_id, available);
+
+    let mut tx_builder = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, tx_policies);
+
+    account.add_witnesses(&mut tx_builder)?;
+
+    let amount = if asset_id == *provider.base_asset_id() {
+        deduct_fee_from_sweep(&mut tx_builder, available, provider).await?
+    } else {
+        account.adjust_for_fee(&mut tx_builder, 0).await?;
+        available
+    };
+
+    Ok((tx_builder, amount))
+}
+
+/// How many times the fee of a base asset sweep is re-estimated before giving up.
+const MAX_SWEEP_FEE_ESTIMATIONS: usize = 5;
+
+/// Lowers the amount of the single coin output of `tb` by the transaction fee until the fee
+/// estimated for the resulting transaction stays the same. Returns the final amount.
+async fn deduct_fee_from_sweep(
+    tb: &mut ScriptTransactionBuilder,
+    available: u64,
+    provider: &Provider,
+) -> Result<u64> {
+    let mut amount = available;
+
+    for _ in 0..MAX_SWEEP_FEE_ESTIMATIONS {
+        let fee = tb
+            .fee_checked_from_tx(provider)
+            .await?
+            .ok_or_else(|| error!(Other, "could not estimate the fee of the sweep"))?
+            .max_fee();
+
+        let new_amount = available
+            .checked_sub(fee)
+            .filter(|amount| *amount > 0)
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "the balance of {available} does not cover the fee of {fee}"
+                )
+            })?;
+
+        if new_amount == amount {
+            return Ok(amount);
+        }
+
+        amount = new_amount;
+        set_coin_output_amount(tb, amount);
+    }
+
+    Err(error!(
+        Other,
+        "the fee of the sweep did not settle after {MAX_SWEEP_FEE_ESTIMATIONS} estimations"
+    ))
+}
+
+fn set_coin_output_amount(tb: &mut ScriptTransactionBuilder, new_amount: u64) {
+    for output in tb.outputs_mut() {
+        if let Output::Coin { amount, .. } = output {
+            *amount = new_amount;
+        }
+    }
+}
+
+/// Adds up the amounts of each asset, keeping the order in which the assets first appear.
+fn sum_amounts_per_asset(assets: &[(AssetId, u64)]) -> Result<Vec<(AssetId, u64)>> {
+    if assets.is_empty() {
+        return Err(error!(Other, "no assets to transfer"));
+    }
+
+    let mut amounts: Vec<(AssetId, u64)> = vec![];
+    for &(asset_id, amount) in assets {
+        match amounts.iter_mut().find(|(id, _)| *id == asset_id) {
+            Some((_, total)) => {
+                *total = total
+                    .checked_add(amount)
+                    .ok_or_else(|| error!(Other, "amount of asset `{asset_id}` overflows"))?;
+            }
+            None => amounts.push((asset_id, amount)),
+        }
+    }
+
+    Ok(amounts)
 }
 
 #[cfg(test)]
@@ -290,7 +619,11 @@ mod tests {
     use fuel_tx::{Address, ConsensusParameters, Output, Transaction as FuelTransaction};
     use fuels_core::{
         traits::Signer,
-        types::{transaction::Transaction, transaction_builders::DryRunner},
+        types::{
+            transaction::Transaction,
+            transaction_builders::DryRunner,
+            typed_data::{TypedData, TypedDataDomain},
+        },
     };
     use rand::{rngs::StdRng, RngCore, SeedableRng};
 
@@ -327,6 +660,28 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn sign_and_verify_typed_data() -> Result<()> {
+        let wallet = WalletUnlocked::new_from_private_key(
+            SecretKey::from_str(
+                "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+            )?,
+            None,
+        );
+
+        // ANCHOR: sign_typed_data
+        let domain = TypedDataDomain::new("Exchange", "1", 0)
+            .with_verifying_contract(ContractId::from([1; 32]));
+        let typed_data = TypedData::new(domain, Address::from([2; 32]))?;
+
+        let signature = wallet.sign_typed_data(&typed_data).await?;
+
+        typed_data.verify(&signature, wallet.address())?;
+        // ANCHOR_END: sign_typed_data
+
+        Ok(())
+    }
+
     #[derive(Default)]
     struct MockDryRunner {
         c_param: ConsensusParameters,
@@ -410,4 +765,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn amounts_of_the_same_asset_are_added_up() -> Result<()> {
+        let asset_a = AssetId::from([1; 32]);
+        let asset_b = AssetId::from([2; 32]);
+
+        let amounts = sum_amounts_per_asset(&[(asset_a, 10), (asset_b, 5), (asset_a, 20)])?;
+
+        assert_eq!(amounts, vec![(asset_a, 30), (asset_b, 5)]);
+        assert!(sum_amounts_per_asset(&[]).is_err());
+        assert!(sum_amounts_per_asset(&[(asset_a, u64::MAX), (asset_a, 1)]).is_err());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,207 @@
+use fuel_tx::Output;
+use fuel_types::AssetId;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    input::Input,
+    transaction_builders::TransactionBuilder,
+};
+
+use crate::{Account, DynAccount, ViewOnlyAccount};
+
+/// The amount an account contributes to a transaction funded with [`CoFunding`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contribution {
+    pub address: Bech32Address,
+    pub amount: u64,
+}
+
+/// Funds a transaction from several accounts, e.g. two parties splitting a deposit. Each
+/// contributor gives at most its cap, in the order the contributors were added, until the amount
+/// is covered.
+///
+/// The inputs of every contributor are added to the transaction builder together with an output
+/// returning its change, so that no contributor pays more than its share. Signatures of wallets
+/// are added to the builder as well. The first contributor pays the fee.
+#[derive(Debug, Clone)]
+pub struct CoFunding {
+    asset_id: AssetId,
+    amount: u64,
+    contributors: Vec<(DynAccount, u64)>,
+}
+
+impl CoFunding {
+    /// Funds `amount` of `asset_id`, e.g. the amount transferred by the outputs of the
+    /// transaction.
+    pub fn new(asset_id: AssetId, amount: u64) -> Self {
+        Self {
+            asset_id,
+            amount,
+            contributors: vec![],
+        }
+    }
+
+    /// Adds `account` as a contributor giving at most `cap`.
+    pub fn add_contributor(mut self, account: impl Account + 'static, cap: u64) -> Self {
+        self.contributors.push((DynAccount::new(account), cap));
+        self
+    }
+
+    /// Returns how much each contributor gives. Fails if the caps don't cover the amount or if
+    /// an account was added more than once.
+    pub fn contributions(&self) -> Result<Vec<Contribution>> {
+        let mut remaining = self.amount;
+        let mut contributions: Vec<Contribution> = vec![];
+
+        for (account, cap) in &self.contributors {
+            let address = account.address();
+            if contributions.iter().any(|c| &c.address == address) {
+                return Err(error!(
+                    Other,
+                    "account `{address}` was added as a contributor more than once"
+                ));
+            }
+
+            let amount = remaining.min(*cap);
+            remaining -= amount;
+            contributions.push(Contribution {
+                address: address.clone(),
+                amount,
+            });
+        }
+
+        if remaining > 0 {
+            let covered = self.amount - remaining;
+            return Err(error!(
+                Other,
+                "the caps of the contributors cover {covered} of the {} to fund", self.amount
+            ));
+        }
+
+        Ok(contributions)
+    }
+
+    /// Adds the inputs, change outputs and signatures of the contributors to `tb`, and the base
+    /// asset inputs of the first contributor needed to pay the fee. Returns how much each
+    /// contributor gives.
+    ///
+    /// The change of the first contributor is returned by the change output of the asset, which
+    /// is added if `tb` doesn't have one. The other contributors get a coin output with their
+    /// exact change.
+    pub async fn fund<Tb: TransactionBuilder + Sync>(
+        &self,
+        tb: &mut Tb,
+    ) -> Result<Vec<Contribution>> {
+        let contributions = self.contributions()?;
+        let (fee_payer, _) = self
+            .contributors
+            .first()
+            .ok_or_else(|| error!(Other, "`CoFunding` needs at least one contributor"))?;
+        let base_asset_id = *fee_payer.try_provider()?.base_asset_id();
+
+        let change_owner = match self.change_output_owner(tb) {
+            Some(owner) => owner,
+            None => {
+                tb.outputs_mut()
+                    .push(Output::change(fee_payer.address().into(), 0, self.asset_id));
+                fee_payer.address().clone()
+            }
+        };
+
+        let mut exact_change = 0;
+        for (index, ((account, _), contribution)) in
+            self.contributors.iter().zip(&contributions).enumerate()
+        {
+            if contribution.amount > 0 {
+                let inputs = account
+                    .get_asset_inputs_for_amount(self.asset_id, contribution.amount)
+                    .await?;
+                let change = total_amount(&inputs) - contribution.amount;
+
+                tb.inputs_mut().extend(inputs);
+                if change > 0 && account.address() != &change_owner {
+                    tb.outputs_mut().push(Output::coin(
+                        account.address().into(),
+                        change,
+                        self.asset_id,
+                    ));
+                    exact_change += change;
+                }
+            }
+
+            // the fee payer signs for the base asset inputs paying the fee
+            if contribution.amount > 0 || index == 0 {
+                account.add_witnesses(tb)?;
+            }
+        }
+
+        let used_base_amount = if self.asset_id == base_asset_id {
+            self.amount + exact_change
+        } else {
+            0
+        };
+        fee_payer.adjust_for_fee(tb, used_base_amount).await?;
+
+        Ok(contributions)
+    }
+
+    fn change_output_owner(&self, tb: &impl TransactionBuilder) -> Option<Bech32Address> {
+        tb.outputs().iter().find_map(|output| match output {
+            Output::Change { to, asset_id, .. } if asset_id == &self.asset_id => {
+                Some(Bech32Address::from(*to))
+            }
+            _ => None,
+        })
+    }
+}
+
+fn total_amount(inputs: &[Input]) -> u64 {
+    inputs.iter().filter_map(Input::amount).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::WalletUnlocked;
+
+    #[test]
+    fn contributors_give_up_to_their_cap_in_order() -> Result<()> {
+        let alice = WalletUnlocked::new_random(None);
+        let bob = WalletUnlocked::new_random(None);
+        let carol = WalletUnlocked::new_random(None);
+
+        let contributions = CoFunding::new(AssetId::zeroed(), 100)
+            .add_contributor(alice.clone(), 60)
+            .add_contributor(bob.clone(), 60)
+            .add_contributor(carol.clone(), 60)
+            .contributions()?;
+
+        let amounts: Vec<_> = contributions
+            .iter()
+            .map(|c| (&c.address, c.amount))
+            .collect();
+        assert_eq!(
+            amounts,
+            [
+                (alice.address(), 60),
+                (bob.address(), 40),
+                (carol.address(), 0)
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn caps_must_cover_the_amount() {
+        let err = CoFunding::new(AssetId::zeroed(), 100)
+            .add_contributor(WalletUnlocked::new_random(None), 30)
+            .add_contributor(WalletUnlocked::new_random(None), 30)
+            .contributions()
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("the caps of the contributors cover 60 of the 100 to fund"));
+    }
+}
@@ -0,0 +1,197 @@
+use std::{any::Any, fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+use fuel_tx::Output;
+use fuel_types::AssetId;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::Result,
+    input::Input,
+    transaction_builders::{TransactionBuilder, TransactionBuilderMut},
+};
+
+use crate::{provider::Provider, Account, CoinFilter, ViewOnlyAccount};
+
+/// An [`Account`] whose concrete type is erased, so that different kinds of accounts, e.g.
+/// wallets and predicates, can be kept in the same collection. `DynAccount` is itself an
+/// `Account` and can be used wherever one is expected. Clones share the wrapped account.
+#[derive(Debug, Clone)]
+pub struct DynAccount {
+    account: Arc<dyn ErasedAccount>,
+}
+
+impl DynAccount {
+    pub fn new(account: impl Account + 'static) -> Self {
+        Self {
+            account: Arc::new(account),
+        }
+    }
+
+    /// Returns the wrapped account if it is a `T`.
+    pub fn downcast_ref<T: Account + 'static>(&self) -> Option<&T> {
+        self.account.as_any().downcast_ref()
+    }
+}
+
+/// The object-safe part of [`Account`] the other methods are implemented with.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+trait ErasedAccount: Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn address(&self) -> &Bech32Address;
+
+    fn try_provider(&self) -> Result<&Provider>;
+
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>>;
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>>;
+
+    fn get_asset_outputs_for_amount(
+        &self,
+        to: &Bech32Address,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Vec<Output>;
+
+    fn add_witnesses(&self, tb: TransactionBuilderMut<'_>) -> Result<()>;
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T: Account + 'static> ErasedAccount for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn address(&self) -> &Bech32Address {
+        ViewOnlyAccount::address(self)
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        ViewOnlyAccount::try_provider(self)
+    }
+
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        Account::get_asset_inputs_for_amount(self, asset_id, amount).await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        Account::get_asset_inputs_for_amount_filtered(self, asset_id, amount, filter).await
+    }
+
+    fn get_asset_outputs_for_amount(
+        &self,
+        to: &Bech32Address,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Vec<Output> {
+        Account::get_asset_outputs_for_amount(self, to, asset_id, amount)
+    }
+
+    fn add_witnesses(&self, tb: TransactionBuilderMut<'_>) -> Result<()> {
+        match tb {
+            TransactionBuilderMut::Script(tb) => Account::add_witnesses(self, tb),
+            TransactionBuilderMut::Create(tb) => Account::add_witnesses(self, tb),
+        }
+    }
+}
+
+impl ViewOnlyAccount for DynAccount {
+    fn address(&self) -> &Bech32Address {
+        self.account.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.account.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for DynAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.account
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        self.account
+            .get_asset_inputs_for_amount_filtered(asset_id, amount, filter)
+            .await
+    }
+
+    fn get_asset_outputs_for_amount(
+        &self,
+        to: &Bech32Address,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Vec<Output> {
+        self.account
+            .get_asset_outputs_for_amount(to, asset_id, amount)
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        self.account.add_witnesses(tb.as_builder_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::{
+        errors::Result,
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+    };
+
+    use super::DynAccount;
+    use crate::{predicate::Predicate, wallet::WalletUnlocked, Account, ViewOnlyAccount};
+
+    #[test]
+    fn accounts_of_different_types_can_be_stored_together() -> Result<()> {
+        let wallet = WalletUnlocked::new_random(None);
+        let predicate = Predicate::from_code(vec![1, 2, 3]);
+
+        let accounts = vec![DynAccount::new(wallet.clone()), DynAccount::new(predicate)];
+
+        assert_eq!(accounts[0].address(), wallet.address());
+        assert!(accounts[0].downcast_ref::<WalletUnlocked>().is_some());
+        assert!(accounts[1].downcast_ref::<WalletUnlocked>().is_none());
+        assert!(accounts[1].downcast_ref::<Predicate>().is_some());
+
+        // only the wallet signs
+        let mut tb = ScriptTransactionBuilder::default();
+        for account in &accounts {
+            account.add_witnesses(&mut tb)?;
+        }
+        assert!(tb.add_signer(wallet).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuels_core::{
+    crypto::compact_from_rsv,
+    traits::Signer,
+    types::{
+        bech32::Bech32Address, errors::Result, input::Input,
+        transaction_builders::TransactionBuilder, AssetId,
+    },
+};
+
+use crate::{
+    accounts_utils::try_provider_error, predicate::Predicate, provider::Provider, Account,
+    CoinFilter, ViewOnlyAccount,
+};
+
+/// An Ethereum key able to produce EIP-191 (`personal_sign`) signatures, e.g. a MetaMask wallet.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait EvmSigner: Debug + Clone + Send + Sync + 'static {
+    /// Returns the `r || s || v` signature of `message` prefixed with
+    /// `"\x19Ethereum Signed Message:\n" || len(message)`.
+    async fn personal_sign(&self, message: &[u8]) -> Result<[u8; 65]>;
+}
+
+/// Converts a 65-byte Ethereum `r || s || v` signature into the 64-byte compact format used by
+/// the FuelVM, see [`compact_from_rsv`].
+pub fn evm_signature_to_compact(signature: [u8; 65]) -> Result<Signature> {
+    compact_from_rsv(&signature)
+}
+
+/// Account whose funds are owned by a predicate validating signatures of an Ethereum key.
+///
+/// The predicate is expected to be configured with the EVM address of `signer` and to accept
+/// the transaction if one of its witnesses is an EIP-191 signature of the transaction id,
+/// in the compact format, that recovers to that address. Witnesses are added automatically by
+/// the `Account` methods or by calling [`Account::add_witnesses`] on a transaction builder.
+///
+/// Such a predicate is maintained in `packages/fuels/predicates/evm`, with the address set
+/// through its `SIGNER` configurable.
+#[derive(Debug, Clone)]
+pub struct EvmControlledAccount<S> {
+    predicate: Predicate,
+    signer: S,
+}
+
+impl<S: EvmSigner> EvmControlledAccount<S> {
+    pub fn new(predicate: Predicate, signer: S) -> Self {
+        Self { predicate, signer }
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn signer(&self) -> &S {
+        &self.signer
+    }
+
+    pub fn set_provider(&mut self, provider: Provider) {
+        self.predicate.set_provider(provider);
+    }
+
+    pub fn with_provider(self, provider: Provider) -> Self {
+        Self {
+            predicate: self.predicate.with_provider(provider),
+            ..self
+        }
+    }
+}
+
+impl<S: EvmSigner> ViewOnlyAccount for EvmControlledAccount<S> {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.provider().ok_or_else(try_provider_error)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: EvmSigner> Account for EvmControlledAccount<S> {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount_filtered(asset_id, amount, filter)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(EvmWitnessSigner {
+            signer: self.signer.clone(),
+            address: self.address().clone(),
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Adapts an [`EvmSigner`] to the [`Signer`] used by the transaction builders so that the
+/// signature is added as a witness once the transaction id is known.
+struct EvmWitnessSigner<S> {
+    signer: S,
+    address: Bech32Address,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: EvmSigner> Signer for EvmWitnessSigner<S> {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let signature = self.signer.personal_sign(message.as_ref()).await?;
+
+        evm_signature_to_compact(signature)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    fn to_evm_signature(compact: Signature, v_offset: u8) -> [u8; 65] {
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(compact.as_ref());
+
+        let recovery_id = signature[32] >> 7;
+        signature[32] &= 0x7f;
+        signature[64] = recovery_id + v_offset;
+
+        signature
+    }
+
+    #[test]
+    fn evm_signature_is_converted_to_compact_format() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let message = Message::new("fuel");
+        let expected = Signature::sign(&secret_key, &message);
+
+        for v_offset in [0, 27] {
+            let signature = evm_signature_to_compact(to_evm_signature(expected, v_offset))?;
+
+            assert_eq!(signature, expected);
+            assert_eq!(
+                signature.recover(&message)?,
+                secret_key.public_key(),
+                "recovered public key should match the signing key"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_recovery_id_is_rejected() {
+        let mut invalid = [0u8; 65];
+        invalid[64] = 2;
+
+        let err = evm_signature_to_compact(invalid).expect_err("should fail");
+
+        assert!(err.to_string().contains("invalid `v` `2`"));
+    }
+}
@@ -3,14 +3,38 @@ mod account;
 #[cfg(feature = "std")]
 mod accounts_utils;
 #[cfg(feature = "std")]
+pub mod co_funding;
+#[cfg(feature = "std")]
+mod dyn_account;
+#[cfg(feature = "std")]
+pub mod evm;
+#[cfg(feature = "std")]
+pub mod mnemonic;
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
+#[cfg(feature = "std")]
+pub mod portfolio;
+#[cfg(feature = "std")]
 pub mod provider;
 #[cfg(feature = "std")]
+pub mod session;
+#[cfg(feature = "std")]
+pub mod signing_policy;
+#[cfg(feature = "std")]
 pub mod wallet;
 
 #[cfg(feature = "std")]
 pub use account::*;
+#[cfg(feature = "std")]
+pub use dyn_account::DynAccount;
 
 #[cfg(feature = "coin-cache")]
 mod coin_cache;
 
+/// Used by the code generated by `#[derive(Account)]`.
+#[doc(hidden)]
+pub mod __private {
+    pub use async_trait::async_trait;
+}
+
 pub mod predicate;
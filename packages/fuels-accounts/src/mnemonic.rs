@@ -0,0 +1,433 @@
+//! Generation and validation of BIP-39 mnemonic phrases. Only English phrases are supported
+//! unless the `mnemonic-all-langs` feature is enabled, which brings in the word lists of all the
+//! BIP-39 languages.
+
+use std::str::FromStr;
+
+use coins_bip32::{path::DerivationPath, prelude::SigningKey};
+use coins_bip39::Mnemonic;
+use fuel_crypto::{Hasher, SecretKey};
+use fuels_core::types::errors::{error, Error, Result};
+use rand::{CryptoRng, Rng};
+
+const BITS_PER_WORD: usize = 11;
+
+/// The BIP-39 word lists. All but English require the `mnemonic-all-langs` feature.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Language {
+    #[cfg(feature = "mnemonic-all-langs")]
+    ChineseSimplified,
+    #[cfg(feature = "mnemonic-all-langs")]
+    ChineseTraditional,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Czech,
+    #[default]
+    English,
+    #[cfg(feature = "mnemonic-all-langs")]
+    French,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Italian,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Japanese,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Korean,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Portuguese,
+    #[cfg(feature = "mnemonic-all-langs")]
+    Spanish,
+}
+
+/// Runs `$body` with `$wordlist` being the `coins_bip39` word list of `$language`.
+macro_rules! with_wordlist {
+    ($language:expr, $wordlist:ident => $body:expr) => {
+        match $language {
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::ChineseSimplified => {
+                type $wordlist = coins_bip39::ChineseSimplified;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::ChineseTraditional => {
+                type $wordlist = coins_bip39::ChineseTraditional;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Czech => {
+                type $wordlist = coins_bip39::Czech;
+                $body
+            }
+            Language::English => {
+                type $wordlist = coins_bip39::English;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::French => {
+                type $wordlist = coins_bip39::French;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Italian => {
+                type $wordlist = coins_bip39::Italian;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Japanese => {
+                type $wordlist = coins_bip39::Japanese;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Korean => {
+                type $wordlist = coins_bip39::Korean;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Portuguese => {
+                type $wordlist = coins_bip39::Portuguese;
+                $body
+            }
+            #[cfg(feature = "mnemonic-all-langs")]
+            Language::Spanish => {
+                type $wordlist = coins_bip39::Spanish;
+                $body
+            }
+        }
+    };
+}
+
+impl Language {
+    /// The 2048 words of the list, in order.
+    pub fn words(self) -> &'static [&'static str] {
+        with_wordlist!(self, W => <W as coins_bip39::Wordlist>::get_all())
+    }
+
+    fn separator(self) -> &'static str {
+        // ideographic space, as required by BIP-39
+        #[cfg(feature = "mnemonic-all-langs")]
+        if self == Language::Japanese {
+            return "\u{3000}";
+        }
+
+        " "
+    }
+}
+
+/// The number of words of a mnemonic phrase. Each word encodes 11 bits, of which the entropy
+/// takes 32 out of every 33.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WordCount {
+    #[default]
+    Twelve,
+    Fifteen,
+    Eighteen,
+    TwentyOne,
+    TwentyFour,
+}
+
+impl WordCount {
+    pub fn words(self) -> usize {
+        match self {
+            WordCount::Twelve => 12,
+            WordCount::Fifteen => 15,
+            WordCount::Eighteen => 18,
+            WordCount::TwentyOne => 21,
+            WordCount::TwentyFour => 24,
+        }
+    }
+
+    /// The entropy encoded by the phrase, in bytes: 16 for 12 words up to 32 for 24 words.
+    pub fn entropy_len(self) -> usize {
+        self.words() * BITS_PER_WORD * 32 / 33 / 8
+    }
+
+    fn from_words(words: usize) -> Option<Self> {
+        [
+            WordCount::Twelve,
+            WordCount::Fifteen,
+            WordCount::Eighteen,
+            WordCount::TwentyOne,
+            WordCount::TwentyFour,
+        ]
+        .into_iter()
+        .find(|count| count.words() == words)
+    }
+}
+
+impl TryFrom<usize> for WordCount {
+    type Error = MnemonicError;
+
+    fn try_from(words: usize) -> std::result::Result<Self, Self::Error> {
+        Self::from_words(words).ok_or(MnemonicError::WordCount(words))
+    }
+}
+
+/// Why a mnemonic phrase is invalid, see [`validate_mnemonic_phrase`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MnemonicError {
+    #[error("expected 12, 15, 18, 21 or 24 words, got {0}")]
+    WordCount(usize),
+    /// `position` starts at 1 for the first word.
+    #[error("word {position}, `{word}`, is not in the {language:?} word list")]
+    UnknownWord {
+        position: usize,
+        word: String,
+        language: Language,
+    },
+    #[error("the checksum of the phrase doesn't match its words")]
+    Checksum,
+}
+
+impl From<MnemonicError> for Error {
+    fn from(err: MnemonicError) -> Self {
+        error!(Other, "invalid mnemonic phrase: {err}")
+    }
+}
+
+/// Generates mnemonic phrases with a configurable number of words and language.
+///
+/// The entropy is drawn from the given random number generator, e.g. a hardware RNG, or given
+/// directly with [`MnemonicGenerator::generate_from_entropy`] for deterministic phrases.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MnemonicGenerator {
+    word_count: WordCount,
+    language: Language,
+}
+
+impl MnemonicGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_word_count(mut self, word_count: WordCount) -> Self {
+        self.word_count = word_count;
+        self
+    }
+
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn generate<R: Rng + CryptoRng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut entropy = vec![0; self.word_count.entropy_len()];
+        rng.fill_bytes(&mut entropy);
+
+        self.generate_from_entropy(&entropy)
+            .expect("entropy has the length of the word count")
+    }
+
+    /// Encodes `entropy` as a phrase. Its length determines the number of words, from 16 bytes
+    /// for 12 words up to 32 bytes for 24 words, and must be a multiple of 4.
+    pub fn generate_from_entropy(&self, entropy: &[u8]) -> Result<String> {
+        let word_count = WordCount::from_words(entropy.len() * 3 / 4)
+            .filter(|count| count.entropy_len() == entropy.len())
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "expected 16, 20, 24, 28 or 32 bytes of entropy, got {}",
+                    entropy.len()
+                )
+            })?;
+
+        let checksum = Hasher::hash(entropy);
+        let checksum_len = word_count.words() * BITS_PER_WORD - entropy.len() * 8;
+
+        let bits = to_bits(entropy)
+            .into_iter()
+            .chain(to_bits(checksum.as_ref()).into_iter().take(checksum_len))
+            .collect::<Vec<_>>();
+
+        let words = self.language.words();
+        let phrase = bits
+            .chunks(BITS_PER_WORD)
+            .map(|chunk| words[from_bits(chunk)])
+            .collect::<Vec<_>>()
+            .join(self.language.separator());
+
+        Ok(phrase)
+    }
+}
+
+/// Checks that `phrase` is a valid mnemonic phrase in `language`: a supported number of words,
+/// all of them in the word list, and a matching checksum. Words can be separated by any
+/// whitespace.
+pub fn validate_mnemonic_phrase(
+    phrase: &str,
+    language: Language,
+) -> std::result::Result<(), MnemonicError> {
+    let words = language.words();
+    let indices = phrase
+        .split_whitespace()
+        .enumerate()
+        .map(|(index, word)| {
+            words
+                .iter()
+                .position(|candidate| *candidate == word)
+                .ok_or_else(|| MnemonicError::UnknownWord {
+                    position: index + 1,
+                    word: word.to_string(),
+                    language,
+                })
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let word_count = WordCount::try_from(indices.len())?;
+
+    let bits = indices
+        .into_iter()
+        .flat_map(|index| {
+            (0..BITS_PER_WORD)
+                .rev()
+                .map(move |bit| (index >> bit) & 1 == 1)
+        })
+        .collect::<Vec<_>>();
+    let (entropy, checksum) = bits.split_at(word_count.entropy_len() * 8);
+
+    let entropy = entropy.chunks(8).map(from_bits).map(|byte| byte as u8);
+    let expected_checksum = to_bits(Hasher::hash(entropy.collect::<Vec<_>>()).as_ref());
+
+    if checksum != &expected_checksum[..checksum.len()] {
+        return Err(MnemonicError::Checksum);
+    }
+
+    Ok(())
+}
+
+/// Derives the secret key at `path` from a phrase in `language`, without a passphrase.
+pub(crate) fn derive_secret_key(phrase: &str, language: Language, path: &str) -> Result<SecretKey> {
+    validate_mnemonic_phrase(phrase, language)?;
+
+    let path = DerivationPath::from_str(path)
+        .map_err(|err| error!(Other, "invalid derivation path `{path}`: {err}"))?;
+    // the seed is computed from the normalized phrase, in which words are separated by spaces
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let derived_key = with_wordlist!(language, W => {
+        Mnemonic::<W>::new_from_phrase(&phrase)
+            .and_then(|mnemonic| mnemonic.derive_key(path, None))
+    })
+    .map_err(|err| {
+        error!(
+            Other,
+            "could not derive a key from the mnemonic phrase: {err}"
+        )
+    })?;
+
+    let signing_key: &SigningKey = derived_key.as_ref();
+
+    SecretKey::try_from(signing_key.to_bytes().as_slice())
+        .map_err(|err| error!(Other, "invalid derived key: {err}"))
+}
+
+fn to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |bit| (byte >> bit) & 1 == 1))
+        .collect()
+}
+
+fn from_bits(bits: &[bool]) -> usize {
+    bits.iter()
+        .fold(0, |value, bit| (value << 1) | usize::from(*bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    #[test]
+    fn phrases_match_the_bip39_test_vectors() -> Result<()> {
+        let english = MnemonicGenerator::new();
+
+        assert_eq!(
+            english.generate_from_entropy(&[0; 16])?,
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+        );
+        assert_eq!(
+            english.generate_from_entropy(&[0x7f; 16])?,
+            "legal winner thank year wave sausage worth useful legal winner thank yellow"
+        );
+        assert_eq!(
+            english.generate_from_entropy(&[0xff; 32])?,
+            format!("{}vote", "zoo ".repeat(23))
+        );
+
+        let err = english
+            .generate_from_entropy(&[0; 17])
+            .expect_err("should fail");
+        assert!(err.to_string().contains("got 17"));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "mnemonic-all-langs")]
+    fn generated_phrases_are_valid() {
+        // ANCHOR: mnemonic_generator
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let phrase = MnemonicGenerator::new()
+            .with_word_count(WordCount::TwentyFour)
+            .with_language(Language::Spanish)
+            .generate(&mut rng);
+
+        assert!(validate_mnemonic_phrase(&phrase, Language::Spanish).is_ok());
+        // ANCHOR_END: mnemonic_generator
+
+        assert_eq!(phrase.split_whitespace().count(), 24);
+
+        for language in [Language::English, Language::Japanese, Language::Korean] {
+            let phrase = MnemonicGenerator::new()
+                .with_language(language)
+                .generate(&mut rng);
+
+            assert_eq!(validate_mnemonic_phrase(&phrase, language), Ok(()));
+        }
+    }
+
+    #[test]
+    fn invalid_phrases_are_explained() {
+        // ANCHOR: validate_mnemonic_phrase
+        let err = validate_mnemonic_phrase(
+            "oblige salon price punch sadle immune slogan rare snap desert retire surprise",
+            Language::English,
+        )
+        .expect_err("should fail");
+
+        assert_eq!(
+            err,
+            MnemonicError::UnknownWord {
+                position: 5,
+                word: "sadle".to_string(),
+                language: Language::English,
+            }
+        );
+        // ANCHOR_END: validate_mnemonic_phrase
+
+        assert_eq!(
+            validate_mnemonic_phrase("abandon abandon about", Language::English),
+            Err(MnemonicError::WordCount(3))
+        );
+
+        let wrong_checksum = "abandon ".repeat(12);
+        assert_eq!(
+            validate_mnemonic_phrase(&wrong_checksum, Language::English),
+            Err(MnemonicError::Checksum)
+        );
+    }
+
+    #[test]
+    fn keys_are_derived_like_english_wallets() -> Result<()> {
+        let phrase =
+            "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+        let path = "m/44'/60'/0'/0/0";
+
+        assert_eq!(
+            derive_secret_key(phrase, Language::English, path)?,
+            SecretKey::new_from_mnemonic_phrase_with_path(phrase, path)?
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+use std::{fmt, path::PathBuf, sync::Mutex};
+
+use async_trait::async_trait;
+use cryptoki::{
+    context::{CInitializeArgs, Pkcs11},
+    mechanism::Mechanism,
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    slot::Slot,
+    types::AuthPin,
+};
+use fuel_crypto::{Message, PublicKey, Signature};
+use fuels_core::{
+    crypto::recover_compact_from_rs,
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, FUEL_BECH32_HRP},
+        errors::{error, Result},
+    },
+};
+use zeroize::Zeroizing;
+
+/// Configuration needed to access a secp256k1 key stored in a PKCS#11 token.
+#[derive(Clone)]
+pub struct Pkcs11Config {
+    /// Path to the PKCS#11 module (shared library) provided by the HSM vendor.
+    pub module_path: PathBuf,
+    /// Id of the slot holding the token.
+    pub slot_id: u64,
+    /// User PIN of the token.
+    pub pin: Zeroizing<String>,
+    /// `CKA_LABEL` of both the private and the public key.
+    pub key_label: String,
+}
+
+impl fmt::Debug for Pkcs11Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs11Config")
+            .field("module_path", &self.module_path)
+            .field("slot_id", &self.slot_id)
+            .field("key_label", &self.key_label)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`Signer`] backed by a secp256k1 key that never leaves a PKCS#11 token (e.g. an HSM).
+pub struct Pkcs11Signer {
+    // `Session` is not `Sync`, signing requests are serialized through the lock
+    session: Mutex<Session>,
+    private_key: ObjectHandle,
+    public_key: PublicKey,
+    address: Bech32Address,
+}
+
+impl Pkcs11Signer {
+    /// Loads the PKCS#11 module, logs into the token and looks up the key pair labeled
+    /// `config.key_label`.
+    pub fn connect(config: Pkcs11Config) -> Result<Self> {
+        let pkcs11 = Pkcs11::new(&config.module_path).map_err(pkcs11_error)?;
+        pkcs11
+            .initialize(CInitializeArgs::OsThreads)
+            .map_err(pkcs11_error)?;
+
+        let slot = Slot::try_from(config.slot_id).map_err(pkcs11_error)?;
+        let session = pkcs11.open_ro_session(slot).map_err(pkcs11_error)?;
+        let pin = AuthPin::new(config.pin.as_str().into());
+        session
+            .login(UserType::User, Some(&pin))
+            .map_err(pkcs11_error)?;
+
+        let private_key = find_key(&session, KeyKind::Private, &config.key_label)?;
+        let public_key_handle = find_key(&session, KeyKind::Public, &config.key_label)?;
+        let public_key = read_public_key(&session, public_key_handle)?;
+        let address = Bech32Address::new(FUEL_BECH32_HRP, public_key.hash());
+
+        Ok(Self {
+            session: Mutex::new(session),
+            private_key,
+            public_key,
+            address,
+        })
+    }
+
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn sign_digest(&self, message: &Message) -> Result<Vec<u8>> {
+        let session = self
+            .session
+            .lock()
+            .map_err(|_| error!(Other, "PKCS#11 session lock poisoned"))?;
+
+        session
+            .sign(&Mechanism::Ecdsa, self.private_key, message.as_ref())
+            .map_err(pkcs11_error)
+    }
+}
+
+impl fmt::Debug for Pkcs11Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pkcs11Signer")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for Pkcs11Signer {
+    async fn sign(&self, message: Message) -> Result<Signature> {
+        let raw_signature = self.sign_digest(&message)?;
+
+        to_compact_signature(&raw_signature, &message, &self.public_key)
+    }
+
+    fn address(&self) -> &Bech32Address {
+        &self.address
+    }
+}
+
+fn pkcs11_error(err: impl fmt::Display) -> fuels_core::types::errors::Error {
+    error!(Other, "PKCS#11 error: {err}")
+}
+
+#[derive(Debug, Clone, Copy)]
+enum KeyKind {
+    Private,
+    Public,
+}
+
+impl KeyKind {
+    fn object_class(self) -> ObjectClass {
+        match self {
+            KeyKind::Private => ObjectClass::PRIVATE_KEY,
+            KeyKind::Public => ObjectClass::PUBLIC_KEY,
+        }
+    }
+}
+
+fn find_key(session: &Session, kind: KeyKind, label: &str) -> Result<ObjectHandle> {
+    let template = [
+        Attribute::Class(kind.object_class()),
+        Attribute::KeyType(KeyType::EC),
+        Attribute::Label(label.as_bytes().to_vec()),
+    ];
+
+    match session.find_objects(&template).map_err(pkcs11_error)?[..] {
+        [handle] => Ok(handle),
+        [] => Err(error!(
+            Other,
+            "no {kind:?} key labeled `{label}` found in token"
+        )),
+        _ => Err(error!(
+            Other,
+            "multiple {kind:?} keys labeled `{label}` found in token"
+        )),
+    }
+}
+
+fn read_public_key(session: &Session, handle: ObjectHandle) -> Result<PublicKey> {
+    let attributes = session
+        .get_attributes(handle, &[AttributeType::EcPoint])
+        .map_err(pkcs11_error)?;
+
+    let ec_point = attributes
+        .into_iter()
+        .find_map(|attribute| match attribute {
+            Attribute::EcPoint(point) => Some(point),
+            _ => None,
+        })
+        .ok_or_else(|| error!(Other, "public key has no `CKA_EC_POINT` attribute"))?;
+
+    parse_ec_point(&ec_point)
+}
+
+/// `CKA_EC_POINT` holds the uncompressed point, usually wrapped in a DER octet string.
+fn parse_ec_point(ec_point: &[u8]) -> Result<PublicKey> {
+    let uncompressed = match ec_point {
+        [0x04, 0x41, 0x04, coordinates @ ..] | [0x04, coordinates @ ..]
+            if coordinates.len() == PublicKey::LEN =>
+        {
+            coordinates
+        }
+        _ => {
+            return Err(error!(
+                Other,
+                "expected an uncompressed secp256k1 public key, got {} bytes",
+                ec_point.len()
+            ))
+        }
+    };
+
+    Ok(PublicKey::try_from(uncompressed)?)
+}
+
+/// Converts a raw `r || s` signature into the compact format expected by fuel-tx, finding the
+/// recovery id for which it recovers to the public key of the token.
+fn to_compact_signature(
+    raw_signature: &[u8],
+    message: &Message,
+    public_key: &PublicKey,
+) -> Result<Signature> {
+    let raw_signature: &[u8; 64] = raw_signature.try_into().map_err(|_| {
+        error!(
+            Other,
+            "invalid signature returned by the token: expected 64 bytes, got {}",
+            raw_signature.len()
+        )
+    })?;
+
+    recover_compact_from_rs(raw_signature, message, public_key)
+        .map_err(|e| error!(Other, "invalid signature returned by the token: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+    use fuels_core::crypto::SignatureParts;
+
+    use super::*;
+
+    #[test]
+    fn raw_signatures_are_converted_to_compact() -> Result<()> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let public_key = secret_key.public_key();
+        let message = Message::new("fuel");
+        let expected = Signature::sign(&secret_key, &message);
+
+        let parts = SignatureParts::from_compact(&expected);
+        let raw_signature = [parts.r, parts.s].concat();
+
+        assert_eq!(
+            to_compact_signature(&raw_signature, &message, &public_key)?,
+            expected
+        );
+
+        let err = to_compact_signature(&raw_signature[..63], &message, &public_key)
+            .expect_err("should fail");
+        assert!(err.to_string().contains("expected 64 bytes, got 63"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn ec_point_is_parsed_with_and_without_der_wrapping() -> Result<()> {
+        let public_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?
+        .public_key();
+
+        let raw = [[0x04].as_slice(), public_key.as_ref()].concat();
+        let der_wrapped = [[0x04, 0x41].as_slice(), raw.as_slice()].concat();
+
+        assert_eq!(parse_ec_point(&raw)?, public_key);
+        assert_eq!(parse_ec_point(&der_wrapped)?, public_key);
+
+        Ok(())
+    }
+}
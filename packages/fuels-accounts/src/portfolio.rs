@@ -0,0 +1,241 @@
+//! Values the assets held by an account, see [`ViewOnlyAccount::portfolio`].
+//!
+//! [`ViewOnlyAccount::portfolio`]: crate::ViewOnlyAccount::portfolio
+
+use std::{cmp::Reverse, collections::HashMap, fmt::Debug};
+
+use async_trait::async_trait;
+use fuel_types::AssetId;
+use fuels_core::types::{
+    errors::{error, Result},
+    units::format_units,
+    U256,
+};
+
+#[cfg(feature = "price-oracle-http")]
+mod http;
+#[cfg(feature = "price-oracle-http")]
+pub use http::HttpPriceOracle;
+
+/// The currency prices are quoted in, e.g. `USD` with 2 decimals to express prices in cents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteCurrency {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl QuoteCurrency {
+    pub fn new(symbol: impl Into<String>, decimals: u8) -> Self {
+        Self {
+            symbol: symbol.into(),
+            decimals,
+        }
+    }
+}
+
+/// The price of an asset, as reported by a [`PriceOracle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetPrice {
+    /// The price of one whole unit of the asset, in base units of the quote currency.
+    pub price: U256,
+    /// How many decimals the asset has, i.e. one whole unit is `10^asset_decimals` base units.
+    pub asset_decimals: u8,
+}
+
+impl AssetPrice {
+    pub fn new(price: impl Into<U256>, asset_decimals: u8) -> Self {
+        Self {
+            price: price.into(),
+            asset_decimals,
+        }
+    }
+
+    /// The value of `amount` base units of the asset, in base units of the quote currency,
+    /// rounded down.
+    pub fn value_of(&self, amount: u64) -> Result<U256> {
+        self.price
+            .checked_mul(U256::from(amount))
+            .map(|value| value / U256::exp10(usize::from(self.asset_decimals)))
+            .ok_or_else(|| error!(Other, "the value of {amount} overflows a `U256`"))
+    }
+}
+
+/// Provides the prices used to value a [`Portfolio`], e.g. from an exchange or a price feed.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait PriceOracle: Debug + Send + Sync {
+    fn quote_currency(&self) -> &QuoteCurrency;
+
+    /// Returns the price of `asset_id`, or `None` if the oracle doesn't price the asset.
+    async fn price(&self, asset_id: AssetId) -> Result<Option<AssetPrice>>;
+}
+
+/// A [`PriceOracle`] with fixed prices, e.g. for tests or for assets pegged to the quote currency.
+#[derive(Debug, Clone)]
+pub struct FixedPriceOracle {
+    quote_currency: QuoteCurrency,
+    prices: HashMap<AssetId, AssetPrice>,
+}
+
+impl FixedPriceOracle {
+    pub fn new(quote_currency: QuoteCurrency) -> Self {
+        Self {
+            quote_currency,
+            prices: HashMap::new(),
+        }
+    }
+
+    pub fn with_price(mut self, asset_id: AssetId, price: AssetPrice) -> Self {
+        self.prices.insert(asset_id, price);
+        self
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl PriceOracle for FixedPriceOracle {
+    fn quote_currency(&self) -> &QuoteCurrency {
+        &self.quote_currency
+    }
+
+    async fn price(&self, asset_id: AssetId) -> Result<Option<AssetPrice>> {
+        Ok(self.prices.get(&asset_id).copied())
+    }
+}
+
+/// The balance of an asset and its value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holding {
+    pub asset_id: AssetId,
+    /// The balance, in base units of the asset.
+    pub amount: u64,
+    /// `None` if the oracle doesn't price the asset.
+    pub price: Option<AssetPrice>,
+    /// The value of the balance, in base units of the quote currency. `None` if the oracle
+    /// doesn't price the asset.
+    pub value: Option<U256>,
+}
+
+impl Holding {
+    /// The balance in whole units of the asset, e.g. `"1.5"`. `None` if the oracle doesn't price
+    /// the asset, since the decimals of the asset are then unknown.
+    pub fn formatted_amount(&self) -> Option<String> {
+        self.price
+            .map(|price| format_units(self.amount, price.asset_decimals))
+    }
+}
+
+/// The assets held by an account, valued with the prices of a [`PriceOracle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Portfolio {
+    pub quote_currency: QuoteCurrency,
+    /// Sorted by decreasing value, followed by the assets the oracle doesn't price.
+    pub holdings: Vec<Holding>,
+    /// The value of all priced holdings, in base units of the quote currency.
+    pub total_value: U256,
+}
+
+impl Portfolio {
+    /// Values `balances`, given in base units, with the prices of `oracle`. The assets are priced
+    /// one after the other.
+    pub async fn from_balances<O: PriceOracle>(
+        balances: impl IntoIterator<Item = (AssetId, u64)>,
+        oracle: &O,
+    ) -> Result<Self> {
+        let mut holdings = vec![];
+        let mut total_value = U256::zero();
+
+        for (asset_id, amount) in balances {
+            let price = oracle.price(asset_id).await?;
+            let value = price.map(|price| price.value_of(amount)).transpose()?;
+
+            if let Some(value) = value {
+                total_value = total_value
+                    .checked_add(value)
+                    .ok_or_else(|| error!(Other, "the total value overflows a `U256`"))?;
+            }
+
+            holdings.push(Holding {
+                asset_id,
+                amount,
+                price,
+                value,
+            });
+        }
+
+        holdings.sort_by_key(|holding| (Reverse(holding.value), holding.asset_id));
+
+        Ok(Self {
+            quote_currency: oracle.quote_currency().clone(),
+            holdings,
+            total_value,
+        })
+    }
+
+    /// The total value in whole units of the quote currency, e.g. `"1234.5"`.
+    pub fn formatted_total_value(&self) -> String {
+        format_units(self.total_value, self.quote_currency.decimals)
+    }
+
+    /// The holdings the oracle doesn't price, and which are left out of the total value.
+    pub fn unpriced(&self) -> impl Iterator<Item = &Holding> {
+        self.holdings
+            .iter()
+            .filter(|holding| holding.value.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_types::Address;
+
+    use super::*;
+    use crate::{provider::MockProvider, wallet::Wallet, ViewOnlyAccount};
+
+    #[tokio::test]
+    async fn holdings_are_valued_with_the_oracle_prices() -> Result<()> {
+        let owner = Address::from([1; 32]);
+        let eth = AssetId::zeroed();
+        let usdc = AssetId::from([2; 32]);
+        let unknown = AssetId::from([3; 32]);
+
+        let mock = MockProvider::new()
+            .with_balance(owner, eth, 1_500_000_000)
+            .with_balance(owner, usdc, 2_000_000)
+            .with_balance(owner, unknown, 42);
+        let wallet = Wallet::from_address(owner.into(), Some(mock.provider()));
+
+        // ANCHOR: portfolio
+        let oracle = FixedPriceOracle::new(QuoteCurrency::new("USD", 2))
+            // 1 ETH, with 9 decimals, is worth $3000.25
+            .with_price(eth, AssetPrice::new(300_025, 9))
+            // 1 USDC, with 6 decimals, is worth $1
+            .with_price(usdc, AssetPrice::new(100, 6));
+
+        let portfolio = wallet.portfolio(&oracle).await?;
+        // ANCHOR_END: portfolio
+
+        assert_eq!(portfolio.formatted_total_value(), "4502.37");
+
+        let assets = portfolio
+            .holdings
+            .iter()
+            .map(|holding| (holding.asset_id, holding.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            assets,
+            [
+                (eth, Some(U256::from(450_037))),
+                (usdc, Some(U256::from(200))),
+                (unknown, None)
+            ]
+        );
+        assert_eq!(
+            portfolio.holdings[0].formatted_amount().as_deref(),
+            Some("1.5")
+        );
+        assert_eq!(portfolio.unpriced().count(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use fuel_types::AssetId;
+use fuels_core::types::{
+    errors::{error, Result},
+    units::parse_units,
+};
+use reqwest::StatusCode;
+use serde_json::Value;
+
+use super::{AssetPrice, PriceOracle, QuoteCurrency};
+
+/// Decimals of the base asset, used for the assets without configured decimals.
+const DEFAULT_ASSET_DECIMALS: u8 = 9;
+
+/// A [`PriceOracle`] fetching prices from an HTTP endpoint returning JSON.
+///
+/// The URL of each request is the given template with `{asset_id}` replaced by the `0x` prefixed
+/// asset ID. The price is read from the response with a JSON pointer, `/price` by default, and
+/// can be either a number or a decimal string, in whole units of the quote currency. Assets the
+/// endpoint answers with `404 Not Found`, or with a `null` price, are not priced.
+#[derive(Debug, Clone)]
+pub struct HttpPriceOracle {
+    client: reqwest::Client,
+    url_template: String,
+    price_pointer: String,
+    quote_currency: QuoteCurrency,
+    asset_decimals: HashMap<AssetId, u8>,
+}
+
+impl HttpPriceOracle {
+    pub fn new(url_template: impl Into<String>, quote_currency: QuoteCurrency) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url_template: url_template.into(),
+            price_pointer: "/price".to_string(),
+            quote_currency,
+            asset_decimals: HashMap::new(),
+        }
+    }
+
+    /// Sets the JSON pointer locating the price in the response, e.g. `/data/0/price`.
+    pub fn with_price_pointer(mut self, pointer: impl Into<String>) -> Self {
+        self.price_pointer = pointer.into();
+        self
+    }
+
+    /// Sets the decimals of `asset_id`. Assets without configured decimals are assumed to have 9,
+    /// like the base asset.
+    pub fn with_asset_decimals(mut self, asset_id: AssetId, decimals: u8) -> Self {
+        self.asset_decimals.insert(asset_id, decimals);
+        self
+    }
+
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    fn url(&self, asset_id: &AssetId) -> String {
+        self.url_template
+            .replace("{asset_id}", &format!("{asset_id:#x}"))
+    }
+
+    fn parse_price(&self, asset_id: &AssetId, body: &str) -> Result<Option<AssetPrice>> {
+        let body: Value = serde_json::from_str(body)
+            .map_err(|err| error!(Other, "the price of `{asset_id}` is not valid JSON: {err}"))?;
+
+        let price = match body.pointer(&self.price_pointer) {
+            Some(Value::Null) => return Ok(None),
+            Some(Value::Number(number)) => number.to_string(),
+            Some(Value::String(string)) => string.clone(),
+            _ => {
+                return Err(error!(
+                    Other,
+                    "the response for `{asset_id}` has no price at `{}`", self.price_pointer
+                ))
+            }
+        };
+
+        let price = parse_units(
+            truncate_fraction(&price, self.quote_currency.decimals),
+            self.quote_currency.decimals,
+        )?;
+        let asset_decimals = self
+            .asset_decimals
+            .get(asset_id)
+            .copied()
+            .unwrap_or(DEFAULT_ASSET_DECIMALS);
+
+        Ok(Some(AssetPrice::new(price, asset_decimals)))
+    }
+}
+
+/// Drops the fractional digits smaller than the base unit of the quote currency.
+fn truncate_fraction(price: &str, decimals: u8) -> &str {
+    match price.split_once('.') {
+        Some((integer, fraction)) if fraction.len() > usize::from(decimals) => {
+            let len = integer.len() + usize::from(decimals) + usize::from(decimals > 0);
+            &price[..len]
+        }
+        _ => price,
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl PriceOracle for HttpPriceOracle {
+    fn quote_currency(&self) -> &QuoteCurrency {
+        &self.quote_currency
+    }
+
+    async fn price(&self, asset_id: AssetId) -> Result<Option<AssetPrice>> {
+        let fetch_error =
+            |err: reqwest::Error| error!(Other, "could not fetch the price of `{asset_id}`: {err}");
+
+        let response = self
+            .client
+            .get(self.url(&asset_id))
+            .send()
+            .await
+            .map_err(fetch_error)?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let body = response
+            .error_for_status()
+            .map_err(fetch_error)?
+            .text()
+            .await
+            .map_err(fetch_error)?;
+
+        self.parse_price(&asset_id, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::U256;
+
+    use super::*;
+
+    #[test]
+    fn prices_are_read_from_the_response() -> Result<()> {
+        let asset_id = AssetId::from([1; 32]);
+        let oracle = HttpPriceOracle::new(
+            "https://prices.example/assets/{asset_id}",
+            QuoteCurrency::new("USD", 2),
+        )
+        .with_price_pointer("/data/usd")
+        .with_asset_decimals(asset_id, 6);
+
+        assert_eq!(
+            oracle.url(&asset_id),
+            format!("https://prices.example/assets/0x{}", "01".repeat(32))
+        );
+
+        let price = oracle.parse_price(&asset_id, r#"{ "data": { "usd": 3000.256 } }"#)?;
+        assert_eq!(price, Some(AssetPrice::new(300_025, 6)));
+
+        let price = oracle.parse_price(&AssetId::zeroed(), r#"{ "data": { "usd": "12" } }"#)?;
+        assert_eq!(price, Some(AssetPrice::new(U256::from(1200), 9)));
+
+        let price = oracle.parse_price(&asset_id, r#"{ "data": { "usd": null } }"#)?;
+        assert_eq!(price, None);
+
+        let err = oracle
+            .parse_price(&asset_id, r#"{ "price": 1 }"#)
+            .expect_err("should fail");
+        assert!(err.to_string().contains("has no price at `/data/usd`"));
+
+        Ok(())
+    }
+}
@@ -10,7 +10,7 @@ use fuels_core::{
 #[cfg(feature = "std")]
 use crate::accounts_utils::try_provider_error;
 #[cfg(feature = "std")]
-use crate::{provider::Provider, Account, ViewOnlyAccount};
+use crate::{provider::Provider, Account, CoinFilter, ViewOnlyAccount};
 
 #[derive(Debug, Clone)]
 pub struct Predicate {
@@ -112,9 +112,19 @@ impl Account for Predicate {
         &self,
         asset_id: AssetId,
         amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.get_asset_inputs_for_amount_filtered(asset_id, amount, CoinFilter::default())
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
     ) -> Result<Vec<Input>> {
         Ok(self
-            .get_spendable_resources(asset_id, amount)
+            .get_spendable_resources_filtered(asset_id, amount, filter)
             .await?
             .into_iter()
             .map(|resource| {
@@ -1,12 +1,42 @@
-use std::{collections::HashMap, fmt::Debug, net::SocketAddr};
+#[cfg(not(feature = "raw-graphql"))]
+use std::ops::RangeInclusive;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    io,
+    net::SocketAddr,
+    num::{NonZeroU32, NonZeroUsize},
+    sync::Arc,
+    time::Duration,
+};
 
+#[cfg(feature = "raw-graphql")]
+mod asset_info;
+#[cfg(feature = "raw-graphql")]
+mod block_gas;
+mod gas_price_cache;
+mod l1_finality;
+#[cfg(feature = "metrics-prometheus")]
+mod metrics;
+#[cfg(any(test, feature = "mock-provider"))]
+mod mock;
+mod name_resolver;
+mod pending_spends;
+#[cfg(any(all(test, feature = "raw-graphql"), feature = "record-replay"))]
+mod record_replay;
+mod reorg;
+mod resubmit;
 mod retry_util;
 mod retryable_client;
+#[cfg(feature = "subscriptions")]
+mod subscription;
 mod supported_versions;
+#[cfg(feature = "raw-graphql")]
+mod trace;
+mod transport;
 
-#[cfg(feature = "coin-cache")]
-use std::sync::Arc;
-
+#[cfg(feature = "raw-graphql")]
+pub use asset_info::AssetInfo;
 use chrono::{DateTime, Utc};
 use fuel_core_client::client::{
     pagination::{PageDirection, PaginatedResult, PaginationRequest},
@@ -14,41 +44,76 @@ use fuel_core_client::client::{
         balance::Balance,
         contract::ContractBalance,
         gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus as ClientMessageStatus,
     },
 };
 use fuel_core_types::services::executor::{TransactionExecutionResult, TransactionExecutionStatus};
 use fuel_tx::{
-    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UtxoId,
+    AssetId, ConsensusParameters, Receipt, Transaction as FuelTransaction, TxId, UniqueIdentifier,
+    UtxoId,
 };
+#[cfg(not(feature = "raw-graphql"))]
+use fuel_types::ContractId;
 use fuel_types::{Address, BlockHeight, Bytes32, ChainId, Nonce};
-#[cfg(feature = "coin-cache")]
-use fuels_core::types::coin_type_id::CoinTypeId;
+#[cfg(not(feature = "raw-graphql"))]
+use fuels_core::types::transaction::TransactionType;
 use fuels_core::{
-    constants::{DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, DEFAULT_GAS_ESTIMATION_TOLERANCE},
+    codec::LogDecoder,
+    constants::{
+        DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, DEFAULT_GAS_ESTIMATION_TOLERANCE,
+        DEFAULT_PAGINATION_CONCURRENCY,
+    },
+    debug::SourceMapRegistry,
+    traits::{Parameterize, Tokenizable},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
-        block::Block,
+        block::{Block, BlockRef, DaCompressedBlock, FullBlock},
         chain_info::ChainInfo,
         coin::Coin,
         coin_type::CoinType,
-        errors::Result,
-        message::Message,
+        coin_type_id::CoinTypeId,
+        errors::{error, transaction::Reason, Error, Result},
+        message::{Message, MessageStatus},
         message_proof::MessageProof,
         node_info::NodeInfo,
-        transaction::{Transaction, Transactions},
-        transaction_builders::DryRunner,
+        transaction::{CostBreakdown, PredicateEstimationCache, Transaction, Transactions},
+        transaction_builders::{BlockGasUsage, DryRunner},
         transaction_response::TransactionResponse,
         tx_status::TxStatus,
     },
 };
+#[cfg(not(target_arch = "wasm32"))]
+use futures::stream::BoxStream;
+#[cfg(target_arch = "wasm32")]
+use futures::stream::LocalBoxStream;
+use futures::{future, stream, Stream, StreamExt, TryStreamExt};
+use gas_price_cache::GasPriceCache;
+pub use gas_price_cache::{GasPriceCacheConfig, GasPriceKind};
+pub use l1_finality::L1FinalitySource;
+#[cfg(feature = "metrics-prometheus")]
+pub use metrics::ProviderMetrics;
+#[cfg(any(test, feature = "mock-provider"))]
+pub use mock::MockProvider;
+pub use name_resolver::{AddressBook, NameResolver, Recipient};
+pub use pending_spends::PendingSpends;
+#[cfg(any(all(test, feature = "raw-graphql"), feature = "record-replay"))]
+pub use record_replay::{RecordingTransport, ReplayTransport};
+pub use reorg::{ReorgEvent, ReorgMonitor};
+pub use resubmit::ResubmitPolicy;
 pub use retry_util::{Backoff, RetryConfig};
+#[cfg(feature = "raw-graphql")]
+use serde::{de::DeserializeOwned, Serialize};
 use tai64::Tai64;
-#[cfg(feature = "coin-cache")]
 use tokio::sync::Mutex;
+#[cfg(feature = "raw-graphql")]
+pub use trace::{ExecutionTrace, TraceStep};
+#[cfg(feature = "raw-graphql")]
+pub use transport::RawSubscription;
+pub use transport::Transport;
 
 #[cfg(feature = "coin-cache")]
 use crate::coin_cache::CoinsCache;
-use crate::provider::retryable_client::RetryableClient;
+use crate::{provider::retryable_client::RetryableClient, signing_policy::SigningPolicy};
 
 #[derive(Debug)]
 // ANCHOR: transaction_cost
@@ -57,9 +122,194 @@ pub struct TransactionCost {
     pub gas_used: u64,
     pub metered_bytes_size: u64,
     pub total_fee: u64,
+    pub breakdown: CostBreakdown,
 }
 // ANCHOR_END: transaction_cost
 
+/// Toggles for dry runs, see [`Provider::dry_run_with_options`]. By default, resources are
+/// validated, predicates are estimated and the estimated gas price is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimulationOptions {
+    utxo_validation: bool,
+    predicate_estimation: bool,
+    zero_gas_price: bool,
+}
+
+impl Default for SimulationOptions {
+    fn default() -> Self {
+        Self {
+            utxo_validation: true,
+            predicate_estimation: true,
+            zero_gas_price: false,
+        }
+    }
+}
+
+impl SimulationOptions {
+    /// Whether the node checks that the inputs exist, are unspent and are signed or unlocked by
+    /// their predicates.
+    pub fn with_utxo_validation(mut self, enabled: bool) -> Self {
+        self.utxo_validation = enabled;
+        self
+    }
+
+    pub fn utxo_validation(&self) -> bool {
+        self.utxo_validation
+    }
+
+    /// Whether the gas used by the predicates is estimated before the dry run.
+    pub fn with_predicate_estimation(mut self, enabled: bool) -> Self {
+        self.predicate_estimation = enabled;
+        self
+    }
+
+    pub fn predicate_estimation(&self) -> bool {
+        self.predicate_estimation
+    }
+
+    /// Whether the transaction is built as if gas were free, so that its max fee only covers
+    /// the tip. Only affects how call handlers build the transaction, the node dry runs it at
+    /// its own gas price.
+    pub fn with_zero_gas_price(mut self, enabled: bool) -> Self {
+        self.zero_gas_price = enabled;
+        self
+    }
+
+    pub fn zero_gas_price(&self) -> bool {
+        self.zero_gas_price
+    }
+}
+
+/// How [`Provider::send_transaction_and_await_commit_with_options`] waits for the commitment.
+/// By default, it waits as long as it takes and polls the status of the transaction every
+/// second if the node doesn't support subscriptions.
+#[derive(Clone)]
+pub struct AwaitCommitOptions {
+    timeout: Option<Duration>,
+    poll_interval: Duration,
+    on_timeout: CommitTimeoutBehavior,
+    on_status_change: Option<Arc<dyn Fn(&TxStatus) + Send + Sync>>,
+}
+
+impl Default for AwaitCommitOptions {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            poll_interval: Duration::from_secs(1),
+            on_timeout: CommitTimeoutBehavior::Fail,
+            on_status_change: None,
+        }
+    }
+}
+
+impl Debug for AwaitCommitOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AwaitCommitOptions")
+            .field("timeout", &self.timeout)
+            .field("poll_interval", &self.poll_interval)
+            .field("on_timeout", &self.on_timeout)
+            .field("on_status_change", &self.on_status_change.is_some())
+            .finish()
+    }
+}
+
+impl AwaitCommitOptions {
+    /// Bounds the time spent waiting for the commitment, once the transaction is submitted.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// How often the status of the transaction is queried if the node doesn't support
+    /// subscriptions.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    pub fn with_on_timeout(mut self, on_timeout: CommitTimeoutBehavior) -> Self {
+        self.on_timeout = on_timeout;
+        self
+    }
+
+    pub fn on_timeout(&self) -> CommitTimeoutBehavior {
+        self.on_timeout
+    }
+
+    /// Calls `listener` with the status of the transaction whenever it changes while awaiting
+    /// the commitment, starting with its current status, e.g. to show the progress to a user.
+    /// The last call is with the final status, e.g. [`TxStatus::SqueezedOut`] with its reason.
+    ///
+    /// The supported nodes don't report preconfirmations, so a transaction goes from
+    /// [`TxStatus::Submitted`] to its final status.
+    pub fn with_on_status_change(
+        mut self,
+        listener: impl Fn(&TxStatus) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_status_change = Some(Arc::new(listener));
+        self
+    }
+}
+
+/// Reports the status changes of a transaction to the listener of the [`AwaitCommitOptions`].
+struct StatusNotifier<'a> {
+    listener: Option<&'a (dyn Fn(&TxStatus) + Send + Sync)>,
+    last: Option<std::mem::Discriminant<TxStatus>>,
+}
+
+impl<'a> StatusNotifier<'a> {
+    fn new(options: &'a AwaitCommitOptions) -> Self {
+        Self {
+            listener: options.on_status_change.as_deref(),
+            last: None,
+        }
+    }
+
+    fn is_listening(&self) -> bool {
+        self.listener.is_some()
+    }
+
+    fn notify(&mut self, tx_status: &TxStatus) {
+        let Some(listener) = self.listener else {
+            return;
+        };
+
+        let kind = std::mem::discriminant(tx_status);
+        if self.last != Some(kind) {
+            self.last = Some(kind);
+            listener(tx_status);
+        }
+    }
+}
+
+/// What happens when the transaction isn't committed within the timeout of the
+/// [`AwaitCommitOptions`]. The transaction stays in the pool of the node either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommitTimeoutBehavior {
+    /// Fails with a [`Provider`](Error::Provider) error.
+    #[default]
+    Fail,
+    /// Returns [`CommitOutcome::Pending`] with the id of the transaction, to be awaited later,
+    /// e.g. with [`Provider::await_transaction_commit`].
+    ReturnTxId,
+}
+
+/// The result of [`Provider::send_transaction_and_await_commit_with_options`].
+#[derive(Debug, Clone)]
+pub enum CommitOutcome {
+    Committed(TxStatus),
+    /// The transaction was submitted but not committed within the timeout.
+    Pending(TxId),
+}
+
 pub(crate) struct ResourceQueries {
     utxos: Vec<UtxoId>,
     messages: Vec<Nonce>,
@@ -89,6 +339,8 @@ pub struct ResourceFilter {
     pub amount: u64,
     pub excluded_utxos: Vec<UtxoId>,
     pub excluded_message_nonces: Vec<Nonce>,
+    pub min_coin_amount: Option<u64>,
+    pub max_coin_amount: Option<u64>,
 }
 // ANCHOR_END: resource_filter
 
@@ -97,6 +349,15 @@ impl ResourceFilter {
         (&self.from).into()
     }
 
+    fn has_amount_range(&self) -> bool {
+        self.min_coin_amount.is_some() || self.max_coin_amount.is_some()
+    }
+
+    fn is_in_amount_range(&self, amount: u64) -> bool {
+        self.min_coin_amount.map_or(true, |min| amount >= min)
+            && self.max_coin_amount.map_or(true, |max| amount <= max)
+    }
+
     pub(crate) fn resource_queries(&self) -> ResourceQueries {
         ResourceQueries {
             utxos: self.excluded_utxos.clone(),
@@ -107,6 +368,53 @@ impl ResourceFilter {
     }
 }
 
+/// Criteria used to select the messages returned by [`Provider::get_messages_stream`].
+#[derive(Debug, Clone, Default)]
+pub struct MessageFilter {
+    /// Only return messages with this status. Checking the status costs an additional request
+    /// per message.
+    pub status: Option<MessageStatus>,
+    /// If set, only return messages with (`true`) or without (`false`) data.
+    pub with_data: Option<bool>,
+    /// Only return messages with at least this amount.
+    pub min_amount: Option<u64>,
+}
+
+impl MessageFilter {
+    fn matches_content(&self, message: &Message) -> bool {
+        let has_data = !message.data.is_empty();
+
+        self.with_data
+            .map_or(true, |with_data| with_data == has_data)
+            && self.min_amount.map_or(true, |min| message.amount >= min)
+    }
+}
+
+/// Logs yielded by [`Provider::subscribe_logs`].
+#[cfg(not(target_arch = "wasm32"))]
+pub type LogStream<'a, T> = BoxStream<'a, Result<(BlockHeight, TxId, T)>>;
+#[cfg(target_arch = "wasm32")]
+pub type LogStream<'a, T> = LocalBoxStream<'a, Result<(BlockHeight, TxId, T)>>;
+
+/// A change of a contract balance observed by [`Provider::subscribe_contract_balances`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractBalanceChange {
+    pub asset_id: AssetId,
+    pub previous_balance: u64,
+    pub new_balance: u64,
+    /// Height of the latest block when the change was observed.
+    pub block_height: u32,
+    /// Transactions with the contract as input included since the previous observation. One or
+    /// more of them caused the change.
+    pub tx_ids: Vec<TxId>,
+}
+
+impl ContractBalanceChange {
+    pub fn delta(&self) -> i128 {
+        i128::from(self.new_balance) - i128::from(self.previous_balance)
+    }
+}
+
 /// Encapsulates common client operations in the SDK.
 /// Note that you may also use `client`, which is an instance
 /// of `FuelClient`, directly, which provides a broader API.
@@ -114,6 +422,16 @@ impl ResourceFilter {
 pub struct Provider {
     client: RetryableClient,
     consensus_parameters: ConsensusParameters,
+    pagination_concurrency: NonZeroUsize,
+    predicate_estimation_cache: Option<PredicateEstimationCache>,
+    gas_price_cache: Option<Arc<GasPriceCache>>,
+    l1_finality_source: Option<Arc<dyn L1FinalitySource>>,
+    name_resolver: Option<Arc<dyn NameResolver>>,
+    pending_spends: Option<Arc<dyn PendingSpends>>,
+    signing_policies: Vec<SigningPolicy>,
+    source_maps: Option<SourceMapRegistry>,
+    #[cfg(feature = "metrics-prometheus")]
+    metrics: Option<ProviderMetrics>,
     #[cfg(feature = "coin-cache")]
     cache: Arc<Mutex<CoinsCache>>,
 }
@@ -129,6 +447,10 @@ impl Provider {
     }
 
     /// Connects to an existing node at the given address.
+    ///
+    /// The HTTP client is built by the node client with its default settings: the size of the
+    /// connection pool, keep-alive and HTTP/2 can't be configured. Use [`Provider::from_transport`]
+    /// with a transport of your own to tune them.
     pub async fn connect(url: impl AsRef<str>) -> Result<Provider> {
         let client = RetryableClient::connect(&url, Default::default()).await?;
         let consensus_parameters = client.chain_info().await?.consensus_parameters;
@@ -136,28 +458,202 @@ impl Provider {
         Ok(Self {
             client,
             consensus_parameters,
+            pagination_concurrency: DEFAULT_PAGINATION_CONCURRENCY,
+            predicate_estimation_cache: None,
+            gas_price_cache: None,
+            l1_finality_source: None,
+            name_resolver: None,
+            pending_spends: None,
+            signing_policies: vec![],
+            source_maps: None,
+            #[cfg(feature = "metrics-prometheus")]
+            metrics: None,
             #[cfg(feature = "coin-cache")]
             cache: Default::default(),
         })
     }
 
+    /// Creates a provider that sends its requests through `transport` instead of connecting to
+    /// the node at `url`. Unlike [`Provider::connect`], the consensus parameters are not queried
+    /// and must be given.
+    pub fn from_transport(
+        url: impl Into<String>,
+        transport: impl Transport + 'static,
+        consensus_parameters: ConsensusParameters,
+    ) -> Self {
+        Self {
+            client: RetryableClient::from_transport(transport, url, Default::default()),
+            consensus_parameters,
+            pagination_concurrency: DEFAULT_PAGINATION_CONCURRENCY,
+            predicate_estimation_cache: None,
+            gas_price_cache: None,
+            l1_finality_source: None,
+            name_resolver: None,
+            pending_spends: None,
+            signing_policies: vec![],
+            source_maps: None,
+            #[cfg(feature = "metrics-prometheus")]
+            metrics: None,
+            #[cfg(feature = "coin-cache")]
+            cache: Default::default(),
+        }
+    }
+
     pub fn url(&self) -> &str {
         self.client.url()
     }
 
     /// Sends a transaction to the underlying Provider's client.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     pub async fn send_transaction_and_await_commit<T: Transaction>(
         &self,
         tx: T,
     ) -> Result<TxStatus> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
-        let tx_status = self
-            .client
-            .submit_and_await_commit(&tx.clone().into())
-            .await?
-            .into();
+
+        #[cfg(feature = "metrics-prometheus")]
+        let submitted_at = std::time::Instant::now();
+        #[cfg(feature = "metrics-prometheus")]
+        self.observe_submission();
+
+        let tx_id = self.submit(tx.clone()).await?;
+        let client_status = self.client.await_transaction_commit(&tx_id).await?;
+
+        #[cfg(feature = "metrics-prometheus")]
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_commit(submitted_at.elapsed(), &client_status);
+        }
+
+        let tx_status = client_status.into();
 
         #[cfg(feature = "coin-cache")]
+        self.release_coins_if_failed(&tx, &tx_status).await;
+
+        Ok(tx_status)
+    }
+
+    /// Like [`Provider::send_transaction_and_await_commit`], but waits for the commitment as
+    /// configured by `options`, e.g. for at most a given time.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
+    pub async fn send_transaction_and_await_commit_with_options<T: Transaction>(
+        &self,
+        tx: T,
+        options: AwaitCommitOptions,
+    ) -> Result<CommitOutcome> {
+        let tx = self.prepare_transaction_for_sending(tx).await?;
+
+        #[cfg(feature = "metrics-prometheus")]
+        let submitted_at = std::time::Instant::now();
+        #[cfg(feature = "metrics-prometheus")]
+        self.observe_submission();
+
+        let tx_id = self.submit(tx.clone()).await?;
+
+        let outcome = self
+            .await_transaction_commit_with_options(tx_id, options)
+            .await?;
+
+        #[cfg(feature = "metrics-prometheus")]
+        if let (Some(metrics), CommitOutcome::Committed(_)) = (&self.metrics, &outcome) {
+            let elapsed = submitted_at.elapsed();
+            // the fee paid is only part of the status reported by the node
+            if let Ok(client_status) = self.client.transaction_status(&tx_id).await {
+                metrics.observe_commit(elapsed, &client_status);
+            }
+        }
+
+        #[cfg(feature = "coin-cache")]
+        if let CommitOutcome::Committed(tx_status) = &outcome {
+            self.release_coins_if_failed(&tx, tx_status).await;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Like [`Provider::await_transaction_commit`], but waits as configured by `options`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx_id))
+    )]
+    pub async fn await_transaction_commit_with_options(
+        &self,
+        tx_id: TxId,
+        options: AwaitCommitOptions,
+    ) -> Result<CommitOutcome> {
+        let await_commit = self.await_commit_or_poll(&tx_id, &options);
+
+        let Some(timeout) = options.timeout else {
+            return Ok(CommitOutcome::Committed(await_commit.await?));
+        };
+
+        match fuels_core::timeout(timeout, await_commit).await {
+            Some(tx_status) => Ok(CommitOutcome::Committed(tx_status?)),
+            None => match options.on_timeout {
+                CommitTimeoutBehavior::Fail => Err(error!(
+                    Provider,
+                    "transaction `{tx_id}` was not committed within {timeout:?}"
+                )),
+                CommitTimeoutBehavior::ReturnTxId => Ok(CommitOutcome::Pending(tx_id)),
+            },
+        }
+    }
+
+    /// Awaits the commitment through a subscription, falling back to polling the status of the
+    /// transaction if the node doesn't support subscriptions.
+    async fn await_commit_or_poll(
+        &self,
+        tx_id: &TxId,
+        options: &AwaitCommitOptions,
+    ) -> Result<TxStatus> {
+        let mut notifier = StatusNotifier::new(options);
+
+        if notifier.is_listening() {
+            let tx_status = self.tx_status(tx_id).await?;
+            notifier.notify(&tx_status);
+
+            if !matches!(tx_status, TxStatus::Submitted) {
+                return Ok(tx_status);
+            }
+        }
+
+        match self
+            .client
+            .await_transaction_commit(tx_id)
+            .await
+            .map(TxStatus::from)
+        {
+            Ok(TxStatus::Submitted) => {}
+            Ok(tx_status) => {
+                notifier.notify(&tx_status);
+                return Ok(tx_status);
+            }
+            #[cfg(feature = "tracing")]
+            Err(err) => tracing::debug!(%err, "subscription failed, polling the status instead"),
+            #[cfg(not(feature = "tracing"))]
+            Err(_) => {}
+        }
+
+        loop {
+            let tx_status = self.tx_status(tx_id).await?;
+            notifier.notify(&tx_status);
+
+            if !matches!(tx_status, TxStatus::Submitted) {
+                return Ok(tx_status);
+            }
+
+            retry_util::sleep(options.poll_interval).await;
+        }
+    }
+
+    /// Forgets the coins of a transaction that was squeezed out or reverted.
+    #[cfg(feature = "coin-cache")]
+    async fn release_coins_if_failed<T: Transaction>(&self, tx: &T, tx_status: &TxStatus) {
         if matches!(
             tx_status,
             TxStatus::SqueezedOut { .. } | TxStatus::Revert { .. }
@@ -167,10 +663,12 @@ impl Provider {
                 .await
                 .remove_items(tx.used_coins(self.base_asset_id()))
         }
-
-        Ok(tx_status)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     async fn prepare_transaction_for_sending<T: Transaction>(&self, mut tx: T) -> Result<T> {
         tx.precompute(&self.chain_id())?;
 
@@ -178,8 +676,8 @@ impl Provider {
         let latest_block_height = chain_info.latest_block.header.height;
         tx.check(latest_block_height, self.consensus_parameters())?;
 
-        if tx.is_using_predicates() {
-            tx.estimate_predicates(self.consensus_parameters())?;
+        // a cached estimation was only stored after the same predicates passed
+        if tx.is_using_predicates() && !self.estimate_predicates(&mut tx)? {
             tx.clone()
                 .validate_predicates(self.consensus_parameters(), latest_block_height)?;
         }
@@ -189,11 +687,48 @@ impl Provider {
         Ok(tx)
     }
 
+    /// Returns `true` if the estimation was served from the predicate estimation cache.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(cached = self.predicate_estimation_cache.is_some())
+        )
+    )]
+    fn estimate_predicates<T: Transaction>(&self, tx: &mut T) -> Result<bool> {
+        match &self.predicate_estimation_cache {
+            Some(cache) => tx.estimate_predicates_cached(self.consensus_parameters(), cache),
+            None => tx
+                .estimate_predicates(self.consensus_parameters())
+                .map(|_| false),
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     pub async fn send_transaction<T: Transaction>(&self, tx: T) -> Result<TxId> {
         let tx = self.prepare_transaction_for_sending(tx).await?;
+
+        #[cfg(feature = "metrics-prometheus")]
+        self.observe_submission();
+
         self.submit(tx).await
     }
 
+    #[cfg(feature = "metrics-prometheus")]
+    fn observe_submission(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_submission();
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %id))
+    )]
     pub async fn await_transaction_commit<T: Transaction>(&self, id: TxId) -> Result<TxStatus> {
         Ok(self.client.await_transaction_commit(&id).await?.into())
     }
@@ -209,24 +744,194 @@ impl Provider {
         Ok(())
     }
 
-    #[cfg(not(feature = "coin-cache"))]
+    /// Submits `tx` and records the coins it spends in the coin cache, the signing policies and
+    /// the pending spends. Every method sending a transaction submits it through here.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        Ok(self.client.submit(&tx.into()).await?)
-    }
+        let used_coins = tx.used_coins(self.base_asset_id());
+        let id = tx.id(self.chain_id());
+
+        let tx_id = match self.client.submit(&tx.into()).await {
+            Ok(tx_id) => tx_id,
+            Err(err) => {
+                self.release_signed([id]);
+                return Err(err.into());
+            }
+        };
 
-    #[cfg(feature = "coin-cache")]
-    async fn submit<T: Transaction>(&self, tx: T) -> Result<TxId> {
-        let used_utxos = tx.used_coins(self.base_asset_id());
-        let tx_id = self.client.submit(&tx.into()).await?;
-        self.cache.lock().await.insert_multiple(used_utxos);
+        #[cfg(feature = "coin-cache")]
+        self.cache.lock().await.insert_multiple(used_coins.clone());
+        self.record_signed_submission(&tx_id);
+        self.record_pending_spends(tx_id, used_coins).await;
 
         Ok(tx_id)
     }
 
+    /// Records the spending of the transaction towards the daily limits of the signing policies
+    /// it was signed under.
+    fn record_signed_submission(&self, tx_id: &TxId) {
+        for policy in &self.signing_policies {
+            policy.record_submission(tx_id);
+        }
+    }
+
+    fn release_signed_dry_runs(&self, transactions: &Transactions) {
+        let chain_id = self.chain_id();
+        self.release_signed(transactions.as_slice().iter().map(|tx| tx.id(&chain_id)));
+    }
+
+    /// Releases the amounts reserved by the signing policies for transactions that weren't
+    /// submitted, e.g. because they were only dry run.
+    fn release_signed(&self, tx_ids: impl IntoIterator<Item = TxId>) {
+        if self.signing_policies.is_empty() {
+            return;
+        }
+
+        for tx_id in tx_ids {
+            for policy in &self.signing_policies {
+                policy.release(&tx_id);
+            }
+        }
+    }
+
+    async fn record_pending_spends(
+        &self,
+        tx_id: TxId,
+        used_coins: HashMap<(Bech32Address, AssetId), Vec<CoinTypeId>>,
+    ) {
+        if let Some(pending_spends) = &self.pending_spends {
+            let used_coins = used_coins.into_iter().collect::<Vec<_>>();
+            pending_spends.record_submission(tx_id, &used_coins).await;
+        }
+    }
+
     pub async fn tx_status(&self, tx_id: &TxId) -> Result<TxStatus> {
         Ok(self.client.transaction_status(tx_id).await?.into())
     }
 
+    /// Waits until the transaction is committed and `confirmations` blocks were produced on top
+    /// of the block including it. If the block is replaced in the meantime, waits for the
+    /// transaction to be included again before counting the confirmations anew. Fails if the
+    /// transaction is squeezed out.
+    pub async fn await_confirmations(
+        &self,
+        tx_id: &TxId,
+        confirmations: u32,
+        poll_interval: Duration,
+    ) -> Result<TxStatus> {
+        let mut status: TxStatus = self.client.await_transaction_commit(tx_id).await?.into();
+
+        loop {
+            if let TxStatus::SqueezedOut { reason } = status {
+                return Err(Error::Transaction(Reason::SqueezedOut(reason)));
+            }
+
+            if let Some((height, id)) = self.including_block(tx_id).await? {
+                let target_height = height.saturating_add(confirmations);
+
+                while self.latest_block_height().await? < target_height {
+                    retry_util::sleep(poll_interval).await;
+                }
+
+                let still_included = self
+                    .block_by_height(height.into())
+                    .await?
+                    .is_some_and(|block| block.id == id);
+
+                if still_included {
+                    return Ok(status);
+                }
+            }
+
+            retry_util::sleep(poll_interval).await;
+            status = self.tx_status(tx_id).await?;
+        }
+    }
+
+    /// Waits until the transaction is committed and the commitment of the block including it is
+    /// final on the L1, as reported by the source set with
+    /// [`Provider::with_l1_finality_source`]. Fails if that takes longer than `timeout`.
+    pub async fn await_l1_finality(&self, tx_id: &TxId, timeout: Duration) -> Result<TxStatus> {
+        let source = self.l1_finality_source.as_ref().ok_or_else(|| {
+            error!(
+                Other,
+                "no L1 finality source set, see `Provider::with_l1_finality_source`"
+            )
+        })?;
+
+        let await_finality = async {
+            let status: TxStatus = self.client.await_transaction_commit(tx_id).await?.into();
+            if let TxStatus::SqueezedOut { reason } = status {
+                return Err(Error::Transaction(Reason::SqueezedOut(reason)));
+            }
+
+            loop {
+                if let Some((height, _)) = self.including_block(tx_id).await? {
+                    let finalized_height = source.latest_finalized_height().await?;
+
+                    if finalized_height.is_some_and(|finalized| finalized >= height) {
+                        return Ok(status);
+                    }
+                }
+
+                retry_util::sleep(source.poll_interval()).await;
+            }
+        };
+
+        fuels_core::timeout(timeout, await_finality)
+            .await
+            .unwrap_or_else(|| {
+                Err(error!(
+                    Provider,
+                    "transaction `{tx_id}` was not finalized on the L1 within {timeout:?}"
+                ))
+            })
+    }
+
+    /// Returns the height and id of the canonical block including the transaction.
+    async fn including_block(&self, tx_id: &TxId) -> Result<Option<(u32, Bytes32)>> {
+        let Some(height) = self
+            .get_transaction_by_id(tx_id)
+            .await?
+            .and_then(|response| response.block_height)
+        else {
+            return Ok(None);
+        };
+
+        let block = self.block_by_height(height).await?;
+
+        Ok(block
+            .filter(|block| block.transactions.contains(tx_id))
+            .map(|block| (*height, block.id)))
+    }
+
+    /// Polls the node every `poll_interval` and yields an event whenever one of the last `depth`
+    /// observed blocks is replaced.
+    pub fn watch_reorgs(
+        &self,
+        depth: NonZeroU32,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<ReorgEvent>> + '_ {
+        let initial_state = (ReorgMonitor::new(depth), true);
+
+        stream::try_unfold(
+            initial_state,
+            move |(mut monitor, is_first_poll)| async move {
+                if !is_first_poll {
+                    retry_util::sleep(poll_interval).await;
+                }
+
+                let reorg = monitor.poll(self).await?;
+
+                Ok::<_, Error>(Some((reorg, (monitor, false))))
+            },
+        )
+        .try_filter_map(|reorg| future::ready(Ok(reorg)))
+    }
+
     pub async fn chain_info(&self) -> Result<ChainInfo> {
         Ok(self.client.chain_info().await?.into())
     }
@@ -247,15 +952,43 @@ impl Provider {
         Ok(self.client.node_info().await?.into())
     }
 
+    /// Returns the gas price of the latest block. If a cache is set with
+    /// [`Provider::with_gas_price_cache`], the gas price is only queried once the refresh
+    /// interval elapsed.
     pub async fn latest_gas_price(&self) -> Result<LatestGasPrice> {
-        Ok(self.client.latest_gas_price().await?)
+        match &self.gas_price_cache {
+            Some(cache) => {
+                cache
+                    .latest(|| async { Ok(self.client.latest_gas_price().await?) })
+                    .await
+            }
+            None => Ok(self.client.latest_gas_price().await?),
+        }
     }
 
+    /// Returns the gas price estimated for inclusion within `block_horizon` blocks, as used by
+    /// the transaction builders. If a cache is set with [`Provider::with_gas_price_cache`], the
+    /// estimate of each block horizon is only queried once the refresh interval elapsed.
     pub async fn estimate_gas_price(&self, block_horizon: u32) -> Result<EstimateGasPrice> {
-        Ok(self.client.estimate_gas_price(block_horizon).await?)
+        match &self.gas_price_cache {
+            Some(cache) => {
+                cache
+                    .estimate(block_horizon, || async {
+                        Ok(self.client.estimate_gas_price(block_horizon).await?)
+                    })
+                    .await
+            }
+            None => Ok(self.client.estimate_gas_price(block_horizon).await?),
+        }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     pub async fn dry_run(&self, tx: impl Transaction) -> Result<TxStatus> {
+        self.release_signed([tx.id(self.chain_id())]);
+
         let [(_, tx_status)] = self
             .client
             .dry_run(Transactions::new().insert(tx).as_slice())
@@ -273,6 +1006,8 @@ impl Provider {
         &self,
         transactions: Transactions,
     ) -> Result<Vec<(TxId, TxStatus)>> {
+        self.release_signed_dry_runs(&transactions);
+
         Ok(self
             .client
             .dry_run(transactions.as_slice())
@@ -302,7 +1037,36 @@ impl Provider {
         )
     }
 
+    /// Dry runs `tx` with the given [`SimulationOptions`].
+    pub async fn dry_run_with_options<T: Transaction>(
+        &self,
+        mut tx: T,
+        options: SimulationOptions,
+    ) -> Result<TxStatus> {
+        if options.predicate_estimation() && tx.is_using_predicates() {
+            self.estimate_predicates(&mut tx)?;
+        }
+
+        if options.utxo_validation() {
+            self.dry_run(tx).await
+        } else {
+            self.dry_run_no_validation(tx).await
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_id = %tx.id(self.chain_id())))
+    )]
     pub async fn dry_run_no_validation(&self, tx: impl Transaction) -> Result<TxStatus> {
+        self.release_signed([tx.id(self.chain_id())]);
+
+        self.dry_run_unvalidated(tx).await
+    }
+
+    /// Like [`Provider::dry_run_no_validation`], but keeps the amounts reserved by the signing
+    /// policies, e.g. while validating a transaction about to be submitted.
+    async fn dry_run_unvalidated(&self, tx: impl Transaction) -> Result<TxStatus> {
         let [(_, tx_status)] = self
             .client
             .dry_run_opt(Transactions::new().insert(tx).as_slice(), Some(false))
@@ -320,6 +1084,8 @@ impl Provider {
         &self,
         transactions: Transactions,
     ) -> Result<Vec<(TxId, TxStatus)>> {
+        self.release_signed_dry_runs(&transactions);
+
         Ok(self
             .client
             .dry_run_opt(transactions.as_slice(), Some(false))
@@ -359,7 +1125,50 @@ impl Provider {
         Ok(coins)
     }
 
-    async fn request_coins_to_spend(&self, filter: ResourceFilter) -> Result<Vec<CoinType>> {
+    /// Gets all unspent coins owned by address `from` for each of the given `asset_ids`.
+    /// The coins of different assets are fetched concurrently, with at most
+    /// `pagination_concurrency` assets being paginated at the same time. The returned coins are
+    /// ordered the same way as `asset_ids`.
+    pub async fn get_coins_for_assets(
+        &self,
+        from: &Bech32Address,
+        asset_ids: impl IntoIterator<Item = AssetId>,
+    ) -> Result<Vec<Coin>> {
+        let coins_per_asset: Vec<Vec<Coin>> = stream::iter(asset_ids)
+            .map(|asset_id| self.get_coins(from, asset_id))
+            .buffered(self.pagination_concurrency.get())
+            .try_collect()
+            .await?;
+
+        Ok(coins_per_asset.into_iter().flatten().collect())
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(owner = %filter.from, asset_id = ?filter.asset_id, amount = filter.amount)
+        )
+    )]
+    async fn request_coins_to_spend(&self, mut filter: ResourceFilter) -> Result<Vec<CoinType>> {
+        if let Some(pending_spends) = &self.pending_spends {
+            let asset_id = filter.asset_id.unwrap_or(*self.base_asset_id());
+            for id in pending_spends
+                .pending_resources(&filter.from, asset_id)
+                .await?
+            {
+                match id {
+                    CoinTypeId::UtxoId(utxo_id) => filter.excluded_utxos.push(utxo_id),
+                    CoinTypeId::Nonce(nonce) => filter.excluded_message_nonces.push(nonce),
+                }
+            }
+        }
+
+        if filter.has_amount_range() {
+            self.exclude_out_of_range_resources(&mut filter).await?;
+        }
+
         let queries = filter.resource_queries();
 
         let res = self
@@ -378,6 +1187,38 @@ impl Provider {
         Ok(res)
     }
 
+    /// Adds the resources whose amount is outside of the range of `filter` to its exclusions.
+    /// The node doesn't filter by amount, so all coins of the owner are fetched to find them.
+    async fn exclude_out_of_range_resources(&self, filter: &mut ResourceFilter) -> Result<()> {
+        let asset_id = filter.asset_id.unwrap_or(*self.base_asset_id());
+
+        let excluded_utxos = self
+            .get_coins(&filter.from, asset_id)
+            .await?
+            .into_iter()
+            .filter(|coin| !filter.is_in_amount_range(coin.amount))
+            .map(|coin| coin.utxo_id)
+            .collect::<Vec<_>>();
+
+        let excluded_message_nonces = if asset_id == *self.base_asset_id() {
+            self.get_messages(&filter.from)
+                .await?
+                .into_iter()
+                .filter(|message| !filter.is_in_amount_range(message.amount))
+                .map(|message| message.nonce)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        filter.excluded_utxos.extend(excluded_utxos);
+        filter
+            .excluded_message_nonces
+            .extend(excluded_message_nonces);
+
+        Ok(())
+    }
+
     /// Get some spendable coins of asset `asset_id` for address `from` that add up at least to
     /// amount `amount`. The returned coins (UTXOs) are actual coins that can be spent. The number
     /// of coins (UXTOs) is optimized to prevent dust accumulation.
@@ -516,6 +1357,135 @@ impl Provider {
         Ok(balances)
     }
 
+    /// Polls the node every `poll_interval` and yields the changes of the balances of
+    /// `contract_id`. Only the balances of `asset_ids` are tracked, or all of the contract's
+    /// balances if `asset_ids` is empty.
+    ///
+    /// The balances are recorded before the stream is returned, so every change made afterwards
+    /// is yielded.
+    pub async fn subscribe_contract_balances<'a>(
+        &'a self,
+        contract_id: &'a Bech32ContractId,
+        asset_ids: Vec<AssetId>,
+        poll_interval: Duration,
+    ) -> Result<impl Stream<Item = Result<ContractBalanceChange>> + 'a> {
+        let asset_ids = Arc::new(asset_ids);
+        let initial_state = (
+            self.latest_block_height().await?,
+            self.tracked_contract_balances(contract_id, &asset_ids)
+                .await?,
+        );
+
+        Ok(stream::try_unfold(initial_state, move |state| {
+            let asset_ids = asset_ids.clone();
+            async move {
+                retry_util::sleep(poll_interval).await;
+                let (last_height, previous_balances) = state;
+
+                let height = self.latest_block_height().await?;
+                if height == last_height {
+                    return Ok(Some((
+                        stream::iter(vec![]),
+                        (last_height, previous_balances),
+                    )));
+                }
+
+                let balances = self
+                    .tracked_contract_balances(contract_id, &asset_ids)
+                    .await?;
+
+                let changed_balances = balances
+                    .keys()
+                    .chain(previous_balances.keys())
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .filter_map(|asset_id| {
+                        let previous_balance = previous_balances.get(asset_id).copied();
+                        let new_balance = balances.get(asset_id).copied();
+
+                        (previous_balance != new_balance).then_some((
+                            *asset_id,
+                            previous_balance.unwrap_or_default(),
+                            new_balance.unwrap_or_default(),
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+
+                let changes = if changed_balances.is_empty() {
+                    vec![]
+                } else {
+                    let tx_ids = self
+                        .txs_with_contract_input(contract_id.into(), last_height + 1..=height)
+                        .await?;
+
+                    changed_balances
+                        .into_iter()
+                        .map(|(asset_id, previous_balance, new_balance)| {
+                            Ok(ContractBalanceChange {
+                                asset_id,
+                                previous_balance,
+                                new_balance,
+                                block_height: height,
+                                tx_ids: tx_ids.clone(),
+                            })
+                        })
+                        .collect()
+                };
+
+                Ok::<_, Error>(Some((stream::iter(changes), (height, balances))))
+            }
+        })
+        .try_flatten())
+    }
+
+    async fn tracked_contract_balances(
+        &self,
+        contract_id: &Bech32ContractId,
+        asset_ids: &[AssetId],
+    ) -> Result<HashMap<AssetId, u64>> {
+        let mut balances = self.get_contract_balances(contract_id).await?;
+
+        if !asset_ids.is_empty() {
+            balances.retain(|asset_id, _| asset_ids.contains(asset_id));
+        }
+
+        Ok(balances)
+    }
+
+    /// Without the `raw-graphql` feature, the transactions are fetched one by one. The
+    /// transactions of a block are fetched concurrently, see [`Provider::get_full_block`].
+    #[cfg(not(feature = "raw-graphql"))]
+    async fn txs_with_contract_input(
+        &self,
+        contract_id: ContractId,
+        heights: RangeInclusive<u32>,
+    ) -> Result<Vec<TxId>> {
+        let mut tx_ids = vec![];
+
+        for height in heights {
+            let Some(block) = self.get_full_block(BlockHeight::from(height)).await? else {
+                continue;
+            };
+
+            for (tx_id, response) in block.transactions {
+                let inputs = match &response.transaction {
+                    TransactionType::Script(tx) => tx.inputs(),
+                    TransactionType::Create(tx) => tx.inputs(),
+                    _ => continue,
+                };
+
+                if inputs
+                    .iter()
+                    .any(|input| input.contract_id() == Some(&contract_id))
+                {
+                    tx_ids.push(tx_id);
+                }
+            }
+        }
+
+        Ok(tx_ids)
+    }
+
     pub async fn get_transaction_by_id(&self, tx_id: &TxId) -> Result<Option<TransactionResponse>> {
         Ok(self.client.transaction(tx_id).await?.map(Into::into))
     }
@@ -583,6 +1553,228 @@ impl Provider {
         Ok(self.client.block_by_height(height).await?.map(Into::into))
     }
 
+    /// Gets the block at `height` in the compressed form the node posts to the DA layer. Returns
+    /// `None` if the node has no compressed block at that height, e.g. because DA compression is
+    /// disabled or the block isn't produced yet.
+    ///
+    /// Only nodes compressing their blocks serve the query, others reject it. Querying the node
+    /// requires the `raw-graphql` feature, without it only custom transports serve the blocks.
+    pub async fn da_compressed_block(
+        &self,
+        height: BlockHeight,
+    ) -> Result<Option<DaCompressedBlock>> {
+        Ok(self
+            .client
+            .da_compressed_block(height)
+            .await?
+            .map(|bytes| DaCompressedBlock {
+                height: *height,
+                bytes,
+            }))
+    }
+
+    /// Sends a custom GraphQL `query` to the node and deserializes the `data` of the response
+    /// into `T`. Useful for node fields the SDK doesn't wrap yet. The request goes through the
+    /// provider's transport and is retried according to its [`RetryConfig`].
+    ///
+    /// `variables` must serialize to a JSON object, or to `null` if the query has none.
+    #[cfg(feature = "raw-graphql")]
+    pub async fn raw_query<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: impl Serialize,
+    ) -> Result<T> {
+        let variables = serde_json::to_value(variables)
+            .map_err(|err| error!(Other, "could not serialize the query variables: {err}"))?;
+        let data = self.client.raw_query(query, &variables).await?;
+
+        serde_json::from_value(data).map_err(|err| {
+            error!(
+                Provider,
+                "could not deserialize the response of the query: {err}"
+            )
+        })
+    }
+
+    /// Sends a custom GraphQL `subscription` to the node and yields the `data` of each response,
+    /// deserialized into `T`. Useful for node subscriptions the SDK doesn't wrap yet.
+    ///
+    /// Starting the subscription is retried according to the provider's [`RetryConfig`]. If the
+    /// connection is lost after a response was received, the subscription is started again, so
+    /// the node may send again responses that were already yielded. Errors reported by the node
+    /// end the stream.
+    ///
+    /// Subscribing to a node requires the `subscriptions` feature, without it only custom
+    /// transports serve subscriptions.
+    #[cfg(feature = "raw-graphql")]
+    pub fn raw_subscription<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        subscription: &'a str,
+        variables: impl Serialize,
+    ) -> impl Stream<Item = Result<T>> + 'a {
+        let variables = serde_json::to_value(variables).map_err(|err| {
+            error!(
+                Other,
+                "could not serialize the subscription variables: {err}"
+            )
+        });
+
+        stream::once(future::ready(variables))
+            .map_ok(move |variables| self.raw_subscription_responses(subscription, variables))
+            .try_flatten()
+            .and_then(|data| async move {
+                serde_json::from_value(data).map_err(|err| {
+                    error!(
+                        Provider,
+                        "could not deserialize the response of the subscription: {err}"
+                    )
+                })
+            })
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    fn raw_subscription_responses<'a>(
+        &'a self,
+        subscription: &'a str,
+        variables: serde_json::Value,
+    ) -> impl Stream<Item = Result<serde_json::Value>> + 'a {
+        // the active subscription, and whether it yielded a response yet
+        let initial_state: Option<(RawSubscription, bool)> = None;
+
+        stream::try_unfold(initial_state, move |state| {
+            let variables = variables.clone();
+            let subscribe = move |variables: serde_json::Value| async move {
+                self.client
+                    .raw_subscription(subscription, &variables)
+                    .await
+                    .map_err(Error::from)
+            };
+
+            async move {
+                let (mut responses, mut received) = match state {
+                    Some(state) => state,
+                    None => (subscribe(variables.clone()).await?, false),
+                };
+
+                loop {
+                    match responses.next().await {
+                        Some(Ok(data)) => return Ok(Some((data, Some((responses, true))))),
+                        // the connection was lost, subscribe again
+                        Some(Err(err)) if err.kind() != io::ErrorKind::InvalidData && received => {
+                            responses = subscribe(variables.clone()).await?;
+                            received = false;
+                        }
+                        Some(Err(err)) => {
+                            return Err(error!(Provider, "subscription failed: {err}"));
+                        }
+                        None => return Ok(None),
+                    }
+                }
+            }
+        })
+    }
+
+    /// Gets a block together with its transactions, their statuses and receipts. The node has no
+    /// query returning all of them at once, so the transactions are fetched concurrently, with at
+    /// most `pagination_concurrency` requests in flight at the same time.
+    pub async fn get_full_block(&self, block: impl Into<BlockRef>) -> Result<Option<FullBlock>> {
+        let block = match block.into() {
+            BlockRef::Height(height) => self.block_by_height(height).await?,
+            BlockRef::Id(id) => self.block(&id).await?,
+        };
+
+        let Some(Block {
+            id,
+            header,
+            transactions: tx_ids,
+        }) = block
+        else {
+            return Ok(None);
+        };
+
+        let transactions = stream::iter(tx_ids)
+            .map(|tx_id| async move {
+                let response = self.get_transaction_by_id(&tx_id).await?.ok_or_else(|| {
+                    error!(Provider, "transaction `{tx_id}` of block `{id}` not found")
+                })?;
+
+                Ok::<_, Error>((tx_id, response))
+            })
+            .buffered(self.pagination_concurrency.get())
+            .try_collect()
+            .await?;
+
+        Ok(Some(FullBlock {
+            id,
+            header,
+            transactions,
+        }))
+    }
+
+    /// Yields the logs of type `T` that `log_decoder` can decode, with the height of their block
+    /// and the id of their transaction. Blocks are read starting from `from_height`, or from the
+    /// next block if `None`, so past logs are backfilled before the node is polled every
+    /// `poll_interval` for new blocks. Logs of reverted transactions are skipped.
+    pub fn subscribe_logs<'a, T>(
+        &'a self,
+        log_decoder: &'a LogDecoder,
+        from_height: Option<u32>,
+        poll_interval: Duration,
+    ) -> LogStream<'a, T>
+    where
+        T: Tokenizable + Parameterize + Send + 'static,
+    {
+        let logs = stream::try_unfold(from_height, move |next_height| async move {
+            let latest_height = self.latest_block_height().await?;
+            let height = next_height.unwrap_or(latest_height + 1);
+
+            if height > latest_height {
+                retry_util::sleep(poll_interval).await;
+                return Ok(Some((stream::iter(vec![]), Some(height))));
+            }
+
+            let logs = self
+                .logs_in_block(log_decoder, height)
+                .await?
+                .into_iter()
+                .map(Ok)
+                .collect::<Vec<_>>();
+
+            Ok::<_, Error>(Some((stream::iter(logs), Some(height + 1))))
+        })
+        .try_flatten();
+
+        Box::pin(logs)
+    }
+
+    async fn logs_in_block<T>(
+        &self,
+        log_decoder: &LogDecoder,
+        height: u32,
+    ) -> Result<Vec<(BlockHeight, TxId, T)>>
+    where
+        T: Tokenizable + Parameterize + 'static,
+    {
+        let block = self
+            .get_full_block(height)
+            .await?
+            .ok_or_else(|| error!(Provider, "block at height `{height}` not found"))?;
+
+        let mut logs = vec![];
+        for (tx_id, response) in block.transactions {
+            if let TxStatus::Success { receipts } = response.status {
+                logs.extend(
+                    log_decoder
+                        .decode_logs_with_type::<T>(&receipts)?
+                        .into_iter()
+                        .map(|log| (height.into(), tx_id, log)),
+                );
+            }
+        }
+
+        Ok(logs)
+    }
+
     // - Get block(s)
     pub async fn get_blocks(
         &self,
@@ -598,6 +1790,15 @@ impl Provider {
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(tx_id = %tx.id(self.chain_id())),
+            ret
+        )
+    )]
     pub async fn estimate_transaction_cost<T: Transaction>(
         &self,
         tx: T,
@@ -605,10 +1806,28 @@ impl Provider {
         block_horizon: Option<u32>,
     ) -> Result<TransactionCost> {
         let block_horizon = block_horizon.unwrap_or(DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON);
-        let tolerance = tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE);
 
         let EstimateGasPrice { gas_price, .. } = self.estimate_gas_price(block_horizon).await?;
 
+        self.estimate_transaction_cost_at_gas_price(tx, tolerance, gas_price)
+            .await
+    }
+
+    /// Like [`Provider::estimate_transaction_cost`], but computes the fee at `gas_price` instead
+    /// of the gas price estimated by the node, e.g. for reproducible estimates or to find out
+    /// what the transaction would cost at a given price.
+    ///
+    /// Only the fee depends on the gas price. The dry run measuring the gas used is the same as
+    /// for [`Provider::estimate_transaction_cost`], since the node doesn't take a gas price for
+    /// dry runs.
+    pub async fn estimate_transaction_cost_at_gas_price<T: Transaction>(
+        &self,
+        tx: T,
+        tolerance: Option<f64>,
+        gas_price: u64,
+    ) -> Result<TransactionCost> {
+        let tolerance = tolerance.unwrap_or(DEFAULT_GAS_ESTIMATION_TOLERANCE);
+
         let gas_used = self
             .get_gas_used_with_tolerance(tx.clone(), tolerance)
             .await?;
@@ -623,6 +1842,7 @@ impl Provider {
             gas_used,
             metered_bytes_size: tx.metered_bytes_size() as u64,
             total_fee: transaction_fee.max_fee(),
+            breakdown: tx.cost_breakdown(&self.consensus_parameters),
         })
     }
 
@@ -632,7 +1852,7 @@ impl Provider {
         tx: T,
         tolerance: f64,
     ) -> Result<u64> {
-        let receipts = self.dry_run_no_validation(tx).await?.take_receipts();
+        let receipts = self.dry_run_unvalidated(tx).await?.take_receipts();
         let gas_used = self.get_gas_used(&receipts);
 
         Ok((gas_used as f64 * (1.0 + tolerance)) as u64)
@@ -650,21 +1870,180 @@ impl Provider {
             .unwrap_or(0)
     }
 
+    /// Gets all unspent messages whose recipient is `from`.
     pub async fn get_messages(&self, from: &Bech32Address) -> Result<Vec<Message>> {
-        let pagination = PaginationRequest {
-            cursor: None,
-            results: 100,
-            direction: PageDirection::Forward,
+        let mut messages: Vec<Message> = vec![];
+
+        let mut cursor = None;
+
+        loop {
+            let res = self
+                .client
+                .messages(
+                    Some(&from.into()),
+                    PaginationRequest {
+                        cursor: cursor.clone(),
+                        results: 100,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await?;
+
+            if res.results.is_empty() {
+                break;
+            }
+            messages.extend(res.results.into_iter().map(Into::into));
+            cursor = res.cursor;
+        }
+
+        Ok(messages)
+    }
+
+    /// Gets all unspent messages for each of the given recipients. The messages of different
+    /// recipients are fetched concurrently, with at most `pagination_concurrency` recipients
+    /// being paginated at the same time. The returned messages are ordered the same way as
+    /// `recipients`.
+    pub async fn get_messages_for_recipients(
+        &self,
+        recipients: &[Bech32Address],
+    ) -> Result<Vec<Message>> {
+        let messages_per_recipient: Vec<Vec<Message>> = stream::iter(recipients)
+            .map(|recipient| self.get_messages(recipient))
+            .buffered(self.pagination_concurrency.get())
+            .try_collect()
+            .await?;
+
+        Ok(messages_per_recipient.into_iter().flatten().collect())
+    }
+
+    /// Lazily paginates through the messages whose recipient is `owner`, yielding only the ones
+    /// matching `filter`.
+    pub fn get_messages_stream<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+        filter: MessageFilter,
+    ) -> impl Stream<Item = Result<Message>> + 'a {
+        let owner: Address = owner.into();
+        let filter = Arc::new(filter);
+
+        let pages = stream::try_unfold(Some(None), move |cursor| async move {
+            let Some(cursor) = cursor else {
+                return Ok(None);
+            };
+
+            let page = self
+                .client
+                .messages(
+                    Some(&owner),
+                    PaginationRequest {
+                        cursor,
+                        results: 100,
+                        direction: PageDirection::Forward,
+                    },
+                )
+                .await?;
+
+            if page.results.is_empty() {
+                return Ok::<_, Error>(None);
+            }
+
+            let next_cursor = page.has_next_page.then_some(page.cursor);
+
+            Ok(Some((page.results, next_cursor)))
+        });
+
+        pages
+            .map_ok(|messages| stream::iter(messages.into_iter().map(Ok)))
+            .try_flatten()
+            .try_filter_map(move |message| {
+                let filter = filter.clone();
+                async move {
+                    let mut message = Message::from(message);
+                    if !filter.matches_content(&message) {
+                        return Ok(None);
+                    }
+
+                    if let Some(expected_status) = &filter.status {
+                        match self.message_status(&message.nonce).await? {
+                            Some(status) if status == *expected_status => message.status = status,
+                            _ => return Ok(None),
+                        }
+                    }
+
+                    Ok(Some(message))
+                }
+            })
+    }
+
+    /// Polls the node every `poll_interval` and yields the messages whose recipient is `owner`
+    /// as they arrive. Messages already present when the stream is first polled are not yielded.
+    pub fn subscribe_messages<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Message>> + 'a {
+        self.poll_messages(owner, None, poll_interval)
+    }
+
+    /// Like [`Provider::subscribe_messages`], but first yields the unspent messages already
+    /// present with a DA height of at least `da_height`, e.g. to resume processing deposits
+    /// after a restart.
+    pub fn subscribe_messages_since<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+        da_height: u64,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Message>> + 'a {
+        self.poll_messages(owner, Some(da_height), poll_interval)
+    }
+
+    fn poll_messages<'a>(
+        &'a self,
+        owner: &'a Bech32Address,
+        since_da_height: Option<u64>,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<Message>> + 'a {
+        let seen: Option<HashSet<Nonce>> = None;
+
+        stream::try_unfold(seen, move |seen| async move {
+            if seen.is_some() {
+                retry_util::sleep(poll_interval).await;
+            }
+            let messages = self.get_messages(owner).await?;
+
+            let mut seen = seen.unwrap_or_else(|| {
+                messages
+                    .iter()
+                    .filter(|message| {
+                        since_da_height.map_or(true, |da_height| message.da_height < da_height)
+                    })
+                    .map(|message| message.nonce)
+                    .collect()
+            });
+
+            // Spent messages are no longer returned and never come back, so they can be forgotten.
+            let present: HashSet<Nonce> = messages.iter().map(|message| message.nonce).collect();
+            seen.retain(|nonce| present.contains(nonce));
+
+            let new_messages = messages
+                .into_iter()
+                .filter(|message| seen.insert(message.nonce))
+                .map(Ok)
+                .collect::<Vec<_>>();
+
+            Ok::<_, Error>(Some((stream::iter(new_messages), Some(seen))))
+        })
+        .try_flatten()
+    }
+
+    async fn message_status(&self, nonce: &Nonce) -> Result<Option<MessageStatus>> {
+        let status = match self.client.message_status(nonce).await? {
+            ClientMessageStatus::Unspent => Some(MessageStatus::Unspent),
+            ClientMessageStatus::Spent => Some(MessageStatus::Spent),
+            ClientMessageStatus::NotFound => None,
         };
 
-        Ok(self
-            .client
-            .messages(Some(&from.into()), pagination)
-            .await?
-            .results
-            .into_iter()
-            .map(Into::into)
-            .collect())
+        Ok(status)
     }
 
     pub async fn get_message_proof(
@@ -688,11 +2067,198 @@ impl Provider {
         Ok(proof)
     }
 
+    /// Waits until the transaction is committed and a block is produced on top of the block
+    /// including it, then returns the proof of the message `nonce` against that commit block.
+    /// The node is polled every `poll_interval`. Fails if the transaction failed, or if the proof
+    /// isn't available within `timeout`.
+    pub async fn await_message_proof(
+        &self,
+        tx_id: &TxId,
+        nonce: &Nonce,
+        poll_interval: Duration,
+        timeout: Duration,
+    ) -> Result<MessageProof> {
+        let await_proof = async {
+            let status: TxStatus = self.client.await_transaction_commit(tx_id).await?.into();
+            status.check_with_source_maps(None, self.source_maps())?;
+
+            loop {
+                if let Some((height, _)) = self.including_block(tx_id).await? {
+                    let commit_height = self.latest_block_height().await?;
+
+                    if commit_height > height {
+                        if let Some(proof) = self
+                            .get_message_proof(tx_id, nonce, None, Some(commit_height))
+                            .await?
+                        {
+                            return Ok(proof);
+                        }
+                    }
+                }
+
+                retry_util::sleep(poll_interval).await;
+            }
+        };
+
+        fuels_core::timeout(timeout, await_proof)
+            .await
+            .unwrap_or_else(|| {
+                Err(error!(
+                    Provider,
+                    "the proof of message `{nonce}` of transaction `{tx_id}` was not available within {timeout:?}"
+                ))
+            })
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.client.set_retry_config(retry_config);
 
         self
     }
+
+    /// Sets a timeout applied to every request sent to the node. A request that times out is
+    /// treated as a failed attempt and is retried according to the provider's [`RetryConfig`].
+    ///
+    /// Note that this includes requests waiting for a transaction to be committed.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.client.set_request_timeout(Some(timeout));
+
+        self
+    }
+
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.client.request_timeout()
+    }
+
+    /// Caches the gas prices returned by [`Provider::latest_gas_price`] and
+    /// [`Provider::estimate_gas_price`] as configured by `config`, e.g. to build many
+    /// transactions without querying the node for each of them.
+    /// Clones of the provider share the cache.
+    pub fn with_gas_price_cache(mut self, config: GasPriceCacheConfig) -> Self {
+        self.gas_price_cache = Some(Arc::new(GasPriceCache::new(config)));
+
+        self
+    }
+
+    /// Sets the source used by [`Provider::await_l1_finality`] to learn which blocks are final
+    /// on the L1.
+    pub fn with_l1_finality_source(mut self, source: impl L1FinalitySource + 'static) -> Self {
+        self.l1_finality_source = Some(Arc::new(source));
+
+        self
+    }
+
+    /// Sets the tracker of in-flight transactions whose resources are never selected by
+    /// [`Provider::get_spendable_resources`]. The tracker is informed of every transaction
+    /// submitted through the provider.
+    pub fn with_pending_spends(mut self, pending_spends: impl PendingSpends + 'static) -> Self {
+        self.pending_spends = Some(Arc::new(pending_spends));
+
+        self
+    }
+
+    /// Informs `policy` of the transactions submitted through the provider, so that it records
+    /// their spending. Set by [`WalletUnlocked`](crate::wallet::WalletUnlocked) on its provider.
+    pub(crate) fn with_signing_policy(mut self, policy: &SigningPolicy) -> Self {
+        if !self
+            .signing_policies
+            .iter()
+            .any(|registered| registered.is_shared_with(policy))
+        {
+            self.signing_policies.push(policy.clone());
+        }
+
+        self
+    }
+
+    /// Sets the source maps used to report the Sway code that panicked or reverted in the errors
+    /// of failed calls, and to locate the steps of execution traces. Clones of the provider share
+    /// the registry.
+    pub fn with_source_maps(mut self, source_maps: SourceMapRegistry) -> Self {
+        self.source_maps = Some(source_maps);
+
+        self
+    }
+
+    pub fn source_maps(&self) -> Option<&SourceMapRegistry> {
+        self.source_maps.as_ref()
+    }
+
+    /// Sets the resolver used by [`Provider::resolve_recipient`] to turn names into addresses.
+    pub fn with_name_resolver(mut self, resolver: impl NameResolver + 'static) -> Self {
+        self.name_resolver = Some(Arc::new(resolver));
+
+        self
+    }
+
+    /// Returns the address of `recipient`, resolving names with the resolver set by
+    /// [`Provider::with_name_resolver`]. Fails if the name is unknown or no resolver is set.
+    pub async fn resolve_recipient(
+        &self,
+        recipient: impl Into<Recipient>,
+    ) -> Result<Bech32Address> {
+        let name = match recipient.into() {
+            Recipient::Address(address) => return Ok(address),
+            Recipient::Name(name) => name,
+        };
+
+        let resolver = self.name_resolver.as_ref().ok_or_else(|| {
+            error!(
+                Other,
+                "cannot resolve `{name}`: no name resolver set, see `Provider::with_name_resolver`"
+            )
+        })?;
+
+        resolver
+            .resolve(&name)
+            .await?
+            .ok_or_else(|| error!(Other, "no address is registered under the name `{name}`"))
+    }
+
+    /// Sets the maximum number of paginated queries that are in flight at the same time when
+    /// fetching resources for multiple assets or recipients, or the transactions of a block.
+    pub fn with_pagination_concurrency(mut self, concurrency: NonZeroUsize) -> Self {
+        self.pagination_concurrency = concurrency;
+
+        self
+    }
+
+    pub fn pagination_concurrency(&self) -> NonZeroUsize {
+        self.pagination_concurrency
+    }
+
+    /// Registers the provider metrics into `registry` and starts collecting them. See
+    /// [`ProviderMetrics`] for the list of exported metrics.
+    #[cfg(feature = "metrics-prometheus")]
+    pub fn with_prometheus_metrics(mut self, registry: &prometheus::Registry) -> Result<Self> {
+        let metrics = ProviderMetrics::register(registry)?;
+        self.client.set_metrics(metrics.clone());
+        self.metrics = Some(metrics);
+
+        Ok(self)
+    }
+
+    /// Enables or disables caching of predicate estimations. When enabled, transactions spending
+    /// from predicates with the same code and data that were already estimated, under the same
+    /// consensus parameters, will reuse the previous estimation instead of running the predicates
+    /// again. The cache is shared between all clones of the provider.
+    ///
+    /// Predicates are always estimated locally, using the consensus parameters fetched when the
+    /// provider was connected, so the cache saves execution time rather than requests to the node.
+    /// Transaction builders built with this provider reuse the same cache. A transaction whose
+    /// predicates were all found in the cache isn't validated again before being sent.
+    pub fn with_predicate_estimation_cache(mut self, enabled: bool) -> Self {
+        self.predicate_estimation_cache = enabled.then(Default::default);
+
+        self
+    }
+
+    /// Removes all cached predicate estimations. Does nothing if the cache is disabled.
+    pub fn clear_predicate_estimation_cache(&self) {
+        if let Some(cache) = &self.predicate_estimation_cache {
+            cache.clear();
+        }
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -717,4 +2283,57 @@ impl DryRunner for Provider {
     fn consensus_parameters(&self) -> &ConsensusParameters {
         self.consensus_parameters()
     }
+
+    async fn spent_resources(&self, ids: &[CoinTypeId]) -> Result<Vec<CoinTypeId>> {
+        let spent: Vec<Option<CoinTypeId>> = stream::iter(ids)
+            .map(|id| async move {
+                let is_spent = match id {
+                    CoinTypeId::UtxoId(utxo_id) => self.client.coin(utxo_id).await?.is_none(),
+                    CoinTypeId::Nonce(nonce) => !matches!(
+                        self.client.message_status(nonce).await?,
+                        ClientMessageStatus::Unspent
+                    ),
+                };
+
+                Ok::<_, Error>(is_spent.then(|| id.clone()))
+            })
+            .buffered(self.pagination_concurrency.get())
+            .try_collect()
+            .await?;
+
+        Ok(spent.into_iter().flatten().collect())
+    }
+
+    async fn dry_run_receipts(&self, tx: FuelTransaction) -> Result<Vec<Receipt>> {
+        let [tx_execution_status] = self
+            .client
+            .dry_run_opt(&vec![tx], Some(false))
+            .await?
+            .try_into()
+            .expect("should have only one element");
+
+        Ok(tx_execution_status.result.receipts().to_vec())
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn recent_blocks_gas(&self, count: u32) -> Result<Vec<BlockGasUsage>> {
+        self.recent_blocks_gas_usage(count).await
+    }
+
+    #[cfg(not(feature = "raw-graphql"))]
+    async fn recent_blocks_gas(&self, _count: u32) -> Result<Vec<BlockGasUsage>> {
+        Err(error!(
+            Other,
+            "inspecting recent blocks requires the `raw-graphql` feature"
+        ))
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn latest_da_compression_ratio(&self) -> Result<Option<f64>> {
+        Provider::latest_da_compression_ratio(self).await
+    }
+
+    fn predicate_estimation_cache(&self) -> Option<&PredicateEstimationCache> {
+        self.predicate_estimation_cache.as_ref()
+    }
 }
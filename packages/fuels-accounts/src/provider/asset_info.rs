@@ -0,0 +1,210 @@
+use std::str::FromStr;
+
+use fuel_tx::{AssetId, Bytes32, ContractId};
+use fuels_core::types::{
+    bech32::Bech32ContractId,
+    errors::{error, Error, Result},
+    Bits256,
+};
+use semver::Version;
+use serde::Deserialize;
+
+use super::Provider;
+
+const ASSET_DETAILS: &str =
+    "query($id: AssetId!) { assetDetails(id: $id) { subId contractId totalSupply } }";
+
+/// The first fuel-core version serving `assetDetails`. Nodes also need to index assets for the
+/// query to succeed.
+const ASSET_DETAILS_MIN_NODE_VERSION: Version = Version::new(0, 41, 0);
+
+/// What the node knows about an asset, see [`Provider::asset_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInfo {
+    pub asset_id: AssetId,
+    /// The amount minted minus the amount burned.
+    pub total_supply: u128,
+    /// The contract that minted the asset.
+    pub contract_id: Bech32ContractId,
+    /// The sub id the contract minted the asset with.
+    pub sub_id: Bits256,
+}
+
+impl AssetInfo {
+    /// Returns `true` if the asset was minted by `contract_id`.
+    pub fn is_issued_by(&self, contract_id: &Bech32ContractId) -> bool {
+        &self.contract_id == contract_id
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AssetDetails {
+    sub_id: String,
+    contract_id: String,
+    total_supply: String,
+}
+
+impl AssetDetails {
+    fn into_asset_info(self, asset_id: AssetId) -> Result<AssetInfo> {
+        let total_supply = self
+            .total_supply
+            .parse()
+            .map_err(|err| error!(Provider, "the node sent an invalid total supply: {err}"))?;
+
+        let contract_id = ContractId::from_str(&self.contract_id)
+            .map_err(|err| error!(Provider, "the node sent an invalid contract id: {err}"))?;
+
+        let sub_id = Bytes32::from_str(&self.sub_id)
+            .map_err(|err| error!(Provider, "the node sent an invalid sub id: {err}"))?;
+
+        Ok(AssetInfo {
+            asset_id,
+            total_supply,
+            contract_id: contract_id.into(),
+            sub_id: Bits256(*sub_id),
+        })
+    }
+}
+
+/// The node reports assets it doesn't know as a missing resource.
+fn is_unknown_asset(err: &Error) -> bool {
+    err.to_string().contains("not found")
+}
+
+impl Provider {
+    /// Gets the total supply of `asset_id`, the contract that minted it and the sub id it was
+    /// minted with. Returns `None` if the node knows nothing about the asset, e.g. because it was
+    /// never minted by a contract.
+    ///
+    /// Asset details are served by fuel-core 0.41.0 and later, the request fails on older nodes.
+    pub async fn asset_info(&self, asset_id: &AssetId) -> Result<Option<AssetInfo>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Response {
+            asset_details: AssetDetails,
+        }
+
+        self.check_serves_asset_details().await?;
+
+        let response = self
+            .raw_query::<Response>(
+                ASSET_DETAILS,
+                serde_json::json!({ "id": asset_id.to_string() }),
+            )
+            .await;
+
+        match response {
+            Ok(response) => response.asset_details.into_asset_info(*asset_id).map(Some),
+            Err(err) if is_unknown_asset(&err) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn check_serves_asset_details(&self) -> Result<()> {
+        let node_version = self.node_info().await?.node_version;
+        let version = Version::parse(&node_version).map_err(|err| {
+            error!(
+                Provider,
+                "the node sent an invalid version `{node_version}`: {err}"
+            )
+        })?;
+
+        if version < ASSET_DETAILS_MIN_NODE_VERSION {
+            return Err(error!(
+                Provider,
+                "asset details are served by fuel-core {ASSET_DETAILS_MIN_NODE_VERSION} and later, the node runs {version}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::MockProvider;
+
+    #[tokio::test]
+    async fn asset_details_are_typed() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let sub_id = Bytes32::from([2; 32]);
+        let asset_id = contract_id.asset_id(&sub_id);
+
+        // ANCHOR: asset_info
+        let provider = MockProvider::new()
+            .with_node_version("0.41.0")
+            .with_raw_query_response(
+                ASSET_DETAILS,
+                serde_json::json!({
+                    "assetDetails": {
+                        "subId": sub_id.to_string(),
+                        "contractId": contract_id.to_string(),
+                        "totalSupply": "340282366920938463463374607431768211455",
+                    }
+                }),
+            )
+            .provider();
+
+        let info = provider
+            .asset_info(&asset_id)
+            .await?
+            .expect("asset is known to the node");
+        // ANCHOR_END: asset_info
+
+        assert_eq!(info.asset_id, asset_id);
+        assert_eq!(info.total_supply, u128::MAX);
+        assert!(info.is_issued_by(&contract_id.into()));
+        assert_eq!(info.sub_id, Bits256(*sub_id));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unknown_assets_have_no_info() -> Result<()> {
+        let provider = MockProvider::new()
+            .with_node_version("0.41.0")
+            .with_raw_query_error(
+                ASSET_DETAILS,
+                "resource of type `AssetsInfo` was not found at the: 0x00",
+            )
+            .provider();
+
+        assert_eq!(provider.asset_info(&AssetId::zeroed()).await?, None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn other_query_errors_are_reported() {
+        let provider = MockProvider::new()
+            .with_node_version("0.41.0")
+            .with_raw_query_error(ASSET_DETAILS, "asset metadata isn't indexed")
+            .provider();
+
+        let err = provider
+            .asset_info(&AssetId::zeroed())
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("asset metadata isn't indexed"));
+    }
+
+    #[tokio::test]
+    async fn older_nodes_are_not_queried() {
+        let provider = MockProvider::new()
+            .with_node_version("0.26.0")
+            .with_raw_query_response(ASSET_DETAILS, serde_json::json!({}))
+            .provider();
+
+        let err = provider
+            .asset_info(&AssetId::zeroed())
+            .await
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("served by fuel-core 0.41.0 and later, the node runs 0.26.0"));
+    }
+}
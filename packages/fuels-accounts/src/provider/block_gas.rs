@@ -0,0 +1,328 @@
+use std::ops::RangeInclusive;
+
+use fuel_tx::{
+    field::Inputs, Chargeable, ConsensusParameters, ContractId, Transaction as FuelTransaction,
+    TxId, UniqueIdentifier,
+};
+use fuel_types::canonical::Deserialize as _;
+use fuels_core::types::{
+    errors::{error, Result},
+    transaction_builders::BlockGasUsage,
+};
+use serde::Deserialize;
+
+use super::Provider;
+
+/// Queries the latest blocks together with their transactions, so that their gas usage is known
+/// without a request per transaction.
+const RECENT_BLOCKS_TRANSACTIONS: &str = "query($count: Int) { blocks(last: $count) { nodes { header { height } transactions { rawPayload } } } }";
+
+/// Queries `count` blocks, starting after the height `after`, together with their transactions.
+const BLOCKS_TRANSACTIONS: &str = "query($count: Int, $after: String) { blocks(first: $count, after: $after) { nodes { header { height } transactions { rawPayload } } } }";
+
+/// The number of blocks requested by each query of [`BLOCKS_TRANSACTIONS`].
+const BLOCKS_PER_QUERY: u32 = 100;
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    blocks: Blocks,
+}
+
+#[derive(Debug, Deserialize)]
+struct Blocks {
+    nodes: Vec<BlockTransactions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BlockTransactions {
+    header: Header,
+    transactions: Vec<RawTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Header {
+    height: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawTransaction {
+    raw_payload: String,
+}
+
+impl BlockTransactions {
+    fn gas_usage(self, consensus_parameters: &ConsensusParameters) -> Result<BlockGasUsage> {
+        let height = self.height()?;
+
+        let mut gas_used = 0u64;
+        for tx in &self.transactions {
+            gas_used = gas_used.saturating_add(max_gas(tx.decode()?, consensus_parameters));
+        }
+
+        Ok(BlockGasUsage {
+            height,
+            gas_used,
+            gas_limit: consensus_parameters.block_gas_limit(),
+        })
+    }
+}
+
+impl BlockTransactions {
+    fn height(&self) -> Result<u32> {
+        self.header
+            .height
+            .parse()
+            .map_err(|err| error!(Provider, "the node sent an invalid block height: {err}"))
+    }
+}
+
+impl RawTransaction {
+    fn bytes(&self) -> Result<Vec<u8>> {
+        hex::decode(self.raw_payload.trim_start_matches("0x"))
+            .map_err(|err| error!(Provider, "the node sent an invalid transaction: {err}"))
+    }
+
+    fn decode(&self) -> Result<FuelTransaction> {
+        FuelTransaction::from_bytes(&self.bytes()?)
+            .map_err(|err| error!(Provider, "the node sent an invalid transaction: {err:?}"))
+    }
+}
+
+/// The max gas of `tx`, which is what the block producer counts against the block gas limit.
+fn max_gas(tx: FuelTransaction, consensus_parameters: &ConsensusParameters) -> u64 {
+    let gas_costs = consensus_parameters.gas_costs();
+    let fee_params = consensus_parameters.fee_params();
+
+    match tx {
+        FuelTransaction::Script(tx) => tx.max_gas(gas_costs, fee_params),
+        FuelTransaction::Create(tx) => tx.max_gas(gas_costs, fee_params),
+        FuelTransaction::Upgrade(tx) => tx.max_gas(gas_costs, fee_params),
+        FuelTransaction::Upload(tx) => tx.max_gas(gas_costs, fee_params),
+        FuelTransaction::Mint(_) => 0,
+    }
+}
+
+impl Provider {
+    /// The gas used by the latest `count` blocks, fetched in a single query.
+    pub(crate) async fn recent_blocks_gas_usage(&self, count: u32) -> Result<Vec<BlockGasUsage>> {
+        let count = i32::try_from(count).unwrap_or(i32::MAX);
+        let response: Response = self
+            .raw_query(
+                RECENT_BLOCKS_TRANSACTIONS,
+                serde_json::json!({ "count": count }),
+            )
+            .await?;
+
+        response
+            .blocks
+            .nodes
+            .into_iter()
+            .map(|block| block.gas_usage(self.consensus_parameters()))
+            .collect()
+    }
+
+    /// The ids of the transactions of the blocks at `heights` with `contract_id` as input,
+    /// fetched in a query per [`BLOCKS_PER_QUERY`] blocks.
+    pub(crate) async fn txs_with_contract_input(
+        &self,
+        contract_id: ContractId,
+        heights: RangeInclusive<u32>,
+    ) -> Result<Vec<TxId>> {
+        let chain_id = self.chain_id();
+        let mut tx_ids = vec![];
+
+        let (mut start, end) = heights.into_inner();
+        while start <= end {
+            let count = (end - start).saturating_add(1).min(BLOCKS_PER_QUERY);
+            let after = start.checked_sub(1).map(|height| height.to_string());
+            let response: Response = self
+                .raw_query(
+                    BLOCKS_TRANSACTIONS,
+                    serde_json::json!({ "count": count, "after": after }),
+                )
+                .await?;
+
+            for tx in response
+                .blocks
+                .nodes
+                .iter()
+                .flat_map(|block| &block.transactions)
+            {
+                let tx = tx.decode()?;
+                let inputs = match &tx {
+                    FuelTransaction::Script(tx) => tx.inputs(),
+                    FuelTransaction::Create(tx) => tx.inputs(),
+                    _ => continue,
+                };
+
+                if inputs
+                    .iter()
+                    .any(|input| input.contract_id() == Some(&contract_id))
+                {
+                    tx_ids.push(tx.id(&chain_id));
+                }
+            }
+
+            match start.checked_add(count) {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+
+        Ok(tx_ids)
+    }
+
+    /// The size of the latest block in the compressed form the node posts to the DA layer,
+    /// divided by the size of its transactions. `None` if the block has no transactions or the
+    /// node has no compressed block, which includes nodes rejecting the query because they don't
+    /// compress their blocks.
+    pub(crate) async fn latest_da_compression_ratio(&self) -> Result<Option<f64>> {
+        let response: Response = self
+            .raw_query(
+                RECENT_BLOCKS_TRANSACTIONS,
+                serde_json::json!({ "count": 1 }),
+            )
+            .await?;
+        let Some(block) = response.blocks.nodes.into_iter().next() else {
+            return Ok(None);
+        };
+
+        let mut size = 0;
+        for tx in &block.transactions {
+            size += tx.bytes()?.len();
+        }
+        if size == 0 {
+            return Ok(None);
+        }
+
+        let Ok(Some(compressed)) = self.da_compressed_block(block.height()?.into()).await else {
+            return Ok(None);
+        };
+
+        Ok(Some(compressed.bytes.len() as f64 / size as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::TransactionBuilder;
+    use fuel_types::canonical::Serialize;
+
+    use super::*;
+    use crate::provider::MockProvider;
+
+    #[tokio::test]
+    async fn gas_usage_is_computed_from_a_single_query() -> Result<()> {
+        let consensus_parameters = ConsensusParameters::default();
+        let tx = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(1000)
+            .finalize_as_transaction();
+        let raw_payload = format!("0x{}", hex::encode(tx.to_bytes()));
+
+        let mock = MockProvider::new()
+            .with_consensus_parameters(consensus_parameters.clone())
+            .with_raw_query_response(
+                RECENT_BLOCKS_TRANSACTIONS,
+                serde_json::json!({
+                    "blocks": { "nodes": [
+                        { "header": { "height": "1" }, "transactions": [] },
+                        {
+                            "header": { "height": "2" },
+                            "transactions": [
+                                { "rawPayload": raw_payload },
+                                { "rawPayload": raw_payload },
+                            ]
+                        },
+                    ] }
+                }),
+            );
+
+        let usages = mock.provider().recent_blocks_gas_usage(2).await?;
+
+        let gas_limit = consensus_parameters.block_gas_limit();
+        let tx_gas = max_gas(tx, &consensus_parameters);
+        assert_eq!(
+            usages,
+            [
+                BlockGasUsage {
+                    height: 1,
+                    gas_used: 0,
+                    gas_limit,
+                },
+                BlockGasUsage {
+                    height: 2,
+                    gas_used: 2 * tx_gas,
+                    gas_limit,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn txs_with_a_contract_input_are_found_in_a_single_query() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let with_contract = TransactionBuilder::script(vec![], vec![])
+            .add_input(fuel_tx::Input::contract(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                contract_id,
+            ))
+            .finalize_as_transaction();
+        let without_contract =
+            TransactionBuilder::script(vec![1], vec![]).finalize_as_transaction();
+        let raw_payload = |tx: &FuelTransaction| format!("0x{}", hex::encode(tx.to_bytes()));
+
+        let mock = MockProvider::new().with_raw_query_response(
+            BLOCKS_TRANSACTIONS,
+            serde_json::json!({
+                "blocks": { "nodes": [
+                    {
+                        "header": { "height": "3" },
+                        "transactions": [{ "rawPayload": raw_payload(&without_contract) }]
+                    },
+                    {
+                        "header": { "height": "4" },
+                        "transactions": [{ "rawPayload": raw_payload(&with_contract) }]
+                    },
+                ] }
+            }),
+        );
+        let provider = mock.provider();
+
+        let tx_ids = provider.txs_with_contract_input(contract_id, 3..=4).await?;
+
+        assert_eq!(tx_ids, [with_contract.id(&provider.chain_id())]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn da_compression_ratio_is_measured_on_the_latest_block() -> Result<()> {
+        let tx = TransactionBuilder::script(vec![0; 100], vec![]).finalize_as_transaction();
+        let bytes = tx.to_bytes();
+        let raw_payload = format!("0x{}", hex::encode(&bytes));
+        let latest_block = serde_json::json!({
+            "blocks": { "nodes": [{
+                "header": { "height": "7" },
+                "transactions": [{ "rawPayload": raw_payload }, { "rawPayload": raw_payload }]
+            }] }
+        });
+
+        let uncompressed = MockProvider::new()
+            .with_raw_query_response(RECENT_BLOCKS_TRANSACTIONS, latest_block.clone())
+            .provider();
+        assert_eq!(uncompressed.latest_da_compression_ratio().await?, None);
+
+        let compressed = MockProvider::new()
+            .with_raw_query_response(RECENT_BLOCKS_TRANSACTIONS, latest_block)
+            .with_da_compressed_block(7, vec![0; bytes.len()])
+            .provider();
+        assert_eq!(compressed.latest_da_compression_ratio().await?, Some(0.5));
+
+        Ok(())
+    }
+}
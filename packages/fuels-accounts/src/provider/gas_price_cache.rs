@@ -0,0 +1,354 @@
+use std::{collections::HashMap, fmt::Debug, future::Future, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use fuel_core_client::client::types::gas_price::{EstimateGasPrice, LatestGasPrice};
+use fuels_core::types::errors::Result;
+use tokio::sync::Mutex;
+
+type ChangeListener = dyn Fn(GasPriceKind, u64, u64) + Send + Sync;
+
+/// A gas price served by the cache, see [`GasPriceCacheConfig::with_on_change`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GasPriceKind {
+    /// The gas price of the latest block, see
+    /// [`Provider::latest_gas_price`](crate::provider::Provider::latest_gas_price).
+    Latest,
+    /// The gas price estimated for inclusion within `block_horizon` blocks, see
+    /// [`Provider::estimate_gas_price`](crate::provider::Provider::estimate_gas_price).
+    Estimate { block_horizon: u32 },
+}
+
+/// Configures how [`Provider::latest_gas_price`](crate::provider::Provider::latest_gas_price)
+/// and [`Provider::estimate_gas_price`](crate::provider::Provider::estimate_gas_price) cache
+/// the gas price, see
+/// [`Provider::with_gas_price_cache`](crate::provider::Provider::with_gas_price_cache). By
+/// default, each gas price is queried at most once every 10 seconds.
+#[derive(Clone)]
+pub struct GasPriceCacheConfig {
+    refresh_interval: Duration,
+    change_threshold: f64,
+    on_change: Option<Arc<ChangeListener>>,
+}
+
+impl Default for GasPriceCacheConfig {
+    fn default() -> Self {
+        Self {
+            refresh_interval: Duration::from_secs(10),
+            change_threshold: 0.0,
+            on_change: None,
+        }
+    }
+}
+
+impl Debug for GasPriceCacheConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GasPriceCacheConfig")
+            .field("refresh_interval", &self.refresh_interval)
+            .field("change_threshold", &self.change_threshold)
+            .field("on_change", &self.on_change.is_some())
+            .finish()
+    }
+}
+
+impl GasPriceCacheConfig {
+    /// How long a queried gas price is served before it is queried again.
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    pub fn refresh_interval(&self) -> Duration {
+        self.refresh_interval
+    }
+
+    /// The relative change of the gas price, e.g. `0.1` for 10%, above which the listener set
+    /// with [`GasPriceCacheConfig::with_on_change`] is called. Defaults to `0.0`, i.e. every
+    /// change is reported.
+    pub fn with_change_threshold(mut self, change_threshold: f64) -> Self {
+        self.change_threshold = change_threshold;
+        self
+    }
+
+    pub fn change_threshold(&self) -> f64 {
+        self.change_threshold
+    }
+
+    /// Calls `listener` with the kind of gas price, its previously reported value and the new
+    /// one whenever a refresh finds that the gas price changed by more than the change threshold.
+    /// Each kind of gas price, including estimates for different block horizons, is compared to
+    /// its own last reported value, so that slow drifts are reported as well.
+    ///
+    /// The listener is called while the cache is being refreshed and should return quickly.
+    pub fn with_on_change(
+        mut self,
+        listener: impl Fn(GasPriceKind, u64, u64) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_change = Some(Arc::new(listener));
+        self
+    }
+}
+
+/// A gas price the cache can serve.
+pub(crate) trait GasPrice: Clone {
+    fn gas_price(&self) -> u64;
+}
+
+impl GasPrice for LatestGasPrice {
+    fn gas_price(&self) -> u64 {
+        self.gas_price
+    }
+}
+
+impl GasPrice for EstimateGasPrice {
+    fn gas_price(&self) -> u64 {
+        self.gas_price
+    }
+}
+
+#[derive(Debug)]
+struct Cached<T> {
+    value: T,
+    fetched_at: DateTime<Utc>,
+    reported_gas_price: u64,
+}
+
+/// Serves the latest gas price, and the estimated gas price of each block horizon, until the
+/// refresh interval elapsed. Concurrent requests wait for a single refresh instead of each
+/// querying the node.
+#[derive(Debug)]
+pub(crate) struct GasPriceCache {
+    config: GasPriceCacheConfig,
+    latest: Mutex<Option<Cached<LatestGasPrice>>>,
+    estimates: Mutex<HashMap<u32, Cached<EstimateGasPrice>>>,
+}
+
+impl GasPriceCache {
+    pub(crate) fn new(config: GasPriceCacheConfig) -> Self {
+        Self {
+            config,
+            latest: Default::default(),
+            estimates: Default::default(),
+        }
+    }
+
+    pub(crate) async fn latest<F>(&self, fetch: impl FnOnce() -> F) -> Result<LatestGasPrice>
+    where
+        F: Future<Output = Result<LatestGasPrice>>,
+    {
+        let mut latest = self.latest.lock().await;
+
+        self.get_or_refresh(GasPriceKind::Latest, &mut latest, fetch)
+            .await
+    }
+
+    pub(crate) async fn estimate<F>(
+        &self,
+        block_horizon: u32,
+        fetch: impl FnOnce() -> F,
+    ) -> Result<EstimateGasPrice>
+    where
+        F: Future<Output = Result<EstimateGasPrice>>,
+    {
+        let mut estimates = self.estimates.lock().await;
+
+        let mut cached = estimates.remove(&block_horizon);
+        let estimate = self
+            .get_or_refresh(GasPriceKind::Estimate { block_horizon }, &mut cached, fetch)
+            .await;
+        estimates.extend(cached.map(|cached| (block_horizon, cached)));
+
+        estimate
+    }
+
+    async fn get_or_refresh<T: GasPrice, F>(
+        &self,
+        kind: GasPriceKind,
+        cached: &mut Option<Cached<T>>,
+        fetch: impl FnOnce() -> F,
+    ) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        let now = Utc::now();
+        if let Some(cached) = cached.as_ref().filter(|cached| !self.is_stale(cached, now)) {
+            return Ok(cached.value.clone());
+        }
+
+        let latest = fetch().await?;
+        let gas_price = latest.gas_price();
+        let reported_gas_price = match cached.as_ref().map(|cached| cached.reported_gas_price) {
+            Some(reported) if self.changed_significantly(reported, gas_price) => {
+                if let Some(listener) = &self.config.on_change {
+                    listener(kind, reported, gas_price);
+                }
+                gas_price
+            }
+            Some(reported) => reported,
+            None => gas_price,
+        };
+
+        *cached = Some(Cached {
+            value: latest.clone(),
+            fetched_at: now,
+            reported_gas_price,
+        });
+
+        Ok(latest)
+    }
+
+    fn is_stale<T>(&self, cached: &Cached<T>, now: DateTime<Utc>) -> bool {
+        let age = (now - cached.fetched_at).to_std().unwrap_or_default();
+
+        age >= self.config.refresh_interval
+    }
+
+    fn changed_significantly(&self, reported: u64, latest: u64) -> bool {
+        if reported == latest {
+            return false;
+        }
+
+        let relative_change = reported.abs_diff(latest) as f64 / reported.max(1) as f64;
+
+        relative_change > self.config.change_threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex as StdMutex;
+
+    use fuel_tx::Output;
+    use fuels_core::types::{
+        coin::Coin,
+        coin_type::CoinType,
+        input::Input,
+        transaction::TxPolicies,
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+        AssetId,
+    };
+
+    use super::*;
+    use crate::{provider::MockProvider, wallet::WalletUnlocked, ViewOnlyAccount};
+
+    #[tokio::test]
+    async fn gas_price_is_served_from_the_cache_until_refreshed() -> Result<()> {
+        let mock = MockProvider::new().with_gas_price(10);
+        let provider = mock
+            .provider()
+            .with_gas_price_cache(GasPriceCacheConfig::default());
+
+        assert_eq!(provider.latest_gas_price().await?.gas_price, 10);
+
+        let mock = mock.with_gas_price(20);
+        assert_eq!(provider.latest_gas_price().await?.gas_price, 10);
+
+        let provider = mock.provider().with_gas_price_cache(
+            GasPriceCacheConfig::default().with_refresh_interval(Duration::ZERO),
+        );
+        assert_eq!(provider.latest_gas_price().await?.gas_price, 20);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn significant_changes_are_reported() -> Result<()> {
+        let changes = Arc::new(StdMutex::new(vec![]));
+        // ANCHOR: gas_price_cache
+        let config = GasPriceCacheConfig::default()
+            .with_refresh_interval(Duration::ZERO)
+            .with_change_threshold(0.1)
+            .with_on_change({
+                let changes = changes.clone();
+                move |kind, previous, latest| changes.lock().unwrap().push((kind, previous, latest))
+            });
+        let mock = MockProvider::new().with_gas_price(100);
+        let provider = mock.provider().with_gas_price_cache(config);
+        // ANCHOR_END: gas_price_cache
+
+        for gas_price in [100, 105, 109, 111, 90] {
+            mock.clone().with_gas_price(gas_price);
+            provider.latest_gas_price().await?;
+        }
+
+        let latest = GasPriceKind::Latest;
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [(latest, 100, 111), (latest, 111, 90)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn estimates_are_cached_per_block_horizon() -> Result<()> {
+        let changes = Arc::new(StdMutex::new(vec![]));
+        let mock = MockProvider::new().with_gas_price(10);
+        let provider =
+            mock.provider()
+                .with_gas_price_cache(GasPriceCacheConfig::default().with_on_change({
+                    let changes = changes.clone();
+                    move |kind, previous, latest| {
+                        changes.lock().unwrap().push((kind, previous, latest))
+                    }
+                }));
+
+        assert_eq!(provider.estimate_gas_price(10).await?.gas_price, 10);
+        let mock = mock.with_gas_price(20);
+        assert_eq!(provider.estimate_gas_price(10).await?.gas_price, 10);
+        assert_eq!(provider.estimate_gas_price(5).await?.gas_price, 20);
+        assert_eq!(mock.gas_price_requests(), 2);
+
+        let provider = mock.provider().with_gas_price_cache(
+            GasPriceCacheConfig::default()
+                .with_refresh_interval(Duration::ZERO)
+                .with_on_change({
+                    let changes = changes.clone();
+                    move |kind, previous, latest| {
+                        changes.lock().unwrap().push((kind, previous, latest))
+                    }
+                }),
+        );
+        provider.estimate_gas_price(10).await?;
+        let mock = mock.with_gas_price(30);
+        provider.estimate_gas_price(10).await?;
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [(GasPriceKind::Estimate { block_horizon: 10 }, 20, 30)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn building_transactions_reuses_the_estimated_gas_price() -> Result<()> {
+        let mock = MockProvider::new().with_gas_price(1);
+        let provider = mock
+            .provider()
+            .with_gas_price_cache(GasPriceCacheConfig::default());
+
+        let wallet = WalletUnlocked::new_random(None);
+        for _ in 0..2 {
+            let coin = Coin {
+                amount: 1000,
+                owner: wallet.address().clone(),
+                ..Default::default()
+            };
+            let mut tb = ScriptTransactionBuilder::prepare_transfer(
+                vec![Input::resource_signed(CoinType::Coin(coin))],
+                vec![Output::change(
+                    wallet.address().into(),
+                    0,
+                    AssetId::zeroed(),
+                )],
+                TxPolicies::default(),
+            );
+            tb.add_signer(wallet.clone())?;
+            tb.build(&provider).await?;
+        }
+
+        assert_eq!(mock.gas_price_requests(), 1);
+
+        Ok(())
+    }
+}
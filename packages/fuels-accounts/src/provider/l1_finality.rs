@@ -0,0 +1,21 @@
+use std::{fmt::Debug, time::Duration};
+
+use async_trait::async_trait;
+use fuels_core::types::errors::Result;
+
+/// Reports which Fuel blocks have their commitment finalized on the L1, e.g. by reading the
+/// state contract of the bridge on Ethereum. The node only knows about the blocks it produced, so
+/// [`Provider::await_l1_finality`](crate::provider::Provider::await_l1_finality) relies on a
+/// source set with
+/// [`Provider::with_l1_finality_source`](crate::provider::Provider::with_l1_finality_source).
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait L1FinalitySource: Debug + Send + Sync {
+    /// Returns the height of the latest Fuel block whose commitment is final on the L1, if any.
+    async fn latest_finalized_height(&self) -> Result<Option<u32>>;
+
+    /// How often the source is queried while awaiting finality.
+    fn poll_interval(&self) -> Duration {
+        Duration::from_secs(12)
+    }
+}
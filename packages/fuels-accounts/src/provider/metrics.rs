@@ -0,0 +1,175 @@
+use std::{fmt, time::Duration};
+
+use fuel_core_client::client::types::TransactionStatus as ClientTransactionStatus;
+use fuels_core::types::errors::{error, Error, Result};
+use prometheus::{
+    exponential_buckets, Histogram, HistogramOpts, IntCounter, IntCounterVec, Opts, Registry,
+};
+
+const NAMESPACE: &str = "fuels";
+
+/// Prometheus metrics collected by a [`Provider`](crate::provider::Provider).
+///
+/// All metrics are registered under the `fuels_provider` prefix:
+/// - `requests_total{method, status}`: node requests, after retries, by client method
+/// - `tx_submissions_total`: submitted transactions
+/// - `tx_confirmation_seconds`: time between submitting a transaction and its commit
+/// - `tx_fee_paid`: fee paid by committed transactions, in base asset units
+#[derive(Clone)]
+pub struct ProviderMetrics {
+    requests: IntCounterVec,
+    tx_submissions: IntCounter,
+    tx_confirmation_seconds: Histogram,
+    tx_fee_paid: Histogram,
+}
+
+impl ProviderMetrics {
+    /// Creates the provider metrics and registers them into `registry`.
+    pub fn register(registry: &Registry) -> Result<Self> {
+        let opts = |name: &str, help: &str| {
+            Opts::new(name, help)
+                .namespace(NAMESPACE)
+                .subsystem("provider")
+        };
+
+        let requests = IntCounterVec::new(
+            opts("requests_total", "Requests sent to the node"),
+            &["method", "status"],
+        )
+        .map_err(metrics_error)?;
+        let tx_submissions = IntCounter::with_opts(opts(
+            "tx_submissions_total",
+            "Transactions submitted to the node",
+        ))
+        .map_err(metrics_error)?;
+        let tx_confirmation_seconds = Histogram::with_opts(
+            HistogramOpts::from(opts(
+                "tx_confirmation_seconds",
+                "Time between submitting a transaction and its commit",
+            ))
+            .buckets(exponential_buckets(0.25, 2.0, 10).map_err(metrics_error)?),
+        )
+        .map_err(metrics_error)?;
+        let tx_fee_paid = Histogram::with_opts(
+            HistogramOpts::from(opts("tx_fee_paid", "Fee paid by committed transactions"))
+                .buckets(exponential_buckets(1.0, 10.0, 12).map_err(metrics_error)?),
+        )
+        .map_err(metrics_error)?;
+
+        registry
+            .register(Box::new(requests.clone()))
+            .map_err(metrics_error)?;
+        registry
+            .register(Box::new(tx_submissions.clone()))
+            .map_err(metrics_error)?;
+        registry
+            .register(Box::new(tx_confirmation_seconds.clone()))
+            .map_err(metrics_error)?;
+        registry
+            .register(Box::new(tx_fee_paid.clone()))
+            .map_err(metrics_error)?;
+
+        Ok(Self {
+            requests,
+            tx_submissions,
+            tx_confirmation_seconds,
+            tx_fee_paid,
+        })
+    }
+
+    pub(crate) fn observe_request(&self, method: &str, succeeded: bool) {
+        let status = if succeeded { "ok" } else { "error" };
+        self.requests.with_label_values(&[method, status]).inc();
+    }
+
+    pub(crate) fn observe_submission(&self) {
+        self.tx_submissions.inc();
+    }
+
+    /// Observes the commit of a transaction submitted `elapsed` ago, with the fee it paid.
+    pub(crate) fn observe_commit(&self, elapsed: Duration, status: &ClientTransactionStatus) {
+        self.tx_confirmation_seconds.observe(elapsed.as_secs_f64());
+
+        match status {
+            ClientTransactionStatus::Success { total_fee, .. }
+            | ClientTransactionStatus::Failure { total_fee, .. } => {
+                self.tx_fee_paid.observe(*total_fee as f64);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Debug for ProviderMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProviderMetrics").finish_non_exhaustive()
+    }
+}
+
+fn metrics_error(err: prometheus::Error) -> Error {
+    error!(Other, "failed to set up provider metrics: {err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use tai64::Tai64;
+
+    use super::*;
+
+    #[test]
+    fn requests_are_counted_by_method_and_status() -> Result<()> {
+        let registry = Registry::new();
+        let metrics = ProviderMetrics::register(&registry)?;
+
+        metrics.observe_request("chain_info", true);
+        metrics.observe_request("chain_info", true);
+        metrics.observe_request("submit", false);
+
+        let requests = metrics.requests.clone();
+        assert_eq!(requests.with_label_values(&["chain_info", "ok"]).get(), 2);
+        assert_eq!(requests.with_label_values(&["submit", "error"]).get(), 1);
+        assert_eq!(requests.with_label_values(&["submit", "ok"]).get(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn committed_transactions_observe_the_fee_paid() -> Result<()> {
+        let registry = Registry::new();
+        let metrics = ProviderMetrics::register(&registry)?;
+
+        let committed = ClientTransactionStatus::Success {
+            block_height: 1u32.into(),
+            time: Tai64::now(),
+            program_state: None,
+            receipts: vec![],
+            total_gas: 100,
+            total_fee: 42,
+        };
+        metrics.observe_commit(Duration::from_secs(1), &committed);
+
+        let squeezed_out = ClientTransactionStatus::SqueezedOut {
+            reason: "squeezed".to_string(),
+        };
+        metrics.observe_commit(Duration::from_secs(1), &squeezed_out);
+
+        assert_eq!(metrics.tx_fee_paid.get_sample_count(), 1);
+        assert_eq!(metrics.tx_fee_paid.get_sample_sum(), 42.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn registering_twice_in_the_same_registry_fails() -> Result<()> {
+        let registry = Registry::new();
+        ProviderMetrics::register(&registry)?;
+
+        let err = ProviderMetrics::register(&registry).expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("failed to set up provider metrics"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1005 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    io,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use fuel_core_client::client::{
+    pagination::{PaginatedResult, PaginationRequest},
+    types::{
+        chain_info::ChainInfo,
+        coins::CoinType,
+        gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::Message as ClientMessage,
+        primitives::TransactionId,
+        Balance, Coin as ClientCoin, NodeInfo, TransactionStatus,
+    },
+};
+use fuel_core_types::{
+    fuel_vm::state::ProgramState,
+    services::executor::{TransactionExecutionResult, TransactionExecutionStatus},
+};
+use fuel_tx::{ConsensusParameters, Receipt, Transaction, TxId, UniqueIdentifier, UtxoId};
+use fuel_types::{Address, AssetId, BlockHeight, Nonce};
+use fuels_core::types::{coin::Coin, message::Message, tx_status::TxStatus};
+use tai64::Tai64;
+
+#[cfg(feature = "raw-graphql")]
+use super::transport::RawSubscription;
+use super::{
+    transport::{self, Transport},
+    Provider,
+};
+
+/// Serves the requests of a [`Provider`] from programmed responses, so that code depending on a
+/// provider can be unit tested without running a node.
+///
+/// Clones share their state: responses can still be programmed, and submitted transactions
+/// inspected, after handing out providers with [`MockProvider::provider`]. Requests without a
+/// programmed response fail as unsupported.
+#[derive(Debug, Clone, Default)]
+pub struct MockProvider {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    consensus_parameters: ConsensusParameters,
+    block_height: u32,
+    node_version: Option<String>,
+    gas_price: u64,
+    gas_price_requests: usize,
+    balances: HashMap<(Address, AssetId), u64>,
+    coins: Vec<Coin>,
+    messages: Vec<Message>,
+    dry_runs: VecDeque<TxStatus>,
+    submissions: VecDeque<TxStatus>,
+    submitted: Vec<Transaction>,
+    statuses: HashMap<TxId, TxStatus>,
+    da_compressed_blocks: HashMap<u32, Vec<u8>>,
+    #[cfg(feature = "raw-graphql")]
+    raw_query_responses: HashMap<String, serde_json::Value>,
+    #[cfg(feature = "raw-graphql")]
+    raw_query_errors: HashMap<String, String>,
+    #[cfg(feature = "raw-graphql")]
+    raw_subscription_events: HashMap<String, Vec<serde_json::Value>>,
+}
+
+impl MockProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a provider sending its requests to this mock.
+    pub fn provider(&self) -> Provider {
+        let consensus_parameters = self.state().consensus_parameters.clone();
+
+        Provider::from_transport("mock://", self.clone(), consensus_parameters)
+    }
+
+    pub fn with_consensus_parameters(self, consensus_parameters: ConsensusParameters) -> Self {
+        self.state().consensus_parameters = consensus_parameters;
+        self
+    }
+
+    /// Sets the height of the latest block, which is also the height of committed transactions.
+    pub fn with_block_height(self, block_height: u32) -> Self {
+        self.state().block_height = block_height;
+        self
+    }
+
+    /// Sets the version the node reports in its info. Node info isn't served without it.
+    pub fn with_node_version(self, node_version: impl Into<String>) -> Self {
+        self.state().node_version = Some(node_version.into());
+        self
+    }
+
+    pub fn with_gas_price(self, gas_price: u64) -> Self {
+        self.state().gas_price = gas_price;
+        self
+    }
+
+    /// Sets the balance of `owner` in `asset_id`, without providing coins to spend it.
+    pub fn with_balance(self, owner: impl Into<Address>, asset_id: AssetId, amount: u64) -> Self {
+        self.state()
+            .balances
+            .insert((owner.into(), asset_id), amount);
+        self
+    }
+
+    /// Adds a spendable coin, which also counts towards the balance of its owner.
+    pub fn with_coin(self, coin: Coin) -> Self {
+        {
+            let mut state = self.state();
+            *state
+                .balances
+                .entry(((&coin.owner).into(), coin.asset_id))
+                .or_default() += coin.amount;
+            state.coins.push(coin);
+        }
+        self
+    }
+
+    /// Adds an unspent message. Messages added after a provider was handed out are returned by
+    /// its next requests, as if they had just been relayed from the base layer.
+    pub fn with_message(self, message: Message) -> Self {
+        self.state().messages.push(message);
+        self
+    }
+
+    /// Queues the result of the next dry run. Dry runs without a queued result succeed without
+    /// receipts, and a [`TxStatus::SqueezedOut`] result fails the dry run with its reason.
+    pub fn with_dry_run_result(self, status: TxStatus) -> Self {
+        self.state().dry_runs.push_back(status);
+        self
+    }
+
+    /// Queues the status of the next submitted transaction. Transactions submitted without a
+    /// queued status succeed without receipts.
+    pub fn with_submission_result(self, status: TxStatus) -> Self {
+        self.state().submissions.push_back(status);
+        self
+    }
+
+    /// Sets the DA-compressed form of the block at `height`.
+    pub fn with_da_compressed_block(self, height: u32, bytes: impl Into<Vec<u8>>) -> Self {
+        self.state()
+            .da_compressed_blocks
+            .insert(height, bytes.into());
+        self
+    }
+
+    /// Sets the `data` returned for the GraphQL `query`, whatever its variables.
+    #[cfg(feature = "raw-graphql")]
+    pub fn with_raw_query_response(
+        self,
+        query: impl Into<String>,
+        data: serde_json::Value,
+    ) -> Self {
+        self.state().raw_query_responses.insert(query.into(), data);
+        self
+    }
+
+    /// Makes the GraphQL `query` fail with an error the node reports with `message`, whatever its
+    /// variables.
+    #[cfg(feature = "raw-graphql")]
+    pub fn with_raw_query_error(
+        self,
+        query: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.state()
+            .raw_query_errors
+            .insert(query.into(), message.into());
+        self
+    }
+
+    /// Sets the `data` of the responses sent for the GraphQL `subscription`, whatever its
+    /// variables. The subscription ends after the last response.
+    #[cfg(feature = "raw-graphql")]
+    pub fn with_raw_subscription_events(
+        self,
+        subscription: impl Into<String>,
+        events: Vec<serde_json::Value>,
+    ) -> Self {
+        self.state()
+            .raw_subscription_events
+            .insert(subscription.into(), events);
+        self
+    }
+
+    /// Returns how many times the latest or the estimated gas price was requested.
+    pub fn gas_price_requests(&self) -> usize {
+        self.state().gas_price_requests
+    }
+
+    /// Returns the transactions submitted so far, in order.
+    pub fn submitted_transactions(&self) -> Vec<Transaction> {
+        self.state().submitted.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().expect("mock state is not poisoned")
+    }
+
+    fn execute(&self, transactions: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let mut state = self.state();
+        let chain_id = state.consensus_parameters.chain_id();
+
+        transactions
+            .iter()
+            .map(|tx| {
+                let result = match state.dry_runs.pop_front() {
+                    Some(TxStatus::SqueezedOut { reason }) => {
+                        return Err(io::Error::new(io::ErrorKind::Other, reason))
+                    }
+                    Some(TxStatus::Revert {
+                        receipts,
+                        revert_id,
+                        ..
+                    }) => TransactionExecutionResult::Failed {
+                        result: Some(ProgramState::Revert(revert_id)),
+                        receipts,
+                        total_gas: 0,
+                        total_fee: 0,
+                    },
+                    Some(TxStatus::Success { receipts }) => success(receipts),
+                    Some(TxStatus::Submitted) | None => success(vec![]),
+                };
+
+                Ok(TransactionExecutionStatus {
+                    id: tx.id(&chain_id),
+                    result,
+                })
+            })
+            .collect()
+    }
+
+    fn status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        let state = self.state();
+        let status = state.statuses.get(id).cloned().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("transaction `{id}` was not submitted"),
+            )
+        })?;
+
+        Ok(client_status(status, state.block_height))
+    }
+}
+
+fn success(receipts: Vec<Receipt>) -> TransactionExecutionResult {
+    TransactionExecutionResult::Success {
+        result: None,
+        receipts,
+        total_gas: 0,
+        total_fee: 0,
+    }
+}
+
+fn client_status(status: TxStatus, block_height: u32) -> TransactionStatus {
+    match status {
+        TxStatus::Success { receipts } => TransactionStatus::Success {
+            block_height: block_height.into(),
+            time: Tai64::now(),
+            program_state: None,
+            receipts,
+            total_gas: 0,
+            total_fee: 0,
+        },
+        TxStatus::Submitted => TransactionStatus::Submitted {
+            submitted_at: Tai64::now(),
+        },
+        TxStatus::SqueezedOut { reason } => TransactionStatus::SqueezedOut { reason },
+        TxStatus::Revert {
+            receipts,
+            reason,
+            revert_id,
+        } => TransactionStatus::Failure {
+            block_height: block_height.into(),
+            time: Tai64::now(),
+            reason,
+            program_state: Some(ProgramState::Revert(revert_id)),
+            receipts,
+            total_gas: 0,
+            total_fee: 0,
+        },
+    }
+}
+
+fn client_coin(coin: &Coin) -> ClientCoin {
+    ClientCoin {
+        amount: coin.amount,
+        block_created: coin.block_created,
+        tx_created_idx: 0,
+        asset_id: coin.asset_id,
+        utxo_id: coin.utxo_id,
+        owner: (&coin.owner).into(),
+    }
+}
+
+fn client_message(message: &Message) -> ClientMessage {
+    ClientMessage {
+        amount: message.amount,
+        sender: (&message.sender).into(),
+        recipient: (&message.recipient).into(),
+        nonce: message.nonce,
+        data: message.data.clone(),
+        da_height: message.da_height,
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for MockProvider {
+    async fn health(&self) -> io::Result<bool> {
+        Ok(true)
+    }
+
+    async fn chain_info(&self) -> io::Result<ChainInfo> {
+        let state = self.state();
+
+        Ok(transport::chain_info(
+            "mock".to_string(),
+            state.block_height,
+            Tai64::now(),
+            state.consensus_parameters.clone(),
+        ))
+    }
+
+    async fn await_transaction_commit(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        self.status(id)
+    }
+
+    async fn node_info(&self) -> io::Result<NodeInfo> {
+        let Some(node_version) = self.state().node_version.clone() else {
+            return transport::unsupported("node_info");
+        };
+
+        Ok(NodeInfo {
+            utxo_validation: true,
+            vm_backtrace: false,
+            max_tx: u64::MAX,
+            max_depth: u64::MAX,
+            node_version,
+        })
+    }
+
+    async fn submit_and_await_commit(&self, tx: &Transaction) -> io::Result<TransactionStatus> {
+        let id = self.submit(tx).await?;
+
+        self.status(&id)
+    }
+
+    async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        let mut state = self.state();
+        let id = tx.id(&state.consensus_parameters.chain_id());
+        let status = state
+            .submissions
+            .pop_front()
+            .unwrap_or(TxStatus::Success { receipts: vec![] });
+
+        state.statuses.insert(id, status);
+        state.submitted.push(tx.clone());
+
+        Ok(id)
+    }
+
+    async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        self.status(id)
+    }
+
+    async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+        let mut state = self.state();
+        state.gas_price_requests += 1;
+
+        Ok(LatestGasPrice {
+            gas_price: state.gas_price,
+            block_height: BlockHeight::from(state.block_height),
+        })
+    }
+
+    async fn estimate_gas_price(&self, _block_horizon: u32) -> io::Result<EstimateGasPrice> {
+        let mut state = self.state();
+        state.gas_price_requests += 1;
+
+        Ok(EstimateGasPrice {
+            gas_price: state.gas_price,
+        })
+    }
+
+    async fn dry_run(&self, tx: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        self.execute(tx)
+    }
+
+    async fn dry_run_opt(
+        &self,
+        tx: &[Transaction],
+        _utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        self.execute(tx)
+    }
+
+    async fn da_compressed_block(&self, height: BlockHeight) -> io::Result<Option<Vec<u8>>> {
+        Ok(self
+            .state()
+            .da_compressed_blocks
+            .get(&u32::from(height))
+            .cloned())
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn raw_query(
+        &self,
+        query: &str,
+        _variables: &serde_json::Value,
+    ) -> io::Result<serde_json::Value> {
+        let state = self.state();
+        if let Some(message) = state.raw_query_errors.get(query) {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Response errors; {message}"),
+            ));
+        }
+
+        state
+            .raw_query_responses
+            .get(query)
+            .cloned()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no response is programmed for the query `{query}`"),
+                )
+            })
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn raw_subscription(
+        &self,
+        subscription: &str,
+        _variables: &serde_json::Value,
+    ) -> io::Result<RawSubscription> {
+        let events = self
+            .state()
+            .raw_subscription_events
+            .get(subscription)
+            .cloned()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no events are programmed for the subscription `{subscription}`"),
+                )
+            })?;
+
+        Ok(Box::pin(futures::stream::iter(events.into_iter().map(Ok))))
+    }
+
+    async fn coins(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ClientCoin, String>> {
+        let results = self
+            .state()
+            .coins
+            .iter()
+            .filter(|coin| Address::from(&coin.owner) == *owner)
+            .filter(|coin| asset_id.map_or(true, |asset_id| coin.asset_id == *asset_id))
+            .map(client_coin)
+            .collect();
+
+        Ok(PaginatedResult {
+            cursor: None,
+            results,
+            has_next_page: false,
+            has_previous_page: false,
+        })
+    }
+
+    async fn coin(&self, id: &UtxoId) -> io::Result<Option<ClientCoin>> {
+        Ok(self
+            .state()
+            .coins
+            .iter()
+            .find(|coin| coin.utxo_id == *id)
+            .map(client_coin))
+    }
+
+    async fn coins_to_spend(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<CoinType>>> {
+        let state = self.state();
+        let excluded_utxos = excluded_ids.map(|(utxos, _)| utxos).unwrap_or_default();
+
+        spend_query
+            .into_iter()
+            .map(|(asset_id, amount, max_inputs)| {
+                let candidates = state.coins.iter().filter(|coin| {
+                    Address::from(&coin.owner) == *owner
+                        && coin.asset_id == asset_id
+                        && !excluded_utxos.contains(&coin.utxo_id)
+                });
+                let max_inputs = max_inputs.map_or(usize::MAX, |max| max as usize);
+
+                let mut total = 0;
+                let mut coins = vec![];
+                for coin in candidates.take(max_inputs) {
+                    if total >= amount {
+                        break;
+                    }
+                    total += coin.amount;
+                    coins.push(CoinType::Coin(client_coin(coin)));
+                }
+
+                if total < amount {
+                    return Err(io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("not enough coins to fit the target of {amount} {asset_id}"),
+                    ));
+                }
+
+                Ok(coins)
+            })
+            .collect()
+    }
+
+    async fn messages(
+        &self,
+        owner: Option<&Address>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ClientMessage, String>> {
+        let state = self.state();
+        let owned = state
+            .messages
+            .iter()
+            .filter(|message| {
+                owner.map_or(true, |owner| Address::from(&message.recipient) == *owner)
+            })
+            .collect::<Vec<_>>();
+
+        // The cursor is the number of messages already returned.
+        let start = request
+            .cursor
+            .and_then(|cursor| cursor.parse::<usize>().ok())
+            .unwrap_or_default()
+            .min(owned.len());
+        let end = start.saturating_add(request.results).min(owned.len());
+
+        Ok(PaginatedResult {
+            cursor: Some(end.to_string()),
+            results: owned[start..end]
+                .iter()
+                .copied()
+                .map(client_message)
+                .collect(),
+            has_next_page: end < owned.len(),
+            has_previous_page: start > 0,
+        })
+    }
+
+    async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> io::Result<u64> {
+        let state = self.state();
+        let asset_id = asset_id.unwrap_or(state.consensus_parameters.base_asset_id());
+
+        Ok(state
+            .balances
+            .get(&(*owner, *asset_id))
+            .copied()
+            .unwrap_or_default())
+    }
+
+    async fn balances(
+        &self,
+        owner: &Address,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Balance, String>> {
+        let results = self
+            .state()
+            .balances
+            .iter()
+            .filter(|((balance_owner, _), _)| balance_owner == owner)
+            .map(|((owner, asset_id), amount)| Balance {
+                owner: *owner,
+                amount: *amount,
+                asset_id: *asset_id,
+            })
+            .collect();
+
+        Ok(PaginatedResult {
+            cursor: None,
+            results,
+            has_next_page: false,
+            has_previous_page: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use fuel_tx::{Policies, Transaction as FuelTransaction};
+    use fuels_core::types::{
+        bech32::Bech32Address, coin::CoinStatus, errors::Result, message::MessageStatus,
+        transaction::ScriptTransaction,
+    };
+    use futures::{StreamExt, TryStreamExt};
+
+    use super::*;
+    use crate::{
+        provider::{AwaitCommitOptions, CommitOutcome, CommitTimeoutBehavior},
+        wallet::Wallet,
+        ViewOnlyAccount,
+    };
+
+    fn given_a_script_tx() -> ScriptTransaction {
+        FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .into()
+    }
+
+    #[tokio::test]
+    async fn balances_and_coins_are_served_from_the_mock() -> Result<()> {
+        let owner = Bech32Address::from(Address::from([2; 32]));
+        let asset_id = AssetId::from([1; 32]);
+        let coin = Coin {
+            amount: 60,
+            block_created: 0,
+            asset_id,
+            utxo_id: UtxoId::default(),
+            owner: owner.clone(),
+            status: CoinStatus::Unspent,
+        };
+        let mock = MockProvider::new()
+            .with_balance(&owner, AssetId::zeroed(), 100)
+            .with_coin(coin);
+        let wallet = Wallet::from_address(owner, Some(mock.provider()));
+
+        assert_eq!(wallet.get_asset_balance(&AssetId::zeroed()).await?, 100);
+        assert_eq!(wallet.get_asset_balance(&asset_id).await?, 60);
+        assert_eq!(wallet.get_balances().await?.len(), 2);
+
+        let resources = wallet.get_spendable_resources(asset_id, 50).await?;
+        assert_eq!(resources.len(), 1);
+
+        let err = wallet
+            .get_spendable_resources(asset_id, 61)
+            .await
+            .expect_err("should fail");
+        assert!(err.to_string().contains("not enough coins"));
+
+        Ok(())
+    }
+
+    fn given_a_message(recipient: &Bech32Address, nonce: u8, da_height: u64) -> Message {
+        Message {
+            amount: 10,
+            sender: Bech32Address::from(Address::from([1; 32])),
+            recipient: recipient.clone(),
+            nonce: Nonce::from([nonce; 32]),
+            data: vec![],
+            da_height,
+            status: MessageStatus::Unspent,
+        }
+    }
+
+    #[tokio::test]
+    async fn subscribed_messages_are_yielded_as_they_arrive() -> Result<()> {
+        let owner = Bech32Address::from(Address::from([2; 32]));
+        let other = Bech32Address::from(Address::from([3; 32]));
+        let mock = MockProvider::new().with_message(given_a_message(&owner, 1, 1));
+        let provider = mock.provider();
+
+        let mut messages = provider
+            .subscribe_messages(&owner, Duration::from_millis(10))
+            .boxed();
+        let no_message = tokio::time::timeout(Duration::from_millis(50), messages.next()).await;
+        assert!(no_message.is_err(), "existing messages are not yielded");
+
+        {
+            let mut state = mock.state();
+            state.messages.push(given_a_message(&other, 2, 2));
+            state.messages.push(given_a_message(&owner, 3, 2));
+        }
+        let message = messages.try_next().await?.expect("stream is endless");
+        assert_eq!(message.nonce, Nonce::from([3; 32]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn messages_since_a_da_height_are_yielded_first() -> Result<()> {
+        let owner = Bech32Address::from(Address::from([2; 32]));
+        // More messages than fit in a page.
+        let mock = (0..150).fold(MockProvider::new(), |mock, nonce| {
+            mock.with_message(given_a_message(&owner, nonce, u64::from(nonce)))
+        });
+        let provider = mock.provider();
+
+        let mut messages = provider
+            .subscribe_messages_since(&owner, 140, Duration::from_millis(10))
+            .boxed();
+
+        let mut nonces = vec![];
+        for _ in 140..150 {
+            let message = messages.try_next().await?.expect("stream is endless");
+            nonces.push(message.nonce);
+        }
+        assert_eq!(
+            nonces,
+            (140..150)
+                .map(|nonce| Nonce::from([nonce; 32]))
+                .collect::<Vec<_>>()
+        );
+
+        mock.state().messages.push(given_a_message(&owner, 200, 1));
+        let message = messages.try_next().await?.expect("stream is endless");
+        assert_eq!(message.nonce, Nonce::from([200; 32]));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dry_runs_and_submissions_return_the_programmed_statuses() -> Result<()> {
+        let mock = MockProvider::new()
+            .with_dry_run_result(TxStatus::Revert {
+                receipts: vec![],
+                reason: "Revert(42)".to_string(),
+                revert_id: 42,
+            })
+            .with_submission_result(TxStatus::SqueezedOut {
+                reason: "too expensive".to_string(),
+            });
+        let provider = mock.provider();
+
+        let status = provider.dry_run(given_a_script_tx()).await?;
+        assert!(matches!(status, TxStatus::Revert { revert_id: 42, .. }));
+
+        let status = provider.dry_run(given_a_script_tx()).await?;
+        assert!(matches!(status, TxStatus::Success { .. }));
+
+        let tx: FuelTransaction = given_a_script_tx().into();
+        let id = mock.submit(&tx).await?;
+
+        let status = provider.tx_status(&id).await?;
+        assert!(matches!(status, TxStatus::SqueezedOut { reason } if reason == "too expensive"));
+        assert_eq!(mock.submitted_transactions(), vec![tx]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn awaiting_the_commit_can_time_out() -> Result<()> {
+        let mock = MockProvider::new().with_submission_result(TxStatus::Submitted);
+        let provider = mock.provider();
+        let tx_id = mock.submit(&given_a_script_tx().into()).await?;
+
+        // ANCHOR: await_commit_options
+        let options = AwaitCommitOptions::default()
+            .with_timeout(Duration::from_millis(50))
+            .with_poll_interval(Duration::from_millis(10))
+            .with_on_timeout(CommitTimeoutBehavior::ReturnTxId);
+
+        match provider
+            .await_transaction_commit_with_options(tx_id, options.clone())
+            .await?
+        {
+            CommitOutcome::Committed(tx_status) => tx_status.check(None)?,
+            CommitOutcome::Pending(tx_id) => println!("`{tx_id}` is still pending"),
+        }
+        // ANCHOR_END: await_commit_options
+
+        let outcome = provider
+            .await_transaction_commit_with_options(tx_id, options.clone())
+            .await?;
+        assert!(matches!(outcome, CommitOutcome::Pending(id) if id == tx_id));
+
+        let err = provider
+            .await_transaction_commit_with_options(
+                tx_id,
+                options.clone().with_on_timeout(CommitTimeoutBehavior::Fail),
+            )
+            .await
+            .expect_err("should fail");
+        assert!(err.to_string().contains("was not committed within 50ms"));
+
+        let tx_id = mock.submit(&given_a_script_tx().into()).await?;
+        let outcome = provider
+            .await_transaction_commit_with_options(tx_id, options)
+            .await?;
+        assert!(matches!(
+            outcome,
+            CommitOutcome::Committed(TxStatus::Success { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn awaiting_confirmations_of_a_squeezed_out_tx_fails() -> Result<()> {
+        let mock = MockProvider::new().with_submission_result(TxStatus::SqueezedOut {
+            reason: "too expensive".to_string(),
+        });
+        let provider = mock.provider();
+        let tx_id = mock.submit(&given_a_script_tx().into()).await?;
+
+        let err = provider
+            .await_confirmations(&tx_id, 1, Duration::from_millis(10))
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("too expensive"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn awaiting_the_message_proof_of_a_failed_tx_fails() -> Result<()> {
+        let mock = MockProvider::new().with_submission_result(TxStatus::Revert {
+            receipts: vec![],
+            reason: "Revert(42)".to_string(),
+            revert_id: 42,
+        });
+        let provider = mock.provider();
+        let tx_id = mock.submit(&given_a_script_tx().into()).await?;
+
+        let err = provider
+            .await_message_proof(
+                &tx_id,
+                &Nonce::default(),
+                Duration::from_millis(10),
+                Duration::from_secs(1),
+            )
+            .await
+            .expect_err("should fail");
+
+        assert!(err.to_string().contains("Revert(42)"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn status_changes_are_reported_while_awaiting_the_commit() -> Result<()> {
+        let mock = MockProvider::new().with_submission_result(TxStatus::Submitted);
+        let provider = mock.provider();
+        let tx_id = mock.submit(&given_a_script_tx().into()).await?;
+
+        let statuses = Arc::new(Mutex::new(vec![]));
+        let options = AwaitCommitOptions::default()
+            .with_poll_interval(Duration::from_millis(10))
+            .with_on_status_change({
+                let statuses = statuses.clone();
+                move |tx_status| {
+                    let status = format!("{tx_status:?}");
+                    statuses.lock().expect("not poisoned").push(status)
+                }
+            });
+
+        let awaiting = provider.await_transaction_commit_with_options(tx_id, options);
+        let squeeze_out = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            mock.state().statuses.insert(
+                tx_id,
+                TxStatus::SqueezedOut {
+                    reason: "too expensive".to_string(),
+                },
+            );
+        };
+        let (outcome, _) = tokio::join!(awaiting, squeeze_out);
+
+        assert!(matches!(
+            outcome?,
+            CommitOutcome::Committed(TxStatus::SqueezedOut { .. })
+        ));
+        assert_eq!(
+            *statuses.lock().expect("not poisoned"),
+            ["Submitted", "SqueezedOut { reason: \"too expensive\" }"]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn da_compressed_blocks_are_served_from_the_mock() -> Result<()> {
+        let provider = MockProvider::new()
+            .with_da_compressed_block(3, [1, 2, 3])
+            .provider();
+
+        // ANCHOR: da_compressed_block
+        let block = provider
+            .da_compressed_block(3.into())
+            .await?
+            .expect("block is programmed");
+        assert_eq!(block.height, 3);
+        assert_eq!(block.bytes, vec![1, 2, 3]);
+        assert_eq!(block.size(), 3);
+        // ANCHOR_END: da_compressed_block
+
+        assert!(provider.da_compressed_block(4.into()).await?.is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    #[tokio::test]
+    async fn raw_queries_are_deserialized_into_the_given_type() -> Result<()> {
+        let query = "query($height: U32!) { block(height: $height) { id } }";
+        let provider = MockProvider::new()
+            .with_raw_query_response(query, serde_json::json!({ "block": { "id": "0x01" } }))
+            .provider();
+
+        // ANCHOR: raw_query
+        #[derive(serde::Deserialize)]
+        struct BlockQuery {
+            block: Option<BlockId>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct BlockId {
+            id: String,
+        }
+
+        let response: BlockQuery = provider
+            .raw_query(query, serde_json::json!({ "height": "3" }))
+            .await?;
+        // ANCHOR_END: raw_query
+
+        assert_eq!(
+            response.block.map(|block| block.id).as_deref(),
+            Some("0x01")
+        );
+
+        let err = provider
+            .raw_query::<u64>(query, ())
+            .await
+            .expect_err("should fail");
+        assert!(err.to_string().contains("could not deserialize"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    #[tokio::test]
+    async fn raw_subscriptions_yield_the_programmed_events() -> Result<()> {
+        let subscription =
+            "subscription($id: TransactionId!) { statusChange(id: $id) { type: __typename } }";
+        let provider = MockProvider::new()
+            .with_raw_subscription_events(
+                subscription,
+                vec![
+                    serde_json::json!({ "statusChange": { "type": "SubmittedStatus" } }),
+                    serde_json::json!({ "statusChange": { "type": "SuccessStatus" } }),
+                ],
+            )
+            .provider();
+
+        // ANCHOR: raw_subscription
+        #[derive(serde::Deserialize)]
+        struct StatusChange {
+            #[serde(rename = "statusChange")]
+            status_change: Status,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Status {
+            #[serde(rename = "type")]
+            kind: String,
+        }
+
+        let tx_id = format!("{:#x}", TxId::zeroed());
+        let statuses = provider
+            .raw_subscription::<StatusChange>(subscription, serde_json::json!({ "id": tx_id }))
+            .map_ok(|response| response.status_change.kind)
+            .try_collect::<Vec<_>>()
+            .await?;
+        // ANCHOR_END: raw_subscription
+
+        assert_eq!(statuses, ["SubmittedStatus", "SuccessStatus"]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unprogrammed_requests_are_unsupported() {
+        let provider = MockProvider::new().provider();
+
+        let err = provider.node_info().await.expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("`node_info` is not supported by this transport"));
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+    fs,
+    path::Path,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use fuels_core::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    Address,
+};
+
+/// Maps human-readable names, e.g. `"treasury"`, to addresses. Set one with
+/// [`Provider::with_name_resolver`](crate::provider::Provider::with_name_resolver) to pass names
+/// wherever a [`Recipient`] is accepted.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait NameResolver: Debug + Send + Sync {
+    /// Returns the address registered under `name`, if any.
+    async fn resolve(&self, name: &str) -> Result<Option<Bech32Address>>;
+}
+
+/// The recipient of a transfer, given either by address or by a name to resolve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recipient {
+    Address(Bech32Address),
+    Name(String),
+}
+
+impl Recipient {
+    /// Strings holding a bech32 or hex address are taken as addresses, everything else as names.
+    pub fn parse(s: &str) -> Self {
+        Bech32Address::from_str(s)
+            .ok()
+            .or_else(|| Address::from_str(s).ok().map(Into::into))
+            .map(Self::Address)
+            .unwrap_or_else(|| Self::Name(s.to_string()))
+    }
+}
+
+impl Display for Recipient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{address}"),
+            Self::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl From<Bech32Address> for Recipient {
+    fn from(address: Bech32Address) -> Self {
+        Self::Address(address)
+    }
+}
+
+impl From<&Bech32Address> for Recipient {
+    fn from(address: &Bech32Address) -> Self {
+        Self::Address(address.clone())
+    }
+}
+
+impl From<Address> for Recipient {
+    fn from(address: Address) -> Self {
+        Self::Address(address.into())
+    }
+}
+
+impl From<&str> for Recipient {
+    fn from(s: &str) -> Self {
+        Self::parse(s)
+    }
+}
+
+impl From<String> for Recipient {
+    fn from(s: String) -> Self {
+        Self::parse(&s)
+    }
+}
+
+/// A [`NameResolver`] backed by a map. Clones share the entries.
+///
+/// Address books can be loaded from and saved to files with one `name address` entry per line.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct AddressBook {
+    entries: Arc<RwLock<HashMap<String, Bech32Address>>>,
+}
+
+impl AddressBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_entry(self, name: impl Into<String>, address: impl Into<Bech32Address>) -> Self {
+        self.insert(name, address);
+
+        self
+    }
+
+    /// Registers `address` under `name`, returning the address registered before, if any.
+    pub fn insert(
+        &self,
+        name: impl Into<String>,
+        address: impl Into<Bech32Address>,
+    ) -> Option<Bech32Address> {
+        self.entries
+            .write()
+            .expect("not poisoned")
+            .insert(name.into(), address.into())
+    }
+
+    pub fn remove(&self, name: &str) -> Option<Bech32Address> {
+        self.entries.write().expect("not poisoned").remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<Bech32Address> {
+        self.entries
+            .read()
+            .expect("not poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path).map_err(|e| {
+            error!(
+                Other,
+                "failed to read address book `{}`: {e}",
+                path.display()
+            )
+        })?;
+
+        contents.parse()
+    }
+
+    /// Writes the entries, sorted by name, to `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut entries = self
+            .entries
+            .read()
+            .expect("not poisoned")
+            .iter()
+            .map(|(name, address)| format!("{name} {address}\n"))
+            .collect::<Vec<_>>();
+        entries.sort();
+
+        Ok(fs::write(path, entries.concat())?)
+    }
+}
+
+impl FromStr for AddressBook {
+    type Err = fuels_core::types::errors::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let address_book = Self::new();
+
+        for (line_number, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (name, address) = line
+                .split_once(char::is_whitespace)
+                .map(|(name, address)| (name, address.trim()))
+                .ok_or_else(|| {
+                    error!(
+                        Other,
+                        "line {} of the address book is not a `name address` entry",
+                        line_number + 1
+                    )
+                })?;
+
+            let Recipient::Address(address) = Recipient::parse(address) else {
+                return Err(error!(
+                    Other,
+                    "line {} of the address book has an invalid address `{address}`",
+                    line_number + 1
+                ));
+            };
+
+            address_book.insert(name, address);
+        }
+
+        Ok(address_book)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl NameResolver for AddressBook {
+    async fn resolve(&self, name: &str) -> Result<Option<Bech32Address>> {
+        Ok(self.get(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_parsed_as_addresses_or_names() {
+        let address = Bech32Address::from(Address::from([1; 32]));
+
+        assert_eq!(
+            Recipient::from(address.to_string()),
+            Recipient::Address(address.clone())
+        );
+        assert_eq!(
+            Recipient::from(format!("{:#x}", Address::from([1; 32])).as_str()),
+            Recipient::Address(address)
+        );
+        assert_eq!(
+            Recipient::from("treasury"),
+            Recipient::Name("treasury".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn address_books_are_saved_and_loaded() -> Result<()> {
+        let treasury = Bech32Address::from(Address::from([1; 32]));
+        let path = tempfile::NamedTempFile::new()?;
+        AddressBook::new()
+            .with_entry("treasury", treasury.clone())
+            .save(path.path())?;
+
+        let address_book = AddressBook::load(path.path())?;
+
+        assert_eq!(address_book.resolve("treasury").await?, Some(treasury));
+        assert_eq!(address_book.resolve("unknown").await?, None);
+
+        let err = "# comment\ntreasury not-an-address"
+            .parse::<AddressBook>()
+            .expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("line 2 of the address book has an invalid address `not-an-address`"));
+
+        Ok(())
+    }
+}
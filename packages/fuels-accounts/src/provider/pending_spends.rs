@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use fuel_tx::TxId;
+use fuel_types::AssetId;
+use fuels_core::types::{bech32::Bech32Address, coin_type_id::CoinTypeId, errors::Result};
+
+/// Keeps track of the resources used by transactions that were submitted but aren't confirmed
+/// yet, so that [`Provider::get_spendable_resources`](crate::provider::Provider::get_spendable_resources)
+/// doesn't select them again. Set one with
+/// [`Provider::with_pending_spends`](crate::provider::Provider::with_pending_spends).
+///
+/// The `coin-cache` feature already covers the transactions submitted through the same
+/// provider. A tracker is meant for the cases it can't see, e.g. transactions submitted by other
+/// processes of a service, by keeping the pending spends in a shared store.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait PendingSpends: Debug + Send + Sync {
+    /// Returns the resources of `owner` with asset `asset_id` used by transactions that aren't
+    /// confirmed yet.
+    async fn pending_resources(
+        &self,
+        owner: &Bech32Address,
+        asset_id: AssetId,
+    ) -> Result<Vec<CoinTypeId>>;
+
+    /// Called after the provider submitted the transaction `tx_id`, spending `resources` grouped
+    /// by owner and asset, whichever method sent it and before its commitment is awaited. Does
+    /// nothing by default. The transaction is already submitted, so failures should be handled
+    /// by the tracker itself.
+    async fn record_submission(
+        &self,
+        _tx_id: TxId,
+        _resources: &[((Bech32Address, AssetId), Vec<CoinTypeId>)],
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use fuel_tx::Output;
+    use fuels_core::types::{
+        coin::Coin,
+        coin_type::CoinType,
+        input::Input,
+        transaction::{ScriptTransaction, Transaction, TxPolicies},
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+    };
+
+    use super::*;
+    use crate::{
+        provider::{AwaitCommitOptions, MockProvider, Provider},
+        wallet::WalletUnlocked,
+        ViewOnlyAccount,
+    };
+
+    #[derive(Debug, Clone, Default)]
+    struct RecordedSubmissions(Arc<Mutex<Vec<(TxId, Vec<CoinTypeId>)>>>);
+
+    #[async_trait]
+    impl PendingSpends for RecordedSubmissions {
+        async fn pending_resources(
+            &self,
+            _owner: &Bech32Address,
+            _asset_id: AssetId,
+        ) -> Result<Vec<CoinTypeId>> {
+            Ok(vec![])
+        }
+
+        async fn record_submission(
+            &self,
+            tx_id: TxId,
+            resources: &[((Bech32Address, AssetId), Vec<CoinTypeId>)],
+        ) {
+            let ids = resources
+                .iter()
+                .flat_map(|(_, ids)| ids.iter().cloned())
+                .collect();
+            self.0.lock().expect("not poisoned").push((tx_id, ids));
+        }
+    }
+
+    async fn build_transfer(provider: &Provider, coin: Coin) -> Result<ScriptTransaction> {
+        let wallet = WalletUnlocked::new_random(Some(provider.clone()));
+        let coin = Coin {
+            owner: wallet.address().clone(),
+            ..coin
+        };
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(
+            vec![Input::resource_signed(CoinType::Coin(coin))],
+            vec![Output::change(
+                wallet.address().into(),
+                0,
+                AssetId::zeroed(),
+            )],
+            TxPolicies::default(),
+        );
+        tb.add_signer(wallet)?;
+
+        tb.build(provider).await
+    }
+
+    #[tokio::test]
+    async fn transactions_are_recorded_whichever_way_they_are_sent() -> Result<()> {
+        let recorded = RecordedSubmissions::default();
+        let provider = MockProvider::new()
+            .provider()
+            .with_pending_spends(recorded.clone());
+        let coin = |amount| Coin {
+            amount,
+            ..Default::default()
+        };
+
+        let tx = build_transfer(&provider, coin(1000)).await?;
+        let awaited_id = tx.id(provider.chain_id());
+        provider.send_transaction_and_await_commit(tx).await?;
+
+        let tx = build_transfer(&provider, coin(2000)).await?;
+        let sent_id = provider.send_transaction(tx).await?;
+
+        let tx = build_transfer(&provider, coin(3000)).await?;
+        let with_options_id = tx.id(provider.chain_id());
+        provider
+            .send_transaction_and_await_commit_with_options(tx, AwaitCommitOptions::default())
+            .await?;
+
+        let coin_id = CoinTypeId::UtxoId(Default::default());
+        assert_eq!(
+            *recorded.0.lock().expect("not poisoned"),
+            [
+                (awaited_id, vec![coin_id.clone()]),
+                (sent_id, vec![coin_id.clone()]),
+                (with_options_id, vec![coin_id])
+            ]
+        );
+
+        Ok(())
+    }
+}
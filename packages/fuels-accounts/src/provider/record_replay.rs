@@ -0,0 +1,1856 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use fuel_core_client::client::{
+    pagination::{PaginatedResult, PaginationRequest},
+    types::{
+        block::{Consensus, Header},
+        coins::{CoinType, MessageCoin},
+        gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus,
+        primitives::{BlockId, TransactionId},
+        Balance, Block, ChainInfo, Coin, ContractBalance, MerkleProof, Message, MessageProof,
+        NodeInfo, TransactionResponse, TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::{
+    fuel_vm::state::ProgramState,
+    services::executor::{TransactionExecutionResult, TransactionExecutionStatus},
+};
+use fuel_tx::{ConsensusParameters, Receipt, Transaction, TxId, UtxoId};
+use fuel_types::{Address, AssetId, BlockHeight, Bytes32, ContractId, Nonce};
+use fuels_core::types::errors::{error, Result};
+use futures::StreamExt;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tai64::Tai64;
+
+use super::{
+    transport::{self, RawSubscription, Transport},
+    Provider,
+};
+
+/// A request sent to the node, identifying the recorded response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+enum Request {
+    Health,
+    Transaction {
+        id: TxId,
+    },
+    ChainInfo,
+    AwaitTransactionCommit {
+        id: TxId,
+    },
+    SubmitAndAwaitCommit {
+        tx: Transaction,
+    },
+    Submit {
+        tx: Transaction,
+    },
+    TransactionStatus {
+        id: TxId,
+    },
+    NodeInfo,
+    LatestGasPrice,
+    EstimateGasPrice {
+        block_horizon: u32,
+    },
+    DryRun {
+        txs: Vec<Transaction>,
+        utxo_validation: Option<bool>,
+    },
+    Coins {
+        owner: Address,
+        asset_id: Option<AssetId>,
+        page: String,
+    },
+    Coin {
+        id: UtxoId,
+    },
+    CoinsToSpend {
+        owner: Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    },
+    Balance {
+        owner: Address,
+        asset_id: Option<AssetId>,
+    },
+    ContractBalance {
+        id: ContractId,
+        asset_id: Option<AssetId>,
+    },
+    ContractBalances {
+        id: ContractId,
+        page: String,
+    },
+    Balances {
+        owner: Address,
+        page: String,
+    },
+    Transactions {
+        page: String,
+    },
+    TransactionsByOwner {
+        owner: Address,
+        page: String,
+    },
+    ProduceBlocks {
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    },
+    Block {
+        id: BlockId,
+    },
+    BlockByHeight {
+        height: u32,
+    },
+    DaCompressedBlock {
+        height: u32,
+    },
+    Blocks {
+        page: String,
+    },
+    Messages {
+        owner: Option<Address>,
+        page: String,
+    },
+    MessageStatus {
+        nonce: Nonce,
+    },
+    MessageProof {
+        transaction_id: TxId,
+        nonce: Nonce,
+        commit_block_id: Option<BlockId>,
+        commit_block_height: Option<u32>,
+    },
+    RawQuery {
+        query: String,
+        variables: serde_json::Value,
+    },
+    RawSubscription {
+        subscription: String,
+        variables: serde_json::Value,
+    },
+}
+
+impl Request {
+    fn key(&self) -> String {
+        serde_json::to_string(self).expect("requests are serializable")
+    }
+}
+
+/// A recorded response, or the error message of a failed request.
+type Recorded = std::result::Result<serde_json::Value, String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: Request,
+    response: Recorded,
+}
+
+/// Appends interactions to the recording file, one JSON object per line. The file is created,
+/// or truncated, when the first interaction is recorded.
+#[derive(Debug)]
+struct Recorder {
+    path: PathBuf,
+    file: Mutex<Option<File>>,
+    /// The error of a subscription that failed to be recorded once dropped, reported by the next
+    /// recorded request since there was no caller to report it to.
+    dropped_error: Mutex<Option<String>>,
+}
+
+impl Recorder {
+    fn append(&self, interaction: &Interaction) -> io::Result<()> {
+        self.write(interaction)?;
+
+        match self.dropped_error.lock().expect("not poisoned").take() {
+            Some(err) => Err(io::Error::new(io::ErrorKind::Other, err)),
+            None => Ok(()),
+        }
+    }
+
+    fn write(&self, interaction: &Interaction) -> io::Result<()> {
+        let mut line = serde_json::to_vec(interaction)?;
+        line.push(b'\n');
+
+        let mut file = self.file.lock().expect("not poisoned");
+        if file.is_none() {
+            *file = Some(File::create(&self.path)?);
+        }
+
+        file.as_mut().expect("file was created").write_all(&line)
+    }
+
+    fn report_dropped_error(&self, request: &Request, err: io::Error) {
+        let err = format!(
+            "could not record the subscription {request:?} to `{}`: {err}",
+            self.path.display()
+        );
+        #[cfg(feature = "tracing")]
+        tracing::warn!("{err}");
+
+        *self.dropped_error.lock().expect("not poisoned") = Some(err);
+    }
+}
+
+/// Wraps a [`Transport`] and records every request with its response to a file, to be served
+/// back by a [`ReplayTransport`].
+///
+/// Each interaction is appended to the file as soon as the response is received. A
+/// subscription is recorded with all the events it yielded once it is dropped. If that fails,
+/// the next recorded request reports the error. The consensus of blocks isn't recorded.
+#[derive(Debug)]
+pub struct RecordingTransport<T> {
+    inner: T,
+    recorder: Arc<Recorder>,
+}
+
+impl<T: Transport> RecordingTransport<T> {
+    pub fn new(inner: T, path: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            recorder: Arc::new(Recorder {
+                path: path.into(),
+                file: Default::default(),
+                dropped_error: Default::default(),
+            }),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.recorder.path
+    }
+
+    fn record<R, M: Serialize>(
+        &self,
+        request: Request,
+        response: io::Result<R>,
+        to_recorded: impl FnOnce(&R) -> io::Result<M>,
+    ) -> io::Result<R> {
+        let recorded = match &response {
+            Ok(response) => Ok(serde_json::to_value(to_recorded(response)?)?),
+            Err(err) => Err(err.to_string()),
+        };
+
+        self.recorder.append(&Interaction {
+            request,
+            response: recorded,
+        })?;
+
+        response
+    }
+}
+
+/// Collects the events of a recorded subscription, appending them to the recording once the
+/// subscription is dropped.
+struct SubscriptionRecording {
+    recorder: Arc<Recorder>,
+    request: Request,
+    events: Vec<Recorded>,
+}
+
+impl Drop for SubscriptionRecording {
+    fn drop(&mut self) {
+        let events = std::mem::take(&mut self.events);
+        let interaction = Interaction {
+            request: self.request.clone(),
+            response: serde_json::to_value(events).map_err(|err| err.to_string()),
+        };
+
+        if let Err(err) = self.recorder.write(&interaction) {
+            self.recorder.report_dropped_error(&self.request, err);
+        }
+    }
+}
+
+impl RecordingTransport<FuelClient> {
+    /// Connects to the node at `url` and returns a provider whose requests are recorded to
+    /// `path`. Unlike [`Provider::connect`], the version of the node is not checked.
+    pub async fn connect(url: impl AsRef<str>, path: impl Into<PathBuf>) -> Result<Provider> {
+        let client = FuelClient::new(url.as_ref()).map_err(|e| error!(Provider, "{e}"))?;
+        let transport = Self::new(client, path);
+        let consensus_parameters = transport.chain_info().await?.consensus_parameters;
+
+        Ok(Provider::from_transport(
+            url.as_ref(),
+            transport,
+            consensus_parameters,
+        ))
+    }
+}
+
+/// Serves the requests recorded by a [`RecordingTransport`], so that tests can run without a
+/// node.
+///
+/// A request is answered with the next response recorded for an identical request, so the
+/// replayed test must send the same requests as the recorded one, e.g. by using wallets with
+/// fixed keys. The latest block of the chain info is only replayed with its height and time,
+/// and blocks are replayed without their consensus.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    path: PathBuf,
+    responses: Mutex<HashMap<String, VecDeque<Recorded>>>,
+}
+
+impl ReplayTransport {
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let recording = fs::read_to_string(&path)?;
+
+        let mut responses: HashMap<_, VecDeque<_>> = HashMap::new();
+        for (index, line) in recording.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let Interaction { request, response } = serde_json::from_str(line).map_err(|err| {
+                error!(
+                    Other,
+                    "could not parse line {} of `{}`: {err}",
+                    index + 1,
+                    path.display()
+                )
+            })?;
+
+            responses
+                .entry(request.key())
+                .or_default()
+                .push_back(response);
+        }
+
+        Ok(Self {
+            path,
+            responses: Mutex::new(responses),
+        })
+    }
+
+    /// Returns a provider served by the recorded responses, starting with the chain info
+    /// requested when the recording provider connected.
+    pub async fn provider(self) -> Result<Provider> {
+        let url = format!("replay://{}", self.path.display());
+        let consensus_parameters = self.chain_info().await?.consensus_parameters;
+
+        Ok(Provider::from_transport(url, self, consensus_parameters))
+    }
+
+    fn responses(&self) -> MutexGuard<'_, HashMap<String, VecDeque<Recorded>>> {
+        self.responses.lock().expect("not poisoned")
+    }
+
+    fn replay<M: DeserializeOwned>(&self, request: Request) -> io::Result<M> {
+        let response = self
+            .responses()
+            .get_mut(&request.key())
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "no response to {request:?} is left in `{}`",
+                        self.path.display()
+                    ),
+                )
+            })?;
+
+        match response {
+            Ok(value) => Ok(serde_json::from_value(value)?),
+            Err(err) => Err(io::Error::new(io::ErrorKind::Other, err)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedChainInfo {
+    name: String,
+    height: u32,
+    time: Tai64,
+    consensus_parameters: ConsensusParameters,
+}
+
+impl From<&ChainInfo> for RecordedChainInfo {
+    fn from(chain_info: &ChainInfo) -> Self {
+        Self {
+            name: chain_info.name.clone(),
+            height: chain_info.latest_block.header.height,
+            time: chain_info.latest_block.header.time,
+            consensus_parameters: chain_info.consensus_parameters.clone(),
+        }
+    }
+}
+
+impl From<RecordedChainInfo> for ChainInfo {
+    fn from(recorded: RecordedChainInfo) -> Self {
+        transport::chain_info(
+            recorded.name,
+            recorded.height,
+            recorded.time,
+            recorded.consensus_parameters,
+        )
+    }
+}
+
+/// Only the revert id of the program state is recorded, as it is the only part read by the SDK.
+#[derive(Debug, Serialize, Deserialize)]
+enum RecordedStatus {
+    Submitted {
+        submitted_at: Tai64,
+    },
+    Success {
+        block_height: u32,
+        time: Tai64,
+        receipts: Vec<Receipt>,
+        total_gas: u64,
+        total_fee: u64,
+    },
+    SqueezedOut {
+        reason: String,
+    },
+    Failure {
+        block_height: u32,
+        time: Tai64,
+        reason: String,
+        revert_id: Option<u64>,
+        receipts: Vec<Receipt>,
+        total_gas: u64,
+        total_fee: u64,
+    },
+}
+
+fn revert_id(program_state: &Option<ProgramState>) -> Option<u64> {
+    match program_state {
+        Some(ProgramState::Revert(revert_id)) => Some(*revert_id),
+        _ => None,
+    }
+}
+
+impl From<&TransactionStatus> for RecordedStatus {
+    fn from(status: &TransactionStatus) -> Self {
+        match status.clone() {
+            TransactionStatus::Submitted { submitted_at } => Self::Submitted { submitted_at },
+            TransactionStatus::Success {
+                block_height,
+                time,
+                receipts,
+                total_gas,
+                total_fee,
+                ..
+            } => Self::Success {
+                block_height: block_height.into(),
+                time,
+                receipts,
+                total_gas,
+                total_fee,
+            },
+            TransactionStatus::SqueezedOut { reason } => Self::SqueezedOut { reason },
+            TransactionStatus::Failure {
+                block_height,
+                time,
+                reason,
+                program_state,
+                receipts,
+                total_gas,
+                total_fee,
+                ..
+            } => Self::Failure {
+                block_height: block_height.into(),
+                time,
+                reason,
+                revert_id: revert_id(&program_state),
+                receipts,
+                total_gas,
+                total_fee,
+            },
+        }
+    }
+}
+
+impl From<RecordedStatus> for TransactionStatus {
+    fn from(recorded: RecordedStatus) -> Self {
+        match recorded {
+            RecordedStatus::Submitted { submitted_at } => Self::Submitted { submitted_at },
+            RecordedStatus::Success {
+                block_height,
+                time,
+                receipts,
+                total_gas,
+                total_fee,
+            } => Self::Success {
+                block_height: block_height.into(),
+                time,
+                program_state: None,
+                receipts,
+                total_gas,
+                total_fee,
+            },
+            RecordedStatus::SqueezedOut { reason } => Self::SqueezedOut { reason },
+            RecordedStatus::Failure {
+                block_height,
+                time,
+                reason,
+                revert_id,
+                receipts,
+                total_gas,
+                total_fee,
+            } => Self::Failure {
+                block_height: block_height.into(),
+                time,
+                reason,
+                program_state: revert_id.map(ProgramState::Revert),
+                receipts,
+                total_gas,
+                total_fee,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedExecution {
+    id: TxId,
+    success: bool,
+    revert_id: Option<u64>,
+    receipts: Vec<Receipt>,
+    total_gas: u64,
+    total_fee: u64,
+}
+
+impl From<&TransactionExecutionStatus> for RecordedExecution {
+    fn from(status: &TransactionExecutionStatus) -> Self {
+        let (success, result, receipts, total_gas, total_fee) = match &status.result {
+            TransactionExecutionResult::Success {
+                result,
+                receipts,
+                total_gas,
+                total_fee,
+            } => (true, result, receipts, total_gas, total_fee),
+            TransactionExecutionResult::Failed {
+                result,
+                receipts,
+                total_gas,
+                total_fee,
+            } => (false, result, receipts, total_gas, total_fee),
+        };
+
+        Self {
+            id: status.id,
+            success,
+            revert_id: revert_id(result),
+            receipts: receipts.clone(),
+            total_gas: *total_gas,
+            total_fee: *total_fee,
+        }
+    }
+}
+
+impl From<RecordedExecution> for TransactionExecutionStatus {
+    fn from(recorded: RecordedExecution) -> Self {
+        let RecordedExecution {
+            id,
+            success,
+            revert_id,
+            receipts,
+            total_gas,
+            total_fee,
+        } = recorded;
+        let result = revert_id.map(ProgramState::Revert);
+
+        let result = if success {
+            TransactionExecutionResult::Success {
+                result,
+                receipts,
+                total_gas,
+                total_fee,
+            }
+        } else {
+            TransactionExecutionResult::Failed {
+                result,
+                receipts,
+                total_gas,
+                total_fee,
+            }
+        };
+
+        Self { id, result }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedCoin {
+    amount: u64,
+    block_created: u32,
+    tx_created_idx: u16,
+    asset_id: AssetId,
+    utxo_id: UtxoId,
+    owner: Address,
+}
+
+impl From<&Coin> for RecordedCoin {
+    fn from(coin: &Coin) -> Self {
+        Self {
+            amount: coin.amount,
+            block_created: coin.block_created,
+            tx_created_idx: coin.tx_created_idx,
+            asset_id: coin.asset_id,
+            utxo_id: coin.utxo_id,
+            owner: coin.owner,
+        }
+    }
+}
+
+impl From<RecordedCoin> for Coin {
+    fn from(recorded: RecordedCoin) -> Self {
+        Self {
+            amount: recorded.amount,
+            block_created: recorded.block_created,
+            tx_created_idx: recorded.tx_created_idx,
+            asset_id: recorded.asset_id,
+            utxo_id: recorded.utxo_id,
+            owner: recorded.owner,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum RecordedCoinType {
+    Coin(RecordedCoin),
+    MessageCoin {
+        amount: u64,
+        sender: Address,
+        recipient: Address,
+        nonce: Nonce,
+        da_height: u64,
+    },
+}
+
+impl TryFrom<&CoinType> for RecordedCoinType {
+    type Error = io::Error;
+
+    fn try_from(coin_type: &CoinType) -> io::Result<Self> {
+        match coin_type {
+            CoinType::Coin(coin) => Ok(Self::Coin(coin.into())),
+            CoinType::MessageCoin(message) => Ok(Self::MessageCoin {
+                amount: message.amount,
+                sender: message.sender,
+                recipient: message.recipient,
+                nonce: message.nonce,
+                da_height: message.da_height,
+            }),
+            CoinType::Unknown => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "cannot record a resource of unknown type",
+            )),
+        }
+    }
+}
+
+impl From<RecordedCoinType> for CoinType {
+    fn from(recorded: RecordedCoinType) -> Self {
+        match recorded {
+            RecordedCoinType::Coin(coin) => Self::Coin(coin.into()),
+            RecordedCoinType::MessageCoin {
+                amount,
+                sender,
+                recipient,
+                nonce,
+                da_height,
+            } => Self::MessageCoin(MessageCoin {
+                amount,
+                sender,
+                recipient,
+                nonce,
+                da_height,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedBalance {
+    owner: Address,
+    amount: u64,
+    asset_id: AssetId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedPage<T> {
+    results: Vec<T>,
+    cursor: Option<String>,
+    has_next_page: bool,
+    has_previous_page: bool,
+}
+
+impl<T> RecordedPage<T> {
+    fn record<R>(page: &PaginatedResult<R, String>, to_recorded: impl Fn(&R) -> T) -> Self {
+        Self {
+            results: page.results.iter().map(to_recorded).collect(),
+            cursor: page.cursor.clone(),
+            has_next_page: page.has_next_page,
+            has_previous_page: page.has_previous_page,
+        }
+    }
+
+    fn replay<R>(self, from_recorded: impl Fn(T) -> R) -> PaginatedResult<R, String> {
+        PaginatedResult {
+            results: self.results.into_iter().map(from_recorded).collect(),
+            cursor: self.cursor,
+            has_next_page: self.has_next_page,
+            has_previous_page: self.has_previous_page,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedContractBalance {
+    contract: ContractId,
+    amount: u64,
+    asset_id: AssetId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedTransaction {
+    transaction: Transaction,
+    status: RecordedStatus,
+}
+
+impl From<&TransactionResponse> for RecordedTransaction {
+    fn from(response: &TransactionResponse) -> Self {
+        Self {
+            transaction: response.transaction.clone(),
+            status: RecordedStatus::from(&response.status),
+        }
+    }
+}
+
+impl From<RecordedTransaction> for TransactionResponse {
+    fn from(recorded: RecordedTransaction) -> Self {
+        Self {
+            transaction: recorded.transaction,
+            status: recorded.status.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedNodeInfo {
+    utxo_validation: bool,
+    vm_backtrace: bool,
+    max_tx: u64,
+    max_depth: u64,
+    node_version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedHeader {
+    id: Bytes32,
+    da_height: u64,
+    consensus_parameters_version: u32,
+    state_transition_bytecode_version: u32,
+    transactions_count: u16,
+    message_receipt_count: u32,
+    transactions_root: Bytes32,
+    message_outbox_root: Bytes32,
+    event_inbox_root: Bytes32,
+    height: u32,
+    prev_root: Bytes32,
+    time: Tai64,
+    application_hash: Bytes32,
+}
+
+impl From<&Header> for RecordedHeader {
+    fn from(header: &Header) -> Self {
+        Self {
+            id: header.id,
+            da_height: header.da_height,
+            consensus_parameters_version: header.consensus_parameters_version,
+            state_transition_bytecode_version: header.state_transition_bytecode_version,
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root,
+            message_outbox_root: header.message_outbox_root,
+            event_inbox_root: header.event_inbox_root,
+            height: header.height,
+            prev_root: header.prev_root,
+            time: header.time,
+            application_hash: header.application_hash,
+        }
+    }
+}
+
+impl From<RecordedHeader> for Header {
+    fn from(recorded: RecordedHeader) -> Self {
+        Self {
+            id: recorded.id,
+            da_height: recorded.da_height,
+            consensus_parameters_version: recorded.consensus_parameters_version,
+            state_transition_bytecode_version: recorded.state_transition_bytecode_version,
+            transactions_count: recorded.transactions_count,
+            message_receipt_count: recorded.message_receipt_count,
+            transactions_root: recorded.transactions_root,
+            message_outbox_root: recorded.message_outbox_root,
+            event_inbox_root: recorded.event_inbox_root,
+            height: recorded.height,
+            prev_root: recorded.prev_root,
+            time: recorded.time,
+            application_hash: recorded.application_hash,
+        }
+    }
+}
+
+/// The consensus of the block isn't recorded, as the SDK doesn't read it.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedBlock {
+    id: BlockId,
+    header: RecordedHeader,
+    transactions: Vec<TxId>,
+}
+
+impl From<&Block> for RecordedBlock {
+    fn from(block: &Block) -> Self {
+        Self {
+            id: block.id,
+            header: RecordedHeader::from(&block.header),
+            transactions: block.transactions.clone(),
+        }
+    }
+}
+
+impl From<RecordedBlock> for Block {
+    fn from(recorded: RecordedBlock) -> Self {
+        Self {
+            id: recorded.id,
+            header: recorded.header.into(),
+            consensus: Consensus::Unknown,
+            transactions: recorded.transactions,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedMessage {
+    amount: u64,
+    sender: Address,
+    recipient: Address,
+    nonce: Nonce,
+    data: Vec<u8>,
+    da_height: u64,
+}
+
+impl From<&Message> for RecordedMessage {
+    fn from(message: &Message) -> Self {
+        Self {
+            amount: message.amount,
+            sender: message.sender,
+            recipient: message.recipient,
+            nonce: message.nonce,
+            data: message.data.clone(),
+            da_height: message.da_height,
+        }
+    }
+}
+
+impl From<RecordedMessage> for Message {
+    fn from(recorded: RecordedMessage) -> Self {
+        Self {
+            amount: recorded.amount,
+            sender: recorded.sender,
+            recipient: recorded.recipient,
+            nonce: recorded.nonce,
+            data: recorded.data,
+            da_height: recorded.da_height,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum RecordedMessageStatus {
+    Unspent,
+    Spent,
+    NotFound,
+}
+
+impl From<&MessageStatus> for RecordedMessageStatus {
+    fn from(status: &MessageStatus) -> Self {
+        match status {
+            MessageStatus::Unspent => Self::Unspent,
+            MessageStatus::Spent => Self::Spent,
+            MessageStatus::NotFound => Self::NotFound,
+        }
+    }
+}
+
+impl From<RecordedMessageStatus> for MessageStatus {
+    fn from(recorded: RecordedMessageStatus) -> Self {
+        match recorded {
+            RecordedMessageStatus::Unspent => Self::Unspent,
+            RecordedMessageStatus::Spent => Self::Spent,
+            RecordedMessageStatus::NotFound => Self::NotFound,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedMerkleProof {
+    proof_set: Vec<Bytes32>,
+    proof_index: u64,
+}
+
+impl From<&MerkleProof> for RecordedMerkleProof {
+    fn from(proof: &MerkleProof) -> Self {
+        Self {
+            proof_set: proof.proof_set.clone(),
+            proof_index: proof.proof_index,
+        }
+    }
+}
+
+impl From<RecordedMerkleProof> for MerkleProof {
+    fn from(recorded: RecordedMerkleProof) -> Self {
+        Self {
+            proof_set: recorded.proof_set,
+            proof_index: recorded.proof_index,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedMessageProof {
+    message_proof: RecordedMerkleProof,
+    block_proof: RecordedMerkleProof,
+    message_block_header: RecordedHeader,
+    commit_block_header: RecordedHeader,
+    sender: Address,
+    recipient: Address,
+    nonce: Nonce,
+    amount: u64,
+    data: Vec<u8>,
+}
+
+impl From<&MessageProof> for RecordedMessageProof {
+    fn from(proof: &MessageProof) -> Self {
+        Self {
+            message_proof: RecordedMerkleProof::from(&proof.message_proof),
+            block_proof: RecordedMerkleProof::from(&proof.block_proof),
+            message_block_header: RecordedHeader::from(&proof.message_block_header),
+            commit_block_header: RecordedHeader::from(&proof.commit_block_header),
+            sender: proof.sender,
+            recipient: proof.recipient,
+            nonce: proof.nonce,
+            amount: proof.amount,
+            data: proof.data.clone(),
+        }
+    }
+}
+
+impl From<RecordedMessageProof> for MessageProof {
+    fn from(recorded: RecordedMessageProof) -> Self {
+        Self {
+            message_proof: recorded.message_proof.into(),
+            block_proof: recorded.block_proof.into(),
+            message_block_header: recorded.message_block_header.into(),
+            commit_block_header: recorded.commit_block_header.into(),
+            sender: recorded.sender,
+            recipient: recorded.recipient,
+            nonce: recorded.nonce,
+            amount: recorded.amount,
+            data: recorded.data,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<T: Transport> Transport for RecordingTransport<T> {
+    async fn health(&self) -> io::Result<bool> {
+        let response = self.inner.health().await;
+        self.record(Request::Health, response, |healthy| Ok(*healthy))
+    }
+
+    async fn transaction(&self, id: &TxId) -> io::Result<Option<TransactionResponse>> {
+        let response = self.inner.transaction(id).await;
+        self.record(Request::Transaction { id: *id }, response, |transaction| {
+            Ok(transaction.as_ref().map(RecordedTransaction::from))
+        })
+    }
+
+    async fn chain_info(&self) -> io::Result<ChainInfo> {
+        let response = self.inner.chain_info().await;
+        self.record(Request::ChainInfo, response, |chain_info| {
+            Ok(RecordedChainInfo::from(chain_info))
+        })
+    }
+
+    async fn await_transaction_commit(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        let response = self.inner.await_transaction_commit(id).await;
+        self.record(
+            Request::AwaitTransactionCommit { id: *id },
+            response,
+            |status| Ok(RecordedStatus::from(status)),
+        )
+    }
+
+    async fn submit_and_await_commit(&self, tx: &Transaction) -> io::Result<TransactionStatus> {
+        let response = self.inner.submit_and_await_commit(tx).await;
+        self.record(
+            Request::SubmitAndAwaitCommit { tx: tx.clone() },
+            response,
+            |status| Ok(RecordedStatus::from(status)),
+        )
+    }
+
+    async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        let response = self.inner.submit(tx).await;
+        self.record(Request::Submit { tx: tx.clone() }, response, |id| Ok(*id))
+    }
+
+    async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        let response = self.inner.transaction_status(id).await;
+        self.record(Request::TransactionStatus { id: *id }, response, |status| {
+            Ok(RecordedStatus::from(status))
+        })
+    }
+
+    async fn node_info(&self) -> io::Result<NodeInfo> {
+        let response = self.inner.node_info().await;
+        self.record(Request::NodeInfo, response, |node_info| {
+            Ok(RecordedNodeInfo {
+                utxo_validation: node_info.utxo_validation,
+                vm_backtrace: node_info.vm_backtrace,
+                max_tx: node_info.max_tx,
+                max_depth: node_info.max_depth,
+                node_version: node_info.node_version.clone(),
+            })
+        })
+    }
+
+    async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+        let response = self.inner.latest_gas_price().await;
+        self.record(Request::LatestGasPrice, response, |price| {
+            Ok((price.gas_price, u32::from(price.block_height)))
+        })
+    }
+
+    async fn estimate_gas_price(&self, block_horizon: u32) -> io::Result<EstimateGasPrice> {
+        let response = self.inner.estimate_gas_price(block_horizon).await;
+        self.record(
+            Request::EstimateGasPrice { block_horizon },
+            response,
+            |price| Ok(price.gas_price),
+        )
+    }
+
+    async fn dry_run(&self, tx: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let response = self.inner.dry_run(tx).await;
+        self.record(
+            Request::DryRun {
+                txs: tx.to_vec(),
+                utxo_validation: None,
+            },
+            response,
+            |statuses| {
+                Ok(statuses
+                    .iter()
+                    .map(RecordedExecution::from)
+                    .collect::<Vec<_>>())
+            },
+        )
+    }
+
+    async fn dry_run_opt(
+        &self,
+        tx: &[Transaction],
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let response = self.inner.dry_run_opt(tx, utxo_validation).await;
+        self.record(
+            Request::DryRun {
+                txs: tx.to_vec(),
+                utxo_validation,
+            },
+            response,
+            |statuses| {
+                Ok(statuses
+                    .iter()
+                    .map(RecordedExecution::from)
+                    .collect::<Vec<_>>())
+            },
+        )
+    }
+
+    async fn coins(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Coin, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.coins(owner, asset_id, request).await;
+        self.record(
+            Request::Coins {
+                owner: *owner,
+                asset_id: asset_id.copied(),
+                page,
+            },
+            response,
+            |coins| Ok(RecordedPage::record(coins, RecordedCoin::from)),
+        )
+    }
+
+    async fn coin(&self, id: &UtxoId) -> io::Result<Option<Coin>> {
+        let response = self.inner.coin(id).await;
+        self.record(Request::Coin { id: *id }, response, |coin| {
+            Ok(coin.as_ref().map(RecordedCoin::from))
+        })
+    }
+
+    async fn coins_to_spend(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<CoinType>>> {
+        let request = Request::CoinsToSpend {
+            owner: *owner,
+            spend_query: spend_query.clone(),
+            excluded_ids: excluded_ids.clone(),
+        };
+        let response = self
+            .inner
+            .coins_to_spend(owner, spend_query, excluded_ids)
+            .await;
+        self.record(request, response, |resources| {
+            resources
+                .iter()
+                .map(|resources| {
+                    resources
+                        .iter()
+                        .map(RecordedCoinType::try_from)
+                        .collect::<io::Result<Vec<_>>>()
+                })
+                .collect::<io::Result<Vec<_>>>()
+        })
+    }
+
+    async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> io::Result<u64> {
+        let response = self.inner.balance(owner, asset_id).await;
+        self.record(
+            Request::Balance {
+                owner: *owner,
+                asset_id: asset_id.copied(),
+            },
+            response,
+            |amount| Ok(*amount),
+        )
+    }
+
+    async fn contract_balance(&self, id: &ContractId, asset: Option<&AssetId>) -> io::Result<u64> {
+        let response = self.inner.contract_balance(id, asset).await;
+        self.record(
+            Request::ContractBalance {
+                id: *id,
+                asset_id: asset.copied(),
+            },
+            response,
+            |amount| Ok(*amount),
+        )
+    }
+
+    async fn contract_balances(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ContractBalance, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.contract_balances(contract, request).await;
+        self.record(
+            Request::ContractBalances {
+                id: *contract,
+                page,
+            },
+            response,
+            |balances| {
+                Ok(RecordedPage::record(balances, |balance| {
+                    RecordedContractBalance {
+                        contract: balance.contract,
+                        amount: balance.amount,
+                        asset_id: balance.asset_id,
+                    }
+                }))
+            },
+        )
+    }
+
+    async fn balances(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Balance, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.balances(owner, request).await;
+        self.record(
+            Request::Balances {
+                owner: *owner,
+                page,
+            },
+            response,
+            |balances| {
+                Ok(RecordedPage::record(balances, |balance| RecordedBalance {
+                    owner: balance.owner,
+                    amount: balance.amount,
+                    asset_id: balance.asset_id,
+                }))
+            },
+        )
+    }
+
+    async fn transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.transactions(request).await;
+        self.record(Request::Transactions { page }, response, |transactions| {
+            Ok(RecordedPage::record(
+                transactions,
+                RecordedTransaction::from,
+            ))
+        })
+    }
+
+    async fn transactions_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.transactions_by_owner(owner, request).await;
+        self.record(
+            Request::TransactionsByOwner {
+                owner: *owner,
+                page,
+            },
+            response,
+            |transactions| {
+                Ok(RecordedPage::record(
+                    transactions,
+                    RecordedTransaction::from,
+                ))
+            },
+        )
+    }
+
+    async fn produce_blocks(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        let response = self
+            .inner
+            .produce_blocks(blocks_to_produce, start_timestamp)
+            .await;
+        self.record(
+            Request::ProduceBlocks {
+                blocks_to_produce,
+                start_timestamp,
+            },
+            response,
+            |height| Ok(u32::from(*height)),
+        )
+    }
+
+    async fn block(&self, id: &BlockId) -> io::Result<Option<Block>> {
+        let response = self.inner.block(id).await;
+        self.record(Request::Block { id: *id }, response, |block| {
+            Ok(block.as_ref().map(RecordedBlock::from))
+        })
+    }
+
+    async fn block_by_height(&self, height: BlockHeight) -> io::Result<Option<Block>> {
+        let response = self.inner.block_by_height(height).await;
+        self.record(
+            Request::BlockByHeight {
+                height: height.into(),
+            },
+            response,
+            |block| Ok(block.as_ref().map(RecordedBlock::from)),
+        )
+    }
+
+    async fn da_compressed_block(&self, height: BlockHeight) -> io::Result<Option<Vec<u8>>> {
+        let response = self.inner.da_compressed_block(height).await;
+        self.record(
+            Request::DaCompressedBlock {
+                height: height.into(),
+            },
+            response,
+            |bytes| Ok(bytes.clone()),
+        )
+    }
+
+    async fn blocks(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Block, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.blocks(request).await;
+        self.record(Request::Blocks { page }, response, |blocks| {
+            Ok(RecordedPage::record(blocks, RecordedBlock::from))
+        })
+    }
+
+    async fn messages(
+        &self,
+        owner: Option<&Address>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Message, String>> {
+        let page = format!("{request:?}");
+        let response = self.inner.messages(owner, request).await;
+        self.record(
+            Request::Messages {
+                owner: owner.copied(),
+                page,
+            },
+            response,
+            |messages| Ok(RecordedPage::record(messages, RecordedMessage::from)),
+        )
+    }
+
+    async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
+        let response = self.inner.message_status(nonce).await;
+        self.record(
+            Request::MessageStatus { nonce: *nonce },
+            response,
+            |status| Ok(RecordedMessageStatus::from(status)),
+        )
+    }
+
+    async fn message_proof(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Option<MessageProof>> {
+        let request = Request::MessageProof {
+            transaction_id: *transaction_id,
+            nonce: *nonce,
+            commit_block_id: commit_block_id.copied(),
+            commit_block_height: commit_block_height.map(Into::into),
+        };
+        let response = self
+            .inner
+            .message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
+            .await;
+        self.record(request, response, |proof| {
+            Ok(proof.as_ref().map(RecordedMessageProof::from))
+        })
+    }
+
+    async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+    ) -> io::Result<serde_json::Value> {
+        let response = self.inner.raw_query(query, variables).await;
+        self.record(
+            Request::RawQuery {
+                query: query.to_string(),
+                variables: variables.clone(),
+            },
+            response,
+            |data| Ok(data.clone()),
+        )
+    }
+
+    async fn raw_subscription(
+        &self,
+        subscription: &str,
+        variables: &serde_json::Value,
+    ) -> io::Result<RawSubscription> {
+        let request = Request::RawSubscription {
+            subscription: subscription.to_string(),
+            variables: variables.clone(),
+        };
+        let events = match self.inner.raw_subscription(subscription, variables).await {
+            Ok(events) => events,
+            Err(err) => return self.record(request, Err(err), |_| Ok(())),
+        };
+
+        let mut recording = SubscriptionRecording {
+            recorder: self.recorder.clone(),
+            request,
+            events: vec![],
+        };
+
+        Ok(Box::pin(events.map(move |event| {
+            recording.events.push(match &event {
+                Ok(data) => Ok(data.clone()),
+                Err(err) => Err(err.to_string()),
+            });
+
+            event
+        })))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for ReplayTransport {
+    async fn health(&self) -> io::Result<bool> {
+        self.replay(Request::Health)
+    }
+
+    async fn transaction(&self, id: &TxId) -> io::Result<Option<TransactionResponse>> {
+        self.replay::<Option<RecordedTransaction>>(Request::Transaction { id: *id })
+            .map(|transaction| transaction.map(Into::into))
+    }
+
+    async fn chain_info(&self) -> io::Result<ChainInfo> {
+        self.replay::<RecordedChainInfo>(Request::ChainInfo)
+            .map(Into::into)
+    }
+
+    async fn await_transaction_commit(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        self.replay::<RecordedStatus>(Request::AwaitTransactionCommit { id: *id })
+            .map(Into::into)
+    }
+
+    async fn submit_and_await_commit(&self, tx: &Transaction) -> io::Result<TransactionStatus> {
+        self.replay::<RecordedStatus>(Request::SubmitAndAwaitCommit { tx: tx.clone() })
+            .map(Into::into)
+    }
+
+    async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        self.replay(Request::Submit { tx: tx.clone() })
+    }
+
+    async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        self.replay::<RecordedStatus>(Request::TransactionStatus { id: *id })
+            .map(Into::into)
+    }
+
+    async fn node_info(&self) -> io::Result<NodeInfo> {
+        let recorded: RecordedNodeInfo = self.replay(Request::NodeInfo)?;
+
+        Ok(NodeInfo {
+            utxo_validation: recorded.utxo_validation,
+            vm_backtrace: recorded.vm_backtrace,
+            max_tx: recorded.max_tx,
+            max_depth: recorded.max_depth,
+            node_version: recorded.node_version,
+        })
+    }
+
+    async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+        let (gas_price, block_height): (u64, u32) = self.replay(Request::LatestGasPrice)?;
+
+        Ok(LatestGasPrice {
+            gas_price,
+            block_height: block_height.into(),
+        })
+    }
+
+    async fn estimate_gas_price(&self, block_horizon: u32) -> io::Result<EstimateGasPrice> {
+        let gas_price = self.replay(Request::EstimateGasPrice { block_horizon })?;
+
+        Ok(EstimateGasPrice { gas_price })
+    }
+
+    async fn dry_run(&self, tx: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        self.dry_run_opt(tx, None).await
+    }
+
+    async fn dry_run_opt(
+        &self,
+        tx: &[Transaction],
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let statuses: Vec<RecordedExecution> = self.replay(Request::DryRun {
+            txs: tx.to_vec(),
+            utxo_validation,
+        })?;
+
+        Ok(statuses.into_iter().map(Into::into).collect())
+    }
+
+    async fn coins(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Coin, String>> {
+        let page: RecordedPage<RecordedCoin> = self.replay(Request::Coins {
+            owner: *owner,
+            asset_id: asset_id.copied(),
+            page: format!("{request:?}"),
+        })?;
+
+        Ok(page.replay(Into::into))
+    }
+
+    async fn coin(&self, id: &UtxoId) -> io::Result<Option<Coin>> {
+        self.replay::<Option<RecordedCoin>>(Request::Coin { id: *id })
+            .map(|coin| coin.map(Into::into))
+    }
+
+    async fn coins_to_spend(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<CoinType>>> {
+        let resources: Vec<Vec<RecordedCoinType>> = self.replay(Request::CoinsToSpend {
+            owner: *owner,
+            spend_query,
+            excluded_ids,
+        })?;
+
+        Ok(resources
+            .into_iter()
+            .map(|resources| resources.into_iter().map(Into::into).collect())
+            .collect())
+    }
+
+    async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> io::Result<u64> {
+        self.replay(Request::Balance {
+            owner: *owner,
+            asset_id: asset_id.copied(),
+        })
+    }
+
+    async fn contract_balance(&self, id: &ContractId, asset: Option<&AssetId>) -> io::Result<u64> {
+        self.replay(Request::ContractBalance {
+            id: *id,
+            asset_id: asset.copied(),
+        })
+    }
+
+    async fn contract_balances(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ContractBalance, String>> {
+        let page: RecordedPage<RecordedContractBalance> =
+            self.replay(Request::ContractBalances {
+                id: *contract,
+                page: format!("{request:?}"),
+            })?;
+
+        Ok(page.replay(|balance| ContractBalance {
+            contract: balance.contract,
+            amount: balance.amount,
+            asset_id: balance.asset_id,
+        }))
+    }
+
+    async fn balances(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Balance, String>> {
+        let page: RecordedPage<RecordedBalance> = self.replay(Request::Balances {
+            owner: *owner,
+            page: format!("{request:?}"),
+        })?;
+
+        Ok(page.replay(|balance| Balance {
+            owner: balance.owner,
+            amount: balance.amount,
+            asset_id: balance.asset_id,
+        }))
+    }
+
+    async fn transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let page: RecordedPage<RecordedTransaction> = self.replay(Request::Transactions {
+            page: format!("{request:?}"),
+        })?;
+
+        Ok(page.replay(Into::into))
+    }
+
+    async fn transactions_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        let page: RecordedPage<RecordedTransaction> =
+            self.replay(Request::TransactionsByOwner {
+                owner: *owner,
+                page: format!("{request:?}"),
+            })?;
+
+        Ok(page.replay(Into::into))
+    }
+
+    async fn produce_blocks(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        let height: u32 = self.replay(Request::ProduceBlocks {
+            blocks_to_produce,
+            start_timestamp,
+        })?;
+
+        Ok(height.into())
+    }
+
+    async fn block(&self, id: &BlockId) -> io::Result<Option<Block>> {
+        self.replay::<Option<RecordedBlock>>(Request::Block { id: *id })
+            .map(|block| block.map(Into::into))
+    }
+
+    async fn block_by_height(&self, height: BlockHeight) -> io::Result<Option<Block>> {
+        self.replay::<Option<RecordedBlock>>(Request::BlockByHeight {
+            height: height.into(),
+        })
+        .map(|block| block.map(Into::into))
+    }
+
+    async fn da_compressed_block(&self, height: BlockHeight) -> io::Result<Option<Vec<u8>>> {
+        self.replay(Request::DaCompressedBlock {
+            height: height.into(),
+        })
+    }
+
+    async fn blocks(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Block, String>> {
+        let page: RecordedPage<RecordedBlock> = self.replay(Request::Blocks {
+            page: format!("{request:?}"),
+        })?;
+
+        Ok(page.replay(Into::into))
+    }
+
+    async fn messages(
+        &self,
+        owner: Option<&Address>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Message, String>> {
+        let page: RecordedPage<RecordedMessage> = self.replay(Request::Messages {
+            owner: owner.copied(),
+            page: format!("{request:?}"),
+        })?;
+
+        Ok(page.replay(Into::into))
+    }
+
+    async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
+        self.replay::<RecordedMessageStatus>(Request::MessageStatus { nonce: *nonce })
+            .map(Into::into)
+    }
+
+    async fn message_proof(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Option<MessageProof>> {
+        self.replay::<Option<RecordedMessageProof>>(Request::MessageProof {
+            transaction_id: *transaction_id,
+            nonce: *nonce,
+            commit_block_id: commit_block_id.copied(),
+            commit_block_height: commit_block_height.map(Into::into),
+        })
+        .map(|proof| proof.map(Into::into))
+    }
+
+    async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+    ) -> io::Result<serde_json::Value> {
+        self.replay(Request::RawQuery {
+            query: query.to_string(),
+            variables: variables.clone(),
+        })
+    }
+
+    async fn raw_subscription(
+        &self,
+        subscription: &str,
+        variables: &serde_json::Value,
+    ) -> io::Result<RawSubscription> {
+        let events: Vec<Recorded> = self.replay(Request::RawSubscription {
+            subscription: subscription.to_string(),
+            variables: variables.clone(),
+        })?;
+
+        Ok(Box::pin(futures::stream::iter(events.into_iter().map(
+            |event| event.map_err(|err| io::Error::new(io::ErrorKind::Other, err)),
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::Policies;
+    use fuels_core::types::{bech32::Bech32Address, coin::Coin as SdkCoin, tx_status::TxStatus};
+    use futures::TryStreamExt;
+
+    use super::*;
+    use crate::provider::MockProvider;
+
+    #[tokio::test]
+    async fn recorded_responses_are_replayed() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("interactions.jsonl");
+        let owner = Bech32Address::from(Address::from([1; 32]));
+        let asset_id = AssetId::from([2; 32]);
+
+        let mock = MockProvider::new()
+            .with_block_height(7)
+            .with_balance(&owner, asset_id, 100)
+            .with_submission_result(TxStatus::Revert {
+                receipts: vec![],
+                reason: "Revert(1)".to_string(),
+                revert_id: 1,
+            });
+        let recording = RecordingTransport::new(mock, &path);
+        let consensus_parameters = recording.chain_info().await?.consensus_parameters;
+        let provider = Provider::from_transport("mock://", recording, consensus_parameters);
+
+        let tx = Transaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![],
+            vec![],
+            vec![],
+        );
+        let tx_id = provider.client.submit(&tx).await?;
+        let recorded_height = provider.latest_block_height().await?;
+        let recorded_balance = provider.get_asset_balance(&owner, asset_id).await?;
+        let recorded_status = provider.tx_status(&tx_id).await?;
+
+        let provider = ReplayTransport::load(&path)?.provider().await?;
+
+        assert_eq!(provider.client.submit(&tx).await?, tx_id);
+        assert_eq!(provider.latest_block_height().await?, recorded_height);
+        assert_eq!(
+            provider.get_asset_balance(&owner, asset_id).await?,
+            recorded_balance
+        );
+        assert!(matches!(
+            (recorded_status, provider.tx_status(&tx_id).await?),
+            (
+                TxStatus::Revert { revert_id: 1, .. },
+                TxStatus::Revert { revert_id: 1, .. }
+            )
+        ));
+
+        let err = provider
+            .get_asset_balance(&owner, asset_id)
+            .await
+            .expect_err("should fail");
+        assert!(err.to_string().contains("no response to Balance"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn coins_blocks_and_subscriptions_are_replayed() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("interactions.jsonl");
+        let coin = SdkCoin {
+            amount: 10,
+            utxo_id: UtxoId::new(TxId::from([3; 32]), 0),
+            owner: Bech32Address::from(Address::from([1; 32])),
+            ..Default::default()
+        };
+        let subscription = "subscription { blocks { height } }";
+        let events = vec![
+            serde_json::json!({ "blocks": { "height": 1 } }),
+            serde_json::json!({ "blocks": { "height": 2 } }),
+        ];
+
+        let mock = MockProvider::new()
+            .with_coin(coin.clone())
+            .with_da_compressed_block(3, [1, 2, 3])
+            .with_raw_subscription_events(subscription, events.clone());
+        let recording = RecordingTransport::new(mock, &path);
+
+        let recorded_coin = recording.coin(&coin.utxo_id).await?.expect("coin exists");
+        // interactions are written as they happen
+        assert_eq!(fs::read_to_string(&path)?.lines().count(), 1);
+
+        let recorded_block = recording.da_compressed_block(3.into()).await?;
+        let recorded_events = recording
+            .raw_subscription(subscription, &serde_json::Value::Null)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(recorded_events, events);
+        assert_eq!(fs::read_to_string(&path)?.lines().count(), 3);
+
+        let replay = ReplayTransport::load(&path)?;
+
+        let coin = replay
+            .coin(&coin.utxo_id)
+            .await?
+            .expect("coin was recorded");
+        assert_eq!(
+            (coin.utxo_id, coin.amount, coin.owner),
+            (
+                recorded_coin.utxo_id,
+                recorded_coin.amount,
+                recorded_coin.owner
+            )
+        );
+        assert_eq!(replay.da_compressed_block(3.into()).await?, recorded_block);
+        let events = replay
+            .raw_subscription(subscription, &serde_json::Value::Null)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(events, recorded_events);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn subscriptions_failing_to_be_recorded_are_reported_by_the_next_request() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("missing").join("interactions.jsonl");
+        let subscription = "subscription { blocks { height } }";
+        let events = vec![serde_json::json!({ "blocks": { "height": 1 } })];
+
+        let mock = MockProvider::new()
+            .with_block_height(7)
+            .with_raw_subscription_events(subscription, events);
+        let recording = RecordingTransport::new(mock, &path);
+
+        // dropping the subscription doesn't panic although the file can't be created
+        recording
+            .raw_subscription(subscription, &serde_json::Value::Null)
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        fs::create_dir(dir.path().join("missing"))?;
+        let err = recording.chain_info().await.expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("could not record the subscription RawSubscription"));
+
+        // the error is reported once and the request itself was recorded
+        recording.chain_info().await?;
+        assert_eq!(fs::read_to_string(&path)?.lines().count(), 2);
+
+        Ok(())
+    }
+}
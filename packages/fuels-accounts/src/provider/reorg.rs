@@ -0,0 +1,96 @@
+use std::{collections::VecDeque, num::NonZeroU32};
+
+use fuel_types::Bytes32;
+use fuels_core::types::errors::Result;
+
+use crate::provider::Provider;
+
+/// Emitted when blocks previously observed by a [`ReorgMonitor`] are no longer part of the
+/// canonical chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReorgEvent {
+    /// Height of the most recent tracked block that is still part of the canonical chain. `None`
+    /// if the reorg is deeper than the tracked window.
+    pub common_ancestor: Option<u32>,
+    /// Ids of the blocks that were replaced, ordered by height.
+    pub orphaned_blocks: Vec<Bytes32>,
+}
+
+/// Tracks the ids of the most recent blocks and detects when they are replaced.
+#[derive(Debug, Clone)]
+pub struct ReorgMonitor {
+    depth: NonZeroU32,
+    // (height, id) of the tracked blocks, ordered by height
+    tracked_blocks: VecDeque<(u32, Bytes32)>,
+}
+
+impl ReorgMonitor {
+    /// Creates a monitor tracking the last `depth` blocks. Reorgs deeper than `depth` are
+    /// reported without a common ancestor.
+    pub fn new(depth: NonZeroU32) -> Self {
+        Self {
+            depth,
+            tracked_blocks: VecDeque::new(),
+        }
+    }
+
+    /// Compares the tracked blocks with the canonical chain of the node and starts tracking the
+    /// blocks produced since the last call. Returns the detected reorg, if any.
+    pub async fn poll(&mut self, provider: &Provider) -> Result<Option<ReorgEvent>> {
+        let latest_height = provider.latest_block_height().await?;
+
+        let mut orphaned_blocks = vec![];
+        while let Some(&(height, id)) = self.tracked_blocks.back() {
+            let canonical_id = if height <= latest_height {
+                provider
+                    .block_by_height(height.into())
+                    .await?
+                    .map(|block| block.id)
+            } else {
+                None
+            };
+
+            if canonical_id == Some(id) {
+                break;
+            }
+
+            orphaned_blocks.push(id);
+            self.tracked_blocks.pop_back();
+        }
+
+        let reorg = (!orphaned_blocks.is_empty()).then(|| {
+            orphaned_blocks.reverse();
+
+            ReorgEvent {
+                common_ancestor: self.tracked_blocks.back().map(|(height, _)| *height),
+                orphaned_blocks,
+            }
+        });
+
+        self.track_blocks_up_to(provider, latest_height).await?;
+
+        Ok(reorg)
+    }
+
+    async fn track_blocks_up_to(&mut self, provider: &Provider, latest_height: u32) -> Result<()> {
+        let window_start = latest_height.saturating_sub(self.depth.get() - 1);
+        let next_height = self
+            .tracked_blocks
+            .back()
+            .map(|(height, _)| height + 1)
+            .unwrap_or(window_start)
+            .max(window_start);
+
+        for height in next_height..=latest_height {
+            if let Some(block) = provider.block_by_height(height.into()).await? {
+                self.tracked_blocks.push_back((height, block.id));
+            }
+        }
+
+        while self.tracked_blocks.len() > self.depth.get() as usize {
+            self.tracked_blocks.pop_front();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use std::future::Future;
+
+use fuel_tx::TxId;
+use fuels_core::types::{
+    errors::Result,
+    transaction::{Transaction, TxPolicies},
+    tx_status::TxStatus,
+};
+
+use super::Provider;
+
+/// Resubmits a transaction squeezed out of the pool of the node, e.g. because the pool is full
+/// or one of its inputs was spent in the meantime. The transaction is rebuilt with fresh inputs,
+/// its tip raised by `tip_bump` and signed again.
+///
+/// The default policy submits the transaction once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResubmitPolicy {
+    /// How many times the transaction is submitted at most, including the first submission.
+    pub max_attempts: u32,
+    /// Added to the tip of the transaction on every resubmission.
+    pub tip_bump: u64,
+}
+
+impl Default for ResubmitPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            tip_bump: 0,
+        }
+    }
+}
+
+impl ResubmitPolicy {
+    pub fn new(max_attempts: u32, tip_bump: u64) -> Self {
+        Self {
+            max_attempts,
+            tip_bump,
+        }
+    }
+
+    /// Sends the transaction built by `build` with `tx_policies` and awaits its commitment.
+    /// Whenever it is squeezed out and attempts are left, `build` is called again with the tip
+    /// raised by `tip_bump` and the new transaction is sent instead. Returns the id and the
+    /// status of the last transaction sent.
+    pub async fn send_and_await_commit<T, F, Fut>(
+        &self,
+        provider: &Provider,
+        mut tx_policies: TxPolicies,
+        mut build: F,
+    ) -> Result<(TxId, TxStatus)>
+    where
+        T: Transaction,
+        F: FnMut(TxPolicies) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let tx = build(tx_policies).await?;
+            let tx_id = tx.id(provider.chain_id());
+            let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+
+            match tx_status {
+                TxStatus::SqueezedOut { .. } if attempt < self.max_attempts => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(%tx_id, attempt, ?tx_status, "resubmitting transaction");
+
+                    let tip = tx_policies.tip().unwrap_or_default();
+                    tx_policies = tx_policies.with_tip(tip.saturating_add(self.tip_bump));
+                    attempt += 1;
+                }
+                tx_status => return Ok((tx_id, tx_status)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{field::Policies, policies::PolicyType, Output};
+    use fuels_core::types::{
+        coin::Coin,
+        coin_type::CoinType,
+        input::Input,
+        transaction::ScriptTransaction,
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+        AssetId,
+    };
+
+    use super::*;
+    use crate::{provider::MockProvider, wallet::WalletUnlocked, ViewOnlyAccount};
+
+    async fn build_transfer(
+        provider: &Provider,
+        wallet: &WalletUnlocked,
+        tx_policies: TxPolicies,
+    ) -> Result<ScriptTransaction> {
+        let coin = Coin {
+            amount: 1000,
+            owner: wallet.address().clone(),
+            ..Default::default()
+        };
+        let mut tb = ScriptTransactionBuilder::prepare_transfer(
+            vec![Input::resource_signed(CoinType::Coin(coin))],
+            vec![Output::change(
+                wallet.address().into(),
+                0,
+                AssetId::zeroed(),
+            )],
+            tx_policies,
+        );
+        tb.add_signer(wallet.clone())?;
+
+        tb.build(provider).await
+    }
+
+    #[tokio::test]
+    async fn squeezed_out_transactions_are_resubmitted_with_a_higher_tip() -> Result<()> {
+        let squeezed_out = || TxStatus::SqueezedOut {
+            reason: "pool is full".to_string(),
+        };
+        let mock = MockProvider::new()
+            .with_submission_result(squeezed_out())
+            .with_submission_result(squeezed_out());
+        let provider = mock.provider();
+        let wallet = WalletUnlocked::new_random(Some(provider.clone()));
+
+        // ANCHOR: resubmit_policy
+        let (_tx_id, tx_status) = ResubmitPolicy::new(3, 10)
+            .send_and_await_commit(
+                &provider,
+                TxPolicies::default().with_tip(5),
+                |tx_policies| build_transfer(&provider, &wallet, tx_policies),
+            )
+            .await?;
+        // ANCHOR_END: resubmit_policy
+
+        assert!(matches!(tx_status, TxStatus::Success { .. }));
+        let tips = mock
+            .submitted_transactions()
+            .iter()
+            .map(|tx| {
+                tx.as_script()
+                    .and_then(|tx| tx.policies().get(PolicyType::Tip))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(tips, [Some(5), Some(15), Some(25)]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn other_statuses_are_not_resubmitted() -> Result<()> {
+        let mock = MockProvider::new().with_submission_result(TxStatus::Revert {
+            receipts: vec![],
+            reason: "Revert(1)".to_string(),
+            revert_id: 1,
+        });
+        let provider = mock.provider();
+        let wallet = WalletUnlocked::new_random(Some(provider.clone()));
+
+        let (_, tx_status) = ResubmitPolicy::new(3, 10)
+            .send_and_await_commit(&provider, TxPolicies::default(), |tx_policies| {
+                build_transfer(&provider, &wallet, tx_policies)
+            })
+            .await?;
+
+        assert!(matches!(tx_status, TxStatus::Revert { revert_id: 1, .. }));
+        assert_eq!(mock.submitted_transactions().len(), 1);
+
+        Ok(())
+    }
+}
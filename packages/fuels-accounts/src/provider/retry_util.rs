@@ -142,12 +142,22 @@ where
             return result;
         }
 
-        tokio::time::sleep(retry_config.interval.wait_duration(attempt)).await;
+        sleep(retry_config.interval.wait_duration(attempt)).await;
     }
 
     last_result.expect("should not happen")
 }
 
+/// Waits for the given duration. Uses the browser timers on `wasm32` targets since the tokio
+/// timer requires a tokio runtime.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
 #[cfg(test)]
 mod tests {
     mod retry_until {
@@ -1,9 +1,10 @@
-use std::{future::Future, io};
+use std::{future::Future, io, sync::Arc, time::Duration};
 
 use fuel_core_client::client::{
     pagination::{PaginatedResult, PaginationRequest},
     types::{
         gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus,
         primitives::{BlockId, TransactionId},
         Balance, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
         NodeInfo, TransactionResponse, TransactionStatus,
@@ -15,7 +16,16 @@ use fuel_tx::{Transaction, TxId, UtxoId};
 use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
 use fuels_core::types::errors::{error, Error, Result};
 
-use super::supported_versions::{self, VersionCompatibility};
+#[cfg(feature = "metrics-prometheus")]
+use super::metrics::ProviderMetrics;
+#[cfg(feature = "subscriptions")]
+use super::subscription::SseSubscriber;
+#[cfg(feature = "raw-graphql")]
+use super::transport::RawSubscription;
+use super::{
+    supported_versions::{self, VersionCompatibility},
+    transport::Transport,
+};
 use crate::provider::{retry_util, RetryConfig};
 
 #[derive(Debug, thiserror::Error)]
@@ -34,10 +44,17 @@ impl From<RequestError> for Error {
 
 #[derive(Debug, Clone)]
 pub(crate) struct RetryableClient {
-    client: FuelClient,
+    // shared so that cloned providers reuse the same underlying HTTP connection pool
+    client: Arc<dyn Transport>,
+    // set when connected to a node, whose client doesn't expose subscriptions
+    #[cfg(feature = "subscriptions")]
+    subscriber: Option<SseSubscriber>,
     url: String,
     retry_config: RetryConfig,
+    request_timeout: Option<Duration>,
     prepend_warning: Option<String>,
+    #[cfg(feature = "metrics-prometheus")]
+    metrics: Option<ProviderMetrics>,
 }
 
 impl RetryableClient {
@@ -49,13 +66,38 @@ impl RetryableClient {
         let warning = Self::version_compatibility_warning(&node_info)?;
 
         Ok(Self {
-            client,
+            client: Arc::new(client),
+            #[cfg(feature = "subscriptions")]
+            subscriber: Some(SseSubscriber::new(&url)),
             retry_config,
+            request_timeout: None,
             url,
             prepend_warning: warning,
+            #[cfg(feature = "metrics-prometheus")]
+            metrics: None,
         })
     }
 
+    /// Sends the requests through `transport` instead of connecting to a node. The version of
+    /// the node is not checked.
+    pub(crate) fn from_transport(
+        transport: impl Transport + 'static,
+        url: impl Into<String>,
+        retry_config: RetryConfig,
+    ) -> Self {
+        Self {
+            client: Arc::new(transport),
+            #[cfg(feature = "subscriptions")]
+            subscriber: None,
+            retry_config,
+            request_timeout: None,
+            url: url.into(),
+            prepend_warning: None,
+            #[cfg(feature = "metrics-prometheus")]
+            metrics: None,
+        }
+    }
+
     fn version_compatibility_warning(node_info: &NodeInfo) -> Result<Option<String>> {
         let node_version = node_info
             .node_version
@@ -88,73 +130,120 @@ impl RetryableClient {
         self.retry_config = retry_config;
     }
 
-    async fn wrap<T, Fut>(&self, action: impl Fn() -> Fut) -> RequestResult<T>
+    pub(crate) fn set_request_timeout(&mut self, request_timeout: Option<Duration>) {
+        self.request_timeout = request_timeout;
+    }
+
+    pub(crate) fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    #[cfg(feature = "metrics-prometheus")]
+    pub(crate) fn set_metrics(&mut self, metrics: ProviderMetrics) {
+        self.metrics = Some(metrics);
+    }
+
+    #[cfg_attr(not(feature = "metrics-prometheus"), allow(unused_variables))]
+    async fn wrap<T, Fut>(&self, method: &'static str, action: impl Fn() -> Fut) -> RequestResult<T>
     where
         Fut: Future<Output = io::Result<T>>,
     {
-        retry_util::retry(action, &self.retry_config, |result| result.is_err())
-            .await
-            .map_err(|e| {
-                let msg = if let Some(warning) = &self.prepend_warning {
-                    format!("{warning}. {e}")
-                } else {
-                    e.to_string()
-                };
-                RequestError::IO(msg)
-            })
+        let action = &action;
+        let request_timeout = self.request_timeout;
+        let timed_action = move || async move {
+            match request_timeout {
+                Some(duration) => fuels_core::timeout(duration, action())
+                    .await
+                    .unwrap_or_else(|| {
+                        Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("request timed out after {duration:?}"),
+                        ))
+                    }),
+                None => action().await,
+            }
+        };
+
+        let result =
+            retry_util::retry(timed_action, &self.retry_config, |result| result.is_err()).await;
+
+        #[cfg(feature = "metrics-prometheus")]
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_request(method, result.is_ok());
+        }
+
+        result.map_err(|e| {
+            let msg = if let Some(warning) = &self.prepend_warning {
+                format!("{warning}. {e}")
+            } else {
+                e.to_string()
+            };
+            RequestError::IO(msg)
+        })
     }
 
     // DELEGATION START
     pub async fn health(&self) -> RequestResult<bool> {
-        self.wrap(|| self.client.health()).await
+        self.wrap("health", || self.client.health()).await
     }
 
     pub async fn transaction(&self, id: &TxId) -> RequestResult<Option<TransactionResponse>> {
-        self.wrap(|| self.client.transaction(id)).await
+        self.wrap("transaction", || self.client.transaction(id))
+            .await
     }
 
     pub(crate) async fn chain_info(&self) -> RequestResult<ChainInfo> {
-        self.wrap(|| self.client.chain_info()).await
+        self.wrap("chain_info", || self.client.chain_info()).await
     }
 
     pub async fn await_transaction_commit(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.await_transaction_commit(id)).await
+        self.wrap("await_transaction_commit", || {
+            self.client.await_transaction_commit(id)
+        })
+        .await
     }
 
     pub async fn submit_and_await_commit(
         &self,
         tx: &Transaction,
     ) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.submit_and_await_commit(tx)).await
+        self.wrap("submit_and_await_commit", || {
+            self.client.submit_and_await_commit(tx)
+        })
+        .await
     }
 
     pub async fn submit(&self, tx: &Transaction) -> RequestResult<TransactionId> {
-        self.wrap(|| self.client.submit(tx)).await
+        self.wrap("submit", || self.client.submit(tx)).await
     }
 
     pub async fn transaction_status(&self, id: &TxId) -> RequestResult<TransactionStatus> {
-        self.wrap(|| self.client.transaction_status(id)).await
+        self.wrap("transaction_status", || self.client.transaction_status(id))
+            .await
     }
 
     pub async fn node_info(&self) -> RequestResult<NodeInfo> {
-        self.wrap(|| self.client.node_info()).await
+        self.wrap("node_info", || self.client.node_info()).await
     }
 
     pub async fn latest_gas_price(&self) -> RequestResult<LatestGasPrice> {
-        self.wrap(|| self.client.latest_gas_price()).await
+        self.wrap("latest_gas_price", || self.client.latest_gas_price())
+            .await
     }
 
     pub async fn estimate_gas_price(&self, block_horizon: u32) -> RequestResult<EstimateGasPrice> {
-        self.wrap(|| self.client.estimate_gas_price(block_horizon))
-            .await
-            .map(Into::into)
+        self.wrap("estimate_gas_price", || {
+            self.client.estimate_gas_price(block_horizon)
+        })
+        .await
+        .map(Into::into)
     }
 
     pub async fn dry_run(
         &self,
         tx: &[Transaction],
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run(tx)).await
+        self.wrap("dry_run", || self.client.dry_run(tx)).await
     }
 
     pub async fn dry_run_opt(
@@ -162,8 +251,10 @@ impl RetryableClient {
         tx: &[Transaction],
         utxo_validation: Option<bool>,
     ) -> RequestResult<Vec<TransactionExecutionStatus>> {
-        self.wrap(|| self.client.dry_run_opt(tx, utxo_validation))
-            .await
+        self.wrap("dry_run_opt", || {
+            self.client.dry_run_opt(tx, utxo_validation)
+        })
+        .await
     }
 
     pub async fn coins(
@@ -172,8 +263,14 @@ impl RetryableClient {
         asset_id: Option<&AssetId>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Coin, String>> {
-        self.wrap(move || self.client.coins(owner, asset_id, request.clone()))
-            .await
+        self.wrap("coins", move || {
+            self.client.coins(owner, asset_id, request.clone())
+        })
+        .await
+    }
+
+    pub async fn coin(&self, id: &UtxoId) -> RequestResult<Option<Coin>> {
+        self.wrap("coin", || self.client.coin(id)).await
     }
 
     pub async fn coins_to_spend(
@@ -182,7 +279,7 @@ impl RetryableClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
     ) -> RequestResult<Vec<Vec<CoinType>>> {
-        self.wrap(move || {
+        self.wrap("coins_to_spend", move || {
             self.client
                 .coins_to_spend(owner, spend_query.clone(), excluded_ids.clone())
         })
@@ -190,7 +287,8 @@ impl RetryableClient {
     }
 
     pub async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> RequestResult<u64> {
-        self.wrap(|| self.client.balance(owner, asset_id)).await
+        self.wrap("balance", || self.client.balance(owner, asset_id))
+            .await
     }
 
     pub async fn contract_balance(
@@ -198,7 +296,10 @@ impl RetryableClient {
         id: &ContractId,
         asset: Option<&AssetId>,
     ) -> RequestResult<u64> {
-        self.wrap(|| self.client.contract_balance(id, asset)).await
+        self.wrap("contract_balance", || {
+            self.client.contract_balance(id, asset)
+        })
+        .await
     }
 
     pub async fn contract_balances(
@@ -206,8 +307,10 @@ impl RetryableClient {
         contract: &ContractId,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<ContractBalance, String>> {
-        self.wrap(|| self.client.contract_balances(contract, request.clone()))
-            .await
+        self.wrap("contract_balances", || {
+            self.client.contract_balances(contract, request.clone())
+        })
+        .await
     }
 
     pub async fn balances(
@@ -215,7 +318,7 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Balance, String>> {
-        self.wrap(|| self.client.balances(owner, request.clone()))
+        self.wrap("balances", || self.client.balances(owner, request.clone()))
             .await
     }
 
@@ -223,7 +326,7 @@ impl RetryableClient {
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions(request.clone()))
+        self.wrap("transactions", || self.client.transactions(request.clone()))
             .await
     }
 
@@ -232,8 +335,10 @@ impl RetryableClient {
         owner: &Address,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<TransactionResponse, String>> {
-        self.wrap(|| self.client.transactions_by_owner(owner, request.clone()))
-            .await
+        self.wrap("transactions_by_owner", || {
+            self.client.transactions_by_owner(owner, request.clone())
+        })
+        .await
     }
 
     pub async fn produce_blocks(
@@ -241,7 +346,7 @@ impl RetryableClient {
         blocks_to_produce: u32,
         start_timestamp: Option<u64>,
     ) -> RequestResult<BlockHeight> {
-        self.wrap(|| {
+        self.wrap("produce_blocks", || {
             self.client
                 .produce_blocks(blocks_to_produce, start_timestamp)
         })
@@ -249,18 +354,56 @@ impl RetryableClient {
     }
 
     pub async fn block(&self, id: &BlockId) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block(id)).await
+        self.wrap("block", || self.client.block(id)).await
     }
 
     pub async fn block_by_height(&self, height: BlockHeight) -> RequestResult<Option<Block>> {
-        self.wrap(|| self.client.block_by_height(height)).await
+        self.wrap("block_by_height", || self.client.block_by_height(height))
+            .await
+    }
+
+    pub async fn da_compressed_block(&self, height: BlockHeight) -> RequestResult<Option<Vec<u8>>> {
+        self.wrap("da_compressed_block", || {
+            self.client.da_compressed_block(height)
+        })
+        .await
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    pub async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+    ) -> RequestResult<serde_json::Value> {
+        self.wrap("raw_query", || self.client.raw_query(query, variables))
+            .await
+    }
+
+    /// Only starting the subscription is retried, the responses are streamed as they come.
+    /// Without the `subscriptions` feature, subscriptions are only served by custom transports.
+    #[cfg(feature = "raw-graphql")]
+    pub async fn raw_subscription(
+        &self,
+        subscription: &str,
+        variables: &serde_json::Value,
+    ) -> RequestResult<RawSubscription> {
+        self.wrap("raw_subscription", || async {
+            #[cfg(feature = "subscriptions")]
+            if let Some(subscriber) = &self.subscriber {
+                return subscriber.subscribe(subscription, variables).await;
+            }
+
+            self.client.raw_subscription(subscription, variables).await
+        })
+        .await
     }
 
     pub async fn blocks(
         &self,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Block, String>> {
-        self.wrap(|| self.client.blocks(request.clone())).await
+        self.wrap("blocks", || self.client.blocks(request.clone()))
+            .await
     }
 
     pub async fn messages(
@@ -268,7 +411,12 @@ impl RetryableClient {
         owner: Option<&Address>,
         request: PaginationRequest<String>,
     ) -> RequestResult<PaginatedResult<Message, String>> {
-        self.wrap(|| self.client.messages(owner, request.clone()))
+        self.wrap("messages", || self.client.messages(owner, request.clone()))
+            .await
+    }
+
+    pub async fn message_status(&self, nonce: &Nonce) -> RequestResult<MessageStatus> {
+        self.wrap("message_status", || self.client.message_status(nonce))
             .await
     }
 
@@ -280,7 +428,7 @@ impl RetryableClient {
         commit_block_id: Option<&BlockId>,
         commit_block_height: Option<BlockHeight>,
     ) -> RequestResult<Option<MessageProof>> {
-        self.wrap(|| {
+        self.wrap("message_proof", || {
             self.client
                 .message_proof(transaction_id, nonce, commit_block_id, commit_block_height)
         })
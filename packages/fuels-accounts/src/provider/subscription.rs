@@ -0,0 +1,174 @@
+use std::io;
+
+use futures::{stream, Stream, StreamExt};
+use serde_json::Value;
+
+use super::transport::RawSubscription;
+
+/// Sends GraphQL subscriptions to the `/v1/graphql-sub` endpoint of a node, which streams the
+/// responses as server-sent events. The client of the node doesn't expose its subscriptions.
+#[derive(Debug, Clone)]
+pub(crate) struct SseSubscriber {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl SseSubscriber {
+    /// `url` is the address of the node, as given to [`Provider::connect`].
+    ///
+    /// [`Provider::connect`]: crate::provider::Provider::connect
+    pub(crate) fn new(url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: subscription_url(url),
+        }
+    }
+
+    pub(crate) async fn subscribe(
+        &self,
+        query: &str,
+        variables: &Value,
+    ) -> io::Result<RawSubscription> {
+        let to_io_error = |err: reqwest::Error| io::Error::new(io::ErrorKind::Other, err);
+
+        let response = self
+            .http
+            .post(&self.url)
+            .header(reqwest::header::ACCEPT, "text/event-stream")
+            .json(&serde_json::json!({ "query": query, "variables": variables }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(to_io_error)?;
+
+        let events = events(response.bytes_stream());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        return Ok(events.boxed());
+        #[cfg(target_arch = "wasm32")]
+        return Ok(events.boxed_local());
+    }
+}
+
+/// Mirrors the URL handling of the node client: the scheme defaults to `http` and the GraphQL
+/// path is optional.
+fn subscription_url(url: &str) -> String {
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("http://{url}")
+    };
+    let base = url.trim_end_matches('/').trim_end_matches("/v1/graphql");
+
+    format!("{base}/v1/graphql-sub")
+}
+
+/// Splits the body of the response into events, yielding the `data` of each GraphQL response.
+/// Comments, e.g. keep-alives, are skipped.
+fn events<S, B>(body: S) -> impl Stream<Item = io::Result<Value>>
+where
+    S: Stream<Item = reqwest::Result<B>>,
+    B: AsRef<[u8]>,
+{
+    stream::unfold(
+        (Box::pin(body), vec![]),
+        |(mut body, mut buffer)| async move {
+            loop {
+                if let Some(end) = buffer.windows(2).position(|window| window == b"\n\n") {
+                    let event = buffer.drain(..end + 2).collect::<Vec<_>>();
+                    match parse_event(&String::from_utf8_lossy(&event)) {
+                        Some(response) => return Some((response, (body, buffer))),
+                        None => continue,
+                    }
+                }
+
+                match body.next().await? {
+                    Ok(chunk) => {
+                        buffer.extend(chunk.as_ref().iter().filter(|byte| **byte != b'\r'))
+                    }
+                    Err(err) => {
+                        let err = io::Error::new(io::ErrorKind::ConnectionAborted, err);
+                        return Some((Err(err), (body, buffer)));
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Returns `None` if the event carries no data. GraphQL errors are reported as
+/// [`io::ErrorKind::InvalidData`], since subscribing again wouldn't fix them.
+fn parse_event(event: &str) -> Option<io::Result<Value>> {
+    let data = event
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(|data| data.strip_prefix(' ').unwrap_or(data))
+        .collect::<Vec<_>>();
+
+    if data.is_empty() {
+        return None;
+    }
+
+    let invalid_data = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+    let response = serde_json::from_str::<Value>(&data.join("\n"))
+        .map_err(|err| invalid_data(format!("the subscription sent invalid JSON: {err}")));
+
+    Some(
+        response.and_then(|mut response| match response.get("errors") {
+            Some(Value::Array(errors)) if !errors.is_empty() => {
+                let messages = errors
+                    .iter()
+                    .map(|error| error["message"].as_str().unwrap_or("unknown error"))
+                    .collect::<Vec<_>>();
+
+                Err(invalid_data(messages.join(", ")))
+            }
+            _ => Ok(response["data"].take()),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscriptions_are_sent_to_the_subscription_endpoint() {
+        for url in [
+            "127.0.0.1:4000",
+            "http://127.0.0.1:4000/",
+            "http://127.0.0.1:4000/v1/graphql",
+        ] {
+            assert_eq!(
+                subscription_url(url),
+                "http://127.0.0.1:4000/v1/graphql-sub"
+            );
+        }
+        assert_eq!(
+            subscription_url("https://testnet.fuel.network/v1/graphql"),
+            "https://testnet.fuel.network/v1/graphql-sub"
+        );
+    }
+
+    #[tokio::test]
+    async fn events_are_split_and_parsed() {
+        let body = [
+            ": keep-alive\n\ndata: {\"data\": {\"a\"",
+            ": 1}}\r\n\r\ndata: {\"data\": null, \"errors\": [{\"message\": \"boom\"}]}\n\n",
+            "data: {\"data\": {\"a\": 2}}\n\n",
+        ]
+        .map(|chunk| Ok::<_, reqwest::Error>(chunk.as_bytes()));
+
+        let events = events(stream::iter(body)).collect::<Vec<_>>().await;
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].as_ref().unwrap(), &serde_json::json!({ "a": 1 }));
+
+        let err = events[1].as_ref().expect_err("should fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "boom");
+
+        assert_eq!(events[2].as_ref().unwrap(), &serde_json::json!({ "a": 2 }));
+    }
+}
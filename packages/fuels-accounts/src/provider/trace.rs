@@ -0,0 +1,414 @@
+use std::str::FromStr;
+
+use fuel_core_types::{fuel_asm::RegId, fuel_vm::consts::VM_REGISTER_COUNT};
+use fuel_tx::{ContractId, Receipt, ScriptExecutionResult};
+use fuels_core::{
+    debug::{SourceLocation, SourceMapRegistry},
+    types::{
+        errors::{error, Result},
+        transaction::Transaction,
+    },
+};
+use serde::Deserialize;
+
+use super::Provider;
+
+const START_SESSION: &str = "mutation { startSession }";
+const END_SESSION: &str = "mutation($id: ID!) { endSession(id: $id) }";
+const SET_SINGLE_STEPPING: &str = "mutation($id: ID!) { setSingleStepping(id: $id, enable: true) }";
+const START_TX: &str = "mutation($id: ID!, $tx: String!) { startTx(id: $id, txJson: $tx) { state breakpoint { contract pc } jsonReceipts } }";
+const CONTINUE_TX: &str =
+    "mutation($id: ID!) { continueTx(id: $id) { state breakpoint { contract pc } jsonReceipts } }";
+const REGISTER: &str =
+    "query($id: ID!, $register: U32!) { register(id: $id, register: $register) }";
+
+/// The instructions executed by a transaction, recorded by stepping through it with the debugger
+/// of the node. See [`Provider::execution_trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionTrace {
+    steps: Vec<TraceStep>,
+    receipts: Vec<Receipt>,
+}
+
+/// The state of the VM before an instruction is executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// The contract being executed, zeroed while the script is executed.
+    pub contract: ContractId,
+    /// The offset of the instruction in the bytecode of the contract or script.
+    pub pc: u64,
+    /// The gas left for the transaction, i.e. `$ggas`.
+    pub gas: u64,
+    /// All the registers, only captured for the last steps of the trace.
+    pub registers: Option<Vec<u64>>,
+    /// The Sway code the instruction was compiled from, if the provider has the source map of the
+    /// program, see [`Provider::with_source_maps`].
+    pub location: Option<SourceLocation>,
+}
+
+impl ExecutionTrace {
+    pub fn steps(&self) -> &[TraceStep] {
+        &self.steps
+    }
+
+    pub fn receipts(&self) -> &[Receipt] {
+        &self.receipts
+    }
+
+    /// Returns `true` if the transaction panicked or reverted.
+    pub fn failed(&self) -> bool {
+        self.receipts.iter().any(|receipt| match receipt {
+            Receipt::Panic { .. } | Receipt::Revert { .. } => true,
+            Receipt::ScriptResult { result, .. } => *result != ScriptExecutionResult::Success,
+            _ => false,
+        })
+    }
+
+    /// Returns the last steps, whose registers were captured. The last one is the instruction
+    /// that ended the execution, e.g. the failing one.
+    pub fn last_steps(&self) -> &[TraceStep] {
+        let first = self
+            .steps
+            .iter()
+            .position(|step| step.registers.is_some())
+            .unwrap_or(self.steps.len());
+
+        &self.steps[first..]
+    }
+
+    fn located(mut self, source_maps: Option<&SourceMapRegistry>) -> Self {
+        if let Some(source_maps) = source_maps {
+            for step in &mut self.steps {
+                step.location = source_maps.locate(&step.contract, step.pc);
+            }
+        }
+
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RunResult {
+    state: RunState,
+    breakpoint: Option<Breakpoint>,
+    json_receipts: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum RunState {
+    Completed,
+    Breakpoint,
+}
+
+#[derive(Debug, Deserialize)]
+struct Breakpoint {
+    contract: String,
+    pc: String,
+}
+
+impl Breakpoint {
+    fn location(&self) -> Result<(ContractId, u64)> {
+        let contract = ContractId::from_str(&self.contract)
+            .map_err(|err| error!(Provider, "the debugger sent an invalid contract id: {err}"))?;
+
+        Ok((contract, parse_u64(&self.pc)?))
+    }
+}
+
+fn parse_u64(value: &str) -> Result<u64> {
+    value
+        .parse()
+        .map_err(|err| error!(Provider, "the debugger sent an invalid number: {err}"))
+}
+
+/// A session of the debugger of the node, single stepping through one transaction.
+struct DebugSession<'a> {
+    provider: &'a Provider,
+    id: String,
+}
+
+impl<'a> DebugSession<'a> {
+    async fn start(provider: &'a Provider, tx_json: &str) -> Result<(Self, RunResult)> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Started {
+            start_session: String,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TxStarted {
+            start_tx: RunResult,
+        }
+
+        let id = provider
+            .raw_query::<Started>(START_SESSION, ())
+            .await?
+            .start_session;
+        let session = Self { provider, id };
+
+        let started = async {
+            session
+                .provider
+                .raw_query::<serde_json::Value>(SET_SINGLE_STEPPING, session.variables())
+                .await?;
+
+            let variables = serde_json::json!({ "id": session.id, "tx": tx_json });
+            session
+                .provider
+                .raw_query::<TxStarted>(START_TX, variables)
+                .await
+        }
+        .await;
+
+        match started {
+            Ok(started) => Ok((session, started.start_tx)),
+            Err(err) => {
+                session.end().await;
+                Err(err)
+            }
+        }
+    }
+
+    async fn step(&self) -> Result<RunResult> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Continued {
+            continue_tx: RunResult,
+        }
+
+        Ok(self
+            .provider
+            .raw_query::<Continued>(CONTINUE_TX, self.variables())
+            .await?
+            .continue_tx)
+    }
+
+    async fn register(&self, register: u8) -> Result<u64> {
+        #[derive(Deserialize)]
+        struct Register {
+            register: String,
+        }
+
+        let variables = serde_json::json!({ "id": self.id, "register": register.to_string() });
+        let response = self
+            .provider
+            .raw_query::<Register>(REGISTER, variables)
+            .await?;
+
+        parse_u64(&response.register)
+    }
+
+    async fn registers(&self) -> Result<Vec<u64>> {
+        let mut registers = Vec::with_capacity(VM_REGISTER_COUNT);
+        for register in 0..VM_REGISTER_COUNT as u8 {
+            registers.push(self.register(register).await?);
+        }
+
+        Ok(registers)
+    }
+
+    fn variables(&self) -> serde_json::Value {
+        serde_json::json!({ "id": self.id })
+    }
+
+    /// Sessions that aren't ended are kept by the node until it restarts, so failing to end one
+    /// is not worth failing the trace for.
+    async fn end(self) {
+        let _ = self
+            .provider
+            .raw_query::<serde_json::Value>(END_SESSION, self.variables())
+            .await;
+    }
+}
+
+/// Steps through the whole transaction, capturing the registers of the steps from
+/// `registers_from` on. Returns the steps and the receipts of the transaction.
+async fn run(
+    provider: &Provider,
+    tx_json: &str,
+    registers_from: usize,
+) -> Result<(Vec<TraceStep>, Vec<Receipt>)> {
+    let (session, mut result) = DebugSession::start(provider, tx_json).await?;
+
+    let mut steps = vec![];
+    let traced = async {
+        while result.state == RunState::Breakpoint {
+            let breakpoint = result
+                .breakpoint
+                .as_ref()
+                .ok_or_else(|| error!(Provider, "the debugger stopped without a breakpoint"))?;
+            let (contract, pc) = breakpoint.location()?;
+
+            let registers = if steps.len() >= registers_from {
+                Some(session.registers().await?)
+            } else {
+                None
+            };
+            let gas = match &registers {
+                Some(registers) => registers[RegId::GGAS.to_u8() as usize],
+                None => session.register(RegId::GGAS.to_u8()).await?,
+            };
+
+            steps.push(TraceStep {
+                contract,
+                pc,
+                gas,
+                registers,
+                location: None,
+            });
+            result = session.step().await?;
+        }
+
+        result
+            .json_receipts
+            .iter()
+            .map(|receipt| serde_json::from_str(receipt))
+            .collect::<std::result::Result<Vec<Receipt>, _>>()
+            .map_err(|err| error!(Provider, "the debugger sent an invalid receipt: {err}"))
+    }
+    .await;
+
+    session.end().await;
+
+    Ok((steps, traced?))
+}
+
+impl Provider {
+    /// Executes `tx` instruction by instruction with the debugger of the node and records the
+    /// contract, offset and gas left of every instruction, plus all the registers for the last
+    /// `window` instructions, i.e. around the point where a failing transaction panicked or
+    /// reverted.
+    ///
+    /// Nothing is committed, the transaction is executed against the latest state like a dry
+    /// run. The node must be started with `--debug`, which the test helpers do by default.
+    /// Every instruction costs a few requests to the node, so this is meant for debugging, not
+    /// for production use.
+    pub async fn execution_trace(
+        &self,
+        tx: impl Transaction,
+        window: usize,
+    ) -> Result<ExecutionTrace> {
+        let tx: fuel_tx::Transaction = tx.into();
+        let tx_json = serde_json::to_string(&tx)
+            .map_err(|err| error!(Other, "could not serialize the transaction: {err}"))?;
+
+        // The registers of the last steps are only known to be needed once the transaction has
+        // been executed, so it is executed a second time to capture them.
+        let (steps, receipts) = run(self, &tx_json, usize::MAX).await?;
+        if window == 0 || steps.is_empty() {
+            return Ok(ExecutionTrace { steps, receipts }.located(self.source_maps()));
+        }
+
+        let (steps, receipts) = run(self, &tx_json, steps.len().saturating_sub(window)).await?;
+
+        Ok(ExecutionTrace { steps, receipts }.located(self.source_maps()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(pc: u64, registers: Option<Vec<u64>>) -> TraceStep {
+        TraceStep {
+            contract: ContractId::zeroed(),
+            pc,
+            gas: 100 - pc,
+            registers,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn last_steps_are_those_with_registers() {
+        let trace = ExecutionTrace {
+            steps: vec![
+                step(0, None),
+                step(4, Some(vec![0; 64])),
+                step(8, Some(vec![1; 64])),
+            ],
+            receipts: vec![],
+        };
+
+        let pcs = trace
+            .last_steps()
+            .iter()
+            .map(|step| step.pc)
+            .collect::<Vec<_>>();
+        assert_eq!(pcs, [4, 8]);
+    }
+
+    #[test]
+    fn steps_are_located_with_the_source_maps_of_the_provider() -> Result<()> {
+        let source_maps = SourceMapRegistry::new();
+        source_maps.add_script(fuels_core::debug::SourceMap::from_json(
+            r#"{
+                "paths": ["src/main.sw"],
+                "map": {
+                    "1": { "path": 0, "range": { "start": { "line": 5, "col": 9 }, "end": { "line": 5, "col": 20 } } }
+                }
+            }"#,
+        )?);
+        let trace = || ExecutionTrace {
+            steps: vec![step(0, None), step(4, None)],
+            receipts: vec![],
+        };
+
+        let locations = |trace: ExecutionTrace| {
+            trace
+                .steps
+                .into_iter()
+                .map(|step| step.location.map(|location| location.to_string()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            locations(trace().located(Some(&source_maps))),
+            [None, Some("src/main.sw:5:9".to_string())]
+        );
+        assert_eq!(locations(trace().located(None)), [None, None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn panics_and_reverts_are_failures() {
+        let trace = |receipts| ExecutionTrace {
+            steps: vec![],
+            receipts,
+        };
+
+        assert!(!trace(vec![Receipt::script_result(
+            ScriptExecutionResult::Success,
+            10
+        )])
+        .failed());
+        assert!(trace(vec![Receipt::revert(ContractId::zeroed(), 1, 0, 0)]).failed());
+        assert!(trace(vec![Receipt::script_result(
+            ScriptExecutionResult::Panic,
+            10
+        )])
+        .failed());
+    }
+
+    #[test]
+    fn run_results_are_deserialized() {
+        let result: RunResult = serde_json::from_value(serde_json::json!({
+            "state": "BREAKPOINT",
+            "breakpoint": {
+                "contract": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "pc": "12"
+            },
+            "jsonReceipts": []
+        }))
+        .unwrap();
+
+        assert_eq!(result.state, RunState::Breakpoint);
+        let (contract, pc) = result.breakpoint.unwrap().location().unwrap();
+        let mut expected = [0; 32];
+        expected[31] = 1;
+        assert_eq!(contract, ContractId::new(expected));
+        assert_eq!(pc, 12);
+    }
+}
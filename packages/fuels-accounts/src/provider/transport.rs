@@ -0,0 +1,514 @@
+use std::{fmt::Debug, io};
+
+use async_trait::async_trait;
+use fuel_core_client::client::{
+    pagination::{PaginatedResult, PaginationRequest},
+    types::{
+        gas_price::{EstimateGasPrice, LatestGasPrice},
+        message::MessageStatus,
+        primitives::{BlockId, TransactionId},
+        Balance, Block, ChainInfo, Coin, CoinType, ContractBalance, Message, MessageProof,
+        NodeInfo, TransactionResponse, TransactionStatus,
+    },
+    FuelClient,
+};
+use fuel_core_types::services::executor::TransactionExecutionStatus;
+use fuel_tx::{Transaction, TxId, UtxoId};
+use fuel_types::{Address, AssetId, BlockHeight, ContractId, Nonce};
+#[cfg(all(feature = "raw-graphql", not(target_arch = "wasm32")))]
+use futures::stream::BoxStream;
+#[cfg(all(feature = "raw-graphql", target_arch = "wasm32"))]
+use futures::stream::LocalBoxStream;
+
+/// The `data` of each response to a GraphQL subscription, see [`Transport::raw_subscription`].
+#[cfg(all(feature = "raw-graphql", not(target_arch = "wasm32")))]
+pub type RawSubscription = BoxStream<'static, io::Result<serde_json::Value>>;
+#[cfg(all(feature = "raw-graphql", target_arch = "wasm32"))]
+pub type RawSubscription = LocalBoxStream<'static, io::Result<serde_json::Value>>;
+
+/// Builds the chain info of a transport without a node. Only the height and time of the latest
+/// block are known.
+#[cfg(any(test, feature = "mock-provider", feature = "record-replay"))]
+pub(crate) fn chain_info(
+    name: String,
+    height: u32,
+    time: tai64::Tai64,
+    consensus_parameters: fuel_tx::ConsensusParameters,
+) -> ChainInfo {
+    use fuel_core_client::client::types::block::{Consensus, Header};
+
+    ChainInfo {
+        da_height: 0,
+        name,
+        latest_block: Block {
+            id: Default::default(),
+            header: Header {
+                id: Default::default(),
+                da_height: 0,
+                consensus_parameters_version: 0,
+                state_transition_bytecode_version: 0,
+                transactions_count: 0,
+                message_receipt_count: 0,
+                transactions_root: Default::default(),
+                message_outbox_root: Default::default(),
+                event_inbox_root: Default::default(),
+                height,
+                prev_root: Default::default(),
+                time,
+                application_hash: Default::default(),
+            },
+            consensus: Consensus::Unknown,
+            transactions: vec![],
+        },
+        consensus_parameters,
+    }
+}
+
+/// Queries a block in the compressed form posted to the DA layer. Only nodes compressing blocks
+/// serve `daCompressedBlock`, others reject the query.
+#[cfg(feature = "raw-graphql")]
+const DA_COMPRESSED_BLOCK: &str =
+    "query($height: U32!) { daCompressedBlock(height: $height) { bytes } }";
+
+/// Extracts the bytes of the block from the `data` of a [`DA_COMPRESSED_BLOCK`] response.
+#[cfg(feature = "raw-graphql")]
+fn da_compressed_block_bytes(data: serde_json::Value) -> io::Result<Option<Vec<u8>>> {
+    let invalid = |reason: String| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid DA compressed block: {reason}"),
+        )
+    };
+
+    match data.get("daCompressedBlock") {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(block) => {
+            let bytes = block
+                .get("bytes")
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| invalid("missing `bytes`".to_string()))?;
+
+            hex::decode(bytes.trim_start_matches("0x"))
+                .map(Some)
+                .map_err(|err| invalid(err.to_string()))
+        }
+    }
+}
+
+pub(crate) fn unsupported<T>(method: &str) -> io::Result<T> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("`{method}` is not supported by this transport"),
+    ))
+}
+
+/// The requests a [`Provider`](crate::provider::Provider) sends to a node. Implemented by
+/// [`FuelClient`] for real nodes, and by
+/// [`MockProvider`](crate::provider::MockProvider) for tests without a node.
+///
+/// Every request fails as unsupported unless overridden, so that implementations only need to
+/// provide what they serve. Failed requests are retried according to the
+/// [`RetryConfig`](crate::provider::RetryConfig) of the provider.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Transport: Debug + Send + Sync {
+    async fn health(&self) -> io::Result<bool> {
+        unsupported("health")
+    }
+
+    async fn transaction(&self, _id: &TxId) -> io::Result<Option<TransactionResponse>> {
+        unsupported("transaction")
+    }
+
+    async fn chain_info(&self) -> io::Result<ChainInfo> {
+        unsupported("chain_info")
+    }
+
+    async fn await_transaction_commit(&self, _id: &TxId) -> io::Result<TransactionStatus> {
+        unsupported("await_transaction_commit")
+    }
+
+    async fn submit_and_await_commit(&self, _tx: &Transaction) -> io::Result<TransactionStatus> {
+        unsupported("submit_and_await_commit")
+    }
+
+    async fn submit(&self, _tx: &Transaction) -> io::Result<TransactionId> {
+        unsupported("submit")
+    }
+
+    async fn transaction_status(&self, _id: &TxId) -> io::Result<TransactionStatus> {
+        unsupported("transaction_status")
+    }
+
+    async fn node_info(&self) -> io::Result<NodeInfo> {
+        unsupported("node_info")
+    }
+
+    async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+        unsupported("latest_gas_price")
+    }
+
+    async fn estimate_gas_price(&self, _block_horizon: u32) -> io::Result<EstimateGasPrice> {
+        unsupported("estimate_gas_price")
+    }
+
+    async fn dry_run(&self, _tx: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        unsupported("dry_run")
+    }
+
+    async fn dry_run_opt(
+        &self,
+        _tx: &[Transaction],
+        _utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        unsupported("dry_run_opt")
+    }
+
+    async fn coins(
+        &self,
+        _owner: &Address,
+        _asset_id: Option<&AssetId>,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Coin, String>> {
+        unsupported("coins")
+    }
+
+    async fn coin(&self, _id: &UtxoId) -> io::Result<Option<Coin>> {
+        unsupported("coin")
+    }
+
+    async fn coins_to_spend(
+        &self,
+        _owner: &Address,
+        _spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        _excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<CoinType>>> {
+        unsupported("coins_to_spend")
+    }
+
+    async fn balance(&self, _owner: &Address, _asset_id: Option<&AssetId>) -> io::Result<u64> {
+        unsupported("balance")
+    }
+
+    async fn contract_balance(
+        &self,
+        _id: &ContractId,
+        _asset: Option<&AssetId>,
+    ) -> io::Result<u64> {
+        unsupported("contract_balance")
+    }
+
+    async fn contract_balances(
+        &self,
+        _contract: &ContractId,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ContractBalance, String>> {
+        unsupported("contract_balances")
+    }
+
+    async fn balances(
+        &self,
+        _owner: &Address,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Balance, String>> {
+        unsupported("balances")
+    }
+
+    async fn transactions(
+        &self,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        unsupported("transactions")
+    }
+
+    async fn transactions_by_owner(
+        &self,
+        _owner: &Address,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        unsupported("transactions_by_owner")
+    }
+
+    async fn produce_blocks(
+        &self,
+        _blocks_to_produce: u32,
+        _start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        unsupported("produce_blocks")
+    }
+
+    async fn block(&self, _id: &BlockId) -> io::Result<Option<Block>> {
+        unsupported("block")
+    }
+
+    async fn block_by_height(&self, _height: BlockHeight) -> io::Result<Option<Block>> {
+        unsupported("block_by_height")
+    }
+
+    /// The block at `height` in the compressed form posted to the DA layer.
+    async fn da_compressed_block(&self, _height: BlockHeight) -> io::Result<Option<Vec<u8>>> {
+        unsupported("da_compressed_block")
+    }
+
+    async fn blocks(
+        &self,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Block, String>> {
+        unsupported("blocks")
+    }
+
+    async fn messages(
+        &self,
+        _owner: Option<&Address>,
+        _request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Message, String>> {
+        unsupported("messages")
+    }
+
+    async fn message_status(&self, _nonce: &Nonce) -> io::Result<MessageStatus> {
+        unsupported("message_status")
+    }
+
+    async fn message_proof(
+        &self,
+        _transaction_id: &TxId,
+        _nonce: &Nonce,
+        _commit_block_id: Option<&BlockId>,
+        _commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Option<MessageProof>> {
+        unsupported("message_proof")
+    }
+
+    /// Sends a GraphQL `query` with its `variables` and returns the `data` of the response.
+    #[cfg(feature = "raw-graphql")]
+    async fn raw_query(
+        &self,
+        _query: &str,
+        _variables: &serde_json::Value,
+    ) -> io::Result<serde_json::Value> {
+        unsupported("raw_query")
+    }
+
+    /// Starts a GraphQL `subscription` with its `variables`. Errors reported by the node in the
+    /// responses should be of kind [`io::ErrorKind::InvalidData`], other errors are treated as
+    /// a lost connection and the subscription is started again.
+    #[cfg(feature = "raw-graphql")]
+    async fn raw_subscription(
+        &self,
+        _subscription: &str,
+        _variables: &serde_json::Value,
+    ) -> io::Result<RawSubscription> {
+        unsupported("raw_subscription")
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for FuelClient {
+    async fn health(&self) -> io::Result<bool> {
+        FuelClient::health(self).await
+    }
+
+    async fn transaction(&self, id: &TxId) -> io::Result<Option<TransactionResponse>> {
+        FuelClient::transaction(self, id).await
+    }
+
+    async fn chain_info(&self) -> io::Result<ChainInfo> {
+        FuelClient::chain_info(self).await
+    }
+
+    async fn await_transaction_commit(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        FuelClient::await_transaction_commit(self, id).await
+    }
+
+    async fn submit_and_await_commit(&self, tx: &Transaction) -> io::Result<TransactionStatus> {
+        FuelClient::submit_and_await_commit(self, tx).await
+    }
+
+    async fn submit(&self, tx: &Transaction) -> io::Result<TransactionId> {
+        FuelClient::submit(self, tx).await
+    }
+
+    async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
+        FuelClient::transaction_status(self, id).await
+    }
+
+    async fn node_info(&self) -> io::Result<NodeInfo> {
+        FuelClient::node_info(self).await
+    }
+
+    async fn latest_gas_price(&self) -> io::Result<LatestGasPrice> {
+        FuelClient::latest_gas_price(self).await
+    }
+
+    async fn estimate_gas_price(&self, block_horizon: u32) -> io::Result<EstimateGasPrice> {
+        FuelClient::estimate_gas_price(self, block_horizon)
+            .await
+            .map(Into::into)
+    }
+
+    async fn dry_run(&self, tx: &[Transaction]) -> io::Result<Vec<TransactionExecutionStatus>> {
+        FuelClient::dry_run(self, tx).await
+    }
+
+    async fn dry_run_opt(
+        &self,
+        tx: &[Transaction],
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        FuelClient::dry_run_opt(self, tx, utxo_validation).await
+    }
+
+    async fn coins(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Coin, String>> {
+        FuelClient::coins(self, owner, asset_id, request).await
+    }
+
+    async fn coin(&self, id: &UtxoId) -> io::Result<Option<Coin>> {
+        FuelClient::coin(self, id).await
+    }
+
+    async fn coins_to_spend(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<CoinType>>> {
+        FuelClient::coins_to_spend(self, owner, spend_query, excluded_ids).await
+    }
+
+    async fn balance(&self, owner: &Address, asset_id: Option<&AssetId>) -> io::Result<u64> {
+        FuelClient::balance(self, owner, asset_id).await
+    }
+
+    async fn contract_balance(&self, id: &ContractId, asset: Option<&AssetId>) -> io::Result<u64> {
+        FuelClient::contract_balance(self, id, asset).await
+    }
+
+    async fn contract_balances(
+        &self,
+        contract: &ContractId,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<ContractBalance, String>> {
+        FuelClient::contract_balances(self, contract, request).await
+    }
+
+    async fn balances(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Balance, String>> {
+        FuelClient::balances(self, owner, request).await
+    }
+
+    async fn transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        FuelClient::transactions(self, request).await
+    }
+
+    async fn transactions_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<TransactionResponse, String>> {
+        FuelClient::transactions_by_owner(self, owner, request).await
+    }
+
+    async fn produce_blocks(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        FuelClient::produce_blocks(self, blocks_to_produce, start_timestamp).await
+    }
+
+    async fn block(&self, id: &BlockId) -> io::Result<Option<Block>> {
+        FuelClient::block(self, id).await
+    }
+
+    async fn block_by_height(&self, height: BlockHeight) -> io::Result<Option<Block>> {
+        FuelClient::block_by_height(self, height).await
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn da_compressed_block(&self, height: BlockHeight) -> io::Result<Option<Vec<u8>>> {
+        let variables = serde_json::json!({ "height": (*height).to_string() });
+        let data = Transport::raw_query(self, DA_COMPRESSED_BLOCK, &variables).await?;
+
+        da_compressed_block_bytes(data)
+    }
+
+    async fn blocks(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Block, String>> {
+        FuelClient::blocks(self, request).await
+    }
+
+    async fn messages(
+        &self,
+        owner: Option<&Address>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<Message, String>> {
+        FuelClient::messages(self, owner, request).await
+    }
+
+    async fn message_status(&self, nonce: &Nonce) -> io::Result<MessageStatus> {
+        FuelClient::message_status(self, nonce).await
+    }
+
+    async fn message_proof(
+        &self,
+        transaction_id: &TxId,
+        nonce: &Nonce,
+        commit_block_id: Option<&BlockId>,
+        commit_block_height: Option<BlockHeight>,
+    ) -> io::Result<Option<MessageProof>> {
+        FuelClient::message_proof(
+            self,
+            transaction_id,
+            nonce,
+            commit_block_id,
+            commit_block_height,
+        )
+        .await
+    }
+
+    #[cfg(feature = "raw-graphql")]
+    async fn raw_query(
+        &self,
+        query: &str,
+        variables: &serde_json::Value,
+    ) -> io::Result<serde_json::Value> {
+        let operation =
+            cynic::Operation::<serde_json::Value, _>::new(query.to_string(), variables.clone());
+
+        FuelClient::query(self, operation).await
+    }
+}
+
+#[cfg(all(test, feature = "raw-graphql"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn da_compressed_blocks_are_decoded() -> io::Result<()> {
+        let block = serde_json::json!({ "daCompressedBlock": { "bytes": "0x010203" } });
+        assert_eq!(da_compressed_block_bytes(block)?, Some(vec![1, 2, 3]));
+
+        let missing = serde_json::json!({ "daCompressedBlock": null });
+        assert_eq!(da_compressed_block_bytes(missing)?, None);
+
+        let invalid = serde_json::json!({ "daCompressedBlock": { "bytes": "0xzz" } });
+        let err = da_compressed_block_bytes(invalid).expect_err("should fail");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,547 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use async_trait::async_trait;
+use fuel_asm::Instruction;
+use fuel_crypto::{Message, Signature};
+use fuel_tx::{
+    field::{Inputs, Outputs, Script as ScriptField, ScriptData},
+    Address, AssetId, ConsensusParameters, ContractId, Script, Transaction as FuelTransaction,
+    UniqueIdentifier,
+};
+use fuels_core::{
+    call_instructions::decode_call_script,
+    constants::WORD_SIZE,
+    offsets::call_script_data_offset,
+    traits::Signer,
+    types::{
+        bech32::{Bech32Address, Bech32ContractId},
+        errors::{
+            transaction::{PolicyViolation, Reason},
+            Error, Result,
+        },
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        Selector,
+    },
+};
+
+use crate::{
+    accounts_utils::try_provider_error, provider::Provider, signing_policy::outflows,
+    wallet::WalletUnlocked, Account, CoinFilter, ViewOnlyAccount,
+};
+
+/// What a [`SessionAccount`] is allowed to sign: calls to the methods of a single contract,
+/// spending at most the given allowances, until a block height.
+#[derive(Debug, Clone)]
+pub struct SessionScope {
+    contract_id: ContractId,
+    selectors: Vec<Selector>,
+    allowances: HashMap<AssetId, u64>,
+    expiry_height: u32,
+}
+
+impl SessionScope {
+    /// Creates a scope allowing calls to `contract_id` until the block at `expiry_height` is
+    /// produced. No method can be called and no asset can be spent until they are added.
+    pub fn new(contract_id: &Bech32ContractId, expiry_height: u32) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            selectors: vec![],
+            allowances: HashMap::new(),
+            expiry_height,
+        }
+    }
+
+    /// Allows calling the methods with the given encoded selectors, see
+    /// [`encode_fn_selector`](fuels_core::codec::encode_fn_selector).
+    pub fn with_selectors(mut self, selectors: impl IntoIterator<Item = Selector>) -> Self {
+        self.selectors.extend(selectors);
+        self
+    }
+
+    /// Allows spending at most `amount` of `asset_id` over the whole session, counting both the
+    /// amounts forwarded to the contract and the coins sent to other addresses.
+    pub fn with_max_spend(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.allowances.insert(asset_id, amount);
+        self
+    }
+
+    pub fn expiry_height(&self) -> u32 {
+        self.expiry_height
+    }
+}
+
+/// An account backed by a short-lived session key that only signs transactions within its
+/// [`SessionScope`]. The main account delegates authority by funding the session address, the
+/// main key is never handed to the session.
+///
+/// The checks are done by the SDK when signing and are not enforced on-chain. Only scripts built
+/// by the SDK for contract calls are signed, so that what the transaction does can be read from
+/// its script data. Clones share the spending recorded against the allowances. Spending is
+/// recorded whenever the session key signs, including when only simulating a call.
+#[derive(Debug, Clone)]
+pub struct SessionAccount {
+    wallet: WalletUnlocked,
+    scope: SessionScope,
+    spent: Arc<Mutex<HashMap<AssetId, u64>>>,
+}
+
+impl SessionAccount {
+    /// Generates a random session key restricted to `scope`.
+    pub fn generate(scope: SessionScope, provider: Provider) -> Self {
+        Self {
+            wallet: WalletUnlocked::new_random(Some(provider)),
+            scope,
+            spent: Default::default(),
+        }
+    }
+
+    pub fn scope(&self) -> &SessionScope {
+        &self.scope
+    }
+
+    /// Returns the amount of `asset_id` spent by the transactions signed so far.
+    pub fn spent(&self, asset_id: &AssetId) -> u64 {
+        self.spent
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(asset_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn enforce_scope(
+        &self,
+        tx: &Script,
+        consensus_parameters: &ConsensusParameters,
+        base_asset_id: &AssetId,
+    ) -> Result<()> {
+        let scope = &self.scope;
+
+        let denied_contract = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.contract_id())
+            .find(|contract_id| **contract_id != scope.contract_id);
+        if let Some(contract_id) = denied_contract {
+            return Err(violation(PolicyViolation::ContractNotAllowed(*contract_id)));
+        }
+
+        let calls = decode_contract_calls(tx.script(), tx.script_data(), consensus_parameters)
+            .ok_or_else(|| violation(PolicyViolation::UnknownScript))?;
+        if calls.is_empty() {
+            return Err(violation(PolicyViolation::MissingContractCall(
+                scope.contract_id,
+            )));
+        }
+
+        let mut spending: HashMap<AssetId, u64> = HashMap::new();
+        for call in &calls {
+            if call.contract_id != scope.contract_id {
+                return Err(violation(PolicyViolation::ContractNotAllowed(
+                    call.contract_id,
+                )));
+            }
+
+            if !scope.selectors.contains(&call.selector) {
+                return Err(violation(PolicyViolation::SelectorNotAllowed(
+                    call.selector.clone(),
+                )));
+            }
+
+            for (asset_id, amount) in &call.forwarded {
+                if *amount > 0 {
+                    let spent = spending.entry(*asset_id).or_default();
+                    *spent = spent.saturating_add(*amount);
+                }
+            }
+        }
+
+        // The call script only transfers to the called contract, so variable outputs can only be
+        // filled by the contract from its own balance. Coins and change sent elsewhere are
+        // spent by the session.
+        let session_address = Address::from(self.address());
+        for outflow in outflows(&session_address, tx.inputs(), tx.outputs(), base_asset_id) {
+            if outflow.recipient.is_some() {
+                let spent = spending.entry(outflow.asset_id).or_default();
+                *spent = spent.saturating_add(outflow.amount);
+            }
+        }
+
+        let mut total_spent = self.spent.lock().unwrap_or_else(PoisonError::into_inner);
+        for (asset_id, amount) in &spending {
+            let allowance = scope.allowances.get(asset_id).copied().unwrap_or_default();
+            let already_spent = total_spent.get(asset_id).copied().unwrap_or_default();
+            let remaining = allowance.saturating_sub(already_spent);
+
+            if *amount > remaining {
+                return Err(violation(PolicyViolation::SpendLimit {
+                    asset_id: *asset_id,
+                    amount: *amount,
+                    remaining,
+                }));
+            }
+        }
+
+        for (asset_id, amount) in spending {
+            *total_spent.entry(asset_id).or_default() += amount;
+        }
+
+        Ok(())
+    }
+}
+
+impl ViewOnlyAccount for SessionAccount {
+    fn address(&self) -> &Bech32Address {
+        self.wallet.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.wallet.provider().ok_or_else(try_provider_error)
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Account for SessionAccount {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.wallet
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        self.wallet
+            .get_asset_inputs_for_amount_filtered(asset_id, amount, filter)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(SessionSigner {
+            session: self.clone(),
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Signs with the session key the transactions within the scope of the session, as long as it
+/// has not expired.
+struct SessionSigner {
+    session: SessionAccount,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for SessionSigner {
+    /// Bare messages are out of the scope of any session.
+    async fn sign(&self, _message: Message) -> Result<Signature> {
+        Err(violation(PolicyViolation::BlindSigning))
+    }
+
+    async fn sign_transaction(&self, tx: &FuelTransaction, message: Message) -> Result<Signature> {
+        let session = &self.session;
+        let provider = session.try_provider()?;
+
+        let expiry_height = session.scope.expiry_height;
+        let current_height = provider.latest_block_height().await?;
+        if current_height >= expiry_height {
+            return Err(violation(PolicyViolation::SessionExpired {
+                expiry_height,
+                current_height,
+            }));
+        }
+
+        if message != Message::from_bytes(*tx.id(&provider.chain_id())) {
+            return Err(violation(PolicyViolation::BlindSigning));
+        }
+
+        let FuelTransaction::Script(script) = tx else {
+            return Err(violation(PolicyViolation::UnknownScript));
+        };
+        session.enforce_scope(
+            script,
+            provider.consensus_parameters(),
+            provider.base_asset_id(),
+        )?;
+
+        session.wallet.sign(message).await
+    }
+
+    fn address(&self) -> &Bech32Address {
+        self.session.address()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ContractCall {
+    contract_id: ContractId,
+    selector: Selector,
+    /// The assets transferred before the call and the amount forwarded by the call itself.
+    forwarded: Vec<(AssetId, u64)>,
+}
+
+/// Decodes the calls of an SDK contract call script, see
+/// [`decode_call_script`]. Returns `None` if `script` isn't one or its parameters can't be read
+/// from `script_data`. The selector is read where the called contract reads it, i.e. through the
+/// offset stored after the contract ID, as a length prefixed method name.
+fn decode_contract_calls(
+    script: &[u8],
+    script_data: &[u8],
+    consensus_parameters: &ConsensusParameters,
+) -> Option<Vec<ContractCall>> {
+    let offsets = decode_call_script(script)?;
+    let data_offset = call_script_data_offset(
+        consensus_parameters,
+        script.len().checked_sub(Instruction::SIZE)?,
+    )
+    .ok()?;
+
+    let read = |offset: usize, len: usize| -> Option<&[u8]> {
+        let start = offset.checked_sub(data_offset)?;
+        script_data.get(start..start.checked_add(len)?)
+    };
+    let read_word = |offset: usize| -> Option<usize> {
+        let word = u64::from_be_bytes(read(offset, WORD_SIZE)?.try_into().ok()?);
+        usize::try_from(word).ok()
+    };
+    let read_asset = |amount_offset: usize, asset_id_offset: usize| -> Option<(AssetId, u64)> {
+        let amount = u64::from_be_bytes(read(amount_offset, WORD_SIZE)?.try_into().ok()?);
+        let asset_id = AssetId::try_from(read(asset_id_offset, AssetId::LEN)?).ok()?;
+        Some((asset_id, amount))
+    };
+
+    offsets
+        .iter()
+        .map(|offsets| {
+            let contract_id =
+                ContractId::try_from(read(offsets.call_data_offset, ContractId::LEN)?).ok()?;
+
+            let selector_offset = read_word(offsets.call_data_offset + ContractId::LEN)?;
+            let name_len = read_word(selector_offset)?;
+            let selector = read(selector_offset, WORD_SIZE.checked_add(name_len)?)?.to_vec();
+
+            let forwarded = offsets
+                .forwarded_assets_offsets
+                .iter()
+                .chain([&(offsets.amount_offset, offsets.asset_id_offset)])
+                .map(|(amount_offset, asset_id_offset)| {
+                    read_asset(*amount_offset, *asset_id_offset)
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            Some(ContractCall {
+                contract_id,
+                selector,
+                forwarded,
+            })
+        })
+        .collect()
+}
+
+fn violation(violation: PolicyViolation) -> Error {
+    Error::Transaction(Reason::PolicyViolation(violation))
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::{op, RegId};
+    use fuel_tx::{Output, TxPointer, UtxoId};
+    use fuels_core::{
+        call_instructions::{
+            get_instructions, get_single_call_instructions, CallOpcodeParamsOffset, CallRegisters,
+        },
+        codec::encode_fn_selector,
+    };
+
+    use super::*;
+
+    /// Builds the script and script data of SDK calls forwarding `amount` of `asset_id` to the
+    /// methods `name` of `contract_id`.
+    fn call_script(calls: &[(ContractId, &str, u64, AssetId)]) -> (Vec<u8>, Vec<u8>) {
+        let call_len = get_single_call_instructions(&Default::default(), &CallRegisters::default())
+            .expect("valid offsets")
+            .len();
+        let data_offset =
+            call_script_data_offset(&ConsensusParameters::default(), calls.len() * call_len)
+                .expect("small script");
+
+        let mut script_data = vec![];
+        let mut offsets = vec![];
+        for (contract_id, name, amount, asset_id) in calls {
+            let amount_offset = data_offset + script_data.len();
+            let call_data_offset = amount_offset + WORD_SIZE + AssetId::LEN;
+            let selector_offset = call_data_offset + ContractId::LEN + 2 * WORD_SIZE;
+            let selector = encode_fn_selector(name);
+
+            script_data.extend(
+                [
+                    amount.to_be_bytes().as_slice(),
+                    asset_id.as_slice(),
+                    contract_id.as_slice(),
+                    &(selector_offset as u64).to_be_bytes(),
+                    &((selector_offset + selector.len()) as u64).to_be_bytes(),
+                    &selector,
+                ]
+                .concat(),
+            );
+            offsets.push(CallOpcodeParamsOffset {
+                call_data_offset,
+                amount_offset,
+                asset_id_offset: amount_offset + WORD_SIZE,
+                ..Default::default()
+            });
+        }
+
+        let script = [
+            get_instructions(&offsets, &CallRegisters::default()).expect("valid offsets"),
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+        ]
+        .concat();
+
+        (script, script_data)
+    }
+
+    fn session(scope: SessionScope) -> SessionAccount {
+        SessionAccount {
+            wallet: WalletUnlocked::new_random(None),
+            scope,
+            spent: Default::default(),
+        }
+    }
+
+    fn script_tx(
+        (script, script_data): (Vec<u8>, Vec<u8>),
+        inputs: Vec<fuel_tx::Input>,
+        outputs: Vec<Output>,
+    ) -> Script {
+        FuelTransaction::script(
+            0,
+            script,
+            script_data,
+            Default::default(),
+            inputs,
+            outputs,
+            vec![],
+        )
+    }
+
+    fn policy_violation(err: Error) -> PolicyViolation {
+        match err {
+            Error::Transaction(Reason::PolicyViolation(violation)) => violation,
+            other => panic!("expected a policy violation, got: {other}"),
+        }
+    }
+
+    #[test]
+    fn contract_calls_are_decoded_from_the_call_script() {
+        let contract_id = ContractId::from([1; 32]);
+        let other_contract_id = ContractId::from([2; 32]);
+        let asset_id = AssetId::from([3; 32]);
+
+        let (script, script_data) = call_script(&[
+            (contract_id, "deposit", 10, asset_id),
+            (other_contract_id, "other", 0, AssetId::zeroed()),
+        ]);
+
+        let calls = decode_contract_calls(&script, &script_data, &ConsensusParameters::default());
+
+        assert_eq!(
+            calls,
+            Some(vec![
+                ContractCall {
+                    contract_id,
+                    selector: encode_fn_selector("deposit"),
+                    forwarded: vec![(asset_id, 10)],
+                },
+                ContractCall {
+                    contract_id: other_contract_id,
+                    selector: encode_fn_selector("other"),
+                    forwarded: vec![(AssetId::zeroed(), 0)],
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn scripts_other_than_call_scripts_are_rejected() {
+        let contract_id = ContractId::from([1; 32]);
+        let scope = SessionScope::new(&contract_id.into(), 100)
+            .with_selectors([encode_fn_selector("play")]);
+
+        let (script, script_data) = call_script(&[(contract_id, "play", 0, AssetId::zeroed())]);
+        // the script data of a valid call, with a script draining the session into a variable
+        // output after the call
+        let drain = op::tro(
+            RegId::new(0x10),
+            RegId::new(0x11),
+            RegId::new(0x11),
+            RegId::new(0x12),
+        );
+        let tampered = [
+            &script[..script.len() - Instruction::SIZE],
+            &drain.to_bytes(),
+            &script[script.len() - Instruction::SIZE..],
+        ]
+        .concat();
+
+        let tx = script_tx((tampered, script_data), vec![], vec![]);
+        let err = session(scope)
+            .enforce_scope(&tx, &ConsensusParameters::default(), &AssetId::zeroed())
+            .expect_err("should fail");
+
+        assert_eq!(policy_violation(err), PolicyViolation::UnknownScript);
+    }
+
+    #[test]
+    fn forwarded_amounts_and_change_sent_elsewhere_are_spent() -> Result<()> {
+        let contract_id = ContractId::from([1; 32]);
+        let asset_id = AssetId::zeroed();
+        let scope = SessionScope::new(&contract_id.into(), 100)
+            .with_selectors([encode_fn_selector("deposit")])
+            .with_max_spend(asset_id, 100);
+        let session = session(scope);
+
+        let coin = fuel_tx::Input::coin_signed(
+            UtxoId::default(),
+            session.address().into(),
+            1000,
+            asset_id,
+            TxPointer::default(),
+            0,
+        );
+        let calls = || call_script(&[(contract_id, "deposit", 40, asset_id)]);
+
+        let back_to_session = Output::change(session.address().into(), 0, asset_id);
+        let tx = script_tx(calls(), vec![coin.clone()], vec![back_to_session]);
+        session.enforce_scope(&tx, &ConsensusParameters::default(), &asset_id)?;
+        assert_eq!(session.spent(&asset_id), 40);
+
+        let to_stranger = Output::change(Address::from([9; 32]), 0, asset_id);
+        let tx = script_tx(calls(), vec![coin], vec![to_stranger]);
+        let err = session
+            .enforce_scope(&tx, &ConsensusParameters::default(), &asset_id)
+            .expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::SpendLimit {
+                asset_id,
+                amount: 1040,
+                remaining: 60
+            }
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,684 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use fuel_tx::{
+    field::{Inputs, Outputs},
+    Address, AssetId, ContractId, Input, Output, Transaction as FuelTransaction, TxId,
+};
+use fuels_core::types::{
+    bech32::{Bech32Address, Bech32ContractId},
+    errors::{
+        transaction::{PolicyViolation, Reason},
+        Error, Result,
+    },
+};
+
+/// Restrictions enforced by a [`WalletUnlocked`](crate::wallet::WalletUnlocked) when it signs a
+/// transaction, whether through a transaction builder or a direct call to
+/// [`Signer::sign_transaction`](fuels_core::traits::Signer::sign_transaction). Meant as a safety
+/// net for hot wallets used by services. A wallet with a policy refuses to sign bare messages, as
+/// it can't check what they authorize.
+///
+/// Every output that can move the wallet's coins is counted:
+/// - coin outputs sent to addresses other than the wallet, by their amount;
+/// - change outputs sent to other addresses, by the wallet's inputs of the asset left over after
+///   the coin outputs;
+/// - variable outputs, whose recipients and amounts are only known once the script runs, by the
+///   wallet's inputs left over after the coin outputs, for every asset. Their recipients are
+///   unknown, so they are rejected when the recipients are restricted.
+///
+/// Assets transferred to contracts by scripts are not counted and should be restricted with
+/// [`SigningPolicy::with_allowed_contracts`] instead.
+///
+/// Clones of a policy share the spending recorded for the daily limits. The amounts sent by a
+/// transaction are reserved when the wallet signs it, so that transactions signed back to back
+/// can't exceed the limits together, and recorded as spent once the wallet's provider submits
+/// it. The reservation is released if the transaction is only simulated or its submission fails.
+#[derive(Debug, Clone, Default)]
+pub struct SigningPolicy {
+    max_amount_per_tx: HashMap<AssetId, u64>,
+    max_amount_per_day: HashMap<AssetId, u64>,
+    allowed_recipients: Option<HashSet<Address>>,
+    allowed_asset_ids: Option<HashSet<AssetId>>,
+    allowed_contracts: Option<HashSet<ContractId>>,
+    daily_spending: Arc<Mutex<VecDeque<Spending>>>,
+    signed: Arc<Mutex<HashMap<TxId, Signed>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Spending {
+    time: DateTime<Utc>,
+    asset_id: AssetId,
+    amount: u64,
+}
+
+/// The amounts sent by a transaction signed but not submitted yet.
+#[derive(Debug, Clone)]
+struct Signed {
+    time: DateTime<Utc>,
+    sent_amounts: HashMap<AssetId, u64>,
+    /// Whether the amounts count towards the daily limits until the transaction is submitted.
+    reserved: bool,
+}
+
+/// Value leaving the wallet through an output. `recipient` is `None` for variable outputs.
+pub(crate) struct Outflow {
+    pub recipient: Option<Address>,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+impl SigningPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the amount of `asset_id` sent by a single transaction.
+    pub fn with_max_amount_per_tx(mut self, asset_id: AssetId, limit: u64) -> Self {
+        self.max_amount_per_tx.insert(asset_id, limit);
+        self
+    }
+
+    /// Limits the amount of `asset_id` sent within any 24 hour window.
+    pub fn with_max_amount_per_day(mut self, asset_id: AssetId, limit: u64) -> Self {
+        self.max_amount_per_day.insert(asset_id, limit);
+        self
+    }
+
+    /// Only allows sending coins to the given addresses.
+    pub fn with_allowed_recipients(
+        mut self,
+        recipients: impl IntoIterator<Item = Bech32Address>,
+    ) -> Self {
+        self.allowed_recipients = Some(recipients.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only allows sending coins of the given assets.
+    pub fn with_allowed_asset_ids(mut self, asset_ids: impl IntoIterator<Item = AssetId>) -> Self {
+        self.allowed_asset_ids = Some(asset_ids.into_iter().collect());
+        self
+    }
+
+    /// Only allows transactions whose contract inputs are among the given contracts.
+    pub fn with_allowed_contracts(
+        mut self,
+        contract_ids: impl IntoIterator<Item = Bech32ContractId>,
+    ) -> Self {
+        self.allowed_contracts = Some(contract_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns `true` if `other` is a clone of this policy, sharing its recorded spending.
+    pub(crate) fn is_shared_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.daily_spending, &other.daily_spending)
+    }
+
+    /// Checks the transaction `tx_id` about to be signed by `owner` and reserves the amounts it
+    /// sends until it is submitted, see [`SigningPolicy::record_submission`] and
+    /// [`SigningPolicy::release`].
+    pub(crate) fn check(
+        &self,
+        owner: &Bech32Address,
+        tx: &FuelTransaction,
+        tx_id: TxId,
+        base_asset_id: &AssetId,
+    ) -> Result<()> {
+        let (inputs, outputs): (&[Input], &[Output]) = match tx {
+            FuelTransaction::Script(tx) => (tx.inputs(), tx.outputs()),
+            FuelTransaction::Create(tx) => (tx.inputs(), tx.outputs()),
+            FuelTransaction::Upgrade(tx) => (tx.inputs(), tx.outputs()),
+            FuelTransaction::Upload(tx) => (tx.inputs(), tx.outputs()),
+            FuelTransaction::Mint(_) => (&[], &[]),
+        };
+
+        let sent_amounts = self.sent_amounts(owner, inputs, outputs, base_asset_id)?;
+
+        let now = Utc::now();
+        let mut signed = self.signed.lock().unwrap_or_else(PoisonError::into_inner);
+        let window_start = now - Duration::days(1);
+        signed.retain(|_, signed| signed.time > window_start);
+
+        // signing the same transaction again doesn't reserve its amounts twice
+        let reserved = signed
+            .iter()
+            .filter(|(id, signed)| **id != tx_id && signed.reserved)
+            .flat_map(|(_, signed)| &signed.sent_amounts);
+        self.check_daily_limits(&sent_amounts, reserved, now)?;
+
+        signed.insert(
+            tx_id,
+            Signed {
+                time: now,
+                sent_amounts,
+                reserved: true,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Stops counting the amounts of `tx_id` towards the daily limits, because it was only
+    /// simulated or its submission failed. They are still recorded if it is submitted later.
+    pub(crate) fn release(&self, tx_id: &TxId) {
+        if let Some(signed) = self
+            .signed
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get_mut(tx_id)
+        {
+            signed.reserved = false;
+        }
+    }
+
+    /// Records the amounts sent by `tx_id` towards the daily limits, if it was signed under
+    /// this policy. Called by the provider after submitting a transaction.
+    pub(crate) fn record_submission(&self, tx_id: &TxId) {
+        let signed = self
+            .signed
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(tx_id);
+
+        if let Some(signed) = signed {
+            self.record_daily_spending(signed.sent_amounts, Utc::now());
+        }
+    }
+
+    /// Checks the inputs and outputs of a transaction signed by `owner` against the
+    /// restrictions and returns the amounts it sends.
+    fn sent_amounts(
+        &self,
+        owner: &Bech32Address,
+        inputs: &[Input],
+        outputs: &[Output],
+        base_asset_id: &AssetId,
+    ) -> Result<HashMap<AssetId, u64>> {
+        if let Some(allowed_contracts) = &self.allowed_contracts {
+            let denied_contract = inputs
+                .iter()
+                .filter_map(Input::contract_id)
+                .find(|contract_id| !allowed_contracts.contains(contract_id));
+
+            if let Some(contract_id) = denied_contract {
+                return Err(violation(PolicyViolation::ContractNotAllowed(*contract_id)));
+            }
+        }
+
+        let owner = Address::from(owner);
+        let mut sent_amounts: HashMap<AssetId, u64> = HashMap::new();
+        for outflow in outflows(&owner, inputs, outputs, base_asset_id) {
+            match (&self.allowed_recipients, outflow.recipient) {
+                (Some(allowed), Some(recipient)) if !allowed.contains(&recipient) => {
+                    return Err(violation(PolicyViolation::RecipientNotAllowed(recipient)));
+                }
+                (Some(_), None) => {
+                    return Err(violation(PolicyViolation::UnknownRecipient {
+                        asset_id: outflow.asset_id,
+                        amount: outflow.amount,
+                    }));
+                }
+                _ => {}
+            }
+
+            if self
+                .allowed_asset_ids
+                .as_ref()
+                .is_some_and(|allowed| !allowed.contains(&outflow.asset_id))
+            {
+                return Err(violation(PolicyViolation::AssetNotAllowed(
+                    outflow.asset_id,
+                )));
+            }
+
+            let sent = sent_amounts.entry(outflow.asset_id).or_default();
+            *sent = sent.saturating_add(outflow.amount);
+        }
+
+        for (asset_id, amount) in &sent_amounts {
+            if let Some(&limit) = self.max_amount_per_tx.get(asset_id) {
+                if *amount > limit {
+                    return Err(violation(PolicyViolation::TxAmountLimit {
+                        asset_id: *asset_id,
+                        amount: *amount,
+                        limit,
+                    }));
+                }
+            }
+        }
+
+        Ok(sent_amounts)
+    }
+
+    /// Checks `sent_amounts` against what is left of the daily limits after the recorded
+    /// spending and the `reserved` amounts.
+    fn check_daily_limits<'a>(
+        &self,
+        sent_amounts: &HashMap<AssetId, u64>,
+        reserved: impl Iterator<Item = (&'a AssetId, &'a u64)>,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let mut daily_spending = self
+            .daily_spending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let window_start = now - Duration::days(1);
+        while daily_spending
+            .front()
+            .is_some_and(|spending| spending.time <= window_start)
+        {
+            daily_spending.pop_front();
+        }
+
+        let mut spent: HashMap<AssetId, u64> = HashMap::new();
+        let recorded = daily_spending
+            .iter()
+            .map(|spending| (&spending.asset_id, &spending.amount));
+        for (asset_id, amount) in recorded.chain(reserved) {
+            let total = spent.entry(*asset_id).or_default();
+            *total = total.saturating_add(*amount);
+        }
+
+        for (asset_id, amount) in sent_amounts {
+            let Some(&limit) = self.max_amount_per_day.get(asset_id) else {
+                continue;
+            };
+
+            let spent = spent.get(asset_id).copied().unwrap_or_default();
+            let remaining = limit.saturating_sub(spent);
+
+            if *amount > remaining {
+                return Err(violation(PolicyViolation::DailyAmountLimit {
+                    asset_id: *asset_id,
+                    amount: *amount,
+                    remaining,
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn record_daily_spending(&self, sent_amounts: HashMap<AssetId, u64>, now: DateTime<Utc>) {
+        self.daily_spending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(
+                sent_amounts
+                    .into_iter()
+                    .filter(|(asset_id, _)| self.max_amount_per_day.contains_key(asset_id))
+                    .map(|(asset_id, amount)| Spending {
+                        time: now,
+                        asset_id,
+                        amount,
+                    }),
+            );
+    }
+}
+
+/// Returns the value that can leave `owner` through `outputs`, see [`SigningPolicy`].
+pub(crate) fn outflows(
+    owner: &Address,
+    inputs: &[Input],
+    outputs: &[Output],
+    base_asset_id: &AssetId,
+) -> Vec<Outflow> {
+    let mut owned: HashMap<AssetId, u64> = HashMap::new();
+    for input in inputs {
+        if input.input_owner() != Some(owner) {
+            continue;
+        }
+
+        if let (Some(asset_id), Some(amount)) = (input.asset_id(base_asset_id), input.amount()) {
+            let total = owned.entry(*asset_id).or_default();
+            *total = total.saturating_add(amount);
+        }
+    }
+
+    let mut coin_outputs: HashMap<AssetId, u64> = HashMap::new();
+    for output in outputs {
+        if let Output::Coin {
+            amount, asset_id, ..
+        } = output
+        {
+            let total = coin_outputs.entry(*asset_id).or_default();
+            *total = total.saturating_add(*amount);
+        }
+    }
+    let left_over = |asset_id: &AssetId| {
+        let owned = owned.get(asset_id).copied().unwrap_or_default();
+        owned.saturating_sub(coin_outputs.get(asset_id).copied().unwrap_or_default())
+    };
+
+    let mut outflows = vec![];
+    for output in outputs {
+        match output {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } if to != owner => outflows.push(Outflow {
+                recipient: Some(*to),
+                asset_id: *asset_id,
+                amount: *amount,
+            }),
+            Output::Change { to, asset_id, .. } if to != owner => {
+                let amount = left_over(asset_id);
+                if amount > 0 {
+                    outflows.push(Outflow {
+                        recipient: Some(*to),
+                        asset_id: *asset_id,
+                        amount,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if outputs
+        .iter()
+        .any(|output| matches!(output, Output::Variable { .. }))
+    {
+        outflows.extend(owned.keys().filter_map(|asset_id| {
+            let amount = left_over(asset_id);
+            (amount > 0).then_some(Outflow {
+                recipient: None,
+                asset_id: *asset_id,
+                amount,
+            })
+        }));
+    }
+
+    outflows
+}
+
+fn violation(violation: PolicyViolation) -> Error {
+    Error::Transaction(Reason::PolicyViolation(violation))
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{Bytes32, TxPointer, UtxoId};
+    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+
+    use super::*;
+
+    fn owner() -> Bech32Address {
+        Bech32Address::new(FUEL_BECH32_HRP, [1; 32])
+    }
+
+    fn recipient() -> Bech32Address {
+        Bech32Address::new(FUEL_BECH32_HRP, [2; 32])
+    }
+
+    fn coin_to(recipient: &Bech32Address, amount: u64, asset_id: AssetId) -> Output {
+        Output::coin(recipient.into(), amount, asset_id)
+    }
+
+    fn owned_coin(amount: u64, asset_id: AssetId) -> Input {
+        Input::coin_signed(
+            UtxoId::default(),
+            (&owner()).into(),
+            amount,
+            asset_id,
+            TxPointer::default(),
+            0,
+        )
+    }
+
+    fn contract_input(contract_id: ContractId) -> Input {
+        let zeroes = Bytes32::zeroed();
+        Input::contract(
+            UtxoId::new(zeroes, 0),
+            zeroes,
+            zeroes,
+            TxPointer::default(),
+            contract_id,
+        )
+    }
+
+    fn sent_amounts(
+        policy: &SigningPolicy,
+        inputs: &[Input],
+        outputs: &[Output],
+    ) -> Result<HashMap<AssetId, u64>> {
+        policy.sent_amounts(&owner(), inputs, outputs, &AssetId::zeroed())
+    }
+
+    fn policy_violation(err: Error) -> PolicyViolation {
+        match err {
+            Error::Transaction(Reason::PolicyViolation(violation)) => violation,
+            other => panic!("expected a policy violation, got: {other}"),
+        }
+    }
+
+    #[test]
+    fn change_sent_to_the_owner_is_not_restricted() -> Result<()> {
+        let policy = SigningPolicy::new()
+            .with_allowed_recipients([recipient()])
+            .with_max_amount_per_tx(AssetId::zeroed(), 10);
+
+        let inputs = [owned_coin(1000, AssetId::zeroed())];
+        let outputs = [
+            coin_to(&owner(), 1000, AssetId::zeroed()),
+            Output::change((&owner()).into(), 0, AssetId::zeroed()),
+        ];
+        sent_amounts(&policy, &inputs, &outputs)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_recipients_assets_and_contracts_are_rejected() {
+        let other_asset = AssetId::from([3; 32]);
+        let contract_id = ContractId::from([4; 32]);
+        let policy = SigningPolicy::new()
+            .with_allowed_recipients([recipient()])
+            .with_allowed_asset_ids([AssetId::zeroed()])
+            .with_allowed_contracts([Bech32ContractId::new(FUEL_BECH32_HRP, [6; 32])]);
+
+        let stranger = Bech32Address::new(FUEL_BECH32_HRP, [5; 32]);
+        let err = sent_amounts(&policy, &[], &[coin_to(&stranger, 1, AssetId::zeroed())])
+            .expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::RecipientNotAllowed((&stranger).into())
+        );
+
+        let err = sent_amounts(&policy, &[], &[coin_to(&recipient(), 1, other_asset)])
+            .expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::AssetNotAllowed(other_asset)
+        );
+
+        let err =
+            sent_amounts(&policy, &[contract_input(contract_id)], &[]).expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::ContractNotAllowed(contract_id)
+        );
+    }
+
+    #[test]
+    fn amounts_are_limited_per_tx() {
+        let asset_id = AssetId::zeroed();
+        let policy = SigningPolicy::new().with_max_amount_per_tx(asset_id, 100);
+
+        let outputs = [
+            coin_to(&recipient(), 60, asset_id),
+            coin_to(&recipient(), 60, asset_id),
+        ];
+        let err = sent_amounts(&policy, &[], &outputs).expect_err("should fail");
+
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::TxAmountLimit {
+                asset_id,
+                amount: 120,
+                limit: 100
+            }
+        );
+    }
+
+    #[test]
+    fn change_sent_to_others_is_counted() {
+        let asset_id = AssetId::zeroed();
+        let stranger = Bech32Address::new(FUEL_BECH32_HRP, [5; 32]);
+        let inputs = [owned_coin(1000, asset_id)];
+        let outputs = [
+            coin_to(&owner(), 100, asset_id),
+            Output::change((&stranger).into(), 0, asset_id),
+        ];
+
+        let policy = SigningPolicy::new().with_allowed_recipients([recipient()]);
+        let err = sent_amounts(&policy, &inputs, &outputs).expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::RecipientNotAllowed((&stranger).into())
+        );
+
+        let policy = SigningPolicy::new().with_max_amount_per_tx(asset_id, 500);
+        let err = sent_amounts(&policy, &inputs, &outputs).expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::TxAmountLimit {
+                asset_id,
+                amount: 900,
+                limit: 500
+            }
+        );
+    }
+
+    #[test]
+    fn variable_outputs_are_counted() {
+        let asset_id = AssetId::zeroed();
+        let inputs = [owned_coin(1000, asset_id)];
+        let outputs = [
+            Output::variable(Address::zeroed(), 0, AssetId::zeroed()),
+            Output::change((&owner()).into(), 0, asset_id),
+        ];
+
+        let policy = SigningPolicy::new().with_allowed_recipients([recipient()]);
+        let err = sent_amounts(&policy, &inputs, &outputs).expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::UnknownRecipient {
+                asset_id,
+                amount: 1000
+            }
+        );
+
+        let policy = SigningPolicy::new().with_max_amount_per_tx(asset_id, 500);
+        let err = sent_amounts(&policy, &inputs, &outputs).expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::TxAmountLimit {
+                asset_id,
+                amount: 1000,
+                limit: 500
+            }
+        );
+    }
+
+    #[test]
+    fn amounts_are_limited_per_day() -> Result<()> {
+        let asset_id = AssetId::zeroed();
+        let policy = SigningPolicy::new().with_max_amount_per_day(asset_id, 100);
+        let now = Utc::now();
+        let spend = |amount| HashMap::from([(asset_id, amount)]);
+
+        policy.record_daily_spending(spend(70), now - Duration::hours(12));
+
+        let err = policy
+            .check_daily_limits(&spend(40), [].into_iter(), now)
+            .expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::DailyAmountLimit {
+                asset_id,
+                amount: 40,
+                remaining: 30
+            }
+        );
+
+        let reserved = spend(20);
+        let err = policy
+            .check_daily_limits(&spend(30), reserved.iter(), now)
+            .expect_err("should fail");
+        assert_eq!(
+            policy_violation(err),
+            PolicyViolation::DailyAmountLimit {
+                asset_id,
+                amount: 30,
+                remaining: 10
+            }
+        );
+
+        policy.check_daily_limits(&spend(30), [].into_iter(), now)?;
+        policy.record_daily_spending(spend(30), now);
+
+        // spending older than a day no longer counts
+        policy.check_daily_limits(&spend(70), [].into_iter(), now + Duration::hours(13))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn signed_amounts_are_reserved_until_released_or_submitted() -> Result<()> {
+        let asset_id = AssetId::zeroed();
+        let policy = SigningPolicy::new().with_max_amount_per_day(asset_id, 100);
+        let tx: FuelTransaction = FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Default::default(),
+            vec![],
+            vec![coin_to(&recipient(), 60, asset_id)],
+            vec![],
+        )
+        .into();
+        let tx_id = TxId::from([1; 32]);
+
+        let other_tx_id = TxId::from([2; 32]);
+        let exceeds_the_limit = |err| {
+            assert_eq!(
+                policy_violation(err),
+                PolicyViolation::DailyAmountLimit {
+                    asset_id,
+                    amount: 60,
+                    remaining: 40
+                }
+            )
+        };
+
+        // signing the same transaction again doesn't reserve its amounts twice
+        policy.check(&owner(), &tx, tx_id, &asset_id)?;
+        policy.check(&owner(), &tx, tx_id, &asset_id)?;
+
+        // another transaction signed before the first one is submitted
+        let err = policy
+            .check(&owner(), &tx, other_tx_id, &asset_id)
+            .expect_err("should fail");
+        exceeds_the_limit(err);
+
+        // e.g. the first transaction was only simulated
+        policy.release(&tx_id);
+        policy.check(&owner(), &tx, other_tx_id, &asset_id)?;
+        policy.release(&other_tx_id);
+
+        // a released transaction is still recorded once submitted
+        policy.record_submission(&tx_id);
+        let err = policy
+            .check(&owner(), &tx, other_tx_id, &asset_id)
+            .expect_err("should fail");
+        exceeds_the_limit(err);
+
+        Ok(())
+    }
+}
@@ -1,22 +1,36 @@
-use std::{fmt, ops, path::Path};
+#[cfg(all(feature = "keystore", not(target_arch = "wasm32")))]
+use std::path::Path;
+use std::{fmt, ops};
 
 use async_trait::async_trait;
 use elliptic_curve::rand_core;
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+use fuel_tx::{Transaction as FuelTransaction, UniqueIdentifier};
 use fuels_core::{
     traits::Signer,
     types::{
         bech32::{Bech32Address, FUEL_BECH32_HRP},
-        errors::{error, Result},
+        errors::{
+            error,
+            transaction::{PolicyViolation, Reason},
+            Error, Result,
+        },
         input::Input,
         transaction_builders::TransactionBuilder,
-        AssetId,
+        typed_data::TypedData,
+        AssetId, ChainId,
     },
 };
 use rand::{CryptoRng, Rng};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-use crate::{accounts_utils::try_provider_error, provider::Provider, Account, ViewOnlyAccount};
+use crate::{
+    accounts_utils::try_provider_error,
+    mnemonic::{self, Language},
+    provider::Provider,
+    signing_policy::SigningPolicy,
+    Account, CoinFilter, ViewOnlyAccount,
+};
 
 pub const DEFAULT_DERIVATION_PATH_PREFIX: &str = "m/44'/1179993420'";
 
@@ -45,6 +59,10 @@ pub struct WalletUnlocked {
     #[zeroize(skip)]
     wallet: Wallet,
     pub(crate) private_key: SecretKey,
+    #[zeroize(skip)]
+    signing_policy: Option<SigningPolicy>,
+    #[zeroize(skip)]
+    expected_chain_id: Option<ChainId>,
 }
 
 impl Wallet {
@@ -73,6 +91,8 @@ impl Wallet {
         WalletUnlocked {
             wallet: self,
             private_key,
+            signing_policy: None,
+            expected_chain_id: None,
         }
     }
 }
@@ -99,9 +119,64 @@ impl WalletUnlocked {
     // as this could lead to ending up with a `WalletUnlocked` in an inconsistent state (e.g. the
     // private key doesn't match the inner wallet's public key).
     pub fn set_provider(&mut self, provider: Provider) {
+        let provider = match &self.signing_policy {
+            Some(signing_policy) => provider.with_signing_policy(signing_policy),
+            None => provider,
+        };
+
         self.wallet.set_provider(provider);
     }
 
+    /// Attaches a [`SigningPolicy`] checked whenever the wallet signs a transaction. Transactions
+    /// violating it are rejected with a
+    /// [`PolicyViolation`](fuels_core::types::errors::transaction::PolicyViolation) error.
+    ///
+    /// The wallet's provider records the spending of the transactions it submits towards the
+    /// policy, so the wallet should submit through [`ViewOnlyAccount::try_provider`].
+    pub fn with_signing_policy(mut self, signing_policy: SigningPolicy) -> Self {
+        if let Some(provider) = self.wallet.provider.take() {
+            self.wallet.provider = Some(provider.with_signing_policy(&signing_policy));
+        }
+
+        self.signing_policy = Some(signing_policy);
+        self
+    }
+
+    pub fn signing_policy(&self) -> Option<&SigningPolicy> {
+        self.signing_policy.as_ref()
+    }
+
+    /// Makes the wallet refuse to sign transactions unless its provider is connected to the
+    /// network with the given `chain_id`. Signing then fails with a
+    /// [`WrongNetwork`](fuels_core::types::errors::transaction::Reason::WrongNetwork) error,
+    /// guarding against broadcasting to the wrong network by mistake. The check is done when
+    /// the wallet adds its witness to a builder and again when it signs the transaction, however
+    /// it was added as a signer.
+    pub fn with_expected_chain_id(mut self, chain_id: ChainId) -> Self {
+        self.expected_chain_id = Some(chain_id);
+        self
+    }
+
+    pub fn expected_chain_id(&self) -> Option<ChainId> {
+        self.expected_chain_id
+    }
+
+    fn verify_chain_id(&self) -> Result<()> {
+        let Some(expected) = self.expected_chain_id else {
+            return Ok(());
+        };
+
+        let actual = self.try_provider()?.chain_id();
+        if actual != expected {
+            return Err(Error::Transaction(Reason::WrongNetwork {
+                expected,
+                actual,
+            }));
+        }
+
+        Ok(())
+    }
+
     /// Creates a new wallet with a random private key.
     pub fn new_random(provider: Option<Provider>) -> Self {
         let mut rng = rand::thread_rng();
@@ -136,7 +211,22 @@ impl WalletUnlocked {
         Ok(Self::new_from_private_key(secret_key, provider))
     }
 
+    /// Creates a new wallet from a mnemonic phrase in any of the BIP-39 languages, at the given
+    /// derivation path. The phrase is validated first, see
+    /// [`validate_mnemonic_phrase`](crate::mnemonic::validate_mnemonic_phrase).
+    pub fn new_from_mnemonic_phrase_with_language(
+        phrase: &str,
+        language: Language,
+        provider: Option<Provider>,
+        path: &str,
+    ) -> Result<Self> {
+        let secret_key = mnemonic::derive_secret_key(phrase, language, path)?;
+
+        Ok(Self::new_from_private_key(secret_key, provider))
+    }
+
     /// Creates a new wallet and stores its encrypted version in the given path.
+    #[cfg(all(feature = "keystore", not(target_arch = "wasm32")))]
     pub fn new_from_keystore<P, R, S>(
         dir: P,
         rng: &mut R,
@@ -160,6 +250,7 @@ impl WalletUnlocked {
 
     /// Encrypts the wallet's private key with the given password and saves it
     /// to the given path.
+    #[cfg(all(feature = "keystore", not(target_arch = "wasm32")))]
     pub fn encrypt<P, S>(&self, dir: P, password: S) -> Result<String>
     where
         P: AsRef<Path>,
@@ -172,6 +263,7 @@ impl WalletUnlocked {
     }
 
     /// Recreates a wallet from an encrypted JSON wallet given the provided path and password.
+    #[cfg(all(feature = "keystore", not(target_arch = "wasm32")))]
     pub fn load_keystore<P, S>(keypath: P, password: S, provider: Option<Provider>) -> Result<Self>
     where
         P: AsRef<Path>,
@@ -209,9 +301,19 @@ impl Account for WalletUnlocked {
         &self,
         asset_id: AssetId,
         amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.get_asset_inputs_for_amount_filtered(asset_id, amount, CoinFilter::default())
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
     ) -> Result<Vec<Input>> {
         Ok(self
-            .get_spendable_resources(asset_id, amount)
+            .get_spendable_resources_filtered(asset_id, amount, filter)
             .await?
             .into_iter()
             .map(Input::resource_signed)
@@ -219,6 +321,8 @@ impl Account for WalletUnlocked {
     }
 
     fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        self.verify_chain_id()?;
+
         tb.add_signer(self.clone())?;
 
         Ok(())
@@ -229,11 +333,45 @@ impl Account for WalletUnlocked {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl Signer for WalletUnlocked {
     async fn sign(&self, message: Message) -> Result<Signature> {
+        if self.signing_policy.is_some() {
+            return Err(Error::Transaction(Reason::PolicyViolation(
+                PolicyViolation::BlindSigning,
+            )));
+        }
+
         let sig = Signature::sign(&self.private_key, &message);
 
         Ok(sig)
     }
 
+    async fn sign_transaction(&self, tx: &FuelTransaction, message: Message) -> Result<Signature> {
+        self.verify_chain_id()?;
+
+        if let Some(signing_policy) = &self.signing_policy {
+            let provider = self.try_provider()?;
+            let tx_id = tx.id(&provider.chain_id());
+
+            // the policy only vouches for `tx`, not for an arbitrary message passed along
+            if message != Message::from_bytes(*tx_id) {
+                return Err(Error::Transaction(Reason::PolicyViolation(
+                    PolicyViolation::BlindSigning,
+                )));
+            }
+
+            signing_policy.check(self.address(), tx, tx_id, provider.base_asset_id())?;
+        }
+
+        Ok(Signature::sign(&self.private_key, &message))
+    }
+
+    /// Typed data digests are domain separated, so they can't authorize a transaction and are
+    /// signed even under a [`SigningPolicy`].
+    async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature> {
+        let message = typed_data.message_to_sign()?;
+
+        Ok(Signature::sign(&self.private_key, &message))
+    }
+
     fn address(&self) -> &Bech32Address {
         &self.address
     }
@@ -254,18 +392,21 @@ impl ops::Deref for WalletUnlocked {
     }
 }
 
-/// Generates a random mnemonic phrase given a random number generator and the number of words to
-/// generate, `count`.
+/// Generates a random English mnemonic phrase given a random number generator and the number of
+/// words to generate, `count`. See [`MnemonicGenerator`](crate::mnemonic::MnemonicGenerator) for
+/// other languages and for phrases from a given entropy.
 pub fn generate_mnemonic_phrase<R: Rng>(rng: &mut R, count: usize) -> Result<String> {
     Ok(fuel_crypto::generate_mnemonic_phrase(rng, count)?)
 }
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "keystore")]
     use tempfile::tempdir;
 
     use super::*;
 
+    #[cfg(feature = "keystore")]
     #[tokio::test]
     async fn encrypted_json_keystore() -> Result<()> {
         let dir = tempdir()?;
@@ -291,6 +432,21 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn wallets_with_a_signing_policy_refuse_blind_signing() {
+        let wallet = WalletUnlocked::new_random(None).with_signing_policy(SigningPolicy::new());
+
+        let err = wallet
+            .sign(Message::new("Hello there!".as_bytes()))
+            .await
+            .expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            Error::Transaction(Reason::PolicyViolation(PolicyViolation::BlindSigning))
+        ));
+    }
+
     #[tokio::test]
     async fn mnemonic_generation() -> Result<()> {
         let mnemonic = generate_mnemonic_phrase(&mut rand::thread_rng(), 12)?;
@@ -333,6 +489,7 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "keystore")]
     #[tokio::test]
     async fn encrypt_and_store_wallet_from_mnemonic() -> Result<()> {
         let dir = tempdir()?;
@@ -0,0 +1,62 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::error::{error, Result};
+
+/// Runs `forc build` for the project or workspace at `project_dir` with the build `profile`. The
+/// `FORC` environment variable allows using a `forc` binary that is not in `PATH`.
+pub fn build(project_dir: impl AsRef<Path>, profile: &str) -> Result<()> {
+    let project_dir = project_dir.as_ref();
+    let forc = env::var("FORC").unwrap_or_else(|_| "forc".to_string());
+
+    let output = Command::new(&forc)
+        .arg("build")
+        .arg("--path")
+        .arg(project_dir)
+        .args(["--build-profile", profile])
+        .output()
+        .map_err(|e| error!("failed to run `{forc} build`: {e}. Make sure `forc` is installed"))?;
+
+    if !output.status.success() {
+        return Err(error!(
+            "`{forc} build` failed for `{}`:\n{}",
+            project_dir.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(())
+}
+
+/// The files of the project or workspace at `project_dir`, without its build output.
+pub fn source_files(project_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    collect_source_files(project_dir.as_ref(), &mut files)?;
+    files.sort();
+
+    Ok(files)
+}
+
+fn collect_source_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_dir() {
+            if !is_ignored_dir(&entry.path()) {
+                collect_source_files(&entry.path(), files)?;
+            }
+        } else {
+            files.push(entry.path());
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn is_ignored_dir(dir: &Path) -> bool {
+    dir.file_name()
+        .is_some_and(|name| name == "out" || name == ".git")
+}
@@ -0,0 +1,228 @@
+//! Compiles Sway projects from build scripts.
+//!
+//! [`compile`] builds the projects with `forc`, tells Cargo to run the build script again when
+//! one of their files changes and exposes the paths of the artifacts of every built project as
+//! environment variables, e.g. for a project named `my_contract`:
+//!
+//! - `FUELS_MY_CONTRACT_ABI`
+//! - `FUELS_MY_CONTRACT_BIN`
+//! - `FUELS_MY_CONTRACT_STORAGE_SLOTS`, for contracts
+//!
+//! ```no_run
+//! // build.rs
+//! fn main() {
+//!     fuels_build::compile(["sway/my_contract"], "release").expect("failed to compile");
+//! }
+//! ```
+//!
+//! The bindings can then be generated with
+//! `abigen!(Contract(name = "MyContract", abi_env = "FUELS_MY_CONTRACT_ABI"))`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub use forc::{build, source_files};
+
+use crate::error::{error, Result};
+
+pub mod error;
+mod forc;
+
+/// The artifacts of a compiled Sway project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifacts {
+    /// The name of the project, as given in its `Forc.toml`.
+    pub name: String,
+    pub abi: PathBuf,
+    pub bin: PathBuf,
+    /// Only contracts have storage slots.
+    pub storage_slots: Option<PathBuf>,
+}
+
+impl Artifacts {
+    /// The prefix of the environment variables set by [`compile`] for this project.
+    pub fn env_var_prefix(&self) -> String {
+        format!("FUELS_{}", self.name.to_uppercase().replace('-', "_"))
+    }
+
+    fn env_vars(&self) -> Vec<(String, &Path)> {
+        let prefix = self.env_var_prefix();
+
+        let mut env_vars = vec![
+            (format!("{prefix}_ABI"), self.abi.as_path()),
+            (format!("{prefix}_BIN"), self.bin.as_path()),
+        ];
+        if let Some(storage_slots) = &self.storage_slots {
+            env_vars.push((format!("{prefix}_STORAGE_SLOTS"), storage_slots.as_path()));
+        }
+
+        env_vars
+    }
+}
+
+/// Compiles the Sway projects or workspaces in `project_dirs` with the build `profile`. Meant to
+/// be called from build scripts, as it prints instructions for Cargo. See the crate
+/// documentation for details.
+pub fn compile<P: AsRef<Path>>(
+    project_dirs: impl IntoIterator<Item = P>,
+    profile: &str,
+) -> Result<Vec<Artifacts>> {
+    println!("cargo:rerun-if-env-changed=FORC");
+
+    let mut compiled = vec![];
+    for project_dir in project_dirs {
+        let project_dir = project_dir.as_ref();
+        let project_dir = project_dir.canonicalize().map_err(|e| {
+            error!(
+                "unable to canonicalize Sway project path `{}`: {e}",
+                project_dir.display()
+            )
+        })?;
+
+        for file in source_files(&project_dir)? {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+
+        build(&project_dir, profile)?;
+
+        let artifacts = find_artifacts(&project_dir, profile)?;
+        if artifacts.is_empty() {
+            return Err(error!(
+                "no `{profile}` artifacts found in `{}` after building it",
+                project_dir.display()
+            ));
+        }
+
+        for (name, path) in artifacts.iter().flat_map(Artifacts::env_vars) {
+            println!("cargo:rustc-env={name}={}", path.display());
+        }
+
+        compiled.extend(artifacts);
+    }
+
+    Ok(compiled)
+}
+
+/// Finds the artifacts built with `profile` of the project at `dir`, or of the members of the
+/// workspace at `dir`.
+pub fn find_artifacts(dir: impl AsRef<Path>, profile: &str) -> Result<Vec<Artifacts>> {
+    let mut artifacts = vec![];
+    collect_artifacts(dir.as_ref(), profile, &mut artifacts)?;
+    artifacts.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(artifacts)
+}
+
+fn collect_artifacts(dir: &Path, profile: &str, artifacts: &mut Vec<Artifacts>) -> Result<()> {
+    let out_dir = dir.join("out").join(profile);
+    if out_dir.is_dir() {
+        for entry in fs::read_dir(&out_dir)? {
+            let abi = entry?.path();
+            let Some(name) = abi
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_suffix("-abi.json"))
+            else {
+                continue;
+            };
+
+            let storage_slots = out_dir.join(format!("{name}-storage_slots.json"));
+            artifacts.push(Artifacts {
+                name: name.to_string(),
+                bin: out_dir.join(format!("{name}.bin")),
+                storage_slots: storage_slots.is_file().then_some(storage_slots),
+                abi,
+            });
+        }
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() && !forc::is_ignored_dir(&entry.path()) {
+            collect_artifacts(&entry.path(), profile, artifacts)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use super::*;
+
+    fn touch(path: &Path) -> Result<()> {
+        fs::create_dir_all(path.parent().expect("has a parent"))?;
+        File::create(path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn artifacts_of_workspace_members_are_found() -> Result<()> {
+        // given
+        let workspace = tempfile::tempdir()?;
+        let dir = workspace.path();
+        for file in [
+            "Forc.toml",
+            "my-contract/Forc.toml",
+            "my-contract/src/main.sw",
+            "my-contract/out/release/my-contract-abi.json",
+            "my-contract/out/release/my-contract.bin",
+            "my-contract/out/release/my-contract-storage_slots.json",
+            "my-contract/out/debug/my-contract-abi.json",
+            "script/out/release/script-abi.json",
+            "script/out/release/script.bin",
+        ] {
+            touch(&dir.join(file))?;
+        }
+
+        // when
+        let artifacts = find_artifacts(dir, "release")?;
+
+        // then
+        let release = dir.join("my-contract/out/release");
+        assert_eq!(
+            artifacts,
+            vec![
+                Artifacts {
+                    name: "my-contract".to_string(),
+                    abi: release.join("my-contract-abi.json"),
+                    bin: release.join("my-contract.bin"),
+                    storage_slots: Some(release.join("my-contract-storage_slots.json")),
+                },
+                Artifacts {
+                    name: "script".to_string(),
+                    abi: dir.join("script/out/release/script-abi.json"),
+                    bin: dir.join("script/out/release/script.bin"),
+                    storage_slots: None,
+                },
+            ]
+        );
+        assert_eq!(
+            artifacts[0]
+                .env_vars()
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>(),
+            [
+                "FUELS_MY_CONTRACT_ABI",
+                "FUELS_MY_CONTRACT_BIN",
+                "FUELS_MY_CONTRACT_STORAGE_SLOTS"
+            ]
+        );
+        assert_eq!(
+            source_files(dir)?,
+            [
+                dir.join("Forc.toml"),
+                dir.join("my-contract/Forc.toml"),
+                dir.join("my-contract/src/main.sw"),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -4,4 +4,5 @@ mod generated_code;
 mod resolved_type;
 mod utils;
 
-pub use abigen::{Abi, Abigen, AbigenTarget, ProgramType};
+pub use abigen::{Abi, AbiProblem, AbiValidator, Abigen, AbigenTarget, ProgramType};
+pub use custom_types::TypeAttributes;
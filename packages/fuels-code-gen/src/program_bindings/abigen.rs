@@ -1,5 +1,6 @@
 use std::{collections::HashSet, path::PathBuf};
 
+pub use abi_validator::{AbiProblem, AbiValidator};
 pub use abigen_target::{Abi, AbigenTarget, ProgramType};
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
 use inflector::Inflector;
@@ -11,12 +12,14 @@ use regex::Regex;
 use crate::{
     error::Result,
     program_bindings::{
-        abigen::bindings::generate_bindings, custom_types::generate_types,
+        abigen::bindings::{generate_bindings, interface_impls},
+        custom_types::{generate_types, TypeAttributes},
         generated_code::GeneratedCode,
     },
-    utils::ident,
+    utils::{ident, TypePath},
 };
 
+mod abi_validator;
 mod abigen_target;
 mod bindings;
 mod configurables;
@@ -34,7 +37,17 @@ impl Abigen {
     /// for, and of what nature (Contract, Script or Predicate).
     /// * `no_std`: don't use the Rust std library.
     pub fn generate(targets: Vec<AbigenTarget>, no_std: bool) -> Result<TokenStream> {
-        let generated_code = Self::generate_code(no_std, targets)?;
+        Self::generate_with_type_attributes(targets, vec![], no_std)
+    }
+
+    /// Same as [`Abigen::generate`], additionally adding `type_attributes` to the declarations
+    /// of the generated structs and enums they name.
+    pub fn generate_with_type_attributes(
+        targets: Vec<AbigenTarget>,
+        type_attributes: Vec<TypeAttributes>,
+        no_std: bool,
+    ) -> Result<TokenStream> {
+        let generated_code = Self::generate_code(no_std, targets, &type_attributes)?;
 
         let use_statements = generated_code.use_statements_for_uniquely_named_types();
 
@@ -65,12 +78,23 @@ impl Abigen {
         .expect("Wasm hotfix failed!")
     }
 
-    fn generate_code(no_std: bool, parsed_targets: Vec<AbigenTarget>) -> Result<GeneratedCode> {
+    fn generate_code(
+        no_std: bool,
+        parsed_targets: Vec<AbigenTarget>,
+        type_attributes: &[TypeAttributes],
+    ) -> Result<GeneratedCode> {
         let custom_types = Self::filter_custom_types(&parsed_targets);
         let shared_types = Self::filter_shared_types(custom_types);
 
-        let bindings = Self::generate_all_bindings(parsed_targets, no_std, &shared_types)?;
-        let shared_types = Self::generate_shared_types(shared_types, no_std)?;
+        let interfaces = Self::filter_interfaces(&parsed_targets);
+        let bindings = Self::generate_all_bindings(
+            parsed_targets,
+            no_std,
+            &shared_types,
+            &interfaces,
+            type_attributes,
+        )?;
+        let shared_types = Self::generate_shared_types(shared_types, type_attributes, no_std)?;
 
         let mod_name = ident("abigen_bindings");
         Ok(shared_types.merge(bindings).wrap_in_mod(mod_name))
@@ -80,10 +104,14 @@ impl Abigen {
         targets: Vec<AbigenTarget>,
         no_std: bool,
         shared_types: &HashSet<FullTypeDeclaration>,
+        interfaces: &[AbigenTarget],
+        type_attributes: &[TypeAttributes],
     ) -> Result<GeneratedCode> {
         targets
             .into_iter()
-            .map(|target| Self::generate_binding(target, no_std, shared_types))
+            .map(|target| {
+                Self::generate_binding(target, no_std, shared_types, interfaces, type_attributes)
+            })
             .fold_ok(GeneratedCode::default(), |acc, generated_code| {
                 acc.merge(generated_code)
             })
@@ -93,16 +121,32 @@ impl Abigen {
         target: AbigenTarget,
         no_std: bool,
         shared_types: &HashSet<FullTypeDeclaration>,
+        interfaces: &[AbigenTarget],
+        type_attributes: &[TypeAttributes],
     ) -> Result<GeneratedCode> {
         let mod_name = ident(&format!("{}_mod", &target.name.to_snake_case()));
 
         let recompile_trigger =
             Self::generate_macro_recompile_trigger(target.source.path.as_ref(), no_std);
-        let types = generate_types(&target.source.abi.types, shared_types, no_std)?;
+        let binary_path = Self::generate_binary_path_constant(&target, no_std);
+        let types = generate_types(
+            &target.source.abi.types,
+            shared_types,
+            type_attributes,
+            no_std,
+        )?;
+        let impls = match target.program_type {
+            ProgramType::Contract => {
+                interface_impls(&ident(&target.name), &target.source.abi, interfaces, no_std)?
+            }
+            _ => GeneratedCode::default(),
+        };
         let bindings = generate_bindings(target, no_std)?;
         Ok(recompile_trigger
+            .merge(binary_path)
             .merge(types)
             .merge(bindings)
+            .merge(impls)
             .wrap_in_mod(mod_name))
     }
 
@@ -122,11 +166,35 @@ impl Abigen {
         GeneratedCode::new(code, Default::default(), no_std)
     }
 
+    /// Exposes the binary found next to the ABI of a forc project, see
+    /// [`AbigenTarget::from_forc_project`].
+    fn generate_binary_path_constant(target: &AbigenTarget, no_std: bool) -> GeneratedCode {
+        let Some(binary_path) = target.binary_path.as_ref() else {
+            return GeneratedCode::default();
+        };
+
+        let const_name = ident(&format!(
+            "{}_BINARY_PATH",
+            target.name.to_screaming_snake_case()
+        ));
+        let binary_path = binary_path.display().to_string();
+        let doc = format!("The binary of `{}` built by forc.", target.name);
+
+        let code = quote! {
+            #[doc = #doc]
+            pub const #const_name: &str = #binary_path;
+        };
+        let type_path = TypePath::new(&const_name).expect("the constant name is not empty");
+
+        GeneratedCode::new(code, [type_path].into(), no_std)
+    }
+
     fn generate_shared_types(
         shared_types: HashSet<FullTypeDeclaration>,
+        type_attributes: &[TypeAttributes],
         no_std: bool,
     ) -> Result<GeneratedCode> {
-        let types = generate_types(&shared_types, &HashSet::default(), no_std)?;
+        let types = generate_types(&shared_types, &HashSet::default(), type_attributes, no_std)?;
 
         if types.is_empty() {
             Ok(Default::default())
@@ -145,6 +213,14 @@ impl Abigen {
             .filter(|ttype| ttype.is_custom_type())
     }
 
+    fn filter_interfaces(targets: &[AbigenTarget]) -> Vec<AbigenTarget> {
+        targets
+            .iter()
+            .filter(|target| target.program_type == ProgramType::Interface)
+            .cloned()
+            .collect()
+    }
+
     /// A type is considered "shared" if it appears at least twice in
     /// `all_custom_types`.
     ///
@@ -0,0 +1,474 @@
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+};
+
+use serde_json::{Map, Value};
+
+/// The only `encoding` of the JSON ABI supported by the SDK.
+const SUPPORTED_ENCODING: &str = "1";
+
+/// A problem found in a JSON ABI, located by the JSON path of the offending value,
+/// e.g. `$.functions[2].output.type`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiProblem {
+    pub path: String,
+    pub reason: String,
+}
+
+impl Display for AbiProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.reason)
+    }
+}
+
+/// Checks a JSON ABI against the format emitted by `forc` before it is used to generate
+/// bindings. Unlike deserialization, which stops at the first error, every problem is reported.
+#[derive(Debug, Default)]
+pub struct AbiValidator {
+    declared_type_ids: HashSet<u64>,
+    problems: Vec<AbiProblem>,
+}
+
+impl AbiValidator {
+    /// Returns all problems found in `json_abi`. An empty result means the ABI is valid.
+    pub fn validate(json_abi: &str) -> Vec<AbiProblem> {
+        let abi = match serde_json::from_str::<Value>(json_abi) {
+            Ok(abi) => abi,
+            Err(err) => {
+                return vec![AbiProblem {
+                    path: "$".to_string(),
+                    reason: format!("not valid JSON: {err}"),
+                }]
+            }
+        };
+
+        let mut validator = Self::default();
+        validator.check_program(&abi);
+
+        validator.problems
+    }
+
+    fn check_program(&mut self, abi: &Value) {
+        let Some(program) = self.object("$", abi) else {
+            return;
+        };
+
+        if let Some(spec_version) = program.get("specVersion") {
+            self.problem(
+                "$.specVersion",
+                format!(
+                    "ABI spec version {spec_version} is not supported, rebuild the program with a \
+                     `forc` version compatible with this SDK"
+                ),
+            );
+            return;
+        }
+
+        if let Some(encoding) = program.get("encoding") {
+            if encoding.as_str() != Some(SUPPORTED_ENCODING) {
+                self.problem(
+                    "$.encoding",
+                    format!(
+                        "encoding {encoding} is not supported, expected \"{SUPPORTED_ENCODING}\""
+                    ),
+                );
+            }
+        }
+
+        let types = self.required_array("$", program, "types");
+        self.collect_type_ids(types);
+        self.check_type_declarations(types);
+
+        for (index, function) in self
+            .required_array("$", program, "functions")
+            .iter()
+            .enumerate()
+        {
+            self.check_function(&format!("$.functions[{index}]"), function);
+        }
+
+        for (index, logged_type) in self
+            .optional_array("$", program, "loggedTypes")
+            .iter()
+            .enumerate()
+        {
+            let path = format!("$.loggedTypes[{index}]");
+            if let Some(logged_type) = self.object(&path, logged_type) {
+                self.required_u64(&path, logged_type, "logId");
+                self.required_type_application(&path, logged_type, "loggedType");
+            }
+        }
+
+        for (index, message_type) in self
+            .optional_array("$", program, "messagesTypes")
+            .iter()
+            .enumerate()
+        {
+            let path = format!("$.messagesTypes[{index}]");
+            if let Some(message_type) = self.object(&path, message_type) {
+                self.required_u64(&path, message_type, "messageId");
+                self.required_type_application(&path, message_type, "messageType");
+            }
+        }
+
+        for (index, configurable) in self
+            .optional_array("$", program, "configurables")
+            .iter()
+            .enumerate()
+        {
+            let path = format!("$.configurables[{index}]");
+            if let Some(configurable) = self.object(&path, configurable) {
+                self.required_str(&path, configurable, "name");
+                self.required_type_application(&path, configurable, "configurableType");
+                self.required_u64(&path, configurable, "offset");
+            }
+        }
+    }
+
+    /// Type declarations can reference each other in any order, so their ids are collected
+    /// before any reference is checked. Problems are reported by `check_type_declarations`.
+    fn collect_type_ids(&mut self, types: &[Value]) {
+        self.declared_type_ids = types
+            .iter()
+            .filter_map(|declaration| declaration.get("typeId")?.as_u64())
+            .collect();
+    }
+
+    fn check_type_declarations(&mut self, types: &[Value]) {
+        for (index, declaration) in types.iter().enumerate() {
+            let path = format!("$.types[{index}]");
+            let Some(declaration) = self.object(&path, declaration) else {
+                continue;
+            };
+
+            self.required_u64(&path, declaration, "typeId");
+            self.required_str(&path, declaration, "type");
+
+            for (index, component) in self
+                .optional_array(&path, declaration, "components")
+                .iter()
+                .enumerate()
+            {
+                self.check_type_application(&format!("{path}.components[{index}]"), component);
+            }
+
+            for (index, type_parameter) in self
+                .optional_array(&path, declaration, "typeParameters")
+                .iter()
+                .enumerate()
+            {
+                let path = format!("{path}.typeParameters[{index}]");
+                if let Some(type_id) = self.u64(&path, type_parameter) {
+                    self.check_type_reference(&path, type_id);
+                }
+            }
+        }
+    }
+
+    fn check_function(&mut self, path: &str, function: &Value) {
+        let Some(function) = self.object(path, function) else {
+            return;
+        };
+
+        self.required_str(path, function, "name");
+
+        for (index, input) in self
+            .required_array(path, function, "inputs")
+            .iter()
+            .enumerate()
+        {
+            self.check_type_application(&format!("{path}.inputs[{index}]"), input);
+        }
+
+        self.required_type_application(path, function, "output");
+
+        for (index, attribute) in self
+            .optional_array(path, function, "attributes")
+            .iter()
+            .enumerate()
+        {
+            let path = format!("{path}.attributes[{index}]");
+            let Some(attribute) = self.object(&path, attribute) else {
+                continue;
+            };
+
+            self.required_str(&path, attribute, "name");
+            for (index, argument) in self
+                .required_array(&path, attribute, "arguments")
+                .iter()
+                .enumerate()
+            {
+                self.str(&format!("{path}.arguments[{index}]"), argument);
+            }
+        }
+    }
+
+    fn required_type_application(&mut self, path: &str, parent: &Map<String, Value>, field: &str) {
+        if let Some(type_application) = self.required(path, parent, field) {
+            self.check_type_application(&format!("{path}.{field}"), type_application);
+        }
+    }
+
+    fn check_type_application(&mut self, path: &str, type_application: &Value) {
+        let Some(type_application) = self.object(path, type_application) else {
+            return;
+        };
+
+        self.required_str(path, type_application, "name");
+
+        if let Some(type_id) = self.required_u64(path, type_application, "type") {
+            self.check_type_reference(&format!("{path}.type"), type_id);
+        }
+
+        for (index, type_argument) in self
+            .optional_array(path, type_application, "typeArguments")
+            .iter()
+            .enumerate()
+        {
+            self.check_type_application(&format!("{path}.typeArguments[{index}]"), type_argument);
+        }
+    }
+
+    fn check_type_reference(&mut self, path: &str, type_id: u64) {
+        if !self.declared_type_ids.contains(&type_id) {
+            self.problem(
+                path,
+                format!("type id {type_id} is not declared in `$.types`"),
+            );
+        }
+    }
+
+    fn required<'a>(
+        &mut self,
+        path: &str,
+        parent: &'a Map<String, Value>,
+        field: &str,
+    ) -> Option<&'a Value> {
+        let value = parent.get(field);
+        if value.is_none() {
+            self.problem(&format!("{path}.{field}"), "missing required field");
+        }
+
+        value
+    }
+
+    fn required_array<'a>(
+        &mut self,
+        path: &str,
+        parent: &'a Map<String, Value>,
+        field: &str,
+    ) -> &'a [Value] {
+        self.required(path, parent, field)
+            .and_then(|value| self.array(&format!("{path}.{field}"), value))
+            .unwrap_or_default()
+    }
+
+    /// Optional arrays can be either missing or `null`.
+    fn optional_array<'a>(
+        &mut self,
+        path: &str,
+        parent: &'a Map<String, Value>,
+        field: &str,
+    ) -> &'a [Value] {
+        match parent.get(field) {
+            None | Some(Value::Null) => &[],
+            Some(value) => self
+                .array(&format!("{path}.{field}"), value)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn required_u64(
+        &mut self,
+        path: &str,
+        parent: &Map<String, Value>,
+        field: &str,
+    ) -> Option<u64> {
+        self.required(path, parent, field)
+            .and_then(|value| self.u64(&format!("{path}.{field}"), value))
+    }
+
+    fn required_str(&mut self, path: &str, parent: &Map<String, Value>, field: &str) {
+        if let Some(value) = self.required(path, parent, field) {
+            self.str(&format!("{path}.{field}"), value);
+        }
+    }
+
+    fn object<'a>(&mut self, path: &str, value: &'a Value) -> Option<&'a Map<String, Value>> {
+        let object = value.as_object();
+        if object.is_none() {
+            self.unexpected(path, "an object", value);
+        }
+
+        object
+    }
+
+    fn array<'a>(&mut self, path: &str, value: &'a Value) -> Option<&'a [Value]> {
+        let array = value.as_array().map(Vec::as_slice);
+        if array.is_none() {
+            self.unexpected(path, "an array", value);
+        }
+
+        array
+    }
+
+    fn u64(&mut self, path: &str, value: &Value) -> Option<u64> {
+        let number = value.as_u64();
+        if number.is_none() {
+            self.unexpected(path, "an unsigned integer", value);
+        }
+
+        number
+    }
+
+    fn str(&mut self, path: &str, value: &Value) {
+        if !value.is_string() {
+            self.unexpected(path, "a string", value);
+        }
+    }
+
+    fn unexpected(&mut self, path: &str, expected: &str, found: &Value) {
+        let found = match found {
+            Value::Null => "null",
+            Value::Bool(_) => "a boolean",
+            Value::Number(_) => "a number",
+            Value::String(_) => "a string",
+            Value::Array(_) => "an array",
+            Value::Object(_) => "an object",
+        };
+
+        self.problem(path, format!("expected {expected}, found {found}"));
+    }
+
+    fn problem(&mut self, path: &str, reason: impl Into<String>) {
+        self.problems.push(AbiProblem {
+            path: path.to_string(),
+            reason: reason.into(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problems(json_abi: &str) -> Vec<String> {
+        AbiValidator::validate(json_abi)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn valid_abi_has_no_problems() {
+        let json_abi = r#"
+            {
+              "encoding": "1",
+              "types": [
+                { "typeId": 0, "type": "bool", "components": null, "typeParameters": null },
+                {
+                  "typeId": 1,
+                  "type": "struct Wrapper",
+                  "components": [{ "name": "inner", "type": 2, "typeArguments": null }],
+                  "typeParameters": [2]
+                },
+                { "typeId": 2, "type": "generic T", "components": null, "typeParameters": null }
+              ],
+              "functions": [
+                {
+                  "inputs": [
+                    {
+                      "name": "arg",
+                      "type": 1,
+                      "typeArguments": [{ "name": "", "type": 0, "typeArguments": null }]
+                    }
+                  ],
+                  "name": "main",
+                  "output": { "name": "", "type": 0, "typeArguments": null },
+                  "attributes": [{ "name": "storage", "arguments": ["read"] }]
+                }
+              ],
+              "loggedTypes": [
+                { "logId": 0, "loggedType": { "name": "", "type": 0, "typeArguments": null } }
+              ],
+              "messagesTypes": [],
+              "configurables": [
+                {
+                  "name": "FLAG",
+                  "configurableType": { "name": "", "type": 0, "typeArguments": null },
+                  "offset": 120
+                }
+              ]
+            }"#;
+
+        assert_eq!(problems(json_abi), Vec::<String>::new());
+    }
+
+    #[test]
+    fn every_problem_is_reported_with_its_path() {
+        let json_abi = r#"
+            {
+              "types": [
+                { "typeId": "0", "type": "bool" },
+                { "type": "u64", "components": {} }
+              ],
+              "functions": [
+                {
+                  "inputs": [{ "name": "arg", "type": 7 }],
+                  "name": 3,
+                  "output": { "name": "", "type": 0, "typeArguments": null }
+                }
+              ],
+              "configurables": [{ "name": "FLAG", "configurableType": null, "offset": -1 }]
+            }"#;
+
+        assert_eq!(
+            problems(json_abi),
+            [
+                "$.types[0].typeId: expected an unsigned integer, found a string",
+                "$.types[1].typeId: missing required field",
+                "$.types[1].components: expected an array, found an object",
+                "$.functions[0].name: expected a string, found a number",
+                "$.functions[0].inputs[0].type: type id 7 is not declared in `$.types`",
+                "$.functions[0].output.type: type id 0 is not declared in `$.types`",
+                "$.configurables[0].configurableType: expected an object, found null",
+                "$.configurables[0].offset: expected an unsigned integer, found a number",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_sections_are_reported() {
+        assert_eq!(
+            problems("{}"),
+            [
+                "$.types: missing required field",
+                "$.functions: missing required field"
+            ]
+        );
+        assert_eq!(problems("[]"), ["$: expected an object, found an array"]);
+    }
+
+    #[test]
+    fn unsupported_versions_are_reported() {
+        let spec_problems = problems(r#"{ "specVersion": "1", "concreteTypes": [] }"#);
+        assert_eq!(spec_problems.len(), 1);
+        assert!(
+            spec_problems[0].starts_with("$.specVersion: ABI spec version \"1\" is not supported")
+        );
+
+        assert_eq!(
+            problems(r#"{ "encoding": "0", "types": [], "functions": [] }"#),
+            ["$.encoding: encoding \"0\" is not supported, expected \"1\""]
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_reported() {
+        let problems = problems(r#"{ "types": [ }"#);
+
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("$: not valid JSON: "));
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
     convert::TryFrom,
     env, fs,
+    io::ErrorKind,
     path::{Path, PathBuf},
     str::FromStr,
 };
@@ -8,24 +9,60 @@ use std::{
 use fuel_abi_types::abi::full_program::FullProgramABI;
 use proc_macro2::Ident;
 
-use crate::error::{error, Error, Result};
+use crate::{
+    error::{error, Error, Result},
+    program_bindings::abigen::AbiValidator,
+};
 
 #[derive(Debug, Clone)]
 pub struct AbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub(crate) program_type: ProgramType,
+    pub(crate) binary_path: Option<PathBuf>,
 }
 
+/// The build profiles searched for artifacts when none is given, in order.
+const DEFAULT_PROFILES: [&str; 2] = ["release", "debug"];
+
 impl AbigenTarget {
     pub fn new(name: String, source: Abi, program_type: ProgramType) -> Self {
         Self {
             name,
             source,
             program_type,
+            binary_path: None,
         }
     }
 
+    /// Loads the ABI built by `forc build` for the project at `project_dir`, from
+    /// `out/<profile>`. Without a `profile`, the `release` artifacts are used if present,
+    /// otherwise the `debug` ones. The binary next to the ABI, if any, is exposed by the
+    /// bindings, see [`AbigenTarget::with_binary_path`].
+    pub fn from_forc_project(
+        name: String,
+        project_dir: impl AsRef<Path>,
+        program_type: ProgramType,
+        profile: Option<&str>,
+    ) -> Result<Self> {
+        let project_dir = Abi::canonicalize_path(project_dir.as_ref())?;
+        let (abi_path, binary_path) = find_forc_artifacts(&project_dir, profile)?;
+
+        let target = Self::new(name, Abi::load_from(abi_path)?, program_type);
+
+        Ok(match binary_path {
+            Some(binary_path) => target.with_binary_path(binary_path),
+            None => target,
+        })
+    }
+
+    /// Makes the bindings expose `binary_path` as a constant named after the target, e.g.
+    /// `MY_CONTRACT_BINARY_PATH` for `MyContract`.
+    pub fn with_binary_path(mut self, binary_path: impl Into<PathBuf>) -> Self {
+        self.binary_path = Some(binary_path.into());
+        self
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -37,6 +74,81 @@ impl AbigenTarget {
     pub fn program_type(&self) -> ProgramType {
         self.program_type
     }
+
+    pub fn binary_path(&self) -> Option<&PathBuf> {
+        self.binary_path.as_ref()
+    }
+}
+
+/// Returns the ABI in the output directory of the first profile that has one, and the binary
+/// next to it, if it exists.
+fn find_forc_artifacts(
+    project_dir: &Path,
+    profile: Option<&str>,
+) -> Result<(PathBuf, Option<PathBuf>)> {
+    let profiles = match profile {
+        Some(profile) => vec![profile],
+        None => DEFAULT_PROFILES.to_vec(),
+    };
+
+    for profile in &profiles {
+        let out_dir = project_dir.join("out").join(profile);
+
+        if let Some(abi_path) = find_abi_file(&out_dir)? {
+            let binary_path = abi_path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.strip_suffix("-abi.json"))
+                .map(|program_name| out_dir.join(format!("{program_name}.bin")))
+                .filter(|binary_path| binary_path.is_file());
+
+            return Ok((abi_path, binary_path));
+        }
+    }
+
+    let searched = profiles
+        .iter()
+        .map(|profile| format!("`out/{profile}`"))
+        .collect::<Vec<_>>()
+        .join(" or ");
+
+    Err(error!(
+        "no ABI found in {} of the forc project {}. Did you run `forc build`?",
+        searched,
+        project_dir.display()
+    ))
+}
+
+fn find_abi_file(out_dir: &Path) -> Result<Option<PathBuf>> {
+    let entries = match fs::read_dir(out_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(error!(
+                "failed to read directory {}: {}",
+                out_dir.display(),
+                e
+            ))
+        }
+    };
+
+    let abi_files = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|file_name| file_name.to_str())
+                .is_some_and(|file_name| file_name.ends_with("-abi.json"))
+        })
+        .collect::<Vec<_>>();
+
+    match abi_files.as_slice() {
+        [] => Ok(None),
+        [abi_file] => Ok(Some(abi_file.clone())),
+        _ => Err(error!(
+            "found several ABI files in {}, expected one",
+            out_dir.display()
+        )),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -56,7 +168,8 @@ impl Abi {
                 e
             )
         })?;
-        let abi = Self::parse_from_json(&json_abi)?;
+        let abi = Self::parse_from_json(&json_abi)
+            .map_err(|e| error!("invalid `abi` file {}:", path.display()).combine(e))?;
 
         Ok(Abi {
             path: Some(path),
@@ -95,6 +208,18 @@ impl Abi {
     }
 
     fn parse_from_json(json_abi: &str) -> Result<FullProgramABI> {
+        let problems = AbiValidator::validate(json_abi);
+        if !problems.is_empty() {
+            let problems = problems
+                .iter()
+                .map(|problem| format!("\n  {problem}"))
+                .collect::<String>();
+
+            return Err(error!(
+                "malformed `abi`. Did you use `forc` to create it?{problems}"
+            ));
+        }
+
         FullProgramABI::from_json_abi(json_abi)
             .map_err(|e| error!("malformed `abi`. Did you use `forc` to create it?: ").combine(e))
     }
@@ -123,6 +248,9 @@ pub enum ProgramType {
     Script,
     Contract,
     Predicate,
+    /// A Sway `abi` implemented by several contracts. Instead of contract bindings, a trait is
+    /// generated and implemented for the methods of every contract exposing its functions.
+    Interface,
 }
 
 impl FromStr for ProgramType {
@@ -133,9 +261,10 @@ impl FromStr for ProgramType {
             "Script" => ProgramType::Script,
             "Contract" => ProgramType::Contract,
             "Predicate" => ProgramType::Predicate,
+            "Interface" => ProgramType::Interface,
             _ => {
                 return Err(error!(
-                    "`{string}` is not a valid program type. Expected one of: `Script`, `Contract`, `Predicate`"
+                    "`{string}` is not a valid program type. Expected one of: `Script`, `Contract`, `Predicate`, `Interface`"
                 ))
             }
         };
@@ -155,3 +284,78 @@ impl TryFrom<Ident> for ProgramType {
             .map_err(|e| Self::Error::new(ident.span(), e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"{ "types": [], "functions": [] }"#;
+
+    fn add_artifacts(project_dir: &Path, profile: &str, with_binary: bool) -> Result<PathBuf> {
+        let out_dir = project_dir.join("out").join(profile);
+        fs::create_dir_all(&out_dir)?;
+        fs::write(out_dir.join("my_project-abi.json"), ABI)?;
+        if with_binary {
+            fs::write(out_dir.join("my_project.bin"), [0; 8])?;
+        }
+
+        Ok(out_dir)
+    }
+
+    fn target(project_dir: &Path, profile: Option<&str>) -> Result<AbigenTarget> {
+        AbigenTarget::from_forc_project(
+            "MyContract".to_string(),
+            project_dir,
+            ProgramType::Contract,
+            profile,
+        )
+    }
+
+    #[test]
+    fn release_artifacts_are_preferred() -> Result<()> {
+        let project = tempfile::tempdir()?;
+        add_artifacts(project.path(), "debug", true)?;
+
+        let debug = target(project.path(), None)?;
+        assert!(debug
+            .binary_path()
+            .unwrap()
+            .ends_with("debug/my_project.bin"));
+
+        let release_dir = add_artifacts(project.path(), "release", true)?;
+        let release = target(project.path(), None)?;
+        assert_eq!(
+            release.source().path().unwrap(),
+            &release_dir
+                .join("my_project-abi.json")
+                .canonicalize()
+                .unwrap()
+        );
+        assert!(release
+            .binary_path()
+            .unwrap()
+            .ends_with("release/my_project.bin"));
+
+        let debug = target(project.path(), Some("debug"))?;
+        assert!(debug
+            .binary_path()
+            .unwrap()
+            .ends_with("debug/my_project.bin"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_artifacts_are_reported() -> Result<()> {
+        let project = tempfile::tempdir()?;
+        add_artifacts(project.path(), "debug", false)?;
+
+        let err = target(project.path(), Some("release")).expect_err("should fail");
+        assert!(err.to_string().contains("no ABI found in `out/release`"));
+
+        let without_binary = target(project.path(), None)?;
+        assert!(without_binary.binary_path().is_none());
+
+        Ok(())
+    }
+}
@@ -4,7 +4,8 @@ use crate::{
         abigen::{
             abigen_target::AbigenTarget,
             bindings::{
-                contract::contract_bindings, predicate::predicate_bindings, script::script_bindings,
+                contract::contract_bindings, interface::interface_bindings,
+                predicate::predicate_bindings, script::script_bindings,
             },
             ProgramType,
         },
@@ -15,15 +16,19 @@ use crate::{
 
 mod contract;
 mod function_generator;
+mod interface;
 mod predicate;
 mod script;
 mod utils;
 
+pub(crate) use interface::interface_impls;
+
 pub(crate) fn generate_bindings(target: AbigenTarget, no_std: bool) -> Result<GeneratedCode> {
     let bindings_generator = match target.program_type {
         ProgramType::Script => script_bindings,
         ProgramType::Contract => contract_bindings,
         ProgramType::Predicate => predicate_bindings,
+        ProgramType::Interface => interface_bindings,
     };
 
     let name = ident(&target.name);
@@ -43,6 +43,8 @@ pub(crate) fn contract_bindings(
             account: T,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            decoder_config: ::fuels::core::codec::DecoderConfig,
+            read_cache: ::core::option::Option<::fuels::programs::contract::ReadCallCache>,
         }
 
         impl<T: ::fuels::accounts::Account> #name<T>
@@ -54,7 +56,8 @@ pub(crate) fn contract_bindings(
                 let contract_id: ::fuels::types::bech32::Bech32ContractId = contract_id.into();
                 let log_decoder = ::fuels::core::codec::LogDecoder::new(#log_formatters);
                 let encoder_config = ::fuels::core::codec::EncoderConfig::default();
-                Self { contract_id, account, log_decoder, encoder_config }
+                let decoder_config = ::fuels::core::codec::DecoderConfig::default();
+                Self { contract_id, account, log_decoder, encoder_config, decoder_config, read_cache: ::core::option::Option::None }
             }
 
             pub fn contract_id(&self) -> &::fuels::types::bech32::Bech32ContractId {
@@ -71,7 +74,9 @@ pub(crate) fn contract_bindings(
                         contract_id: self.contract_id,
                         account,
                         log_decoder: self.log_decoder,
-                        encoder_config: self.encoder_config
+                        encoder_config: self.encoder_config,
+                        decoder_config: self.decoder_config,
+                        read_cache: self.read_cache,
                 }
             }
 
@@ -82,6 +87,36 @@ pub(crate) fn contract_bindings(
                 self
             }
 
+            pub fn with_decoder_config(mut self, decoder_config: ::fuels::core::codec::DecoderConfig)
+            -> #name::<T> {
+                self.decoder_config = decoder_config;
+                self.log_decoder.set_decoder_config(decoder_config);
+
+                self
+            }
+
+            /// Serves the simulations of calls made through this instance from `read_cache` when
+            /// the same call was simulated at the current block height.
+            pub fn with_read_cache(mut self, read_cache: ::fuels::programs::contract::ReadCallCache)
+            -> #name::<T> {
+                self.read_cache = ::core::option::Option::Some(read_cache);
+
+                self
+            }
+
+            /// Returns a copy of the instance encoding calls with `encoder_config` and decoding
+            /// their outputs and logs with `decoder_config`. The log formatters are shared with
+            /// `self`, so this is cheap enough to do per call site.
+            pub fn with_codec_config(
+                &self,
+                encoder_config: ::fuels::core::codec::EncoderConfig,
+                decoder_config: ::fuels::core::codec::DecoderConfig,
+            ) -> #name::<T> {
+                self.clone()
+                    .with_encoder_config(encoder_config)
+                    .with_decoder_config(decoder_config)
+            }
+
             pub async fn get_balances(&self) -> ::fuels::types::errors::Result<::std::collections::HashMap<::fuels::types::AssetId, u64>> {
                 ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
                                   .get_contract_balances(&self.contract_id)
@@ -89,12 +124,36 @@ pub(crate) fn contract_bindings(
                                   .map_err(::std::convert::Into::into)
             }
 
+            pub async fn get_asset_balance(&self, asset_id: &::fuels::types::AssetId) -> ::fuels::types::errors::Result<u64> {
+                ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?
+                                  .get_contract_asset_balance(&self.contract_id, *asset_id)
+                                  .await
+            }
+
+            pub fn subscribe_events<E>(
+                &self,
+                from_height: ::core::option::Option<u32>,
+                poll_interval: ::std::time::Duration,
+            ) -> ::fuels::types::errors::Result<::fuels::accounts::provider::LogStream<'_, E>>
+            where
+                E: ::fuels::core::traits::Tokenizable
+                    + ::fuels::core::traits::Parameterize
+                    + ::core::marker::Send
+                    + 'static,
+            {
+                let provider = ::fuels::accounts::ViewOnlyAccount::try_provider(&self.account)?;
+
+                ::core::result::Result::Ok(provider.subscribe_logs(&self.log_decoder, from_height, poll_interval))
+            }
+
             pub fn methods(&self) -> #methods_name<T> {
                 #methods_name {
                     contract_id: self.contract_id.clone(),
                     account: self.account.clone(),
                     log_decoder: self.log_decoder.clone(),
                     encoder_config: self.encoder_config.clone(),
+                    decoder_config: self.decoder_config,
+                    read_cache: self.read_cache.clone(),
                 }
             }
         }
@@ -105,6 +164,8 @@ pub(crate) fn contract_bindings(
             account: T,
             log_decoder: ::fuels::core::codec::LogDecoder,
             encoder_config: ::fuels::core::codec::EncoderConfig,
+            decoder_config: ::fuels::core::codec::DecoderConfig,
+            read_cache: ::core::option::Option<::fuels::programs::contract::ReadCallCache>,
         }
 
         impl<T: ::fuels::accounts::Account> #methods_name<T> {
@@ -167,6 +228,7 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
     let fn_selector = generator.fn_selector();
     let arg_tokens = generator.tokenized_args();
     let is_payable = abi_fun.is_payable();
+    let writes_storage = writes_storage(abi_fun);
     let body = quote! {
             ::fuels::programs::contract::method_hash(
                 self.contract_id.clone(),
@@ -177,19 +239,29 @@ pub(crate) fn expand_fn(abi_fun: &FullABIFunction) -> Result<TokenStream> {
                 #is_payable,
                 self.encoder_config.clone(),
             )
+            .with_decoder_config(self.decoder_config)
+            .with_read_cache(self.read_cache.clone())
+            .with_writes_storage(#writes_storage)
     };
     generator.set_body(body);
 
     Ok(generator.generate())
 }
 
+/// Sway only lets methods annotated with `#[storage(write)]` write to storage.
+fn writes_storage(abi_fun: &FullABIFunction) -> bool {
+    abi_fun.attributes().iter().any(|attribute| {
+        attribute.name == "storage" && attribute.arguments.iter().any(|arg| arg == "write")
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
     use fuel_abi_types::abi::{
         full_program::FullABIFunction,
-        program::{ABIFunction, ProgramABI, TypeApplication, TypeDeclaration},
+        program::{ABIFunction, Attribute, ProgramABI, TypeApplication, TypeDeclaration},
     };
     use pretty_assertions::assert_eq;
     use quote::quote;
@@ -369,6 +441,9 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_decoder_config(self.decoder_config)
+                .with_read_cache(self.read_cache.clone())
+                .with_writes_storage(false)
             }
         };
 
@@ -422,6 +497,9 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_decoder_config(self.decoder_config)
+                .with_read_cache(self.read_cache.clone())
+                .with_writes_storage(false)
             }
         };
 
@@ -531,6 +609,55 @@ mod tests {
                     false,
                     self.encoder_config.clone(),
                 )
+                .with_decoder_config(self.decoder_config)
+                .with_read_cache(self.read_cache.clone())
+                .with_writes_storage(false)
+            }
+        };
+
+        assert_eq!(result?.to_string(), expected.to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_fn_writing_storage() -> Result<()> {
+        let the_function = ABIFunction {
+            name: "increment".to_string(),
+            attributes: Some(vec![Attribute {
+                name: String::from("storage"),
+                arguments: vec![String::from("read"), String::from("write")],
+            }]),
+            ..Default::default()
+        };
+        let types = [(
+            0,
+            TypeDeclaration {
+                type_id: 0,
+                type_field: String::from("()"),
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        let result = expand_fn(&FullABIFunction::from_counterpart(&the_function, &types)?);
+
+        let expected = quote! {
+            #[doc = "Calls the contract's `increment` function"]
+            pub fn increment(&self) -> ::fuels::programs::contract::ContractCallHandler<T, ()> {
+                ::fuels::programs::contract::method_hash(
+                    self.contract_id.clone(),
+                    self.account.clone(),
+                    ::fuels::core::codec::encode_fn_selector( "increment"),
+                    &[],
+                    self.log_decoder.clone(),
+                    false,
+                    self.encoder_config.clone(),
+                )
+                .with_decoder_config(self.decoder_config)
+                .with_read_cache(self.read_cache.clone())
+                .with_writes_storage(true)
             }
         };
 
@@ -1,5 +1,5 @@
 use fuel_abi_types::abi::full_program::FullABIFunction;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 
 use crate::{
@@ -80,15 +80,58 @@ impl FunctionGenerator {
         &self.output_type
     }
 
+    pub fn arg_names(&self) -> impl Iterator<Item = &Ident> {
+        self.args.iter().map(|(name, _)| name)
+    }
+
     pub fn generate(&self) -> TokenStream {
-        let name = safe_ident(&self.name);
-        let doc = self
-            .doc
+        let doc = self.doc();
+        let signature = self.signature();
+        let body = &self.body;
+
+        quote! {
+            #doc
+            pub #signature {
+                #body
+            }
+        }
+    }
+
+    /// Generates the declaration of the function as a trait method, without a body.
+    pub fn generate_trait_declaration(&self) -> TokenStream {
+        let doc = self.doc();
+        let signature = self.signature();
+
+        quote! {
+            #doc
+            #signature;
+        }
+    }
+
+    /// Generates the function as the implementation of a trait method, with `body` as its body.
+    pub fn generate_trait_impl(&self, body: TokenStream) -> TokenStream {
+        let doc = self.doc();
+        let signature = self.signature();
+
+        quote! {
+            #doc
+            #signature {
+                #body
+            }
+        }
+    }
+
+    fn doc(&self) -> TokenStream {
+        self.doc
             .as_ref()
             .map(|text| {
                 quote! { #[doc = #text] }
             })
-            .unwrap_or_default();
+            .unwrap_or_default()
+    }
+
+    fn signature(&self) -> TokenStream {
+        let name = safe_ident(&self.name);
 
         let arg_declarations = self.args.iter().map(|(name, ty)| {
             get_equivalent_bech32_type(ty)
@@ -99,16 +142,10 @@ impl FunctionGenerator {
         });
 
         let output_type = self.output_type();
-        let body = &self.body;
 
         let params = quote! { &self, #(#arg_declarations),* };
 
-        quote! {
-            #doc
-            pub fn #name(#params) -> #output_type {
-                #body
-            }
-        }
+        quote! { fn #name(#params) -> #output_type }
     }
 }
 
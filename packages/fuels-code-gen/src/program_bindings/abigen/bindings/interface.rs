@@ -0,0 +1,205 @@
+use fuel_abi_types::abi::full_program::{FullABIFunction, FullProgramABI, FullTypeApplication};
+use inflector::Inflector;
+use itertools::Itertools;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, TokenStreamExt};
+
+use crate::{
+    error::Result,
+    program_bindings::{
+        abigen::{
+            abigen_target::AbigenTarget, bindings::function_generator::FunctionGenerator,
+            ProgramType,
+        },
+        generated_code::GeneratedCode,
+    },
+    utils::{ident, safe_ident, TypePath},
+};
+
+/// Generates a trait with a method for every function of the interface. It is implemented for the
+/// methods of every contract exposing all of those functions, see [`interface_impls`].
+pub(crate) fn interface_bindings(
+    name: &Ident,
+    abi: FullProgramABI,
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    if no_std {
+        return Ok(GeneratedCode::default());
+    }
+
+    let declarations = abi
+        .functions
+        .iter()
+        .map(|fun| Ok(interface_fn(fun)?.generate_trait_declaration()))
+        .fold_ok(TokenStream::default(), |mut all_code, code| {
+            all_code.append_all(code);
+            all_code
+        })?;
+
+    let code = quote! {
+        pub trait #name<T: ::fuels::accounts::Account> {
+            #declarations
+        }
+    };
+
+    let type_path = TypePath::new(name).expect("We know the given type is not empty");
+
+    Ok(GeneratedCode::new(code, [type_path].into(), no_std))
+}
+
+/// Implements the trait of every interface in `targets` whose functions are all exposed by the
+/// contract `contract_name`, with the same argument and output types. The generated code belongs
+/// to the mod of the contract.
+pub(crate) fn interface_impls(
+    contract_name: &Ident,
+    contract_abi: &FullProgramABI,
+    targets: &[AbigenTarget],
+    no_std: bool,
+) -> Result<GeneratedCode> {
+    if no_std {
+        return Ok(GeneratedCode::default());
+    }
+
+    let methods_name = ident(&format!("{contract_name}Methods"));
+
+    let code = targets
+        .iter()
+        .filter(|target| target.program_type == ProgramType::Interface)
+        .filter(|interface| implements(contract_abi, &interface.source.abi))
+        .map(|interface| interface_impl(&methods_name, interface))
+        .fold_ok(TokenStream::default(), |mut all_code, code| {
+            all_code.append_all(code);
+            all_code
+        })?;
+
+    Ok(GeneratedCode::new(code, Default::default(), no_std))
+}
+
+fn interface_impl(methods_name: &Ident, interface: &AbigenTarget) -> Result<TokenStream> {
+    let interface_name = ident(&interface.name);
+    let interface_mod = ident(&format!("{}_mod", interface.name.to_snake_case()));
+
+    let fn_impls = interface
+        .source
+        .abi
+        .functions
+        .iter()
+        .map(|fun| {
+            let generator = interface_fn(fun)?;
+            let fn_name = safe_ident(fun.name());
+            let arg_names = generator.arg_names();
+
+            Ok(generator.generate_trait_impl(quote! {
+                #methods_name::#fn_name(self, #(#arg_names),*)
+            }))
+        })
+        .fold_ok(TokenStream::default(), |mut all_code, code| {
+            all_code.append_all(code);
+            all_code
+        })?;
+
+    Ok(quote! {
+        impl<T: ::fuels::accounts::Account> super::#interface_mod::#interface_name<T> for #methods_name<T> {
+            #fn_impls
+        }
+    })
+}
+
+fn interface_fn(fun: &FullABIFunction) -> Result<FunctionGenerator> {
+    let mut generator = FunctionGenerator::new(fun)?;
+
+    generator.set_doc(format!("Calls the contract's `{}` function", fun.name()));
+
+    let original_output = generator.output_type();
+    generator.set_output_type(
+        quote! {::fuels::programs::contract::ContractCallHandler<T, #original_output> },
+    );
+
+    Ok(generator)
+}
+
+fn implements(contract_abi: &FullProgramABI, interface_abi: &FullProgramABI) -> bool {
+    interface_abi.functions.iter().all(|interface_fn| {
+        contract_abi
+            .functions
+            .iter()
+            .any(|contract_fn| same_signature(contract_fn, interface_fn))
+    })
+}
+
+/// Argument names are not part of the signature, implementations may name them differently.
+fn same_signature(lhs: &FullABIFunction, rhs: &FullABIFunction) -> bool {
+    let same_type = |lhs: &FullTypeApplication, rhs: &FullTypeApplication| {
+        lhs.type_decl == rhs.type_decl && lhs.type_arguments == rhs.type_arguments
+    };
+
+    lhs.name() == rhs.name()
+        && same_type(lhs.output(), rhs.output())
+        && lhs.inputs().len() == rhs.inputs().len()
+        && lhs
+            .inputs()
+            .iter()
+            .zip(rhs.inputs())
+            .all(|(lhs, rhs)| same_type(lhs, rhs))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::program_bindings::abigen::Abi;
+
+    fn given_an_abi(fn_name: &str, input_type: &str) -> Abi {
+        Abi::from_str(&format!(
+            r#"
+            {{
+                "types": [
+                    {{ "typeId": 0, "type": "bool", "components": null, "typeParameters": null }},
+                    {{ "typeId": 1, "type": "{input_type}", "components": null, "typeParameters": null }}
+                ],
+                "functions": [
+                    {{
+                        "inputs": [{{ "name": "amount", "type": 1, "typeArguments": null }}],
+                        "name": "{fn_name}",
+                        "output": {{ "name": "", "type": 0, "typeArguments": null }}
+                    }}
+                ]
+            }}
+            "#
+        ))
+        .expect("valid abi")
+    }
+
+    #[test]
+    fn interfaces_are_implemented_only_by_matching_contracts() -> Result<()> {
+        let interface = AbigenTarget::new(
+            "Vault".to_string(),
+            given_an_abi("deposit", "u64"),
+            ProgramType::Interface,
+        );
+        let targets = [interface];
+
+        let matching = given_an_abi("deposit", "u64").abi;
+        let other_name = given_an_abi("withdraw", "u64").abi;
+        let other_input = given_an_abi("deposit", "u32").abi;
+
+        let code = interface_impls(&ident("VaultA"), &matching, &targets, false)?.code();
+        let expected = quote! {
+            impl<T: ::fuels::accounts::Account> super::vault_mod::Vault<T> for VaultAMethods<T> {
+                #[doc = "Calls the contract's `deposit` function"]
+                fn deposit(&self, amount: ::core::primitive::u64) -> ::fuels::programs::contract::ContractCallHandler<T, ::core::primitive::bool> {
+                    VaultAMethods::deposit(self, amount)
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected.to_string());
+
+        for abi in [other_name, other_input] {
+            let code = interface_impls(&ident("VaultB"), &abi, &targets, false)?;
+            assert!(code.is_empty());
+        }
+
+        Ok(())
+    }
+}
@@ -2,10 +2,12 @@ use std::collections::HashSet;
 
 use fuel_abi_types::abi::full_program::FullTypeDeclaration;
 use itertools::Itertools;
+use proc_macro2::TokenStream;
 use quote::quote;
+use syn::parse::Parser;
 
 use crate::{
-    error::Result,
+    error::{error, Result},
     program_bindings::{
         custom_types::{enums::expand_custom_enum, structs::expand_custom_struct},
         generated_code::GeneratedCode,
@@ -18,6 +20,48 @@ mod enums;
 mod structs;
 mod utils;
 
+/// Extra attributes, e.g. derives or `#[non_exhaustive]`, added to the declaration of the
+/// generated structs and enums named in `types`.
+#[derive(Debug, Clone)]
+pub struct TypeAttributes {
+    types: Vec<TypePath>,
+    attributes: TokenStream,
+}
+
+impl TypeAttributes {
+    /// `types` is a comma separated list of type names, e.g. `"MyEnum, some_lib::MyStruct"`. A
+    /// name without a path matches the type of that name in any module. `attributes` holds outer
+    /// attributes, e.g. `"#[non_exhaustive] #[derive(::serde::Serialize)]"`.
+    pub fn new(types: &str, attributes: &str) -> Result<Self> {
+        let types = types
+            .split(',')
+            .map(str::trim)
+            .filter(|ty| !ty.is_empty())
+            .map(TypePath::new)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if types.is_empty() {
+            return Err(error!("no types given for the attributes `{attributes}`"));
+        }
+
+        let attributes: TokenStream = attributes.parse()?;
+        syn::Attribute::parse_outer
+            .parse2(attributes.clone())
+            .map_err(|e| error!("expected outer attributes, e.g. `#[non_exhaustive]`: {e}"))?;
+
+        Ok(Self { types, attributes })
+    }
+
+    fn applies_to(&self, type_path: &TypePath) -> bool {
+        self.types.iter().any(|ty| {
+            if ty.has_multiple_parts() {
+                ty == type_path
+            } else {
+                ty.ident() == type_path.ident()
+            }
+        })
+    }
+}
+
 /// Generates Rust code for each type inside `types` if:
 /// * the type is not present inside `shared_types`, and
 /// * if it should be generated (see: [`should_skip_codegen`], and
@@ -29,9 +73,11 @@ mod utils;
 /// * `types`: Types you wish to generate Rust code for.
 /// * `shared_types`: Types that are shared between multiple
 ///                   contracts/scripts/predicates and thus generated elsewhere.
+/// * `type_attributes`: Extra attributes to add to the generated types.
 pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>>(
     types: T,
     shared_types: &HashSet<FullTypeDeclaration>,
+    type_attributes: &[TypeAttributes],
     no_std: bool,
 ) -> Result<GeneratedCode> {
     types
@@ -39,11 +85,14 @@ pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>
         .filter(|ttype| !should_skip_codegen(ttype))
         .map(|ttype: &FullTypeDeclaration| {
             if shared_types.contains(ttype) {
-                reexport_the_shared_type(ttype, no_std)
-            } else if ttype.is_struct_type() {
-                expand_custom_struct(ttype, no_std)
+                return reexport_the_shared_type(ttype, no_std);
+            }
+
+            let attributes = attributes_of(ttype, type_attributes)?;
+            if ttype.is_struct_type() {
+                expand_custom_struct(ttype, &attributes, no_std)
             } else {
-                expand_custom_enum(ttype, no_std)
+                expand_custom_enum(ttype, &attributes, no_std)
             }
         })
         .fold_ok(GeneratedCode::default(), |acc, generated_code| {
@@ -51,6 +100,19 @@ pub(crate) fn generate_types<'a, T: IntoIterator<Item = &'a FullTypeDeclaration>
         })
 }
 
+fn attributes_of(
+    ttype: &FullTypeDeclaration,
+    type_attributes: &[TypeAttributes],
+) -> Result<TokenStream> {
+    let type_path = ttype.custom_type_path()?;
+
+    Ok(type_attributes
+        .iter()
+        .filter(|type_attributes| type_attributes.applies_to(&type_path))
+        .map(|type_attributes| type_attributes.attributes.clone())
+        .collect())
+}
+
 /// Instead of generating bindings for `ttype` this fn will just generate a `pub use` pointing to
 /// the already generated equivalent shared type.
 fn reexport_the_shared_type(ttype: &FullTypeDeclaration, no_std: bool) -> Result<GeneratedCode> {
@@ -169,7 +231,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -202,8 +268,12 @@ mod tests {
         };
         let types = [(0, p.clone())].into_iter().collect::<HashMap<_, _>>();
 
-        expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)
-            .expect_err("Was able to construct an enum without variants");
+        expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )
+        .expect_err("Was able to construct an enum without variants");
 
         Ok(())
     }
@@ -253,7 +323,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -315,7 +389,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -389,7 +467,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual = expand_custom_enum(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_enum(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[allow(clippy::enum_variant_names)]
@@ -464,8 +546,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -512,8 +597,11 @@ mod tests {
         };
         let types = [(0, p.clone())].into_iter().collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -581,8 +669,11 @@ mod tests {
         .into_iter()
         .collect::<HashMap<_, _>>();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(&p, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(&p, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -692,8 +783,11 @@ mod tests {
 
         let s1 = types.get(&3).unwrap();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(s1, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(s1, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -720,8 +814,11 @@ mod tests {
 
         let s2 = types.get(&4).unwrap();
 
-        let actual =
-            expand_custom_struct(&FullTypeDeclaration::from_counterpart(s2, &types), false)?;
+        let actual = expand_custom_struct(
+            &FullTypeDeclaration::from_counterpart(s2, &types),
+            &TokenStream::default(),
+            false,
+        )?;
 
         let expected = quote! {
             #[derive(
@@ -760,7 +857,7 @@ mod tests {
         let shared_types = HashSet::from([type_decl.clone()]);
 
         // when
-        let generated_code = generate_types(&[type_decl], &shared_types, false).unwrap();
+        let generated_code = generate_types(&[type_decl], &shared_types, &[], false).unwrap();
 
         // then
         let expected_code = quote! {
@@ -783,4 +880,54 @@ mod tests {
 
         assert_eq!(generated_code.code().to_string(), expected_code.to_string());
     }
+
+    #[test]
+    fn type_attributes_are_added_to_the_named_types() -> Result<()> {
+        // given
+        let [matched_by_name, matched_by_path, unmatched] = [
+            "struct some_lib::Matched",
+            "struct other_lib::AlsoMatched",
+            "struct other_lib::Unmatched",
+        ]
+        .map(|type_field| FullTypeDeclaration {
+            type_field: type_field.to_string(),
+            components: vec![],
+            type_parameters: vec![],
+        });
+        let type_attributes = [
+            TypeAttributes::new("Matched", "#[non_exhaustive]")?,
+            TypeAttributes::new(
+                "other_lib::AlsoMatched",
+                "#[derive(::core::hash::Hash)] #[allow(dead_code)]",
+            )?,
+        ];
+        let generate = |type_decl: FullTypeDeclaration| -> Result<String> {
+            let code = generate_types(&[type_decl], &HashSet::default(), &type_attributes, false)?;
+
+            Ok(code.code().to_string())
+        };
+
+        // when
+        let matched_by_name = generate(matched_by_name)?;
+        let matched_by_path = generate(matched_by_path)?;
+        let unmatched = generate(unmatched)?;
+
+        // then
+        assert!(matched_by_name.contains("# [non_exhaustive]"));
+        assert!(
+            matched_by_path.contains("# [derive (:: core :: hash :: Hash)] # [allow (dead_code)]")
+        );
+        assert!(!matched_by_path.contains("non_exhaustive"));
+        assert!(!unmatched.contains("non_exhaustive"));
+        assert!(!unmatched.contains("Hash"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_attributes_must_be_outer_attributes() {
+        let err = TypeAttributes::new("MyEnum", "non_exhaustive").expect_err("should fail");
+
+        assert!(err.to_string().contains("expected outer attributes"));
+    }
 }
@@ -18,6 +18,7 @@ use crate::{
 /// given TypeDeclaration.
 pub(crate) fn expand_custom_enum(
     type_decl: &FullTypeDeclaration,
+    attributes: &TokenStream,
     no_std: bool,
 ) -> Result<GeneratedCode> {
     let enum_type_path = type_decl.custom_type_path()?;
@@ -29,7 +30,7 @@ pub(crate) fn expand_custom_enum(
     }
     let generics = extract_generic_parameters(type_decl);
 
-    let code = enum_decl(enum_ident, &components, &generics, no_std);
+    let code = enum_decl(enum_ident, &components, &generics, attributes, no_std);
 
     let enum_code = GeneratedCode::new(code, HashSet::from([enum_ident.into()]), no_std);
 
@@ -40,6 +41,7 @@ fn enum_decl(
     enum_ident: &Ident,
     components: &Components,
     generics: &[Ident],
+    attributes: &TokenStream,
     no_std: bool,
 ) -> TokenStream {
     let maybe_disable_std = no_std.then(|| quote! {#[NoStd]});
@@ -59,6 +61,7 @@ fn enum_decl(
             ::fuels::macros::Tokenizable,
             ::fuels::macros::TryFrom,
         )]
+        #attributes
         #maybe_disable_std
         pub enum #enum_ident #generics_w_bounds {
             #(#enum_variants,)*
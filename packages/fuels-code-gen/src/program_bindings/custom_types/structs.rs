@@ -18,6 +18,7 @@ use crate::{
 /// given TypeDeclaration.
 pub(crate) fn expand_custom_struct(
     type_decl: &FullTypeDeclaration,
+    attributes: &TokenStream,
     no_std: bool,
 ) -> Result<GeneratedCode> {
     let struct_type_path = type_decl.custom_type_path()?;
@@ -26,7 +27,13 @@ pub(crate) fn expand_custom_struct(
     let components = Components::new(&type_decl.components, true, struct_type_path.parent())?;
     let generic_parameters = extract_generic_parameters(type_decl);
 
-    let code = struct_decl(struct_ident, &components, &generic_parameters, no_std);
+    let code = struct_decl(
+        struct_ident,
+        &components,
+        &generic_parameters,
+        attributes,
+        no_std,
+    );
 
     let struct_code = GeneratedCode::new(code, HashSet::from([struct_ident.into()]), no_std);
 
@@ -37,6 +44,7 @@ fn struct_decl(
     struct_ident: &Ident,
     components: &Components,
     generics: &[Ident],
+    attributes: &TokenStream,
     no_std: bool,
 ) -> TokenStream {
     let derive_default = components
@@ -61,6 +69,7 @@ fn struct_decl(
             ::fuels::macros::Tokenizable,
             ::fuels::macros::TryFrom,
         )]
+        #attributes
         #maybe_disable_std
         pub struct #struct_ident #generics_w_bounds {
             #( pub #field_names: #field_types, )*
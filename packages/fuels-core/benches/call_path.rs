@@ -0,0 +1,98 @@
+//! Measures the work repeated by abigen-generated bindings on every contract call.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use fuel_tx::ContractId;
+use fuels_core::{
+    codec::{encode_fn_selector, log_formatters_lookup, ABIEncoder, LogDecoder, LogFormatter},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        param_types::{EnumVariants, ParamType},
+        Bits256, SizedAsciiString,
+    },
+};
+use fuels_macros::Parameterize;
+
+#[allow(dead_code)]
+#[derive(Parameterize)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+enum Side {
+    Buy(u64),
+    Sell(u64),
+}
+
+#[allow(dead_code)]
+#[derive(Parameterize)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+struct Order {
+    maker: Bits256,
+    amounts: Vec<u64>,
+    side: Side,
+    memo: SizedAsciiString<8>,
+}
+
+/// What the derived `Order::param_type` did before caching, resolving every member on each call.
+fn uncached_order_param_type() -> ParamType {
+    let side_variants = vec![
+        ("Buy".to_string(), u64::param_type()),
+        ("Sell".to_string(), u64::param_type()),
+    ];
+
+    ParamType::Struct {
+        name: "Order".to_string(),
+        fields: vec![
+            ("maker".to_string(), Bits256::param_type()),
+            ("amounts".to_string(), Vec::<u64>::param_type()),
+            (
+                "side".to_string(),
+                ParamType::Enum {
+                    name: "Side".to_string(),
+                    enum_variants: EnumVariants::new(side_variants).expect("has variants"),
+                    generics: vec![],
+                },
+            ),
+            ("memo".to_string(), SizedAsciiString::<8>::param_type()),
+        ],
+        generics: vec![],
+    }
+}
+
+fn log_decoder_with(number_of_logs: u64) -> LogDecoder {
+    let pairs = (0..number_of_logs)
+        .map(|log_id| (log_id, LogFormatter::new::<SizedAsciiString<8>>()))
+        .collect();
+
+    LogDecoder::new(log_formatters_lookup(pairs, ContractId::zeroed()))
+}
+
+fn call_path(c: &mut Criterion) {
+    let log_decoder = log_decoder_with(64);
+    c.bench_function("clone log decoder", |b| {
+        b.iter(|| black_box(&log_decoder).clone())
+    });
+
+    c.bench_function("encode fn selector", |b| {
+        b.iter(|| encode_fn_selector(black_box("some_contract_method")))
+    });
+
+    c.bench_function("resolve output param type", |b| {
+        b.iter(<(u64, Bits256, Vec<u32>, SizedAsciiString<8>)>::param_type)
+    });
+
+    assert_eq!(Order::param_type(), uncached_order_param_type());
+    c.bench_function("resolve custom type param type (uncached)", |b| {
+        b.iter(uncached_order_param_type)
+    });
+    c.bench_function("resolve custom type param type (cached)", |b| {
+        b.iter(Order::param_type)
+    });
+
+    let args = (42u64, Bits256([1; 32]), vec![1u32, 2, 3]).into_token();
+    c.bench_function("encode call args", |b| {
+        b.iter(|| ABIEncoder::default().encode(black_box(std::slice::from_ref(&args))))
+    });
+}
+
+criterion_group!(benches, call_path);
+criterion_main!(benches);
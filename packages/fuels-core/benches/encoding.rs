@@ -0,0 +1,58 @@
+//! Measures encoding large call and predicate data.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    traits::Tokenizable,
+    types::{Bits256, Token},
+};
+
+fn large_args(len: usize) -> Token {
+    let structs = (0..len as u64)
+        .map(|i| (i, Bits256([i as u8; 32]), vec![i as u32; 4]))
+        .collect::<Vec<_>>();
+
+    structs.into_token()
+}
+
+fn encoding(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+
+    for len in [100, 1_000, 10_000] {
+        let args = [large_args(len)];
+        let config = EncoderConfig {
+            max_tokens: usize::MAX,
+            ..Default::default()
+        };
+        let encoder = ABIEncoder::new(config);
+        let encoded_len = encoder.encode(&args).unwrap().resolve(0).len();
+        group.throughput(Throughput::Bytes(encoded_len as u64));
+
+        group.bench_with_input(BenchmarkId::new("growing buffer", len), &args, |b, args| {
+            b.iter(|| encoder.encode(black_box(args)))
+        });
+
+        let encoder = ABIEncoder::new(EncoderConfig {
+            initial_capacity: encoded_len,
+            ..config
+        });
+        group.bench_with_input(
+            BenchmarkId::new("pre-sized buffer", len),
+            &args,
+            |b, args| b.iter(|| encoder.encode(black_box(args))),
+        );
+
+        let mut buffer = Vec::with_capacity(encoded_len);
+        group.bench_with_input(BenchmarkId::new("reused buffer", len), &args, |b, args| {
+            b.iter(|| {
+                buffer.clear();
+                encoder.encode_into(black_box(args), &mut buffer)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, encoding);
+criterion_main!(benches);
@@ -21,6 +21,9 @@ pub struct EncoderConfig {
     /// The total memory size of the top-level token must fit in the available memory of the
     /// system.
     pub max_total_enum_width: usize,
+    /// Bytes reserved for the output before encoding. Encoding large arguments, like long
+    /// vectors, reallocates the output less often when it is close to the encoded size.
+    pub initial_capacity: usize,
 }
 
 // ANCHOR: default_encoder_config
@@ -30,6 +33,7 @@ impl Default for EncoderConfig {
             max_depth: 45,
             max_tokens: 10_000,
             max_total_enum_width: 10_000,
+            initial_capacity: 0,
         }
     }
 }
@@ -50,6 +54,12 @@ impl ABIEncoder {
     pub fn encode(&self, args: &[Token]) -> Result<UnresolvedBytes> {
         BoundedEncoder::new(self.config, false).encode(args)
     }
+
+    /// Encodes `Token`s in `args` like [`ABIEncoder::encode`], appending the bytes to `buffer`.
+    /// Reusing a buffer avoids allocating when encoding many arguments of similar size.
+    pub fn encode_into(&self, args: &[Token], buffer: &mut Vec<u8>) -> Result<()> {
+        BoundedEncoder::new(self.config, false).encode_into(args, buffer)
+    }
 }
 
 #[derive(Default, Clone, Debug)]
@@ -660,6 +670,29 @@ mod tests {
             });
     }
 
+    #[test]
+    fn encoding_into_a_buffer_appends_the_encoded_bytes() -> Result<()> {
+        // given
+        let tokens = [
+            Token::Vector(vec![Token::U64(1), Token::U64(2)]),
+            Token::String("fuel".to_string()),
+        ];
+        let encoder = ABIEncoder::new(EncoderConfig {
+            initial_capacity: 64,
+            ..Default::default()
+        });
+        let mut buffer = vec![0xff];
+
+        // when
+        encoder.encode_into(&tokens, &mut buffer)?;
+
+        // then
+        let expected = chain!([0xff], encoder.encode(&tokens)?.resolve(0)).collect::<Vec<_>>();
+        assert_eq!(buffer, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn encoder_for_configurables_optimizes_top_level_u8() {
         // given
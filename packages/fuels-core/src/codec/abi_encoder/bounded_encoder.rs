@@ -13,6 +13,7 @@ use crate::{
 pub(crate) struct BoundedEncoder {
     depth_tracker: CounterWithLimit,
     token_tracker: CounterWithLimit,
+    initial_capacity: usize,
 }
 
 impl BoundedEncoder {
@@ -24,110 +25,118 @@ impl BoundedEncoder {
         Self {
             depth_tracker,
             token_tracker,
+            initial_capacity: config.initial_capacity,
         }
     }
 
     pub fn encode(&mut self, args: &[Token]) -> Result<UnresolvedBytes> {
-        let data = vec![Data::Inline(self.encode_tokens(args)?)];
+        let mut buffer = Vec::with_capacity(self.initial_capacity);
+        self.encode_into(args, &mut buffer)?;
 
-        Ok(UnresolvedBytes::new(data))
+        Ok(UnresolvedBytes::new(vec![Data::Inline(buffer)]))
     }
 
-    fn encode_tokens(&mut self, tokens: &[Token]) -> Result<Vec<u8>> {
-        let mut data = vec![];
+    pub fn encode_into(&mut self, args: &[Token], buffer: &mut Vec<u8>) -> Result<()> {
+        self.encode_tokens(args, buffer)
+    }
 
+    fn encode_tokens(&mut self, tokens: &[Token], buffer: &mut Vec<u8>) -> Result<()> {
         for token in tokens.iter() {
-            let new_data = self.encode_token(token)?;
-            data.extend(new_data);
+            self.encode_token(token, buffer)?;
         }
 
-        Ok(data)
+        Ok(())
     }
 
     fn run_w_depth_tracking(
         &mut self,
-        encoder: impl FnOnce(&mut Self) -> Result<Vec<u8>>,
-    ) -> Result<Vec<u8>> {
+        buffer: &mut Vec<u8>,
+        encoder: impl FnOnce(&mut Self, &mut Vec<u8>) -> Result<()>,
+    ) -> Result<()> {
         self.depth_tracker.increase()?;
-        let res = encoder(self);
+        let res = encoder(self, buffer);
         self.depth_tracker.decrease();
 
         res
     }
 
-    fn encode_token(&mut self, arg: &Token) -> Result<Vec<u8>> {
+    fn encode_token(&mut self, arg: &Token, buffer: &mut Vec<u8>) -> Result<()> {
         self.token_tracker.increase()?;
-        let encoded_token = match arg {
-            Token::Unit => vec![],
-            Token::Bool(arg_bool) => vec![u8::from(*arg_bool)],
-            Token::U8(arg_u8) => vec![*arg_u8],
-            Token::U16(arg_u16) => arg_u16.to_be_bytes().to_vec(),
-            Token::U32(arg_u32) => arg_u32.to_be_bytes().to_vec(),
-            Token::U64(arg_u64) => arg_u64.to_be_bytes().to_vec(),
-            Token::U128(arg_u128) => arg_u128.to_be_bytes().to_vec(),
-            Token::U256(arg_u256) => Self::encode_u256(*arg_u256),
-            Token::B256(arg_bits256) => arg_bits256.to_vec(),
-            Token::Bytes(data) => Self::encode_bytes(data.to_vec())?,
-            Token::String(string) => Self::encode_bytes(string.clone().into_bytes())?,
-            Token::RawSlice(data) => Self::encode_bytes(data.clone())?,
-            Token::StringArray(arg_string) => Self::encode_string_array(arg_string)?,
-            Token::StringSlice(arg_string) => Self::encode_string_slice(arg_string)?,
-            Token::Tuple(arg_tuple) => {
-                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_tuple))?
-            }
-            Token::Array(arg_array) => {
-                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_array))?
+        match arg {
+            Token::Unit => {}
+            Token::Bool(arg_bool) => buffer.push(u8::from(*arg_bool)),
+            Token::U8(arg_u8) => buffer.push(*arg_u8),
+            Token::U16(arg_u16) => buffer.extend_from_slice(&arg_u16.to_be_bytes()),
+            Token::U32(arg_u32) => buffer.extend_from_slice(&arg_u32.to_be_bytes()),
+            Token::U64(arg_u64) => buffer.extend_from_slice(&arg_u64.to_be_bytes()),
+            Token::U128(arg_u128) => buffer.extend_from_slice(&arg_u128.to_be_bytes()),
+            Token::U256(arg_u256) => Self::encode_u256(*arg_u256, buffer),
+            Token::B256(arg_bits256) => buffer.extend_from_slice(arg_bits256),
+            Token::Bytes(data) => Self::encode_bytes(data, buffer),
+            Token::String(string) => Self::encode_bytes(string.as_bytes(), buffer),
+            Token::RawSlice(data) => Self::encode_bytes(data, buffer),
+            Token::StringArray(arg_string) => Self::encode_string_array(arg_string, buffer)?,
+            Token::StringSlice(arg_string) => Self::encode_string_slice(arg_string, buffer)?,
+            Token::Tuple(arg_tuple) => self
+                .run_w_depth_tracking(buffer, |ctx, buffer| ctx.encode_tokens(arg_tuple, buffer))?,
+            Token::Array(arg_array) => self
+                .run_w_depth_tracking(buffer, |ctx, buffer| ctx.encode_tokens(arg_array, buffer))?,
+            Token::Vector(data) => {
+                self.run_w_depth_tracking(buffer, |ctx, buffer| ctx.encode_vector(data, buffer))?
             }
-            Token::Vector(data) => self.run_w_depth_tracking(|ctx| ctx.encode_vector(data))?,
-            Token::Struct(arg_struct) => {
-                self.run_w_depth_tracking(|ctx| ctx.encode_tokens(arg_struct))?
+            Token::Struct(arg_struct) => self.run_w_depth_tracking(buffer, |ctx, buffer| {
+                ctx.encode_tokens(arg_struct, buffer)
+            })?,
+            Token::Enum(arg_enum) => {
+                self.run_w_depth_tracking(buffer, |ctx, buffer| ctx.encode_enum(arg_enum, buffer))?
             }
-            Token::Enum(arg_enum) => self.run_w_depth_tracking(|ctx| ctx.encode_enum(arg_enum))?,
         };
 
-        Ok(encoded_token)
+        Ok(())
     }
 
-    fn encode_u256(arg_u256: U256) -> Vec<u8> {
+    fn encode_u256(arg_u256: U256, buffer: &mut Vec<u8>) {
         let mut bytes = [0u8; 32];
         arg_u256.to_big_endian(&mut bytes);
 
-        bytes.to_vec()
+        buffer.extend_from_slice(&bytes);
     }
 
-    fn encode_bytes(data: Vec<u8>) -> Result<Vec<u8>> {
-        let len = data.len();
-
-        Ok([Self::encode_length(len as u64), data].concat())
+    fn encode_bytes(data: &[u8], buffer: &mut Vec<u8>) {
+        Self::encode_length(data.len() as u64, buffer);
+        buffer.extend_from_slice(data);
     }
 
-    fn encode_string_array(arg_string: &StaticStringToken) -> Result<Vec<u8>> {
-        Ok(arg_string.get_encodable_str()?.as_bytes().to_vec())
+    fn encode_string_array(arg_string: &StaticStringToken, buffer: &mut Vec<u8>) -> Result<()> {
+        buffer.extend_from_slice(arg_string.get_encodable_str()?.as_bytes());
+
+        Ok(())
     }
 
-    fn encode_string_slice(arg_string: &StaticStringToken) -> Result<Vec<u8>> {
-        Self::encode_bytes(arg_string.get_encodable_str()?.as_bytes().to_vec())
+    fn encode_string_slice(arg_string: &StaticStringToken, buffer: &mut Vec<u8>) -> Result<()> {
+        Self::encode_bytes(arg_string.get_encodable_str()?.as_bytes(), buffer);
+
+        Ok(())
     }
 
-    fn encode_vector(&mut self, data: &[Token]) -> Result<Vec<u8>> {
-        let encoded_data = self.encode_tokens(data)?;
+    fn encode_vector(&mut self, data: &[Token], buffer: &mut Vec<u8>) -> Result<()> {
+        Self::encode_length(data.len() as u64, buffer);
 
-        Ok([Self::encode_length(data.len() as u64), encoded_data].concat())
+        self.encode_tokens(data, buffer)
     }
 
-    fn encode_enum(&mut self, selector: &EnumSelector) -> Result<Vec<u8>> {
+    fn encode_enum(&mut self, selector: &EnumSelector, buffer: &mut Vec<u8>) -> Result<()> {
         let (discriminant, token_within_enum, _) = selector;
-        let encoded_discriminant = Self::encode_discriminant(*discriminant);
-        let encoded_token = self.encode_token(token_within_enum)?;
+        Self::encode_discriminant(*discriminant, buffer);
 
-        Ok([encoded_discriminant, encoded_token].concat())
+        self.encode_token(token_within_enum, buffer)
     }
 
-    fn encode_length(len: u64) -> Vec<u8> {
-        len.to_be_bytes().to_vec()
+    fn encode_length(len: u64, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&len.to_be_bytes());
     }
 
-    fn encode_discriminant(discriminant: u64) -> Vec<u8> {
-        discriminant.to_be_bytes().to_vec()
+    fn encode_discriminant(discriminant: u64, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&discriminant.to_be_bytes());
     }
 }
@@ -3,6 +3,7 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
     iter::FilterMap,
+    sync::Arc,
 };
 
 use fuel_tx::{ContractId, Receipt};
@@ -10,12 +11,17 @@ use fuel_tx::{ContractId, Receipt};
 use crate::{
     codec::{ABIDecoder, DecoderConfig},
     traits::{Parameterize, Tokenizable},
-    types::errors::{error, Error, Result},
+    types::{
+        errors::{error, Error, Result},
+        param_types::ParamType,
+        DynamicValue,
+    },
 };
 
 #[derive(Clone)]
 pub struct LogFormatter {
     formatter: fn(DecoderConfig, &[u8]) -> Result<String>,
+    param_type: fn() -> ParamType,
     type_id: TypeId,
 }
 
@@ -23,6 +29,7 @@ impl LogFormatter {
     pub fn new<T: Tokenizable + Parameterize + Debug + 'static>() -> Self {
         Self {
             formatter: Self::format_log::<T>,
+            param_type: T::param_type,
             type_id: TypeId::of::<T>(),
         }
     }
@@ -43,6 +50,10 @@ impl LogFormatter {
     pub fn format(&self, decoder_config: DecoderConfig, bytes: &[u8]) -> Result<String> {
         (self.formatter)(decoder_config, bytes)
     }
+
+    pub fn param_type(&self) -> ParamType {
+        (self.param_type)()
+    }
 }
 
 impl Debug for LogFormatter {
@@ -60,8 +71,9 @@ pub struct LogId(ContractId, u64);
 /// Struct used to pass the log mappings from the Abigen
 #[derive(Debug, Clone, Default)]
 pub struct LogDecoder {
-    /// A mapping of LogId and param-type
-    log_formatters: HashMap<LogId, LogFormatter>,
+    /// A mapping of LogId and param-type. Shared between clones since the decoder is cloned
+    /// into every call handler
+    log_formatters: Arc<HashMap<LogId, LogFormatter>>,
     decoder_config: DecoderConfig,
 }
 
@@ -89,7 +101,7 @@ impl LogResult {
 impl LogDecoder {
     pub fn new(log_formatters: HashMap<LogId, LogFormatter>) -> Self {
         Self {
-            log_formatters,
+            log_formatters: Arc::new(log_formatters),
             decoder_config: Default::default(),
         }
     }
@@ -110,21 +122,44 @@ impl LogDecoder {
         LogResult { results }
     }
 
-    fn format_log(&self, log_id: &LogId, data: &[u8]) -> Result<String> {
-        self.log_formatters
-            .get(log_id)
-            .ok_or_else(|| {
-                error!(
-                    Codec,
-                    "missing log formatter for log_id: `{:?}`, data: `{:?}`. \
-                     Consider adding external contracts using `with_contracts()`",
-                    log_id,
-                    data
-                )
+    /// Get all logs from the given receipts as values named after their type, e.g. `u64` or
+    /// `Vec<MyStruct>`.
+    pub fn decode_logs_as_values(
+        &self,
+        receipts: &[Receipt],
+    ) -> Vec<Result<(String, DynamicValue)>> {
+        receipts
+            .iter()
+            .extract_log_id_and_data()
+            .map(|(log_id, data)| {
+                let param_type = self.log_formatter(&log_id, &data)?.param_type();
+                let token = ABIDecoder::new(self.decoder_config).decode(&param_type, &data)?;
+
+                Ok((
+                    param_type.type_name(),
+                    DynamicValue::from_token(token, &param_type)?,
+                ))
             })
+            .collect()
+    }
+
+    fn format_log(&self, log_id: &LogId, data: &[u8]) -> Result<String> {
+        self.log_formatter(log_id, data)
             .and_then(|log_formatter| log_formatter.format(self.decoder_config, data))
     }
 
+    fn log_formatter(&self, log_id: &LogId, data: &[u8]) -> Result<&LogFormatter> {
+        self.log_formatters.get(log_id).ok_or_else(|| {
+            error!(
+                Codec,
+                "missing log formatter for log_id: `{:?}`, data: `{:?}`. \
+                 Consider adding external contracts using `with_contracts()`",
+                log_id,
+                data
+            )
+        })
+    }
+
     pub(crate) fn decode_last_log(&self, receipts: &[Receipt]) -> Result<String> {
         receipts
             .iter()
@@ -183,7 +218,8 @@ impl LogDecoder {
     }
 
     pub fn merge(&mut self, log_decoder: LogDecoder) {
-        self.log_formatters.extend(log_decoder.log_formatters);
+        let log_formatters = Arc::unwrap_or_clone(log_decoder.log_formatters);
+        Arc::make_mut(&mut self.log_formatters).extend(log_formatters);
     }
 }
 
@@ -0,0 +1,423 @@
+//! Conversions between the compact signatures used by the FuelVM and the formats used by other
+//! tooling, e.g. the 65-byte `r || s || v` signatures of Ethereum or the DER signatures of HSMs.
+//!
+//! A compact signature is `r || s` where `s` is in the lower half of the curve order and the
+//! recovery id is stored in its most significant bit. Signatures with a high `s` are normalized
+//! when converted to the compact format, flipping the recovery id, so that they still recover to
+//! the same public key.
+
+use fuel_crypto::{Message, PublicKey, Signature};
+
+use crate::{error, types::errors::Result};
+
+/// The order of the secp256k1 curve.
+const CURVE_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// Half of [`CURVE_ORDER`], rounded down. Normalized signatures have `s <= HALF_CURVE_ORDER`.
+const HALF_CURVE_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+
+const DER_SEQUENCE: u8 = 0x30;
+const DER_INTEGER: u8 = 0x02;
+
+/// How the recovery id is encoded in the `v` byte of an `r || s || v` signature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecoveryIdFormat {
+    /// `v` is the recovery id, i.e. `0` or `1`.
+    Raw,
+    /// `v` is the recovery id plus 27, i.e. `27` or `28`, as returned by `personal_sign`.
+    #[default]
+    Legacy,
+}
+
+impl RecoveryIdFormat {
+    fn offset(self) -> u8 {
+        match self {
+            Self::Raw => 0,
+            Self::Legacy => 27,
+        }
+    }
+}
+
+/// The components of a signature: `r`, the normalized `s` and the recovery id, `0` or `1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureParts {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+    pub recovery_id: u8,
+}
+
+impl SignatureParts {
+    /// Splits a compact signature into its components.
+    pub fn from_compact(signature: &Signature) -> Self {
+        let (r, s) = split(signature.as_ref());
+        let recovery_id = s[0] >> 7;
+
+        let mut s = s;
+        s[0] &= 0x7f;
+
+        Self { r, s, recovery_id }
+    }
+
+    /// Builds the compact signature. A high `s` is normalized and the recovery id flipped
+    /// accordingly.
+    pub fn to_compact(&self) -> Result<Signature> {
+        if self.recovery_id > 1 {
+            return Err(error!(
+                Other,
+                "invalid recovery id `{}`: expected `0` or `1`", self.recovery_id
+            ));
+        }
+        validate_scalar("r", &self.r)?;
+        validate_scalar("s", &self.s)?;
+
+        let (s, recovery_id) = if self.s > HALF_CURVE_ORDER {
+            (negate(&self.s), self.recovery_id ^ 1)
+        } else {
+            (self.s, self.recovery_id)
+        };
+
+        let mut compact = [0u8; 64];
+        compact[..32].copy_from_slice(&self.r);
+        compact[32..].copy_from_slice(&s);
+        compact[32] |= recovery_id << 7;
+
+        Ok(Signature::from_bytes(compact))
+    }
+}
+
+/// Converts a compact signature into a 65-byte `r || s || v` signature, as used by Ethereum.
+pub fn compact_to_rsv(signature: &Signature, format: RecoveryIdFormat) -> [u8; 65] {
+    let parts = SignatureParts::from_compact(signature);
+
+    let mut rsv = [0u8; 65];
+    rsv[..32].copy_from_slice(&parts.r);
+    rsv[32..64].copy_from_slice(&parts.s);
+    rsv[64] = parts.recovery_id + format.offset();
+
+    rsv
+}
+
+/// Converts a 65-byte `r || s || v` signature into the compact format. `v` can be either `0`/`1`
+/// or `27`/`28`.
+pub fn compact_from_rsv(signature: &[u8; 65]) -> Result<Signature> {
+    let v = signature[64];
+    let recovery_id = match v {
+        0 | 27 => 0,
+        1 | 28 => 1,
+        _ => {
+            return Err(error!(
+                Other,
+                "invalid `v` `{v}`: expected `0`, `1`, `27` or `28`"
+            ))
+        }
+    };
+    let (r, s) = split(&signature[..64]);
+
+    SignatureParts { r, s, recovery_id }.to_compact()
+}
+
+/// Encodes the `r` and `s` of a compact signature as a DER `SEQUENCE` of two `INTEGER`s. DER
+/// signatures don't carry a recovery id, see [`compact_from_der`].
+pub fn compact_to_der(signature: &Signature) -> Vec<u8> {
+    let parts = SignatureParts::from_compact(signature);
+
+    let mut integers = encode_der_integer(&parts.r);
+    integers.extend(encode_der_integer(&parts.s));
+
+    let mut der = vec![DER_SEQUENCE, integers.len() as u8];
+    der.extend(integers);
+
+    der
+}
+
+/// Converts a DER signature into the compact format, with the given recovery id. Use
+/// [`recover_compact_from_der`] if the recovery id is unknown.
+pub fn compact_from_der(der: &[u8], recovery_id: u8) -> Result<Signature> {
+    let (r, s) = decode_der(der)?;
+
+    SignatureParts { r, s, recovery_id }.to_compact()
+}
+
+/// Converts a DER signature of `message` into the compact format, finding the recovery id for
+/// which the signature recovers to `public_key`.
+pub fn recover_compact_from_der(
+    der: &[u8],
+    message: &Message,
+    public_key: &PublicKey,
+) -> Result<Signature> {
+    let (r, s) = decode_der(der)?;
+
+    recover_compact(r, s, message, public_key)
+}
+
+/// Builds the compact signature of `message` from its `r` and `s`, finding the recovery id for
+/// which the signature recovers to `public_key`.
+pub fn recover_compact(
+    r: [u8; 32],
+    s: [u8; 32],
+    message: &Message,
+    public_key: &PublicKey,
+) -> Result<Signature> {
+    for recovery_id in [0, 1] {
+        let signature = SignatureParts { r, s, recovery_id }.to_compact()?;
+
+        if signature
+            .recover(message)
+            .is_ok_and(|recovered| recovered == *public_key)
+        {
+            return Ok(signature);
+        }
+    }
+
+    Err(error!(
+        Other,
+        "the signature doesn't recover to `{public_key}` for any recovery id"
+    ))
+}
+
+/// Like [`recover_compact`], for a signature given as `r || s`, the format returned by PKCS#11
+/// tokens and most HSMs.
+pub fn recover_compact_from_rs(
+    signature: &[u8; 64],
+    message: &Message,
+    public_key: &PublicKey,
+) -> Result<Signature> {
+    let (r, s) = split(signature);
+
+    recover_compact(r, s, message, public_key)
+}
+
+fn split(bytes: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut r = [0u8; 32];
+    let mut s = [0u8; 32];
+    r.copy_from_slice(&bytes[..32]);
+    s.copy_from_slice(&bytes[32..64]);
+
+    (r, s)
+}
+
+fn validate_scalar(name: &str, scalar: &[u8; 32]) -> Result<()> {
+    if scalar.iter().all(|byte| *byte == 0) || *scalar >= CURVE_ORDER {
+        return Err(error!(
+            Other,
+            "invalid `{name}`: must be between 1 and the curve order"
+        ));
+    }
+
+    Ok(())
+}
+
+/// Computes `CURVE_ORDER - scalar`, for `0 < scalar < CURVE_ORDER`.
+fn negate(scalar: &[u8; 32]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut borrow = 0;
+
+    for (byte, (order, scalar)) in result.iter_mut().zip(CURVE_ORDER.iter().zip(scalar)).rev() {
+        let difference = i16::from(*order) - i16::from(*scalar) - borrow;
+        borrow = i16::from(difference < 0);
+        *byte = (difference + 256 * borrow) as u8;
+    }
+
+    result
+}
+
+/// Encodes `scalar` as a minimal, positive DER `INTEGER`.
+fn encode_der_integer(scalar: &[u8; 32]) -> Vec<u8> {
+    let leading_zeros = scalar.iter().take_while(|byte| **byte == 0).count().min(31);
+    let bytes = &scalar[leading_zeros..];
+    let needs_padding = bytes[0] & 0x80 != 0;
+
+    let mut integer = vec![
+        DER_INTEGER,
+        (bytes.len() + usize::from(needs_padding)) as u8,
+    ];
+    if needs_padding {
+        integer.push(0);
+    }
+    integer.extend_from_slice(bytes);
+
+    integer
+}
+
+fn decode_der(der: &[u8]) -> Result<([u8; 32], [u8; 32])> {
+    let invalid = |reason: &str| error!(Other, "invalid DER signature: {reason}");
+
+    let contents = match der {
+        [DER_SEQUENCE, len, contents @ ..] if usize::from(*len) == contents.len() => contents,
+        [DER_SEQUENCE, ..] => return Err(invalid("wrong length")),
+        _ => return Err(invalid("expected a `SEQUENCE`")),
+    };
+
+    let (r, rest) = decode_der_integer(contents).map_err(invalid)?;
+    let (s, rest) = decode_der_integer(rest).map_err(invalid)?;
+    if !rest.is_empty() {
+        return Err(invalid("unexpected trailing bytes"));
+    }
+
+    Ok((r, s))
+}
+
+fn decode_der_integer(der: &[u8]) -> std::result::Result<([u8; 32], &[u8]), &'static str> {
+    let (bytes, rest) = match der {
+        [DER_INTEGER, len, rest @ ..] if usize::from(*len) <= rest.len() => {
+            rest.split_at(usize::from(*len))
+        }
+        [DER_INTEGER, ..] => return Err("wrong length"),
+        _ => return Err("expected an `INTEGER`"),
+    };
+
+    let bytes = match bytes {
+        [] => return Err("empty `INTEGER`"),
+        [first, ..] if first & 0x80 != 0 => return Err("negative `INTEGER`"),
+        [0, second, ..] if second & 0x80 == 0 => return Err("non-minimal `INTEGER`"),
+        [0, magnitude @ ..] if !magnitude.is_empty() => magnitude,
+        bytes => bytes,
+    };
+    if bytes.len() > 32 {
+        return Err("`INTEGER` larger than 32 bytes");
+    }
+
+    let mut scalar = [0u8; 32];
+    scalar[32 - bytes.len()..].copy_from_slice(bytes);
+
+    Ok((scalar, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+
+    fn signed_message() -> Result<(Signature, Message, PublicKey)> {
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let message = Message::new("fuel");
+
+        Ok((
+            Signature::sign(&secret_key, &message),
+            message,
+            secret_key.public_key(),
+        ))
+    }
+
+    #[test]
+    fn signatures_round_trip_through_all_formats() -> Result<()> {
+        let (signature, message, public_key) = signed_message()?;
+
+        // ANCHOR: signature_formats
+        let rsv = compact_to_rsv(&signature, RecoveryIdFormat::Legacy);
+        assert_eq!(compact_from_rsv(&rsv)?, signature);
+
+        let der = compact_to_der(&signature);
+        let from_der = recover_compact_from_der(&der, &message, &public_key)?;
+        assert_eq!(from_der, signature);
+        // ANCHOR_END: signature_formats
+
+        let parts = SignatureParts::from_compact(&signature);
+        assert_eq!(rsv[64], parts.recovery_id + 27);
+        assert_eq!(
+            compact_to_rsv(&signature, RecoveryIdFormat::Raw)[64],
+            parts.recovery_id
+        );
+        assert_eq!(parts.to_compact()?, signature);
+        assert_eq!(compact_from_der(&der, parts.recovery_id)?, signature);
+
+        Ok(())
+    }
+
+    #[test]
+    fn high_s_is_normalized() -> Result<()> {
+        let (signature, message, public_key) = signed_message()?;
+        let parts = SignatureParts::from_compact(&signature);
+
+        let malleated = SignatureParts {
+            s: negate(&parts.s),
+            recovery_id: parts.recovery_id ^ 1,
+            ..parts
+        };
+        assert!(malleated.s > HALF_CURVE_ORDER);
+
+        let normalized = malleated.to_compact()?;
+        assert_eq!(normalized, signature);
+        assert_eq!(normalized.recover(&message)?, public_key);
+
+        let mut der = encode_der_integer(&malleated.r);
+        der.extend(encode_der_integer(&malleated.s));
+        let len = der.len() as u8;
+        der.splice(0..0, [DER_SEQUENCE, len]);
+        assert_eq!(
+            recover_compact_from_der(&der, &message, &public_key)?,
+            signature
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn der_integers_are_minimal_and_positive() {
+        let mut scalar = [0u8; 32];
+        scalar[31] = 0x7f;
+        assert_eq!(encode_der_integer(&scalar), [DER_INTEGER, 1, 0x7f]);
+
+        scalar[31] = 0x80;
+        assert_eq!(encode_der_integer(&scalar), [DER_INTEGER, 2, 0, 0x80]);
+
+        let scalar = [0xaa; 32];
+        let encoded = encode_der_integer(&scalar);
+        assert_eq!(encoded[..3], [DER_INTEGER, 33, 0]);
+        assert_eq!(decode_der_integer(&encoded), Ok((scalar, &[][..])));
+
+        assert_eq!(
+            decode_der_integer(&[DER_INTEGER, 2, 0, 0x7f]),
+            Err("non-minimal `INTEGER`")
+        );
+        assert_eq!(
+            decode_der_integer(&[DER_INTEGER, 1, 0x80]),
+            Err("negative `INTEGER`")
+        );
+    }
+
+    #[test]
+    fn invalid_signatures_are_rejected() -> Result<()> {
+        let (signature, message, _) = signed_message()?;
+
+        let mut rsv = compact_to_rsv(&signature, RecoveryIdFormat::Raw);
+        rsv[64] = 2;
+        let err = compact_from_rsv(&rsv).expect_err("should fail");
+        assert!(err.to_string().contains("invalid `v` `2`"));
+
+        let zero_s = SignatureParts {
+            s: [0; 32],
+            ..SignatureParts::from_compact(&signature)
+        };
+        let err = zero_s.to_compact().expect_err("should fail");
+        assert!(err.to_string().contains("invalid `s`"));
+
+        let mut der = compact_to_der(&signature);
+        der.push(0);
+        let err = compact_from_der(&der, 0).expect_err("should fail");
+        assert!(err
+            .to_string()
+            .contains("invalid DER signature: wrong length"));
+
+        let other_key = SecretKey::from_str(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        )?
+        .public_key();
+        let err = recover_compact_from_der(&compact_to_der(&signature), &message, &other_key)
+            .expect_err("should fail");
+        assert!(err.to_string().contains("doesn't recover"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,383 @@
+mod source_map;
+
+use std::collections::HashSet;
+
+use fuel_asm::RegId;
+use fuel_tx::{ConsensusParameters, Contract, ContractId, Receipt, Script, StorageSlot};
+use fuel_vm::{
+    checked_transaction::{IntoChecked, Ready},
+    interpreter::{Interpreter, InterpreterParams},
+    state::{DebugEval, ProgramState},
+    storage::{InterpreterStorage, MemoryStorage},
+};
+
+pub use source_map::{SourceLocation, SourceMap, SourceMapRegistry};
+
+use crate::types::{
+    errors::{error, Result},
+    transaction::ScriptTransaction,
+};
+
+/// Where the VM is about to execute an instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The contract being executed, zeroed while the script is executed.
+    pub contract: ContractId,
+    /// The offset of the instruction in the bytecode of the contract or script.
+    pub pc: u64,
+}
+
+impl Location {
+    pub fn script(pc: u64) -> Self {
+        Self {
+            contract: ContractId::zeroed(),
+            pc,
+        }
+    }
+
+    pub fn contract(contract: ContractId, pc: u64) -> Self {
+        Self { contract, pc }
+    }
+}
+
+/// Why the [`Debugger`] stopped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stop {
+    /// One instruction was executed, see [`Debugger::step`].
+    Step(Location),
+    /// A breakpoint set with [`Debugger::set_breakpoint`] was reached.
+    Breakpoint(Location),
+    /// A contract was called, see [`Debugger::break_on_calls_to`]. The VM stops at the first
+    /// instruction of the contract.
+    ContractCall(ContractId),
+    /// The last instruction changed memory watched with [`Debugger::watch`].
+    Watchpoint {
+        address: usize,
+        old: Vec<u8>,
+        new: Vec<u8>,
+    },
+    /// The script returned, reverted or panicked. The receipts are available through
+    /// [`Debugger::receipts`].
+    Finished(ProgramState),
+}
+
+#[derive(Debug)]
+struct Watchpoint {
+    address: usize,
+    value: Vec<u8>,
+}
+
+/// Executes a script transaction instruction by instruction in a local VM, without a node.
+///
+/// Contracts called by the script must be added with [`Debugger::with_contract`]. Signatures
+/// and predicates aren't verified and coin inputs aren't looked up, so the transaction doesn't
+/// need to be funded. The gas price is zero.
+///
+/// Nothing runs until the first call to [`Debugger::step`] or [`Debugger::resume`].
+#[derive(Debug)]
+pub struct Debugger {
+    vm: Interpreter<MemoryStorage, Script>,
+    tx: Option<Ready<Script>>,
+    location: Option<Location>,
+    finished: Option<ProgramState>,
+    breakpoints: HashSet<Location>,
+    call_breakpoints: HashSet<ContractId>,
+    break_on_every_call: bool,
+    watchpoints: Vec<Watchpoint>,
+}
+
+impl Debugger {
+    pub fn new(tx: ScriptTransaction, consensus_parameters: &ConsensusParameters) -> Result<Self> {
+        let tx = Script::from(tx)
+            .into_checked_basic(0.into(), consensus_parameters)?
+            .into_ready(
+                0,
+                consensus_parameters.gas_costs(),
+                consensus_parameters.fee_params(),
+            )?;
+
+        let mut vm = Interpreter::with_storage(
+            MemoryStorage::default(),
+            InterpreterParams::new(0, consensus_parameters),
+        );
+        vm.set_single_stepping(true);
+
+        Ok(Self {
+            vm,
+            tx: Some(tx),
+            location: None,
+            finished: None,
+            breakpoints: Default::default(),
+            call_breakpoints: Default::default(),
+            break_on_every_call: false,
+            watchpoints: vec![],
+        })
+    }
+
+    /// Deploys a contract called by the script. Its storage starts with `storage_slots`.
+    pub fn with_contract(
+        mut self,
+        contract_id: ContractId,
+        bytecode: impl Into<Vec<u8>>,
+        storage_slots: &[StorageSlot],
+    ) -> Result<Self> {
+        if self.tx.is_none() {
+            return Err(error!(
+                Other,
+                "contracts must be added before the script is executed"
+            ));
+        }
+
+        let contract = Contract::from(bytecode.into());
+        self.vm
+            .as_mut()
+            .deploy_contract_with_id(storage_slots, &contract, &contract_id)
+            .map_err(|err| error!(Other, "could not deploy contract {contract_id}: {err:?}"))?;
+
+        Ok(self)
+    }
+
+    pub fn set_breakpoint(&mut self, location: Location) {
+        self.breakpoints.insert(location);
+    }
+
+    pub fn remove_breakpoint(&mut self, location: &Location) {
+        self.breakpoints.remove(location);
+    }
+
+    /// Stops [`Debugger::resume`] whenever `contract` is called.
+    pub fn break_on_calls_to(&mut self, contract: ContractId) {
+        self.call_breakpoints.insert(contract);
+    }
+
+    /// Stops [`Debugger::resume`] whenever any contract is called.
+    pub fn break_on_every_call(&mut self) {
+        self.break_on_every_call = true;
+    }
+
+    /// Stops [`Debugger::resume`] whenever an instruction changes the `len` bytes of memory
+    /// starting at `address`.
+    pub fn watch(&mut self, address: usize, len: usize) -> Result<()> {
+        let value = self.memory(address, len)?.to_vec();
+        self.watchpoints
+            .retain(|watchpoint| watchpoint.address != address);
+        self.watchpoints.push(Watchpoint { address, value });
+
+        Ok(())
+    }
+
+    pub fn unwatch(&mut self, address: usize) {
+        self.watchpoints
+            .retain(|watchpoint| watchpoint.address != address);
+    }
+
+    /// Executes the next instruction. The first call starts the script and stops before its
+    /// first instruction.
+    pub fn step(&mut self) -> Result<Stop> {
+        self.advance()?;
+
+        Ok(match self.finished {
+            Some(state) => Stop::Finished(state),
+            None => Stop::Step(
+                self.location
+                    .expect("should stop at a breakpoint while single stepping"),
+            ),
+        })
+    }
+
+    /// Executes instructions until a breakpoint, a contract call or a watchpoint is hit, or the
+    /// script finishes.
+    pub fn resume(&mut self) -> Result<Stop> {
+        loop {
+            let frame_pointer = self.register(RegId::FP);
+            self.advance()?;
+
+            if let Some(state) = self.finished {
+                return Ok(Stop::Finished(state));
+            }
+            if let Some(stop) = self.check_stop(frame_pointer) {
+                return Ok(stop);
+            }
+        }
+    }
+
+    /// Returns `None` until the script is started.
+    pub fn location(&self) -> Option<Location> {
+        self.location
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.is_some()
+    }
+
+    pub fn registers(&self) -> &[u64] {
+        self.vm.registers()
+    }
+
+    pub fn register(&self, register: RegId) -> u64 {
+        self.registers()[register.to_u8() as usize]
+    }
+
+    pub fn memory(&self, address: usize, len: usize) -> Result<&[u8]> {
+        read(self.vm.memory(), address, len).ok_or_else(|| {
+            error!(
+                Other,
+                "memory range {address}..{address}+{len} is out of bounds"
+            )
+        })
+    }
+
+    /// The receipts produced so far.
+    pub fn receipts(&self) -> &[Receipt] {
+        self.vm.receipts()
+    }
+
+    fn advance(&mut self) -> Result<()> {
+        if let Some(state) = self.finished {
+            return Err(error!(Other, "the script already finished: {state:?}"));
+        }
+
+        let state = match self.tx.take() {
+            Some(tx) => {
+                let state = *self
+                    .vm
+                    .transact(tx)
+                    .map_err(|err| error!(Other, "vm: {err}"))?
+                    .state();
+                // Memory now holds the transaction, which isn't a change made by the script.
+                self.refresh_watchpoints();
+
+                state
+            }
+            None => self.vm.resume().map_err(|err| error!(Other, "vm: {err}"))?,
+        };
+
+        match state {
+            ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint)) => {
+                self.location = Some(Location::contract(*breakpoint.contract(), breakpoint.pc()));
+            }
+            state if !state.is_debug() => self.finished = Some(state),
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn check_stop(&mut self, frame_pointer: u64) -> Option<Stop> {
+        let location = self.location?;
+
+        let entered_call = self.register(RegId::FP) > frame_pointer;
+        if entered_call
+            && (self.break_on_every_call || self.call_breakpoints.contains(&location.contract))
+        {
+            return Some(Stop::ContractCall(location.contract));
+        }
+
+        if self.breakpoints.contains(&location) {
+            return Some(Stop::Breakpoint(location));
+        }
+
+        let memory = self.vm.memory();
+        self.watchpoints.iter_mut().find_map(|watchpoint| {
+            let new = read(memory, watchpoint.address, watchpoint.value.len())?;
+            (new != watchpoint.value.as_slice()).then(|| Stop::Watchpoint {
+                address: watchpoint.address,
+                old: std::mem::replace(&mut watchpoint.value, new.to_vec()),
+                new: new.to_vec(),
+            })
+        })
+    }
+
+    fn refresh_watchpoints(&mut self) {
+        let memory = self.vm.memory();
+        for watchpoint in &mut self.watchpoints {
+            if let Some(value) = read(memory, watchpoint.address, watchpoint.value.len()) {
+                watchpoint.value = value.to_vec();
+            }
+        }
+    }
+}
+
+fn read(memory: &[u8], address: usize, len: usize) -> Option<&[u8]> {
+    memory.get(address..address.checked_add(len)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_asm::op;
+    use fuel_tx::{policies::Policies, Input, Transaction as FuelTransaction, Witness};
+
+    use super::*;
+
+    fn script_tx(script: Vec<u8>) -> ScriptTransaction {
+        let input = Input::coin_signed(
+            Default::default(),
+            Default::default(),
+            100,
+            Default::default(),
+            Default::default(),
+            0,
+        );
+
+        FuelTransaction::script(
+            1_000_000,
+            script,
+            vec![],
+            Policies::new().with_max_fee(0),
+            vec![input],
+            vec![],
+            vec![Witness::default()],
+        )
+        .into()
+    }
+
+    #[test]
+    fn scripts_can_be_stepped_through() -> Result<()> {
+        // ANCHOR: debugger
+        let script = [
+            op::movi(0x10, 42),
+            op::cfei(8),
+            op::sw(RegId::SSP, 0x10, 0),
+            op::ret(RegId::ONE),
+        ]
+        .into_iter()
+        .collect();
+        let mut debugger = Debugger::new(script_tx(script), &ConsensusParameters::standard())?;
+
+        assert_eq!(debugger.step()?, Stop::Step(Location::script(0)));
+        assert_eq!(debugger.step()?, Stop::Step(Location::script(4)));
+
+        let stack = debugger.register(RegId::SSP) as usize;
+        debugger.watch(stack, 8)?;
+        assert_eq!(
+            debugger.resume()?,
+            Stop::Watchpoint {
+                address: stack,
+                old: vec![0; 8],
+                new: 42u64.to_be_bytes().to_vec(),
+            }
+        );
+        assert_eq!(debugger.location(), Some(Location::script(12)));
+
+        assert_eq!(debugger.resume()?, Stop::Finished(ProgramState::Return(1)));
+        // ANCHOR_END: debugger
+        assert!(debugger.step().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn breakpoints_stop_the_script() -> Result<()> {
+        let script = [op::noop(), op::noop(), op::noop(), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect();
+        let mut debugger = Debugger::new(script_tx(script), &ConsensusParameters::standard())?;
+        debugger.set_breakpoint(Location::script(8));
+
+        assert_eq!(debugger.resume()?, Stop::Breakpoint(Location::script(8)));
+        assert_eq!(debugger.resume()?, Stop::Finished(ProgramState::Return(1)));
+        assert!(debugger.is_finished());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use fuel_tx::{ContractId, Receipt};
+use serde::Deserialize;
+
+use crate::types::errors::{error, Result};
+
+/// A source map emitted by `forc build --source-maps`, mapping the instructions of a program to
+/// the Sway code they were compiled from.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SourceMap {
+    paths: Vec<PathBuf>,
+    map: BTreeMap<usize, SourceMapSpan>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct SourceMapSpan {
+    path: usize,
+    range: LocationRange,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct LocationRange {
+    start: LineCol,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct LineCol {
+    line: usize,
+    col: usize,
+}
+
+/// A position in a Sway source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for SourceLocation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.path.display(), self.line, self.column)
+    }
+}
+
+impl SourceMap {
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|err| error!(Codec, "invalid source map: {err}"))
+    }
+
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|err| error!(Other, "could not read source map {path:?}: {err}"))?;
+
+        Self::from_json(&json)
+    }
+
+    /// Returns the location of the code compiled to the instruction at offset `pc` in the
+    /// bytecode. Instructions the compiler didn't map, e.g. those it generated, are attributed to
+    /// the closest mapped instruction before them.
+    pub fn locate(&self, pc: u64) -> Option<SourceLocation> {
+        let instruction = usize::try_from(pc / 4).ok()?;
+        let (_, span) = self.map.range(..=instruction).next_back()?;
+
+        Some(SourceLocation {
+            path: self.paths.get(span.path)?.clone(),
+            line: span.range.start.line,
+            column: span.range.start.col,
+        })
+    }
+}
+
+/// The source maps of the programs under test, keyed by contract id. Scripts are executed
+/// outside of contracts and are registered under [`ContractId::zeroed`].
+///
+/// Once set on a provider with `Provider::with_source_maps`, revert errors of the transactions
+/// sent through it report the Sway code that panicked or reverted. Clones share the registered
+/// source maps.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMapRegistry {
+    maps: Arc<RwLock<HashMap<ContractId, SourceMap>>>,
+}
+
+impl SourceMapRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the source map of a contract, replacing the one registered before, if any.
+    pub fn add(&self, contract_id: ContractId, map: SourceMap) {
+        self.maps
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(contract_id, map);
+    }
+
+    pub fn add_script(&self, map: SourceMap) {
+        self.add(ContractId::zeroed(), map)
+    }
+
+    /// Returns the location of the instruction at offset `pc` in the bytecode of `contract_id`,
+    /// e.g. the location of a step of an execution trace.
+    pub fn locate(&self, contract_id: &ContractId, pc: u64) -> Option<SourceLocation> {
+        self.maps
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(contract_id)?
+            .locate(pc)
+    }
+
+    /// Returns the location of the instruction that emitted a `Panic` or `Revert` receipt.
+    pub fn locate_receipt(&self, receipt: &Receipt) -> Option<SourceLocation> {
+        match receipt {
+            Receipt::Panic { id, pc, is, .. } | Receipt::Revert { id, pc, is, .. } => {
+                self.locate(id, pc.checked_sub(*is)?)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the location of the last panic or revert among `receipts`.
+    pub fn locate_failure(&self, receipts: &[Receipt]) -> Option<SourceLocation> {
+        receipts
+            .iter()
+            .rev()
+            .find(|receipt| matches!(receipt, Receipt::Panic { .. } | Receipt::Revert { .. }))
+            .and_then(|receipt| self.locate_receipt(receipt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source_map() -> SourceMap {
+        SourceMap::from_json(
+            r#"{
+                "paths": ["src/main.sw", "std/src/revert.sw"],
+                "map": {
+                    "0": { "path": 0, "range": { "start": { "line": 3, "col": 1 }, "end": { "line": 3, "col": 10 } } },
+                    "2": { "path": 1, "range": { "start": { "line": 7, "col": 5 }, "end": { "line": 7, "col": 20 } } }
+                }
+            }"#,
+        )
+        .expect("is a valid source map")
+    }
+
+    #[test]
+    fn instructions_are_located_in_the_sources() {
+        let map = source_map();
+
+        let location = |pc| map.locate(pc).map(|location| location.to_string());
+        assert_eq!(location(0).as_deref(), Some("src/main.sw:3:1"));
+        assert_eq!(location(4).as_deref(), Some("src/main.sw:3:1"));
+        assert_eq!(location(8).as_deref(), Some("std/src/revert.sw:7:5"));
+        assert_eq!(location(100).as_deref(), Some("std/src/revert.sw:7:5"));
+    }
+
+    #[test]
+    fn failures_are_located_by_contract() {
+        let contract_id = ContractId::from([1; 32]);
+        let registry = SourceMapRegistry::new();
+        registry.add(contract_id, source_map());
+
+        let receipts = [
+            Receipt::revert(ContractId::from([2; 32]), 1, 1008, 1000),
+            Receipt::revert(contract_id, 1, 1008, 1000),
+        ];
+
+        assert_eq!(
+            registry.locate_failure(&receipts),
+            Some(SourceLocation {
+                path: "std/src/revert.sw".into(),
+                line: 7,
+                column: 5,
+            })
+        );
+        assert!(registry.locate_failure(&receipts[..1]).is_none());
+    }
+}
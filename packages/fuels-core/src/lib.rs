@@ -1,4 +1,6 @@
 pub mod codec;
+pub mod crypto;
+pub mod debug;
 pub mod traits;
 pub mod types;
 mod utils;
@@ -230,4 +230,41 @@ mod tests {
     fn test_param_type_raw_slice() {
         assert_eq!(RawSlice::param_type(), ParamType::RawSlice);
     }
+
+    #[test]
+    fn derived_param_types_are_resolved_per_generic_instantiation() {
+        #[allow(dead_code)]
+        #[derive(fuels_macros::Parameterize)]
+        #[FuelsCorePath = "crate"]
+        #[FuelsTypesPath = "crate::types"]
+        struct Wrapper<T: Parameterize> {
+            inner: T,
+        }
+
+        #[allow(dead_code)]
+        #[derive(fuels_macros::Parameterize)]
+        #[FuelsCorePath = "crate"]
+        #[FuelsTypesPath = "crate::types"]
+        struct Plain {
+            inner: u8,
+        }
+
+        assert_eq!(
+            Plain::param_type(),
+            ParamType::Struct {
+                name: "Plain".to_string(),
+                fields: vec![("inner".to_string(), ParamType::U8)],
+                generics: vec![],
+            }
+        );
+        assert_ne!(Wrapper::<u8>::param_type(), Wrapper::<u64>::param_type());
+        assert_eq!(
+            Wrapper::<u64>::param_type(),
+            ParamType::Struct {
+                name: "Wrapper".to_string(),
+                fields: vec![("inner".to_string(), ParamType::U64)],
+                generics: vec![ParamType::U64],
+            }
+        );
+    }
 }
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use fuel_crypto::{Message, Signature};
+use fuel_tx::Transaction;
 
-use crate::types::{bech32::Bech32Address, errors::Result};
+use crate::types::{bech32::Bech32Address, errors::Result, typed_data::TypedData};
 
 /// Trait for signing transactions and messages
 ///
@@ -11,4 +12,17 @@ use crate::types::{bech32::Bech32Address, errors::Result};
 pub trait Signer: 'static {
     async fn sign(&self, message: Message) -> Result<Signature>;
     fn address(&self) -> &Bech32Address;
+
+    /// Signs `message`, the id of `tx`. Signers that need to inspect what they sign, e.g. to
+    /// enforce a policy, override this instead of trusting the bare id passed to [`Self::sign`].
+    async fn sign_transaction(&self, _tx: &Transaction, message: Message) -> Result<Signature> {
+        self.sign(message).await
+    }
+
+    /// Signs the digest of `typed_data`, which [`TypedData::verify`] checks.
+    async fn sign_typed_data(&self, typed_data: &TypedData) -> Result<Signature> {
+        let message = typed_data.message_to_sign()?;
+
+        self.sign(message).await
+    }
 }
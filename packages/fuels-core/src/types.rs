@@ -4,16 +4,22 @@ pub use fuel_types::{
     MessageId, Nonce, Salt, Word,
 };
 
-pub use crate::types::{core::*, token::*, wrappers::*};
+pub use crate::types::{core::*, dynamic_value::*, token::*, wrappers::*};
 use crate::{error, types::errors::Result};
 
 pub mod bech32;
+pub mod bridge;
+pub mod checksum;
 mod core;
+mod dynamic_value;
 pub mod errors;
 pub mod param_types;
+pub mod signature;
 mod token;
 pub mod transaction_builders;
 pub mod tx_status;
+pub mod typed_data;
+pub mod units;
 pub mod unresolved_bytes;
 mod wrappers;
 
@@ -8,6 +8,7 @@ use fuel_tx::{Address, Bytes32, ContractId, ContractIdExt};
 use fuel_types::AssetId;
 
 use crate::types::{
+    checksum::checksum_encode,
     errors::{Error, Result},
     Bits256,
 };
@@ -15,6 +16,19 @@ use crate::types::{
 // Fuel Network human-readable part for bech32 encoding
 pub const FUEL_BECH32_HRP: &str = "fuel";
 
+/// How [`Bech32Address::format_as`] and [`Bech32ContractId::format_as`] write the identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AddressFormat {
+    /// The bech32m encoding, as written by `Display`.
+    #[default]
+    Bech32,
+    /// The `0x`-prefixed lowercase hex encoding of the hash.
+    Hex,
+    /// The `0x`-prefixed checksummed hex encoding of the hash, see
+    /// [`ChecksumHex`](crate::types::checksum::ChecksumHex).
+    ChecksumHex,
+}
+
 /// Generate type represented in the Bech32 format,
 /// consisting of a human-readable part (hrp) and a hash (e.g. pubkey-, contract hash)
 macro_rules! bech32type {
@@ -40,6 +54,14 @@ macro_rules! bech32type {
             pub fn hrp(&self) -> &str {
                 &self.hrp
             }
+
+            pub fn format_as(&self, format: AddressFormat) -> String {
+                match format {
+                    AddressFormat::Bech32 => self.to_string(),
+                    AddressFormat::Hex => format!("{:#x}", self.hash),
+                    AddressFormat::ChecksumHex => checksum_encode(&self.hash),
+                }
+            }
         }
 
         impl Default for $i {
@@ -237,4 +259,25 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn addresses_are_formatted_as_requested() {
+        use crate::types::checksum::ChecksumHex;
+
+        let address = Address::from([0xab; 32]);
+        let bech32_address = Bech32Address::from(address);
+
+        assert_eq!(
+            bech32_address.format_as(AddressFormat::Bech32),
+            bech32_address.to_string()
+        );
+        assert_eq!(
+            bech32_address.format_as(AddressFormat::Hex),
+            format!("0x{}", "ab".repeat(32))
+        );
+        assert_eq!(
+            bech32_address.format_as(AddressFormat::ChecksumHex),
+            address.to_checksum_hex()
+        );
+    }
 }
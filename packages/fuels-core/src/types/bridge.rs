@@ -0,0 +1,444 @@
+#![cfg(feature = "std")]
+//! The calldata of `relayMessage` of the `FuelMessagePortal` on the base layer, which finalizes a
+//! withdrawal made with `Account::withdraw_to_base_layer`.
+//!
+//! The portal expects
+//!
+//! ```solidity
+//! function relayMessage(
+//!     Message message,
+//!     FuelBlockHeaderLite rootBlockHeader,
+//!     FuelBlockHeader blockHeader,
+//!     MerkleProof blockInHistoryProof,
+//!     MerkleProof messageInBlockProof
+//! )
+//! ```
+//!
+//! with the structs mirrored by [`RelayedMessage`], [`BlockHeaderLite`], [`BlockHeader`] and
+//! [`InclusionProof`]. [`RelayMessageCalldata::encode`] produces the Ethereum ABI encoding of the
+//! arguments, without the function selector.
+
+use fuel_types::{Bytes32, Nonce};
+
+use crate::types::{
+    block::Header,
+    message_proof::{MerkleProof, MessageProof},
+};
+
+/// The offset of TAI64 labels, in which block times are committed to the base layer, from unix
+/// timestamps.
+const TAI64_UNIX_OFFSET: u64 = (1 << 62) + 10;
+
+/// `struct Message { bytes32 sender; bytes32 recipient; bytes32 nonce; uint64 amount; bytes data; }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayedMessage {
+    pub sender: Bytes32,
+    pub recipient: Bytes32,
+    pub nonce: Nonce,
+    pub amount: u64,
+    pub data: Vec<u8>,
+}
+
+/// `struct FuelBlockHeaderLite { bytes32 prevRoot; uint32 height; uint64 timestamp; bytes32 applicationHash; }`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHeaderLite {
+    pub prev_root: Bytes32,
+    pub height: u32,
+    /// TAI64 label of the block time.
+    pub timestamp: u64,
+    pub application_hash: Bytes32,
+}
+
+/// ```solidity
+/// struct FuelBlockHeader {
+///     bytes32 prevRoot;
+///     uint32 height;
+///     uint64 timestamp;
+///     uint64 daHeight;
+///     uint16 txCount;
+///     uint32 outputMessagesCount;
+///     bytes32 txRoot;
+///     bytes32 outputMessagesRoot;
+///     bytes32 eventInboxRoot;
+///     uint32 consensusParametersVersion;
+///     uint32 stateTransitionBytecodeVersion;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHeader {
+    pub prev_root: Bytes32,
+    pub height: u32,
+    /// TAI64 label of the block time.
+    pub timestamp: u64,
+    pub da_height: u64,
+    pub tx_count: u16,
+    pub output_messages_count: u32,
+    pub tx_root: Bytes32,
+    pub output_messages_root: Bytes32,
+    pub event_inbox_root: Bytes32,
+    pub consensus_parameters_version: u32,
+    pub state_transition_bytecode_version: u32,
+}
+
+/// `struct MerkleProof { uint256 key; bytes32[] proof; }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InclusionProof {
+    pub key: u64,
+    pub proof: Vec<Bytes32>,
+}
+
+/// The arguments of `relayMessage`, see the module documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelayMessageCalldata {
+    pub message: RelayedMessage,
+    /// The header of the commit block, the root of the block proof.
+    pub root_block_header: BlockHeaderLite,
+    /// The header of the block containing the message.
+    pub block_header: BlockHeader,
+    pub block_in_history_proof: InclusionProof,
+    pub message_in_block_proof: InclusionProof,
+}
+
+impl RelayMessageCalldata {
+    /// The Ethereum ABI encoding of the arguments, without the function selector.
+    pub fn encode(&self) -> Vec<u8> {
+        SolValue::Tuple(vec![
+            self.message.to_sol_value(),
+            self.root_block_header.to_sol_value(),
+            self.block_header.to_sol_value(),
+            self.block_in_history_proof.to_sol_value(),
+            self.message_in_block_proof.to_sol_value(),
+        ])
+        .encode()
+    }
+}
+
+impl From<&MessageProof> for RelayMessageCalldata {
+    fn from(proof: &MessageProof) -> Self {
+        Self {
+            message: RelayedMessage {
+                sender: proof.sender.hash(),
+                recipient: proof.recipient.hash(),
+                nonce: proof.nonce,
+                amount: proof.amount,
+                data: proof.data.clone(),
+            },
+            root_block_header: (&proof.commit_block_header).into(),
+            block_header: (&proof.message_block_header).into(),
+            block_in_history_proof: (&proof.block_proof).into(),
+            message_in_block_proof: (&proof.message_proof).into(),
+        }
+    }
+}
+
+impl From<MessageProof> for RelayMessageCalldata {
+    fn from(proof: MessageProof) -> Self {
+        (&proof).into()
+    }
+}
+
+impl From<&Header> for BlockHeaderLite {
+    fn from(header: &Header) -> Self {
+        Self {
+            prev_root: header.prev_root,
+            height: header.height,
+            timestamp: tai64_timestamp(header),
+            application_hash: header.application_hash,
+        }
+    }
+}
+
+impl From<&Header> for BlockHeader {
+    fn from(header: &Header) -> Self {
+        Self {
+            prev_root: header.prev_root,
+            height: header.height,
+            timestamp: tai64_timestamp(header),
+            da_height: header.da_height,
+            tx_count: header.transactions_count,
+            output_messages_count: header.message_receipt_count,
+            tx_root: header.transactions_root,
+            output_messages_root: header.message_outbox_root,
+            event_inbox_root: header.event_inbox_root,
+            consensus_parameters_version: header.consensus_parameters_version,
+            state_transition_bytecode_version: header.state_transition_bytecode_version,
+        }
+    }
+}
+
+impl From<&MerkleProof> for InclusionProof {
+    fn from(proof: &MerkleProof) -> Self {
+        Self {
+            key: proof.proof_index,
+            proof: proof.proof_set.clone(),
+        }
+    }
+}
+
+fn tai64_timestamp(header: &Header) -> u64 {
+    let unix = header.time.map(|time| time.timestamp()).unwrap_or_default();
+
+    TAI64_UNIX_OFFSET.wrapping_add_signed(unix)
+}
+
+impl RelayedMessage {
+    fn to_sol_value(&self) -> SolValue {
+        SolValue::Tuple(vec![
+            SolValue::Word(*self.sender),
+            SolValue::Word(*self.recipient),
+            SolValue::Word(*self.nonce),
+            SolValue::uint(self.amount),
+            SolValue::Bytes(self.data.clone()),
+        ])
+    }
+}
+
+impl BlockHeaderLite {
+    fn to_sol_value(self) -> SolValue {
+        SolValue::Tuple(vec![
+            SolValue::Word(*self.prev_root),
+            SolValue::uint(self.height.into()),
+            SolValue::uint(self.timestamp),
+            SolValue::Word(*self.application_hash),
+        ])
+    }
+}
+
+impl BlockHeader {
+    fn to_sol_value(self) -> SolValue {
+        SolValue::Tuple(vec![
+            SolValue::Word(*self.prev_root),
+            SolValue::uint(self.height.into()),
+            SolValue::uint(self.timestamp),
+            SolValue::uint(self.da_height),
+            SolValue::uint(self.tx_count.into()),
+            SolValue::uint(self.output_messages_count.into()),
+            SolValue::Word(*self.tx_root),
+            SolValue::Word(*self.output_messages_root),
+            SolValue::Word(*self.event_inbox_root),
+            SolValue::uint(self.consensus_parameters_version.into()),
+            SolValue::uint(self.state_transition_bytecode_version.into()),
+        ])
+    }
+}
+
+impl InclusionProof {
+    fn to_sol_value(&self) -> SolValue {
+        SolValue::Tuple(vec![
+            SolValue::uint(self.key),
+            SolValue::Array(
+                self.proof
+                    .iter()
+                    .map(|node| SolValue::Word(**node))
+                    .collect(),
+            ),
+        ])
+    }
+}
+
+/// The subset of Ethereum ABI values needed to encode the calldata.
+enum SolValue {
+    Word([u8; 32]),
+    Bytes(Vec<u8>),
+    Array(Vec<SolValue>),
+    Tuple(Vec<SolValue>),
+}
+
+impl SolValue {
+    fn uint(value: u64) -> Self {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+
+        Self::Word(word)
+    }
+
+    fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Word(_) => false,
+            Self::Bytes(_) | Self::Array(_) => true,
+            Self::Tuple(values) => values.iter().any(Self::is_dynamic),
+        }
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Word(word) => word.to_vec(),
+            Self::Bytes(bytes) => {
+                let mut encoded = Self::uint(bytes.len() as u64).encode();
+                encoded.extend_from_slice(bytes);
+                encoded.resize(32 + bytes.len().div_ceil(32) * 32, 0);
+
+                encoded
+            }
+            Self::Array(values) => {
+                let mut encoded = Self::uint(values.len() as u64).encode();
+                encoded.extend(Self::encode_sequence(values));
+
+                encoded
+            }
+            Self::Tuple(values) => Self::encode_sequence(values),
+        }
+    }
+
+    /// Static values are encoded in place, dynamic ones are appended after the head and
+    /// referenced by their offset from its start.
+    fn encode_sequence(values: &[SolValue]) -> Vec<u8> {
+        let head_len: usize = values
+            .iter()
+            .map(|value| {
+                if value.is_dynamic() {
+                    32
+                } else {
+                    value.encode().len()
+                }
+            })
+            .sum();
+
+        let mut head = Vec::with_capacity(head_len);
+        let mut tail = vec![];
+        for value in values {
+            if value.is_dynamic() {
+                head.extend(Self::uint((head_len + tail.len()) as u64).encode());
+                tail.extend(value.encode());
+            } else {
+                head.extend(value.encode());
+            }
+        }
+        head.extend(tail);
+
+        head
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::*;
+    use crate::types::{bech32::Bech32Address, Address};
+
+    fn word(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    fn uint(value: u64) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    fn header(height: u32) -> Header {
+        Header {
+            id: Bytes32::zeroed(),
+            da_height: 7,
+            transactions_count: 2,
+            message_receipt_count: 1,
+            transactions_root: Bytes32::new(word(0xa1)),
+            message_outbox_root: Bytes32::new(word(0xa2)),
+            event_inbox_root: Bytes32::new(word(0xa3)),
+            consensus_parameters_version: 0,
+            state_transition_bytecode_version: 1,
+            height,
+            prev_root: Bytes32::new(word(0xa4)),
+            time: DateTime::from_timestamp(0, 0),
+            application_hash: Bytes32::new(word(0xa5)),
+        }
+    }
+
+    fn message_proof() -> MessageProof {
+        MessageProof {
+            message_proof: MerkleProof {
+                proof_set: vec![Bytes32::new(word(0xb1))],
+                proof_index: 0,
+            },
+            block_proof: MerkleProof {
+                proof_set: vec![],
+                proof_index: 1,
+            },
+            message_block_header: header(1),
+            commit_block_header: header(2),
+            sender: Bech32Address::from(Address::new(word(0x01))),
+            recipient: Bech32Address::from(Address::new(word(0x02))),
+            nonce: Nonce::new(word(0x03)),
+            amount: 100,
+            data: vec![0xff; 33],
+        }
+    }
+
+    #[test]
+    fn message_proofs_are_converted_to_the_portal_structs() {
+        let calldata = RelayMessageCalldata::from(message_proof());
+
+        assert_eq!(calldata.message.recipient, Bytes32::new(word(0x02)));
+        assert_eq!(
+            calldata.root_block_header,
+            BlockHeaderLite {
+                prev_root: Bytes32::new(word(0xa4)),
+                height: 2,
+                timestamp: TAI64_UNIX_OFFSET,
+                application_hash: Bytes32::new(word(0xa5)),
+            }
+        );
+        assert_eq!(calldata.block_header.height, 1);
+        assert_eq!(calldata.block_header.output_messages_count, 1);
+        assert_eq!(
+            calldata.block_in_history_proof,
+            InclusionProof {
+                key: 1,
+                proof: vec![],
+            }
+        );
+        assert_eq!(calldata.message_in_block_proof.proof.len(), 1);
+    }
+
+    #[test]
+    fn calldata_follows_the_ethereum_abi() {
+        let calldata = RelayMessageCalldata::from(message_proof());
+
+        let expected: Vec<[u8; 32]> = vec![
+            // head: message offset, root header, block header, proof offsets
+            uint(18 * 32),
+            word(0xa4),
+            uint(2),
+            uint(TAI64_UNIX_OFFSET),
+            word(0xa5),
+            word(0xa4),
+            uint(1),
+            uint(TAI64_UNIX_OFFSET),
+            uint(7),
+            uint(2),
+            uint(1),
+            word(0xa1),
+            word(0xa2),
+            word(0xa3),
+            uint(0),
+            uint(1),
+            uint(26 * 32),
+            uint(29 * 32),
+            // message, with the data padded to whole words
+            word(0x01),
+            word(0x02),
+            word(0x03),
+            uint(100),
+            uint(5 * 32),
+            uint(33),
+            word(0xff),
+            {
+                let mut last = [0; 32];
+                last[0] = 0xff;
+                last
+            },
+            // block proof
+            uint(1),
+            uint(64),
+            uint(0),
+            // message proof
+            uint(0),
+            uint(64),
+            uint(1),
+            word(0xb1),
+        ];
+
+        assert_eq!(calldata.encode(), expected.concat());
+    }
+}
@@ -0,0 +1,105 @@
+//! EIP-55-style checksummed hex representation of 32-byte identifiers.
+//!
+//! The hex digits of the identifier are written in lower case, except for the letters whose
+//! corresponding nibble of the SHA-256 hash of the lowercase hex string is 8 or more. A typo
+//! in a checksummed string is then detected with high probability when parsing it.
+
+use fuel_crypto::Hasher;
+
+use crate::types::{
+    errors::{error, Result},
+    Address, ContractId,
+};
+
+/// Formatting and strict parsing of the checksummed hex representation.
+pub trait ChecksumHex: Sized {
+    /// The `0x`-prefixed checksummed hex representation.
+    fn to_checksum_hex(&self) -> String;
+
+    /// Parses a checksummed hex string, with or without the `0x` prefix. Strings whose case does
+    /// not match the checksum, including all-lowercase ones, are rejected.
+    fn from_checksum_hex(s: &str) -> Result<Self>;
+}
+
+macro_rules! impl_checksum_hex {
+    ($($ty:ty),*) => {
+        $(
+            impl ChecksumHex for $ty {
+                fn to_checksum_hex(&self) -> String {
+                    checksum_encode(self)
+                }
+
+                fn from_checksum_hex(s: &str) -> Result<Self> {
+                    Ok(Self::from(checksum_decode(s)?))
+                }
+            }
+        )*
+    };
+}
+
+impl_checksum_hex!(Address, ContractId);
+
+pub(crate) fn checksum_encode(bytes: &[u8; 32]) -> String {
+    let lowercase = hex::encode(bytes);
+    let hash = Hasher::hash(lowercase.as_bytes());
+
+    let checksummed: String = lowercase
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+
+    format!("0x{checksummed}")
+}
+
+fn checksum_decode(s: &str) -> Result<[u8; 32]> {
+    let digits = s.strip_prefix("0x").unwrap_or(s);
+
+    let mut bytes = [0u8; 32];
+    hex::decode_to_slice(digits, &mut bytes)
+        .map_err(|e| error!(Codec, "invalid hex identifier `{s}`: {e}"))?;
+
+    if checksum_encode(&bytes)[2..] != *digits {
+        return Err(error!(Codec, "invalid checksum for hex identifier `{s}`"));
+    }
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksummed_hex_round_trips() -> Result<()> {
+        let address = Address::from([0xab; 32]);
+
+        let checksummed = address.to_checksum_hex();
+
+        assert!(checksummed.starts_with("0x"));
+        assert_ne!(checksummed[2..], checksummed[2..].to_lowercase());
+        assert_eq!(Address::from_checksum_hex(&checksummed)?, address);
+        assert_eq!(Address::from_checksum_hex(&checksummed[2..])?, address);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bad_checksums_are_rejected() {
+        let checksummed = ContractId::from([0xab; 32]).to_checksum_hex();
+        let lowercase = checksummed.to_lowercase();
+
+        let err = ContractId::from_checksum_hex(&lowercase).expect_err("should fail");
+        assert!(err.to_string().contains("invalid checksum"));
+
+        let err = ContractId::from_checksum_hex("0x1234").expect_err("should fail");
+        assert!(err.to_string().contains("invalid hex identifier"));
+    }
+}
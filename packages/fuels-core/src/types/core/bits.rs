@@ -1,7 +1,9 @@
+use std::str::FromStr;
+
 use fuel_types::AssetId;
-use fuels_macros::{Parameterize, Tokenizable, TryFrom};
+use fuels_macros::{Parameterize, Tokenizable};
 
-use crate::types::errors::Result;
+use crate::types::errors::{error, Error, Result};
 
 // A simple wrapper around [u8; 32] representing the `b256` type. Exists
 // mainly so that we may differentiate `Parameterize` and `Tokenizable`
@@ -11,23 +13,30 @@ pub struct Bits256(pub [u8; 32]);
 
 impl Bits256 {
     /// Returns `Self` with zeroes inside.
-    pub fn zeroed() -> Self {
+    pub const fn zeroed() -> Self {
         Self([0; 32])
     }
 
     /// Create a new `Bits256` from a string representation of a hex.
     /// Accepts both `0x` prefixed and non-prefixed hex strings.
     pub fn from_hex_str(hex: &str) -> Result<Self> {
-        let hex = if let Some(stripped_hex) = hex.strip_prefix("0x") {
-            stripped_hex
-        } else {
-            hex
-        };
+        Ok(Bits256(decode_hex(hex, "Bits256")?))
+    }
+}
 
-        let mut bytes = [0u8; 32];
-        hex::decode_to_slice(hex, &mut bytes as &mut [u8])?;
+impl FromStr for Bits256 {
+    type Err = Error;
 
-        Ok(Bits256(bytes))
+    fn from_str(hex: &str) -> Result<Self> {
+        Self::from_hex_str(hex)
+    }
+}
+
+impl TryFrom<&[u8]> for Bits256 {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(Self(to_array(bytes, "Bits256")?))
     }
 }
 
@@ -38,7 +47,7 @@ impl From<AssetId> for Bits256 {
 }
 
 // A simple wrapper around [Bits256; 2] representing the `B512` type.
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable, TryFrom)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable)]
 #[FuelsCorePath = "crate"]
 #[FuelsTypesPath = "crate::types"]
 // ANCHOR: b512
@@ -47,6 +56,46 @@ pub struct B512 {
 }
 // ANCHOR_END: b512
 
+impl B512 {
+    pub const fn from_bytes(bytes: [u8; 64]) -> Self {
+        let mut hi = [0; 32];
+        let mut lo = [0; 32];
+
+        let mut i = 0;
+        while i < 32 {
+            hi[i] = bytes[i];
+            lo[i] = bytes[32 + i];
+            i += 1;
+        }
+
+        Self {
+            bytes: [Bits256(hi), Bits256(lo)],
+        }
+    }
+
+    /// Create a new `B512` from a string representation of a hex.
+    /// Accepts both `0x` prefixed and non-prefixed hex strings.
+    pub fn from_hex_str(hex: &str) -> Result<Self> {
+        Ok(Self::from_bytes(decode_hex(hex, "B512")?))
+    }
+}
+
+impl FromStr for B512 {
+    type Err = Error;
+
+    fn from_str(hex: &str) -> Result<Self> {
+        Self::from_hex_str(hex)
+    }
+}
+
+impl TryFrom<&[u8]> for B512 {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::from_bytes(to_array(bytes, "B512")?))
+    }
+}
+
 impl From<(Bits256, Bits256)> for B512 {
     fn from(bits_tuple: (Bits256, Bits256)) -> Self {
         B512 {
@@ -55,7 +104,7 @@ impl From<(Bits256, Bits256)> for B512 {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable, TryFrom)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Parameterize, Tokenizable)]
 #[FuelsCorePath = "crate"]
 #[FuelsTypesPath = "crate::types"]
 // ANCHOR: evm_address
@@ -71,6 +120,34 @@ impl EvmAddress {
         }
     }
 
+    /// Creates the address from its 20 bytes.
+    pub const fn from_bytes(bytes: [u8; 20]) -> Self {
+        let mut padded = [0; 32];
+
+        let mut i = 0;
+        while i < 20 {
+            padded[12 + i] = bytes[i];
+            i += 1;
+        }
+
+        Self {
+            value: Bits256(padded),
+        }
+    }
+
+    /// Create a new `EvmAddress` from a string representation of a hex. Accepts both `0x`
+    /// prefixed and non-prefixed hex strings of either the 20 bytes of the address or of the 32
+    /// bytes of its `b256` representation, whose first 12 bytes must then be zero.
+    pub fn from_hex_str(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix("0x").unwrap_or(hex);
+
+        if digits.len() == 64 {
+            Self::try_from(Bits256::from_hex_str(hex)?.0.as_slice())
+        } else {
+            Ok(Self::from_bytes(decode_hex(hex, "EvmAddress")?))
+        }
+    }
+
     pub fn value(&self) -> Bits256 {
         self.value
     }
@@ -90,6 +167,84 @@ impl From<Bits256> for EvmAddress {
     }
 }
 
+impl FromStr for EvmAddress {
+    type Err = Error;
+
+    fn from_str(hex: &str) -> Result<Self> {
+        Self::from_hex_str(hex)
+    }
+}
+
+/// Accepts either the 20 bytes of the address or the 32 bytes of its `b256` representation,
+/// whose first 12 bytes must then be zero.
+impl TryFrom<&[u8]> for EvmAddress {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        match bytes.len() {
+            32 if bytes[..12].iter().any(|byte| *byte != 0) => Err(error!(
+                Other,
+                "the first 12 bytes of a `b256` `EvmAddress` must be zero"
+            )),
+            32 => Ok(Self::from_bytes(to_array(&bytes[12..], "EvmAddress")?)),
+            _ => Ok(Self::from_bytes(to_array(bytes, "EvmAddress")?)),
+        }
+    }
+}
+
+macro_rules! impl_try_from_vec {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<&Vec<u8>> for $ty {
+                type Error = Error;
+
+                fn try_from(bytes: &Vec<u8>) -> Result<Self> {
+                    Self::try_from(bytes.as_slice())
+                }
+            }
+
+            impl TryFrom<Vec<u8>> for $ty {
+                type Error = Error;
+
+                fn try_from(bytes: Vec<u8>) -> Result<Self> {
+                    Self::try_from(bytes.as_slice())
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_vec!(Bits256, B512, EvmAddress);
+
+fn decode_hex<const N: usize>(hex: &str, type_name: &str) -> Result<[u8; N]> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+
+    if digits.len() != 2 * N {
+        return Err(error!(
+            Other,
+            "`{type_name}` expects {} hex digits, optionally prefixed with `0x`, got {}",
+            2 * N,
+            digits.len()
+        ));
+    }
+
+    let mut bytes = [0; N];
+    hex::decode_to_slice(digits, &mut bytes)
+        .map_err(|e| error!(Other, "could not parse `{hex}` as `{type_name}`: {e}"))?;
+
+    Ok(bytes)
+}
+
+fn to_array<const N: usize>(bytes: &[u8], type_name: &str) -> Result<[u8; N]> {
+    bytes.try_into().map_err(|_| {
+        error!(
+            Other,
+            "`{type_name}` expects {N} bytes, got {}",
+            bytes.len()
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +275,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn hex_strings_and_slices_are_parsed() -> Result<()> {
+        // ANCHOR: parse_bits
+        let hex = format!("0x{}", "01".repeat(32));
+        assert_eq!(hex.parse::<Bits256>()?, Bits256([1; 32]));
+        assert_eq!(Bits256::try_from([1u8; 32].as_slice())?, Bits256([1; 32]));
+
+        let hex = format!("{}{}", "01".repeat(32), "02".repeat(32));
+        let expected = B512::from((Bits256([1; 32]), Bits256([2; 32])));
+        assert_eq!(hex.parse::<B512>()?, expected);
+        assert_eq!(B512::try_from(hex::decode(&hex)?.as_slice())?, expected);
+
+        let expected = EvmAddress::from_bytes([3; 20]);
+        assert_eq!(
+            format!("0x{}", "03".repeat(20)).parse::<EvmAddress>()?,
+            expected
+        );
+        assert_eq!(expected.value().0[12..], [3; 20]);
+        let b256_hex = format!("0x{}{}", "00".repeat(12), "03".repeat(20));
+        assert_eq!(b256_hex.parse::<EvmAddress>()?, expected);
+        assert_eq!(EvmAddress::try_from([3u8; 20].as_slice())?, expected);
+        // ANCHOR_END: parse_bits
+
+        Ok(())
+    }
+
+    #[test]
+    fn parsing_errors_are_descriptive() {
+        let err = Bits256::from_str("0x0101").expect_err("should fail");
+        assert_eq!(
+            err.to_string(),
+            "`Bits256` expects 64 hex digits, optionally prefixed with `0x`, got 4"
+        );
+
+        let err = B512::from_str(&"zz".repeat(64)).expect_err("should fail");
+        assert!(err.to_string().contains("could not parse"));
+
+        let err = EvmAddress::try_from([1u8; 32].as_slice()).expect_err("should fail");
+        assert!(err.to_string().contains("first 12 bytes"));
+
+        let err = EvmAddress::try_from([1u8; 21].as_slice()).expect_err("should fail");
+        assert_eq!(err.to_string(), "`EvmAddress` expects 20 bytes, got 21");
+    }
+
     #[test]
     fn test_param_type_evm_addr() {
         assert_eq!(
@@ -59,6 +59,20 @@ impl<'de> Deserialize<'de> for U256 {
     }
 }
 
+#[cfg(feature = "primitive-types")]
+impl From<primitive_types::U256> for U256 {
+    fn from(value: primitive_types::U256) -> Self {
+        Self(value.0)
+    }
+}
+
+#[cfg(feature = "primitive-types")]
+impl From<U256> for primitive_types::U256 {
+    fn from(value: U256) -> Self {
+        Self(value.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::U256;
@@ -72,4 +86,15 @@ mod tests {
         let deserialized_num: U256 = serde_json::from_str(&serialized).unwrap();
         assert_eq!(deserialized_num, num);
     }
+
+    #[cfg(feature = "primitive-types")]
+    #[test]
+    fn u256_converts_to_and_from_primitive_types() {
+        let num = U256::MAX - U256::from(u128::MAX);
+
+        let converted = primitive_types::U256::from(num);
+
+        assert_eq!(converted.to_string(), num.to_string());
+        assert_eq!(U256::from(converted), num);
+    }
 }
@@ -0,0 +1,325 @@
+use std::iter::zip;
+
+use crate::types::{
+    core::U256,
+    errors::{error, Result},
+    param_types::ParamType,
+    StaticStringToken, Token,
+};
+
+/// A value decoded without generated bindings. Unlike a [`Token`], structs and enums carry the
+/// names given to them, their fields and their variants by the ABI.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum DynamicValue {
+    Unit,
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    U256(U256),
+    B256([u8; 32]),
+    Bytes(Vec<u8>),
+    String(String),
+    RawSlice(Vec<u8>),
+    StringArray(String),
+    StringSlice(String),
+    Tuple(Vec<DynamicValue>),
+    Array(Vec<DynamicValue>),
+    Vector(Vec<DynamicValue>),
+    Struct {
+        name: String,
+        fields: Vec<(String, DynamicValue)>,
+    },
+    Enum {
+        name: String,
+        variant: String,
+        value: Box<DynamicValue>,
+    },
+}
+
+impl DynamicValue {
+    /// Names the contents of `token`, as decoded for `param_type`.
+    pub fn from_token(token: Token, param_type: &ParamType) -> Result<Self> {
+        let value = match (token, param_type) {
+            (Token::Unit, ParamType::Unit) => Self::Unit,
+            (Token::Bool(value), ParamType::Bool) => Self::Bool(value),
+            (Token::U8(value), ParamType::U8) => Self::U8(value),
+            (Token::U16(value), ParamType::U16) => Self::U16(value),
+            (Token::U32(value), ParamType::U32) => Self::U32(value),
+            (Token::U64(value), ParamType::U64) => Self::U64(value),
+            (Token::U128(value), ParamType::U128) => Self::U128(value),
+            (Token::U256(value), ParamType::U256) => Self::U256(value),
+            (Token::B256(value), ParamType::B256) => Self::B256(value),
+            (Token::Bytes(value), ParamType::Bytes) => Self::Bytes(value),
+            (Token::String(value), ParamType::String) => Self::String(value),
+            (Token::RawSlice(value), ParamType::RawSlice) => Self::RawSlice(value),
+            (Token::StringArray(value), ParamType::StringArray(_)) => Self::StringArray(value.data),
+            (Token::StringSlice(value), ParamType::StringSlice) => Self::StringSlice(value.data),
+            (Token::Tuple(tokens), ParamType::Tuple(param_types)) => {
+                Self::Tuple(Self::from_tokens(tokens, param_types)?)
+            }
+            (Token::Array(tokens), ParamType::Array(param_type, _)) => {
+                Self::Array(Self::from_elements(tokens, param_type)?)
+            }
+            (Token::Vector(tokens), ParamType::Vector(param_type)) => {
+                Self::Vector(Self::from_elements(tokens, param_type)?)
+            }
+            (Token::Struct(tokens), ParamType::Struct { name, fields, .. }) => {
+                let (field_names, param_types): (Vec<_>, Vec<_>) = fields.iter().cloned().unzip();
+                let values = Self::from_tokens(tokens, &param_types)?;
+
+                Self::Struct {
+                    name: name.clone(),
+                    fields: zip(field_names, values).collect(),
+                }
+            }
+            (
+                Token::Enum(selector),
+                ParamType::Enum {
+                    name,
+                    enum_variants,
+                    ..
+                },
+            ) => {
+                let (discriminant, token, _) = *selector;
+                let (variant, param_type) = enum_variants.select_variant(discriminant)?;
+
+                Self::Enum {
+                    name: name.clone(),
+                    variant: variant.clone(),
+                    value: Box::new(Self::from_token(token, param_type)?),
+                }
+            }
+            (token, param_type) => {
+                return Err(error!(
+                    Codec,
+                    "token `{token}` does not match type `{param_type:?}`"
+                ))
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn from_tokens(tokens: Vec<Token>, param_types: &[ParamType]) -> Result<Vec<Self>> {
+        if tokens.len() != param_types.len() {
+            return Err(error!(
+                Codec,
+                "expected {} tokens, got {}",
+                param_types.len(),
+                tokens.len()
+            ));
+        }
+
+        zip(tokens, param_types)
+            .map(|(token, param_type)| Self::from_token(token, param_type))
+            .collect()
+    }
+
+    fn from_elements(tokens: Vec<Token>, param_type: &ParamType) -> Result<Vec<Self>> {
+        tokens
+            .into_iter()
+            .map(|token| Self::from_token(token, param_type))
+            .collect()
+    }
+
+    /// The inverse of [`DynamicValue::from_token`]. Struct fields and enum variants are matched
+    /// to `param_type` by name.
+    pub fn into_token(self, param_type: &ParamType) -> Result<Token> {
+        let token = match (self, param_type) {
+            (Self::Unit, ParamType::Unit) => Token::Unit,
+            (Self::Bool(value), ParamType::Bool) => Token::Bool(value),
+            (Self::U8(value), ParamType::U8) => Token::U8(value),
+            (Self::U16(value), ParamType::U16) => Token::U16(value),
+            (Self::U32(value), ParamType::U32) => Token::U32(value),
+            (Self::U64(value), ParamType::U64) => Token::U64(value),
+            (Self::U128(value), ParamType::U128) => Token::U128(value),
+            (Self::U256(value), ParamType::U256) => Token::U256(value),
+            (Self::B256(value), ParamType::B256) => Token::B256(value),
+            (Self::Bytes(value), ParamType::Bytes) => Token::Bytes(value),
+            (Self::String(value), ParamType::String) => Token::String(value),
+            (Self::RawSlice(value), ParamType::RawSlice) => Token::RawSlice(value),
+            (Self::StringArray(value), ParamType::StringArray(len)) => {
+                Token::StringArray(StaticStringToken::new(value, Some(*len)))
+            }
+            (Self::StringSlice(value), ParamType::StringSlice) => {
+                Token::StringSlice(StaticStringToken::new(value, None))
+            }
+            (Self::Tuple(values), ParamType::Tuple(param_types)) => {
+                Token::Tuple(Self::into_tokens(values, param_types)?)
+            }
+            (Self::Array(values), ParamType::Array(param_type, len)) => {
+                if values.len() != *len {
+                    return Err(error!(
+                        Codec,
+                        "expected an array of {len} elements, got {}",
+                        values.len()
+                    ));
+                }
+
+                Token::Array(Self::into_elements(values, param_type)?)
+            }
+            (Self::Vector(values), ParamType::Vector(param_type)) => {
+                Token::Vector(Self::into_elements(values, param_type)?)
+            }
+            (
+                Self::Struct {
+                    name,
+                    fields: mut values,
+                },
+                ParamType::Struct { fields, .. },
+            ) => {
+                let tokens = fields
+                    .iter()
+                    .map(|(field, param_type)| {
+                        let position = values
+                            .iter()
+                            .position(|(value_field, _)| value_field == field)
+                            .ok_or_else(|| {
+                                error!(Codec, "struct `{name}` is missing field `{field}`")
+                            })?;
+
+                        values.swap_remove(position).1.into_token(param_type)
+                    })
+                    .collect::<Result<_>>()?;
+
+                if let Some((field, _)) = values.first() {
+                    return Err(error!(Codec, "struct `{name}` has no field `{field}`"));
+                }
+
+                Token::Struct(tokens)
+            }
+            (
+                Self::Enum {
+                    name,
+                    variant,
+                    value,
+                },
+                ParamType::Enum { enum_variants, .. },
+            ) => {
+                let (discriminant, (_, param_type)) = enum_variants
+                    .variants()
+                    .iter()
+                    .enumerate()
+                    .find(|(_, (variant_name, _))| *variant_name == variant)
+                    .ok_or_else(|| error!(Codec, "enum `{name}` has no variant `{variant}`"))?;
+                let token = value.into_token(param_type)?;
+
+                Token::Enum(Box::new((
+                    discriminant as u64,
+                    token,
+                    enum_variants.clone(),
+                )))
+            }
+            (value, param_type) => {
+                return Err(error!(
+                    Codec,
+                    "value `{value:?}` does not match type `{param_type:?}`"
+                ))
+            }
+        };
+
+        Ok(token)
+    }
+
+    fn into_tokens(values: Vec<Self>, param_types: &[ParamType]) -> Result<Vec<Token>> {
+        if values.len() != param_types.len() {
+            return Err(error!(
+                Codec,
+                "expected {} values, got {}",
+                param_types.len(),
+                values.len()
+            ));
+        }
+
+        zip(values, param_types)
+            .map(|(value, param_type)| value.into_token(param_type))
+            .collect()
+    }
+
+    fn into_elements(values: Vec<Self>, param_type: &ParamType) -> Result<Vec<Token>> {
+        values
+            .into_iter()
+            .map(|value| value.into_token(param_type))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::param_types::EnumVariants;
+
+    #[test]
+    fn structs_and_enums_are_named() -> Result<()> {
+        let param_type = ParamType::Struct {
+            name: "Transfer".to_string(),
+            fields: vec![
+                ("amount".to_string(), ParamType::U64),
+                (
+                    "kind".to_string(),
+                    ParamType::Enum {
+                        name: "Kind".to_string(),
+                        enum_variants: EnumVariants::new(vec![
+                            ("Deposit".to_string(), ParamType::Unit),
+                            ("Withdrawal".to_string(), ParamType::Bool),
+                        ])?,
+                        generics: vec![],
+                    },
+                ),
+            ],
+            generics: vec![],
+        };
+        let ParamType::Struct { fields, .. } = &param_type else {
+            unreachable!()
+        };
+        let ParamType::Enum { enum_variants, .. } = &fields[1].1 else {
+            unreachable!()
+        };
+        let token = Token::Struct(vec![
+            Token::U64(10),
+            Token::Enum(Box::new((1, Token::Bool(true), enum_variants.clone()))),
+        ]);
+
+        let value = DynamicValue::from_token(token.clone(), &param_type)?;
+
+        assert_eq!(
+            value,
+            DynamicValue::Struct {
+                name: "Transfer".to_string(),
+                fields: vec![
+                    ("amount".to_string(), DynamicValue::U64(10)),
+                    (
+                        "kind".to_string(),
+                        DynamicValue::Enum {
+                            name: "Kind".to_string(),
+                            variant: "Withdrawal".to_string(),
+                            value: Box::new(DynamicValue::Bool(true)),
+                        }
+                    ),
+                ],
+            }
+        );
+        assert_eq!(value.into_token(&param_type)?, token);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mismatched_tokens_are_rejected() {
+        let err = DynamicValue::from_token(
+            Token::Tuple(vec![Token::U8(1)]),
+            &ParamType::Tuple(vec![ParamType::U8, ParamType::U8]),
+        )
+        .expect_err("should fail");
+
+        assert!(err.to_string().contains("expected 2 tokens, got 1"));
+
+        let err =
+            DynamicValue::from_token(Token::U8(1), &ParamType::Bool).expect_err("should fail");
+
+        assert!(err.to_string().contains("does not match type `Bool`"));
+    }
+}
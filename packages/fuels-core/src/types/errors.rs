@@ -1,12 +1,17 @@
 use std::{array::TryFromSliceError, str::Utf8Error};
 
-use fuel_tx::{Receipt, ValidityError};
+use fuel_tx::{Address, AssetId, ContractId, Receipt, ValidityError};
+use fuel_types::ChainId;
 use fuel_vm::checked_transaction::CheckError;
+use fuels_signing::SigningError;
 use hex::FromHexError;
 use thiserror::Error;
 
 pub mod transaction {
+    use std::time::Duration;
+
     use super::*;
+    use crate::types::bech32::Bech32Address;
 
     #[derive(Error, Debug)]
     pub enum Reason {
@@ -22,9 +27,80 @@ pub mod transaction {
             revert_id: u64,
             receipts: Vec<Receipt>,
         },
+        #[error("policy violation: {0}")]
+        PolicyViolation(PolicyViolation),
+        #[error("signer `{address}` failed: {source}")]
+        SignerFailed {
+            address: Bech32Address,
+            source: Box<Error>,
+        },
+        #[error("signer `{address}` did not sign within {timeout:?}")]
+        SignerTimedOut {
+            address: Bech32Address,
+            timeout: Duration,
+        },
+        #[error(
+            "wrong network: expected chain id {}, but the provider is connected to chain id {}",
+            **expected,
+            **actual
+        )]
+        WrongNetwork { expected: ChainId, actual: ChainId },
         #[error(": {0}")]
         Other(String),
     }
+
+    /// A transaction rejected by the signing restrictions of an account, e.g. the signing policy
+    /// of a wallet or the scope of a session key.
+    #[derive(Error, Debug, Clone, PartialEq, Eq)]
+    pub enum PolicyViolation {
+        #[error(
+            "sending {amount} of asset `{asset_id}` exceeds the per transaction limit of {limit}"
+        )]
+        TxAmountLimit {
+            asset_id: AssetId,
+            amount: u64,
+            limit: u64,
+        },
+        #[error("sending {amount} of asset `{asset_id}` exceeds the remaining daily limit of {remaining}")]
+        DailyAmountLimit {
+            asset_id: AssetId,
+            amount: u64,
+            remaining: u64,
+        },
+        #[error("recipient `{0}` is not allowed")]
+        RecipientNotAllowed(Address),
+        #[error(
+            "variable outputs can send {amount} of asset `{asset_id}` to recipients unknown when signing"
+        )]
+        UnknownRecipient { asset_id: AssetId, amount: u64 },
+        #[error("only transactions can be signed under a signing policy")]
+        BlindSigning,
+        #[error("asset `{0}` is not allowed")]
+        AssetNotAllowed(AssetId),
+        #[error("calls to contract `{0}` are not allowed")]
+        ContractNotAllowed(ContractId),
+        #[error("calls to method with selector `{0:?}` are not allowed")]
+        SelectorNotAllowed(Vec<u8>),
+        #[error("transaction does not call contract `{0}`")]
+        MissingContractCall(ContractId),
+        #[error("the script is not a contract call script built by the SDK")]
+        UnknownScript,
+        #[error(
+            "spending {amount} of asset `{asset_id}` exceeds the remaining allowance of {remaining}"
+        )]
+        SpendLimit {
+            asset_id: AssetId,
+            amount: u64,
+            remaining: u64,
+        },
+        #[error(
+            "session expired at block height {expiry_height}, current height is {current_height}"
+        )]
+        SessionExpired {
+            expiry_height: u32,
+            current_height: u32,
+        },
+    }
 }
 use transaction::Reason;
 
@@ -77,6 +153,19 @@ impl From<ValidityError> for Error {
     }
 }
 
+impl From<SigningError> for Error {
+    fn from(err: SigningError) -> Error {
+        match err {
+            SigningError::WitnessLimitExceeded { required, .. } => error_transaction!(
+                Validation,
+                "Witness limit exceeded. Consider setting the limit manually with a transaction \
+                builder. The new limit should be: `{required}`"
+            ),
+            err => error!(Other, "{err}"),
+        }
+    }
+}
+
 macro_rules! impl_error_from {
     ($err_variant:ident, $err_type:ty ) => {
         impl From<$err_type> for $crate::types::errors::Error {
@@ -134,6 +134,48 @@ impl ParamType {
         Ok(heap_bytes_size)
     }
 
+    /// Returns the name of the type as written in Sway, e.g. `Vec<(u64, str[3])>`. Structs and
+    /// enums are named without their module path.
+    pub fn type_name(&self) -> String {
+        let join = |param_types: &[ParamType]| {
+            param_types
+                .iter()
+                .map(ParamType::type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let with_generics = |name: &str, generics: &[ParamType]| {
+            if generics.is_empty() {
+                name.to_string()
+            } else {
+                format!("{name}<{}>", join(generics))
+            }
+        };
+
+        match self {
+            ParamType::Unit => "()".to_string(),
+            ParamType::Bool => "bool".to_string(),
+            ParamType::U8 => "u8".to_string(),
+            ParamType::U16 => "u16".to_string(),
+            ParamType::U32 => "u32".to_string(),
+            ParamType::U64 => "u64".to_string(),
+            ParamType::U128 => "u128".to_string(),
+            ParamType::U256 => "u256".to_string(),
+            ParamType::B256 => "b256".to_string(),
+            ParamType::Bytes => "Bytes".to_string(),
+            ParamType::String => "String".to_string(),
+            ParamType::RawSlice => "raw_slice".to_string(),
+            ParamType::StringArray(len) => format!("str[{len}]"),
+            ParamType::StringSlice => "str".to_string(),
+            ParamType::Tuple(param_types) => format!("({})", join(param_types)),
+            ParamType::Array(param_type, len) => format!("[{}; {len}]", param_type.type_name()),
+            ParamType::Vector(param_type) => format!("Vec<{}>", param_type.type_name()),
+            ParamType::Struct { name, generics, .. } | ParamType::Enum { name, generics, .. } => {
+                with_generics(name, generics)
+            }
+        }
+    }
+
     /// Calculates the number of bytes the VM expects this parameter to be encoded in.
     pub fn compute_encoding_in_bytes(&self) -> Result<usize> {
         let overflow_error = || {
@@ -289,6 +331,23 @@ mod tests {
         assert_eq!(EXPECTED_WIDTH, width);
     }
 
+    #[test]
+    fn type_names_are_written_as_in_sway() {
+        let param_type = ParamType::Vector(Box::new(ParamType::Tuple(vec![
+            ParamType::U64,
+            ParamType::StringArray(3),
+            ParamType::Array(Box::new(ParamType::B256), 2),
+        ])));
+        assert_eq!(param_type.type_name(), "Vec<(u64, str[3], [b256; 2])>");
+
+        let param_type = ParamType::Struct {
+            name: "Wrapper".to_string(),
+            fields: vec![],
+            generics: vec![ParamType::Unit, ParamType::Bytes],
+        };
+        assert_eq!(param_type.type_name(), "Wrapper<(), Bytes>");
+    }
+
     #[test]
     fn test_compute_encoding_in_bytes_overflows() -> Result<()> {
         let overflows = |p: ParamType| {
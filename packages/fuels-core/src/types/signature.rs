@@ -0,0 +1,87 @@
+//! Recovery and verification of the compact signatures produced by [`Signer`]s.
+//!
+//! Signers sign a 32-byte [`Message`]. Arbitrary payloads are hashed into one with
+//! [`message_for_payload`], while transaction ids are signed as they are, see
+//! [`message_for_tx_id`].
+//!
+//! [`Signer`]: crate::traits::Signer
+
+pub use fuel_crypto::{Message, PublicKey, Signature};
+pub use fuels_signing::message_for_tx_id;
+
+use crate::types::{
+    bech32::Bech32Address,
+    errors::{error, Result},
+    Address,
+};
+
+/// The message signed for `payload`, i.e. its SHA-256 hash.
+pub fn message_for_payload(payload: impl AsRef<[u8]>) -> Message {
+    Message::new(payload)
+}
+
+pub fn recover_public_key(signature: &Signature, message: &Message) -> Result<PublicKey> {
+    Ok(signature.recover(message)?)
+}
+
+/// Recovers the address of the account that produced `signature` over `message`.
+pub fn recover_address(signature: &Signature, message: &Message) -> Result<Bech32Address> {
+    let public_key = recover_public_key(signature, message)?;
+
+    Ok(Address::from(*public_key.hash()).into())
+}
+
+/// Fails unless `signature` was produced by `signer` over `message`.
+pub fn verify_signature(
+    signature: &Signature,
+    message: &Message,
+    signer: &Bech32Address,
+) -> Result<()> {
+    let recovered = recover_address(signature, message)?;
+
+    if recovered.hash() != signer.hash() {
+        return Err(error!(
+            Other,
+            "signature was produced by `{recovered}`, not by `{signer}`"
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::SecretKey;
+
+    use super::*;
+    use crate::types::Bytes32;
+
+    #[test]
+    fn signers_are_recovered_from_payloads_and_tx_ids() -> Result<()> {
+        // given
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let signer = Bech32Address::from(Address::from(*PublicKey::from(&secret_key).hash()));
+        let other = Bech32Address::from(Address::from([1; 32]));
+
+        for message in [
+            message_for_payload("some payload"),
+            message_for_tx_id(&Bytes32::from([2; 32])),
+        ] {
+            // when
+            let signature = Signature::sign(&secret_key, &message);
+
+            // then
+            assert_eq!(recover_address(&signature, &message)?, signer);
+            verify_signature(&signature, &message, &signer)?;
+
+            let err = verify_signature(&signature, &message, &other).expect_err("should fail");
+            assert!(err.to_string().contains("signature was produced by"));
+        }
+
+        Ok(())
+    }
+}
@@ -1,22 +1,29 @@
 #![cfg(feature = "std")]
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Formatter},
     iter::repeat,
+    num::NonZeroUsize,
+    sync::Arc,
+    time::Duration,
 };
 
 use async_trait::async_trait;
+use fuel_abi_types::error_codes::FAILED_TRANSFER_TO_ADDRESS_SIGNAL;
 use fuel_asm::{op, GTFArgs, RegId};
 use fuel_crypto::{Message as CryptoMessage, Signature};
 use fuel_tx::{
-    field::{Inputs, Policies as PoliciesField, ScriptGasLimit, WitnessLimit, Witnesses},
+    field::{Inputs, Outputs, Policies as PoliciesField, ScriptGasLimit, WitnessLimit, Witnesses},
     input::coin::{CoinPredicate, CoinSigned},
     policies::{Policies, PolicyType},
-    Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Script, StorageSlot,
-    Transaction as FuelTransaction, TransactionFee, TxPointer, UniqueIdentifier, Witness,
+    Chargeable, ConsensusParameters, Create, Input as FuelInput, Output, Receipt, Script,
+    StorageSlot, Transaction as FuelTransaction, TransactionFee, TxPointer, UniqueIdentifier,
+    Witness,
 };
 use fuel_types::{bytes::padded_len_usize, canonical::Serialize, Bytes32, ChainId, Salt};
+use fuels_signing::{message_for_tx_id, witness_from_signature, witnesses_size};
+use futures::{stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
 
 use crate::{
@@ -27,23 +34,63 @@ use crate::{
         bech32::Bech32Address,
         coin::Coin,
         coin_type::CoinType,
-        errors::{error_transaction, Result},
+        coin_type_id::CoinTypeId,
+        errors::{error_transaction, transaction::Reason, Error, Result},
         input::Input,
         message::Message,
         transaction::{
-            CreateTransaction, EstimablePredicates, ScriptTransaction, Transaction, TxPolicies,
+            CreateTransaction, EstimablePredicates, PredicateEstimationCache, ScriptTransaction,
+            Transaction, TxPolicies,
         },
         unresolved_bytes::UnresolvedBytes,
         Address, AssetId, ContractId,
     },
-    utils::{calculate_witnesses_size, sealed},
+    utils::{self, sealed},
 };
 
+mod fee_cache;
+mod tip_strategy;
+
+pub use fee_cache::FeeEstimationCache;
+use fee_cache::{FeeEstimate, FeeEstimationKey};
+pub use tip_strategy::{BlockGasUsage, CongestionTipStrategy, NetworkConditions, TipStrategy};
+
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait DryRunner: Send + Sync {
     async fn dry_run_and_get_used_gas(&self, tx: FuelTransaction, tolerance: f32) -> Result<u64>;
     async fn estimate_gas_price(&self, block_horizon: u32) -> Result<u64>;
     fn consensus_parameters(&self) -> &ConsensusParameters;
+    /// Returns the resources among `ids` that are already spent or unknown to the node.
+    async fn spent_resources(&self, _ids: &[CoinTypeId]) -> Result<Vec<CoinTypeId>> {
+        Err(error!(
+            Other,
+            "this `DryRunner` cannot check whether resources are spent"
+        ))
+    }
+    /// Dry runs `tx` without validating its resources and returns the receipts, also those of a
+    /// reverted execution.
+    async fn dry_run_receipts(&self, _tx: FuelTransaction) -> Result<Vec<Receipt>> {
+        Err(error!(
+            Other,
+            "this `DryRunner` cannot return the receipts of a dry run"
+        ))
+    }
+    /// Returns the gas used by the latest `count` blocks, newest first.
+    async fn recent_blocks_gas(&self, _count: u32) -> Result<Vec<BlockGasUsage>> {
+        Err(error!(
+            Other,
+            "this `DryRunner` cannot return the gas of the latest blocks"
+        ))
+    }
+    /// Returns the size of the latest block the node posted to the DA layer divided by the size
+    /// of its transactions, `None` if unknown, e.g. because the node doesn't compress its blocks.
+    async fn latest_da_compression_ratio(&self) -> Result<Option<f64>> {
+        Ok(None)
+    }
+    /// Returns the cache to reuse predicate estimations from, if any.
+    fn predicate_estimation_cache(&self) -> Option<&PredicateEstimationCache> {
+        None
+    }
 }
 
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
@@ -59,6 +106,70 @@ impl<T: DryRunner> DryRunner for &T {
     fn consensus_parameters(&self) -> &ConsensusParameters {
         (*self).consensus_parameters()
     }
+
+    async fn spent_resources(&self, ids: &[CoinTypeId]) -> Result<Vec<CoinTypeId>> {
+        (*self).spent_resources(ids).await
+    }
+
+    async fn dry_run_receipts(&self, tx: FuelTransaction) -> Result<Vec<Receipt>> {
+        (*self).dry_run_receipts(tx).await
+    }
+
+    async fn recent_blocks_gas(&self, count: u32) -> Result<Vec<BlockGasUsage>> {
+        (*self).recent_blocks_gas(count).await
+    }
+
+    async fn latest_da_compression_ratio(&self) -> Result<Option<f64>> {
+        (*self).latest_da_compression_ratio().await
+    }
+
+    fn predicate_estimation_cache(&self) -> Option<&PredicateEstimationCache> {
+        (*self).predicate_estimation_cache()
+    }
+}
+
+/// Data availability cost of a transaction, i.e. the part of its cost that depends on its size
+/// rather than on its execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DaCost {
+    /// Size of the transaction in bytes, as metered by the fee calculation.
+    pub size: u64,
+    /// Estimated size of the transaction once compressed for the DA layer. The node compresses
+    /// whole blocks, so the size is derived from the compression ratio of the latest block it
+    /// posted. `None` if the node doesn't compress its blocks.
+    pub compressed_size: Option<u64>,
+    /// Gas charged for the transaction bytes (`size * gas_per_byte`).
+    pub gas: u64,
+    /// Fee component corresponding to `gas` at the estimated gas price.
+    pub fee: u64,
+}
+
+impl DaCost {
+    fn compute<T: Chargeable>(
+        tx: &T,
+        consensus_parameters: &ConsensusParameters,
+        gas_price: u64,
+        compression_ratio: Option<f64>,
+    ) -> Result<Self> {
+        let fee_params = consensus_parameters.fee_params();
+
+        let size = tx.metered_bytes_size() as u64;
+        let compressed_size = compression_ratio.map(|ratio| (size as f64 * ratio).ceil() as u64);
+
+        let gas = size
+            .checked_mul(fee_params.gas_per_byte())
+            .ok_or_else(|| error!(Other, "gas for transaction bytes overflowed"))?;
+        let fee = (gas as u128 * gas_price as u128).div_ceil(fee_params.gas_price_factor() as u128);
+        let fee = u64::try_from(fee)
+            .map_err(|_| error!(Other, "fee for transaction bytes overflowed"))?;
+
+        Ok(Self {
+            size,
+            compressed_size,
+            gas,
+            fee,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -121,7 +232,11 @@ pub trait TransactionBuilder: BuildableTransaction + Send + sealed::Sealed {
     fn add_signer(&mut self, signer: impl Signer + Send + Sync) -> Result<&mut Self>;
     async fn fee_checked_from_tx(&self, provider: impl DryRunner)
         -> Result<Option<TransactionFee>>;
+    /// Estimates the size dependent part of the transaction cost. Witnesses of added `Signer`s
+    /// are accounted for with placeholder signatures.
+    async fn estimate_da_cost(&self, provider: impl DryRunner) -> Result<DaCost>;
     fn with_tx_policies(self, tx_policies: TxPolicies) -> Self;
+    fn tx_policies_mut(&mut self) -> &mut TxPolicies;
     fn with_inputs(self, inputs: Vec<Input>) -> Self;
     fn with_outputs(self, outputs: Vec<Output>) -> Self;
     fn with_witnesses(self, witnesses: Vec<Witness>) -> Self;
@@ -131,11 +246,37 @@ pub trait TransactionBuilder: BuildableTransaction + Send + sealed::Sealed {
     fn outputs_mut(&mut self) -> &mut Vec<Output>;
     fn witnesses(&self) -> &Vec<Witness>;
     fn witnesses_mut(&mut self) -> &mut Vec<Witness>;
+    /// Returns the script data, `None` if the transaction has no script.
+    fn script_data(&self) -> Option<&[u8]>;
     fn with_estimation_horizon(self, block_horizon: u32) -> Self;
+    /// Makes `build` fail if any of the resources used as inputs is already spent. Requires an
+    /// additional request per resource.
+    fn with_spent_resources_check(self, enabled: bool) -> Self;
+    /// Limits the time each added `Signer` has to produce its signature during `build`.
+    fn with_signing_timeout(self, timeout: Duration) -> Self;
+    /// Requests the signatures of the added `Signer`s concurrently during `build`, with at most
+    /// `max_concurrent` requests in flight. Signatures are requested one at a time by default.
+    fn with_concurrent_signing(self, max_concurrent: NonZeroUsize) -> Self;
+    /// Reuses the `max_fee` estimated for similar transactions, see [`FeeEstimationCache`].
+    /// The gas price is estimated for every transaction by default.
+    fn with_fee_estimation_cache(self, cache: FeeEstimationCache) -> Self;
+    /// Lets `strategy` decide the tip per gas if the policies set neither a tip nor a tip per gas,
+    /// e.g. a [`CongestionTipStrategy`] to keep transactions landing when the network is
+    /// congested. Requires estimating the gas price and, if the strategy inspects blocks,
+    /// fetching the latest blocks and their transactions.
+    fn with_tip_strategy(self, strategy: impl TipStrategy + 'static) -> Self;
+    /// Gives access to the concrete builder, e.g. to pass it through an object-safe interface.
+    fn as_builder_mut(&mut self) -> TransactionBuilderMut<'_>;
+}
+
+/// A mutable reference to one of the [`TransactionBuilder`]s.
+pub enum TransactionBuilderMut<'a> {
+    Script(&'a mut ScriptTransactionBuilder),
+    Create(&'a mut CreateTransactionBuilder),
 }
 
 macro_rules! impl_tx_trait {
-    ($ty: ty, $tx_ty: ident) => {
+    ($ty: ty, $tx_ty: ident, $variant: ident) => {
         #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
         impl TransactionBuilder for $ty {
             type TxType = $tx_ty;
@@ -182,7 +323,12 @@ macro_rules! impl_tx_trait {
                 let consensus_parameters = provider.consensus_parameters();
 
                 if tx.is_using_predicates() {
-                    tx.estimate_predicates(consensus_parameters)?;
+                    match provider.predicate_estimation_cache() {
+                        Some(cache) => {
+                            tx.estimate_predicates_cached(consensus_parameters, cache)?;
+                        }
+                        None => tx.estimate_predicates(consensus_parameters)?,
+                    }
                 }
 
                 Ok(TransactionFee::checked_from_tx(
@@ -195,12 +341,40 @@ macro_rules! impl_tx_trait {
                 ))
             }
 
+            async fn estimate_da_cost(&self, provider: impl DryRunner) -> Result<DaCost> {
+                let mut estimation_tb = self.clone_without_signers();
+
+                let witness: Witness = Signature::default().as_ref().into();
+                estimation_tb
+                    .witnesses_mut()
+                    .extend(repeat(witness).take(self.unresolved_signers.len()));
+
+                let tx = BuildableTransaction::build_without_signatures(estimation_tb, &provider)
+                    .await?;
+
+                let gas_price = provider
+                    .estimate_gas_price(self.gas_price_estimation_block_horizon)
+                    .await?;
+                let compression_ratio = provider.latest_da_compression_ratio().await?;
+
+                DaCost::compute(
+                    &tx.tx,
+                    provider.consensus_parameters(),
+                    gas_price,
+                    compression_ratio,
+                )
+            }
+
             fn with_tx_policies(mut self, tx_policies: TxPolicies) -> Self {
                 self.tx_policies = tx_policies;
 
                 self
             }
 
+            fn tx_policies_mut(&mut self) -> &mut TxPolicies {
+                &mut self.tx_policies
+            }
+
             fn with_inputs(mut self, inputs: Vec<Input>) -> Self {
                 self.inputs = inputs;
                 self
@@ -240,11 +414,49 @@ macro_rules! impl_tx_trait {
                 &mut self.witnesses
             }
 
+            fn script_data(&self) -> Option<&[u8]> {
+                self.script_data_if_any()
+            }
+
             fn with_estimation_horizon(mut self, block_horizon: u32) -> Self {
                 self.gas_price_estimation_block_horizon = block_horizon;
 
                 self
             }
+
+            fn with_spent_resources_check(mut self, enabled: bool) -> Self {
+                self.check_spent_resources = enabled;
+
+                self
+            }
+
+            fn with_signing_timeout(mut self, timeout: Duration) -> Self {
+                self.signing_timeout = Some(timeout);
+
+                self
+            }
+
+            fn with_concurrent_signing(mut self, max_concurrent: NonZeroUsize) -> Self {
+                self.signing_concurrency = Some(max_concurrent);
+
+                self
+            }
+
+            fn with_fee_estimation_cache(mut self, cache: FeeEstimationCache) -> Self {
+                self.fee_estimation_cache = Some(cache);
+
+                self
+            }
+
+            fn with_tip_strategy(mut self, strategy: impl TipStrategy + 'static) -> Self {
+                self.tip_strategy = Some(Arc::new(strategy));
+
+                self
+            }
+
+            fn as_builder_mut(&mut self) -> TransactionBuilderMut<'_> {
+                TransactionBuilderMut::$variant(self)
+            }
         }
 
         impl $ty {
@@ -263,6 +475,38 @@ macro_rules! impl_tx_trait {
                     .collect();
             }
 
+            /// Sets the tip per gas decided by the tip strategy, if any, unless the policies set a
+            /// tip or a tip per gas. The tip is scaled by the gas of the transaction and capped at
+            /// the `max_fee` of the policies once the transaction is resolved.
+            async fn apply_tip_strategy(&mut self, provider: impl DryRunner) -> Result<()> {
+                let Some(strategy) = &self.tip_strategy else {
+                    return Ok(());
+                };
+                if self.tx_policies.tip().is_some() || self.tx_policies.tip_per_gas().is_some() {
+                    return Ok(());
+                }
+
+                let gas_price = provider
+                    .estimate_gas_price(self.gas_price_estimation_block_horizon)
+                    .await?;
+                let recent_blocks = match strategy.blocks_to_inspect() {
+                    0 => vec![],
+                    count => provider.recent_blocks_gas(count).await?,
+                };
+                let conditions = NetworkConditions {
+                    gas_price,
+                    recent_blocks,
+                };
+
+                let tip_per_gas = strategy.tip_per_gas(&conditions);
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(gas_price, tip_per_gas, "tip decided by the tip strategy");
+                self.tx_policies = self.tx_policies.with_tip_per_gas(tip_per_gas);
+
+                Ok(())
+            }
+
             fn generate_fuel_policies(&self) -> Result<Policies> {
                 let witness_limit = match self.tx_policies.witness_limit() {
                     Some(limit) => limit,
@@ -273,11 +517,110 @@ macro_rules! impl_tx_trait {
                 // `MaxFee` set to `tip` or `0` for `dry_run`
                 policies.set(PolicyType::MaxFee, self.tx_policies.tip().or(Some(0)));
                 policies.set(PolicyType::Maturity, self.tx_policies.maturity());
-                policies.set(PolicyType::Tip, self.tx_policies.tip());
+                // a tip per gas is only known once the gas of the transaction is, the placeholder
+                // keeps the size of the policies, and thus the input offsets, the same
+                let tip = self
+                    .tx_policies
+                    .tip()
+                    .or(self.tip_per_gas().map(|_| 0));
+                policies.set(PolicyType::Tip, tip);
 
                 Ok(policies)
             }
 
+            /// The tip per gas of the policies, unless they set an explicit tip.
+            fn tip_per_gas(&self) -> Option<f64> {
+                match self.tx_policies.tip() {
+                    Some(_) => None,
+                    None => self.tx_policies.tip_per_gas(),
+                }
+            }
+
+            /// Sets the tip of `tx` to `tip_per_gas` for every unit of its max gas, rounded up and
+            /// capped at `fee_budget`.
+            fn set_tip_policy<T: PoliciesField + Chargeable>(
+                tx: &mut T,
+                consensus_parameters: &ConsensusParameters,
+                tip_per_gas: f64,
+                fee_budget: Option<u64>,
+            ) {
+                let max_gas = tx.max_gas(
+                    consensus_parameters.gas_costs(),
+                    consensus_parameters.fee_params(),
+                );
+                let mut tip = (tip_per_gas * max_gas as f64).ceil() as u64;
+                if let Some(fee_budget) = fee_budget {
+                    tip = tip.min(fee_budget);
+                }
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(tip_per_gas, max_gas, tip, "tip scaled to the gas of the tx");
+                tx.policies_mut().set(PolicyType::Tip, Some(tip));
+            }
+
+            /// Fails if the policies are inconsistent, see [`TxPolicies::validate`], or if the
+            /// `witness_limit` is too small for the witnesses and signatures of the transaction.
+            fn validate_policies(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+                self.tx_policies.validate(consensus_parameters)?;
+
+                if let Some(witness_limit) = self.tx_policies.witness_limit() {
+                    let witnesses_size = self.calculate_witnesses_size()?;
+                    if witness_limit < witnesses_size {
+                        return Err(error_transaction!(
+                            Builder,
+                            "`witness_limit` ({witness_limit}) is too small for the witnesses and signatures of the transaction, which need {witnesses_size} bytes. Raise it or leave it unset to have it estimated"
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// Fails if a resource or a contract is used as input more than once or, if enabled,
+            /// if a resource is already spent.
+            async fn validate_inputs(&self, provider: impl DryRunner) -> Result<()> {
+                let mut resource_ids = vec![];
+                let mut contract_ids = HashSet::new();
+
+                for input in self.inputs() {
+                    match input {
+                        Input::ResourceSigned { resource }
+                        | Input::ResourcePredicate { resource, .. } => {
+                            let id = resource.id();
+                            if resource_ids.contains(&id) {
+                                return Err(error_transaction!(
+                                    Builder,
+                                    "{id} is used as input more than once"
+                                ));
+                            }
+                            resource_ids.push(id);
+                        }
+                        Input::Contract { contract_id, .. } => {
+                            if !contract_ids.insert(*contract_id) {
+                                return Err(error_transaction!(
+                                    Builder,
+                                    "contract `{contract_id}` is used as input more than once"
+                                ));
+                            }
+                        }
+                    }
+                }
+
+                if self.check_spent_resources && !resource_ids.is_empty() {
+                    let spent = provider.spent_resources(&resource_ids).await?;
+
+                    if !spent.is_empty() {
+                        return Err(error_transaction!(
+                            Builder,
+                            "inputs are already spent or unknown to the node: {}",
+                            spent.iter().join(", ")
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+
             fn is_using_predicates(&self) -> bool {
                 self.inputs()
                     .iter()
@@ -298,7 +641,7 @@ macro_rules! impl_tx_trait {
             }
 
             fn calculate_witnesses_size(&self) -> Result<u64> {
-                let witnesses_size = calculate_witnesses_size(&self.witnesses);
+                let witnesses_size = witnesses_size(&self.witnesses);
                 let signature_size = SIGNATURE_WITNESS_SIZE
                     * self.unresolved_witness_indexes.owner_to_idx_offset.len();
 
@@ -307,27 +650,71 @@ macro_rules! impl_tx_trait {
                 Ok(padded_len as u64)
             }
 
-            async fn set_max_fee_policy<T: PoliciesField + Chargeable>(
+            /// Sets the max fee to the estimated fee of `tx`, or to the one cached for a similar
+            /// transaction. Fails if `fee_budget`, the `max_fee` of the user's policies, can't
+            /// even cover the minimum fee of the transaction.
+            async fn set_max_fee_policy<T: Inputs + Outputs + PoliciesField + Chargeable>(
                 tx: &mut T,
                 provider: impl DryRunner,
                 block_horizon: u32,
+                fee_budget: Option<u64>,
+                cache: Option<&FeeEstimationCache>,
             ) -> Result<()> {
-                let gas_price = provider.estimate_gas_price(block_horizon).await?;
                 let consensus_parameters = provider.consensus_parameters();
-
-                let tx_fee = TransactionFee::checked_from_tx(
-                    &consensus_parameters.gas_costs(),
-                    consensus_parameters.fee_params(),
-                    tx,
+                let cache_key = cache.map(|_| {
+                    FeeEstimationKey::new(&*tx, consensus_parameters, block_horizon)
+                });
+                let cached = cache.zip(cache_key.as_ref()).and_then(|(cache, key)| cache.get(key));
+
+                let estimate = match cached {
+                    Some(estimate) => estimate,
+                    None => {
+                        let gas_price = provider.estimate_gas_price(block_horizon).await?;
+
+                        let tx_fee = TransactionFee::checked_from_tx(
+                            &consensus_parameters.gas_costs(),
+                            consensus_parameters.fee_params(),
+                            tx,
+                            gas_price,
+                        )
+                        .ok_or(error_transaction!(
+                            Other,
+                            "error calculating `TransactionFee` in `TransactionBuilder`"
+                        ))?;
+                        let estimate =
+                            FeeEstimate::new(gas_price, tx_fee.min_fee(), tx_fee.max_fee());
+
+                        if let Some((cache, key)) = cache.zip(cache_key) {
+                            cache.insert(key, estimate);
+                        }
+
+                        estimate
+                    }
+                };
+                let FeeEstimate {
                     gas_price,
-                )
-                .ok_or(error_transaction!(
-                    Other,
-                    "error calculating `TransactionFee` in `TransactionBuilder`"
-                ))?;
+                    min_fee,
+                    max_fee,
+                    ..
+                } = estimate;
+
+                if let Some(fee_budget) = fee_budget {
+                    if fee_budget < min_fee {
+                        return Err(error_transaction!(
+                            Builder,
+                            "`max_fee` ({fee_budget}) is below the minimum fee of the transaction ({min_fee}) at gas price {gas_price}. Raise it or leave it unset to have it estimated"
+                        ));
+                    }
+                }
 
-                tx.policies_mut()
-                    .set(PolicyType::MaxFee, Some(tx_fee.max_fee()));
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    gas_price,
+                    max_fee,
+                    cached = cached.is_some(),
+                    "estimated max fee"
+                );
+                tx.policies_mut().set(PolicyType::MaxFee, Some(max_fee));
 
                 Ok(())
             }
@@ -343,6 +730,27 @@ impl Debug for dyn Signer + Send + Sync {
     }
 }
 
+/// Determines how many [`Output::Variable`]s a [`ScriptTransactionBuilder`] adds to the
+/// transaction. Variable outputs receive the coins transferred to addresses during the execution,
+/// e.g. by a contract minting to an address. They are added on top of the builder's outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableOutputPolicy {
+    /// Adds the given number of variable outputs.
+    Exactly(usize),
+    /// Dry runs the transaction, adding a variable output whenever a transfer fails for lack of
+    /// one, until no transfer fails. Requires a dry run per needed variable output.
+    EstimateMinimum,
+    /// Dry runs the transaction once with as many variable outputs as it can hold and adds one
+    /// for every transfer to an address found in the receipts.
+    PerTransferInLogs,
+}
+
+impl Default for VariableOutputPolicy {
+    fn default() -> Self {
+        Self::Exactly(0)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct ScriptTransactionBuilder {
     pub script: Vec<u8>,
@@ -353,6 +761,12 @@ pub struct ScriptTransactionBuilder {
     pub tx_policies: TxPolicies,
     pub gas_estimation_tolerance: f32,
     pub gas_price_estimation_block_horizon: u32,
+    pub variable_output_policy: VariableOutputPolicy,
+    check_spent_resources: bool,
+    signing_timeout: Option<Duration>,
+    signing_concurrency: Option<NonZeroUsize>,
+    fee_estimation_cache: Option<FeeEstimationCache>,
+    tip_strategy: Option<Arc<dyn TipStrategy>>,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
 }
@@ -368,15 +782,28 @@ pub struct CreateTransactionBuilder {
     pub tx_policies: TxPolicies,
     pub salt: Salt,
     pub gas_price_estimation_block_horizon: u32,
+    check_spent_resources: bool,
+    signing_timeout: Option<Duration>,
+    signing_concurrency: Option<NonZeroUsize>,
+    fee_estimation_cache: Option<FeeEstimationCache>,
+    tip_strategy: Option<Arc<dyn TipStrategy>>,
     unresolved_witness_indexes: UnresolvedWitnessIndexes,
     unresolved_signers: Vec<Box<dyn Signer + Send + Sync>>,
 }
 
-impl_tx_trait!(ScriptTransactionBuilder, ScriptTransaction);
-impl_tx_trait!(CreateTransactionBuilder, CreateTransaction);
+impl_tx_trait!(ScriptTransactionBuilder, ScriptTransaction, Script);
+impl_tx_trait!(CreateTransactionBuilder, CreateTransaction, Create);
 
 impl ScriptTransactionBuilder {
-    async fn build(self, provider: impl DryRunner) -> Result<ScriptTransaction> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_type = "script"))
+    )]
+    async fn build(mut self, provider: impl DryRunner) -> Result<ScriptTransaction> {
+        self.apply_tip_strategy(&provider).await?;
+        self.validate_policies(provider.consensus_parameters())?;
+        self.validate_inputs(&provider).await?;
+
         let is_using_predicates = self.is_using_predicates();
         let base_offset = if is_using_predicates {
             self.base_offset(provider.consensus_parameters())?
@@ -427,8 +854,31 @@ impl ScriptTransactionBuilder {
         provider: impl DryRunner,
         tolerance: f32,
     ) -> Result<()> {
-        let consensus_params = provider.consensus_parameters();
-        let base_asset_id = provider.consensus_parameters().base_asset_id();
+        let no_base_asset_input = Self::prepare_for_dry_run(tx, provider.consensus_parameters());
+
+        let gas_used = provider
+            .dry_run_and_get_used_gas(tx.clone().into(), tolerance)
+            .await?;
+
+        // Remove dry-run input and witness.
+        if no_base_asset_input {
+            tx.inputs_mut().pop();
+            tx.witnesses_mut().pop();
+            tx.set_witness_limit(tx.witness_limit() - WITNESS_STATIC_SIZE as u64);
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(gas_used, "estimated script gas limit");
+        *tx.script_gas_limit_mut() = gas_used;
+
+        Ok(())
+    }
+
+    /// Adds a temporary base asset input if there is none, as required by the dry-run
+    /// validation, and raises the `script_gas_limit` to the maximum allowed value. Returns
+    /// whether the input was added.
+    fn prepare_for_dry_run(tx: &mut Script, consensus_params: &ConsensusParameters) -> bool {
+        let base_asset_id = consensus_params.base_asset_id();
 
         // The dry-run validation will check if there is any base asset input.
         // If we are dry-running without inputs we have to add a temporary one.
@@ -454,18 +904,59 @@ impl ScriptTransactionBuilder {
         // Increase `script_gas_limit` to the maximum allowed value.
         *tx.script_gas_limit_mut() = consensus_params.tx_params().max_gas_per_tx() - max_gas;
 
-        let gas_used = provider
-            .dry_run_and_get_used_gas(tx.clone().into(), tolerance)
-            .await?;
+        no_base_asset_input
+    }
 
-        // Remove dry-run input and witness.
-        if no_base_asset_input {
-            tx.inputs_mut().pop();
-            tx.witnesses_mut().pop();
-            tx.set_witness_limit(tx.witness_limit() - WITNESS_STATIC_SIZE as u64);
-        }
+    async fn dry_run_with_variable_outputs(
+        tx: &Script,
+        num_variable_outputs: usize,
+        provider: impl DryRunner,
+    ) -> Result<Vec<Receipt>> {
+        let mut tx = tx.clone();
+        tx.outputs_mut()
+            .extend(new_variable_outputs(num_variable_outputs));
+        Self::prepare_for_dry_run(&mut tx, provider.consensus_parameters());
 
-        *tx.script_gas_limit_mut() = gas_used;
+        provider.dry_run_receipts(tx.into()).await
+    }
+
+    async fn add_variable_outputs(
+        tx: &mut Script,
+        policy: VariableOutputPolicy,
+        provider: impl DryRunner,
+    ) -> Result<()> {
+        let max_outputs = provider.consensus_parameters().tx_params().max_outputs() as usize;
+        let available = max_outputs.saturating_sub(tx.outputs().len());
+
+        let num_variable_outputs = match policy {
+            VariableOutputPolicy::Exactly(num) => num,
+            VariableOutputPolicy::EstimateMinimum => {
+                let mut num = 0;
+                while num < available {
+                    let receipts = Self::dry_run_with_variable_outputs(tx, num, &provider).await?;
+                    let transfer_failed = receipts.iter().any(|r| {
+                        matches!(r, Receipt::Revert { ra, .. } if *ra == FAILED_TRANSFER_TO_ADDRESS_SIGNAL)
+                    });
+                    if !transfer_failed {
+                        break;
+                    }
+                    num += 1;
+                }
+                num
+            }
+            VariableOutputPolicy::PerTransferInLogs => {
+                Self::dry_run_with_variable_outputs(tx, available, &provider)
+                    .await?
+                    .iter()
+                    .filter(|r| matches!(r, Receipt::TransferOut { .. }))
+                    .count()
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(num_variable_outputs, "added variable outputs");
+        tx.outputs_mut()
+            .extend(new_variable_outputs(num_variable_outputs));
 
         Ok(())
     }
@@ -491,6 +982,10 @@ impl ScriptTransactionBuilder {
             dry_run_witnesses,
         );
 
+        if !has_no_code {
+            Self::add_variable_outputs(&mut tx, self.variable_output_policy, &provider).await?;
+        }
+
         if has_no_code {
             *tx.script_gas_limit_mut() = 0;
 
@@ -509,12 +1004,30 @@ impl ScriptTransactionBuilder {
             .await?
         };
 
-        Self::set_max_fee_policy(&mut tx, &provider, self.gas_price_estimation_block_horizon)
-            .await?;
+        if let Some(tip_per_gas) = self.tip_per_gas() {
+            Self::set_tip_policy(
+                &mut tx,
+                provider.consensus_parameters(),
+                tip_per_gas,
+                self.tx_policies.max_fee(),
+            );
+        }
+
+        Self::set_max_fee_policy(
+            &mut tx,
+            &provider,
+            self.gas_price_estimation_block_horizon,
+            self.tx_policies.max_fee(),
+            self.fee_estimation_cache.as_ref(),
+        )
+        .await?;
 
         let missing_witnesses = generate_missing_witnesses(
-            tx.id(&provider.consensus_parameters().chain_id()),
+            &tx.clone().into(),
+            &provider.consensus_parameters().chain_id(),
             &self.unresolved_signers,
+            self.signing_timeout,
+            self.signing_concurrency,
         )
         .await?;
         *tx.witnesses_mut() = [self.witnesses, missing_witnesses].concat();
@@ -548,6 +1061,11 @@ impl ScriptTransactionBuilder {
         self
     }
 
+    pub fn with_variable_output_policy(mut self, policy: VariableOutputPolicy) -> Self {
+        self.variable_output_policy = policy;
+        self
+    }
+
     pub fn prepare_transfer(
         inputs: Vec<Input>,
         outputs: Vec<Output>,
@@ -560,6 +1078,12 @@ impl ScriptTransactionBuilder {
     }
 
     /// Craft a transaction used to transfer funds to a contract.
+    ///
+    /// The script transfers `amount` of `asset_id` to the contract `to`. Besides the resources
+    /// paying for the transfer, `inputs` must contain an [`Input::contract`] for `to`, matched in
+    /// `outputs` by an [`Output::contract`] pointing to the index of that input. Any other
+    /// inputs, outputs and signers can be added to the transaction, e.g. to top up the contract
+    /// and pay someone in the same transaction.
     pub fn prepare_contract_transfer(
         to: ContractId,
         amount: u64,
@@ -640,6 +1164,10 @@ impl ScriptTransactionBuilder {
             .with_outputs(outputs)
     }
 
+    fn script_data_if_any(&self) -> Option<&[u8]> {
+        Some(&self.script_data)
+    }
+
     fn clone_without_signers(&self) -> Self {
         Self {
             script: self.script.clone(),
@@ -649,6 +1177,12 @@ impl ScriptTransactionBuilder {
             witnesses: self.witnesses.clone(),
             tx_policies: self.tx_policies,
             gas_estimation_tolerance: self.gas_estimation_tolerance,
+            variable_output_policy: self.variable_output_policy,
+            check_spent_resources: self.check_spent_resources,
+            signing_timeout: self.signing_timeout,
+            signing_concurrency: self.signing_concurrency,
+            fee_estimation_cache: self.fee_estimation_cache.clone(),
+            tip_strategy: self.tip_strategy.clone(),
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
@@ -657,7 +1191,15 @@ impl ScriptTransactionBuilder {
 }
 
 impl CreateTransactionBuilder {
-    pub async fn build(self, provider: impl DryRunner) -> Result<CreateTransaction> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(tx_type = "create"))
+    )]
+    pub async fn build(mut self, provider: impl DryRunner) -> Result<CreateTransaction> {
+        self.apply_tip_strategy(&provider).await?;
+        self.validate_policies(provider.consensus_parameters())?;
+        self.validate_inputs(&provider).await?;
+
         let consensus_parameters = provider.consensus_parameters();
 
         let is_using_predicates = self.is_using_predicates();
@@ -704,11 +1246,32 @@ impl CreateTransactionBuilder {
             self.witnesses,
         );
 
-        Self::set_max_fee_policy(&mut tx, provider, self.gas_price_estimation_block_horizon)
-            .await?;
+        if let Some(tip_per_gas) = self.tip_per_gas() {
+            Self::set_tip_policy(
+                &mut tx,
+                provider.consensus_parameters(),
+                tip_per_gas,
+                self.tx_policies.max_fee(),
+            );
+        }
+
+        Self::set_max_fee_policy(
+            &mut tx,
+            &provider,
+            self.gas_price_estimation_block_horizon,
+            self.tx_policies.max_fee(),
+            self.fee_estimation_cache.as_ref(),
+        )
+        .await?;
 
-        let missing_witnesses =
-            generate_missing_witnesses(tx.id(chain_id), &self.unresolved_signers).await?;
+        let missing_witnesses = generate_missing_witnesses(
+            &tx.clone().into(),
+            chain_id,
+            &self.unresolved_signers,
+            self.signing_timeout,
+            self.signing_concurrency,
+        )
+        .await?;
         tx.witnesses_mut().extend(missing_witnesses);
 
         Ok(tx)
@@ -762,6 +1325,10 @@ impl CreateTransactionBuilder {
             .with_witnesses(witnesses)
     }
 
+    fn script_data_if_any(&self) -> Option<&[u8]> {
+        None
+    }
+
     fn clone_without_signers(&self) -> Self {
         Self {
             bytecode_length: self.bytecode_length,
@@ -772,6 +1339,11 @@ impl CreateTransactionBuilder {
             witnesses: self.witnesses.clone(),
             tx_policies: self.tx_policies,
             salt: self.salt,
+            check_spent_resources: self.check_spent_resources,
+            signing_timeout: self.signing_timeout,
+            signing_concurrency: self.signing_concurrency,
+            fee_estimation_cache: self.fee_estimation_cache.clone(),
+            tip_strategy: self.tip_strategy.clone(),
             unresolved_witness_indexes: self.unresolved_witness_indexes.clone(),
             unresolved_signers: Default::default(),
             gas_price_estimation_block_horizon: self.gas_price_estimation_block_horizon,
@@ -967,30 +1539,83 @@ pub fn create_coin_message_predicate(
     }
 }
 
+fn new_variable_outputs(num: usize) -> impl Iterator<Item = Output> {
+    repeat(Output::Variable {
+        amount: 0,
+        to: Address::zeroed(),
+        asset_id: AssetId::zeroed(),
+    })
+    .take(num)
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        level = "debug",
+        skip_all,
+        fields(tx_id = %tx.id(chain_id), signers = unresolved_signatures.len())
+    )
+)]
+/// Requests a signature of `tx` from each signer, at most `concurrency` at a time. The
+/// witnesses are returned in the order of the signers.
 async fn generate_missing_witnesses(
-    id: Bytes32,
+    tx: &FuelTransaction,
+    chain_id: &ChainId,
     unresolved_signatures: &[Box<dyn Signer + Send + Sync>],
+    signing_timeout: Option<Duration>,
+    concurrency: Option<NonZeroUsize>,
 ) -> Result<Vec<Witness>> {
-    let mut witnesses = Vec::with_capacity(unresolved_signatures.len());
-    for signer in unresolved_signatures {
-        let message = CryptoMessage::from_bytes(*id);
-        let signature = signer.sign(message).await?;
+    let message = message_for_tx_id(&tx.id(chain_id));
+
+    stream::iter(unresolved_signatures)
+        .map(|signer| sign_with_timeout(signer.as_ref(), tx, message, signing_timeout))
+        .buffered(concurrency.map_or(1, NonZeroUsize::get))
+        .map_ok(|signature| witness_from_signature(&signature))
+        .try_collect()
+        .await
+}
 
-        witnesses.push(signature.as_ref().into());
-    }
+/// Signs `tx`, whose id is `message`, attributing failures and timeouts to the signer.
+async fn sign_with_timeout(
+    signer: &(dyn Signer + Send + Sync),
+    tx: &FuelTransaction,
+    message: CryptoMessage,
+    timeout: Option<Duration>,
+) -> Result<Signature> {
+    let address = signer.address();
+
+    let result = match timeout {
+        Some(timeout) => utils::timeout(timeout, signer.sign_transaction(tx, message))
+            .await
+            .ok_or_else(|| {
+                Error::Transaction(Reason::SignerTimedOut {
+                    address: address.clone(),
+                    timeout,
+                })
+            })?,
+        None => signer.sign_transaction(tx, message).await,
+    };
 
-    Ok(witnesses)
+    result.map_err(|err| {
+        Error::Transaction(Reason::SignerFailed {
+            address: address.clone(),
+            source: Box::new(err),
+        })
+    })
 }
 
 #[cfg(test)]
 mod tests {
-    use std::iter::repeat_with;
+    use std::{
+        iter::repeat_with,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
 
     use fuel_crypto::Signature;
     use fuel_tx::{input::coin::CoinSigned, UtxoId};
 
     use super::*;
-    use crate::types::{bech32::Bech32Address, message::MessageStatus};
+    use crate::types::message::MessageStatus;
 
     #[test]
     fn storage_slots_are_sorted_when_set() {
@@ -1074,12 +1699,18 @@ mod tests {
 
     struct MockDryRunner {
         c_param: ConsensusParameters,
+        spent_resources: Vec<CoinTypeId>,
+        gas_price_estimations: AtomicUsize,
+        predicate_estimation_cache: Option<PredicateEstimationCache>,
     }
 
     impl Default for MockDryRunner {
         fn default() -> Self {
             Self {
                 c_param: ConsensusParameters::standard(),
+                spent_resources: vec![],
+                gas_price_estimations: AtomicUsize::new(0),
+                predicate_estimation_cache: None,
             }
         }
     }
@@ -1096,8 +1727,186 @@ mod tests {
         }
 
         async fn estimate_gas_price(&self, _block_horizon: u32) -> Result<u64> {
+            self.gas_price_estimations.fetch_add(1, Ordering::Relaxed);
             Ok(0)
         }
+
+        async fn spent_resources(&self, ids: &[CoinTypeId]) -> Result<Vec<CoinTypeId>> {
+            Ok(ids
+                .iter()
+                .filter(|id| self.spent_resources.contains(id))
+                .cloned()
+                .collect())
+        }
+
+        fn predicate_estimation_cache(&self) -> Option<&PredicateEstimationCache> {
+            self.predicate_estimation_cache.as_ref()
+        }
+    }
+
+    #[tokio::test]
+    async fn duplicate_inputs_are_rejected() {
+        let mut inputs = given_inputs(2);
+        let duplicate = inputs[0].clone();
+        inputs.push(duplicate);
+
+        let err = ScriptTransactionBuilder::default()
+            .with_inputs(inputs)
+            .build(&MockDryRunner::default())
+            .await
+            .expect_err("should fail");
+
+        let expected_id = CoinTypeId::UtxoId(UtxoId::new([0; 32].into(), 0));
+        assert!(err
+            .to_string()
+            .contains(&format!("{expected_id} is used as input more than once")));
+    }
+
+    #[tokio::test]
+    async fn spent_inputs_are_rejected_if_check_is_enabled() -> Result<()> {
+        let spent_id = CoinTypeId::UtxoId(UtxoId::new([1; 32].into(), 0));
+        let provider = MockDryRunner {
+            spent_resources: vec![spent_id.clone()],
+            ..Default::default()
+        };
+
+        CreateTransactionBuilder::default()
+            .with_inputs(given_inputs(2))
+            .build_without_signatures(&provider)
+            .await?;
+
+        let err = CreateTransactionBuilder::default()
+            .with_inputs(given_inputs(2))
+            .with_spent_resources_check(true)
+            .build_without_signatures(&provider)
+            .await
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains(&format!("already spent or unknown to the node: {spent_id}")));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn builders_reuse_the_predicate_estimation_cache_of_the_provider() -> Result<()> {
+        let cache = PredicateEstimationCache::new();
+        let provider = MockDryRunner {
+            predicate_estimation_cache: Some(cache.clone()),
+            ..Default::default()
+        };
+
+        let code: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let coin = CoinType::Coin(Coin {
+            owner: Bech32Address::new("fuel", FuelInput::predicate_owner(&code)),
+            ..Default::default()
+        });
+        let tb = CreateTransactionBuilder::default().with_inputs(vec![Input::resource_predicate(
+            coin,
+            code,
+            UnresolvedBytes::default(),
+        )]);
+
+        tb.fee_checked_from_tx(&provider).await?;
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        tb.fee_checked_from_tx(&MockDryRunner::default()).await?;
+        assert!(cache.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tip_per_gas_is_scaled_by_the_max_gas_of_the_tx() -> Result<()> {
+        let provider = MockDryRunner::default();
+
+        let tx = CreateTransactionBuilder::default()
+            .with_inputs(given_inputs(2))
+            .with_tx_policies(TxPolicies::default().with_tip_per_gas(0.5))
+            .build_without_signatures(&provider)
+            .await?;
+
+        let max_gas = tx.max_gas(&provider.c_param);
+        assert!(max_gas > 0);
+        assert_eq!(
+            tx.cost_breakdown(&provider.c_param).tip,
+            max_gas.div_ceil(2)
+        );
+
+        let tx = CreateTransactionBuilder::default()
+            .with_inputs(given_inputs(2))
+            .with_tx_policies(TxPolicies::default().with_tip(3).with_tip_per_gas(0.5))
+            .build_without_signatures(&provider)
+            .await?;
+
+        assert_eq!(tx.cost_breakdown(&provider.c_param).tip, 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fee_estimates_are_reused_for_similar_transactions() -> Result<()> {
+        let provider = MockDryRunner::default();
+
+        // ANCHOR: fee_estimation_cache
+        let cache = FeeEstimationCache::new(Duration::from_secs(60));
+
+        for num_inputs in [2, 2, 3] {
+            CreateTransactionBuilder::default()
+                .with_inputs(given_inputs(num_inputs))
+                .with_fee_estimation_cache(cache.clone())
+                .build_without_signatures(&provider)
+                .await?;
+        }
+        // ANCHOR_END: fee_estimation_cache
+
+        assert_eq!(provider.gas_price_estimations.load(Ordering::Relaxed), 2);
+        assert_eq!(cache.len(), 2);
+
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct FixedTipPerGas(f64);
+
+    impl TipStrategy for FixedTipPerGas {
+        fn blocks_to_inspect(&self) -> u32 {
+            0
+        }
+
+        fn tip_per_gas(&self, _conditions: &NetworkConditions) -> f64 {
+            self.0
+        }
+    }
+
+    #[tokio::test]
+    async fn tip_strategy_is_used_unless_a_tip_is_set() -> Result<()> {
+        let provider = MockDryRunner::default();
+
+        let tx = CreateTransactionBuilder::default()
+            .with_tip_strategy(FixedTipPerGas(2.0))
+            .build(&provider)
+            .await?;
+        let max_gas = tx.max_gas(&provider.c_param);
+        assert_eq!(tx.tx.policies().get(PolicyType::Tip), Some(2 * max_gas));
+
+        let tx = CreateTransactionBuilder::default()
+            .with_tx_policies(TxPolicies::default().with_tip(7))
+            .with_tip_strategy(FixedTipPerGas(2.0))
+            .build(&provider)
+            .await?;
+        assert_eq!(tx.tx.policies().get(PolicyType::Tip), Some(7));
+
+        let tx = CreateTransactionBuilder::default()
+            .with_tx_policies(TxPolicies::default().with_max_fee(10))
+            .with_tip_strategy(FixedTipPerGas(2.0))
+            .build(&provider)
+            .await?;
+        assert_eq!(tx.tx.policies().get(PolicyType::Tip), Some(10));
+
+        Ok(())
     }
 
     #[tokio::test]
@@ -1170,6 +1979,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn da_cost_charges_metered_bytes() -> Result<()> {
+        // given
+        let mut consensus_parameters = ConsensusParameters::standard();
+        consensus_parameters.set_fee_params(
+            consensus_parameters
+                .fee_params()
+                .with_gas_per_byte(4)
+                .with_gas_price_factor(10),
+        );
+        let gas_price = 3;
+
+        let witness: Witness = vec![0; 1024].into();
+        let tx = FuelTransaction::create(
+            0,
+            Policies::default(),
+            Salt::zeroed(),
+            vec![],
+            vec![],
+            vec![],
+            vec![witness],
+        );
+
+        // when
+        let da_cost = DaCost::compute(&tx, &consensus_parameters, gas_price, Some(0.25))?;
+
+        // then
+        let size = tx.metered_bytes_size() as u64;
+        assert_eq!(da_cost.size, size);
+        assert_eq!(da_cost.compressed_size, Some(size.div_ceil(4)));
+        assert_eq!(da_cost.gas, size * 4);
+        assert_eq!(da_cost.fee, (size * 4 * gas_price).div_ceil(10));
+
+        Ok(())
+    }
+
     #[derive(Clone, Debug, Default)]
     struct MockSigner {
         address: Bech32Address,
@@ -1195,4 +2040,125 @@ mod tests {
         tb.add_signer(signer.clone()).unwrap();
         tb.add_signer(signer.clone()).unwrap();
     }
+
+    #[derive(Debug)]
+    struct UnresponsiveSigner {
+        address: Bech32Address,
+        delay: Duration,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Signer for UnresponsiveSigner {
+        async fn sign(&self, _message: CryptoMessage) -> Result<Signature> {
+            tokio::time::sleep(self.delay).await;
+
+            Err(error!(Other, "device disconnected"))
+        }
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
+    #[tokio::test]
+    async fn signer_errors_are_attributed_to_the_signer() {
+        let tx = FuelTransaction::default();
+        let address = Bech32Address::new("fuel", [1; 32]);
+        let signers: Vec<Box<dyn Signer + Send + Sync>> = vec![
+            Box::new(MockSigner::default()),
+            Box::new(UnresponsiveSigner {
+                address: address.clone(),
+                delay: Duration::ZERO,
+            }),
+        ];
+
+        let err = generate_missing_witnesses(&tx, &ChainId::default(), &signers, None, None)
+            .await
+            .expect_err("should fail");
+
+        let Error::Transaction(Reason::SignerFailed {
+            address: failed_signer,
+            source,
+        }) = err
+        else {
+            panic!("expected a signer error, got: {err}");
+        };
+        assert_eq!(failed_signer, address);
+        assert_eq!(source.to_string(), "device disconnected");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn slow_signers_time_out() {
+        let tx = FuelTransaction::default();
+        let address = Bech32Address::new("fuel", [1; 32]);
+        let signers: Vec<Box<dyn Signer + Send + Sync>> = vec![Box::new(UnresponsiveSigner {
+            address: address.clone(),
+            delay: Duration::from_secs(60),
+        })];
+        let timeout = Duration::from_secs(5);
+
+        let err =
+            generate_missing_witnesses(&tx, &ChainId::default(), &signers, Some(timeout), None)
+                .await
+                .expect_err("should fail");
+
+        assert!(matches!(
+            err,
+            Error::Transaction(Reason::SignerTimedOut { address: timed_out_signer, timeout: elapsed })
+                if timed_out_signer == address && elapsed == timeout
+        ));
+    }
+
+    #[derive(Debug)]
+    struct DelayedSigner {
+        address: Bech32Address,
+        delay: Duration,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+    impl Signer for DelayedSigner {
+        async fn sign(&self, _message: CryptoMessage) -> Result<Signature> {
+            tokio::time::sleep(self.delay).await;
+
+            Ok(Signature::from_bytes([self.address.hash()[0]; 64]))
+        }
+        fn address(&self) -> &Bech32Address {
+            &self.address
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn signatures_are_collected_concurrently_in_signer_order() -> Result<()> {
+        let tx = FuelTransaction::default();
+        let delays = [3, 1, 2].map(Duration::from_secs);
+        let signers: Vec<Box<dyn Signer + Send + Sync>> = delays
+            .iter()
+            .zip(1u8..)
+            .map(|(delay, byte)| {
+                Box::new(DelayedSigner {
+                    address: Bech32Address::new("fuel", [byte; 32]),
+                    delay: *delay,
+                }) as Box<dyn Signer + Send + Sync>
+            })
+            .collect();
+
+        let start = tokio::time::Instant::now();
+        let witnesses = generate_missing_witnesses(
+            &tx,
+            &ChainId::default(),
+            &signers,
+            None,
+            NonZeroUsize::new(3),
+        )
+        .await?;
+
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+        let expected_witnesses: Vec<Witness> = (1u8..=3)
+            .map(|byte| Signature::from_bytes([byte; 64]).as_ref().into())
+            .collect();
+        assert_eq!(witnesses, expected_witnesses);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,215 @@
+use std::{
+    collections::HashMap,
+    mem::{discriminant, Discriminant},
+    sync::{Arc, Mutex, PoisonError},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use fuel_tx::{
+    field::{Inputs, Outputs, Policies as PoliciesField},
+    policies::PolicyType,
+    Chargeable, ConsensusParameters, Input as FuelInput, Output,
+};
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Caches the `max_fee` estimated by the transaction builders, so that building many similar
+/// transactions doesn't require estimating the gas price every time.
+///
+/// Transactions share an estimate if they have the same inputs and outputs, in kind and order,
+/// the same size and gas limits, the same policies and the same gas price estimation horizon.
+/// Estimates are reused for at most `max_age`, after which the gas price is estimated again.
+/// Clones share the cache, so one cache can be given to every builder of a bot.
+#[derive(Debug, Clone)]
+pub struct FeeEstimationCache {
+    max_age: Duration,
+    capacity: usize,
+    entries: Arc<Mutex<HashMap<FeeEstimationKey, FeeEstimate>>>,
+}
+
+impl FeeEstimationCache {
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            capacity: DEFAULT_CAPACITY,
+            entries: Default::default(),
+        }
+    }
+
+    /// Sets how many estimates are kept, 1024 by default. The oldest estimate is evicted when
+    /// the cache is full.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Returns the number of estimates in the cache, including those older than `max_age`.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    pub(crate) fn get(&self, key: &FeeEstimationKey) -> Option<FeeEstimate> {
+        self.entries()
+            .get(key)
+            .filter(|estimate| self.is_fresh(estimate, Utc::now()))
+            .copied()
+    }
+
+    pub(crate) fn insert(&self, key: FeeEstimationKey, estimate: FeeEstimate) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries();
+
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            let now = Utc::now();
+            entries.retain(|_, estimate| self.is_fresh(estimate, now));
+
+            if entries.len() >= self.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, estimate)| estimate.estimated_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+
+        entries.insert(key, estimate);
+    }
+
+    fn is_fresh(&self, estimate: &FeeEstimate, now: DateTime<Utc>) -> bool {
+        let age = (now - estimate.estimated_at).to_std().unwrap_or_default();
+
+        age <= self.max_age
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<FeeEstimationKey, FeeEstimate>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// The shape of a transaction, i.e. everything its fee depends on besides the gas price.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct FeeEstimationKey {
+    inputs: Vec<(Discriminant<FuelInput>, Option<u64>)>,
+    outputs: Vec<Discriminant<Output>>,
+    size: usize,
+    max_gas: u64,
+    policies: [Option<u64>; 3],
+    block_horizon: u32,
+}
+
+impl FeeEstimationKey {
+    pub(crate) fn new<T: Inputs + Outputs + PoliciesField + Chargeable>(
+        tx: &T,
+        consensus_parameters: &ConsensusParameters,
+        block_horizon: u32,
+    ) -> Self {
+        let policies = tx.policies();
+
+        Self {
+            inputs: tx
+                .inputs()
+                .iter()
+                .map(|input| (discriminant(input), input.predicate_gas_used()))
+                .collect(),
+            outputs: tx.outputs().iter().map(discriminant).collect(),
+            size: tx.metered_bytes_size(),
+            max_gas: tx.max_gas(
+                &consensus_parameters.gas_costs(),
+                consensus_parameters.fee_params(),
+            ),
+            policies: [
+                policies.get(PolicyType::Tip),
+                policies.get(PolicyType::WitnessLimit),
+                policies.get(PolicyType::Maturity),
+            ],
+            block_horizon,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FeeEstimate {
+    pub(crate) gas_price: u64,
+    pub(crate) min_fee: u64,
+    pub(crate) max_fee: u64,
+    // `Instant` isn't available in browsers
+    estimated_at: DateTime<Utc>,
+}
+
+impl FeeEstimate {
+    pub(crate) fn new(gas_price: u64, min_fee: u64, max_fee: u64) -> Self {
+        Self {
+            gas_price,
+            min_fee,
+            max_fee,
+            estimated_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(block_horizon: u32) -> FeeEstimationKey {
+        FeeEstimationKey {
+            inputs: vec![],
+            outputs: vec![],
+            size: 0,
+            max_gas: 0,
+            policies: [None; 3],
+            block_horizon,
+        }
+    }
+
+    #[test]
+    fn stale_estimates_are_ignored() {
+        let cache = FeeEstimationCache::new(Duration::from_secs(60));
+        let stale = FeeEstimate {
+            estimated_at: Utc::now() - chrono::Duration::seconds(61),
+            ..FeeEstimate::new(1, 2, 3)
+        };
+
+        cache.insert(key(1), FeeEstimate::new(1, 2, 3));
+        cache.insert(key(2), stale);
+
+        assert_eq!(cache.get(&key(1)).map(|estimate| estimate.max_fee), Some(3));
+        assert!(cache.get(&key(2)).is_none());
+    }
+
+    #[test]
+    fn oldest_estimate_is_evicted_when_full() {
+        let cache = FeeEstimationCache::new(Duration::from_secs(60)).with_capacity(2);
+        let older = FeeEstimate {
+            estimated_at: Utc::now() - chrono::Duration::seconds(1),
+            ..FeeEstimate::new(1, 2, 3)
+        };
+
+        cache.insert(key(1), older);
+        cache.insert(key(2), FeeEstimate::new(1, 2, 3));
+        cache.insert(key(3), FeeEstimate::new(1, 2, 3));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key(1)).is_none());
+        assert!(cache.get(&key(2)).is_some());
+        assert!(cache.get(&key(3)).is_some());
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+const DEFAULT_BLOCKS_TO_INSPECT: u32 = 10;
+
+/// How much of its gas limit a block used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockGasUsage {
+    pub height: u32,
+    /// The max gas of the transactions of the block, which is what the block producer counts
+    /// against the block gas limit when selecting transactions.
+    pub gas_used: u64,
+    pub gas_limit: u64,
+}
+
+impl BlockGasUsage {
+    /// The share of the block gas limit used by the block, between `0.0` and `1.0`.
+    pub fn fullness(&self) -> f64 {
+        if self.gas_limit == 0 {
+            return 0.0;
+        }
+
+        (self.gas_used as f64 / self.gas_limit as f64).min(1.0)
+    }
+}
+
+/// What the transaction builders know about the network when a [`TipStrategy`] is asked for a
+/// tip.
+#[derive(Debug, Clone)]
+pub struct NetworkConditions {
+    /// The gas price the node expects for the next blocks, estimated with the builder's
+    /// estimation horizon.
+    pub gas_price: u64,
+    /// The gas used by the latest blocks, newest first. Holds at most
+    /// [`TipStrategy::blocks_to_inspect`] blocks.
+    pub recent_blocks: Vec<BlockGasUsage>,
+}
+
+/// Decides the tip of transactions built without an explicit one, see
+/// [`TransactionBuilder::with_tip_strategy`](crate::types::transaction_builders::TransactionBuilder::with_tip_strategy).
+pub trait TipStrategy: Debug + Send + Sync {
+    /// How many of the latest blocks are given to [`TipStrategy::tip_per_gas`]. Return `0` if the
+    /// strategy doesn't need them, to save the requests.
+    fn blocks_to_inspect(&self) -> u32 {
+        DEFAULT_BLOCKS_TO_INSPECT
+    }
+
+    /// The tip for every unit of the max gas of the transaction, like the gas price. The builder
+    /// tips this amount times the max gas, see
+    /// [`TxPolicies::with_tip_per_gas`](crate::types::transaction::TxPolicies::with_tip_per_gas).
+    fn tip_per_gas(&self, conditions: &NetworkConditions) -> f64;
+}
+
+/// Tips per unit of gas a multiple of the gas price that grows with the congestion of the
+/// network, from a tenth of the gas price when the network is idle up to ten times the gas price
+/// when it's congested, like [`TxPolicies::economic`] and [`TxPolicies::fast`].
+///
+/// The congestion is the highest of:
+/// - how full the recent blocks are on average, counting the gas of their transactions against
+///   the block gas limit,
+/// - how much the gas price rose above the lowest gas price seen by the strategy among its last
+///   estimates, a doubling counting as fully congested.
+///
+/// Clones share the gas price history, so a strategy can be given to every builder of a bot.
+///
+/// [`TxPolicies::economic`]: crate::types::transaction::TxPolicies::economic
+/// [`TxPolicies::fast`]: crate::types::transaction::TxPolicies::fast
+#[derive(Debug, Clone)]
+pub struct CongestionTipStrategy {
+    blocks_to_inspect: u32,
+    min_multiplier: f64,
+    max_multiplier: f64,
+    history_len: usize,
+    gas_price_history: Arc<Mutex<VecDeque<u64>>>,
+}
+
+impl Default for CongestionTipStrategy {
+    fn default() -> Self {
+        Self {
+            blocks_to_inspect: DEFAULT_BLOCKS_TO_INSPECT,
+            min_multiplier: 0.1,
+            max_multiplier: 10.0,
+            history_len: 20,
+            gas_price_history: Default::default(),
+        }
+    }
+}
+
+impl CongestionTipStrategy {
+    /// Sets how many of the latest blocks are inspected, 10 by default.
+    pub fn with_blocks_to_inspect(mut self, blocks: u32) -> Self {
+        self.blocks_to_inspect = blocks;
+        self
+    }
+
+    /// Sets the multiples of the gas price tipped when the network is idle and when it's
+    /// congested.
+    pub fn with_multipliers(mut self, min_multiplier: f64, max_multiplier: f64) -> Self {
+        self.min_multiplier = min_multiplier;
+        self.max_multiplier = max_multiplier;
+        self
+    }
+
+    /// Sets how many gas price estimates are kept to detect a rising gas price, 20 by default.
+    pub fn with_history_len(mut self, history_len: usize) -> Self {
+        self.history_len = history_len;
+        self
+    }
+
+    /// Returns the congestion of the network, between `0.0` and `1.0`.
+    pub fn congestion(&self, conditions: &NetworkConditions) -> f64 {
+        Self::block_fullness(&conditions.recent_blocks)
+            .max(self.gas_price_rise(conditions.gas_price))
+            .clamp(0.0, 1.0)
+    }
+
+    fn block_fullness(blocks: &[BlockGasUsage]) -> f64 {
+        if blocks.is_empty() {
+            return 0.0;
+        }
+
+        let total: f64 = blocks.iter().map(BlockGasUsage::fullness).sum();
+
+        total / blocks.len() as f64
+    }
+
+    /// Records `gas_price` and returns how much it rose above the lowest recorded gas price.
+    fn gas_price_rise(&self, gas_price: u64) -> f64 {
+        let mut history = self
+            .gas_price_history
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        history.push_back(gas_price);
+        while history.len() > self.history_len.max(1) {
+            history.pop_front();
+        }
+
+        let lowest = history.iter().copied().min().unwrap_or(gas_price);
+        if lowest == 0 {
+            return 0.0;
+        }
+
+        gas_price.saturating_sub(lowest) as f64 / lowest as f64
+    }
+}
+
+impl TipStrategy for CongestionTipStrategy {
+    fn blocks_to_inspect(&self) -> u32 {
+        self.blocks_to_inspect
+    }
+
+    fn tip_per_gas(&self, conditions: &NetworkConditions) -> f64 {
+        let congestion = self.congestion(conditions);
+        let multiplier =
+            self.min_multiplier + (self.max_multiplier - self.min_multiplier) * congestion;
+
+        conditions.gas_price as f64 * multiplier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conditions(gas_price: u64, gas_used: &[u64]) -> NetworkConditions {
+        NetworkConditions {
+            gas_price,
+            recent_blocks: gas_used
+                .iter()
+                .map(|&gas_used| BlockGasUsage {
+                    height: 0,
+                    gas_used,
+                    gas_limit: 1000,
+                })
+                .collect(),
+        }
+    }
+
+    fn assert_tip_per_gas(
+        strategy: &CongestionTipStrategy,
+        conditions: &NetworkConditions,
+        expected: f64,
+    ) {
+        let tip_per_gas = strategy.tip_per_gas(conditions);
+        assert!(
+            (tip_per_gas - expected).abs() < 1e-9,
+            "expected a tip per gas of {expected}, got {tip_per_gas}"
+        );
+    }
+
+    #[test]
+    fn tip_grows_with_block_gas() {
+        let strategy = CongestionTipStrategy::default();
+
+        assert_tip_per_gas(&strategy, &conditions(100, &[0, 0]), 10.0);
+        assert_tip_per_gas(&strategy, &conditions(100, &[500, 500]), 505.0);
+        assert_tip_per_gas(&strategy, &conditions(100, &[1000, 5000]), 1000.0);
+    }
+
+    #[test]
+    fn tip_grows_with_the_gas_price() {
+        let strategy = CongestionTipStrategy::default().with_history_len(2);
+
+        assert_tip_per_gas(&strategy, &conditions(100, &[]), 10.0);
+        assert_tip_per_gas(&strategy, &conditions(125, &[]), 321.875);
+        assert_tip_per_gas(&strategy, &conditions(300, &[]), 3000.0);
+
+        // the lowest gas price left the history
+        assert_tip_per_gas(&strategy, &conditions(300, &[]), 30.0);
+    }
+
+    #[test]
+    fn low_gas_prices_are_not_rounded_away() {
+        let strategy = CongestionTipStrategy::default();
+
+        assert_tip_per_gas(&strategy, &conditions(1, &[0]), 0.1);
+    }
+}
@@ -10,6 +10,7 @@ use fuel_vm::state::ProgramState;
 
 use crate::{
     codec::LogDecoder,
+    debug::SourceMapRegistry,
     types::errors::{transaction::Reason, Error, Result},
 };
 
@@ -31,6 +32,16 @@ pub enum TxStatus {
 
 impl TxStatus {
     pub fn check(&self, log_decoder: Option<&LogDecoder>) -> Result<()> {
+        self.check_with_source_maps(log_decoder, None)
+    }
+
+    /// Like [`TxStatus::check`], but revert errors also report the Sway code that panicked or
+    /// reverted if `source_maps` has the source map of the failing program.
+    pub fn check_with_source_maps(
+        &self,
+        log_decoder: Option<&LogDecoder>,
+        source_maps: Option<&SourceMapRegistry>,
+    ) -> Result<()> {
         match self {
             Self::SqueezedOut { reason } => {
                 Err(Error::Transaction(Reason::SqueezedOut(reason.clone())))
@@ -39,7 +50,7 @@ impl TxStatus {
                 receipts,
                 reason,
                 revert_id: id,
-            } => Self::map_revert_error(receipts, reason, *id, log_decoder),
+            } => Self::map_revert_error(receipts, reason, *id, log_decoder, source_maps),
             _ => Ok(()),
         }
     }
@@ -49,6 +60,7 @@ impl TxStatus {
         reason: &str,
         id: u64,
         log_decoder: Option<&LogDecoder>,
+        source_maps: Option<&SourceMapRegistry>,
     ) -> Result<()> {
         let reason = match (id, log_decoder) {
             (FAILED_REQUIRE_SIGNAL, Some(log_decoder)) => log_decoder
@@ -69,6 +81,10 @@ impl TxStatus {
             (FAILED_TRANSFER_TO_ADDRESS_SIGNAL, _) => "failed transfer to address".into(),
             _ => reason.to_string(),
         };
+        let reason = match source_maps.and_then(|registry| registry.locate_failure(receipts)) {
+            Some(location) => format!("{reason}, at {location}"),
+            None => reason,
+        };
 
         Err(Error::Transaction(Reason::Reverted {
             reason,
@@ -78,7 +94,17 @@ impl TxStatus {
     }
 
     pub fn take_receipts_checked(self, log_decoder: Option<&LogDecoder>) -> Result<Vec<Receipt>> {
-        self.check(log_decoder)?;
+        self.take_receipts_checked_with_source_maps(log_decoder, None)
+    }
+
+    /// Like [`TxStatus::take_receipts_checked`], with revert errors located as in
+    /// [`TxStatus::check_with_source_maps`].
+    pub fn take_receipts_checked_with_source_maps(
+        self,
+        log_decoder: Option<&LogDecoder>,
+        source_maps: Option<&SourceMapRegistry>,
+    ) -> Result<Vec<Receipt>> {
+        self.check_with_source_maps(log_decoder, source_maps)?;
         Ok(self.take_receipts())
     }
 
@@ -118,3 +144,45 @@ impl From<ClientTransactionStatus> for TxStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::ContractId;
+
+    use super::*;
+    use crate::debug::SourceMap;
+
+    #[test]
+    fn revert_errors_are_located_with_the_given_source_maps() {
+        let contract_id = ContractId::from([1; 32]);
+        let status = TxStatus::Revert {
+            receipts: vec![Receipt::revert(contract_id, 1, 1008, 1000)],
+            reason: "Revert(1)".to_string(),
+            revert_id: 1,
+        };
+        let source_maps = SourceMapRegistry::new();
+        source_maps.add(
+            contract_id,
+            SourceMap::from_json(
+                r#"{
+                    "paths": ["src/main.sw"],
+                    "map": {
+                        "0": { "path": 0, "range": { "start": { "line": 3, "col": 1 }, "end": { "line": 3, "col": 10 } } }
+                    }
+                }"#,
+            )
+            .expect("is a valid source map"),
+        );
+
+        let reason = |result: Result<()>| match result {
+            Err(Error::Transaction(Reason::Reverted { reason, .. })) => reason,
+            other => panic!("expected a revert error, got {other:?}"),
+        };
+
+        assert_eq!(
+            reason(status.check_with_source_maps(None, Some(&source_maps))),
+            "Revert(1), at src/main.sw:3:1"
+        );
+        assert_eq!(reason(status.check(None)), "Revert(1)");
+    }
+}
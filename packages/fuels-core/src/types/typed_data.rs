@@ -0,0 +1,333 @@
+//! Signing of typed structured data, in the spirit of EIP-712.
+//!
+//! The signed digest of a message of type `T` within a [`TypedDataDomain`] is
+//!
+//! ```text
+//! digest      = sha256(0x19 ++ 0x01 ++ struct_hash(domain) ++ struct_hash(message))
+//! struct_hash = sha256(sha256(encode_type(T)) ++ abi_encode(message))
+//! ```
+//!
+//! where `abi_encode` is the encoding of the [`ABIEncoder`], the one of `core::codec::encode` in
+//! Sway, and `encode_type` describes the type of the message, e.g.
+//! `Order(maker:Address,amount:u64)Address(0:b256)`. The domain is encoded as a
+//! `FuelDomain { name: String, version: String, chain_id: u64, verifying_contract: b256 }`.
+//!
+//! A predicate or contract holding the domain separator and the type hash can recompute the
+//! digest of a message and recover the signer with `ec_recover_address`.
+
+use std::collections::BTreeMap;
+
+use fuel_crypto::Hasher;
+
+use crate::{
+    codec::ABIEncoder,
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        param_types::ParamType,
+        signature::{recover_address, verify_signature, Message, Signature},
+        Bytes32, ContractId, Token,
+    },
+};
+
+const DOMAIN_TYPE: &str =
+    "FuelDomain(name:String,version:String,chain_id:u64,verifying_contract:b256)";
+
+/// Identifies the application a [`TypedData`] is signed for, so that a signature cannot be
+/// replayed by another application, version or chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypedDataDomain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    /// The contract expected to verify the signature, if any.
+    pub verifying_contract: Option<ContractId>,
+}
+
+impl TypedDataDomain {
+    pub fn new(name: impl Into<String>, version: impl Into<String>, chain_id: u64) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            chain_id,
+            verifying_contract: None,
+        }
+    }
+
+    pub fn with_verifying_contract(mut self, contract_id: ContractId) -> Self {
+        self.verifying_contract = Some(contract_id);
+        self
+    }
+
+    /// The struct hash of the domain, which is part of every digest signed within it.
+    pub fn separator(&self) -> Result<Bytes32> {
+        let token = Token::Struct(vec![
+            Token::String(self.name.clone()),
+            Token::String(self.version.clone()),
+            Token::U64(self.chain_id),
+            Token::B256(*self.verifying_contract.unwrap_or_default()),
+        ]);
+
+        struct_hash(Hasher::hash(DOMAIN_TYPE), token)
+    }
+}
+
+/// A message of a struct type, to be signed within a [`TypedDataDomain`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypedData {
+    domain: TypedDataDomain,
+    param_type: ParamType,
+    message: Token,
+}
+
+impl TypedData {
+    /// Fails if `message` is not a struct.
+    pub fn new<T: Tokenizable + Parameterize>(domain: TypedDataDomain, message: T) -> Result<Self> {
+        let param_type = T::param_type();
+        if !matches!(param_type, ParamType::Struct { .. }) {
+            return Err(error!(
+                Codec,
+                "typed data must be a struct, got `{}`",
+                param_type.type_name()
+            ));
+        }
+
+        Ok(Self {
+            domain,
+            param_type,
+            message: message.into_token(),
+        })
+    }
+
+    pub fn domain(&self) -> &TypedDataDomain {
+        &self.domain
+    }
+
+    pub fn message(&self) -> &Token {
+        &self.message
+    }
+
+    /// Describes the type of the message, followed by the structs and enums it uses, sorted by
+    /// name. Structs are written as `Name(field:type,...)` and enums as `Name{Variant:type,...}`.
+    pub fn encode_type(&self) -> String {
+        let mut referenced = BTreeMap::new();
+        collect_referenced_types(&self.param_type, &mut referenced);
+
+        let primary = self.param_type.type_name();
+        let primary_definition = referenced
+            .remove(&primary)
+            .expect("the message is a struct");
+
+        std::iter::once(primary_definition)
+            .chain(referenced.into_values())
+            .collect()
+    }
+
+    pub fn type_hash(&self) -> Bytes32 {
+        Hasher::hash(self.encode_type())
+    }
+
+    pub fn struct_hash(&self) -> Result<Bytes32> {
+        struct_hash(self.type_hash(), self.message.clone())
+    }
+
+    /// The digest to sign, see the module documentation.
+    pub fn digest(&self) -> Result<Bytes32> {
+        let digest = Hasher::default()
+            .chain([0x19, 0x01])
+            .chain(self.domain.separator()?)
+            .chain(self.struct_hash()?)
+            .finalize();
+
+        Ok(digest)
+    }
+
+    /// Recovers the address of the account that produced `signature`.
+    pub fn recover_signer(&self, signature: &Signature) -> Result<Bech32Address> {
+        recover_address(signature, &self.message_to_sign()?)
+    }
+
+    /// Fails unless `signature` was produced by `signer` for this typed data.
+    pub fn verify(&self, signature: &Signature, signer: &Bech32Address) -> Result<()> {
+        verify_signature(signature, &self.message_to_sign()?, signer)
+    }
+
+    /// The digest as the message signed by a [`Signer`](crate::traits::Signer).
+    pub fn message_to_sign(&self) -> Result<Message> {
+        Ok(Message::from_bytes(*self.digest()?))
+    }
+}
+
+fn struct_hash(type_hash: Bytes32, token: Token) -> Result<Bytes32> {
+    let encoded = ABIEncoder::default().encode(&[token])?.resolve(0);
+
+    Ok(Hasher::default().chain(type_hash).chain(encoded).finalize())
+}
+
+fn collect_referenced_types(param_type: &ParamType, referenced: &mut BTreeMap<String, String>) {
+    let define = |fields: &[(String, ParamType)], open: char, close: char| {
+        let fields = fields
+            .iter()
+            .map(|(name, field_type)| format!("{name}:{}", field_type.type_name()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{}{open}{fields}{close}", param_type.type_name())
+    };
+
+    match param_type {
+        ParamType::Struct { fields, .. } => {
+            if referenced.contains_key(&param_type.type_name()) {
+                return;
+            }
+            referenced.insert(param_type.type_name(), define(fields, '(', ')'));
+
+            for (_, field) in fields {
+                collect_referenced_types(field, referenced);
+            }
+        }
+        ParamType::Enum { enum_variants, .. } => {
+            if referenced.contains_key(&param_type.type_name()) {
+                return;
+            }
+            let variants = enum_variants.variants();
+            referenced.insert(param_type.type_name(), define(variants, '{', '}'));
+
+            for (_, variant) in variants {
+                collect_referenced_types(variant, referenced);
+            }
+        }
+        ParamType::Tuple(param_types) => param_types
+            .iter()
+            .for_each(|param_type| collect_referenced_types(param_type, referenced)),
+        ParamType::Array(param_type, _) | ParamType::Vector(param_type) => {
+            collect_referenced_types(param_type, referenced)
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use fuel_crypto::{PublicKey, SecretKey};
+
+    use super::*;
+    use crate::types::{param_types::EnumVariants, Address, AssetId};
+
+    #[derive(Clone)]
+    struct Order {
+        maker: Address,
+        asset: AssetId,
+        amount: u64,
+    }
+
+    impl Parameterize for Order {
+        fn param_type() -> ParamType {
+            ParamType::Struct {
+                name: "Order".to_string(),
+                fields: vec![
+                    ("maker".to_string(), Address::param_type()),
+                    ("asset".to_string(), AssetId::param_type()),
+                    ("amount".to_string(), ParamType::U64),
+                    (
+                        "side".to_string(),
+                        ParamType::Enum {
+                            name: "Side".to_string(),
+                            enum_variants: EnumVariants::new(vec![
+                                ("Buy".to_string(), ParamType::Unit),
+                                ("Sell".to_string(), ParamType::Unit),
+                            ])
+                            .expect("valid variants"),
+                            generics: vec![],
+                        },
+                    ),
+                ],
+                generics: vec![],
+            }
+        }
+    }
+
+    impl Tokenizable for Order {
+        fn from_token(_: Token) -> Result<Self> {
+            unimplemented!("only encoded in these tests")
+        }
+
+        fn into_token(self) -> Token {
+            let ParamType::Struct { fields, .. } = Self::param_type() else {
+                unreachable!()
+            };
+            let ParamType::Enum { enum_variants, .. } = fields[3].1.clone() else {
+                unreachable!()
+            };
+
+            Token::Struct(vec![
+                self.maker.into_token(),
+                self.asset.into_token(),
+                Token::U64(self.amount),
+                Token::Enum(Box::new((0, Token::Unit, enum_variants))),
+            ])
+        }
+    }
+
+    fn typed_order(amount: u64) -> Result<TypedData> {
+        let domain = TypedDataDomain::new("Exchange", "1", 0)
+            .with_verifying_contract(ContractId::from([1; 32]));
+        let order = Order {
+            maker: Address::from([2; 32]),
+            asset: AssetId::from([3; 32]),
+            amount,
+        };
+
+        TypedData::new(domain, order)
+    }
+
+    #[test]
+    fn referenced_types_are_encoded_after_the_primary_type() -> Result<()> {
+        let typed_data = typed_order(10)?;
+
+        assert_eq!(
+            typed_data.encode_type(),
+            "Order(maker:Address,asset:AssetId,amount:u64,side:Side)\
+             Address(0:b256)AssetId(0:b256)Side{Buy:(),Sell:()}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn signatures_are_verified_against_the_signer() -> Result<()> {
+        // given
+        let secret_key = SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?;
+        let signer = Bech32Address::from(Address::from(*PublicKey::from(&secret_key).hash()));
+        let typed_data = typed_order(10)?;
+
+        // when
+        let signature = Signature::sign(&secret_key, &typed_data.message_to_sign()?);
+
+        // then
+        typed_data.verify(&signature, &signer)?;
+
+        let other_order = typed_order(11)?;
+        let err = other_order
+            .verify(&signature, &signer)
+            .expect_err("should fail for another message");
+        assert!(err.to_string().contains("signature was produced by"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_structs_can_be_signed() {
+        let err = TypedData::new(TypedDataDomain::new("Exchange", "1", 0), 5u64)
+            .expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("typed data must be a struct, got `u64`"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Helpers for amounts expressed in base units, e.g. converting between `1.5` and `1500000000`
+//! for an asset with 9 decimals, and for arithmetic on amounts that must not overflow silently.
+//!
+//! Checked and saturating arithmetic on [`U256`] is provided by the type itself, e.g.
+//! [`U256::checked_add`] and [`U256::saturating_mul`]. Conversions from the Rust primitives are
+//! provided through `From`, and to them through `TryFrom`, failing if the value doesn't fit.
+
+use crate::types::{
+    errors::{error, Result},
+    U256,
+};
+
+/// Formats `amount`, given in base units, as a decimal number of whole units. Trailing zeros of
+/// the fractional part are omitted, e.g. `format_units(1_500_000_000u64, 9)` is `"1.5"`.
+pub fn format_units(amount: impl Into<U256>, decimals: u8) -> String {
+    let amount = amount.into().to_string();
+    let decimals = usize::from(decimals);
+
+    if decimals == 0 {
+        return amount;
+    }
+
+    let padded = format!("{amount:0>width$}", width = decimals + 1);
+    let (integer, fraction) = padded.split_at(padded.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Parses a decimal number of whole units into base units, e.g. `parse_units("1.5", 9)` is
+/// `1_500_000_000`. Fails if `amount` has more than `decimals` fractional digits, trailing zeros
+/// aside, since that would lose precision, or if the result doesn't fit into a [`U256`].
+///
+/// Use `u64::try_from` on the result to get an amount usable in transactions.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<U256> {
+    let trimmed = amount.trim();
+    let (integer, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+    let is_number = |digits: &str| digits.bytes().all(|byte| byte.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_number(integer) || !is_number(fraction) {
+        return Err(error!(Other, "`{amount}` is not a decimal number"));
+    }
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.len() > usize::from(decimals) {
+        return Err(error!(
+            Other,
+            "`{amount}` has more than {decimals} fractional digits"
+        ));
+    }
+
+    let base_units = format!(
+        "{integer}{fraction:0<width$}",
+        width = usize::from(decimals)
+    );
+
+    U256::from_dec_str(&base_units)
+        .map_err(|_| error!(Other, "`{amount}` does not fit into a `U256`"))
+}
+
+/// Computes `value * numerator / denominator`, rounding down, without overflowing on the
+/// intermediate product. Useful to apply a ratio, e.g. a fee in basis points, to an amount.
+/// Returns `None` if `denominator` is zero or the result doesn't fit into a `u64`.
+pub fn checked_mul_div(value: u64, numerator: u64, denominator: u64) -> Option<u64> {
+    let result = u128::from(value)
+        .checked_mul(u128::from(numerator))?
+        .checked_div(u128::from(denominator))?;
+
+    u64::try_from(result).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amounts_are_formatted_in_whole_units() {
+        assert_eq!(format_units(1_500_000_000u64, 9), "1.5");
+        assert_eq!(format_units(1_000_000_000u64, 9), "1");
+        assert_eq!(format_units(42u64, 9), "0.000000042");
+        assert_eq!(format_units(0u64, 9), "0");
+        assert_eq!(format_units(123u64, 0), "123");
+        assert_eq!(
+            format_units(U256::MAX, 18),
+            "115792089237316195423570985008687907853269984665640564039457.584007913129639935"
+        );
+    }
+
+    #[test]
+    fn amounts_are_parsed_into_base_units() -> Result<()> {
+        assert_eq!(parse_units("1.5", 9)?, U256::from(1_500_000_000u64));
+        assert_eq!(parse_units("1", 9)?, U256::from(1_000_000_000u64));
+        assert_eq!(parse_units(".5", 1)?, U256::from(5));
+        assert_eq!(parse_units("0.000000042", 9)?, U256::from(42));
+        assert_eq!(parse_units(" 7 ", 0)?, U256::from(7));
+        assert_eq!(parse_units("2.50", 1)?, U256::from(25));
+
+        for amount in ["", ".", "1.2.3", "-1", "1e9", "0x10"] {
+            assert!(parse_units(amount, 9).is_err(), "`{amount}` was parsed");
+        }
+
+        let err = parse_units("1.0000000001", 9).expect_err("should fail");
+        assert!(err.to_string().contains("more than 9 fractional digits"));
+
+        let err = parse_units(&U256::MAX.to_string(), 1).expect_err("should fail");
+        assert!(err.to_string().contains("does not fit"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn formatting_and_parsing_round_trip() -> Result<()> {
+        let amount = U256::from(123_456_789_000u64);
+
+        assert_eq!(parse_units(&format_units(amount, 9), 9)?, amount);
+
+        Ok(())
+    }
+
+    #[test]
+    fn ratios_are_applied_without_overflow() {
+        assert_eq!(
+            checked_mul_div(u64::MAX, 30, 10_000),
+            Some(55_340_232_221_128_654)
+        );
+        assert_eq!(checked_mul_div(1_000, 1, 3), Some(333));
+        assert_eq!(checked_mul_div(u64::MAX, 2, 1), None);
+        assert_eq!(checked_mul_div(1, 1, 0), None);
+    }
+}
@@ -5,6 +5,9 @@ use fuel_core_client::client::types::{
     block::{Block as ClientBlock, Header as ClientHeader},
     primitives::Bytes32,
 };
+use fuel_types::BlockHeight;
+
+use crate::types::transaction_response::TransactionResponse;
 
 #[derive(Debug, Clone)]
 pub struct Header {
@@ -61,3 +64,52 @@ impl From<ClientBlock> for Block {
         }
     }
 }
+
+/// A block in the compressed form posted to the DA layer, as served by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaCompressedBlock {
+    pub height: u32,
+    /// The compressed block, exactly as posted to the DA layer.
+    pub bytes: Vec<u8>,
+}
+
+impl DaCompressedBlock {
+    /// The number of bytes posted to the DA layer for this block.
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+}
+
+/// Identifies a block either by its height or by its id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockRef {
+    Height(BlockHeight),
+    Id(Bytes32),
+}
+
+impl From<BlockHeight> for BlockRef {
+    fn from(height: BlockHeight) -> Self {
+        Self::Height(height)
+    }
+}
+
+impl From<u32> for BlockRef {
+    fn from(height: u32) -> Self {
+        Self::Height(height.into())
+    }
+}
+
+impl From<Bytes32> for BlockRef {
+    fn from(id: Bytes32) -> Self {
+        Self::Id(id)
+    }
+}
+
+/// A block together with its transactions, their statuses and receipts.
+#[derive(Debug, Clone)]
+pub struct FullBlock {
+    pub id: Bytes32,
+    pub header: Header,
+    /// The transactions of the block, in the order they were included, paired with their ids.
+    pub transactions: Vec<(Bytes32, TransactionResponse)>,
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 use fuel_tx::UtxoId;
 use fuel_types::Nonce;
 
@@ -6,3 +8,14 @@ pub enum CoinTypeId {
     UtxoId(UtxoId),
     Nonce(Nonce),
 }
+
+impl fmt::Display for CoinTypeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoinTypeId::UtxoId(utxo_id) => {
+                write!(f, "coin `{}:{}`", utxo_id.tx_id(), utxo_id.output_index())
+            }
+            CoinTypeId::Nonce(nonce) => write!(f, "message `{nonce}`"),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use fuel_core_client::client::types::{
 };
 use fuel_types::Bytes32;
 
-use crate::types::{bech32::Bech32Address, block::Header};
+use crate::types::{bech32::Bech32Address, block::Header, bridge::RelayMessageCalldata};
 
 #[derive(Debug)]
 pub struct MerkleProof {
@@ -58,3 +58,11 @@ impl From<ClientMessageProof> for MessageProof {
         }
     }
 }
+
+impl MessageProof {
+    /// Encodes the proof as the arguments of `relayMessage` of the base layer message portal,
+    /// see [`RelayMessageCalldata`].
+    pub fn relay_payload(&self) -> Vec<u8> {
+        RelayMessageCalldata::from(self).encode()
+    }
+}
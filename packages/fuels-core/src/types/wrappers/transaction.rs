@@ -1,11 +1,15 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
 
 use async_trait::async_trait;
-use fuel_crypto::{Message, Signature};
+use fuel_crypto::{Hasher, Signature};
 use fuel_tx::{
     field::{
-        Inputs, Maturity, MintAmount, MintAssetId, Outputs, Script as ScriptField, ScriptData,
-        ScriptGasLimit, WitnessLimit, Witnesses,
+        Inputs, Maturity, MintAmount, MintAssetId, Outputs, Policies as PoliciesField,
+        Script as ScriptField, ScriptData, ScriptGasLimit, WitnessLimit, Witnesses,
     },
     input::{
         coin::{CoinPredicate, CoinSigned},
@@ -13,23 +17,26 @@ use fuel_tx::{
             MessageCoinPredicate, MessageCoinSigned, MessageDataPredicate, MessageDataSigned,
         },
     },
+    policies::PolicyType,
     Bytes32, Cacheable, Chargeable, ConsensusParameters, Create, FormatValidityChecks, Input, Mint,
     Output, Salt as FuelSalt, Script, StorageSlot, Transaction as FuelTransaction, TransactionFee,
     UniqueIdentifier, Upgrade, Upload, Witness,
 };
-use fuel_types::{bytes::padded_len_usize, AssetId, ChainId};
+use fuel_types::{canonical::Serialize, AssetId, ChainId};
 use fuel_vm::checked_transaction::{
     CheckPredicateParams, CheckPredicates, EstimatePredicates, IntoChecked,
 };
+use fuels_signing::{message_for_tx_id, witness_from_signature};
 use itertools::Itertools;
 
 use crate::{
     traits::Signer,
     types::{
         bech32::Bech32Address,
-        errors::{error, error_transaction, Result},
+        errors::{error_transaction, Result},
+        transaction_builders::DryRunner,
     },
-    utils::{calculate_witnesses_size, sealed},
+    utils::{constants::DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, sealed},
 };
 
 #[derive(Default, Debug, Clone)]
@@ -188,6 +195,7 @@ pub struct TxPolicies {
     maturity: Option<u64>,
     max_fee: Option<u64>,
     script_gas_limit: Option<u64>,
+    tip_per_gas: Option<f64>,
 }
 //ANCHOR_END: tx_policies_struct
 
@@ -205,6 +213,7 @@ impl TxPolicies {
             maturity,
             max_fee,
             script_gas_limit,
+            tip_per_gas: None,
         }
     }
 
@@ -217,6 +226,18 @@ impl TxPolicies {
         self.tip
     }
 
+    /// Tips `tip_per_gas` for every unit of the max gas of the transaction, rounded up, instead of
+    /// a fixed amount. The builders work the tip out once the gas of the transaction is known. An
+    /// explicit `tip` takes precedence.
+    pub fn with_tip_per_gas(mut self, tip_per_gas: f64) -> Self {
+        self.tip_per_gas = Some(tip_per_gas);
+        self
+    }
+
+    pub fn tip_per_gas(&self) -> Option<f64> {
+        self.tip_per_gas
+    }
+
     pub fn with_witness_limit(mut self, witness_limit: u64) -> Self {
         self.witness_limit = Some(witness_limit);
         self
@@ -252,6 +273,187 @@ impl TxPolicies {
     pub fn script_gas_limit(&self) -> Option<u64> {
         self.script_gas_limit
     }
+
+    /// Checks the policies for combinations the node would reject, e.g. a `max_fee` lower than
+    /// the `tip` or a `script_gas_limit` above the chain's limit. All the problems found are
+    /// reported in a single error.
+    ///
+    /// The builders run this check, and additionally check the `witness_limit` against the
+    /// signers they have to add, when building a transaction.
+    pub fn validate(&self, consensus_parameters: &ConsensusParameters) -> Result<()> {
+        let tx_params = consensus_parameters.tx_params();
+        let mut problems = vec![];
+
+        if let (Some(max_fee), Some(tip)) = (self.max_fee, self.tip) {
+            if max_fee < tip {
+                problems.push(format!(
+                    "`max_fee` ({max_fee}) is lower than the `tip` ({tip}), raise it or leave it unset to have it estimated"
+                ));
+            }
+        }
+
+        if let Some(script_gas_limit) = self.script_gas_limit {
+            let max_gas_per_tx = tx_params.max_gas_per_tx();
+            if script_gas_limit > max_gas_per_tx {
+                problems.push(format!(
+                    "`script_gas_limit` ({script_gas_limit}) exceeds the chain's max gas per transaction ({max_gas_per_tx})"
+                ));
+            }
+        }
+
+        if let Some(witness_limit) = self.witness_limit {
+            let max_size = tx_params.max_size();
+            if witness_limit > max_size {
+                problems.push(format!(
+                    "`witness_limit` ({witness_limit}) exceeds the chain's max transaction size ({max_size})"
+                ));
+            }
+        }
+
+        if let Some(tip_per_gas) = self.tip_per_gas {
+            if !tip_per_gas.is_finite() || tip_per_gas < 0.0 {
+                problems.push(format!(
+                    "`tip_per_gas` ({tip_per_gas}) must be a non-negative number"
+                ));
+            }
+        }
+
+        if let Some(maturity) = self.maturity {
+            if maturity > u64::from(u32::MAX) {
+                problems.push(format!(
+                    "`maturity` ({maturity}) is not a valid block height"
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(error_transaction!(
+                Builder,
+                "invalid tx policies: {}",
+                problems.join("; ")
+            ))
+        }
+    }
+
+    /// Policies for transactions that should be included as soon as possible. The tip per gas is
+    /// ten times the gas price the node expects for the next block, so the transaction pays ten
+    /// times its gas fee on top as a tip, see [`TxPolicies::with_tip_per_gas`].
+    ///
+    /// Like with the other presets, the max fee and witness limit are left unset so the
+    /// transaction builder can estimate them for the actual transaction.
+    pub async fn fast(provider: &impl DryRunner) -> Result<Self> {
+        Self::tipping_gas_price(provider, 10.0).await
+    }
+
+    /// Policies for transactions that should be included soon, but not at any price. The tip per
+    /// gas is the gas price the node expects for the next block.
+    pub async fn standard(provider: &impl DryRunner) -> Result<Self> {
+        Self::tipping_gas_price(provider, 1.0).await
+    }
+
+    /// Policies for transactions that can wait for the block producer to have room for them. The
+    /// tip per gas is a tenth of the gas price the node expects for the next block.
+    pub async fn economic(provider: &impl DryRunner) -> Result<Self> {
+        Self::tipping_gas_price(provider, 0.1).await
+    }
+
+    async fn tipping_gas_price(provider: &impl DryRunner, multiplier: f64) -> Result<Self> {
+        let gas_price = provider
+            .estimate_gas_price(DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON)
+            .await?;
+
+        Ok(Self::default().with_tip_per_gas(gas_price as f64 * multiplier))
+    }
+}
+
+/// The components of the gas a transaction is charged for, see
+/// [`Transaction::cost_breakdown`]. The components add up to the transaction's max gas.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+// ANCHOR: cost_breakdown
+pub struct CostBreakdown {
+    /// Gas used by the predicates, as `(input index, gas)` pairs.
+    pub predicate_gas: Vec<(usize, u64)>,
+    /// Gas reserved for executing the script, i.e. the script gas limit. Zero for transactions
+    /// without a script.
+    pub script_gas: u64,
+    /// Gas charged for the size of the transaction, witnesses excluded.
+    pub bytes_gas: u64,
+    /// Gas charged for the witnesses, up to the witness limit.
+    pub witness_gas: u64,
+    /// Gas charged for the VM initialization, signature checks and hashing of the transaction.
+    pub base_gas: u64,
+    pub tip: u64,
+    pub witness_limit: u64,
+    /// The max fee set in the policies, the transaction is rejected if it costs more.
+    pub max_fee: Option<u64>,
+}
+// ANCHOR_END: cost_breakdown
+
+impl CostBreakdown {
+    fn compute<T>(tx: &T, consensus_parameters: &ConsensusParameters) -> Self
+    where
+        T: Chargeable + Inputs + Witnesses + WitnessLimit + PoliciesField,
+    {
+        let gas_costs = consensus_parameters.gas_costs();
+        let fee_params = consensus_parameters.fee_params();
+        let gas_per_byte = fee_params.gas_per_byte();
+
+        let predicate_gas = tx
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, input)| input.predicate_gas_used().map(|gas| (idx, gas)))
+            .collect::<Vec<_>>();
+        let total_predicate_gas = predicate_gas.iter().map(|(_, gas)| gas).sum::<u64>();
+
+        let metered_bytes = tx.metered_bytes_size() as u64;
+        let witnesses_size = tx
+            .witnesses()
+            .iter()
+            .map(|witness| witness.size() as u64)
+            .sum::<u64>();
+        let witness_limit = tx.witness_limit();
+        let unused_witness_gas = witness_limit
+            .saturating_sub(witnesses_size)
+            .saturating_mul(gas_per_byte);
+
+        let min_gas = tx.min_gas(gas_costs, fee_params);
+        let max_gas = tx.max_gas(gas_costs, fee_params);
+
+        Self {
+            predicate_gas,
+            script_gas: max_gas
+                .saturating_sub(min_gas)
+                .saturating_sub(unused_witness_gas),
+            bytes_gas: metered_bytes
+                .saturating_sub(witnesses_size)
+                .saturating_mul(gas_per_byte),
+            witness_gas: witness_limit
+                .max(witnesses_size)
+                .saturating_mul(gas_per_byte),
+            base_gas: min_gas
+                .saturating_sub(metered_bytes.saturating_mul(gas_per_byte))
+                .saturating_sub(total_predicate_gas),
+            tip: tx.policies().get(PolicyType::Tip).unwrap_or_default(),
+            witness_limit,
+            max_fee: tx.policies().get(PolicyType::MaxFee),
+        }
+    }
+
+    pub fn total_predicate_gas(&self) -> u64 {
+        self.predicate_gas.iter().map(|(_, gas)| gas).sum()
+    }
+
+    /// The sum of all gas components, equal to the max gas of the transaction.
+    pub fn total_gas(&self) -> u64 {
+        self.total_predicate_gas()
+            + self.script_gas
+            + self.bytes_gas
+            + self.witness_gas
+            + self.base_gas
+    }
 }
 
 use fuel_tx::field::{BytecodeWitnessIndex, Salt, StorageSlots};
@@ -271,7 +473,107 @@ pub trait EstimablePredicates: sealed::Sealed {
     /// If a transaction contains predicates, we have to estimate them
     /// before sending the transaction to the node. The estimation will check
     /// all predicates and set the `predicate_gas_used` to the actual consumed gas.
+    ///
+    /// The predicates are run locally by the embedded `fuel-vm` against the given consensus
+    /// parameters, no request is made to the node.
     fn estimate_predicates(&mut self, consensus_parameters: &ConsensusParameters) -> Result<()>;
+
+    /// Same as [`EstimablePredicates::estimate_predicates`] but reuses the results stored in
+    /// `cache`. The estimation is skipped if all predicates of the transaction have a cached
+    /// entry, otherwise the predicates are estimated and the results are stored in `cache`.
+    ///
+    /// Returns `true` if the estimation was served from the cache.
+    fn estimate_predicates_cached(
+        &mut self,
+        consensus_parameters: &ConsensusParameters,
+        cache: &PredicateEstimationCache,
+    ) -> Result<bool>;
+}
+
+type PredicateEstimationCacheKey = (Bytes32, Bytes32, Bytes32);
+
+/// Stores the `predicate_gas_used` of already estimated predicates. Entries are keyed by the hash
+/// of the predicate code, the hash of the predicate data and the hash of the consensus parameters
+/// used for the estimation.
+///
+/// Only successful estimations are cached, so a cache hit also means the predicates already
+/// passed with this code and data. Note that predicates which inspect the transaction they are
+/// part of can consume a different amount of gas, or fail, for every transaction. Only use the
+/// cache for predicates whose outcome depends solely on their code and data.
+///
+/// Clones share the cache.
+#[derive(Debug, Clone, Default)]
+pub struct PredicateEstimationCache {
+    items: Arc<Mutex<HashMap<PredicateEstimationCacheKey, u64>>>,
+}
+
+impl PredicateEstimationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached estimations.
+    pub fn clear(&self) {
+        self.items().clear();
+    }
+
+    fn items(&self) -> MutexGuard<'_, HashMap<PredicateEstimationCacheKey, u64>> {
+        self.items.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn estimate<T>(&self, tx: &mut T, consensus_parameters: &ConsensusParameters) -> Result<bool>
+    where
+        T: Inputs + EstimatePredicates,
+    {
+        let params_hash = Hasher::hash(serde_json::to_vec(consensus_parameters)?);
+
+        let keys = tx
+            .inputs()
+            .iter()
+            .map(|input| match (input.predicate(), input.predicate_data()) {
+                (Some(code), Some(data)) => {
+                    Some((Hasher::hash(code), Hasher::hash(data), params_hash))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let cached = {
+            let items = self.items();
+            keys.iter()
+                .map(|key| key.as_ref().map(|key| items.get(key).copied()))
+                .collect::<Vec<_>>()
+        };
+
+        if cached.iter().flatten().all(Option::is_some) {
+            for (input, gas_used) in tx.inputs_mut().iter_mut().zip(cached) {
+                if let Some(Some(gas_used)) = gas_used {
+                    input.set_predicate_gas_used(gas_used);
+                }
+            }
+
+            return Ok(true);
+        }
+
+        tx.estimate_predicates(&consensus_parameters.into())?;
+
+        let estimations = tx
+            .inputs()
+            .iter()
+            .zip(keys)
+            .filter_map(|(input, key)| key.zip(input.predicate_gas_used()));
+        self.items().extend(estimations);
+
+        Ok(false)
+    }
 }
 
 pub trait GasValidation: sealed::Sealed {
@@ -307,6 +609,9 @@ pub trait Transaction:
 
     fn max_gas(&self, consensus_parameters: &ConsensusParameters) -> u64;
 
+    /// Splits the gas the transaction is charged for into its components.
+    fn cost_breakdown(&self, consensus_parameters: &ConsensusParameters) -> CostBreakdown;
+
     /// Performs all stateless transaction validity checks. This includes the validity
     /// of fields according to rules in the specification and validity of signatures.
     /// <https://github.com/FuelLabs/fuel-specs/blob/master/src/tx-format/transaction.md>
@@ -449,6 +754,10 @@ macro_rules! impl_tx_wrapper {
                 )
             }
 
+            fn cost_breakdown(&self, consensus_parameters: &ConsensusParameters) -> CostBreakdown {
+                CostBreakdown::compute(&self.tx, consensus_parameters)
+            }
+
             fn fee_checked_from_tx(
                 &self,
                 consensus_parameters: &ConsensusParameters,
@@ -508,25 +817,7 @@ macro_rules! impl_tx_wrapper {
             }
 
             fn append_witness(&mut self, witness: Witness) -> Result<usize> {
-                let witness_size = calculate_witnesses_size(
-                    self.tx.witnesses().iter().chain(std::iter::once(&witness)),
-                );
-                let new_witnesses_size = padded_len_usize(witness_size)
-                    .ok_or_else(|| error!(Other, "witness size overflow: {witness_size}"))?
-                    as u64;
-
-                if new_witnesses_size > self.tx.witness_limit() {
-                    Err(error_transaction!(
-                        Validation,
-                        "Witness limit exceeded. Consider setting the limit manually with \
-                        a transaction builder. The new limit should be: `{new_witnesses_size}`"
-                    ))
-                } else {
-                    let idx = self.tx.witnesses().len();
-                    self.tx.witnesses_mut().push(witness);
-
-                    Ok(idx)
-                }
+                Ok(fuels_signing::append_witness(&mut self.tx, witness)?)
             }
 
             fn used_coins(
@@ -557,11 +848,11 @@ macro_rules! impl_tx_wrapper {
                 signer: &(impl Signer + Send + Sync),
                 chain_id: ChainId,
             ) -> Result<Signature> {
-                let tx_id = self.id(chain_id);
-                let message = Message::from_bytes(*tx_id);
-                let signature = signer.sign(message).await?;
+                let message = message_for_tx_id(&self.id(chain_id));
+                let tx = FuelTransaction::from(self.tx.clone());
+                let signature = signer.sign_transaction(&tx, message).await?;
 
-                self.append_witness(signature.as_ref().into())?;
+                self.append_witness(witness_from_signature(&signature))?;
 
                 Ok(signature)
             }
@@ -578,6 +869,14 @@ impl EstimablePredicates for CreateTransaction {
 
         Ok(())
     }
+
+    fn estimate_predicates_cached(
+        &mut self,
+        consensus_parameters: &ConsensusParameters,
+        cache: &PredicateEstimationCache,
+    ) -> Result<bool> {
+        cache.estimate(&mut self.tx, consensus_parameters)
+    }
 }
 
 impl CreateTransaction {
@@ -600,6 +899,14 @@ impl EstimablePredicates for ScriptTransaction {
 
         Ok(())
     }
+
+    fn estimate_predicates_cached(
+        &mut self,
+        consensus_parameters: &ConsensusParameters,
+        cache: &PredicateEstimationCache,
+    ) -> Result<bool> {
+        cache.estimate(&mut self.tx, consensus_parameters)
+    }
 }
 
 impl GasValidation for CreateTransaction {
@@ -645,10 +952,135 @@ impl ScriptTransaction {
 #[cfg(test)]
 mod test {
 
+    use fuel_asm::{op, RegId};
     use fuel_tx::policies::Policies;
 
     use super::*;
 
+    fn given_a_predicate_tx(predicate_code: Vec<u8>) -> ScriptTransaction {
+        let owner = Input::predicate_owner(&predicate_code);
+        let predicate_input = Input::coin_predicate(
+            Default::default(),
+            owner,
+            100,
+            AssetId::zeroed(),
+            Default::default(),
+            0,
+            predicate_code,
+            vec![],
+        );
+
+        FuelTransaction::script(
+            0,
+            vec![],
+            vec![],
+            Policies::default(),
+            vec![predicate_input],
+            vec![],
+            vec![],
+        )
+        .into()
+    }
+
+    #[test]
+    fn predicate_estimation_is_reused_from_cache() -> Result<()> {
+        // given
+        let consensus_parameters = ConsensusParameters::standard();
+        let chain_id = consensus_parameters.chain_id();
+        let predicate_code: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let cache = PredicateEstimationCache::new();
+
+        let mut first_tx = given_a_predicate_tx(predicate_code.clone());
+        first_tx.precompute(&chain_id)?;
+        let first_hit = first_tx.estimate_predicates_cached(&consensus_parameters, &cache)?;
+        let estimated_gas = first_tx.inputs()[0]
+            .predicate_gas_used()
+            .expect("is a predicate");
+
+        // when
+        let mut second_tx = given_a_predicate_tx(predicate_code);
+        second_tx.precompute(&chain_id)?;
+        let second_hit = second_tx.estimate_predicates_cached(&consensus_parameters, &cache)?;
+
+        // then
+        assert!(!first_hit);
+        assert!(second_hit);
+        assert!(estimated_gas > 0);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(
+            second_tx.inputs()[0].predicate_gas_used(),
+            Some(estimated_gas)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn contradictory_tx_policies_are_rejected() {
+        let consensus_parameters = ConsensusParameters::standard();
+        let max_gas_per_tx = consensus_parameters.tx_params().max_gas_per_tx();
+
+        assert!(TxPolicies::default()
+            .with_tip(10)
+            .with_max_fee(100)
+            .with_script_gas_limit(max_gas_per_tx)
+            .validate(&consensus_parameters)
+            .is_ok());
+
+        let err = TxPolicies::default()
+            .with_tip(100)
+            .with_max_fee(10)
+            .with_script_gas_limit(max_gas_per_tx + 1)
+            .with_maturity(u64::MAX)
+            .validate(&consensus_parameters)
+            .expect_err("should fail");
+
+        let message = err.to_string();
+        assert!(message.contains("lower than the `tip`"));
+        assert!(message.contains("max gas per transaction"));
+        assert!(message.contains("not a valid block height"));
+    }
+
+    #[test]
+    fn cost_breakdown_adds_up_to_max_gas() -> Result<()> {
+        // given
+        let consensus_parameters = ConsensusParameters::standard();
+        let gas_per_byte = consensus_parameters.fee_params().gas_per_byte();
+        let predicate_code: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+
+        let mut tx: ScriptTransaction = FuelTransaction::script(
+            1_000,
+            vec![],
+            vec![],
+            Policies::default()
+                .with_witness_limit(64)
+                .with_tip(5)
+                .with_max_fee(10_000),
+            given_a_predicate_tx(predicate_code).inputs().clone(),
+            vec![],
+            vec![vec![0; 10].into()],
+        )
+        .into();
+        tx.precompute(&consensus_parameters.chain_id())?;
+        tx.estimate_predicates(&consensus_parameters)?;
+
+        // when
+        let breakdown = tx.cost_breakdown(&consensus_parameters);
+
+        // then
+        let predicate_gas = tx.inputs()[0].predicate_gas_used().expect("is a predicate");
+        assert_eq!(breakdown.predicate_gas, vec![(0, predicate_gas)]);
+        assert_eq!(breakdown.script_gas, 1_000);
+        assert_eq!(breakdown.witness_gas, 64 * gas_per_byte);
+        assert!(breakdown.base_gas > 0);
+        assert_eq!(breakdown.tip, 5);
+        assert_eq!(breakdown.witness_limit, 64);
+        assert_eq!(breakdown.max_fee, Some(10_000));
+        assert_eq!(breakdown.total_gas(), tx.max_gas(&consensus_parameters));
+
+        Ok(())
+    }
+
     #[test]
     fn append_witnesses_returns_error_when_limit_exceeded() {
         let mut tx = ScriptTransaction {
@@ -1,8 +1,8 @@
+pub mod call_instructions;
 pub mod constants;
 pub mod offsets;
 
-use constants::{WITNESS_STATIC_SIZE, WORD_SIZE};
-use fuel_tx::Witness;
+use constants::WORD_SIZE;
 
 use crate::{error, types::errors::Result};
 
@@ -26,13 +26,31 @@ pub fn checked_round_up_to_word_alignment(bytes_len: usize) -> Result<usize> {
         )
     })
 }
-pub(crate) fn calculate_witnesses_size<'a, I: IntoIterator<Item = &'a Witness>>(
-    witnesses: I,
-) -> usize {
-    witnesses
-        .into_iter()
-        .map(|w| w.as_ref().len() + WITNESS_STATIC_SIZE)
-        .sum()
+
+/// Awaits `future` for at most `duration`. Returns `None` if the duration elapsed first.
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+pub async fn timeout<T>(
+    duration: std::time::Duration,
+    future: impl std::future::Future<Output = T>,
+) -> Option<T> {
+    tokio::time::timeout(duration, future).await.ok()
+}
+
+/// Awaits `future` for at most `duration`. Returns `None` if the duration elapsed first.
+#[cfg(all(feature = "std", target_arch = "wasm32"))]
+pub async fn timeout<T>(
+    duration: std::time::Duration,
+    future: impl std::future::Future<Output = T>,
+) -> Option<T> {
+    use futures::future::{select, Either};
+
+    let future = std::pin::pin!(future);
+    let timer = gloo_timers::future::sleep(duration);
+
+    match select(future, timer).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
 }
 
 pub(crate) mod sealed {
@@ -48,3 +66,26 @@ pub(crate) fn to_named<'a, I: IntoIterator<Item = &'a crate::types::param_types:
         .map(|pt| ("".to_string(), pt.clone()))
         .collect()
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn timeout_returns_output_if_future_completes_in_time() {
+        let output = timeout(Duration::from_secs(1), async { 42 }).await;
+
+        assert_eq!(output, Some(42));
+    }
+
+    #[tokio::test]
+    async fn timeout_returns_none_if_future_takes_too_long() {
+        let slow_future = tokio::time::sleep(Duration::from_secs(10));
+
+        let output = timeout(Duration::from_millis(10), slow_future).await;
+
+        assert_eq!(output, None);
+    }
+}
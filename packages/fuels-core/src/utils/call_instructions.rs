@@ -0,0 +1,296 @@
+use fuel_asm::{op, Instruction, RegId};
+use itertools::Itertools;
+
+use crate::{error, types::errors::Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+/// Specifies offsets of [`Opcode::CALL`][`fuel_asm::Opcode::CALL`] parameters stored in the script
+/// data from which they can be loaded into registers
+pub struct CallOpcodeParamsOffset {
+    pub call_data_offset: usize,
+    pub amount_offset: usize,
+    pub asset_id_offset: usize,
+    pub gas_forwarded_offset: Option<usize>,
+    /// Offsets of the amount and asset ID of each asset transferred before the call
+    pub forwarded_assets_offsets: Vec<(usize, usize)>,
+}
+
+/// The registers into which the parameters of [`CALL`](fuel_asm::Opcode::CALL) are loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CallRegisters {
+    /// Holds the script data offset of the contract ID, followed by the selector and calldata.
+    pub call_data: RegId,
+    /// Holds the amount forwarded to the contract.
+    pub amount: RegId,
+    /// Holds the script data offset of the forwarded asset ID.
+    pub asset_id: RegId,
+    /// Holds the gas forwarded to the contract. Only used if the call forwards a gas amount,
+    /// otherwise the remaining context gas is forwarded.
+    pub gas: RegId,
+}
+
+impl Default for CallRegisters {
+    /// The first four registers that aren't reserved by the VM, as used by the SDK's calls.
+    fn default() -> Self {
+        Self {
+            call_data: RegId::new(0x10),
+            amount: RegId::new(0x11),
+            asset_id: RegId::new(0x12),
+            gas: RegId::new(0x13),
+        }
+    }
+}
+
+impl CallRegisters {
+    pub fn validate(&self) -> Result<()> {
+        let registers = [self.call_data, self.amount, self.asset_id, self.gas];
+
+        if let Some(reserved) = registers.iter().find(|reg| **reg < RegId::WRITABLE) {
+            return Err(error!(
+                Other,
+                "register `{reserved:?}` is reserved by the VM and cannot hold call parameters"
+            ));
+        }
+
+        if !registers.iter().all_unique() {
+            return Err(error!(
+                Other,
+                "the registers holding call parameters must be distinct, got `{self:?}`"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Given the offsets of the parameters of each call, create the actual opcodes used to call the
+/// contracts. The script still has to return after them
+pub fn get_instructions(
+    offsets: &[CallOpcodeParamsOffset],
+    registers: &CallRegisters,
+) -> Result<Vec<u8>> {
+    offsets
+        .iter()
+        .map(|offset| get_single_call_instructions(offset, registers))
+        .process_results(|iter| iter.flatten().collect::<Vec<_>>())
+}
+
+/// Returns the VM instructions for calling a contract method
+/// We use the [`Opcode`] to call a contract: [`CALL`](Opcode::CALL)
+/// pointing at the following registers, 0x10 to 0x13 by default:
+///
+/// `call_data` Script data offset
+/// `amount` Coin amount
+/// `asset_id` Asset ID
+/// `gas` Gas forwarded
+///
+/// Each asset forwarded in addition to the coin amount is first transferred to the contract with
+/// [`TR`](Opcode::TR), loading its amount into `amount` and its asset ID into `asset_id`.
+///
+/// Note that these are soft rules as we're picking the default registers simply because they
+/// are the first non-reserved ones.
+///
+/// [`Opcode`]: fuel_asm::Opcode
+/// [`Opcode::CALL`]: fuel_asm::Opcode::CALL
+/// [`Opcode::TR`]: fuel_asm::Opcode::TR
+pub fn get_single_call_instructions(
+    offsets: &CallOpcodeParamsOffset,
+    registers: &CallRegisters,
+) -> Result<Vec<u8>> {
+    let CallRegisters {
+        call_data,
+        amount,
+        asset_id,
+        gas,
+    } = *registers;
+
+    let call_data_offset = offsets
+        .call_data_offset
+        .try_into()
+        .expect("call_data_offset out of range");
+    let amount_offset = offsets
+        .amount_offset
+        .try_into()
+        .expect("amount_offset out of range");
+    let asset_id_offset = offsets
+        .asset_id_offset
+        .try_into()
+        .expect("asset_id_offset out of range");
+
+    // the call data starts with the contract ID, which is also the target of the transfers
+    let mut instructions = vec![op::movi(call_data, call_data_offset)];
+
+    for (forwarded_amount_offset, forwarded_asset_id_offset) in &offsets.forwarded_assets_offsets {
+        let forwarded_amount_offset = (*forwarded_amount_offset)
+            .try_into()
+            .expect("forwarded amount offset out of range");
+        let forwarded_asset_id_offset = (*forwarded_asset_id_offset)
+            .try_into()
+            .expect("forwarded asset_id offset out of range");
+
+        instructions.extend(&[
+            op::movi(amount, forwarded_amount_offset),
+            op::lw(amount, amount, 0),
+            op::movi(asset_id, forwarded_asset_id_offset),
+            op::tr(call_data, amount, asset_id),
+        ]);
+    }
+
+    instructions.extend(&[
+        op::movi(amount, amount_offset),
+        op::lw(amount, amount, 0),
+        op::movi(asset_id, asset_id_offset),
+    ]);
+
+    match offsets.gas_forwarded_offset {
+        Some(gas_forwarded_offset) => {
+            let gas_forwarded_offset = gas_forwarded_offset
+                .try_into()
+                .expect("gas_forwarded_offset out of range");
+
+            instructions.extend(&[
+                op::movi(gas, gas_forwarded_offset),
+                op::lw(gas, gas, 0),
+                op::call(call_data, amount, asset_id, gas),
+            ]);
+        }
+        // if `gas_forwarded` was not set use `REG_CGAS`
+        None => instructions.push(op::call(call_data, amount, asset_id, RegId::CGAS)),
+    };
+
+    #[allow(clippy::iter_cloned_collect)]
+    Ok(instructions.into_iter().collect::<Vec<u8>>())
+}
+
+/// Decodes the parameter offsets of the calls performed by `script`. Returns `None` unless
+/// `script` is exactly the script the SDK builds for these calls with the default registers and
+/// no custom instructions, i.e. the calls followed by [`RET`](fuel_asm::Opcode::RET).
+pub fn decode_call_script(script: &[u8]) -> Option<Vec<CallOpcodeParamsOffset>> {
+    if script.len() % Instruction::SIZE != 0 {
+        return None;
+    }
+
+    let instructions = script
+        .chunks(Instruction::SIZE)
+        .map(|bytes| Instruction::try_from(<[u8; 4]>::try_from(bytes).ok()?).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let registers = CallRegisters::default();
+    let movi_immediate = |index: usize, register: RegId| -> Option<usize> {
+        let Some(Instruction::MOVI(movi)) = instructions.get(index) else {
+            return None;
+        };
+        let (destination, immediate) = movi.unpack();
+
+        (destination == register).then(|| immediate.to_u32() as usize)
+    };
+
+    let calls_len = instructions.len().checked_sub(1)?;
+    let mut offsets = vec![];
+    let mut index = 0;
+    while index < calls_len {
+        let call_data_offset = movi_immediate(index, registers.call_data)?;
+        index += 1;
+
+        let mut forwarded_assets_offsets = vec![];
+        while matches!(instructions.get(index + 3), Some(Instruction::TR(_))) {
+            forwarded_assets_offsets.push((
+                movi_immediate(index, registers.amount)?,
+                movi_immediate(index + 2, registers.asset_id)?,
+            ));
+            index += 4;
+        }
+
+        let amount_offset = movi_immediate(index, registers.amount)?;
+        let asset_id_offset = movi_immediate(index + 2, registers.asset_id)?;
+        index += 3;
+
+        let gas_forwarded_offset = match instructions.get(index) {
+            Some(Instruction::CALL(_)) => {
+                index += 1;
+                None
+            }
+            _ => {
+                let offset = movi_immediate(index, registers.gas)?;
+                index += 3;
+                Some(offset)
+            }
+        };
+
+        offsets.push(CallOpcodeParamsOffset {
+            call_data_offset,
+            amount_offset,
+            asset_id_offset,
+            gas_forwarded_offset,
+            forwarded_assets_offsets,
+        });
+    }
+
+    // only the offsets were read so far, the script must be rebuilt from them byte for byte
+    let rebuilt = [
+        get_instructions(&offsets, &registers).ok()?,
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+    ]
+    .concat();
+
+    (rebuilt == script).then_some(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_scripts_are_decoded() -> Result<()> {
+        let offsets = vec![
+            CallOpcodeParamsOffset {
+                call_data_offset: 140,
+                amount_offset: 100,
+                asset_id_offset: 108,
+                gas_forwarded_offset: None,
+                forwarded_assets_offsets: vec![],
+            },
+            CallOpcodeParamsOffset {
+                call_data_offset: 340,
+                amount_offset: 300,
+                asset_id_offset: 308,
+                gas_forwarded_offset: Some(420),
+                forwarded_assets_offsets: vec![(428, 436)],
+            },
+        ];
+        let script = [
+            get_instructions(&offsets, &CallRegisters::default())?,
+            op::ret(RegId::ONE).to_bytes().to_vec(),
+        ]
+        .concat();
+
+        assert_eq!(decode_call_script(&script), Some(offsets));
+
+        Ok(())
+    }
+
+    #[test]
+    fn other_scripts_are_not_decoded() -> Result<()> {
+        let offsets = [CallOpcodeParamsOffset::default()];
+        let calls = get_instructions(&offsets, &CallRegisters::default())?;
+        let ret = op::ret(RegId::ONE).to_bytes().to_vec();
+
+        // an instruction sneaked in after the calls
+        let transfer_out = op::tro(0x10, 0x11, 0x11, 0x12).to_bytes().to_vec();
+        let script = [calls.clone(), transfer_out, ret.clone()].concat();
+        assert_eq!(decode_call_script(&script), None);
+
+        // the call doesn't return
+        assert_eq!(decode_call_script(&calls), None);
+
+        // custom registers
+        let registers = CallRegisters {
+            call_data: RegId::new(0x20),
+            ..Default::default()
+        };
+        let script = [get_instructions(&offsets, &registers)?, ret].concat();
+        assert_eq!(decode_call_script(&script), None);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
+use core::num::NonZeroUsize;
+
 use fuel_tx::Word;
+pub use fuels_signing::WITNESS_STATIC_SIZE;
 
 pub const ENUM_DISCRIMINANT_BYTE_WIDTH: usize = 8;
 pub const WORD_SIZE: usize = core::mem::size_of::<Word>();
@@ -10,7 +13,11 @@ pub const DEFAULT_CALL_PARAMS_AMOUNT: u64 = 0;
 pub const DEFAULT_GAS_ESTIMATION_TOLERANCE: f64 = 0.2;
 pub const DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON: u32 = 1;
 
+pub const DEFAULT_PAGINATION_CONCURRENCY: NonZeroUsize = match NonZeroUsize::new(4) {
+    Some(concurrency) => concurrency,
+    None => unreachable!(),
+};
+
 // The size of a signature inside a transaction `Witness`
-pub const WITNESS_STATIC_SIZE: usize = 8;
 const SIGNATURE_SIZE: usize = 64;
 pub const SIGNATURE_WITNESS_SIZE: usize = WITNESS_STATIC_SIZE + SIGNATURE_SIZE;
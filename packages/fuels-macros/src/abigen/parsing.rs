@@ -1,4 +1,6 @@
-use fuels_code_gen::{Abi, AbigenTarget, ProgramType};
+use std::path::PathBuf;
+
+use fuels_code_gen::{Abi, AbigenTarget, ProgramType, TypeAttributes};
 use syn::{
     parse::{Parse, ParseStream},
     LitStr, Result,
@@ -6,19 +8,18 @@ use syn::{
 
 use crate::parse_utils::{Command, UniqueNameValues};
 
-impl From<MacroAbigenTargets> for Vec<AbigenTarget> {
-    fn from(targets: MacroAbigenTargets) -> Self {
-        targets.targets.into_iter().map(Into::into).collect()
-    }
-}
-
 impl From<MacroAbigenTarget> for AbigenTarget {
     fn from(macro_target: MacroAbigenTarget) -> Self {
-        AbigenTarget::new(
+        let target = AbigenTarget::new(
             macro_target.name,
             macro_target.source,
             macro_target.program_type,
-        )
+        );
+
+        match macro_target.binary_path {
+            Some(binary_path) => target.with_binary_path(binary_path),
+            None => target,
+        }
     }
 }
 
@@ -29,38 +30,130 @@ pub(crate) struct MacroAbigenTarget {
     pub(crate) name: String,
     pub(crate) source: Abi,
     pub program_type: ProgramType,
+    pub(crate) binary_path: Option<PathBuf>,
 }
 
 pub(crate) struct MacroAbigenTargets {
     targets: Vec<MacroAbigenTarget>,
+    type_attributes: Vec<TypeAttributes>,
+}
+
+impl MacroAbigenTargets {
+    pub(crate) fn into_parts(self) -> (Vec<AbigenTarget>, Vec<TypeAttributes>) {
+        let type_attributes = self.type_attributes;
+        let targets = self.targets.into_iter().map(Into::into).collect();
+
+        (targets, type_attributes)
+    }
 }
 
 impl Parse for MacroAbigenTargets {
     fn parse(input: ParseStream) -> Result<Self> {
-        let targets = Command::parse_multiple(input)?
+        let (attribute_commands, target_commands): (Vec<_>, Vec<_>) =
+            Command::parse_multiple(input)?
+                .into_iter()
+                .partition(|command| command.name == "TypeAttributes");
+
+        let targets = target_commands
             .into_iter()
             .map(MacroAbigenTarget::new)
             .collect::<Result<_>>()?;
+        let type_attributes = attribute_commands
+            .into_iter()
+            .map(parse_type_attributes)
+            .collect::<Result<_>>()?;
 
-        Ok(Self { targets })
+        Ok(Self {
+            targets,
+            type_attributes,
+        })
     }
 }
 
+/// Parses `TypeAttributes(types = "MyEnum, MyStruct", attributes = "#[non_exhaustive]")`.
+fn parse_type_attributes(command: Command) -> Result<TypeAttributes> {
+    let name_values = UniqueNameValues::new(command.contents)?;
+    name_values.validate_has_no_other_names(&["types", "attributes"])?;
+
+    let types = name_values.get_as_lit_str("types")?;
+    let attributes = name_values.get_as_lit_str("attributes")?;
+
+    TypeAttributes::new(&types.value(), &attributes.value())
+        .map_err(|e| syn::Error::new(attributes.span(), e.to_string()))
+}
+
 impl MacroAbigenTarget {
     pub fn new(command: Command) -> Result<Self> {
+        let span = command.name.span();
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "abi"])?;
+        name_values
+            .validate_has_no_other_names(&["name", "abi", "abi_env", "project", "profile"])?;
 
         let name = name_values.get_as_lit_str("name")?.value();
-        let abi_lit_str = name_values.get_as_lit_str("abi")?;
-        let source = Self::parse_inline_or_load_abi(abi_lit_str)?;
+
+        let num_sources = ["abi", "abi_env", "project"]
+            .into_iter()
+            .filter(|source| name_values.try_get(source).is_some())
+            .count();
+        if num_sources > 1 {
+            return Err(syn::Error::new(
+                span,
+                "only one of 'abi', 'abi_env' and 'project' can be given",
+            ));
+        }
+        if name_values.try_get("profile").is_some() && name_values.try_get("project").is_none() {
+            return Err(syn::Error::new(
+                span,
+                "'profile' can only be given together with 'project'",
+            ));
+        }
+
+        if name_values.try_get("project").is_some() {
+            let profile = match name_values.try_get("profile") {
+                Some(_) => Some(name_values.get_as_lit_str("profile")?.value()),
+                None => None,
+            };
+
+            return Self::load_from_project(
+                name,
+                program_type,
+                name_values.get_as_lit_str("project")?,
+                profile.as_deref(),
+            );
+        }
+
+        let source = match name_values.try_get("abi_env") {
+            Some(_) => Self::load_abi_from_env(name_values.get_as_lit_str("abi_env")?)?,
+            None => Self::parse_inline_or_load_abi(name_values.get_as_lit_str("abi")?)?,
+        };
 
         Ok(Self {
             name,
             source,
             program_type,
+            binary_path: None,
+        })
+    }
+
+    /// Finds the ABI and binary built by `forc build` in the `out` directory of a forc project,
+    /// see [`AbigenTarget::from_forc_project`].
+    fn load_from_project(
+        name: String,
+        program_type: ProgramType,
+        project_lit_str: &LitStr,
+        profile: Option<&str>,
+    ) -> Result<Self> {
+        let target =
+            AbigenTarget::from_forc_project(name, project_lit_str.value(), program_type, profile)
+                .map_err(|e| syn::Error::new(project_lit_str.span(), e.to_string()))?;
+
+        Ok(Self {
+            name: target.name().to_string(),
+            source: target.source().clone(),
+            program_type,
+            binary_path: target.binary_path().cloned(),
         })
     }
 
@@ -75,4 +168,18 @@ impl MacroAbigenTarget {
         }
         .map_err(|e| syn::Error::new(abi_lit_str.span(), e.to_string()))
     }
+
+    /// Loads the ABI from the path held by an environment variable, e.g. one set by a build
+    /// script with `fuels_build::compile`.
+    fn load_abi_from_env(env_var_lit_str: &LitStr) -> Result<Abi> {
+        let env_var = env_var_lit_str.value();
+        let path = std::env::var(&env_var).map_err(|e| {
+            syn::Error::new(
+                env_var_lit_str.span(),
+                format!("failed to read the `abi` path from environment variable `{env_var}`: {e}"),
+            )
+        })?;
+
+        Abi::load_from(path).map_err(|e| syn::Error::new(env_var_lit_str.span(), e.to_string()))
+    }
 }
@@ -1,3 +1,4 @@
+pub mod account;
 pub mod parameterize;
 pub mod tokenizable;
 pub mod try_from;
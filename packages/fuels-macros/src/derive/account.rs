@@ -0,0 +1,136 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput, Error, Field, Fields, Member, Result};
+
+pub fn generate_account_impl(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Account` can only be derived for structs",
+        ));
+    };
+
+    let (delegate, delegate_field) = find_delegate(&data.fields, &input)?;
+    let delegate_type = &delegate_field.ty;
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { #delegate_type: ::fuels::accounts::Account });
+    let (impl_gen, type_gen, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_gen ::fuels::accounts::ViewOnlyAccount for #name #type_gen #where_clause {
+            fn address(&self) -> &::fuels::types::bech32::Bech32Address {
+                ::fuels::accounts::ViewOnlyAccount::address(&self.#delegate)
+            }
+
+            fn try_provider(
+                &self,
+            ) -> ::fuels::types::errors::Result<&::fuels::accounts::provider::Provider> {
+                ::fuels::accounts::ViewOnlyAccount::try_provider(&self.#delegate)
+            }
+        }
+
+        #[cfg_attr(not(target_arch = "wasm32"), ::fuels::accounts::__private::async_trait)]
+        impl #impl_gen ::fuels::accounts::Account for #name #type_gen #where_clause {
+            async fn get_asset_inputs_for_amount(
+                &self,
+                asset_id: ::fuels::types::AssetId,
+                amount: ::core::primitive::u64,
+            ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::types::input::Input>> {
+                ::fuels::accounts::Account::get_asset_inputs_for_amount(
+                    &self.#delegate,
+                    asset_id,
+                    amount,
+                )
+                .await
+            }
+
+            async fn get_asset_inputs_for_amount_filtered(
+                &self,
+                asset_id: ::fuels::types::AssetId,
+                amount: ::core::primitive::u64,
+                filter: ::fuels::accounts::CoinFilter,
+            ) -> ::fuels::types::errors::Result<::std::vec::Vec<::fuels::types::input::Input>> {
+                ::fuels::accounts::Account::get_asset_inputs_for_amount_filtered(
+                    &self.#delegate,
+                    asset_id,
+                    amount,
+                    filter,
+                )
+                .await
+            }
+
+            fn get_asset_outputs_for_amount(
+                &self,
+                to: &::fuels::types::bech32::Bech32Address,
+                asset_id: ::fuels::types::AssetId,
+                amount: ::core::primitive::u64,
+            ) -> ::std::vec::Vec<::fuels::tx::Output> {
+                ::fuels::accounts::Account::get_asset_outputs_for_amount(
+                    &self.#delegate,
+                    to,
+                    asset_id,
+                    amount,
+                )
+            }
+
+            fn add_witnesses<Tb: ::fuels::types::transaction_builders::TransactionBuilder>(
+                &self,
+                tb: &mut Tb,
+            ) -> ::fuels::types::errors::Result<()> {
+                ::fuels::accounts::Account::add_witnesses(&self.#delegate, tb)
+            }
+        }
+    })
+}
+
+fn find_delegate<'a>(fields: &'a Fields, input: &DeriveInput) -> Result<(Member, &'a Field)> {
+    let mut delegates = vec![];
+
+    for (index, field) in fields.iter().enumerate() {
+        if is_delegate(field)? {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            };
+            delegates.push((member, field));
+        }
+    }
+
+    match delegates.len() {
+        0 => Err(Error::new_spanned(
+            &input.ident,
+            "expected a field marked with `#[account(delegate)]`",
+        )),
+        1 => Ok(delegates.pop().expect("has one element")),
+        _ => Err(Error::new_spanned(
+            delegates[1].1,
+            "only one field can be marked with `#[account(delegate)]`",
+        )),
+    }
+}
+
+fn is_delegate(field: &Field) -> Result<bool> {
+    let mut is_delegate = false;
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("account"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("delegate") {
+                is_delegate = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `delegate`"))
+            }
+        })?;
+    }
+
+    Ok(is_delegate)
+}
@@ -52,19 +52,49 @@ fn parameterize_for_struct(
 
     let std_lib = std_lib_path(no_std);
 
+    let param_type = cached_param_type(
+        quote! {
+            #fuels_types_path::param_types::ParamType::Struct{
+                name: #std_lib::string::String::from(#name_stringified),
+                fields: #std_lib::vec![#((#field_names, #param_type_calls)),*],
+                generics: #std_lib::vec![#(#generic_param_types),*],
+            }
+        },
+        &generics,
+        &fuels_types_path,
+        no_std,
+    );
+
     Ok(quote! {
         impl #impl_gen #fuels_core_path::traits::Parameterize for #name #type_gen #where_clause {
             fn param_type() -> #fuels_types_path::param_types::ParamType {
-                #fuels_types_path::param_types::ParamType::Struct{
-                    name: #std_lib::string::String::from(#name_stringified),
-                    fields: #std_lib::vec![#((#field_names, #param_type_calls)),*],
-                    generics: #std_lib::vec![#(#generic_param_types),*],
-                }
+                #param_type
             }
         }
     })
 }
 
+/// Resolves the `ParamType` of non-generic types only once, so that calls returning them don't
+/// rebuild it every time. A static inside a generic function would be shared by all of its
+/// instantiations, so generic types are still resolved on every call, as are `no_std` types.
+fn cached_param_type(
+    param_type: TokenStream,
+    generics: &Generics,
+    fuels_types_path: &TokenStream,
+    no_std: bool,
+) -> TokenStream {
+    if no_std || !generics.params.is_empty() {
+        return param_type;
+    }
+
+    quote! {
+        static PARAM_TYPE: ::std::sync::OnceLock<#fuels_types_path::param_types::ParamType> =
+            ::std::sync::OnceLock::new();
+
+        ::std::clone::Clone::clone(PARAM_TYPE.get_or_init(|| { #param_type }))
+    }
+}
+
 fn parameterize_generic_params(
     generics: &Generics,
     fuels_core_path: &TokenStream,
@@ -98,22 +128,31 @@ fn parameterize_for_enum(
 
     let std_lib = std_lib_path(no_std);
 
+    let param_type = cached_param_type(
+        quote! {
+            let variants = #std_lib::vec![#((#variant_names, #variant_param_types)),*];
+            let enum_variants = #fuels_types_path::param_types::EnumVariants::new(variants)
+                .unwrap_or_else(|_| ::std::panic!(
+                        "{} has no variants which isn't allowed",
+                        #enum_name_str
+                    )
+                );
+
+            #fuels_types_path::param_types::ParamType::Enum {
+                name: #std_lib::string::String::from(#enum_name_str),
+                enum_variants,
+                generics: #std_lib::vec![#(#generic_param_types),*]
+            }
+        },
+        &generics,
+        &fuels_types_path,
+        no_std,
+    );
+
     Ok(quote! {
         impl #impl_gen #fuels_core_path::traits::Parameterize for #name #type_gen #where_clause {
             fn param_type() -> #fuels_types_path::param_types::ParamType {
-                let variants = #std_lib::vec![#((#variant_names, #variant_param_types)),*];
-                let enum_variants = #fuels_types_path::param_types::EnumVariants::new(variants)
-                    .unwrap_or_else(|_| ::std::panic!(
-                            "{} has no variants which isn't allowed",
-                            #enum_name_str
-                        )
-                    );
-
-                #fuels_types_path::param_types::ParamType::Enum {
-                    name: #std_lib::string::String::from(#enum_name_str),
-                    enum_variants,
-                    generics: #std_lib::vec![#(#generic_param_types),*]
-                }
+                #param_type
             }
         }
     })
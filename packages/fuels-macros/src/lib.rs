@@ -5,8 +5,8 @@ use syn::{parse_macro_input, DeriveInput};
 use crate::{
     abigen::MacroAbigenTargets,
     derive::{
-        parameterize::generate_parameterize_impl, tokenizable::generate_tokenizable_impl,
-        try_from::generate_try_from_impl,
+        account::generate_account_impl, parameterize::generate_parameterize_impl,
+        tokenizable::generate_tokenizable_impl, try_from::generate_try_from_impl,
     },
     setup_program_test::{generate_setup_program_test_code, TestProgramCommands},
 };
@@ -19,10 +19,16 @@ mod setup_program_test;
 /// Used to generate bindings for Contracts, Scripts and Predicates. Accepts
 /// input in the form of `ProgramType(name="MyBindings", abi=ABI_SOURCE)...`
 ///
-/// `ProgramType` is either `Contract`, `Script` or `Predicate`.
+/// `ProgramType` is either `Contract`, `Script`, `Predicate` or `Interface`. An `Interface`
+/// generates a trait implemented by every `Contract` exposing all of its functions.
 ///
 /// `ABI_SOURCE` is a string literal representing either a path to the JSON ABI
-/// file or the contents of the JSON ABI file itself.
+/// file or the contents of the JSON ABI file itself. Instead of `abi`, `project` can be
+/// given the path of a forc project whose `out/release` or `out/debug` directory holds the
+/// ABI, optionally restricted to one build profile with `profile`.
+///
+/// Extra attributes can be added to generated structs and enums with
+/// `TypeAttributes(types = "MyEnum, MyStruct", attributes = "#[non_exhaustive]")`.
 ///
 ///```text
 /// abigen!(Contract(
@@ -34,18 +40,18 @@ mod setup_program_test;
 /// More details can be found in the [`Fuel Rust SDK Book`](https://fuellabs.github.io/fuels-rs/latest)
 #[proc_macro]
 pub fn abigen(input: TokenStream) -> TokenStream {
-    let targets = parse_macro_input!(input as MacroAbigenTargets);
+    let (targets, type_attributes) = parse_macro_input!(input as MacroAbigenTargets).into_parts();
 
-    Abigen::generate(targets.into(), false)
+    Abigen::generate_with_type_attributes(targets, type_attributes, false)
         .expect("abigen generation failed")
         .into()
 }
 
 #[proc_macro]
 pub fn wasm_abigen(input: TokenStream) -> TokenStream {
-    let targets = parse_macro_input!(input as MacroAbigenTargets);
+    let (targets, type_attributes) = parse_macro_input!(input as MacroAbigenTargets).into_parts();
 
-    Abigen::generate(targets.into(), true)
+    Abigen::generate_with_type_attributes(targets, type_attributes, true)
         .expect("abigen generation failed")
         .into()
 }
@@ -88,3 +94,24 @@ pub fn try_from(stream: TokenStream) -> TokenStream {
         .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
+
+/// Implements `ViewOnlyAccount` and `Account` for a struct wrapping an account, e.g. a
+/// `WalletUnlocked` or a `Predicate` kept together with application data. Every method is
+/// delegated to the field marked with `#[account(delegate)]`:
+///
+///```text
+/// #[derive(Debug, Clone, Account)]
+/// struct Treasury {
+///     #[account(delegate)]
+///     wallet: WalletUnlocked,
+///     label: String,
+/// }
+///```
+#[proc_macro_derive(Account, attributes(account))]
+pub fn account(stream: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(stream as DeriveInput);
+
+    generate_account_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
@@ -2,4 +2,5 @@ pub(crate) use code_gen::generate_setup_program_test_code;
 pub(crate) use parsing::TestProgramCommands;
 
 mod code_gen;
+mod forc_build;
 mod parsing;
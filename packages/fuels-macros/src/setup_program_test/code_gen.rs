@@ -8,9 +8,12 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::LitStr;
 
-use crate::setup_program_test::parsing::{
-    AbigenCommand, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
-    TestProgramCommands,
+use crate::setup_program_test::{
+    forc_build::build_if_stale,
+    parsing::{
+        AbigenCommand, DeployContractCommand, InitializeWalletCommand, LoadScriptCommand,
+        TestProgramCommands,
+    },
 };
 
 pub(crate) fn generate_setup_program_test_code(
@@ -42,7 +45,11 @@ fn generate_project_lookup(commands: &AbigenCommand) -> syn::Result<HashMap<Stri
         .targets
         .iter()
         .map(|command| -> syn::Result<_> {
-            let project = Project::new(command.program_type, &command.project)?;
+            let project = Project::new(command.program_type, &command.project, &command.profile)?;
+            if command.build {
+                project.build_if_stale()?;
+            }
+
             Ok((command.name.value(), project))
         })
         .collect::<Result<Vec<_>, _>>()?;
@@ -190,10 +197,11 @@ struct Project {
     program_type: ProgramType,
     path: PathBuf,
     path_span: Span,
+    profile: String,
 }
 
 impl Project {
-    fn new(program_type: ProgramType, dir: &LitStr) -> syn::Result<Self> {
+    fn new(program_type: ProgramType, dir: &LitStr, profile: &str) -> syn::Result<Self> {
         let path = Path::new(&dir.value()).canonicalize().map_err(|_| {
             syn::Error::new_spanned(
                 dir.clone(),
@@ -205,12 +213,22 @@ impl Project {
             program_type,
             path,
             path_span: dir.span(),
+            profile: profile.to_string(),
         })
     }
 
+    fn build_if_stale(&self) -> syn::Result<()> {
+        let artifacts = [self.abi_path(), self.bin_path()].map(PathBuf::from);
+
+        build_if_stale(&self.path, &self.profile, &artifacts)
+            .map_err(|e| syn::Error::new(self.path_span, e))
+    }
+
     fn compile_file_path(&self, suffix: &str, description: &str) -> String {
         self.path
-            .join(["out/release/", self.project_name(), suffix].concat())
+            .join("out")
+            .join(&self.profile)
+            .join([self.project_name(), suffix].concat())
             .to_str()
             .unwrap_or_else(|| panic!("could not join path for {description}"))
             .to_string()
@@ -0,0 +1,107 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Runs `forc build` for the project at `project_dir` with the build `profile`, unless all of
+/// the `artifacts` exist and are newer than every source file of the project.
+pub(crate) fn build_if_stale(
+    project_dir: &Path,
+    profile: &str,
+    artifacts: &[PathBuf],
+) -> Result<(), String> {
+    let stale = is_stale(project_dir, artifacts).map_err(|e| {
+        format!(
+            "failed to check whether `{}` needs to be built: {e}",
+            project_dir.display()
+        )
+    })?;
+
+    if stale {
+        fuels_build::build(project_dir, profile).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+fn is_stale(project_dir: &Path, artifacts: &[PathBuf]) -> io::Result<bool> {
+    let mut oldest_artifact = None;
+    for artifact in artifacts {
+        match fs::metadata(artifact) {
+            Ok(metadata) => {
+                let modified = metadata.modified()?;
+                oldest_artifact = Some(
+                    oldest_artifact.map_or(modified, |oldest: SystemTime| oldest.min(modified)),
+                );
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(true),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let Some(oldest_artifact) = oldest_artifact else {
+        return Ok(true);
+    };
+
+    Ok(latest_source_change(project_dir)?.is_some_and(|changed| changed > oldest_artifact))
+}
+
+/// The latest modification time of the files of the project, ignoring its build output.
+fn latest_source_change(project_dir: &Path) -> io::Result<Option<SystemTime>> {
+    let mut latest = None;
+    for file in fuels_build::source_files(project_dir).map_err(io::Error::other)? {
+        latest = latest.max(Some(fs::metadata(file)?.modified()?));
+    }
+
+    Ok(latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::File,
+        time::{Duration, SystemTime},
+    };
+
+    use super::*;
+
+    fn touch(path: &Path, modified: SystemTime) -> io::Result<()> {
+        fs::create_dir_all(path.parent().expect("has a parent"))?;
+        File::create(path)?.set_modified(modified)
+    }
+
+    #[test]
+    fn artifacts_are_stale_if_missing_or_older_than_the_sources() -> io::Result<()> {
+        // given
+        let project = tempfile::tempdir()?;
+        let now = SystemTime::now();
+        let artifacts = [
+            project.path().join("out/release/project.bin"),
+            project.path().join("out/release/project-abi.json"),
+        ];
+        touch(&project.path().join("Forc.toml"), now)?;
+        touch(&project.path().join("src/main.sw"), now)?;
+
+        // then
+        assert!(is_stale(project.path(), &artifacts)?);
+
+        // when
+        touch(&artifacts[0], now + Duration::from_secs(1))?;
+        touch(&artifacts[1], now + Duration::from_secs(1))?;
+
+        // then
+        assert!(!is_stale(project.path(), &artifacts)?);
+
+        // when
+        touch(
+            &project.path().join("src/main.sw"),
+            now + Duration::from_secs(2),
+        )?;
+
+        // then
+        assert!(is_stale(project.path(), &artifacts)?);
+
+        Ok(())
+    }
+}
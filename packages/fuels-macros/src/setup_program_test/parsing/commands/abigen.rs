@@ -2,7 +2,7 @@ use std::convert::TryFrom;
 
 use fuels_code_gen::ProgramType;
 use proc_macro2::Span;
-use syn::{Error, LitStr};
+use syn::{Error, Lit, LitStr};
 
 use crate::parse_utils::{Command, UniqueNameValues};
 
@@ -11,6 +11,9 @@ pub(crate) struct TargetInfo {
     pub(crate) name: LitStr,
     pub(crate) project: LitStr,
     pub(crate) program_type: ProgramType,
+    /// Whether to run `forc build` if the artifacts are missing or stale.
+    pub(crate) build: bool,
+    pub(crate) profile: String,
 }
 
 impl TryFrom<Command> for TargetInfo {
@@ -20,15 +23,32 @@ impl TryFrom<Command> for TargetInfo {
         let program_type = command.name.try_into()?;
 
         let name_values = UniqueNameValues::new(command.contents)?;
-        name_values.validate_has_no_other_names(&["name", "project"])?;
+        name_values.validate_has_no_other_names(&["name", "project", "build", "profile"])?;
 
         let name = name_values.get_as_lit_str("name")?.clone();
         let project = name_values.get_as_lit_str("project")?.clone();
 
+        let build = match name_values.try_get("build") {
+            Some(Lit::Bool(build)) => build.value,
+            Some(lit) => {
+                return Err(Error::new_spanned(
+                    lit.clone(),
+                    "expected the attribute 'build' to have a boolean value",
+                ))
+            }
+            None => false,
+        };
+        let profile = match name_values.try_get("profile") {
+            Some(_) => name_values.get_as_lit_str("profile")?.value(),
+            None => "release".to_string(),
+        };
+
         Ok(Self {
             name,
             project,
             program_type,
+            build,
+            profile,
         })
     }
 }
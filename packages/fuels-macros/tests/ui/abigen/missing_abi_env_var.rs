@@ -0,0 +1,5 @@
+use fuels_macros::abigen;
+
+abigen!(Contract(name = "SomeName", abi_env = "FUELS_UNSET_ABI"));
+
+fn main() {}
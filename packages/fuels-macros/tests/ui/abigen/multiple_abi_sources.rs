@@ -0,0 +1,9 @@
+use fuels_macros::abigen;
+
+abigen!(Contract(
+    name = "SomeName",
+    abi = "some-abi.json",
+    abi_env = "SOME_ABI"
+));
+
+fn main() {}
@@ -0,0 +1,8 @@
+use fuels_macros::Account;
+
+#[derive(Account)]
+struct Treasury {
+    label: String,
+}
+
+fn main() {}
@@ -0,0 +1,283 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use fuel_abi_types::abi::program::ProgramABI;
+use fuels_accounts::{
+    predicate::Predicate,
+    provider::Provider,
+    wallet::{WalletUnlocked, DEFAULT_DERIVATION_PATH_PREFIX},
+    Account, DynAccount,
+};
+use fuels_core::{
+    codec::ABIEncoder,
+    types::{
+        errors::{error, Result},
+        param_types::ParamType,
+        DynamicValue, Token,
+    },
+};
+use serde::Deserialize;
+
+/// Declares a set of named accounts, see [`Accounts`].
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AccountsConfig {
+    #[serde(default)]
+    pub accounts: HashMap<String, AccountConfig>,
+}
+
+/// How to instantiate an account. Secrets are never part of the configuration, they are read
+/// from the environment variables named in it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccountConfig {
+    /// A wallet derived from the mnemonic phrase stored in the environment variable
+    /// `mnemonic_env`. The derivation path defaults to the first account of the phrase.
+    Mnemonic {
+        mnemonic_env: String,
+        #[serde(default)]
+        derivation_path: Option<String>,
+    },
+    /// A wallet decrypted from a keystore file with the password stored in the environment
+    /// variable `password_env`.
+    Keystore { path: PathBuf, password_env: String },
+    /// A predicate. Its `data` holds the arguments of the predicate's `main` function, as
+    /// described by the predicate's `abi`.
+    Predicate {
+        code: PathBuf,
+        #[serde(default)]
+        abi: Option<PathBuf>,
+        #[serde(default)]
+        data: Vec<DynamicValue>,
+    },
+}
+
+/// Named accounts instantiated from a declarative configuration, so that scripts and services
+/// don't have to repeat the code setting up their wallets and predicates.
+///
+/// A TOML configuration looks like this:
+///
+/// ```toml
+/// [accounts.deployer]
+/// type = "mnemonic"
+/// mnemonic_env = "DEPLOYER_MNEMONIC"
+///
+/// [accounts.bot]
+/// type = "keystore"
+/// path = "keys/bot.json"
+/// password_env = "BOT_PASSWORD"
+///
+/// [accounts.vault]
+/// type = "predicate"
+/// code = "out/release/vault.bin"
+/// abi = "out/release/vault-abi.json"
+/// data = [{ U64 = 4097 }, { U64 = 4097 }]
+/// ```
+///
+/// Relative paths are resolved against the directory of the configuration file.
+#[derive(Debug, Clone, Default)]
+pub struct Accounts {
+    accounts: HashMap<String, DynAccount>,
+}
+
+impl Accounts {
+    pub fn load_from_toml(path: impl AsRef<Path>, provider: Provider) -> Result<Self> {
+        let path = path.as_ref();
+        let config = toml::from_str(&read_config(path)?)
+            .map_err(|err| error!(Other, "could not parse `{}`: {err}", path.display()))?;
+
+        Self::from_config(config, config_dir(path), provider)
+    }
+
+    pub fn load_from_json(path: impl AsRef<Path>, provider: Provider) -> Result<Self> {
+        let path = path.as_ref();
+        let config = serde_json::from_str(&read_config(path)?)
+            .map_err(|err| error!(Other, "could not parse `{}`: {err}", path.display()))?;
+
+        Self::from_config(config, config_dir(path), provider)
+    }
+
+    /// Instantiates the accounts of `config`, resolving relative paths against `base_dir`.
+    pub fn from_config(
+        config: AccountsConfig,
+        base_dir: impl AsRef<Path>,
+        provider: Provider,
+    ) -> Result<Self> {
+        let base_dir = base_dir.as_ref();
+
+        let accounts = config
+            .accounts
+            .into_iter()
+            .map(|(name, account_config)| {
+                let account = instantiate(account_config, base_dir, &provider)
+                    .map_err(|err| error!(Other, "could not load account `{name}`: {err}"))?;
+
+                Ok((name, account))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { accounts })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DynAccount> {
+        self.accounts.get(name)
+    }
+
+    /// Returns the account `name` if it is a `T`, e.g. a [`WalletUnlocked`] or a [`Predicate`].
+    pub fn get_as<T: Account + 'static>(&self, name: &str) -> Option<&T> {
+        self.get(name)?.downcast_ref()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &DynAccount)> {
+        self.accounts
+            .iter()
+            .map(|(name, account)| (name.as_str(), account))
+    }
+
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+}
+
+fn read_config(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| error!(Other, "could not read `{}`: {err}", path.display()))
+}
+
+fn config_dir(path: &Path) -> &Path {
+    path.parent().unwrap_or_else(|| Path::new("."))
+}
+
+fn instantiate(config: AccountConfig, base_dir: &Path, provider: &Provider) -> Result<DynAccount> {
+    let account = match config {
+        AccountConfig::Mnemonic {
+            mnemonic_env,
+            derivation_path,
+        } => {
+            let phrase = read_env(&mnemonic_env)?;
+            let path = derivation_path
+                .unwrap_or_else(|| format!("{DEFAULT_DERIVATION_PATH_PREFIX}/0'/0/0"));
+
+            DynAccount::new(WalletUnlocked::new_from_mnemonic_phrase_with_path(
+                &phrase,
+                Some(provider.clone()),
+                &path,
+            )?)
+        }
+        #[cfg(feature = "keystore")]
+        AccountConfig::Keystore { path, password_env } => {
+            let password = read_env(&password_env)?;
+
+            DynAccount::new(WalletUnlocked::load_keystore(
+                base_dir.join(path),
+                password,
+                Some(provider.clone()),
+            )?)
+        }
+        #[cfg(not(feature = "keystore"))]
+        AccountConfig::Keystore { .. } => {
+            return Err(error!(
+                Other,
+                "keystore accounts require the `keystore` feature"
+            ))
+        }
+        AccountConfig::Predicate { code, abi, data } => {
+            let code = fs::read(base_dir.join(code))?;
+            let tokens = match abi {
+                Some(abi) => predicate_data_tokens(&base_dir.join(abi), data)?,
+                None if data.is_empty() => vec![],
+                None => return Err(error!(Other, "predicate data requires the predicate's ABI")),
+            };
+            let data = ABIEncoder::default().encode(&tokens)?;
+
+            DynAccount::new(
+                Predicate::from_code(code)
+                    .with_data(data)
+                    .with_provider(provider.clone()),
+            )
+        }
+    };
+
+    Ok(account)
+}
+
+fn read_env(name: &str) -> Result<String> {
+    env::var(name).map_err(|_| error!(Other, "environment variable `{name}` is not set"))
+}
+
+fn predicate_data_tokens(abi_path: &Path, data: Vec<DynamicValue>) -> Result<Vec<Token>> {
+    let abi: ProgramABI = serde_json::from_str(&read_config(abi_path)?)
+        .map_err(|err| error!(Other, "could not parse the ABI: {err}"))?;
+    let type_lookup: HashMap<_, _> = abi
+        .types
+        .into_iter()
+        .map(|declaration| (declaration.type_id, declaration))
+        .collect();
+
+    let main = abi
+        .functions
+        .iter()
+        .find(|function| function.name == "main")
+        .ok_or_else(|| error!(Other, "the ABI has no `main` function"))?;
+
+    if main.inputs.len() != data.len() {
+        return Err(error!(
+            Other,
+            "`main` takes {} arguments, got {}",
+            main.inputs.len(),
+            data.len()
+        ));
+    }
+
+    main.inputs
+        .iter()
+        .zip(data)
+        .map(|(input, value)| {
+            let param_type = ParamType::try_from_type_application(input, &type_lookup)?;
+
+            value.into_token(&param_type)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn configs_are_parsed() -> Result<()> {
+        let config: AccountsConfig = toml::from_str(
+            r#"
+            [accounts.deployer]
+            type = "mnemonic"
+            mnemonic_env = "DEPLOYER_MNEMONIC"
+
+            [accounts.vault]
+            type = "predicate"
+            code = "vault.bin"
+            abi = "vault-abi.json"
+            data = [{ U64 = 4097 }, { Bool = true }]
+            "#,
+        )
+        .map_err(|err| error!(Other, "{err}"))?;
+
+        assert!(matches!(
+            &config.accounts["deployer"],
+            AccountConfig::Mnemonic { mnemonic_env, derivation_path: None }
+                if mnemonic_env == "DEPLOYER_MNEMONIC"
+        ));
+        assert!(matches!(
+            &config.accounts["vault"],
+            AccountConfig::Predicate { data, .. }
+                if *data == [DynamicValue::U64(4097), DynamicValue::Bool(true)]
+        ));
+
+        Ok(())
+    }
+}
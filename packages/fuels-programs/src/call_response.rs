@@ -1,11 +1,12 @@
-use std::fmt::Debug;
+use std::{collections::BTreeMap, fmt::Debug};
 
 use fuel_tx::{Bytes32, Receipt};
 use fuels_core::{
     codec::{LogDecoder, LogResult},
     traits::{Parameterize, Tokenizable},
-    types::errors::Result,
+    types::{errors::Result, DynamicValue},
 };
+use serde_json::{json, Value};
 
 /// [`FuelCallResponse`] is a struct that is returned by a call to the contract or script. Its value
 /// field holds the decoded typed value returned by the contract's method. The other field holds all
@@ -57,3 +58,128 @@ impl<D> FuelCallResponse<D> {
         self.log_decoder.decode_logs_with_type::<T>(&self.receipts)
     }
 }
+
+impl<D: Tokenizable + Parameterize + Clone> FuelCallResponse<D> {
+    /// Describes the response as JSON, with the layout:
+    ///
+    /// ```json
+    /// {
+    ///   "tx_id": "0x..." or null,
+    ///   "value": { "type": "u64", "value": { "U64": 42 } },
+    ///   "logs": [{ "type": "MyStruct", "value": { "Struct": ... } }, { "error": "..." }],
+    ///   "gas_used": 1234,
+    ///   "receipts": { "count": 4, "kinds": { "call": 1, "log_data": 1, ... } }
+    /// }
+    /// ```
+    ///
+    /// Values are [`DynamicValue`]s, so structs and enums keep the names of their fields and
+    /// variants. Logs that cannot be decoded are replaced by their error.
+    pub fn to_json(&self) -> Result<Value> {
+        let param_type = D::param_type();
+        let value = DynamicValue::from_token(self.value.clone().into_token(), &param_type)?;
+
+        let logs: Vec<_> = self
+            .log_decoder
+            .decode_logs_as_values(&self.receipts)
+            .into_iter()
+            .map(|log| match log {
+                Ok((type_name, value)) => json!({ "type": type_name, "value": value }),
+                Err(err) => json!({ "error": err.to_string() }),
+            })
+            .collect();
+
+        let mut kinds = BTreeMap::<_, usize>::new();
+        for receipt in &self.receipts {
+            *kinds.entry(receipt_kind(receipt)).or_default() += 1;
+        }
+
+        Ok(json!({
+            "tx_id": self.tx_id.map(|tx_id| format!("{tx_id:#x}")),
+            "value": { "type": param_type.type_name(), "value": value },
+            "logs": logs,
+            "gas_used": self.gas_used,
+            "receipts": { "count": self.receipts.len(), "kinds": kinds },
+        }))
+    }
+}
+
+fn receipt_kind(receipt: &Receipt) -> &'static str {
+    match receipt {
+        Receipt::Call { .. } => "call",
+        Receipt::Return { .. } => "return",
+        Receipt::ReturnData { .. } => "return_data",
+        Receipt::Panic { .. } => "panic",
+        Receipt::Revert { .. } => "revert",
+        Receipt::Log { .. } => "log",
+        Receipt::LogData { .. } => "log_data",
+        Receipt::Transfer { .. } => "transfer",
+        Receipt::TransferOut { .. } => "transfer_out",
+        Receipt::ScriptResult { .. } => "script_result",
+        Receipt::MessageOut { .. } => "message_out",
+        Receipt::Mint { .. } => "mint",
+        Receipt::Burn { .. } => "burn",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::{ContractId, ScriptExecutionResult};
+    use fuels_core::codec::{log_formatters_lookup, LogFormatter};
+
+    use super::*;
+
+    #[test]
+    fn responses_are_described_as_json() -> Result<()> {
+        let log_decoder = LogDecoder::new(log_formatters_lookup(
+            vec![(1, LogFormatter::new::<u64>())],
+            ContractId::zeroed(),
+        ));
+        let receipts = vec![
+            Receipt::Log {
+                id: ContractId::zeroed(),
+                ra: 7,
+                rb: 1,
+                rc: 0,
+                rd: 0,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::Log {
+                id: ContractId::zeroed(),
+                ra: 8,
+                rb: 2,
+                rc: 0,
+                rd: 0,
+                pc: 0,
+                is: 0,
+            },
+            Receipt::ScriptResult {
+                result: ScriptExecutionResult::Success,
+                gas_used: 100,
+            },
+        ];
+        let response = FuelCallResponse::new(true, receipts, log_decoder, Some(Bytes32::zeroed()));
+
+        let json = response.to_json()?;
+
+        assert_eq!(json["tx_id"], format!("0x{}", "0".repeat(64)));
+        assert_eq!(
+            json["value"],
+            json!({ "type": "bool", "value": { "Bool": true } })
+        );
+        assert_eq!(
+            json["logs"][0],
+            json!({ "type": "u64", "value": { "U64": 7 } })
+        );
+        assert!(json["logs"][1]["error"]
+            .as_str()
+            .is_some_and(|err| err.contains("missing log formatter")));
+        assert_eq!(json["gas_used"], 100);
+        assert_eq!(
+            json["receipts"],
+            json!({ "count": 3, "kinds": { "log": 2, "script_result": 1 } })
+        );
+
+        Ok(())
+    }
+}
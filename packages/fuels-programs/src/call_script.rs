@@ -0,0 +1,584 @@
+use fuel_asm::{op, Instruction, RegId};
+use fuel_tx::{Address, AssetId, ConsensusParameters};
+use fuel_types::bytes::padded_len_usize;
+pub use fuels_core::call_instructions::CallRegisters;
+use fuels_core::{
+    call_instructions::get_instructions,
+    error,
+    offsets::call_script_data_offset,
+    types::{errors::Result, unresolved_bytes::UnresolvedBytes},
+};
+
+use crate::{
+    call_utils::{
+        build_script_data_from_contract_calls, compute_calls_instructions_len,
+        extract_unique_contract_ids, CallOpcodeParamsOffset,
+    },
+    contract::{ContractCall, Transfer},
+};
+
+/// The number of instructions performing a transfer, see [`transfer_instructions`].
+const TRANSFER_INSTRUCTIONS_LEN: usize = 6;
+
+/// Instructions executed before and after the contract calls of a call script.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CustomInstructions {
+    pub prologue: Vec<Instruction>,
+    pub epilogue: Vec<Instruction>,
+}
+
+impl CustomInstructions {
+    fn len(&self) -> usize {
+        (self.prologue.len() + self.epilogue.len()) * Instruction::SIZE
+    }
+}
+
+/// The script and script data performing a sequence of contract calls, see
+/// [`CallScriptBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallScript {
+    pub script: Vec<u8>,
+    pub script_data: Vec<u8>,
+    /// Where the parameters of each call are stored, as absolute offsets in the VM memory of the
+    /// transaction. Useful to load them in custom instructions.
+    pub call_offsets: Vec<CallOpcodeParamsOffset>,
+}
+
+/// Builds the script and script data performing contract calls, the same way the SDK does for
+/// [`ContractCallHandler`](crate::contract::ContractCallHandler) and
+/// [`MultiContractCallHandler`](crate::contract::MultiContractCallHandler).
+///
+/// The script data of each call is laid out one after the other, with the offsets computed for
+/// the consensus parameters of the target network, and the calls are performed in the order they
+/// were added. The resulting script and script data can be set on a
+/// [`ScriptTransactionBuilder`](fuels_core::types::transaction_builders::ScriptTransactionBuilder),
+/// together with the contract inputs and outputs of the called contracts.
+#[derive(Debug, Default)]
+pub struct CallScriptBuilder {
+    calls: Vec<ContractCall>,
+    registers: CallRegisters,
+    custom_instructions: CustomInstructions,
+}
+
+impl CallScriptBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_call(mut self, call: ContractCall) -> Self {
+        self.calls.push(call);
+        self
+    }
+
+    pub fn with_calls(mut self, calls: impl IntoIterator<Item = ContractCall>) -> Self {
+        self.calls.extend(calls);
+        self
+    }
+
+    /// Sets the registers into which the call parameters are loaded, e.g. to keep the values of
+    /// the default ones for custom instructions.
+    pub fn with_registers(mut self, registers: CallRegisters) -> Self {
+        self.registers = registers;
+        self
+    }
+
+    /// Sets instructions executed before the first call. The registers holding the call
+    /// parameters are overwritten by the calls, use other ones to pass values to the epilogue.
+    pub fn with_prologue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.prologue = instructions;
+        self
+    }
+
+    /// Sets instructions executed after the last call, before the script returns.
+    pub fn with_epilogue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.epilogue = instructions;
+        self
+    }
+
+    pub fn calls(&self) -> &[ContractCall] {
+        &self.calls
+    }
+
+    pub fn build(
+        &self,
+        consensus_parameters: &ConsensusParameters,
+        base_asset_id: AssetId,
+    ) -> Result<CallScript> {
+        build_call_script(
+            &self.calls,
+            &self.registers,
+            &self.custom_instructions,
+            consensus_parameters,
+            base_asset_id,
+        )
+    }
+}
+
+pub(crate) fn build_call_script(
+    calls: &[ContractCall],
+    registers: &CallRegisters,
+    custom_instructions: &CustomInstructions,
+    consensus_parameters: &ConsensusParameters,
+    base_asset_id: AssetId,
+) -> Result<CallScript> {
+    registers.validate()?;
+
+    let transfers = calls
+        .iter()
+        .flat_map(|call| &call.transfers)
+        .collect::<Vec<_>>();
+
+    let instructions_len = compute_calls_instructions_len(calls)?
+        + custom_instructions.len()
+        + transfers.len() * TRANSFER_INSTRUCTIONS_LEN * Instruction::SIZE;
+    let data_offset = call_script_data_offset(consensus_parameters, instructions_len)?;
+
+    let (mut script_data, call_offsets) =
+        build_script_data_from_contract_calls(calls, data_offset, base_asset_id)?;
+
+    // the variable outputs of the transfers follow the contract outputs
+    let first_output_index = extract_unique_contract_ids(calls).len();
+    let mut transfers_instructions = vec![];
+    for (index, transfer) in transfers.into_iter().enumerate() {
+        let recipient_offset = data_offset + script_data.len();
+        script_data.extend(transfer.data());
+
+        transfers_instructions.extend(transfer_instructions(
+            recipient_offset,
+            first_output_index + index,
+            registers,
+        )?);
+    }
+
+    let to_bytes = |instructions: &[Instruction]| {
+        instructions
+            .iter()
+            .flat_map(|instruction| instruction.to_bytes())
+            .collect::<Vec<_>>()
+    };
+
+    let script = [
+        to_bytes(&custom_instructions.prologue),
+        get_instructions(&call_offsets, registers)?,
+        to_bytes(&transfers_instructions),
+        to_bytes(&custom_instructions.epilogue),
+        op::ret(RegId::ONE).to_bytes().to_vec(),
+    ]
+    .concat();
+
+    Ok(CallScript {
+        script,
+        script_data,
+        call_offsets,
+    })
+}
+
+impl Transfer {
+    /// The script data of the transfer: the recipient, the asset ID and the amount.
+    fn data(&self) -> Vec<u8> {
+        let mut data = self.recipient.hash().to_vec();
+        data.extend(self.asset_id.iter());
+        data.extend(self.amount.to_be_bytes());
+
+        data
+    }
+}
+
+/// Returns the instructions transferring the asset of the transfer whose data starts at
+/// `data_offset` to the variable output at `output_index`. They reuse the registers of the call
+/// parameters, which are free once the calls are done.
+fn transfer_instructions(
+    data_offset: usize,
+    output_index: usize,
+    registers: &CallRegisters,
+) -> Result<[Instruction; TRANSFER_INSTRUCTIONS_LEN]> {
+    let immediate = |value: usize| {
+        u32::try_from(value)
+            .ok()
+            .filter(|value| *value < 1 << 18)
+            .ok_or_else(|| error!(Other, "transfer parameter `{value}` is out of range"))
+    };
+    let recipient_offset = immediate(data_offset)?;
+    let asset_id_offset = immediate(data_offset + Address::LEN)?;
+    let amount_offset = immediate(data_offset + Address::LEN + AssetId::LEN)?;
+    let output_index = immediate(output_index)?;
+
+    let CallRegisters {
+        call_data: recipient,
+        amount,
+        asset_id,
+        gas: output,
+    } = *registers;
+
+    Ok([
+        op::movi(recipient, recipient_offset),
+        op::movi(output, output_index),
+        op::movi(amount, amount_offset),
+        op::lw(amount, amount, 0),
+        op::movi(asset_id, asset_id_offset),
+        op::tro(recipient, output, amount, asset_id),
+    ])
+}
+
+/// Builds a script performing `calls` before executing `script_binary`, in the same transaction.
+///
+/// Compiled scripts expect to be loaded at the start of the script, so the calls can't simply be
+/// prepended. Instead, the first instruction of the script is replaced by a jump to instructions
+/// appended after it, which perform the calls, execute the replaced instruction and jump back to
+/// the second one. The script data holds the arguments of the script, followed by the data of the
+/// calls.
+pub(crate) fn build_script_with_calls(
+    script_binary: &[u8],
+    script_args: &UnresolvedBytes,
+    calls: &[ContractCall],
+    consensus_parameters: &ConsensusParameters,
+    base_asset_id: AssetId,
+) -> Result<CallScript> {
+    if calls.iter().any(|call| !call.transfers.is_empty()) {
+        return Err(error!(
+            Other,
+            "transfers can only be attached to calls of a contract call handler"
+        ));
+    }
+
+    let first_instruction = script_binary
+        .get(..Instruction::SIZE)
+        .filter(|_| script_binary.len() % Instruction::SIZE == 0)
+        .and_then(|bytes| Instruction::try_from(<[u8; 4]>::try_from(bytes).ok()?).ok())
+        .ok_or_else(|| error!(Other, "the script binary is not a sequence of instructions"))?;
+    let replayed = replay_elsewhere(first_instruction)?;
+
+    let calls_start =
+        padded_len_usize(script_binary.len()).ok_or_else(|| error!(Other, "script is too long"))?;
+    let calls_index = u32::try_from(calls_start / Instruction::SIZE)
+        .ok()
+        .filter(|index| *index < 1 << 24)
+        .ok_or_else(|| error!(Other, "script is too long to perform calls before it"))?;
+
+    let registers = CallRegisters::default();
+    let restored_registers = [
+        registers.call_data,
+        registers.amount,
+        registers.asset_id,
+        registers.gas,
+    ]
+    .map(|register| op::move_(register, RegId::ZERO));
+    let trailer = [&[replayed][..], &restored_registers, &[op::ji(1)]].concat();
+
+    let script_len =
+        calls_start + compute_calls_instructions_len(calls)? + trailer.len() * Instruction::SIZE;
+    let script_data_offset = call_script_data_offset(consensus_parameters, script_len)?;
+
+    let mut script_data = script_args.resolve(script_data_offset as u64);
+    let (calls_data, call_offsets) = build_script_data_from_contract_calls(
+        calls,
+        script_data_offset + script_data.len(),
+        base_asset_id,
+    )?;
+    script_data.extend(calls_data);
+
+    let mut script = script_binary.to_vec();
+    script[..Instruction::SIZE].copy_from_slice(&op::ji(calls_index).to_bytes());
+    script.resize(calls_start, 0);
+    script.extend(get_instructions(&call_offsets, &registers)?);
+    script.extend(
+        trailer
+            .iter()
+            .flat_map(|instruction| instruction.to_bytes()),
+    );
+
+    Ok(CallScript {
+        script,
+        script_data,
+        call_offsets,
+    })
+}
+
+/// Returns an instruction doing what `instruction` does at the start of the script, wherever it
+/// is executed. Sway scripts start by reading the program counter, which equals `$is` there.
+fn replay_elsewhere(instruction: Instruction) -> Result<Instruction> {
+    match instruction {
+        Instruction::MOVE(move_) => {
+            let (destination, source) = move_.unpack();
+            if source == RegId::PC {
+                return Ok(op::move_(destination, RegId::IS));
+            }
+        }
+        Instruction::JMPF(_)
+        | Instruction::JMPB(_)
+        | Instruction::JNZF(_)
+        | Instruction::JNZB(_)
+        | Instruction::JNEF(_)
+        | Instruction::JNEB(_) => {
+            return Err(error!(
+                Other,
+                "cannot perform calls before a script starting with a relative jump"
+            ))
+        }
+        _ => {}
+    }
+
+    if instruction.reg_ids().contains(&Some(RegId::PC)) {
+        return Err(error!(
+            Other,
+            "cannot perform calls before a script whose first instruction reads `$pc`"
+        ));
+    }
+
+    Ok(instruction)
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::{
+        constants::WORD_SIZE,
+        types::{bech32::Bech32Address, unresolved_bytes::Data},
+    };
+
+    use super::*;
+    use crate::contract::CallParameters;
+
+    #[test]
+    fn calls_are_scripted_with_the_given_registers() -> Result<()> {
+        // ANCHOR: call_script_builder
+        let registers = CallRegisters {
+            call_data: RegId::new(0x20),
+            amount: RegId::new(0x21),
+            asset_id: RegId::new(0x22),
+            gas: RegId::new(0x23),
+        };
+
+        let call_script = CallScriptBuilder::new()
+            .with_call(ContractCall::new_with_random_id())
+            .with_call(
+                ContractCall::new_with_random_id()
+                    .with_call_parameters(CallParameters::default().with_gas_forwarded(1000)),
+            )
+            .with_registers(registers)
+            .build(&ConsensusParameters::default(), AssetId::zeroed())?;
+        // ANCHOR_END: call_script_builder
+
+        let instructions = call_script
+            .script
+            .chunks(Instruction::SIZE)
+            .map(|bytes| Instruction::try_from(<[u8; 4]>::try_from(bytes).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        let offset = |offset: usize| offset as u32;
+        let [first, second] = &call_script.call_offsets[..] else {
+            panic!("expected the offsets of two calls");
+        };
+        let gas_offset = second.gas_forwarded_offset.expect("gas is forwarded");
+
+        let expected = [
+            op::movi(0x20, offset(first.call_data_offset)),
+            op::movi(0x21, offset(first.amount_offset)),
+            op::lw(0x21, 0x21, 0),
+            op::movi(0x22, offset(first.asset_id_offset)),
+            op::call(0x20, 0x21, 0x22, RegId::CGAS),
+            op::movi(0x20, offset(second.call_data_offset)),
+            op::movi(0x21, offset(second.amount_offset)),
+            op::lw(0x21, 0x21, 0),
+            op::movi(0x22, offset(second.asset_id_offset)),
+            op::movi(0x23, offset(gas_offset)),
+            op::lw(0x23, 0x23, 0),
+            op::call(0x20, 0x21, 0x22, 0x23),
+            op::ret(RegId::ONE),
+        ];
+        assert_eq!(instructions, expected);
+
+        let data_offset = first.amount_offset;
+        let gas = &call_script.script_data[gas_offset - data_offset..][..WORD_SIZE];
+        assert_eq!(u64::from_be_bytes(gas.try_into().unwrap()), 1000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_instructions_surround_the_calls() -> Result<()> {
+        let prologue = vec![op::movi(0x20, 1), op::movi(0x21, 2)];
+        let epilogue = vec![op::log(0x20, 0x21, RegId::ZERO, RegId::ZERO)];
+
+        let build = |builder: CallScriptBuilder| {
+            builder
+                .with_call(ContractCall::new_with_random_id())
+                .build(&ConsensusParameters::default(), AssetId::zeroed())
+        };
+        let plain = build(CallScriptBuilder::new())?;
+        let custom = build(
+            CallScriptBuilder::new()
+                .with_prologue(prologue.clone())
+                .with_epilogue(epilogue.clone()),
+        )?;
+
+        let to_bytes = |instructions: &[Instruction]| {
+            instructions
+                .iter()
+                .flat_map(|instruction| instruction.to_bytes())
+                .collect::<Vec<_>>()
+        };
+        let prologue_len = prologue.len() * Instruction::SIZE;
+        let epilogue_len = epilogue.len() * Instruction::SIZE;
+
+        let script_len = custom.script.len();
+        assert_eq!(script_len, plain.script.len() + prologue_len + epilogue_len);
+        assert_eq!(custom.script[..prologue_len], to_bytes(&prologue));
+        assert_eq!(
+            custom.script[script_len - epilogue_len - Instruction::SIZE..],
+            to_bytes(&[epilogue, vec![op::ret(RegId::ONE)]].concat())
+        );
+
+        // the script grew from 24 to 36 bytes, padded to 40
+        let data_offset = |call_script: &CallScript| call_script.call_offsets[0].amount_offset;
+        assert_eq!(data_offset(&custom) - data_offset(&plain), 16);
+
+        Ok(())
+    }
+
+    #[test]
+    fn transfers_are_performed_after_the_calls() -> Result<()> {
+        let recipient = Bech32Address::new("fuel", [1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let mut call = ContractCall::new_with_random_id();
+        call.add_transfer(Transfer {
+            recipient: recipient.clone(),
+            amount: 1000,
+            asset_id,
+        });
+
+        let call_script = CallScriptBuilder::new()
+            .with_call(call)
+            .build(&ConsensusParameters::default(), AssetId::zeroed())?;
+
+        let instructions = call_script
+            .script
+            .chunks(Instruction::SIZE)
+            .map(|bytes| Instruction::try_from(<[u8; 4]>::try_from(bytes).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        // the transfer data follows the data of the call
+        let data_offset = call_script.call_offsets[0].amount_offset;
+        let transfer_data_len = Address::LEN + AssetId::LEN + WORD_SIZE;
+        let transfer_offset = data_offset + call_script.script_data.len() - transfer_data_len;
+        let offset = |offset: usize| offset as u32;
+
+        // the only contract output is at index 0, the variable output of the transfer follows it
+        let expected = [
+            op::movi(0x10, offset(transfer_offset)),
+            op::movi(0x13, 1),
+            op::movi(0x11, offset(transfer_offset + Address::LEN + AssetId::LEN)),
+            op::lw(0x11, 0x11, 0),
+            op::movi(0x12, offset(transfer_offset + Address::LEN)),
+            op::tro(0x10, 0x13, 0x11, 0x12),
+            op::ret(RegId::ONE),
+        ];
+        assert_eq!(
+            instructions[instructions.len() - expected.len()..],
+            expected
+        );
+
+        let transfer_data = &call_script.script_data[transfer_offset - data_offset..];
+        assert_eq!(transfer_data[..Address::LEN], recipient.hash()[..]);
+        assert_eq!(transfer_data[Address::LEN..][..AssetId::LEN], asset_id[..]);
+        assert_eq!(
+            transfer_data[Address::LEN + AssetId::LEN..],
+            1000u64.to_be_bytes()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_or_repeated_registers_are_rejected() {
+        let build = |registers| {
+            CallScriptBuilder::new()
+                .with_call(ContractCall::new_with_random_id())
+                .with_registers(registers)
+                .build(&ConsensusParameters::default(), AssetId::zeroed())
+        };
+
+        let reserved = CallRegisters {
+            gas: RegId::CGAS,
+            ..Default::default()
+        };
+        let err = build(reserved).expect_err("should fail");
+        assert!(err.to_string().contains("reserved by the VM"));
+
+        let repeated = CallRegisters {
+            amount: RegId::new(0x10),
+            ..Default::default()
+        };
+        let err = build(repeated).expect_err("should fail");
+        assert!(err.to_string().contains("must be distinct"));
+    }
+
+    #[test]
+    fn calls_are_performed_before_the_script() -> Result<()> {
+        let data_pointer = RegId::new(0x3c);
+        let script_binary = [op::move_(data_pointer, RegId::PC), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect::<Vec<u8>>();
+        let script_args = UnresolvedBytes::new(vec![Data::Inline(vec![7; WORD_SIZE])]);
+
+        let call_script = build_script_with_calls(
+            &script_binary,
+            &script_args,
+            &[ContractCall::new_with_random_id()],
+            &ConsensusParameters::default(),
+            AssetId::zeroed(),
+        )?;
+
+        let instructions = call_script
+            .script
+            .chunks(Instruction::SIZE)
+            .map(|bytes| Instruction::try_from(<[u8; 4]>::try_from(bytes).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let call = &call_script.call_offsets[0];
+        let offset = |offset: usize| offset as u32;
+
+        let expected = [
+            op::ji(2),
+            op::ret(RegId::ONE),
+            op::movi(0x10, offset(call.call_data_offset)),
+            op::movi(0x11, offset(call.amount_offset)),
+            op::lw(0x11, 0x11, 0),
+            op::movi(0x12, offset(call.asset_id_offset)),
+            op::call(0x10, 0x11, 0x12, RegId::CGAS),
+            op::move_(data_pointer, RegId::IS),
+            op::move_(0x10, RegId::ZERO),
+            op::move_(0x11, RegId::ZERO),
+            op::move_(0x12, RegId::ZERO),
+            op::move_(0x13, RegId::ZERO),
+            op::ji(1),
+        ];
+        assert_eq!(instructions, expected);
+
+        // the arguments of the script come first
+        assert_eq!(call_script.script_data[..WORD_SIZE], [7; WORD_SIZE]);
+        assert_eq!(
+            call.amount_offset,
+            call_script_data_offset(&ConsensusParameters::default(), call_script.script.len())?
+                + WORD_SIZE
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn scripts_starting_with_relative_jumps_are_rejected() {
+        let script_binary = [op::jmpf(RegId::ZERO, 1), op::noop(), op::ret(RegId::ONE)]
+            .into_iter()
+            .collect::<Vec<u8>>();
+
+        let err = build_script_with_calls(
+            &script_binary,
+            &UnresolvedBytes::default(),
+            &[ContractCall::new_with_random_id()],
+            &ConsensusParameters::default(),
+            AssetId::zeroed(),
+        )
+        .expect_err("should fail");
+
+        assert!(err.to_string().contains("relative jump"));
+    }
+}
@@ -1,38 +1,41 @@
 use std::{collections::HashSet, iter, vec};
 
 use fuel_abi_types::error_codes::FAILED_TRANSFER_TO_ADDRESS_SIGNAL;
-use fuel_asm::{op, RegId};
-use fuel_tx::{AssetId, Bytes32, ContractId, Output, PanicReason, Receipt, TxPointer, UtxoId};
+use fuel_tx::{
+    AssetId, Bytes32, ConsensusParameters, ContractId, Output, PanicReason, Receipt,
+    Transaction as FuelTransaction, TxPointer, UtxoId, Witness,
+};
 use fuel_types::{Address, Word};
-use fuels_accounts::Account;
+use fuels_accounts::{
+    provider::{Provider, SimulationOptions},
+    Account,
+};
 use fuels_core::{
+    call_instructions::{get_single_call_instructions, CallRegisters},
     constants::WORD_SIZE,
     error,
-    offsets::call_script_data_offset,
     types::{
         bech32::{Bech32Address, Bech32ContractId},
+        coin::Coin,
+        coin_type::CoinType,
+        coin_type_id::CoinTypeId,
         errors::{transaction::Reason, Error, Result},
         input::Input,
-        param_types::ParamType,
-        transaction::{ScriptTransaction, TxPolicies},
+        transaction::{PredicateEstimationCache, ScriptTransaction, Transaction, TxPolicies},
         transaction_builders::{
-            BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+            BlockGasUsage, BuildableTransaction, DryRunner, ScriptTransactionBuilder,
+            TransactionBuilder, VariableOutputPolicy,
         },
     },
 };
 use itertools::{chain, Itertools};
 
-use crate::contract::ContractCall;
+use crate::{
+    call_script::{build_call_script, CallScript, CustomInstructions},
+    contract::ContractCall,
+};
 
-#[derive(Default)]
-/// Specifies offsets of [`Opcode::CALL`][`fuel_asm::Opcode::CALL`] parameters stored in the script
-/// data from which they can be loaded into registers
-pub(crate) struct CallOpcodeParamsOffset {
-    pub call_data_offset: usize,
-    pub amount_offset: usize,
-    pub asset_id_offset: usize,
-    pub gas_forwarded_offset: Option<usize>,
-}
+pub use fuels_core::call_instructions::CallOpcodeParamsOffset;
 
 /// How many times to attempt to resolve missing tx dependencies.
 pub const DEFAULT_TX_DEP_ESTIMATION_ATTEMPTS: u64 = 10;
@@ -71,6 +74,8 @@ pub trait TxDependencyExtension: Sized + sealed::Sealed {
     /// [`Output::Contract`]: fuel_tx::Output::Contract
     fn append_contract(self, contract_id: Bech32ContractId) -> Self;
 
+    /// Appends the dependency reported missing by `receipts`. The VM stops at the first missing
+    /// contract or variable output, so a failed run reveals a single dependency.
     fn append_missing_dependencies(mut self, receipts: &[Receipt]) -> Self {
         if is_missing_output_variables(receipts) {
             self = self.append_variable_outputs(1);
@@ -106,17 +111,23 @@ pub trait TxDependencyExtension: Sized + sealed::Sealed {
 /// Creates a [`ScriptTransactionBuilder`] from contract calls.
 pub(crate) async fn transaction_builder_from_contract_calls(
     calls: &[ContractCall],
+    custom_instructions: &CustomInstructions,
     tx_policies: TxPolicies,
+    variable_output_policy: VariableOutputPolicy,
     account: &impl Account,
 ) -> Result<ScriptTransactionBuilder> {
-    let calls_instructions_len = compute_calls_instructions_len(calls)?;
     let provider = account.try_provider()?;
-    let consensus_parameters = provider.consensus_parameters();
-    let data_offset = call_script_data_offset(consensus_parameters, calls_instructions_len)?;
-
-    let (script_data, call_param_offsets) =
-        build_script_data_from_contract_calls(calls, data_offset, *provider.base_asset_id())?;
-    let script = get_instructions(calls, call_param_offsets)?;
+    let CallScript {
+        script,
+        script_data,
+        ..
+    } = build_call_script(
+        calls,
+        &CallRegisters::default(),
+        custom_instructions,
+        provider.consensus_parameters(),
+        *provider.base_asset_id(),
+    )?;
 
     let required_asset_amounts = calculate_required_asset_amounts(calls, *provider.base_asset_id());
 
@@ -138,8 +149,9 @@ pub(crate) async fn transaction_builder_from_contract_calls(
 
     Ok(ScriptTransactionBuilder::default()
         .with_tx_policies(tx_policies)
+        .with_variable_output_policy(variable_output_policy)
         .with_script(script)
-        .with_script_data(script_data.clone())
+        .with_script_data(script_data)
         .with_inputs(inputs)
         .with_outputs(outputs))
 }
@@ -149,19 +161,21 @@ pub(crate) async fn transaction_builder_from_contract_calls(
 /// transaction inputs/outputs consisting of assets and contracts.
 pub(crate) async fn build_tx_from_contract_calls(
     calls: &[ContractCall],
+    custom_instructions: &CustomInstructions,
     tx_policies: TxPolicies,
+    variable_output_policy: VariableOutputPolicy,
     account: &impl Account,
 ) -> Result<ScriptTransaction> {
-    let mut tb = transaction_builder_from_contract_calls(calls, tx_policies, account).await?;
-
-    let base_asset_id = *account.try_provider()?.base_asset_id();
-    let required_asset_amounts = calculate_required_asset_amounts(calls, base_asset_id);
+    let mut tb = transaction_builder_from_contract_calls(
+        calls,
+        custom_instructions,
+        tx_policies,
+        variable_output_policy,
+        account,
+    )
+    .await?;
 
-    let base_asset_id = account.try_provider()?.base_asset_id();
-    let used_base_amount = required_asset_amounts
-        .iter()
-        .find_map(|(asset_id, amount)| (asset_id == base_asset_id).then_some(*amount))
-        .unwrap_or_default();
+    let used_base_amount = used_base_amount(calls, *account.try_provider()?.base_asset_id());
 
     account.add_witnesses(&mut tb)?;
     account.adjust_for_fee(&mut tb, used_base_amount).await?;
@@ -169,23 +183,164 @@ pub(crate) async fn build_tx_from_contract_calls(
     tb.build(account.try_provider()?).await
 }
 
+/// The amount of the base asset forwarded by `calls`.
+pub(crate) fn used_base_amount(calls: &[ContractCall], base_asset_id: AssetId) -> u64 {
+    calculate_required_asset_amounts(calls, base_asset_id)
+        .into_iter()
+        .find_map(|(asset_id, amount)| (asset_id == base_asset_id).then_some(amount))
+        .unwrap_or_default()
+}
+
+/// Builds the transaction of `tb` for a dry run with `options`.
+///
+/// Without UTXO validation, the fee is covered by a placeholder coin of `account` instead of its
+/// actual resources, so that calls can be simulated for accounts that aren't funded yet. Missing
+/// witnesses, e.g. those of predicates, are filled with empty ones.
+pub(crate) async fn build_tx_for_simulation(
+    mut tb: ScriptTransactionBuilder,
+    account: &impl Account,
+    used_base_amount: u64,
+    options: SimulationOptions,
+) -> Result<ScriptTransaction> {
+    let provider = account.try_provider()?;
+    let dry_runner = SimulationDryRunner {
+        provider,
+        zero_gas_price: options.zero_gas_price(),
+    };
+
+    account.add_witnesses(&mut tb)?;
+
+    if options.utxo_validation() {
+        account.adjust_for_fee(&mut tb, used_base_amount).await?;
+
+        return tb.build(&dry_runner).await;
+    }
+
+    add_placeholder_fee_coin(&mut tb, account.address(), used_base_amount, &dry_runner).await?;
+
+    let mut tx = tb.build(&dry_runner).await?;
+    add_empty_witnesses(&mut tx)?;
+
+    Ok(tx)
+}
+
+async fn add_placeholder_fee_coin(
+    tb: &mut ScriptTransactionBuilder,
+    owner: &Bech32Address,
+    used_base_amount: u64,
+    dry_runner: &SimulationDryRunner<'_>,
+) -> Result<()> {
+    let base_asset_id = *dry_runner.provider.base_asset_id();
+    let placeholder_coin = |amount| {
+        Input::resource_signed(CoinType::Coin(Coin {
+            amount,
+            asset_id: base_asset_id,
+            owner: owner.clone(),
+            ..Default::default()
+        }))
+    };
+
+    // The amount doesn't change the fee, only the size of the input does.
+    tb.inputs_mut().push(placeholder_coin(0));
+    if !tb.outputs().iter().any(
+        |output| matches!(output, Output::Change { asset_id, .. } if *asset_id == base_asset_id),
+    ) {
+        tb.outputs_mut()
+            .push(Output::change(owner.into(), 0, base_asset_id));
+    }
+
+    let fee = tb
+        .fee_checked_from_tx(dry_runner)
+        .await?
+        .ok_or_else(|| error!(Other, "could not estimate the fee of the simulation"))?
+        .max_fee();
+
+    tb.inputs_mut().pop();
+    tb.inputs_mut()
+        .push(placeholder_coin(used_base_amount.saturating_add(fee)));
+
+    Ok(())
+}
+
+fn add_empty_witnesses(tx: &mut ScriptTransaction) -> Result<()> {
+    let num_witnesses = tx
+        .inputs()
+        .iter()
+        .filter_map(|input| input.witness_index())
+        .max()
+        .map_or(0, |index| index as usize + 1);
+
+    while tx.witnesses().len() < num_witnesses {
+        tx.append_witness(Witness::default())?;
+    }
+
+    Ok(())
+}
+
+/// Forwards to the provider, except for the gas price, which is zero if `zero_gas_price` is set.
+struct SimulationDryRunner<'a> {
+    provider: &'a Provider,
+    zero_gas_price: bool,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl DryRunner for SimulationDryRunner<'_> {
+    async fn dry_run_and_get_used_gas(&self, tx: FuelTransaction, tolerance: f32) -> Result<u64> {
+        self.provider.dry_run_and_get_used_gas(tx, tolerance).await
+    }
+
+    async fn estimate_gas_price(&self, block_horizon: u32) -> Result<u64> {
+        if self.zero_gas_price {
+            return Ok(0);
+        }
+
+        DryRunner::estimate_gas_price(self.provider, block_horizon).await
+    }
+
+    fn consensus_parameters(&self) -> &ConsensusParameters {
+        self.provider.consensus_parameters()
+    }
+
+    async fn spent_resources(&self, ids: &[CoinTypeId]) -> Result<Vec<CoinTypeId>> {
+        self.provider.spent_resources(ids).await
+    }
+
+    async fn dry_run_receipts(&self, tx: FuelTransaction) -> Result<Vec<Receipt>> {
+        self.provider.dry_run_receipts(tx).await
+    }
+
+    async fn recent_blocks_gas(&self, count: u32) -> Result<Vec<BlockGasUsage>> {
+        self.provider.recent_blocks_gas(count).await
+    }
+
+    async fn latest_da_compression_ratio(&self) -> Result<Option<f64>> {
+        DryRunner::latest_da_compression_ratio(self.provider).await
+    }
+
+    fn predicate_estimation_cache(&self) -> Option<&PredicateEstimationCache> {
+        self.provider.predicate_estimation_cache()
+    }
+}
+
 /// Compute the length of the calling scripts for the two types of contract calls: those that return
 /// a heap type, and those that don't.
-fn compute_calls_instructions_len(calls: &[ContractCall]) -> Result<usize> {
+pub(crate) fn compute_calls_instructions_len(calls: &[ContractCall]) -> Result<usize> {
     calls
         .iter()
         .map(|c| {
-            // Use placeholder for `call_param_offsets` and `output_param_type`, because the length of
-            // the calling script doesn't depend on the underlying type, just on whether or not
-            // gas was forwarded or contract call output type is a heap type.
+            // Use placeholders for `call_param_offsets` and the registers, because the length of
+            // the calling script doesn't depend on them, just on whether or not gas was forwarded
+            // and how many assets are forwarded.
 
             let mut call_opcode_params = CallOpcodeParamsOffset::default();
 
             if c.call_parameters.gas_forwarded().is_some() {
                 call_opcode_params.gas_forwarded_offset = Some(0);
             }
+            call_opcode_params.forwarded_assets_offsets =
+                vec![(0, 0); c.call_parameters.forwarded_assets().len()];
 
-            get_single_call_instructions(&call_opcode_params, &c.output_param)
+            get_single_call_instructions(&call_opcode_params, &CallRegisters::default())
                 .map(|instructions| instructions.len())
         })
         .process_results(|c| c.sum())
@@ -198,11 +353,14 @@ pub(crate) fn calculate_required_asset_amounts(
 ) -> Vec<(AssetId, u64)> {
     let call_param_assets = calls
         .iter()
-        .map(|call| {
-            (
+        .flat_map(|call| {
+            let forwarded_by_call = (
                 call.call_parameters.asset_id().unwrap_or(base_asset_id),
                 call.call_parameters.amount(),
-            )
+            );
+
+            iter::once(forwarded_by_call)
+                .chain(call.call_parameters.forwarded_assets().iter().copied())
         })
         .collect::<Vec<_>>();
 
@@ -217,7 +375,12 @@ pub(crate) fn calculate_required_asset_amounts(
         })
         .collect::<Vec<_>>();
 
-    let merged_assets = chain!(call_param_assets, custom_assets).collect::<Vec<_>>();
+    let transfers = calls
+        .iter()
+        .flat_map(|call| &call.transfers)
+        .map(|transfer| (transfer.asset_id, transfer.amount));
+
+    let merged_assets = chain!(call_param_assets, custom_assets, transfers).collect::<Vec<_>>();
 
     sum_up_amounts_for_each_asset_id(merged_assets)
 }
@@ -239,22 +402,6 @@ fn sum_up_amounts_for_each_asset_id(
         .collect()
 }
 
-/// Given a list of contract calls, create the actual opcodes used to call the contract
-pub(crate) fn get_instructions(
-    calls: &[ContractCall],
-    offsets: Vec<CallOpcodeParamsOffset>,
-) -> Result<Vec<u8>> {
-    calls
-        .iter()
-        .zip(&offsets)
-        .map(|(call, offset)| get_single_call_instructions(offset, &call.output_param))
-        .process_results(|iter| iter.flatten().collect::<Vec<_>>())
-        .map(|mut bytes| {
-            bytes.extend(op::ret(RegId::ONE).to_bytes());
-            bytes
-        })
-}
-
 /// Returns script data, consisting of the following items in the given order:
 /// 1. Amount to be forwarded `(1 * `[`WORD_SIZE`]`)`
 /// 2. Asset ID to be forwarded ([`AssetId::LEN`])
@@ -264,6 +411,8 @@ pub(crate) fn get_instructions(
 /// 6. Encoded function selector - method name
 /// 7. Encoded arguments
 /// 8. Gas to be forwarded `(1 * `[`WORD_SIZE`]`)` - Optional
+/// 9. Amount `(1 * `[`WORD_SIZE`]`)` and asset ID ([`AssetId::LEN`]) of each asset transferred
+///    before the call - Optional
 pub(crate) fn build_script_data_from_contract_calls(
     calls: &[ContractCall],
     data_offset: usize,
@@ -305,11 +454,25 @@ pub(crate) fn build_script_data_from_contract_calls(
             encoded_args_offset + encoded_args_len
         });
 
+        let forwarded_assets_offsets = call
+            .call_parameters
+            .forwarded_assets()
+            .iter()
+            .map(|(asset_id, amount)| {
+                let amount_offset = data_offset + script_data.len();
+                script_data.extend(amount.to_be_bytes()); // 9. Forwarded amount - Optional
+                script_data.extend(asset_id.iter()); // 9. Forwarded asset ID - Optional
+
+                (amount_offset, amount_offset + WORD_SIZE)
+            })
+            .collect();
+
         param_offsets.push(CallOpcodeParamsOffset {
             amount_offset,
             asset_id_offset,
             gas_forwarded_offset,
             call_data_offset,
+            forwarded_assets_offsets,
         });
 
         // the data segment that holds the parameters for the next call
@@ -320,62 +483,6 @@ pub(crate) fn build_script_data_from_contract_calls(
     Ok((script_data, param_offsets))
 }
 
-/// Returns the VM instructions for calling a contract method
-/// We use the [`Opcode`] to call a contract: [`CALL`](Opcode::CALL)
-/// pointing at the following registers:
-///
-/// 0x10 Script data offset
-/// 0x11 Coin amount
-/// 0x12 Asset ID
-/// 0x13 Gas forwarded
-///
-/// Note that these are soft rules as we're picking this addresses simply because they
-/// non-reserved register.
-pub(crate) fn get_single_call_instructions(
-    offsets: &CallOpcodeParamsOffset,
-    _output_param_type: &ParamType,
-) -> Result<Vec<u8>> {
-    let call_data_offset = offsets
-        .call_data_offset
-        .try_into()
-        .expect("call_data_offset out of range");
-    let amount_offset = offsets
-        .amount_offset
-        .try_into()
-        .expect("amount_offset out of range");
-    let asset_id_offset = offsets
-        .asset_id_offset
-        .try_into()
-        .expect("asset_id_offset out of range");
-
-    let mut instructions = [
-        op::movi(0x10, call_data_offset),
-        op::movi(0x11, amount_offset),
-        op::lw(0x11, 0x11, 0),
-        op::movi(0x12, asset_id_offset),
-    ]
-    .to_vec();
-
-    match offsets.gas_forwarded_offset {
-        Some(gas_forwarded_offset) => {
-            let gas_forwarded_offset = gas_forwarded_offset
-                .try_into()
-                .expect("gas_forwarded_offset out of range");
-
-            instructions.extend(&[
-                op::movi(0x13, gas_forwarded_offset),
-                op::lw(0x13, 0x13, 0),
-                op::call(0x10, 0x11, 0x12, 0x13),
-            ]);
-        }
-        // if `gas_forwarded` was not set use `REG_CGAS`
-        None => instructions.push(op::call(0x10, 0x11, 0x12, RegId::CGAS)),
-    };
-
-    #[allow(clippy::iter_cloned_collect)]
-    Ok(instructions.into_iter().collect::<Vec<u8>>())
-}
-
 /// Returns the assets and contracts that will be consumed ([`Input`]s)
 /// and created ([`Output`]s) by the transaction
 pub(crate) fn get_transaction_inputs_outputs(
@@ -383,9 +490,28 @@ pub(crate) fn get_transaction_inputs_outputs(
     asset_inputs: Vec<Input>,
     address: &Bech32Address,
     base_asset_id: AssetId,
+) -> (Vec<Input>, Vec<Output>) {
+    get_transaction_inputs_outputs_with_contracts(
+        calls,
+        HashSet::new(),
+        asset_inputs,
+        address,
+        base_asset_id,
+    )
+}
+
+/// Like [`get_transaction_inputs_outputs`], with inputs and outputs for `other_contracts` too,
+/// e.g. the contracts called by a script executed after the calls.
+pub(crate) fn get_transaction_inputs_outputs_with_contracts(
+    calls: &[ContractCall],
+    other_contracts: HashSet<ContractId>,
+    asset_inputs: Vec<Input>,
+    address: &Bech32Address,
+    base_asset_id: AssetId,
 ) -> (Vec<Input>, Vec<Output>) {
     let asset_ids = extract_unique_asset_ids(&asset_inputs, base_asset_id);
-    let contract_ids = extract_unique_contract_ids(calls);
+    let mut contract_ids = extract_unique_contract_ids(calls);
+    contract_ids.extend(other_contracts);
     let num_of_contracts = contract_ids.len();
 
     let inputs = chain!(generate_contract_inputs(contract_ids), asset_inputs).collect();
@@ -394,8 +520,11 @@ pub(crate) fn get_transaction_inputs_outputs(
     // contract_inputs are referencing them via `output_index`. The node
     // will, upon receiving our request, use `output_index` to index the
     // `inputs` array we've sent over.
+    // The call script transfers to the variable outputs right after them, see
+    // `build_call_script`.
     let outputs = chain!(
         generate_contract_outputs(num_of_contracts),
+        generate_transfer_outputs(calls),
         generate_asset_change_outputs(address, asset_ids),
         generate_custom_outputs(calls),
         extract_variable_outputs(calls)
@@ -427,6 +556,13 @@ fn generate_custom_outputs(calls: &[ContractCall]) -> Vec<Output> {
         .collect::<Vec<_>>()
 }
 
+/// The variable outputs filled by the transfers of `calls`.
+fn generate_transfer_outputs(calls: &[ContractCall]) -> Vec<Output> {
+    let num_transfers = calls.iter().map(|call| call.transfers.len()).sum();
+
+    new_variable_outputs(num_transfers)
+}
+
 fn extract_unique_asset_ids(asset_inputs: &[Input], base_asset_id: AssetId) -> HashSet<AssetId> {
     asset_inputs
         .iter()
@@ -478,7 +614,7 @@ pub(crate) fn generate_contract_inputs(contract_ids: HashSet<ContractId>) -> Vec
         .collect()
 }
 
-fn extract_unique_contract_ids(calls: &[ContractCall]) -> HashSet<ContractId> {
+pub(crate) fn extract_unique_contract_ids(calls: &[ContractCall]) -> HashSet<ContractId> {
     calls
         .iter()
         .flat_map(|call| {
@@ -530,6 +666,7 @@ mod test {
     use fuels_core::types::{
         coin::{Coin, CoinStatus},
         coin_type::CoinType,
+        param_types::ParamType,
     };
     use rand::Rng;
 
@@ -548,7 +685,9 @@ mod test {
                 external_contracts: Default::default(),
                 output_param: ParamType::Unit,
                 is_payable: false,
+                writes_storage: None,
                 custom_assets: Default::default(),
+                transfers: vec![],
             }
         }
     }
@@ -807,8 +946,49 @@ mod test {
         )
     }
 
+    #[test]
+    fn forwarded_assets_are_required_and_in_script_data() -> Result<()> {
+        let asset_id_1 = AssetId::from([1; 32]);
+        let asset_id_2 = AssetId::from([2; 32]);
+        let call = ContractCall::new_with_random_id().with_call_parameters(
+            CallParameters::default()
+                .with_amount(100)
+                .with_asset_id(asset_id_1)
+                .with_forwarded_assets(vec![(asset_id_1, 200), (asset_id_2, 300)]),
+        );
+
+        let asset_id_amounts =
+            calculate_required_asset_amounts(slice::from_ref(&call), AssetId::zeroed());
+        assert_eq!(
+            asset_id_amounts.into_iter().collect::<HashSet<_>>(),
+            [(asset_id_1, 300), (asset_id_2, 300)].into()
+        );
+
+        let data_offset = 1000;
+        let (script_data, offsets) =
+            build_script_data_from_contract_calls(&[call], data_offset, AssetId::zeroed())?;
+
+        let forwarded = offsets[0]
+            .forwarded_assets_offsets
+            .iter()
+            .map(|(amount_offset, asset_id_offset)| {
+                let amount = &script_data[amount_offset - data_offset..][..WORD_SIZE];
+                let asset_id = &script_data[asset_id_offset - data_offset..][..AssetId::LEN];
+
+                (
+                    AssetId::try_from(asset_id).unwrap(),
+                    u64::from_be_bytes(amount.try_into().unwrap()),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(forwarded, vec![(asset_id_1, 200), (asset_id_2, 300)]);
+
+        Ok(())
+    }
+
     mod compute_calls_instructions_len {
         use fuel_asm::Instruction;
+        use fuel_tx::AssetId;
         use fuels_core::types::param_types::{EnumVariants, ParamType};
 
         use crate::{call_utils::compute_calls_instructions_len, contract::ContractCall};
@@ -817,6 +997,8 @@ mod test {
         const BASE_INSTRUCTION_COUNT: usize = 5;
         // 2 instructions (movi and lw) added in get_single_call_instructions when gas_offset is set
         const GAS_OFFSET_INSTRUCTION_COUNT: usize = 2;
+        // movi, lw, movi + tr for each forwarded asset
+        const FORWARDED_ASSET_INSTRUCTION_COUNT: usize = 4;
 
         #[test]
         fn test_simple() {
@@ -836,6 +1018,21 @@ mod test {
             );
         }
 
+        #[test]
+        fn test_with_forwarded_assets() {
+            let mut call = ContractCall::new_with_random_id();
+            call.call_parameters = call.call_parameters.with_forwarded_assets(vec![
+                (AssetId::from([1; 32]), 10),
+                (AssetId::from([2; 32]), 20),
+            ]);
+            let instructions_len = compute_calls_instructions_len(&[call]).unwrap();
+            assert_eq!(
+                instructions_len,
+                Instruction::SIZE
+                    * (BASE_INSTRUCTION_COUNT + 2 * FORWARDED_ASSET_INSTRUCTION_COUNT)
+            );
+        }
+
         #[test]
         fn test_with_enum_with_only_non_heap_variants() {
             let mut call = ContractCall::new_with_random_id();
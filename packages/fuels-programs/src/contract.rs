@@ -7,20 +7,36 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use fuel_asm::Instruction;
 use fuel_tx::{
-    AssetId, Bytes32, Contract as FuelContract, ContractId, Output, Receipt, Salt, StorageSlot,
+    AssetId, Bytes32, Contract as FuelContract, ContractId, Create, Output, Receipt, Salt,
+    StorageSlot,
+};
+use fuel_types::canonical::Serialize;
+#[cfg(feature = "raw-graphql")]
+use fuels_accounts::provider::ExecutionTrace;
+use fuels_accounts::{
+    provider::{Provider, ResubmitPolicy, SimulationOptions, TransactionCost},
+    Account,
 };
-use fuels_accounts::{provider::TransactionCost, Account};
 use fuels_core::{
-    codec::{ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
-    constants::DEFAULT_CALL_PARAMS_AMOUNT,
+    codec::{encode_fn_selector, ABIEncoder, DecoderConfig, EncoderConfig, LogDecoder},
+    constants::{
+        DEFAULT_CALL_PARAMS_AMOUNT, DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON, SIGNATURE_WITNESS_SIZE,
+    },
     traits::{Parameterize, Tokenizable},
     types::{
         bech32::{Bech32Address, Bech32ContractId},
+        coin::Coin,
+        coin_type::CoinType,
         errors::{error, Error, Result},
+        input::Input,
         param_types::ParamType,
         transaction::{ScriptTransaction, Transaction, TxPolicies},
-        transaction_builders::{CreateTransactionBuilder, ScriptTransactionBuilder},
+        transaction_builders::{
+            CreateTransactionBuilder, ScriptTransactionBuilder, TransactionBuilder,
+            VariableOutputPolicy,
+        },
         tx_status::TxStatus,
         unresolved_bytes::UnresolvedBytes,
         Selector, Token,
@@ -30,19 +46,36 @@ use fuels_core::{
 
 use crate::{
     call_response::FuelCallResponse,
+    call_script::CustomInstructions,
     call_utils::{
-        build_tx_from_contract_calls, new_variable_outputs, sealed,
-        transaction_builder_from_contract_calls, TxDependencyExtension,
+        build_tx_for_simulation, build_tx_from_contract_calls, new_variable_outputs, sealed,
+        transaction_builder_from_contract_calls, used_base_amount, TxDependencyExtension,
     },
     receipt_parser::ReceiptParser,
+    receipt_sink,
     submit_response::{SubmitResponse, SubmitResponseMultiple},
 };
 
+mod metadata;
+mod ownable;
+mod proxy;
+mod read_cache;
+mod src3;
+mod token;
+pub use metadata::{Metadata, MetadataContract, DESCRIPTION_KEY, IMAGE_URI_KEY};
+pub use ownable::{Ownable, OwnershipState};
+pub use proxy::ProxyContract;
+pub use read_cache::ReadCallCache;
+use read_cache::ReadCallKey;
+pub use src3::Src3Contract;
+pub use token::{format_units, parse_units, TokenContract};
+
 #[derive(Debug, Clone)]
 pub struct CallParameters {
     amount: u64,
     asset_id: Option<AssetId>,
     gas_forwarded: Option<u64>,
+    forwarded_assets: Vec<(AssetId, u64)>,
 }
 
 impl CallParameters {
@@ -51,6 +84,7 @@ impl CallParameters {
             amount,
             asset_id: Some(asset_id),
             gas_forwarded: Some(gas_forwarded),
+            forwarded_assets: vec![],
         }
     }
 
@@ -80,6 +114,18 @@ impl CallParameters {
     pub fn gas_forwarded(&self) -> Option<u64> {
         self.gas_forwarded
     }
+
+    /// Assets transferred to the contract by the same script, right before the call. Unlike the
+    /// amount set with [`CallParameters::with_amount`], they are not reported by `msg_amount`
+    /// and `msg_asset_id`, only credited to the balance of the contract.
+    pub fn with_forwarded_assets(mut self, forwarded_assets: Vec<(AssetId, u64)>) -> Self {
+        self.forwarded_assets = forwarded_assets;
+        self
+    }
+
+    pub fn forwarded_assets(&self) -> &[(AssetId, u64)] {
+        &self.forwarded_assets
+    }
 }
 
 impl Default for CallParameters {
@@ -88,6 +134,7 @@ impl Default for CallParameters {
             amount: DEFAULT_CALL_PARAMS_AMOUNT,
             asset_id: None,
             gas_forwarded: None,
+            forwarded_assets: vec![],
         }
     }
 }
@@ -238,6 +285,70 @@ impl LoadConfiguration {
     }
 }
 
+/// The expected cost of deploying a [`Contract`], see [`Contract::estimate_deploy_cost`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+// ANCHOR: deploy_cost
+pub struct DeployCost {
+    pub gas_price: u64,
+    /// The max fee of the deployment transaction.
+    pub fee: u64,
+    pub bytecode_size: u64,
+    /// The largest bytecode the chain accepts.
+    pub max_bytecode_size: u64,
+    pub storage_slots: u64,
+    /// The most storage slots a contract can be deployed with.
+    pub max_storage_slots: u64,
+    /// The part of `fee` charged for the storage slots.
+    pub storage_slots_fee: u64,
+    /// The size of the deployment transaction, witnesses included.
+    pub tx_size: u64,
+    /// The largest transaction the chain accepts.
+    pub max_tx_size: u64,
+}
+// ANCHOR_END: deploy_cost
+
+impl DeployCost {
+    /// Fails with every limit of the chain the deployment exceeds.
+    pub fn check_limits(&self) -> Result<()> {
+        let exceeded = [
+            (
+                self.bytecode_size > self.max_bytecode_size,
+                format!(
+                    "bytecode size {} exceeds the limit of {}",
+                    self.bytecode_size, self.max_bytecode_size
+                ),
+            ),
+            (
+                self.storage_slots > self.max_storage_slots,
+                format!(
+                    "{} storage slots exceed the limit of {}",
+                    self.storage_slots, self.max_storage_slots
+                ),
+            ),
+            (
+                self.tx_size > self.max_tx_size,
+                format!(
+                    "transaction size {} exceeds the limit of {}",
+                    self.tx_size, self.max_tx_size
+                ),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(is_exceeded, message)| is_exceeded.then_some(message))
+        .collect::<Vec<_>>();
+
+        if exceeded.is_empty() {
+            Ok(())
+        } else {
+            Err(error!(
+                Other,
+                "contract cannot be deployed: {}",
+                exceeded.join("; ")
+            ))
+        }
+    }
+}
+
 /// [`Contract`] is a struct to interface with a contract. That includes things such as
 /// compiling, deploying, and running transactions against a contract.
 #[derive(Debug)]
@@ -327,6 +438,73 @@ impl Contract {
         Ok(self.contract_id.into())
     }
 
+    /// Estimates what deploying the contract with `tx_policies` costs, without deploying it. The
+    /// estimation assumes the fee is paid with a single coin, i.e. one input, its signature and
+    /// a change output, like [`Contract::deploy`] usually does.
+    ///
+    /// The contract is deployed in a single transaction, use [`DeployCost::check_limits`] to
+    /// fail early when it exceeds the limits of the chain.
+    pub async fn estimate_deploy_cost(
+        &self,
+        provider: &Provider,
+        tx_policies: TxPolicies,
+    ) -> Result<DeployCost> {
+        let consensus_parameters = provider.consensus_parameters();
+        let base_asset_id = *provider.base_asset_id();
+
+        // Stands in for the account paying for the deployment.
+        let payer = Bech32Address::default();
+        let fee_coin = CoinType::Coin(Coin {
+            owner: payer.clone(),
+            asset_id: base_asset_id,
+            ..Default::default()
+        });
+
+        let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
+            self.binary.clone(),
+            self.contract_id,
+            self.state_root,
+            self.salt,
+            self.storage_slots.clone(),
+            tx_policies,
+        );
+        tb.inputs_mut().push(Input::resource_signed(fee_coin));
+        tb.outputs_mut()
+            .push(Output::change((&payer).into(), 0, base_asset_id));
+
+        let tx = tb.build(provider).await?;
+
+        let gas_price = provider
+            .estimate_gas_price(DEFAULT_GAS_ESTIMATION_BLOCK_HORIZON)
+            .await?
+            .gas_price;
+        let fee = tx
+            .fee_checked_from_tx(consensus_parameters, gas_price)
+            .ok_or_else(|| error!(Other, "could not calculate the fee of the deployment"))?
+            .max_fee();
+
+        let fee_params = consensus_parameters.fee_params();
+        let storage_slots_gas =
+            (self.storage_slots.len() * StorageSlot::SLOT_SIZE) as u64 * fee_params.gas_per_byte();
+        let storage_slots_fee = (storage_slots_gas as u128 * gas_price as u128)
+            .div_ceil(fee_params.gas_price_factor() as u128) as u64;
+
+        // The signature of the payer isn't part of the built transaction.
+        let tx_size = Create::from(tx).size() + SIGNATURE_WITNESS_SIZE;
+
+        Ok(DeployCost {
+            gas_price,
+            fee,
+            bytecode_size: self.binary.len() as u64,
+            max_bytecode_size: consensus_parameters.contract_params().contract_max_size(),
+            storage_slots: self.storage_slots.len() as u64,
+            max_storage_slots: consensus_parameters.contract_params().max_storage_slots(),
+            storage_slots_fee,
+            tx_size: tx_size as u64,
+            max_tx_size: consensus_parameters.tx_params().max_size(),
+        })
+    }
+
     pub fn load_from(binary_filepath: impl AsRef<Path>, config: LoadConfiguration) -> Result<Self> {
         let binary_filepath = binary_filepath.as_ref();
         validate_path_and_extension(binary_filepath, "bin")?;
@@ -414,7 +592,22 @@ pub struct ContractCall {
     pub external_contracts: Vec<Bech32ContractId>,
     pub output_param: ParamType,
     pub is_payable: bool,
+    /// Whether the method writes to storage, as declared by its `#[storage]` attribute. `None`
+    /// if unknown, e.g. for calls not built by `abigen!`.
+    pub writes_storage: Option<bool>,
     pub custom_assets: HashMap<(AssetId, Option<Bech32Address>), u64>,
+    /// Transfers performed by the call script after the calls, see
+    /// [`ContractCallHandler::add_transfer`].
+    pub transfers: Vec<Transfer>,
+}
+
+/// A transfer of `amount` of `asset_id` to `recipient`, performed by the call script with
+/// [`TRO`](fuel_asm::Opcode::TRO) after the calls, so that it is reverted if a call fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+    pub recipient: Bech32Address,
+    pub amount: u64,
+    pub asset_id: AssetId,
 }
 
 impl ContractCall {
@@ -461,6 +654,10 @@ impl ContractCall {
     pub fn add_custom_asset(&mut self, asset_id: AssetId, amount: u64, to: Option<Bech32Address>) {
         *self.custom_assets.entry((asset_id, to)).or_default() += amount;
     }
+
+    pub fn add_transfer(&mut self, transfer: Transfer) {
+        self.transfers.push(transfer);
+    }
 }
 
 #[derive(Debug)]
@@ -470,6 +667,10 @@ pub struct ContractCallHandler<T: Account, D> {
     pub contract_call: ContractCall,
     pub tx_policies: TxPolicies,
     decoder_config: DecoderConfig,
+    variable_output_policy: VariableOutputPolicy,
+    resubmit_policy: ResubmitPolicy,
+    read_cache: Option<ReadCallCache>,
+    custom_instructions: CustomInstructions,
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     pub account: T,
@@ -539,10 +740,47 @@ where
         self
     }
 
+    /// Transfers `amount` of `asset_id` from the account to `recipient` in the call transaction,
+    /// e.g. to pay a referrer. The call script transfers the asset after the call, to a variable
+    /// output added to the transaction, so the transfer is reverted if the call fails.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .add_transfer(referrer.address(), 100, AssetId::zeroed())
+    ///     .call()
+    /// ```
+    pub fn add_transfer(
+        mut self,
+        recipient: &Bech32Address,
+        amount: u64,
+        asset_id: AssetId,
+    ) -> Self {
+        self.contract_call.add_transfer(Transfer {
+            recipient: recipient.clone(),
+            amount,
+            asset_id,
+        });
+        self
+    }
+
     pub fn is_payable(&self) -> bool {
         self.contract_call.is_payable
     }
 
+    /// Returns `None` if it isn't known whether the method writes to storage.
+    pub fn writes_storage(&self) -> Option<bool> {
+        self.contract_call.writes_storage
+    }
+
+    /// Declares whether the method writes to storage, which [`Self::read`] checks. Set by the
+    /// bindings generated by `abigen!` from the `#[storage]` attribute of the method.
+    pub fn with_writes_storage(mut self, writes_storage: bool) -> Self {
+        self.contract_call.writes_storage = Some(writes_storage);
+        self
+    }
+
     /// Sets the transaction policies for a given transaction.
     /// Note that this is a builder method, i.e. use it as a chain:
     /// ```ignore
@@ -560,6 +798,74 @@ where
         self
     }
 
+    /// Sets how many variable outputs are added to the transaction, on top of the ones appended
+    /// with `append_variable_outputs`. See [`VariableOutputPolicy`] for the available options.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_variable_output_policy(VariableOutputPolicy::EstimateMinimum)
+    ///     .call()
+    /// ```
+    pub fn with_variable_output_policy(mut self, policy: VariableOutputPolicy) -> Self {
+        self.variable_output_policy = policy;
+        self
+    }
+
+    /// Resubmits the transaction if it is squeezed out, see [`ResubmitPolicy`]. It is rebuilt
+    /// with fresh inputs and a higher tip, and signed again by the account.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_resubmit_policy(ResubmitPolicy::new(3, 100))
+    ///     .call()
+    /// ```
+    pub fn with_resubmit_policy(mut self, policy: ResubmitPolicy) -> Self {
+        self.resubmit_policy = policy;
+        self
+    }
+
+    /// Serves [`Self::simulate`] from `read_cache` when the same call was simulated at the
+    /// current block height, see [`ReadCallCache`]. Contract instances set the cache given to
+    /// their `with_read_cache`.
+    pub fn with_read_cache(mut self, read_cache: impl Into<Option<ReadCallCache>>) -> Self {
+        self.read_cache = read_cache.into();
+        self
+    }
+
+    /// Sets instructions executed before the contract call, e.g. to check a balance or transfer
+    /// assets. The registers 0x10 to 0x13 are overwritten by the call, use other ones to pass
+    /// values to the epilogue.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_prologue(vec![op::movi(0x20, 1)])
+    ///     .call()
+    /// ```
+    pub fn with_prologue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.prologue = instructions;
+        self
+    }
+
+    /// Sets instructions executed after the contract call, before the script returns.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// my_contract_instance
+    ///     .my_method(...)
+    ///     .with_epilogue(vec![op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO)])
+    ///     .call()
+    /// ```
+    pub fn with_epilogue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.epilogue = instructions;
+        self
+    }
+
     /// Sets the call parameters for a given contract call.
     /// Note that this is a builder method, i.e. use it as a chain:
     ///
@@ -568,7 +874,7 @@ where
     /// my_contract_instance.my_method(...).call_params(params).call()
     /// ```
     pub fn call_params(mut self, params: CallParameters) -> Result<Self> {
-        if !self.is_payable() && params.amount > 0 {
+        if !self.is_payable() && (params.amount > 0 || !params.forwarded_assets.is_empty()) {
             return Err(error!(Other, "assets forwarded to non-payable method"));
         }
         self.contract_call.call_parameters = params;
@@ -578,7 +884,9 @@ where
     pub async fn transaction_builder(&self) -> Result<ScriptTransactionBuilder> {
         transaction_builder_from_contract_calls(
             std::slice::from_ref(&self.contract_call),
+            &self.custom_instructions,
             self.tx_policies,
+            self.variable_output_policy,
             &self.account,
         )
         .await
@@ -586,9 +894,15 @@ where
 
     /// Returns the script that executes the contract call
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
+        self.build_tx_with_policies(self.tx_policies).await
+    }
+
+    async fn build_tx_with_policies(&self, tx_policies: TxPolicies) -> Result<ScriptTransaction> {
         build_tx_from_contract_calls(
             std::slice::from_ref(&self.contract_call),
-            self.tx_policies,
+            &self.custom_instructions,
+            tx_policies,
+            self.variable_output_policy,
             &self.account,
         )
         .await
@@ -612,21 +926,85 @@ where
     /// Call a contract's method on the node, in a simulated manner, meaning the state of the
     /// blockchain is *not* modified but simulated.
     pub async fn simulate(&mut self) -> Result<FuelCallResponse<D>> {
-        self.call_or_simulate(true).await
+        let Some(read_cache) = self.read_cache.clone() else {
+            return self.call_or_simulate(true).await;
+        };
+
+        let block_height = self.account.try_provider()?.latest_block_height().await?;
+        let key = ReadCallKey::new(
+            &self.contract_call,
+            self.account.address().into(),
+            block_height,
+        );
+        if let Some(receipts) = key.as_ref().and_then(|key| read_cache.get(key)) {
+            return self.get_response(receipts);
+        }
+
+        let response = self.call_or_simulate(true).await?;
+        if let Some(key) = key {
+            read_cache.insert(key, response.receipts.clone());
+        }
+
+        Ok(response)
     }
 
-    async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
+    /// Calls a read-only method without spending anything: the call is always dry run, without
+    /// UTXO validation and at a zero gas price, so the account doesn't need to be funded.
+    ///
+    /// Fails without dry running the call if the method writes to storage, or if the call
+    /// forwards assets. Methods not known to be read-only, see [`Self::writes_storage`], are
+    /// dry run all the same.
+    pub async fn read(&mut self) -> Result<FuelCallResponse<D>> {
+        check_read_only(&self.contract_call)?;
+
+        self.simulate_with_options(read_only_simulation()).await
+    }
+
+    /// Like [`Self::simulate`], but dry runs with the given [`SimulationOptions`]. Without UTXO
+    /// validation, read-only calls can be simulated for accounts that aren't funded yet. Calls
+    /// forwarding assets still need the account to own them.
+    pub async fn simulate_with_options(
+        &mut self,
+        options: SimulationOptions,
+    ) -> Result<FuelCallResponse<D>> {
+        let calls = std::slice::from_ref(&self.contract_call);
         let provider = self.account.try_provider()?;
 
+        let tb = self.transaction_builder().await?;
+        let used_base_amount = used_base_amount(calls, *provider.base_asset_id());
+        let tx = build_tx_for_simulation(tb, &self.account, used_base_amount, options).await?;
+
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
-        let tx_status = if simulate {
-            provider.dry_run(tx).await?
+        let tx_status = provider.dry_run_with_options(tx, options).await?;
+        receipt_sink::capture(self.cached_tx_id, true, &tx_status, &self.log_decoder);
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            provider.source_maps(),
+        )?;
+
+        self.get_response(receipts)
+    }
+
+    async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
+        let provider = self.account.try_provider()?;
+
+        let (tx_id, tx_status) = if simulate {
+            let tx = self.build_tx().await?;
+            (tx.id(provider.chain_id()), provider.dry_run(tx).await?)
         } else {
-            provider.send_transaction_and_await_commit(tx).await?
+            self.resubmit_policy
+                .send_and_await_commit(provider, self.tx_policies, |tx_policies| {
+                    self.build_tx_with_policies(tx_policies)
+                })
+                .await?
         };
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        self.cached_tx_id = Some(tx_id);
+        receipt_sink::capture(self.cached_tx_id, simulate, &tx_status, &self.log_decoder);
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            provider.source_maps(),
+        )?;
 
         self.get_response(receipts)
     }
@@ -647,6 +1025,34 @@ where
         Ok(transaction_cost)
     }
 
+    /// Like [`Self::estimate_transaction_cost`], but computes the fee at `gas_price`, see
+    /// [`Provider::estimate_transaction_cost_at_gas_price`].
+    pub async fn estimate_transaction_cost_at_gas_price(
+        &self,
+        tolerance: Option<f64>,
+        gas_price: u64,
+    ) -> Result<TransactionCost> {
+        let script = self.build_tx().await?;
+        let provider = self.account.try_provider()?;
+
+        provider
+            .estimate_transaction_cost_at_gas_price(script, tolerance, gas_price)
+            .await
+    }
+
+    /// Executes the call instruction by instruction with the debugger of the node, see
+    /// [`Provider::execution_trace`]. Useful to find out where and why a call reverts, the
+    /// registers are captured for the last `window` instructions.
+    #[cfg(feature = "raw-graphql")]
+    pub async fn execution_trace(&self, window: usize) -> Result<ExecutionTrace> {
+        let tx = self.build_tx().await?;
+
+        self.account
+            .try_provider()?
+            .execution_trace(tx, window)
+            .await
+    }
+
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         let token = ReceiptParser::new(&receipts, self.decoder_config).parse_call(
@@ -664,7 +1070,13 @@ where
 
     /// Create a [`FuelCallResponse`] from `TxStatus`
     pub fn get_response_from(&self, tx_status: TxStatus) -> Result<FuelCallResponse<D>> {
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            self.account
+                .try_provider()
+                .ok()
+                .and_then(Provider::source_maps),
+        )?;
 
         self.get_response(receipts)
     }
@@ -740,7 +1152,9 @@ pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
         external_contracts: vec![],
         output_param: D::param_type(),
         is_payable,
+        writes_storage: None,
         custom_assets: Default::default(),
+        transfers: vec![],
     };
 
     ContractCallHandler {
@@ -751,9 +1165,62 @@ pub fn method_hash<D: Tokenizable + Parameterize + Debug, T: Account>(
         datatype: PhantomData,
         log_decoder,
         decoder_config: Default::default(),
+        variable_output_policy: Default::default(),
+        resubmit_policy: Default::default(),
+        read_cache: None,
+        custom_instructions: Default::default(),
     }
 }
 
+/// Prepares a call to `method` of a contract whose ABI is known in advance, e.g. one
+/// implementing a standard, without going through generated bindings.
+pub(crate) fn method_by_name<D: Tokenizable + Parameterize + Debug, T: Account>(
+    contract_id: Bech32ContractId,
+    account: T,
+    method: &str,
+    args: &[Token],
+) -> ContractCallHandler<T, D> {
+    method_hash(
+        contract_id,
+        account,
+        encode_fn_selector(method),
+        args,
+        LogDecoder::default(),
+        false,
+        EncoderConfig::default(),
+    )
+}
+
+fn check_read_only(call: &ContractCall) -> Result<()> {
+    if call.writes_storage == Some(true) {
+        return Err(error!(
+            Other,
+            "cannot `read` a method writing to the storage of contract {}, use `call` instead",
+            call.contract_id
+        ));
+    }
+
+    let params = &call.call_parameters;
+    if params.amount > 0
+        || !params.forwarded_assets.is_empty()
+        || !call.custom_assets.is_empty()
+        || !call.transfers.is_empty()
+    {
+        return Err(error!(
+            Other,
+            "cannot `read` a call forwarding or transferring assets, use `call` instead"
+        ));
+    }
+
+    Ok(())
+}
+
+fn read_only_simulation() -> SimulationOptions {
+    SimulationOptions::default()
+        .with_utxo_validation(false)
+        .with_zero_gas_price(true)
+}
+
 #[derive(Debug)]
 #[must_use = "contract calls do nothing unless you `call` them"]
 /// Helper that handles bundling multiple calls into a single transaction
@@ -764,6 +1231,9 @@ pub struct MultiContractCallHandler<T: Account> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    variable_output_policy: VariableOutputPolicy,
+    resubmit_policy: ResubmitPolicy,
+    custom_instructions: CustomInstructions,
     pub account: T,
 }
 
@@ -776,6 +1246,9 @@ impl<T: Account> MultiContractCallHandler<T> {
             account,
             log_decoder: LogDecoder::new(Default::default()),
             decoder_config: DecoderConfig::default(),
+            variable_output_policy: VariableOutputPolicy::default(),
+            resubmit_policy: ResubmitPolicy::default(),
+            custom_instructions: CustomInstructions::default(),
         }
     }
 
@@ -803,6 +1276,36 @@ impl<T: Account> MultiContractCallHandler<T> {
         self
     }
 
+    /// Sets how many variable outputs are added to the transaction, on top of the ones appended
+    /// to the calls. See [`VariableOutputPolicy`] for the available options.
+    /// Note that this is a builder method
+    pub fn with_variable_output_policy(mut self, policy: VariableOutputPolicy) -> Self {
+        self.variable_output_policy = policy;
+        self
+    }
+
+    /// Resubmits the transaction if it is squeezed out, see [`ResubmitPolicy`].
+    /// Note that this is a builder method
+    pub fn with_resubmit_policy(mut self, policy: ResubmitPolicy) -> Self {
+        self.resubmit_policy = policy;
+        self
+    }
+
+    /// Sets instructions executed before the first contract call. The registers 0x10 to 0x13 are
+    /// overwritten by the calls, use other ones to pass values to the epilogue.
+    /// Note that this is a builder method
+    pub fn with_prologue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.prologue = instructions;
+        self
+    }
+
+    /// Sets instructions executed after the last contract call, before the script returns.
+    /// Note that this is a builder method
+    pub fn with_epilogue(mut self, instructions: Vec<Instruction>) -> Self {
+        self.custom_instructions.epilogue = instructions;
+        self
+    }
+
     fn validate_contract_calls(&self) -> Result<()> {
         if self.contract_calls.is_empty() {
             return Err(error!(
@@ -847,7 +1350,9 @@ impl<T: Account> MultiContractCallHandler<T> {
 
         transaction_builder_from_contract_calls(
             &self.contract_calls,
+            &self.custom_instructions,
             self.tx_policies,
+            self.variable_output_policy,
             &self.account,
         )
         .await
@@ -855,9 +1360,20 @@ impl<T: Account> MultiContractCallHandler<T> {
 
     /// Returns the script that executes the contract calls
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
+        self.build_tx_with_policies(self.tx_policies).await
+    }
+
+    async fn build_tx_with_policies(&self, tx_policies: TxPolicies) -> Result<ScriptTransaction> {
         self.validate_contract_calls()?;
 
-        build_tx_from_contract_calls(&self.contract_calls, self.tx_policies, &self.account).await
+        build_tx_from_contract_calls(
+            &self.contract_calls,
+            &self.custom_instructions,
+            tx_policies,
+            self.variable_output_policy,
+            &self.account,
+        )
+        .await
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
@@ -884,22 +1400,61 @@ impl<T: Account> MultiContractCallHandler<T> {
         self.call_or_simulate(true).await
     }
 
-    async fn call_or_simulate<D: Tokenizable + Debug>(
+    /// Calls read-only methods without spending anything, see [`ContractCallHandler::read`].
+    /// Fails if any of the methods writes to storage or forwards assets.
+    pub async fn read<D: Tokenizable + Debug>(&mut self) -> Result<FuelCallResponse<D>> {
+        self.contract_calls.iter().try_for_each(check_read_only)?;
+
+        self.simulate_with_options(read_only_simulation()).await
+    }
+
+    /// Like [`Self::simulate`], but dry runs with the given [`SimulationOptions`], see
+    /// [`ContractCallHandler::simulate_with_options`].
+    pub async fn simulate_with_options<D: Tokenizable + Debug>(
         &mut self,
-        simulate: bool,
+        options: SimulationOptions,
     ) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
         let provider = self.account.try_provider()?;
 
+        let tb = self.transaction_builder().await?;
+        let used_base_amount = used_base_amount(&self.contract_calls, *provider.base_asset_id());
+        let tx = build_tx_for_simulation(tb, &self.account, used_base_amount, options).await?;
+
         self.cached_tx_id = Some(tx.id(provider.chain_id()));
 
-        let tx_status = if simulate {
-            provider.dry_run(tx).await?
+        let tx_status = provider.dry_run_with_options(tx, options).await?;
+        receipt_sink::capture(self.cached_tx_id, true, &tx_status, &self.log_decoder);
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            provider.source_maps(),
+        )?;
+
+        self.get_response(receipts)
+    }
+
+    async fn call_or_simulate<D: Tokenizable + Debug>(
+        &mut self,
+        simulate: bool,
+    ) -> Result<FuelCallResponse<D>> {
+        let provider = self.account.try_provider()?;
+
+        let (tx_id, tx_status) = if simulate {
+            let tx = self.build_tx().await?;
+            (tx.id(provider.chain_id()), provider.dry_run(tx).await?)
         } else {
-            provider.send_transaction_and_await_commit(tx).await?
+            self.resubmit_policy
+                .send_and_await_commit(provider, self.tx_policies, |tx_policies| {
+                    self.build_tx_with_policies(tx_policies)
+                })
+                .await?
         };
+        self.cached_tx_id = Some(tx_id);
+        receipt_sink::capture(self.cached_tx_id, simulate, &tx_status, &self.log_decoder);
 
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            provider.source_maps(),
+        )?;
 
         self.get_response(receipts)
     }
@@ -931,6 +1486,34 @@ impl<T: Account> MultiContractCallHandler<T> {
         Ok(transaction_cost)
     }
 
+    /// Like [`Self::estimate_transaction_cost`], but computes the fee at `gas_price`, see
+    /// [`Provider::estimate_transaction_cost_at_gas_price`].
+    pub async fn estimate_transaction_cost_at_gas_price(
+        &self,
+        tolerance: Option<f64>,
+        gas_price: u64,
+    ) -> Result<TransactionCost> {
+        let script = self.build_tx().await?;
+
+        self.account
+            .try_provider()?
+            .estimate_transaction_cost_at_gas_price(script, tolerance, gas_price)
+            .await
+    }
+
+    /// Executes the call instruction by instruction with the debugger of the node, see
+    /// [`Provider::execution_trace`]. Useful to find out where and why a call reverts, the
+    /// registers are captured for the last `window` instructions.
+    #[cfg(feature = "raw-graphql")]
+    pub async fn execution_trace(&self, window: usize) -> Result<ExecutionTrace> {
+        let tx = self.build_tx().await?;
+
+        self.account
+            .try_provider()?
+            .execution_trace(tx, window)
+            .await
+    }
+
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response<D: Tokenizable + Debug>(
         &self,
@@ -0,0 +1,116 @@
+use fuel_tx::AssetId;
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::Tokenizable,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        Bits256, Bytes,
+    },
+};
+
+use crate::contract::method_by_name;
+
+/// Metadata key conventionally holding a description of the asset.
+pub const DESCRIPTION_KEY: &str = "description";
+/// Metadata key conventionally holding the URI of an image of the asset.
+pub const IMAGE_URI_KEY: &str = "image";
+
+/// The `Metadata` type of SRC-7.
+#[derive(Debug, Clone, PartialEq, Eq, fuels_macros::Parameterize, fuels_macros::Tokenizable)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+pub enum Metadata {
+    B256(Bits256),
+    Bytes(Bytes),
+    Int(u64),
+    String(String),
+}
+
+impl Metadata {
+    pub fn as_b256(&self) -> Option<&Bits256> {
+        match self {
+            Self::B256(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&Bytes> {
+        match self {
+            Self::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<u64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the SRC-7 metadata of the assets of a contract without generated bindings. The calls
+/// are simulated, so they don't spend the funds of the account.
+#[derive(Debug, Clone)]
+pub struct MetadataContract<A> {
+    contract_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> MetadataContract<A> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            account,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    /// Returns the metadata of `asset_id` stored under `key`, if any.
+    pub async fn metadata(&self, asset_id: AssetId, key: &str) -> Result<Option<Metadata>> {
+        let args = [asset_id.into_token(), key.to_string().into_token()];
+
+        let response = method_by_name(
+            self.contract_id.clone(),
+            self.account.clone(),
+            "metadata",
+            &args,
+        )
+        .simulate()
+        .await?;
+
+        Ok(response.value)
+    }
+
+    /// Returns the metadata stored under [`DESCRIPTION_KEY`].
+    pub async fn description(&self, asset_id: AssetId) -> Result<Option<String>> {
+        self.string_metadata(asset_id, DESCRIPTION_KEY).await
+    }
+
+    /// Returns the metadata stored under [`IMAGE_URI_KEY`].
+    pub async fn image_uri(&self, asset_id: AssetId) -> Result<Option<String>> {
+        self.string_metadata(asset_id, IMAGE_URI_KEY).await
+    }
+
+    async fn string_metadata(&self, asset_id: AssetId, key: &str) -> Result<Option<String>> {
+        match self.metadata(asset_id, key).await? {
+            None => Ok(None),
+            Some(Metadata::String(value)) => Ok(Some(value)),
+            Some(other) => Err(error!(
+                Codec,
+                "expected metadata `{key}` of asset `{asset_id}` to be a string, got: {other:?}"
+            )),
+        }
+    }
+}
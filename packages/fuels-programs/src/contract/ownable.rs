@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+
+use fuels_accounts::{Account, ViewOnlyAccount};
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::{Bech32Address, Bech32ContractId},
+        errors::{error, Result},
+        Identity, Token,
+    },
+};
+
+use crate::contract::{method_by_name, ContractCallHandler};
+
+/// The `State` type of SRC-5, describing who owns a contract.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, fuels_macros::Parameterize, fuels_macros::Tokenizable,
+)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+pub enum OwnershipState {
+    Uninitialized,
+    Initialized(Identity),
+    Revoked,
+}
+
+impl OwnershipState {
+    pub fn is_owned_by(&self, address: &Bech32Address) -> bool {
+        *self == Self::Initialized(Identity::Address(address.into()))
+    }
+
+    pub(crate) fn ensure_owned_by(
+        &self,
+        address: &Bech32Address,
+        contract_id: &Bech32ContractId,
+    ) -> Result<()> {
+        if !self.is_owned_by(address) {
+            return Err(error!(
+                Other,
+                "`{address}` is not the owner of contract `{contract_id}`, the owner is: {self:?}"
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Queries and transfers the ownership of any SRC-5 contract without generated bindings.
+///
+/// The transfer calls follow the `transfer_ownership` and `renounce_ownership` methods exposed
+/// by contracts built on the ownership library of sway-libs.
+#[derive(Debug, Clone)]
+pub struct Ownable<A> {
+    contract_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> Ownable<A> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            account,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    pub async fn owner(&self) -> Result<OwnershipState> {
+        Ok(self.call("owner", &[]).simulate().await?.value)
+    }
+
+    /// Returns whether the account of the handle owns the contract.
+    pub async fn is_owner(&self) -> Result<bool> {
+        Ok(self.owner().await?.is_owned_by(self.account.address()))
+    }
+
+    /// Returns a call transferring the ownership to `new_owner`. Fails if the account of the
+    /// handle is not the owner.
+    pub async fn transfer_ownership(
+        &self,
+        new_owner: Identity,
+    ) -> Result<ContractCallHandler<A, ()>> {
+        self.ensure_owner().await?;
+
+        Ok(self.call("transfer_ownership", &[new_owner.into_token()]))
+    }
+
+    /// Returns a call giving up the ownership for good. Fails if the account of the handle is
+    /// not the owner.
+    pub async fn renounce_ownership(&self) -> Result<ContractCallHandler<A, ()>> {
+        self.ensure_owner().await?;
+
+        Ok(self.call("renounce_ownership", &[]))
+    }
+
+    async fn ensure_owner(&self) -> Result<()> {
+        self.owner()
+            .await?
+            .ensure_owned_by(self.account.address(), &self.contract_id)
+    }
+
+    fn call<D: Tokenizable + Parameterize + Debug>(
+        &self,
+        method: &str,
+        args: &[Token],
+    ) -> ContractCallHandler<A, D> {
+        method_by_name(self.contract_id.clone(), self.account.clone(), method, args)
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt::Debug;
+
+use fuel_crypto::Hasher;
+use fuel_tx::{Bytes32, ContractId, StorageSlot};
+use fuels_accounts::{Account, ViewOnlyAccount};
+use fuels_core::{
+    codec::LogDecoder,
+    traits::{Parameterize, Tokenizable},
+    types::{bech32::Bech32ContractId, errors::Result, transaction::TxPolicies, Token},
+};
+
+use crate::contract::{
+    method_by_name, Contract, ContractCallHandler, OwnershipState, SettableContract,
+};
+
+/// Handle to an SRC-14 proxy deployed with [`Contract::deploy_as_proxy`].
+///
+/// Calls made through the proxy are forwarded to the implementation, which must be an input of
+/// the transaction. Bindings generated for the implementation ABI can be used with the id of the
+/// proxy and the handle passed to `with_contracts`:
+///
+/// ```ignore
+/// let instance = MyContract::new(proxy.contract_id().clone(), wallet);
+/// instance.methods().my_method().with_contracts(&[&proxy]).call().await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProxyContract<A> {
+    contract_id: Bech32ContractId,
+    implementation_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> ProxyContract<A> {
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    pub fn implementation_id(&self) -> &Bech32ContractId {
+        &self.implementation_id
+    }
+
+    pub fn account(&self) -> A {
+        self.account.clone()
+    }
+
+    pub fn with_account<U: Account>(self, account: U) -> ProxyContract<U> {
+        ProxyContract {
+            contract_id: self.contract_id,
+            implementation_id: self.implementation_id,
+            account,
+        }
+    }
+
+    /// Returns the owner of the proxy as stored on-chain.
+    pub async fn owner(&self) -> Result<OwnershipState> {
+        Ok(self.proxy_call("proxy_owner", &[]).simulate().await?.value)
+    }
+
+    /// Points the proxy at `new_implementation`. Fails without submitting a transaction if the
+    /// account of the handle is not the owner of the proxy.
+    pub async fn upgrade_to(&mut self, new_implementation: &Bech32ContractId) -> Result<()> {
+        self.owner()
+            .await?
+            .ensure_owned_by(self.account.address(), &self.contract_id)?;
+
+        self.proxy_call::<()>(
+            "set_proxy_target",
+            &[ContractId::from(new_implementation).into_token()],
+        )
+        .call()
+        .await?;
+
+        self.implementation_id = new_implementation.clone();
+
+        Ok(())
+    }
+
+    fn proxy_call<D: Tokenizable + Parameterize + Debug>(
+        &self,
+        method: &str,
+        args: &[Token],
+    ) -> ContractCallHandler<A, D> {
+        method_by_name(self.contract_id.clone(), self.account.clone(), method, args)
+    }
+}
+
+impl<A: Account> SettableContract for ProxyContract<A> {
+    fn id(&self) -> Bech32ContractId {
+        self.implementation_id.clone()
+    }
+
+    fn log_decoder(&self) -> LogDecoder {
+        LogDecoder::default()
+    }
+}
+
+impl Contract {
+    /// Deploys this contract as the implementation behind `proxy`, a compiled SRC-14 proxy
+    /// following the storage layout of the standard's reference owned proxy. The proxy is
+    /// deployed with its target set to this contract and `account` as its owner, so no
+    /// initialization call is needed.
+    pub async fn deploy_as_proxy<A: Account>(
+        self,
+        proxy: Contract,
+        account: A,
+        tx_policies: TxPolicies,
+    ) -> Result<ProxyContract<A>> {
+        let implementation_id = self.deploy(&account, tx_policies).await?;
+
+        let storage_slots = proxy
+            .storage_slots
+            .into_iter()
+            .filter(|slot| {
+                ![
+                    target_key(),
+                    next_key(&target_key()),
+                    owner_key(),
+                    next_key(&owner_key()),
+                ]
+                .contains(slot.key())
+            })
+            .chain(initial_proxy_slots(&implementation_id, &account))
+            .collect();
+
+        let contract_id = Contract::new(proxy.binary, proxy.salt, storage_slots)
+            .deploy(&account, tx_policies)
+            .await?;
+
+        Ok(ProxyContract {
+            contract_id,
+            implementation_id,
+            account,
+        })
+    }
+}
+
+/// `sha256("storage_SRC14_0")`, the slots holding the target of the proxy.
+fn target_key() -> Bytes32 {
+    Hasher::hash("storage_SRC14_0")
+}
+
+/// `sha256("storage_SRC14_1")`, the slots holding the owner of the proxy.
+fn owner_key() -> Bytes32 {
+    Hasher::hash("storage_SRC14_1")
+}
+
+fn next_key(key: &Bytes32) -> Bytes32 {
+    let mut next = **key;
+    for byte in next.iter_mut().rev() {
+        let (incremented, overflow) = byte.overflowing_add(1);
+        *byte = incremented;
+        if !overflow {
+            break;
+        }
+    }
+
+    next.into()
+}
+
+/// Storage slots pointing the proxy at `implementation_id` and making `account` its owner, as
+/// the reference proxy stores them: the target is an `Option<ContractId>` and the owner a
+/// `State`, both enums encoded as their discriminant word followed by their value. Values larger
+/// than a slot are stored in consecutive slots, padded with zeroes.
+fn initial_proxy_slots(
+    implementation_id: &Bech32ContractId,
+    account: &impl Account,
+) -> [StorageSlot; 4] {
+    const SOME: u64 = 1;
+    const INITIALIZED: u64 = 1;
+    const ADDRESS: u64 = 0;
+
+    let target = [
+        SOME.to_be_bytes().as_slice(),
+        implementation_id.hash().as_slice(),
+        &[0; 24],
+    ]
+    .concat();
+
+    let owner = [
+        INITIALIZED.to_be_bytes().as_slice(),
+        &ADDRESS.to_be_bytes(),
+        account.address().hash().as_slice(),
+        &[0; 16],
+    ]
+    .concat();
+
+    let [target, target_next] = value_slots(target_key(), &target);
+    let [owner, owner_next] = value_slots(owner_key(), &owner);
+
+    [target, target_next, owner, owner_next]
+}
+
+/// Splits a 64 bytes `value` over the slot at `key` and the next one.
+fn value_slots(key: Bytes32, value: &[u8]) -> [StorageSlot; 2] {
+    [
+        StorageSlot::new(
+            key,
+            Bytes32::try_from(&value[..32]).expect("slice has 32 bytes"),
+        ),
+        StorageSlot::new(
+            next_key(&key),
+            Bytes32::try_from(&value[32..]).expect("slice has 32 bytes"),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_key_carries_over() {
+        let mut key = [0xff; 32];
+        key[0] = 0;
+
+        let mut expected = [0; 32];
+        expected[0] = 1;
+
+        assert_eq!(next_key(&key.into()), Bytes32::from(expected));
+    }
+
+    #[test]
+    fn target_is_stored_as_an_option() {
+        let implementation_id = Bech32ContractId::new("fuel", [7; 32]);
+        let account = fuels_accounts::wallet::WalletUnlocked::new_random(None);
+
+        let [target, target_next, ..] = initial_proxy_slots(&implementation_id, &account);
+
+        let mut expected = [0; 64];
+        expected[7] = 1;
+        expected[8..40].copy_from_slice(&[7; 32]);
+
+        assert_eq!(*target.key(), target_key());
+        assert_eq!(target.value().as_slice(), &expected[..32]);
+        assert_eq!(*target_next.key(), next_key(&target_key()));
+        assert_eq!(target_next.value().as_slice(), &expected[32..]);
+    }
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use fuel_tx::{Address, AssetId, ContractId, Receipt};
+use fuels_core::types::{unresolved_bytes::UnresolvedBytes, Selector};
+
+use crate::contract::ContractCall;
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Caches the receipts of simulated contract calls, so that simulating the same call again at
+/// the same block height doesn't dry run it on the node. Meant for getters polled by dashboards.
+///
+/// Calls share an entry if they call the same method of the same contract with the same
+/// arguments and forwarded amount, from the same account, at the same block height. Entries are
+/// reused for at most `ttl`, which bounds how long state changed within a block, e.g. by a
+/// transaction in the pool of the node, can go unnoticed. Only successful simulations are
+/// cached. Clones share the cache.
+///
+/// Other settings of the call handler, e.g. custom instructions or the transaction policies,
+/// aren't part of the key, so don't share a cache between handlers whose results depend on them.
+#[derive(Debug, Clone)]
+pub struct ReadCallCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: Arc<Mutex<HashMap<ReadCallKey, CachedReceipts>>>,
+}
+
+impl ReadCallCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            capacity: DEFAULT_CAPACITY,
+            entries: Default::default(),
+        }
+    }
+
+    /// Sets how many simulations are kept, 1024 by default. The oldest one is evicted when the
+    /// cache is full.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns the number of cached simulations, including those older than `ttl`.
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        self.entries().clear();
+    }
+
+    pub(crate) fn get(&self, key: &ReadCallKey) -> Option<Vec<Receipt>> {
+        self.entries()
+            .get(key)
+            .filter(|cached| self.is_fresh(cached, Utc::now()))
+            .map(|cached| cached.receipts.clone())
+    }
+
+    pub(crate) fn insert(&self, key: ReadCallKey, receipts: Vec<Receipt>) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries();
+
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            let now = Utc::now();
+            entries.retain(|_, cached| self.is_fresh(cached, now));
+
+            if entries.len() >= self.capacity {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, cached)| cached.cached_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+
+        entries.insert(
+            key,
+            CachedReceipts {
+                receipts,
+                cached_at: Utc::now(),
+            },
+        );
+    }
+
+    fn is_fresh(&self, cached: &CachedReceipts, now: DateTime<Utc>) -> bool {
+        let age = (now - cached.cached_at).to_std().unwrap_or_default();
+
+        age <= self.ttl
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashMap<ReadCallKey, CachedReceipts>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ReadCallKey {
+    contract_id: ContractId,
+    selector: Selector,
+    args: UnresolvedBytes,
+    amount: u64,
+    asset_id: Option<AssetId>,
+    caller: Address,
+    block_height: u32,
+}
+
+impl ReadCallKey {
+    /// Returns `None` if the arguments of the call couldn't be encoded.
+    pub(crate) fn new(call: &ContractCall, caller: Address, block_height: u32) -> Option<Self> {
+        Some(Self {
+            contract_id: (&call.contract_id).into(),
+            selector: call.encoded_selector.clone(),
+            args: call.encoded_args.as_ref().ok()?.clone(),
+            amount: call.call_parameters.amount(),
+            asset_id: call.call_parameters.asset_id(),
+            caller,
+            block_height,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CachedReceipts {
+    receipts: Vec<Receipt>,
+    // `Instant` isn't available in browsers
+    cached_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(block_height: u32) -> ReadCallKey {
+        ReadCallKey {
+            contract_id: ContractId::zeroed(),
+            selector: vec![1],
+            args: UnresolvedBytes::default(),
+            amount: 0,
+            asset_id: None,
+            caller: Address::zeroed(),
+            block_height,
+        }
+    }
+
+    fn receipts(gas_used: u64) -> Vec<Receipt> {
+        vec![Receipt::script_result(
+            fuel_tx::ScriptExecutionResult::Success,
+            gas_used,
+        )]
+    }
+
+    #[test]
+    fn simulations_are_cached_per_block_height() {
+        let cache = ReadCallCache::new(Duration::from_secs(60));
+
+        cache.insert(key(1), receipts(10));
+
+        assert_eq!(cache.get(&key(1)), Some(receipts(10)));
+        assert!(cache.get(&key(2)).is_none());
+    }
+
+    #[test]
+    fn expired_simulations_are_ignored() {
+        let cache = ReadCallCache::new(Duration::ZERO);
+
+        cache.insert(key(1), receipts(10));
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert!(cache.get(&key(1)).is_none());
+    }
+
+    #[test]
+    fn oldest_simulation_is_evicted_when_full() {
+        let cache = ReadCallCache::new(Duration::from_secs(60)).with_capacity(2);
+
+        cache.insert(key(1), receipts(10));
+        cache.insert(key(2), receipts(20));
+        cache.insert(key(3), receipts(30));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key(1)).is_none());
+        assert!(cache.get(&key(3)).is_some());
+    }
+}
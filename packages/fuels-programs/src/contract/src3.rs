@@ -0,0 +1,98 @@
+use std::fmt::Debug;
+
+use fuel_tx::{AssetId, ContractId};
+use fuels_accounts::Account;
+use fuels_core::{
+    codec::{encode_fn_selector, EncoderConfig, LogDecoder},
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId, errors::Result, transaction_builders::VariableOutputPolicy,
+        Bits256, Identity, Token,
+    },
+};
+
+use crate::contract::{method_hash, CallParameters, ContractCallHandler};
+
+/// Mints and burns the assets of any SRC-3 contract without generated bindings.
+///
+/// The calls are prepared with what the node needs to execute them: minting to an address adds
+/// the variable output receiving the coins, minting to another contract adds that contract as
+/// an input, and burning forwards the coins to burn.
+#[derive(Debug, Clone)]
+pub struct Src3Contract<A> {
+    contract_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> Src3Contract<A> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            account,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    /// Returns the id of the asset the contract mints for `sub_id`.
+    pub fn asset_id(&self, sub_id: Bits256) -> AssetId {
+        self.contract_id.asset_id(&sub_id)
+    }
+
+    /// Returns a call minting `amount` of the asset of `sub_id` to `recipient`.
+    pub fn mint(
+        &self,
+        recipient: Identity,
+        sub_id: Bits256,
+        amount: u64,
+    ) -> ContractCallHandler<A, ()> {
+        let args = [
+            recipient.into_token(),
+            sub_id.into_token(),
+            amount.into_token(),
+        ];
+        let call = self.call("mint", &args, false);
+
+        match recipient {
+            Identity::Address(_) => {
+                call.with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            }
+            Identity::ContractId(contract_id)
+                if contract_id != ContractId::from(&self.contract_id) =>
+            {
+                call.with_contract_ids(&[contract_id.into()])
+            }
+            Identity::ContractId(_) => call,
+        }
+    }
+
+    /// Returns a call burning `amount` of the asset of `sub_id`. The coins are taken from the
+    /// account of the handle.
+    pub fn burn(&self, sub_id: Bits256, amount: u64) -> Result<ContractCallHandler<A, ()>> {
+        let args = [sub_id.into_token(), amount.into_token()];
+        let call_parameters = CallParameters::default()
+            .with_amount(amount)
+            .with_asset_id(self.asset_id(sub_id));
+
+        self.call("burn", &args, true).call_params(call_parameters)
+    }
+
+    fn call<D: Tokenizable + Parameterize + Debug>(
+        &self,
+        method: &str,
+        args: &[Token],
+        is_payable: bool,
+    ) -> ContractCallHandler<A, D> {
+        method_hash(
+            self.contract_id.clone(),
+            self.account.clone(),
+            encode_fn_selector(method),
+            args,
+            LogDecoder::default(),
+            is_payable,
+            EncoderConfig::default(),
+        )
+    }
+}
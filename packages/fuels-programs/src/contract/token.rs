@@ -0,0 +1,102 @@
+use std::fmt::Debug;
+
+use fuel_tx::AssetId;
+use fuels_accounts::Account;
+use fuels_core::{
+    traits::{Parameterize, Tokenizable},
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+    },
+};
+
+use crate::contract::method_by_name;
+
+pub use fuels_core::types::units::{format_units, parse_units};
+
+/// Reads the metadata of the assets of an SRC-20 token contract without generated bindings.
+/// The calls are simulated, so they don't spend the funds of the account.
+#[derive(Debug, Clone)]
+pub struct TokenContract<A> {
+    contract_id: Bech32ContractId,
+    account: A,
+}
+
+impl<A: Account> TokenContract<A> {
+    pub fn new(contract_id: impl Into<Bech32ContractId>, account: A) -> Self {
+        Self {
+            contract_id: contract_id.into(),
+            account,
+        }
+    }
+
+    pub fn contract_id(&self) -> &Bech32ContractId {
+        &self.contract_id
+    }
+
+    pub async fn total_assets(&self) -> Result<u64> {
+        self.read("total_assets", None).await
+    }
+
+    /// Returns `None` if `asset_id` is not minted by the contract.
+    pub async fn total_supply(&self, asset_id: AssetId) -> Result<Option<u64>> {
+        self.read("total_supply", Some(asset_id)).await
+    }
+
+    pub async fn name(&self, asset_id: AssetId) -> Result<Option<String>> {
+        self.read("name", Some(asset_id)).await
+    }
+
+    pub async fn symbol(&self, asset_id: AssetId) -> Result<Option<String>> {
+        self.read("symbol", Some(asset_id)).await
+    }
+
+    pub async fn decimals(&self, asset_id: AssetId) -> Result<Option<u8>> {
+        self.read("decimals", Some(asset_id)).await
+    }
+
+    /// Formats a base unit `amount` of `asset_id` using the decimals reported by the contract,
+    /// see [`format_units`].
+    pub async fn format_amount(&self, asset_id: AssetId, amount: u64) -> Result<String> {
+        let decimals = self.expect_decimals(asset_id).await?;
+
+        Ok(format_units(amount, decimals))
+    }
+
+    /// Parses a decimal `amount` of `asset_id` into base units using the decimals reported by
+    /// the contract, see [`parse_units`].
+    pub async fn parse_amount(&self, asset_id: AssetId, amount: &str) -> Result<u64> {
+        let decimals = self.expect_decimals(asset_id).await?;
+
+        u64::try_from(parse_units(amount, decimals)?)
+            .map_err(|_| error!(Other, "`{amount}` does not fit into a `u64`"))
+    }
+
+    async fn expect_decimals(&self, asset_id: AssetId) -> Result<u8> {
+        self.decimals(asset_id).await?.ok_or_else(|| {
+            error!(
+                Other,
+                "contract `{}` reports no decimals for asset `{asset_id}`", self.contract_id
+            )
+        })
+    }
+
+    async fn read<D: Tokenizable + Parameterize + Debug>(
+        &self,
+        method: &str,
+        asset_id: Option<AssetId>,
+    ) -> Result<D> {
+        let args: Vec<_> = asset_id.into_iter().map(Tokenizable::into_token).collect();
+
+        let response = method_by_name(
+            self.contract_id.clone(),
+            self.account.clone(),
+            method,
+            &args,
+        )
+        .simulate()
+        .await?;
+
+        Ok(response.value)
+    }
+}
@@ -0,0 +1,264 @@
+//! Typed interface to the escrow predicate maintained in `packages/fuels/predicates/escrow`.
+
+use std::path::Path;
+
+use fuel_tx::Output;
+use fuels_accounts::{predicate::Predicate, provider::Provider, Account, ViewOnlyAccount};
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    traits::{Signer, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        transaction::TxPolicies,
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+        Address, AssetId,
+    },
+    Configurables,
+};
+
+use crate::templates::{self, load_code_and_abi};
+
+/// The parties and conditions of an escrow. Each set of terms results in a different predicate
+/// address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscrowTerms {
+    pub buyer: Bech32Address,
+    pub seller: Bech32Address,
+    pub arbiter: Bech32Address,
+    /// The escrowed asset. Other assets sent to the escrow are not protected by the predicate.
+    pub asset_id: AssetId,
+    /// Block height from which anyone can refund the buyer.
+    pub deadline: u32,
+}
+
+/// The `Action` passed to the escrow predicate, selecting the spend path.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, fuels_macros::Parameterize, fuels_macros::Tokenizable,
+)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+enum Action {
+    Release,
+    Refund,
+    Arbitrate(Address),
+}
+
+/// Escrow predicate configured with [`EscrowTerms`]. Funds are escrowed by transferring
+/// `asset_id` to [`Escrow::address`], and spent with the transaction builders returned by
+/// [`Escrow::release`], [`Escrow::refund`], [`Escrow::refund_after_deadline`] and
+/// [`Escrow::arbitrate`].
+///
+/// The builders spend the whole escrowed balance and send it, as change, to the recipient.
+/// If the escrowed asset is the base asset the fee is deducted from it, otherwise an account
+/// has to cover the fee with `adjust_for_fee` and add its witness after the builder is returned.
+#[derive(Debug, Clone)]
+pub struct Escrow {
+    predicate: Predicate,
+    terms: EscrowTerms,
+}
+
+impl Escrow {
+    /// Loads the compiled escrow predicate from `binary_filepath` and configures it with
+    /// `terms`. The ABI is expected next to the binary, as output by `forc build`.
+    pub fn load_from(binary_filepath: impl AsRef<Path>, terms: EscrowTerms) -> Result<Self> {
+        let (code, abi) = load_code_and_abi(binary_filepath.as_ref())?;
+
+        Self::from_code_and_abi(code, &abi, terms)
+    }
+
+    /// Configures the compiled escrow predicate `code`, whose JSON ABI is `abi`, with `terms`.
+    pub fn from_code_and_abi(code: Vec<u8>, abi: &str, terms: EscrowTerms) -> Result<Self> {
+        let configurables = configurables(abi, &terms)?;
+        let predicate = Predicate::from_code(code).with_configurables(configurables);
+
+        Ok(Self { predicate, terms })
+    }
+
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.predicate.set_provider(provider);
+        self
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    pub fn terms(&self) -> &EscrowTerms {
+        &self.terms
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    /// Pays the escrowed funds to the seller, approved by `buyer`.
+    pub async fn release(
+        &self,
+        buyer: impl Signer + Send + Sync,
+    ) -> Result<ScriptTransactionBuilder> {
+        ensure_party(&buyer, &self.terms.buyer, "buyer")?;
+
+        let mut tb = self.spend(Action::Release, &self.terms.seller).await?;
+        tb.add_signer(buyer)?;
+
+        Ok(tb)
+    }
+
+    /// Pays the escrowed funds back to the buyer, approved by `seller`.
+    pub async fn refund(
+        &self,
+        seller: impl Signer + Send + Sync,
+    ) -> Result<ScriptTransactionBuilder> {
+        ensure_party(&seller, &self.terms.seller, "seller")?;
+
+        let mut tb = self.spend(Action::Refund, &self.terms.buyer).await?;
+        tb.add_signer(seller)?;
+
+        Ok(tb)
+    }
+
+    /// Pays the escrowed funds back to the buyer without any signature. The transaction matures
+    /// at the deadline, so it is rejected by the node until the deadline is reached.
+    ///
+    /// Without a signature, the predicate only accepts a transaction with an empty script and
+    /// neither contract inputs nor variable outputs, so the script must be left as is.
+    pub async fn refund_after_deadline(&self) -> Result<ScriptTransactionBuilder> {
+        let tb = self.spend(Action::Refund, &self.terms.buyer).await?;
+        let tx_policies = tb.tx_policies.with_maturity(self.terms.deadline.into());
+
+        Ok(tb.with_tx_policies(tx_policies))
+    }
+
+    /// Pays the escrowed funds to `recipient`, either the buyer or the seller, as decided by
+    /// `arbiter`.
+    pub async fn arbitrate(
+        &self,
+        arbiter: impl Signer + Send + Sync,
+        recipient: &Bech32Address,
+    ) -> Result<ScriptTransactionBuilder> {
+        ensure_party(&arbiter, &self.terms.arbiter, "arbiter")?;
+        if *recipient != self.terms.buyer && *recipient != self.terms.seller {
+            return Err(error!(
+                Other,
+                "arbitrated escrow funds can only go to the buyer or the seller, not `{recipient}`"
+            ));
+        }
+
+        let mut tb = self
+            .spend(Action::Arbitrate(recipient.into()), recipient)
+            .await?;
+        tb.add_signer(arbiter)?;
+
+        Ok(tb)
+    }
+
+    /// Prepares a transaction spending the whole escrowed balance to `recipient`. The signer of
+    /// the spend path, if any, must be the first signer added so that its witness is at index 0.
+    async fn spend(
+        &self,
+        action: Action,
+        recipient: &Bech32Address,
+    ) -> Result<ScriptTransactionBuilder> {
+        let witness_index = 0u64;
+        let data = ABIEncoder::new(EncoderConfig::default())
+            .encode(&[action.into_token(), witness_index.into_token()])?;
+        let predicate = self.predicate.clone().with_data(data);
+
+        let asset_id = self.terms.asset_id;
+        let balance = predicate.get_asset_balance(&asset_id).await?;
+        if balance == 0 {
+            return Err(error!(
+                Other,
+                "the escrow at `{}` holds no funds",
+                self.address()
+            ));
+        }
+
+        let inputs = predicate
+            .get_asset_inputs_for_amount(asset_id, balance)
+            .await?;
+        let outputs = vec![Output::change(recipient.into(), 0, asset_id)];
+
+        Ok(ScriptTransactionBuilder::prepare_transfer(
+            inputs,
+            outputs,
+            TxPolicies::default(),
+        ))
+    }
+}
+
+fn ensure_party(signer: &impl Signer, expected: &Bech32Address, party: &str) -> Result<()> {
+    if signer.address() != expected {
+        return Err(error!(
+            Other,
+            "expected the signer to be the {party} `{expected}`, got `{}`",
+            signer.address()
+        ));
+    }
+
+    Ok(())
+}
+
+fn configurables(abi: &str, terms: &EscrowTerms) -> Result<Configurables> {
+    templates::configurables(
+        abi,
+        [
+            ("BUYER", Address::from(&terms.buyer).into_token()),
+            ("SELLER", Address::from(&terms.seller).into_token()),
+            ("ARBITER", Address::from(&terms.arbiter).into_token()),
+            ("ASSET_ID", terms.asset_id.into_token()),
+            ("DEADLINE", terms.deadline.into_token()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+
+    use super::*;
+
+    #[test]
+    fn missing_configurables_are_reported() {
+        let abi = r#"
+            {
+              "types": [
+                {
+                  "typeId": 0,
+                  "type": "u32",
+                  "components": null,
+                  "typeParameters": null
+                }
+              ],
+              "functions": [],
+              "loggedTypes": [],
+              "messagesTypes": [],
+              "configurables": [
+                {
+                  "name": "DEADLINE",
+                  "configurableType": {
+                    "name": "",
+                    "type": 0,
+                    "typeArguments": null
+                  },
+                  "offset": 100
+                }
+              ]
+            }"#;
+        let party = |byte| Bech32Address::new(FUEL_BECH32_HRP, [byte; 32]);
+        let terms = EscrowTerms {
+            buyer: party(1),
+            seller: party(2),
+            arbiter: party(3),
+            asset_id: AssetId::zeroed(),
+            deadline: 10,
+        };
+
+        let err = Escrow::from_code_and_abi(vec![], abi, terms).expect_err("should fail");
+
+        assert!(err
+            .to_string()
+            .contains("the ABI has no configurable named `BUYER`"));
+    }
+}
@@ -1,6 +1,14 @@
+pub mod accounts_config;
 pub mod call_response;
+pub mod call_script;
 pub mod call_utils;
 pub mod contract;
+pub mod escrow;
+pub mod receipt_decoder;
 pub mod receipt_parser;
+pub mod receipt_sink;
+pub mod recovery;
 pub mod script_calls;
 mod submit_response;
+mod templates;
+pub mod timelock;
@@ -0,0 +1,453 @@
+//! Decoding of contract calls and logs from the receipts of a transaction, using a JSON ABI
+//! instead of generated bindings.
+
+use std::collections::HashMap;
+
+use fuel_abi_types::abi::program::{ProgramABI, TypeApplication};
+use fuel_tx::{ContractId, Receipt, TxId};
+use fuels_accounts::provider::Provider;
+use fuels_core::{
+    codec::{ABIDecoder, DecoderConfig},
+    constants::WORD_SIZE,
+    types::{
+        bech32::Bech32ContractId,
+        errors::{error, Result},
+        param_types::{NamedParamType, ParamType},
+        transaction::TransactionType,
+        AssetId, DynamicValue,
+    },
+};
+
+/// A top-level contract call made by a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCall {
+    pub contract_id: Bech32ContractId,
+    pub function: String,
+    pub arguments: Vec<(String, DynamicValue)>,
+    /// `None` if the transaction reverted before the call returned.
+    pub return_value: Option<DynamicValue>,
+}
+
+/// A value logged by one of the called contracts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog {
+    pub contract_id: Bech32ContractId,
+    pub log_id: u64,
+    pub value: DynamicValue,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DecodedReceipts {
+    pub calls: Vec<DecodedCall>,
+    pub logs: Vec<DecodedLog>,
+}
+
+#[derive(Debug, Clone)]
+struct FunctionTypes {
+    inputs: Vec<NamedParamType>,
+    output: ParamType,
+}
+
+/// Decodes the calls made to a contract, and the values it logged, from the receipts of script
+/// transactions built by the SDK.
+///
+/// The called functions are identified by the selectors in the script data. Calls to functions
+/// missing from the ABI, e.g. calls to other contracts in a multi call, are skipped, as are the
+/// logs of the contracts they call.
+#[derive(Debug, Clone)]
+pub struct ReceiptDecoder {
+    functions: HashMap<String, FunctionTypes>,
+    logged_types: HashMap<u64, ParamType>,
+    decoder: ABIDecoder,
+}
+
+impl ReceiptDecoder {
+    pub fn new(json_abi: &str) -> Result<Self> {
+        let abi: ProgramABI = serde_json::from_str(json_abi)
+            .map_err(|err| error!(Other, "could not parse the ABI: {err}"))?;
+        let type_lookup: HashMap<_, _> = abi
+            .types
+            .into_iter()
+            .map(|declaration| (declaration.type_id, declaration))
+            .collect();
+        let param_type = |type_application: &TypeApplication| {
+            ParamType::try_from_type_application(type_application, &type_lookup)
+        };
+
+        let functions = abi
+            .functions
+            .iter()
+            .map(|function| {
+                let inputs = function
+                    .inputs
+                    .iter()
+                    .map(|input| Ok((input.name.clone(), param_type(input)?)))
+                    .collect::<Result<_>>()?;
+                let output = param_type(&function.output)?;
+
+                Ok((function.name.clone(), FunctionTypes { inputs, output }))
+            })
+            .collect::<Result<_>>()?;
+
+        let logged_types = abi
+            .logged_types
+            .unwrap_or_default()
+            .iter()
+            .map(|logged_type| Ok((logged_type.log_id, param_type(&logged_type.application)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            functions,
+            logged_types,
+            decoder: ABIDecoder::default(),
+        })
+    }
+
+    pub fn with_decoder_config(mut self, decoder_config: DecoderConfig) -> Self {
+        self.decoder = ABIDecoder::new(decoder_config);
+        self
+    }
+
+    /// Fetches the transaction `tx_id` and its receipts from `provider` and decodes them.
+    pub async fn decode_transaction(
+        &self,
+        provider: &Provider,
+        tx_id: &TxId,
+    ) -> Result<DecodedReceipts> {
+        let response = provider
+            .get_transaction_by_id(tx_id)
+            .await?
+            .ok_or_else(|| error!(Other, "transaction `{tx_id}` not found"))?;
+
+        let TransactionType::Script(tx) = response.transaction else {
+            return Err(error!(
+                Other,
+                "transaction `{tx_id}` is not a script transaction"
+            ));
+        };
+
+        self.decode(tx.script_data(), &response.status.take_receipts())
+    }
+
+    /// Decodes the top-level calls made by a script transaction with `script_data`, and the
+    /// logs of the called contracts, from its `receipts`.
+    pub fn decode(&self, script_data: &[u8], receipts: &[Receipt]) -> Result<DecodedReceipts> {
+        let Some(data_offset) = script_data_offset(script_data) else {
+            return Ok(DecodedReceipts::default());
+        };
+
+        let mut calls = vec![];
+        let mut pending_call = None;
+        let mut depth = 0usize;
+
+        for receipt in receipts {
+            match receipt {
+                Receipt::Call {
+                    id,
+                    to,
+                    param1,
+                    param2,
+                    ..
+                } if *id == ContractId::zeroed() => {
+                    calls.extend(pending_call.take());
+                    pending_call =
+                        self.decode_call(script_data, data_offset, *to, *param1, *param2)?;
+                    depth = 1;
+                }
+                Receipt::Call { .. } => depth += 1,
+                Receipt::Return { id, .. } | Receipt::ReturnData { id, .. }
+                    if *id != ContractId::zeroed() =>
+                {
+                    depth = depth.saturating_sub(1);
+                    if depth > 0 {
+                        continue;
+                    }
+
+                    if let Some(mut call) = pending_call.take() {
+                        let data = match receipt {
+                            Receipt::ReturnData { data, .. } => data.as_deref().unwrap_or_default(),
+                            _ => Default::default(),
+                        };
+                        call.return_value =
+                            Some(self.decode_value(&self.functions[&call.function].output, data)?);
+                        calls.push(call);
+                    }
+                }
+                _ => {}
+            }
+        }
+        calls.extend(pending_call);
+
+        let logs = receipts
+            .iter()
+            .filter_map(|receipt| match receipt {
+                Receipt::LogData { id, rb, data, .. } => Some((id, *rb, data)),
+                _ => None,
+            })
+            .filter(|(id, _, _)| {
+                calls
+                    .iter()
+                    .any(|call| ContractId::from(&call.contract_id) == **id)
+            })
+            .filter_map(|(id, log_id, data)| {
+                let param_type = self.logged_types.get(&log_id)?;
+                let value = data
+                    .as_deref()
+                    .ok_or_else(|| error!(Codec, "log `{log_id}` of contract `{id}` has no data"))
+                    .and_then(|data| self.decode_value(param_type, data));
+
+                Some(value.map(|value| DecodedLog {
+                    contract_id: (*id).into(),
+                    log_id,
+                    value,
+                }))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(DecodedReceipts { calls, logs })
+    }
+
+    /// `selector_offset` and `args_offset` are the absolute offsets of the encoded selector and
+    /// arguments of the call, as passed to the `CALL` instruction.
+    fn decode_call(
+        &self,
+        script_data: &[u8],
+        data_offset: u64,
+        contract_id: ContractId,
+        selector_offset: u64,
+        args_offset: u64,
+    ) -> Result<Option<DecodedCall>> {
+        let position = |offset: u64| {
+            offset
+                .checked_sub(data_offset)
+                .and_then(|position| usize::try_from(position).ok())
+                .filter(|position| *position <= script_data.len())
+                .ok_or_else(|| {
+                    error!(
+                        Codec,
+                        "call to contract `{contract_id}` points outside of the script data"
+                    )
+                })
+        };
+
+        let function = read_selector(script_data, position(selector_offset)?)?;
+        let Some(function_types) = self.functions.get(&function) else {
+            return Ok(None);
+        };
+
+        let (names, param_types): (Vec<_>, Vec<_>) = function_types.inputs.iter().cloned().unzip();
+        let tokens = self
+            .decoder
+            .decode_multiple(&param_types, &script_data[position(args_offset)?..])?;
+        let arguments = names
+            .into_iter()
+            .zip(tokens.into_iter().zip(&param_types))
+            .map(|(name, (token, param_type))| {
+                Ok((name, DynamicValue::from_token(token, param_type)?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Some(DecodedCall {
+            contract_id: contract_id.into(),
+            function,
+            arguments,
+            return_value: None,
+        }))
+    }
+
+    fn decode_value(&self, param_type: &ParamType, bytes: &[u8]) -> Result<DynamicValue> {
+        let token = self.decoder.decode(param_type, bytes)?;
+
+        DynamicValue::from_token(token, param_type)
+    }
+}
+
+/// The script data of contract calls starts with the segment of the first call. The absolute
+/// offset of its selector follows the amount, asset id and contract id of the call, and points
+/// right after the selector and arguments offsets.
+fn script_data_offset(script_data: &[u8]) -> Option<u64> {
+    const SELECTOR_OFFSET_POSITION: usize = WORD_SIZE + AssetId::LEN + ContractId::LEN;
+
+    let selector_offset = read_word(script_data, SELECTOR_OFFSET_POSITION)?;
+
+    selector_offset.checked_sub((SELECTOR_OFFSET_POSITION + 2 * WORD_SIZE) as u64)
+}
+
+fn read_word(bytes: &[u8], position: usize) -> Option<u64> {
+    let word = bytes.get(position..position.checked_add(WORD_SIZE)?)?;
+
+    Some(u64::from_be_bytes(word.try_into().ok()?))
+}
+
+/// Reads a selector encoded by `encode_fn_selector`: the length of the name followed by the name.
+fn read_selector(script_data: &[u8], position: usize) -> Result<String> {
+    let invalid_selector = || error!(Codec, "invalid function selector at {position}");
+
+    let len = read_word(script_data, position)
+        .and_then(|len| usize::try_from(len).ok())
+        .ok_or_else(invalid_selector)?;
+    let start = position + WORD_SIZE;
+    let name = start
+        .checked_add(len)
+        .and_then(|end| script_data.get(start..end))
+        .ok_or_else(invalid_selector)?;
+
+    String::from_utf8(name.to_vec()).map_err(|_| invalid_selector())
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::codec::encode_fn_selector;
+
+    use super::*;
+
+    const ABI: &str = r#"
+        {
+          "types": [
+            { "typeId": 0, "type": "u64", "components": null, "typeParameters": null },
+            { "typeId": 1, "type": "bool", "components": null, "typeParameters": null }
+          ],
+          "functions": [
+            {
+              "inputs": [{ "name": "amount", "type": 0, "typeArguments": null }],
+              "name": "deposit",
+              "output": { "name": "", "type": 1, "typeArguments": null }
+            }
+          ],
+          "loggedTypes": [
+            { "logId": 7, "loggedType": { "name": "", "type": 0, "typeArguments": null } }
+          ],
+          "messagesTypes": [],
+          "configurables": []
+        }"#;
+
+    const DATA_OFFSET: u64 = 10_000;
+
+    fn contract_id() -> ContractId {
+        ContractId::from([1; 32])
+    }
+
+    /// Script data with a single call, laid out as done by `build_script_data_from_contract_calls`.
+    fn script_data(function: &str, args: &[u8]) -> (Vec<u8>, u64, u64) {
+        let selector = encode_fn_selector(function);
+        let selector_offset = DATA_OFFSET + 88;
+        let args_offset = selector_offset + selector.len() as u64;
+
+        let script_data = [
+            0u64.to_be_bytes().as_slice(),
+            &[0; 32],
+            contract_id().as_slice(),
+            &selector_offset.to_be_bytes(),
+            &args_offset.to_be_bytes(),
+            &selector,
+            args,
+        ]
+        .concat();
+
+        (script_data, selector_offset, args_offset)
+    }
+
+    fn call(param1: u64, param2: u64) -> Receipt {
+        Receipt::Call {
+            id: ContractId::zeroed(),
+            to: contract_id(),
+            amount: Default::default(),
+            asset_id: Default::default(),
+            gas: Default::default(),
+            param1,
+            param2,
+            pc: Default::default(),
+            is: Default::default(),
+        }
+    }
+
+    fn return_data(data: &[u8]) -> Receipt {
+        Receipt::ReturnData {
+            id: contract_id(),
+            ptr: Default::default(),
+            len: Default::default(),
+            digest: Default::default(),
+            data: Some(data.to_vec()),
+            pc: Default::default(),
+            is: Default::default(),
+        }
+    }
+
+    fn log_data(log_id: u64, data: &[u8]) -> Receipt {
+        Receipt::LogData {
+            id: contract_id(),
+            ra: Default::default(),
+            rb: log_id,
+            ptr: Default::default(),
+            len: Default::default(),
+            digest: Default::default(),
+            data: Some(data.to_vec()),
+            pc: Default::default(),
+            is: Default::default(),
+        }
+    }
+
+    #[test]
+    fn calls_and_logs_are_decoded() -> Result<()> {
+        let (script_data, selector_offset, args_offset) =
+            script_data("deposit", &42u64.to_be_bytes());
+        let receipts = [
+            call(selector_offset, args_offset),
+            log_data(7, &5u64.to_be_bytes()),
+            log_data(8, &[1]),
+            return_data(&[1]),
+        ];
+
+        let decoded = ReceiptDecoder::new(ABI)?.decode(&script_data, &receipts)?;
+
+        assert_eq!(
+            decoded.calls,
+            [DecodedCall {
+                contract_id: contract_id().into(),
+                function: "deposit".to_string(),
+                arguments: vec![("amount".to_string(), DynamicValue::U64(42))],
+                return_value: Some(DynamicValue::Bool(true)),
+            }]
+        );
+        assert_eq!(
+            decoded.logs,
+            [DecodedLog {
+                contract_id: contract_id().into(),
+                log_id: 7,
+                value: DynamicValue::U64(5),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reverted_calls_have_no_return_value() -> Result<()> {
+        let (script_data, selector_offset, args_offset) =
+            script_data("deposit", &42u64.to_be_bytes());
+        let receipts = [call(selector_offset, args_offset)];
+
+        let decoded = ReceiptDecoder::new(ABI)?.decode(&script_data, &receipts)?;
+
+        assert_eq!(decoded.calls.len(), 1);
+        assert_eq!(decoded.calls[0].return_value, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn calls_to_unknown_functions_are_skipped() -> Result<()> {
+        let (script_data, selector_offset, args_offset) = script_data("withdraw", &[]);
+        let receipts = [
+            call(selector_offset, args_offset),
+            log_data(7, &5u64.to_be_bytes()),
+            return_data(&[]),
+        ];
+
+        let decoded = ReceiptDecoder::new(ABI)?.decode(&script_data, &receipts)?;
+
+        assert_eq!(decoded, DecodedReceipts::default());
+
+        Ok(())
+    }
+}
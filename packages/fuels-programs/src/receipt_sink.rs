@@ -0,0 +1,252 @@
+//! Opt-in capture of the receipts of every transaction sent by the call handlers, e.g. to analyse
+//! gas regressions or debug flaky failures of a test run.
+
+use std::{
+    fmt::Write,
+    fs,
+    path::Path,
+    sync::{Arc, Mutex, MutexGuard, RwLock, RwLockWriteGuard},
+};
+
+use fuel_tx::{Receipt, TxId};
+use fuels_core::{
+    codec::LogDecoder,
+    types::{errors::Result, tx_status::TxStatus},
+};
+use serde::Serialize;
+
+static GLOBAL_SINK: RwLock<Option<ReceiptSink>> = RwLock::new(None);
+
+/// A transaction captured by a [`ReceiptSink`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CapturedTransaction {
+    pub tx_id: Option<TxId>,
+    /// Whether the transaction was dry-run instead of committed.
+    pub simulated: bool,
+    /// One of `success`, `revert`, `squeezed_out` or `submitted`.
+    pub status: String,
+    pub reason: Option<String>,
+    /// Gas used by the script, 0 if the receipts have no `ScriptResult`.
+    pub gas_used: u64,
+    /// Logs the call handler could decode.
+    pub logs: Vec<String>,
+    pub receipts: Vec<Receipt>,
+}
+
+/// Collects [`CapturedTransaction`]s. Clones share the captured transactions.
+///
+/// Nothing is captured until a sink is installed with [`ReceiptSink::install`], after which
+/// every call, simulation and submit response of contract and script call handlers is recorded,
+/// including the failing ones.
+#[derive(Debug, Clone, Default)]
+pub struct ReceiptSink {
+    captured: Arc<Mutex<Vec<CapturedTransaction>>>,
+}
+
+impl ReceiptSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes this sink the global one, replacing the sink installed before, if any.
+    pub fn install(&self) -> Option<ReceiptSink> {
+        global_sink().replace(self.clone())
+    }
+
+    /// Stops capturing and returns the sink that was installed, if any.
+    pub fn uninstall() -> Option<ReceiptSink> {
+        global_sink().take()
+    }
+
+    pub fn captured(&self) -> Vec<CapturedTransaction> {
+        self.lock().clone()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear()
+    }
+
+    pub fn record(
+        &self,
+        tx_id: Option<TxId>,
+        simulated: bool,
+        tx_status: &TxStatus,
+        log_decoder: &LogDecoder,
+    ) {
+        let (status, reason, receipts) = match tx_status {
+            TxStatus::Success { receipts } => ("success", None, receipts.as_slice()),
+            TxStatus::Revert {
+                receipts, reason, ..
+            } => ("revert", Some(reason.clone()), receipts.as_slice()),
+            TxStatus::SqueezedOut { reason } => {
+                ("squeezed_out", Some(reason.clone()), [].as_slice())
+            }
+            TxStatus::Submitted => ("submitted", None, [].as_slice()),
+        };
+
+        let gas_used = receipts
+            .iter()
+            .rfind(|r| matches!(r, Receipt::ScriptResult { .. }))
+            .and_then(Receipt::gas_used)
+            .unwrap_or_default();
+        let logs = log_decoder
+            .decode_logs(receipts)
+            .filter_succeeded()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        self.lock().push(CapturedTransaction {
+            tx_id,
+            simulated,
+            status: status.to_string(),
+            reason,
+            gas_used,
+            logs,
+            receipts: receipts.to_vec(),
+        });
+    }
+
+    /// Serializes the captured transactions, with their receipts, as a JSON array.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&*self.lock())?)
+    }
+
+    /// Formats the captured transactions as CSV, one row per transaction. Receipts are only
+    /// counted and logs are separated by `;`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("tx_id,simulated,status,reason,gas_used,receipts,logs\n");
+
+        for captured in self.lock().iter() {
+            let tx_id = captured
+                .tx_id
+                .map(|tx_id| tx_id.to_string())
+                .unwrap_or_default();
+            let reason = captured.reason.as_deref().unwrap_or_default();
+
+            writeln!(
+                csv,
+                "{tx_id},{},{},{},{},{},{}",
+                captured.simulated,
+                captured.status,
+                csv_field(reason),
+                captured.gas_used,
+                captured.receipts.len(),
+                csv_field(&captured.logs.join(";"))
+            )
+            .expect("writing to a string cannot fail");
+        }
+
+        csv
+    }
+
+    pub fn export_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, self.to_json()?)?)
+    }
+
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(fs::write(path, self.to_csv())?)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<CapturedTransaction>> {
+        self.captured.lock().expect("not poisoned")
+    }
+}
+
+fn global_sink() -> RwLockWriteGuard<'static, Option<ReceiptSink>> {
+    GLOBAL_SINK.write().expect("not poisoned")
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Records the transaction in the global sink, if one is installed.
+pub(crate) fn capture(
+    tx_id: Option<TxId>,
+    simulated: bool,
+    tx_status: &TxStatus,
+    log_decoder: &LogDecoder,
+) {
+    if let Some(sink) = GLOBAL_SINK.read().expect("not poisoned").as_ref() {
+        sink.record(tx_id, simulated, tx_status, log_decoder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fuel_tx::ScriptExecutionResult;
+
+    use super::*;
+
+    fn script_result(gas_used: u64) -> Receipt {
+        Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        }
+    }
+
+    #[test]
+    fn captured_transactions_are_exported() -> Result<()> {
+        let sink = ReceiptSink::new();
+        let log_decoder = LogDecoder::default();
+
+        sink.record(
+            Some(TxId::zeroed()),
+            false,
+            &TxStatus::Success {
+                receipts: vec![script_result(42)],
+            },
+            &log_decoder,
+        );
+        sink.record(
+            None,
+            true,
+            &TxStatus::Revert {
+                receipts: vec![script_result(7)],
+                reason: "failed, \"badly\"".to_string(),
+                revert_id: 1,
+            },
+            &log_decoder,
+        );
+
+        let captured = sink.captured();
+        assert_eq!(captured.len(), 2);
+        assert_eq!(captured[0].gas_used, 42);
+        assert_eq!(captured[1].status, "revert");
+
+        let json: serde_json::Value = serde_json::from_str(&sink.to_json()?)?;
+        assert_eq!(json[0]["gas_used"], 42);
+        assert_eq!(json[1]["simulated"], true);
+
+        let expected_csv = format!(
+            "tx_id,simulated,status,reason,gas_used,receipts,logs\n\
+             {},false,success,,42,1,\n\
+             ,true,revert,\"failed, \"\"badly\"\"\",7,1,\n",
+            TxId::zeroed()
+        );
+        assert_eq!(sink.to_csv(), expected_csv);
+
+        Ok(())
+    }
+
+    #[test]
+    fn only_the_installed_sink_captures() {
+        let status = TxStatus::Success {
+            receipts: vec![script_result(1)],
+        };
+        let sink = ReceiptSink::new();
+
+        capture(None, false, &status, &LogDecoder::default());
+        sink.install();
+        capture(None, false, &status, &LogDecoder::default());
+        ReceiptSink::uninstall();
+        capture(None, false, &status, &LogDecoder::default());
+
+        assert_eq!(sink.captured().len(), 1);
+    }
+}
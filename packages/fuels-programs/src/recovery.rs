@@ -0,0 +1,432 @@
+//! Typed interface to the social recovery predicate maintained in
+//! `packages/fuels/predicates/recovery`.
+
+use std::{collections::HashSet, fmt::Debug, path::Path, str::FromStr};
+
+use async_trait::async_trait;
+use fuel_tx::Output;
+use fuels_accounts::{
+    predicate::Predicate, provider::Provider, Account, CoinFilter, ViewOnlyAccount,
+};
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    traits::{Signer, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        input::Input,
+        transaction::TxPolicies,
+        transaction_builders::{ScriptTransactionBuilder, TransactionBuilder},
+        unresolved_bytes::UnresolvedBytes,
+        Address, AssetId, Token,
+    },
+    Configurables,
+};
+
+use crate::templates::{self, load_code_and_abi};
+
+/// The most guardians a recovery predicate can be configured with.
+pub const MAX_GUARDIANS: usize = 5;
+
+/// The keys controlling a [`RecoveryPredicate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryTerms {
+    pub owner: Bech32Address,
+    /// At most [`MAX_GUARDIANS`] distinct guardians.
+    pub guardians: Vec<Bech32Address>,
+    /// Number of distinct guardians needed to recover the funds.
+    pub threshold: usize,
+    /// Number of blocks after the creation of an instance, by [`RecoveryPredicate::rotate_owner`]
+    /// or [`RecoveryPredicate::recover`], during which only the owner can spend its funds.
+    pub delay: u32,
+}
+
+impl RecoveryTerms {
+    fn validate(&self) -> Result<()> {
+        if self.guardians.is_empty() || self.guardians.len() > MAX_GUARDIANS {
+            return Err(error!(
+                Other,
+                "a recovery predicate needs between 1 and {MAX_GUARDIANS} guardians, got {}",
+                self.guardians.len()
+            ));
+        }
+
+        if self.guardians.iter().collect::<HashSet<_>>().len() != self.guardians.len() {
+            return Err(error!(
+                Other,
+                "the guardians of a recovery predicate must be distinct"
+            ));
+        }
+
+        if self.threshold == 0 || self.threshold > self.guardians.len() {
+            return Err(error!(
+                Other,
+                "the recovery threshold must be between 1 and the number of guardians ({}), got {}",
+                self.guardians.len(),
+                self.threshold
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// The `Action` passed to the recovery predicate, selecting the spend path.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, fuels_macros::Parameterize, fuels_macros::Tokenizable,
+)]
+#[FuelsCorePath = "fuels_core"]
+#[FuelsTypesPath = "fuels_core::types"]
+enum Action {
+    Spend(u64),
+    Recover,
+}
+
+/// Recovery predicate configured with [`RecoveryTerms`]. Its funds are spent by the owner, through
+/// the account returned by [`RecoveryPredicate::owner_account`], or, from the recovery height on,
+/// recovered by a threshold of guardians with [`RecoveryPredicate::recover`].
+///
+/// Predicates cannot read the chain state, so the recovery height is enforced through the maturity
+/// of the recovering transaction and the delay is counted from the creation of the instance. The
+/// owner rotates to a fresh instance, possibly with a new key, with
+/// [`RecoveryPredicate::rotate_owner`], which also pushes the recovery height back.
+///
+/// Rotations and recoveries sweep every asset held by the predicate to the new instance. The fee
+/// is deducted from the base asset, so the predicate has to hold some.
+#[derive(Debug, Clone)]
+pub struct RecoveryPredicate {
+    predicate: Predicate,
+    code: Vec<u8>,
+    abi: String,
+    terms: RecoveryTerms,
+    recovery_height: u32,
+}
+
+impl RecoveryPredicate {
+    /// Loads the compiled recovery predicate from `binary_filepath` and configures it with
+    /// `terms`, recoverable from block `recovery_height`. The ABI is expected next to the binary,
+    /// as output by `forc build`.
+    pub fn load_from(
+        binary_filepath: impl AsRef<Path>,
+        terms: RecoveryTerms,
+        recovery_height: u32,
+    ) -> Result<Self> {
+        let (code, abi) = load_code_and_abi(binary_filepath.as_ref())?;
+
+        Self::from_code_and_abi(code, &abi, terms, recovery_height)
+    }
+
+    /// Configures the compiled recovery predicate `code`, whose JSON ABI is `abi`.
+    pub fn from_code_and_abi(
+        code: Vec<u8>,
+        abi: &str,
+        terms: RecoveryTerms,
+        recovery_height: u32,
+    ) -> Result<Self> {
+        terms.validate()?;
+
+        let configurables = configurables(abi, &terms, recovery_height)?;
+        let predicate = Predicate::from_code(code.clone()).with_configurables(configurables);
+
+        Ok(Self {
+            predicate,
+            code,
+            abi: abi.to_string(),
+            terms,
+            recovery_height,
+        })
+    }
+
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.predicate.set_provider(provider);
+        self
+    }
+
+    pub fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    pub fn terms(&self) -> &RecoveryTerms {
+        &self.terms
+    }
+
+    pub fn recovery_height(&self) -> u32 {
+        self.recovery_height
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    /// Returns whether the latest block has reached the recovery height.
+    pub async fn is_recoverable(&self) -> Result<bool> {
+        let height = self.predicate.try_provider()?.latest_block_height().await?;
+
+        Ok(height >= self.recovery_height)
+    }
+
+    /// Returns an account spending the funds through the owner path, signed by `owner`.
+    pub fn owner_account<S: Signer + Debug + Clone + Send + Sync>(
+        &self,
+        owner: S,
+    ) -> Result<RecoveryOwner<S>> {
+        ensure_owner(&owner, &self.terms.owner)?;
+
+        Ok(RecoveryOwner {
+            predicate: self
+                .predicate
+                .clone()
+                .with_data(action_data(Action::Spend(0))?),
+            owner,
+        })
+    }
+
+    /// Sweeps the funds, approved by `owner`, to a fresh instance owned by `new_owner` and
+    /// recoverable `delay` blocks after the latest block. Rotating to the same owner only pushes
+    /// the recovery height back. Returns the new instance and the sweeping transaction.
+    pub async fn rotate_owner(
+        &self,
+        owner: impl Signer + Send + Sync,
+        new_owner: &Bech32Address,
+    ) -> Result<(Self, ScriptTransactionBuilder)> {
+        ensure_owner(&owner, &self.terms.owner)?;
+
+        let successor = self.successor(new_owner).await?;
+        let mut tb = self.sweep(Action::Spend(0), successor.address()).await?;
+        tb.add_signer(owner)?;
+
+        Ok((successor, tb))
+    }
+
+    /// Sweeps the funds, approved by `guardians`, to a fresh instance owned by `new_owner` and
+    /// guarded by the same guardians. The transaction matures at the recovery height, so it is
+    /// rejected by the node until the recovery height is reached.
+    pub async fn recover<S: Signer + Send + Sync>(
+        &self,
+        guardians: impl IntoIterator<Item = S>,
+        new_owner: &Bech32Address,
+    ) -> Result<(Self, ScriptTransactionBuilder)> {
+        let guardians: Vec<S> = guardians.into_iter().collect();
+
+        let mut approving = HashSet::new();
+        for guardian in &guardians {
+            if !self.terms.guardians.contains(guardian.address()) {
+                return Err(error!(
+                    Other,
+                    "`{}` is not a guardian of the recovery predicate at `{}`",
+                    guardian.address(),
+                    self.address()
+                ));
+            }
+            approving.insert(guardian.address().clone());
+        }
+
+        if approving.len() < self.terms.threshold {
+            return Err(error!(
+                Other,
+                "recovering requires {} distinct guardians, got {}",
+                self.terms.threshold,
+                approving.len()
+            ));
+        }
+
+        let successor = self.successor(new_owner).await?;
+        let tb = self.sweep(Action::Recover, successor.address()).await?;
+        let tx_policies = tb.tx_policies.with_maturity(self.recovery_height.into());
+        let mut tb = tb.with_tx_policies(tx_policies);
+        for guardian in guardians {
+            tb.add_signer(guardian)?;
+        }
+
+        Ok((successor, tb))
+    }
+
+    /// An instance with the same guardians, owned by `new_owner` and recoverable `delay` blocks
+    /// after the latest block.
+    async fn successor(&self, new_owner: &Bech32Address) -> Result<Self> {
+        let provider = self.predicate.try_provider()?;
+        let recovery_height = provider
+            .latest_block_height()
+            .await?
+            .saturating_add(self.terms.delay);
+
+        let terms = RecoveryTerms {
+            owner: new_owner.clone(),
+            ..self.terms.clone()
+        };
+
+        Ok(
+            Self::from_code_and_abi(self.code.clone(), &self.abi, terms, recovery_height)?
+                .with_provider(provider.clone()),
+        )
+    }
+
+    /// Prepares a transaction spending every asset held by the predicate to `recipient`.
+    async fn sweep(
+        &self,
+        action: Action,
+        recipient: &Bech32Address,
+    ) -> Result<ScriptTransactionBuilder> {
+        let predicate = self.predicate.clone().with_data(action_data(action)?);
+
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        for (asset_id, balance) in predicate.get_balances().await? {
+            if balance == 0 {
+                continue;
+            }
+
+            let asset_id = AssetId::from_str(&asset_id)
+                .map_err(|e| error!(Other, "invalid asset id `{asset_id}`: {e}"))?;
+            inputs.extend(
+                predicate
+                    .get_asset_inputs_for_amount(asset_id, balance)
+                    .await?,
+            );
+            outputs.push(Output::change(recipient.into(), 0, asset_id));
+        }
+
+        if inputs.is_empty() {
+            return Err(error!(
+                Other,
+                "the recovery predicate at `{}` holds no funds",
+                self.address()
+            ));
+        }
+
+        Ok(ScriptTransactionBuilder::prepare_transfer(
+            inputs,
+            outputs,
+            TxPolicies::default(),
+        ))
+    }
+}
+
+/// Account spending the funds of a [`RecoveryPredicate`] through the owner path.
+///
+/// Witnesses are added automatically by the `Account` methods or by calling
+/// [`Account::add_witnesses`] on a transaction builder. The signature of the owner is expected
+/// to be the first witness of the transaction.
+#[derive(Debug, Clone)]
+pub struct RecoveryOwner<S> {
+    predicate: Predicate,
+    owner: S,
+}
+
+impl<S> RecoveryOwner<S> {
+    pub fn owner(&self) -> &S {
+        &self.owner
+    }
+}
+
+impl<S: Signer + Debug + Clone + Send + Sync> ViewOnlyAccount for RecoveryOwner<S> {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: Signer + Debug + Clone + Send + Sync> Account for RecoveryOwner<S> {
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount(asset_id, amount)
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        self.predicate
+            .get_asset_inputs_for_amount_filtered(asset_id, amount, filter)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(self.owner.clone())?;
+
+        Ok(())
+    }
+}
+
+fn ensure_owner(signer: &impl Signer, owner: &Bech32Address) -> Result<()> {
+    if signer.address() != owner {
+        return Err(error!(
+            Other,
+            "expected the signer to be the owner `{owner}`, got `{}`",
+            signer.address()
+        ));
+    }
+
+    Ok(())
+}
+
+fn action_data(action: Action) -> Result<UnresolvedBytes> {
+    ABIEncoder::new(EncoderConfig::default()).encode(&[action.into_token()])
+}
+
+fn configurables(abi: &str, terms: &RecoveryTerms, recovery_height: u32) -> Result<Configurables> {
+    let guardians = (0..MAX_GUARDIANS)
+        .map(|index| {
+            terms
+                .guardians
+                .get(index)
+                .map(Address::from)
+                .unwrap_or_default()
+                .into_token()
+        })
+        .collect();
+
+    templates::configurables(
+        abi,
+        [
+            ("OWNER", Address::from(&terms.owner).into_token()),
+            ("GUARDIANS", Token::Array(guardians)),
+            ("THRESHOLD", (terms.threshold as u64).into_token()),
+            ("RECOVERY_HEIGHT", recovery_height.into_token()),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fuels_core::types::bech32::FUEL_BECH32_HRP;
+
+    use super::*;
+
+    fn key(byte: u8) -> Bech32Address {
+        Bech32Address::new(FUEL_BECH32_HRP, [byte; 32])
+    }
+
+    #[test]
+    fn invalid_terms_are_rejected() {
+        let terms = |guardians: Vec<Bech32Address>, threshold| RecoveryTerms {
+            owner: key(0),
+            guardians,
+            threshold,
+            delay: 10,
+        };
+        let error_of = |terms: RecoveryTerms| {
+            RecoveryPredicate::from_code_and_abi(vec![], "", terms, 0)
+                .expect_err("should fail")
+                .to_string()
+        };
+
+        assert!(error_of(terms(vec![], 1)).contains("needs between 1 and 5 guardians, got 0"));
+        assert!(error_of(terms((1..=6).map(key).collect(), 1))
+            .contains("needs between 1 and 5 guardians, got 6"));
+        assert!(error_of(terms(vec![key(1), key(1)], 1)).contains("must be distinct"));
+        assert!(error_of(terms(vec![key(1), key(2)], 3))
+            .contains("threshold must be between 1 and the number of guardians (2), got 3"));
+    }
+}
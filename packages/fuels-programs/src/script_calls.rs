@@ -2,8 +2,10 @@ use std::{collections::HashSet, fmt::Debug, marker::PhantomData};
 
 use fuel_tx::{Bytes32, ContractId, Output, Receipt};
 use fuel_types::bytes::padded_len_usize;
+#[cfg(feature = "raw-graphql")]
+use fuels_accounts::provider::ExecutionTrace;
 use fuels_accounts::{
-    provider::{Provider, TransactionCost},
+    provider::{Provider, ResubmitPolicy, SimulationOptions, TransactionCost},
     Account,
 };
 use fuels_core::{
@@ -18,21 +20,26 @@ use fuels_core::{
         transaction::{ScriptTransaction, Transaction, TxPolicies},
         transaction_builders::{
             BuildableTransaction, ScriptTransactionBuilder, TransactionBuilder,
+            VariableOutputPolicy,
         },
         tx_status::TxStatus,
         unresolved_bytes::UnresolvedBytes,
+        Token,
     },
 };
 use itertools::chain;
 
 use crate::{
     call_response::FuelCallResponse,
+    call_script::{build_script_with_calls, CallScript},
     call_utils::{
-        generate_contract_inputs, generate_contract_outputs, new_variable_outputs, sealed,
-        TxDependencyExtension,
+        build_tx_for_simulation, calculate_required_asset_amounts, generate_contract_inputs,
+        generate_contract_outputs, get_transaction_inputs_outputs_with_contracts,
+        new_variable_outputs, sealed, used_base_amount, TxDependencyExtension,
     },
-    contract::SettableContract,
+    contract::{ContractCall, ContractCallHandler, SettableContract},
     receipt_parser::ReceiptParser,
+    receipt_sink,
     submit_response::SubmitResponse,
 };
 
@@ -45,6 +52,8 @@ pub struct ScriptCall {
     pub outputs: Vec<Output>,
     pub external_contracts: Vec<Bech32ContractId>,
     pub variable_outputs: Vec<Output>,
+    /// Contract calls performed before the script is executed, in the same transaction.
+    pub contract_calls: Vec<ContractCall>,
 }
 
 impl ScriptCall {
@@ -84,6 +93,8 @@ pub struct ScriptCallHandler<T: Account, D> {
     // Initially `None`, gets set to the right tx id after the transaction is submitted
     cached_tx_id: Option<Bytes32>,
     decoder_config: DecoderConfig,
+    variable_output_policy: VariableOutputPolicy,
+    resubmit_policy: ResubmitPolicy,
     pub account: T,
     pub provider: Provider,
     pub datatype: PhantomData<D>,
@@ -108,6 +119,7 @@ where
             outputs: vec![],
             external_contracts: vec![],
             variable_outputs: vec![],
+            contract_calls: vec![],
         };
         Self {
             script_call,
@@ -118,6 +130,8 @@ where
             datatype: PhantomData,
             log_decoder,
             decoder_config: DecoderConfig::default(),
+            variable_output_policy: VariableOutputPolicy::default(),
+            resubmit_policy: ResubmitPolicy::default(),
         }
     }
 
@@ -139,6 +153,20 @@ where
         self
     }
 
+    /// Sets how many variable outputs are added to the transaction, on top of the ones appended
+    /// with `append_variable_outputs`. See [`VariableOutputPolicy`] for the available options.
+    pub fn with_variable_output_policy(mut self, policy: VariableOutputPolicy) -> Self {
+        self.variable_output_policy = policy;
+        self
+    }
+
+    /// Resubmits the transaction if it is squeezed out, see [`ResubmitPolicy`]. It is rebuilt
+    /// with fresh inputs and a higher tip, and signed again by the account.
+    pub fn with_resubmit_policy(mut self, policy: ResubmitPolicy) -> Self {
+        self.resubmit_policy = policy;
+        self
+    }
+
     pub fn with_outputs(mut self, outputs: Vec<Output>) -> Self {
         self.script_call = self.script_call.with_outputs(outputs);
         self
@@ -162,6 +190,26 @@ where
         self
     }
 
+    /// Performs the contract call before `main` is executed, in the same transaction, so that
+    /// both succeed or revert together. The values returned by the calls are decoded by
+    /// [`Self::call_with_contract_calls`]. Calls are performed in the order they were added.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    ///
+    /// ```ignore
+    /// let price_call = oracle_instance.methods().price(asset_id);
+    /// script_instance.main(...).add_contract_call(price_call).call_with_contract_calls::<(u64,)>()
+    /// ```
+    pub fn add_contract_call(
+        mut self,
+        call_handler: ContractCallHandler<impl Account, impl Tokenizable>,
+    ) -> Self {
+        self.log_decoder.merge(call_handler.log_decoder);
+        self.script_call
+            .contract_calls
+            .push(call_handler.contract_call);
+        self
+    }
+
     /// Compute the script data by calculating the script offset and resolving the encoded arguments
     async fn compute_script_data(&self) -> Result<Vec<u8>> {
         let consensus_parameters = self.provider.consensus_parameters();
@@ -204,11 +252,78 @@ where
         Ok((inputs, outputs))
     }
 
+    /// Builds the transaction performing the contract calls before the script, see
+    /// [`build_script_with_calls`].
+    async fn transaction_builder_with_calls(&self) -> Result<ScriptTransactionBuilder> {
+        let calls = &self.script_call.contract_calls;
+        let base_asset_id = *self.provider.base_asset_id();
+        let script_args = self
+            .script_call
+            .encoded_args
+            .as_ref()
+            .map_err(|e| error!(Codec, "cannot encode script call arguments: {e}"))?;
+
+        let CallScript {
+            script,
+            script_data,
+            ..
+        } = build_script_with_calls(
+            &self.script_call.script_binary,
+            script_args,
+            calls,
+            self.provider.consensus_parameters(),
+            base_asset_id,
+        )?;
+
+        let mut asset_inputs = vec![];
+        for (asset_id, amount) in calculate_required_asset_amounts(calls, base_asset_id) {
+            let resources = self
+                .account
+                .get_asset_inputs_for_amount(asset_id, amount)
+                .await?;
+            asset_inputs.extend(resources);
+        }
+
+        let script_contracts = self
+            .script_call
+            .external_contracts
+            .iter()
+            .map(|bech32| bech32.into())
+            .collect();
+        let (inputs, outputs) = get_transaction_inputs_outputs_with_contracts(
+            calls,
+            script_contracts,
+            asset_inputs,
+            self.account.address(),
+            base_asset_id,
+        );
+
+        Ok(ScriptTransactionBuilder::default()
+            .with_tx_policies(self.tx_policies)
+            .with_variable_output_policy(self.variable_output_policy)
+            .with_script(script)
+            .with_script_data(script_data)
+            .with_inputs(chain!(inputs, self.script_call.inputs.clone()).collect())
+            .with_outputs(
+                chain!(
+                    outputs,
+                    self.script_call.outputs.clone(),
+                    self.script_call.variable_outputs.clone(),
+                )
+                .collect(),
+            ))
+    }
+
     pub async fn transaction_builder(&self) -> Result<ScriptTransactionBuilder> {
+        if !self.script_call.contract_calls.is_empty() {
+            return self.transaction_builder_with_calls().await;
+        }
+
         let (inputs, outputs) = self.prepare_inputs_outputs().await?;
 
         Ok(ScriptTransactionBuilder::default()
             .with_tx_policies(self.tx_policies)
+            .with_variable_output_policy(self.variable_output_policy)
             .with_script(self.script_call.script_binary.clone())
             .with_script_data(self.compute_script_data().await?)
             .with_inputs(inputs)
@@ -217,32 +332,63 @@ where
 
     /// Returns the transaction that executes the script call
     pub async fn build_tx(&self) -> Result<ScriptTransaction> {
-        let mut tb = self.transaction_builder().await?;
+        self.build_tx_with_policies(self.tx_policies).await
+    }
+
+    async fn build_tx_with_policies(&self, tx_policies: TxPolicies) -> Result<ScriptTransaction> {
+        let mut tb = self
+            .transaction_builder()
+            .await?
+            .with_tx_policies(tx_policies);
 
         self.account.add_witnesses(&mut tb)?;
-        self.account.adjust_for_fee(&mut tb, 0).await?;
+        self.account
+            .adjust_for_fee(&mut tb, self.used_base_amount())
+            .await?;
 
         tb.build(self.account.try_provider()?).await
     }
 
+    /// The amount of the base asset forwarded by the contract calls.
+    fn used_base_amount(&self) -> u64 {
+        used_base_amount(
+            &self.script_call.contract_calls,
+            *self.provider.base_asset_id(),
+        )
+    }
+
     /// Call a script on the node. If `simulate == true`, then the call is done in a
     /// read-only manner, using a `dry-run`. The [`FuelCallResponse`] struct contains the `main`'s value
     /// in its `value` field as an actual typed value `D` (if your method returns `bool`,
     /// it will be a bool, works also for structs thanks to the `abigen!()`).
     /// The other field of [`FuelCallResponse`], `receipts`, contains the receipts of the transaction.
     async fn call_or_simulate(&mut self, simulate: bool) -> Result<FuelCallResponse<D>> {
-        let tx = self.build_tx().await?;
+        let receipts = self.execute(simulate).await?;
 
-        self.cached_tx_id = Some(tx.id(self.provider.chain_id()));
+        self.get_response(receipts)
+    }
 
-        let tx_status = if simulate {
-            self.provider.dry_run(tx).await?
+    async fn execute(&mut self, simulate: bool) -> Result<Vec<Receipt>> {
+        let (tx_id, tx_status) = if simulate {
+            let tx = self.build_tx().await?;
+            (
+                tx.id(self.provider.chain_id()),
+                self.provider.dry_run(tx).await?,
+            )
         } else {
-            self.provider.send_transaction_and_await_commit(tx).await?
+            self.resubmit_policy
+                .send_and_await_commit(&self.provider, self.tx_policies, |tx_policies| {
+                    self.build_tx_with_policies(tx_policies)
+                })
+                .await?
         };
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        self.cached_tx_id = Some(tx_id);
+        receipt_sink::capture(self.cached_tx_id, simulate, &tx_status, &self.log_decoder);
 
-        self.get_response(receipts)
+        tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            self.provider.source_maps(),
+        )
     }
 
     /// Call a script on the node, in a state-modifying manner.
@@ -250,6 +396,26 @@ where
         self.call_or_simulate(false).await
     }
 
+    /// Like [`Self::call`], but also decodes the values returned by the contract calls added
+    /// with [`Self::add_contract_call`]. The value of the response is a pair of the tuple of these
+    /// values, in the order the calls were added, and the value returned by `main`.
+    pub async fn call_with_contract_calls<C: Tokenizable + Debug>(
+        mut self,
+    ) -> Result<FuelCallResponse<(C, D)>> {
+        let receipts = self.execute(false).await?;
+
+        self.get_response_with_contract_calls(receipts)
+    }
+
+    /// Like [`Self::call_with_contract_calls`], but dry runs the transaction.
+    pub async fn simulate_with_contract_calls<C: Tokenizable + Debug>(
+        &mut self,
+    ) -> Result<FuelCallResponse<(C, D)>> {
+        let receipts = self.execute(true).await?;
+
+        self.get_response_with_contract_calls(receipts)
+    }
+
     pub async fn submit(mut self) -> Result<SubmitResponse<T, D>> {
         let tx = self.build_tx().await?;
         let tx_id = self.provider.send_transaction(tx).await?;
@@ -267,6 +433,28 @@ where
         self.call_or_simulate(true).await
     }
 
+    /// Like [`Self::simulate`], but dry runs with the given [`SimulationOptions`]. Without UTXO
+    /// validation, scripts can be simulated for accounts that aren't funded yet.
+    pub async fn simulate_with_options(
+        &mut self,
+        options: SimulationOptions,
+    ) -> Result<FuelCallResponse<D>> {
+        let tb = self.transaction_builder().await?;
+        let tx =
+            build_tx_for_simulation(tb, &self.account, self.used_base_amount(), options).await?;
+
+        self.cached_tx_id = Some(tx.id(self.provider.chain_id()));
+
+        let tx_status = self.provider.dry_run_with_options(tx, options).await?;
+        receipt_sink::capture(self.cached_tx_id, true, &tx_status, &self.log_decoder);
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            self.provider.source_maps(),
+        )?;
+
+        self.get_response(receipts)
+    }
+
     /// Get a scripts's estimated cost
     pub async fn estimate_transaction_cost(
         &self,
@@ -283,6 +471,30 @@ where
         Ok(transaction_cost)
     }
 
+    /// Like [`Self::estimate_transaction_cost`], but computes the fee at `gas_price`, see
+    /// [`Provider::estimate_transaction_cost_at_gas_price`].
+    pub async fn estimate_transaction_cost_at_gas_price(
+        &self,
+        tolerance: Option<f64>,
+        gas_price: u64,
+    ) -> Result<TransactionCost> {
+        let tx = self.build_tx().await?;
+
+        self.provider
+            .estimate_transaction_cost_at_gas_price(tx, tolerance, gas_price)
+            .await
+    }
+
+    /// Executes the call instruction by instruction with the debugger of the node, see
+    /// [`Provider::execution_trace`]. Useful to find out where and why a call reverts, the
+    /// registers are captured for the last `window` instructions.
+    #[cfg(feature = "raw-graphql")]
+    pub async fn execution_trace(&self, window: usize) -> Result<ExecutionTrace> {
+        let tx = self.build_tx().await?;
+
+        self.provider.execution_trace(tx, window).await
+    }
+
     /// Create a [`FuelCallResponse`] from call receipts
     pub fn get_response(&self, receipts: Vec<Receipt>) -> Result<FuelCallResponse<D>> {
         let token =
@@ -296,9 +508,37 @@ where
         ))
     }
 
+    /// Create a [`FuelCallResponse`] holding the values returned by the contract calls and by
+    /// `main`, see [`Self::call_with_contract_calls`].
+    pub fn get_response_with_contract_calls<C: Tokenizable>(
+        &self,
+        receipts: Vec<Receipt>,
+    ) -> Result<FuelCallResponse<(C, D)>> {
+        let mut receipt_parser = ReceiptParser::new(&receipts, self.decoder_config);
+
+        let call_tokens = self
+            .script_call
+            .contract_calls
+            .iter()
+            .map(|call| receipt_parser.parse_call(&call.contract_id, &call.output_param))
+            .collect::<Result<Vec<_>>>()?;
+        let call_values = C::from_token(Token::Tuple(call_tokens))?;
+        let script_value = D::from_token(receipt_parser.parse_script(&D::param_type())?)?;
+
+        Ok(FuelCallResponse::new(
+            (call_values, script_value),
+            receipts,
+            self.log_decoder.clone(),
+            self.cached_tx_id,
+        ))
+    }
+
     /// Create a [`FuelCallResponse`] from `TxStatus`
     pub fn get_response_from(&self, tx_status: TxStatus) -> Result<FuelCallResponse<D>> {
-        let receipts = tx_status.take_receipts_checked(Some(&self.log_decoder))?;
+        let receipts = tx_status.take_receipts_checked_with_source_maps(
+            Some(&self.log_decoder),
+            self.provider.source_maps(),
+        )?;
 
         self.get_response(receipts)
     }
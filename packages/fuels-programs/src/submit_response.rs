@@ -12,6 +12,7 @@ use fuels_core::{
 use crate::{
     call_response::FuelCallResponse,
     contract::{ContractCallHandler, MultiContractCallHandler},
+    receipt_sink,
     script_calls::ScriptCallHandler,
 };
 
@@ -95,10 +96,11 @@ impl<T: Account, D: Tokenizable + Parameterize + Debug> SubmitResponse<T, D> {
 
     pub async fn response(self) -> Result<FuelCallResponse<D>> {
         let provider = self.call_handler.try_provider()?;
-        let receipts = provider
-            .tx_status(&self.tx_id)
-            .await?
-            .take_receipts_checked(Some(self.call_handler.log_decoder()))?;
+        let tx_status = provider.tx_status(&self.tx_id).await?;
+        let log_decoder = self.call_handler.log_decoder();
+        receipt_sink::capture(Some(self.tx_id), false, &tx_status, log_decoder);
+        let receipts = tx_status
+            .take_receipts_checked_with_source_maps(Some(log_decoder), provider.source_maps())?;
 
         self.call_handler.get_response(receipts)
     }
@@ -128,10 +130,11 @@ impl<T: Account> SubmitResponseMultiple<T> {
 
     pub async fn response<D: Tokenizable + Debug>(self) -> Result<FuelCallResponse<D>> {
         let provider = self.call_handler.account.try_provider()?;
-        let receipts = provider
-            .tx_status(&self.tx_id)
-            .await?
-            .take_receipts_checked(Some(&self.call_handler.log_decoder))?;
+        let tx_status = provider.tx_status(&self.tx_id).await?;
+        let log_decoder = &self.call_handler.log_decoder;
+        receipt_sink::capture(Some(self.tx_id), false, &tx_status, log_decoder);
+        let receipts = tx_status
+            .take_receipts_checked_with_source_maps(Some(log_decoder), provider.source_maps())?;
 
         self.call_handler.get_response(receipts)
     }
@@ -0,0 +1,63 @@
+//! Loading and configuration of the predicate templates maintained in `packages/fuels/predicates`.
+
+use std::{collections::HashMap, fs, path::Path};
+
+use fuel_abi_types::abi::full_program::FullProgramABI;
+use fuels_core::{
+    codec::{ConfigurablesEncoder, EncoderConfig},
+    types::{
+        errors::{error, Result},
+        Token,
+    },
+    Configurables,
+};
+
+/// Reads the compiled program at `binary_filepath` together with its JSON ABI, expected next to
+/// the binary as output by `forc build`.
+pub(crate) fn load_code_and_abi(binary_filepath: &Path) -> Result<(Vec<u8>, String)> {
+    let abi_filepath = binary_filepath
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .zip(binary_filepath.parent())
+        .map(|(stem, dir)| dir.join(format!("{stem}-abi.json")))
+        .ok_or_else(|| {
+            error!(
+                Other,
+                "could not determine the ABI file of `{}`",
+                binary_filepath.display()
+            )
+        })?;
+
+    let code = fs::read(binary_filepath)?;
+    let abi = fs::read_to_string(abi_filepath)?;
+
+    Ok((code, abi))
+}
+
+/// Encodes `values` at the offsets of the configurables of the same name in `abi`.
+pub(crate) fn configurables<'a>(
+    abi: &str,
+    values: impl IntoIterator<Item = (&'a str, Token)>,
+) -> Result<Configurables> {
+    let abi = FullProgramABI::from_json_abi(abi)
+        .map_err(|err| error!(Other, "could not parse the ABI: {err}"))?;
+    let offsets: HashMap<_, _> = abi
+        .configurables
+        .iter()
+        .map(|configurable| (configurable.name.as_str(), configurable.offset))
+        .collect();
+
+    let encoder = ConfigurablesEncoder::new(EncoderConfig::default());
+    let offsets_with_data = values
+        .into_iter()
+        .map(|(name, value)| {
+            let offset = offsets
+                .get(name)
+                .ok_or_else(|| error!(Other, "the ABI has no configurable named `{name}`"))?;
+
+            Ok((*offset, encoder.encode(&[value])?.resolve(0)))
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Configurables::new(offsets_with_data))
+}
@@ -0,0 +1,162 @@
+//! Typed interface to the timelock predicate maintained in `packages/fuels/predicates/timelock`.
+
+use std::{fmt::Debug, path::Path};
+
+use async_trait::async_trait;
+use fuels_accounts::{
+    predicate::Predicate, provider::Provider, Account, CoinFilter, ViewOnlyAccount,
+};
+use fuels_core::{
+    codec::{ABIEncoder, EncoderConfig},
+    traits::{Signer, Tokenizable},
+    types::{
+        bech32::Bech32Address,
+        errors::{error, Result},
+        input::Input,
+        transaction_builders::TransactionBuilder,
+        Address, AssetId,
+    },
+};
+
+use crate::templates::{self, load_code_and_abi};
+
+/// Account whose funds are locked by a predicate until a block height, after which only `owner`
+/// can spend them.
+///
+/// Predicates cannot read the chain state, so the lock is enforced through the maturity of the
+/// spending transaction, which the node rejects until the block at that height is produced. For
+/// the same reason funds cannot be locked until a timestamp.
+///
+/// Witnesses are added automatically by the `Account` methods or by calling
+/// [`Account::add_witnesses`] on a transaction builder, which also sets the maturity. The
+/// signature of the owner is expected to be the first witness of the transaction.
+#[derive(Debug, Clone)]
+pub struct TimelockPredicate<S> {
+    predicate: Predicate,
+    owner: S,
+    unlock_height: u32,
+}
+
+impl<S: Signer + Debug + Clone + Send + Sync> TimelockPredicate<S> {
+    /// Loads the compiled timelock predicate from `binary_filepath` and configures it to be
+    /// spendable by `owner` from block `unlock_height`. The ABI is expected next to the binary,
+    /// as output by `forc build`.
+    pub fn until_height(
+        binary_filepath: impl AsRef<Path>,
+        unlock_height: u32,
+        owner: S,
+    ) -> Result<Self> {
+        let (code, abi) = load_code_and_abi(binary_filepath.as_ref())?;
+
+        Self::from_code_and_abi(code, &abi, unlock_height, owner)
+    }
+
+    /// Configures the compiled timelock predicate `code`, whose JSON ABI is `abi`.
+    pub fn from_code_and_abi(
+        code: Vec<u8>,
+        abi: &str,
+        unlock_height: u32,
+        owner: S,
+    ) -> Result<Self> {
+        let configurables = templates::configurables(
+            abi,
+            [
+                ("OWNER", Address::from(owner.address()).into_token()),
+                ("UNLOCK_HEIGHT", unlock_height.into_token()),
+            ],
+        )?;
+
+        let owner_witness_index = 0u64;
+        let data = ABIEncoder::new(EncoderConfig::default())
+            .encode(&[owner_witness_index.into_token()])?;
+
+        let predicate = Predicate::from_code(code)
+            .with_configurables(configurables)
+            .with_data(data);
+
+        Ok(Self {
+            predicate,
+            owner,
+            unlock_height,
+        })
+    }
+
+    pub fn with_provider(mut self, provider: Provider) -> Self {
+        self.predicate.set_provider(provider);
+        self
+    }
+
+    pub fn predicate(&self) -> &Predicate {
+        &self.predicate
+    }
+
+    pub fn owner(&self) -> &S {
+        &self.owner
+    }
+
+    pub fn unlock_height(&self) -> u32 {
+        self.unlock_height
+    }
+
+    /// Returns whether the latest block has reached the unlock height.
+    pub async fn is_unlocked(&self) -> Result<bool> {
+        let height = self.try_provider()?.latest_block_height().await?;
+
+        Ok(height >= self.unlock_height)
+    }
+}
+
+impl<S: Signer + Debug + Clone + Send + Sync> ViewOnlyAccount for TimelockPredicate<S> {
+    fn address(&self) -> &Bech32Address {
+        self.predicate.address()
+    }
+
+    fn try_provider(&self) -> Result<&Provider> {
+        self.predicate.try_provider()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<S: Signer + Debug + Clone + Send + Sync> Account for TimelockPredicate<S> {
+    /// Fails if the unlock height is not reached yet, as the transaction would be rejected.
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Input>> {
+        self.get_asset_inputs_for_amount_filtered(asset_id, amount, CoinFilter::default())
+            .await
+    }
+
+    async fn get_asset_inputs_for_amount_filtered(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        filter: CoinFilter,
+    ) -> Result<Vec<Input>> {
+        if !self.is_unlocked().await? {
+            return Err(error!(
+                Other,
+                "the timelock at `{}` is locked until block height {}",
+                self.address(),
+                self.unlock_height
+            ));
+        }
+
+        self.predicate
+            .get_asset_inputs_for_amount_filtered(asset_id, amount, filter)
+            .await
+    }
+
+    fn add_witnesses<Tb: TransactionBuilder>(&self, tb: &mut Tb) -> Result<()> {
+        tb.add_signer(self.owner.clone())?;
+
+        let tx_policies = tb.tx_policies_mut();
+        let maturity = tx_policies.maturity().unwrap_or_default();
+        if maturity < self.unlock_height.into() {
+            *tx_policies = tx_policies.with_maturity(self.unlock_height.into());
+        }
+
+        Ok(())
+    }
+}
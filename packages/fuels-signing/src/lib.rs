@@ -0,0 +1,195 @@
+//! Signs Fuel transactions without the standard library, e.g. in firmware or in WASM signers
+//! signing transactions built elsewhere. Only `alloc` is required.
+//!
+//! A transaction is signed by signing its id, which doesn't cover the witnesses, so signatures
+//! can be produced in any order and appended afterwards. The inputs spent by the signer must
+//! already point to the index the witness will be appended at.
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+
+pub use fuel_crypto::{Message, SecretKey, Signature};
+use fuel_tx::{
+    field::{WitnessLimit, Witnesses},
+    Bytes32, Transaction, UniqueIdentifier, Witness,
+};
+use fuel_types::{bytes::padded_len_usize, ChainId};
+
+/// The size of a witness besides its data, i.e. the encoded length of the data.
+pub const WITNESS_STATIC_SIZE: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SigningError {
+    /// The transaction has no witnesses, e.g. a mint transaction.
+    NoWitnesses,
+    /// The witnesses would exceed the witness limit of the transaction.
+    WitnessLimitExceeded {
+        required: u64,
+        limit: u64,
+    },
+    WitnessSizeOverflow,
+}
+
+impl fmt::Display for SigningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWitnesses => write!(f, "the transaction has no witnesses to sign"),
+            Self::WitnessLimitExceeded { required, limit } => write!(
+                f,
+                "witness limit exceeded: the witnesses need `{required}` bytes but the limit \
+                is `{limit}`"
+            ),
+            Self::WitnessSizeOverflow => write!(f, "witness size overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SigningError {}
+
+/// Returns the id of `tx` on the chain `chain_id`.
+pub fn tx_id(tx: &Transaction, chain_id: ChainId) -> Bytes32 {
+    tx.id(&chain_id)
+}
+
+/// The message signed for a transaction, which is its id.
+pub fn message_for_tx_id(tx_id: &Bytes32) -> Message {
+    Message::from_bytes(**tx_id)
+}
+
+/// Wraps a signature into the witness proving ownership of the inputs of the signer.
+pub fn witness_from_signature(signature: &Signature) -> Witness {
+    signature.as_ref().into()
+}
+
+/// Returns the size of `witnesses` once encoded in a transaction, before padding.
+pub fn witnesses_size<'a>(witnesses: impl IntoIterator<Item = &'a Witness>) -> usize {
+    witnesses
+        .into_iter()
+        .map(|witness| witness.as_ref().len() + WITNESS_STATIC_SIZE)
+        .sum()
+}
+
+/// Appends `witness` to `tx` and returns its index. Fails if the witnesses would exceed the
+/// witness limit of `tx`.
+pub fn append_witness<T: Witnesses + WitnessLimit>(
+    tx: &mut T,
+    witness: Witness,
+) -> Result<usize, SigningError> {
+    let witnesses_size = witnesses_size(tx.witnesses().iter().chain(core::iter::once(&witness)));
+    let required =
+        padded_len_usize(witnesses_size).ok_or(SigningError::WitnessSizeOverflow)? as u64;
+
+    let limit = tx.witness_limit();
+    if required > limit {
+        return Err(SigningError::WitnessLimitExceeded { required, limit });
+    }
+
+    let index = tx.witnesses().len();
+    tx.witnesses_mut().push(witness);
+
+    Ok(index)
+}
+
+/// Signs `tx` with `secret_key` and appends the witness. Returns the index of the witness.
+pub fn sign_transaction(
+    tx: &mut Transaction,
+    chain_id: ChainId,
+    secret_key: &SecretKey,
+) -> Result<usize, SigningError> {
+    let message = message_for_tx_id(&tx_id(tx, chain_id));
+    let witness = witness_from_signature(&Signature::sign(secret_key, &message));
+
+    match tx {
+        Transaction::Script(tx) => append_witness(tx, witness),
+        Transaction::Create(tx) => append_witness(tx, witness),
+        Transaction::Upgrade(tx) => append_witness(tx, witness),
+        Transaction::Upload(tx) => append_witness(tx, witness),
+        Transaction::Mint(_) => Err(SigningError::NoWitnesses),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use fuel_crypto::PublicKey;
+    use fuel_tx::{policies::Policies, Mint};
+
+    use super::*;
+
+    fn secret_key() -> SecretKey {
+        SecretKey::try_from([7; 32].as_slice()).expect("valid secret key")
+    }
+
+    fn script(witness_limit: u64) -> Transaction {
+        let policies = Policies::new().with_witness_limit(witness_limit);
+
+        Transaction::script(0, vec![], vec![], policies, vec![], vec![], vec![]).into()
+    }
+
+    #[test]
+    fn witnesses_size_includes_the_static_size_of_each_witness() {
+        let witnesses = [Witness::from(vec![0; 64]), Witness::from(vec![])];
+
+        assert_eq!(witnesses_size(&witnesses), 64 + 2 * WITNESS_STATIC_SIZE);
+    }
+
+    #[test]
+    fn signatures_are_over_the_tx_id() -> Result<(), SigningError> {
+        let chain_id = ChainId::new(1);
+        let mut tx = script(1000);
+        let id = tx_id(&tx, chain_id);
+
+        // ANCHOR: sign_transaction
+        let index = sign_transaction(&mut tx, chain_id, &secret_key())?;
+        // ANCHOR_END: sign_transaction
+
+        // the witnesses are not part of the id
+        assert_eq!(tx_id(&tx, chain_id), id);
+
+        let Transaction::Script(script) = &tx else {
+            unreachable!("the transaction is a script");
+        };
+        let bytes = <[u8; 64]>::try_from(script.witnesses()[index].as_ref())
+            .expect("witness is a signature");
+        let signature = Signature::from_bytes(bytes);
+        let recovered = signature
+            .recover(&message_for_tx_id(&id))
+            .expect("signature is valid");
+        assert_eq!(recovered, PublicKey::from(&secret_key()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn witness_limit_is_enforced() {
+        let mut tx = script(10);
+
+        let err =
+            sign_transaction(&mut tx, ChainId::default(), &secret_key()).expect_err("should fail");
+
+        assert_eq!(
+            err,
+            SigningError::WitnessLimitExceeded {
+                required: 72,
+                limit: 10
+            }
+        );
+    }
+
+    #[test]
+    fn mint_transactions_cannot_be_signed() {
+        let mut tx = Transaction::from(Mint::default());
+
+        let err =
+            sign_transaction(&mut tx, ChainId::default(), &secret_key()).expect_err("should fail");
+
+        assert_eq!(err, SigningError::NoWitnesses);
+    }
+}
@@ -1,10 +1,10 @@
 use std::{
     net::{IpAddr, SocketAddr},
-    path::PathBuf,
+    path::{Path, PathBuf},
+    process::Stdio,
     time::Duration,
 };
 
-use fuel_core_chain_config::{ChainConfig, SnapshotWriter, StateConfig};
 use fuel_core_client::client::FuelClient;
 use fuel_core_services::State;
 use fuels_core::{
@@ -15,13 +15,16 @@ use portpicker::{is_free, pick_unused_port};
 use tempfile::{tempdir, TempDir};
 use tokio::{process::Command, spawn, task::JoinHandle, time::sleep};
 
-use crate::node_types::{DbType, NodeConfig, Trigger};
+use crate::{
+    node_types::{DbType, NodeConfig, Trigger},
+    service::Snapshot,
+    write_snapshot,
+};
 
 #[derive(Debug)]
 pub(crate) struct ExtendedConfig {
     pub node_config: NodeConfig,
-    pub chain_config: ChainConfig,
-    pub state_config: StateConfig,
+    pub snapshot: Snapshot,
     pub snapshot_dir: TempDir,
 }
 
@@ -35,8 +38,7 @@ impl ExtendedConfig {
             "--port".to_string(),
             port,
             "--snapshot".to_string(),
-            self.snapshot_dir
-                .path()
+            self.snapshot_path()
                 .to_str()
                 .expect("Failed to find config file")
                 .to_string(),
@@ -90,11 +92,21 @@ impl ExtendedConfig {
         Ok(args)
     }
 
+    fn snapshot_path(&self) -> &Path {
+        match &self.snapshot {
+            Snapshot::Generated { .. } => self.snapshot_dir.path(),
+            Snapshot::Existing(path) => path,
+        }
+    }
+
     pub fn write_temp_snapshot_files(self) -> FuelResult<TempDir> {
-        let writer = SnapshotWriter::json(self.snapshot_dir.path());
-        writer
-            .write_state_config(self.state_config, &self.chain_config)
-            .map_err(|e| error!(Other, "could not write state config: {}", e))?;
+        if let Snapshot::Generated {
+            chain_config,
+            state_config,
+        } = self.snapshot
+        {
+            write_snapshot(self.snapshot_dir.path(), chain_config, state_config)?;
+        }
 
         Ok(self.snapshot_dir)
     }
@@ -106,11 +118,7 @@ pub struct FuelService {
 }
 
 impl FuelService {
-    pub async fn new_node(
-        node_config: NodeConfig,
-        chain_config: ChainConfig,
-        state_config: StateConfig,
-    ) -> FuelResult<Self> {
+    pub async fn new_node(node_config: NodeConfig, snapshot: Snapshot) -> FuelResult<Self> {
         let requested_port = node_config.addr.port();
 
         let bound_address = match requested_port {
@@ -126,8 +134,7 @@ impl FuelService {
 
         let extended_config = ExtendedConfig {
             node_config,
-            state_config,
-            chain_config,
+            snapshot,
             snapshot_dir: tempdir()?,
         };
 
@@ -176,6 +183,8 @@ fn get_socket_address() -> FuelResult<SocketAddr> {
 
 async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>> {
     let args = extended_config.args_vec()?;
+    let logs = extended_config.node_config.logs.clone();
+    let log_level = extended_config.node_config.log_level.clone();
     let tempdir = extended_config.write_temp_snapshot_files()?;
 
     let binary_name = "fuel-core";
@@ -196,17 +205,40 @@ async fn run_node(extended_config: ExtendedConfig) -> FuelResult<JoinHandle<()>>
     }
 
     let mut command = Command::new(path);
-    let running_node = command.args(args).kill_on_drop(true).output();
+    command.args(args).kill_on_drop(true);
+    if let Some(log_level) = log_level {
+        command.env("RUST_LOG", log_level);
+    }
+
+    let Some(logs) = logs else {
+        let running_node = command.output();
+
+        return Ok(spawn(async move {
+            // ensure drop is not called on the tmp dir and it lives throughout the lifetime of the node
+            let _unused = tempdir;
+            let result = running_node
+                .await
+                .expect("error: could not find `fuel-core` in PATH`");
+            let stdout = String::from_utf8_lossy(&result.stdout);
+            let stderr = String::from_utf8_lossy(&result.stderr);
+            eprintln!("the exit status from the fuel binary was: {result:?}, stdout: {stdout}, stderr: {stderr}");
+        }));
+    };
+
+    let mut running_node = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| error!(Other, "could not start `{binary_name}`: {err}"))?;
+    let stdout = running_node.stdout.take().expect("stdout is piped");
+    let stderr = running_node.stderr.take().expect("stderr is piped");
 
     let join_handle = spawn(async move {
         // ensure drop is not called on the tmp dir and it lives throughout the lifetime of the node
         let _unused = tempdir;
-        let result = running_node
-            .await
-            .expect("error: could not find `fuel-core` in PATH`");
-        let stdout = String::from_utf8_lossy(&result.stdout);
-        let stderr = String::from_utf8_lossy(&result.stderr);
-        eprintln!("the exit status from the fuel binary was: {result:?}, stdout: {stdout}, stderr: {stderr}");
+        tokio::join!(logs.capture(stdout), logs.capture(stderr));
+        let status = running_node.wait().await;
+        eprintln!("the exit status from the fuel binary was: {status:?}");
     });
 
     Ok(join_handle)
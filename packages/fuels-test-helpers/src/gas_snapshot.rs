@@ -0,0 +1,188 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use fuels_core::types::errors::{error, Result};
+
+/// Set to any value but `0` to overwrite the recorded gas with the gas used by the tests.
+pub const UPDATE_GAS_SNAPSHOTS_ENV: &str = "UPDATE_GAS_SNAPSHOTS";
+
+/// Tests of the same binary run in parallel and may share a snapshot file.
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Compares the gas used by named calls with the gas recorded in a snapshot file, so that tests
+/// fail when a change to the contracts makes a call more expensive.
+///
+/// The snapshot file is meant to be committed. It holds one `name = gas` line per call, sorted by
+/// name. Calls missing from the file are added to it. With [`UPDATE_GAS_SNAPSHOTS_ENV`] set, the
+/// recorded gas is overwritten instead of compared, e.g. after an intended change.
+#[derive(Debug, Clone)]
+pub struct GasSnapshot {
+    path: PathBuf,
+    tolerance: f64,
+    update: bool,
+}
+
+impl GasSnapshot {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let update = std::env::var(UPDATE_GAS_SNAPSHOTS_ENV).is_ok_and(|value| value != "0");
+
+        Self {
+            path: path.into(),
+            tolerance: 0.0,
+            update,
+        }
+    }
+
+    /// Allows the gas used to exceed the recorded gas by a fraction of it, e.g. `0.01` for 1%.
+    /// Defaults to 0.
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Overrides [`UPDATE_GAS_SNAPSHOTS_ENV`].
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Compares `gas_used`, e.g. the `gas_used` of a call response, with the gas recorded for
+    /// `name`. Fails if it exceeds the recorded gas by more than the tolerance.
+    pub fn record(&self, name: &str, gas_used: u64) -> Result<()> {
+        if name.is_empty() || name.contains('\n') || name.contains(" = ") {
+            return Err(error!(
+                Other,
+                "invalid gas snapshot name `{name}`: it must be a non-empty single line without ` = `"
+            ));
+        }
+
+        let _guard = FILE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut entries = self.read()?;
+
+        match entries.get(name) {
+            Some(&recorded) if !self.update => {
+                let allowed = recorded as f64 * (1.0 + self.tolerance);
+                if gas_used as f64 > allowed {
+                    return Err(error!(
+                        Other,
+                        "gas regression in `{name}`: used {gas_used}, recorded {recorded} in {:?}. \
+                         Run the tests with `{UPDATE_GAS_SNAPSHOTS_ENV}=1` to accept it",
+                        self.path
+                    ));
+                }
+
+                Ok(())
+            }
+            Some(&recorded) if recorded == gas_used => Ok(()),
+            _ => {
+                entries.insert(name.to_string(), gas_used);
+                self.write(&entries)
+            }
+        }
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, u64>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(err) => return Err(err.into()),
+        };
+
+        contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (name, gas) = line
+                    .rsplit_once(" = ")
+                    .ok_or_else(|| error!(Other, "invalid gas snapshot line `{line}`"))?;
+                let gas = gas
+                    .trim()
+                    .parse()
+                    .map_err(|err| error!(Other, "invalid gas in snapshot line `{line}`: {err}"))?;
+
+                Ok((name.to_string(), gas))
+            })
+            .collect()
+    }
+
+    fn write(&self, entries: &BTreeMap<String, u64>) -> Result<()> {
+        let contents = entries
+            .iter()
+            .map(|(name, gas)| format!("{name} = {gas}\n"))
+            .collect::<String>();
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, contents)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(dir: &tempfile::TempDir) -> GasSnapshot {
+        GasSnapshot::new(dir.path().join("gas.snap")).with_update(false)
+    }
+
+    #[test]
+    fn new_calls_are_recorded() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let snapshot = snapshot(&dir);
+
+        snapshot.record("transfer", 120)?;
+        snapshot.record("mint", 100)?;
+
+        assert_eq!(
+            fs::read_to_string(snapshot.path())?,
+            "mint = 100\ntransfer = 120\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn regressions_beyond_the_tolerance_fail() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let snapshot = snapshot(&dir).with_tolerance(0.1);
+        snapshot.record("transfer", 100)?;
+
+        snapshot.record("transfer", 90)?;
+        snapshot.record("transfer", 110)?;
+        let err = snapshot
+            .record("transfer", 111)
+            .expect_err("should be a regression");
+
+        assert!(err.to_string().contains("gas regression in `transfer`"));
+        assert_eq!(fs::read_to_string(snapshot.path())?, "transfer = 100\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn snapshots_are_overwritten_when_updating() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        snapshot(&dir).record("transfer", 100)?;
+
+        snapshot(&dir).with_update(true).record("transfer", 150)?;
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("gas.snap"))?,
+            "transfer = 150\n"
+        );
+
+        Ok(())
+    }
+}
@@ -1,21 +1,26 @@
 //! Testing helpers/utilities for Fuel SDK.
 extern crate core;
 
+use std::path::Path;
+
 #[cfg(feature = "fuels-accounts")]
 pub use accounts::*;
+use fuel_core_chain_config::SnapshotWriter;
 use fuel_tx::{Bytes32, ConsensusParameters, ContractParameters, TxParameters, UtxoId};
 use fuel_types::{AssetId, Nonce};
 use fuels_accounts::provider::Provider;
 use fuels_core::types::{
     bech32::Bech32Address,
     coin::{Coin, CoinStatus},
-    errors::Result,
+    errors::{error, Result},
     message::{Message, MessageStatus},
 };
+pub use gas_snapshot::*;
 pub use node_types::*;
 use rand::Fill;
 use utils::{into_coin_configs, into_message_configs};
 pub use wallets_config::*;
+mod gas_snapshot;
 mod node_types;
 
 #[cfg(not(feature = "fuel-core-lib"))]
@@ -143,6 +148,24 @@ pub async fn setup_test_provider(
 
     let srv = FuelService::start(node_config, chain_config, state_config).await?;
 
+    connect_to_service(srv).await
+}
+
+/// Like [`setup_test_provider`], but the node starts from the snapshot in `snapshot_dir`, e.g.
+/// one written by [`write_snapshot`]. Together with [`NodeConfig::persist_state`], this boots
+/// a local network with a pre-built state that survives restarts.
+pub async fn setup_test_provider_from_snapshot(
+    snapshot_dir: impl AsRef<Path>,
+    node_config: Option<NodeConfig>,
+) -> Result<Provider> {
+    let srv =
+        FuelService::start_from_snapshot(node_config.unwrap_or_default(), snapshot_dir.as_ref())
+            .await?;
+
+    connect_to_service(srv).await
+}
+
+async fn connect_to_service(srv: FuelService) -> Result<Provider> {
     let address = srv.bound_address();
 
     tokio::spawn(async move {
@@ -153,6 +176,20 @@ pub async fn setup_test_provider(
     Provider::from(address).await
 }
 
+/// Writes a snapshot of the given chain and state into `dir`, in the JSON format read by
+/// `fuel-core --snapshot` and [`setup_test_provider_from_snapshot`].
+pub fn write_snapshot(
+    dir: impl AsRef<Path>,
+    chain_config: ChainConfig,
+    state_config: StateConfig,
+) -> Result<()> {
+    SnapshotWriter::json(dir.as_ref())
+        .write_state_config(state_config, &chain_config)
+        .map_err(|e| error!(Other, "could not write state config: {}", e))?;
+
+    Ok(())
+}
+
 // Testnet ChainConfig with increased tx size and contract size limits
 fn testnet_chain_config() -> ChainConfig {
     let mut consensus_parameters = ConsensusParameters::default();
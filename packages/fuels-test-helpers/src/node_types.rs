@@ -1,6 +1,7 @@
 use std::{
     net::{Ipv4Addr, SocketAddr},
     path::PathBuf,
+    sync::{Arc, Mutex},
     time::Duration,
 };
 
@@ -52,6 +53,17 @@ pub struct NodeConfig {
     pub block_production: Trigger,
     pub vm_backtrace: bool,
     pub silent: bool,
+    /// Captures the output of the node, which is otherwise only printed if the node exits.
+    /// Nothing is captured with the `fuel-core-lib` feature, as the node then logs through the
+    /// `tracing` subscriber of the test.
+    pub logs: Option<NodeLogs>,
+    /// The `RUST_LOG` filter of the node, e.g. `debug` or `fuel_core_txpool=trace`. Ignored with
+    /// the `fuel-core-lib` feature.
+    pub log_level: Option<String>,
+    /// Keeps the state of the node in a RocksDB database at this path, overriding
+    /// `database_type`. A node started again with the same path resumes from the stored state
+    /// instead of its snapshot. Requires the `rocksdb` feature with `fuel-core-lib`.
+    pub persist_state: Option<PathBuf>,
 }
 
 impl Default for NodeConfig {
@@ -65,6 +77,85 @@ impl Default for NodeConfig {
             block_production: Trigger::Instant,
             vm_backtrace: false,
             silent: true,
+            logs: None,
+            log_level: None,
+            persist_state: None,
         }
     }
 }
+
+/// A buffer receiving the output lines of a node, see [`NodeConfig::logs`]. Clones share the
+/// same buffer, so a clone can be kept to read the logs of the node while the test runs.
+#[derive(Clone, Debug, Default)]
+pub struct NodeLogs {
+    lines: Arc<Mutex<Vec<String>>>,
+}
+
+impl NodeLogs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The lines captured so far, stdout and stderr interleaved in the order they were read.
+    pub fn lines(&self) -> Vec<String> {
+        self.lock().clone()
+    }
+
+    pub fn contents(&self) -> String {
+        self.lock().join("\n")
+    }
+
+    /// Returns a guard printing the captured lines to stderr if it is dropped while the test is
+    /// panicking, e.g. because an assertion failed.
+    pub fn print_on_panic(&self) -> PrintLogsOnPanic {
+        PrintLogsOnPanic { logs: self.clone() }
+    }
+
+    #[cfg_attr(feature = "fuel-core-lib", allow(dead_code))]
+    pub(crate) async fn capture(&self, output: impl tokio::io::AsyncRead + Unpin) {
+        use tokio::io::AsyncBufReadExt;
+
+        let mut lines = tokio::io::BufReader::new(output).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            self.lock().push(line);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.lines
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Prints the logs of a node if dropped while panicking, see [`NodeLogs::print_on_panic`].
+#[derive(Debug)]
+#[must_use = "the logs are printed when the guard is dropped"]
+pub struct PrintLogsOnPanic {
+    logs: NodeLogs,
+}
+
+impl Drop for PrintLogsOnPanic {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            eprintln!("---- node logs ----\n{}", self.logs.contents());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn output_lines_are_captured() {
+        let logs = NodeLogs::new();
+
+        logs.clone()
+            .capture(&b"first line\nsecond line\n"[..])
+            .await;
+
+        assert_eq!(logs.lines(), ["first line", "second line"]);
+        assert_eq!(logs.contents(), "first line\nsecond line");
+    }
+}
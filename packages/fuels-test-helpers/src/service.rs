@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, path::PathBuf};
 
 #[cfg(feature = "fuel-core-lib")]
 use fuel_core::service::{Config as ServiceConfig, FuelService as CoreFuelService};
@@ -10,7 +10,17 @@ use fuels_core::types::errors::{error, Result};
 
 #[cfg(not(feature = "fuel-core-lib"))]
 use crate::fuel_bin_service::FuelService as BinFuelService;
-use crate::NodeConfig;
+use crate::{DbType, NodeConfig};
+
+/// The snapshot the node starts from.
+#[derive(Debug)]
+pub(crate) enum Snapshot {
+    Generated {
+        chain_config: ChainConfig,
+        state_config: StateConfig,
+    },
+    Existing(PathBuf),
+}
 
 pub struct FuelService {
     #[cfg(feature = "fuel-core-lib")]
@@ -26,16 +36,42 @@ impl FuelService {
         chain_config: ChainConfig,
         state_config: StateConfig,
     ) -> Result<Self> {
+        let snapshot = Snapshot::Generated {
+            chain_config,
+            state_config,
+        };
+
+        Self::start_with(node_config, snapshot).await
+    }
+
+    /// Starts a node from the snapshot in `snapshot_dir`, as written by
+    /// [`write_snapshot`](crate::write_snapshot) or exported from another node.
+    pub async fn start_from_snapshot(
+        node_config: NodeConfig,
+        snapshot_dir: impl Into<PathBuf>,
+    ) -> Result<Self> {
+        Self::start_with(node_config, Snapshot::Existing(snapshot_dir.into())).await
+    }
+
+    async fn start_with(node_config: NodeConfig, snapshot: Snapshot) -> Result<Self> {
+        let node_config = match node_config.persist_state.clone() {
+            Some(path) => NodeConfig {
+                database_type: DbType::RocksDb(Some(path)),
+                ..node_config
+            },
+            None => node_config,
+        };
+
         #[cfg(feature = "fuel-core-lib")]
         let service = {
-            let config = Self::service_config(node_config, chain_config, state_config);
+            let config = Self::service_config(node_config, snapshot)?;
             CoreFuelService::new_node(config)
                 .await
                 .map_err(|err| error!(Other, "{err}"))?
         };
 
         #[cfg(not(feature = "fuel-core-lib"))]
-        let service = BinFuelService::new_node(node_config, chain_config, state_config).await?;
+        let service = BinFuelService::new_node(node_config, snapshot).await?;
 
         let bound_address = service.bound_address;
 
@@ -60,17 +96,27 @@ impl FuelService {
     }
 
     #[cfg(feature = "fuel-core-lib")]
-    fn service_config(
-        node_config: NodeConfig,
-        chain_config: ChainConfig,
-        state_config: StateConfig,
-    ) -> ServiceConfig {
+    fn service_config(node_config: NodeConfig, snapshot: Snapshot) -> Result<ServiceConfig> {
         use fuel_core::combined_database::CombinedDatabaseConfig;
-        use fuel_core_chain_config::SnapshotReader;
+        use fuel_core_chain_config::{SnapshotMetadata, SnapshotReader};
 
-        use crate::{DbType, MAX_DATABASE_CACHE_SIZE};
+        use crate::MAX_DATABASE_CACHE_SIZE;
 
-        let snapshot_reader = SnapshotReader::new_in_memory(chain_config, state_config);
+        let snapshot_reader = match snapshot {
+            Snapshot::Generated {
+                chain_config,
+                state_config,
+            } => SnapshotReader::new_in_memory(chain_config, state_config),
+            Snapshot::Existing(path) => SnapshotMetadata::read(&path)
+                .and_then(SnapshotReader::open)
+                .map_err(|err| {
+                    error!(
+                        Other,
+                        "could not read the snapshot in `{}`: {err}",
+                        path.display()
+                    )
+                })?,
+        };
 
         let combined_db_config = CombinedDatabaseConfig {
             max_database_cache_size: node_config
@@ -82,7 +128,7 @@ impl FuelService {
             },
             database_type: node_config.database_type.into(),
         };
-        ServiceConfig {
+        Ok(ServiceConfig {
             addr: node_config.addr,
             combined_db_config,
             snapshot_reader,
@@ -90,6 +136,6 @@ impl FuelService {
             debug: node_config.debug,
             block_production: node_config.block_production.into(),
             ..ServiceConfig::local_node()
-        }
+        })
     }
 }
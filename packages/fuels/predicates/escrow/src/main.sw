@@ -0,0 +1,136 @@
+predicate;
+
+// Escrow holding `ASSET_ID` on behalf of `BUYER` until it is either released to `SELLER`,
+// refunded to `BUYER` or assigned by `ARBITER`. Used through `fuels::programs::escrow::Escrow`.
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    inputs::{
+        GTF_INPUT_TYPE,
+        input_count,
+    },
+    outputs::{
+        GTF_OUTPUT_TYPE,
+        output_asset_id,
+        output_asset_to,
+        output_count,
+    },
+    tx::{
+        GTF_SCRIPT_SCRIPT_LENGTH,
+        GTF_TYPE,
+        tx_id,
+        tx_maturity,
+        tx_witness_data,
+    },
+};
+
+// The transaction, input and output types as encoded by the VM.
+const TRANSACTION_TYPE_SCRIPT: u8 = 0;
+const INPUT_TYPE_CONTRACT: u8 = 1;
+const OUTPUT_TYPE_VARIABLE: u8 = 3;
+
+configurable {
+    BUYER: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    SELLER: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    ARBITER: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    ASSET_ID: AssetId = AssetId::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    DEADLINE: u32 = 0,
+}
+
+enum Action {
+    // Pays the seller, signed by the buyer.
+    Release: (),
+    // Pays back the buyer, signed by the seller or by anyone once `DEADLINE` is reached, in which
+    // case the transaction must have an empty script.
+    Refund: (),
+    // Pays either party, signed by the arbiter.
+    Arbitrate: Address,
+}
+
+// Predicates cannot read the chain state, the deadline is enforced through the maturity of the
+// transaction instead, which the VM rejects until the block at that height is produced.
+fn deadline_reached() -> bool {
+    match tx_maturity() {
+        Some(maturity) => maturity >= DEADLINE,
+        None => false,
+    }
+}
+
+fn signed_by(signer: Address, witness_index: u64) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_address(signature, tx_id()) {
+        Result::Ok(address) => address == signer,
+        _ => false,
+    }
+}
+
+// Checks that every output of the escrowed asset, change included, goes to `recipient`.
+fn pays_only(recipient: Address) -> bool {
+    let count = output_count().as_u64();
+    let mut index = 0;
+    while index < count {
+        let is_escrowed_asset = match output_asset_id(index) {
+            Some(asset_id) => asset_id == ASSET_ID,
+            None => false,
+        };
+
+        if is_escrowed_asset {
+            let pays_recipient = match output_asset_to(index) {
+                Some(to) => Address::from(to) == recipient,
+                None => false,
+            };
+            if !pays_recipient {
+                return false;
+            }
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+// Without a signature only the outputs may decide where the funds go. The script must be empty,
+// otherwise it could transfer them to a contract or to a variable output before the change is
+// computed. Contract inputs and variable outputs, the targets of such transfers, are rejected too.
+fn only_outputs_move_funds() -> bool {
+    if __gtf::<u8>(0, GTF_TYPE) != TRANSACTION_TYPE_SCRIPT
+        || __gtf::<u64>(0, GTF_SCRIPT_SCRIPT_LENGTH) != 0
+    {
+        return false;
+    }
+
+    let count = input_count().as_u64();
+    let mut index = 0;
+    while index < count {
+        if __gtf::<u8>(index, GTF_INPUT_TYPE) == INPUT_TYPE_CONTRACT {
+            return false;
+        }
+        index += 1;
+    }
+
+    let count = output_count().as_u64();
+    let mut index = 0;
+    while index < count {
+        if __gtf::<u8>(index, GTF_OUTPUT_TYPE) == OUTPUT_TYPE_VARIABLE {
+            return false;
+        }
+        index += 1;
+    }
+
+    true
+}
+
+fn main(action: Action, witness_index: u64) -> bool {
+    match action {
+        Action::Release => signed_by(BUYER, witness_index) && pays_only(SELLER),
+        Action::Refund => {
+            let approved = (deadline_reached() && only_outputs_move_funds()) || signed_by(SELLER, witness_index);
+            approved && pays_only(BUYER)
+        },
+        Action::Arbitrate(recipient) => {
+            (recipient == BUYER || recipient == SELLER) && signed_by(ARBITER, witness_index) && pays_only(recipient)
+        },
+    }
+}
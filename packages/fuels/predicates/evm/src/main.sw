@@ -0,0 +1,52 @@
+predicate;
+
+// Funds spendable by the Ethereum key whose address is `SIGNER`. Used through
+// `fuels::accounts::evm::EvmControlledAccount`.
+use std::{
+    b512::B512,
+    bytes::Bytes,
+    hash::keccak256,
+    tx::{
+        tx_id,
+        tx_witness_data,
+    },
+    vm::evm::{
+        ecr::ec_recover_evm_address,
+        evm_address::EvmAddress,
+    },
+};
+
+configurable {
+    // The 20-byte EVM address, left-padded with zeros.
+    SIGNER: b256 = 0x0000000000000000000000000000000000000000000000000000000000000000,
+}
+
+// `personal_sign` hashes `"\x19Ethereum Signed Message:\n" || len(message) || message`, the
+// message being the 32-byte transaction id.
+fn eip191_hash(message: b256) -> b256 {
+    let prefix = [
+        0x19u8, 0x45u8, 0x74u8, 0x68u8, 0x65u8, 0x72u8, 0x65u8, 0x75u8, 0x6du8, 0x20u8,
+        0x53u8, 0x69u8, 0x67u8, 0x6eu8, 0x65u8, 0x64u8, 0x20u8, 0x4du8, 0x65u8, 0x73u8,
+        0x73u8, 0x61u8, 0x67u8, 0x65u8, 0x3au8, 0x0au8, 0x33u8, 0x32u8,
+    ];
+
+    let mut data = Bytes::new();
+    let mut i = 0;
+    while i < 28 {
+        data.push(prefix[i]);
+        i += 1;
+    }
+    let mut message = Bytes::from(message);
+    data.append(message);
+
+    keccak256(data)
+}
+
+fn main(witness_index: u64) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_evm_address(signature, eip191_hash(tx_id())) {
+        Result::Ok(address) => address == EvmAddress::from(SIGNER),
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+predicate;
+
+// Funds spendable by `OWNER`, which `THRESHOLD` of the `GUARDIANS` can recover from the block at
+// `RECOVERY_HEIGHT`, e.g. after the owner lost their key. Used through
+// `fuels::programs::recovery::RecoveryPredicate`.
+use std::{
+    b512::B512,
+    constants::ZERO_B256,
+    ecr::ec_recover_address,
+    tx::{
+        tx_id,
+        tx_maturity,
+        tx_witness_data,
+        tx_witnesses_count,
+    },
+};
+
+configurable {
+    OWNER: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    // Unused slots are left as the zero address.
+    GUARDIANS: [Address; 5] = [
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+        Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    THRESHOLD: u64 = 1,
+    RECOVERY_HEIGHT: u32 = 0,
+}
+
+enum Action {
+    // Spent by the owner, whose signature is the witness at the given index.
+    Spend: u64,
+    // Recovered by the guardians, whose signatures can be any of the witnesses.
+    Recover: (),
+}
+
+// Predicates cannot read the chain state, the recovery height is enforced through the maturity of
+// the transaction instead, which the VM rejects until the block at that height is produced.
+fn recovery_open() -> bool {
+    match tx_maturity() {
+        Some(maturity) => maturity >= RECOVERY_HEIGHT,
+        None => false,
+    }
+}
+
+fn signer(witness_index: u64) -> Option<Address> {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_address(signature, tx_id()) {
+        Result::Ok(address) => Some(address),
+        _ => None,
+    }
+}
+
+// Counts the distinct guardians that signed the transaction.
+fn guardian_approvals() -> u64 {
+    let mut approved = [false, false, false, false, false];
+    let witness_count = tx_witnesses_count();
+
+    let mut witness_index = 0;
+    while witness_index < witness_count {
+        if let Some(address) = signer(witness_index) {
+            let mut guardian = 0;
+            while guardian < 5 {
+                if address == GUARDIANS[guardian] && address != Address::from(ZERO_B256) {
+                    approved[guardian] = true;
+                }
+                guardian += 1;
+            }
+        }
+        witness_index += 1;
+    }
+
+    let mut approvals = 0;
+    let mut guardian = 0;
+    while guardian < 5 {
+        if approved[guardian] {
+            approvals += 1;
+        }
+        guardian += 1;
+    }
+
+    approvals
+}
+
+fn main(action: Action) -> bool {
+    match action {
+        Action::Spend(witness_index) => match signer(witness_index) {
+            Some(address) => address == OWNER,
+            None => false,
+        },
+        Action::Recover => recovery_open() && guardian_approvals() >= THRESHOLD,
+    }
+}
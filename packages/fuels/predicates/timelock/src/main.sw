@@ -0,0 +1,40 @@
+predicate;
+
+// Funds spendable by `OWNER` from the block at `UNLOCK_HEIGHT`. Used through
+// `fuels::programs::timelock::TimelockPredicate`.
+use std::{
+    b512::B512,
+    ecr::ec_recover_address,
+    tx::{
+        tx_id,
+        tx_maturity,
+        tx_witness_data,
+    },
+};
+
+configurable {
+    OWNER: Address = Address::from(0x0000000000000000000000000000000000000000000000000000000000000000),
+    UNLOCK_HEIGHT: u32 = 0,
+}
+
+// Predicates cannot read the chain state, the lock is enforced through the maturity of the
+// transaction instead, which the VM rejects until the block at that height is produced.
+fn unlocked() -> bool {
+    match tx_maturity() {
+        Some(maturity) => maturity >= UNLOCK_HEIGHT,
+        None => false,
+    }
+}
+
+fn signed_by_owner(witness_index: u64) -> bool {
+    let signature: B512 = tx_witness_data(witness_index);
+
+    match ec_recover_address(signature, tx_id()) {
+        Result::Ok(address) => address == OWNER,
+        _ => false,
+    }
+}
+
+fn main(witness_index: u64) -> bool {
+    unlocked() && signed_by_owner(witness_index)
+}
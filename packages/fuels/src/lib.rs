@@ -11,16 +11,37 @@
 //!
 //! Examples on how you can use the types imported by the prelude can be found in
 //! the [test suite](https://github.com/FuelLabs/fuels-rs/tree/master/packages/fuels/tests)
+//!
+//! ## Features
+//!
+//! The default features are `std`, `coin-cache` and `subscriptions`. `std` enables:
+//!
+//! - `accounts`: providers, wallets and the other accounts,
+//! - `programs`: contract, script and predicate calls, needed by the bindings of `abigen!`.
+//!
+//! `subscriptions` enables GraphQL subscriptions to the node. The others are opt-in, e.g.:
+//!
+//! - `test-helpers`: launching local nodes, needed by `setup_program_test!`,
+//! - `signers-pkcs11`: signing with keys held by a PKCS#11 token.
+//!
+//! Apps that only query the node can disable the default features and enable `accounts`.
+//!
+//! The test helpers, e.g. `launch_provider_and_get_wallet` in the prelude, used to be enabled by
+//! default. Tests now have to enable the `test-helpers` feature.
+
+pub mod asm {
+    pub use fuel_asm::{op, Instruction, Opcode, RegId};
+}
 
 pub mod tx {
     pub use fuel_tx::{
         field, ConsensusParameters, ContractIdExt, ContractParameters, FeeParameters, GasCosts,
-        PredicateParameters, Receipt, ScriptExecutionResult, ScriptParameters, StorageSlot,
+        Output, PredicateParameters, Receipt, ScriptExecutionResult, ScriptParameters, StorageSlot,
         Transaction as FuelTransaction, TxId, TxParameters, TxPointer, UtxoId, Witness,
     };
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "accounts")]
 pub mod client {
     pub use fuel_core_client::client::{
         pagination::{PageDirection, PaginationRequest},
@@ -28,11 +49,15 @@ pub mod client {
     };
 }
 
+pub mod debug {
+    pub use fuels_core::debug::*;
+}
+
 pub mod macros {
     pub use fuels_macros::*;
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "programs")]
 pub mod programs {
     pub use fuels_programs::*;
 }
@@ -43,6 +68,7 @@ pub mod core {
 
 pub mod crypto {
     pub use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+    pub use fuels_core::crypto::*;
 }
 
 pub mod accounts {
@@ -53,7 +79,7 @@ pub mod types {
     pub use fuels_core::types::*;
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "test-helpers")]
 pub mod test_helpers {
     pub use fuels_test_helpers::*;
 }
@@ -69,26 +95,28 @@ pub mod prelude {
     //! # #![allow(unused_imports)]
     //! use fuels::prelude::*;
     //! ```
-    #[cfg(feature = "std")]
+    #[cfg(feature = "programs")]
+    pub use super::programs::{
+        call_utils::TxDependencyExtension,
+        contract::{
+            CallParameters, Contract, LoadConfiguration, MultiContractCallHandler,
+            SettableContract, StorageConfiguration,
+        },
+    };
+    #[cfg(feature = "test-helpers")]
+    pub use super::test_helpers::*;
+    #[cfg(feature = "accounts")]
     pub use super::{
         accounts::{
             predicate::Predicate,
             provider::*,
             wallet::{generate_mnemonic_phrase, WalletUnlocked},
-            Account, ViewOnlyAccount,
+            Account, CoinFilter, DynAccount, ViewOnlyAccount,
         },
         core::{
             codec::{LogDecoder, LogId, LogResult},
             traits::Signer,
         },
-        programs::{
-            call_utils::TxDependencyExtension,
-            contract::{
-                CallParameters, Contract, LoadConfiguration, MultiContractCallHandler,
-                SettableContract, StorageConfiguration,
-            },
-        },
-        test_helpers::*,
         types::transaction_builders::*,
     };
     pub use super::{
@@ -202,6 +202,134 @@ async fn shared_types() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contracts_implementing_the_same_interface() -> Result<()> {
+    // ANCHOR: interface_bindings
+    abigen!(
+        Interface(
+            name = "Counter",
+            abi = r#"
+            {
+                "types": [
+                    { "typeId": 0, "type": "u64", "components": null, "typeParameters": null }
+                ],
+                "functions": [
+                    {
+                        "inputs": [{ "name": "amount", "type": 0, "typeArguments": null }],
+                        "name": "increment",
+                        "output": { "name": "", "type": 0, "typeArguments": null }
+                    }
+                ]
+            }
+            "#
+        ),
+        Contract(
+            name = "SimpleCounter",
+            abi = r#"
+            {
+                "types": [
+                    { "typeId": 0, "type": "u64", "components": null, "typeParameters": null }
+                ],
+                "functions": [
+                    {
+                        "inputs": [{ "name": "amount", "type": 0, "typeArguments": null }],
+                        "name": "increment",
+                        "output": { "name": "", "type": 0, "typeArguments": null }
+                    }
+                ]
+            }
+            "#
+        ),
+        Contract(
+            name = "ResettableCounter",
+            abi = r#"
+            {
+                "types": [
+                    { "typeId": 0, "type": "u64", "components": null, "typeParameters": null }
+                ],
+                "functions": [
+                    {
+                        "inputs": [{ "name": "value", "type": 0, "typeArguments": null }],
+                        "name": "increment",
+                        "output": { "name": "", "type": 0, "typeArguments": null }
+                    },
+                    {
+                        "inputs": [],
+                        "name": "reset",
+                        "output": { "name": "", "type": 0, "typeArguments": null }
+                    }
+                ]
+            }
+            "#
+        ),
+    );
+
+    fn encoded_increment<T: Account>(counter: &impl Counter<T>, amount: u64) -> Vec<u8> {
+        let call_handler = counter.increment(amount);
+
+        call_handler.contract_call.encoded_args.unwrap().resolve(0)
+    }
+    // ANCHOR_END: interface_bindings
+
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    let simple = SimpleCounter::new(null_contract_id(), wallet.clone());
+    let resettable = ResettableCounter::new(null_contract_id(), wallet);
+
+    let expected = 42u64.to_be_bytes().to_vec();
+    assert_eq!(encoded_increment(&simple.methods(), 42), expected);
+    assert_eq!(encoded_increment(&resettable.methods(), 42), expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn type_attributes_are_added_to_generated_types() -> Result<()> {
+    // ANCHOR: type_attributes
+    abigen!(
+        Contract(
+            name = "Ledger",
+            abi = r#"
+            {
+                "types": [
+                    { "typeId": 0, "type": "u64", "components": null, "typeParameters": null },
+                    {
+                        "typeId": 1,
+                        "type": "enum Entry",
+                        "components": [
+                            { "name": "Credit", "type": 0, "typeArguments": null },
+                            { "name": "Debit", "type": 0, "typeArguments": null }
+                        ],
+                        "typeParameters": null
+                    }
+                ],
+                "functions": [
+                    {
+                        "inputs": [{ "name": "entry", "type": 1, "typeArguments": null }],
+                        "name": "record",
+                        "output": { "name": "", "type": 0, "typeArguments": null }
+                    }
+                ]
+            }
+            "#
+        ),
+        TypeAttributes(
+            types = "Entry",
+            attributes = "#[non_exhaustive] #[derive(Hash, PartialOrd, Ord)]"
+        ),
+    );
+    // ANCHOR_END: type_attributes
+
+    let entries = std::collections::BTreeSet::from([Entry::Debit(2), Entry::Credit(1)]);
+
+    assert_eq!(
+        entries.into_iter().collect::<Vec<_>>(),
+        [Entry::Credit(1), Entry::Debit(2)]
+    );
+
+    Ok(())
+}
+
 #[cfg(feature = "test-type-paths")]
 #[tokio::test]
 async fn type_paths_respected() -> Result<()> {
@@ -1,7 +1,11 @@
-use fuel_tx::ContractParameters;
+use fuel_tx::{ContractParameters, Output};
 use fuels::{
     core::codec::{calldata, encode_fn_selector, DecoderConfig, EncoderConfig},
+    debug::{Debugger, Stop},
     prelude::*,
+    programs::contract::{
+        Metadata, MetadataContract, Ownable, OwnershipState, Src3Contract, TokenContract,
+    },
     types::{errors::transaction::Reason, Bits256, Identity},
 };
 
@@ -137,6 +141,179 @@ async fn test_reverting_transaction() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn reverting_calls_can_be_traced() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "RevertContract",
+            project = "packages/fuels/tests/contracts/revert_transaction_error"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RevertContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_id: ContractId = contract_instance.contract_id().into();
+
+    // ANCHOR: execution_trace
+    let trace = contract_instance
+        .methods()
+        .make_transaction_fail(true)
+        .execution_trace(8)
+        .await?;
+
+    assert!(trace.failed());
+    for step in trace.last_steps() {
+        println!("{} {:#x} gas left: {}", step.contract, step.pc, step.gas);
+    }
+    // ANCHOR_END: execution_trace
+
+    let last_steps = trace.last_steps();
+    assert_eq!(last_steps.len(), 8);
+    assert!(last_steps.iter().all(|step| step
+        .registers
+        .as_ref()
+        .is_some_and(|registers| registers.len() == 64)));
+
+    let failing_step = last_steps.last().expect("has steps");
+    assert_eq!(failing_step.contract, contract_id);
+    assert!(trace
+        .steps()
+        .windows(2)
+        .all(|steps| steps[0].gas >= steps[1].gas));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_calls_can_be_debugged_locally() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "RevertContract",
+            project = "packages/fuels/tests/contracts/revert_transaction_error"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RevertContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let contract_id: ContractId = contract_instance.contract_id().into();
+    let bytecode = std::fs::read(
+        "tests/contracts/revert_transaction_error/out/release/revert_transaction_error.bin",
+    )?;
+
+    // ANCHOR: debug_contract_call
+    let tx = contract_instance
+        .methods()
+        .make_transaction_fail(true)
+        .build_tx()
+        .await?;
+
+    let mut debugger = Debugger::new(tx, provider.consensus_parameters())?.with_contract(
+        contract_id,
+        bytecode,
+        &[],
+    )?;
+    debugger.break_on_calls_to(contract_id);
+
+    assert_eq!(debugger.resume()?, Stop::ContractCall(contract_id));
+    // ANCHOR_END: debug_contract_call
+    assert_eq!(
+        debugger.location().map(|location| location.contract),
+        Some(contract_id)
+    );
+
+    assert!(matches!(debugger.resume()?, Stop::Finished(_)));
+    assert!(debugger
+        .receipts()
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Revert { ra: 128, .. })));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gas_used_by_calls_can_be_snapshotted() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let dir = tempfile::tempdir()?;
+    let snapshot_path = dir.path().join("gas.snap");
+
+    // ANCHOR: gas_snapshot
+    let snapshot = GasSnapshot::new(snapshot_path).with_tolerance(0.01);
+
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+    snapshot.record("initialize_counter", response.gas_used)?;
+    // ANCHOR_END: gas_snapshot
+
+    let err = snapshot
+        .with_update(false)
+        .record("initialize_counter", response.gas_used * 2)
+        .expect_err("should be a regression");
+    assert!(err.to_string().contains("gas regression"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_only_simulations_can_be_cached() -> Result<()> {
+    use fuels::programs::contract::ReadCallCache;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: read_call_cache
+    let read_cache = ReadCallCache::new(std::time::Duration::from_secs(10));
+    let contract_instance = contract_instance.with_read_cache(read_cache.clone());
+
+    let first = contract_instance.methods().get(5, 6).simulate().await?;
+    // served from the cache, as long as no block was produced in between
+    let second = contract_instance.methods().get(5, 6).simulate().await?;
+    // ANCHOR_END: read_call_cache
+
+    assert_eq!(first.value, 11);
+    assert_eq!(second.value, 11);
+    assert_eq!(read_cache.len(), 1);
+
+    contract_instance.methods().get(1, 2).simulate().await?;
+    assert_eq!(read_cache.len(), 2);
+
+    wallet.try_provider()?.produce_blocks(1, None).await?;
+    contract_instance.methods().get(5, 6).simulate().await?;
+    assert_eq!(read_cache.len(), 3);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multiple_read_calls() -> Result<()> {
     setup_program_test!(
@@ -168,6 +345,89 @@ async fn test_multiple_read_calls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn read_only_calls_can_be_simulated_for_unfunded_accounts() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "MultiReadContract",
+            project = "packages/fuels/tests/contracts/multiple_read_calls"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MultiReadContract",
+            wallet = "wallet"
+        ),
+    );
+    contract_instance.methods().store(42).call().await?;
+
+    let provider = wallet.try_provider()?.clone();
+    let unfunded_wallet = WalletUnlocked::new_random(Some(provider));
+    let contract_methods = contract_instance.with_account(unfunded_wallet).methods();
+
+    // the account has no coins to pay for the fee
+    assert!(contract_methods.read().simulate().await.is_err());
+
+    // ANCHOR: simulation_options
+    let options = SimulationOptions::default()
+        .with_utxo_validation(false)
+        .with_zero_gas_price(true);
+
+    let stored = contract_methods
+        .read()
+        .simulate_with_options(options)
+        .await?;
+    // ANCHOR_END: simulation_options
+
+    assert_eq!(stored.value, 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_only_methods_can_be_read_without_funds() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    contract_instance
+        .methods()
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    let provider = wallet.try_provider()?.clone();
+    let unfunded_wallet = WalletUnlocked::new_random(Some(provider));
+    let contract_methods = contract_instance.with_account(unfunded_wallet).methods();
+
+    // ANCHOR: read_mode
+    let counter = contract_methods.get_counter().read().await?;
+
+    // `increment_counter` is annotated with `#[storage(read, write)]`
+    let err = contract_methods
+        .increment_counter(1)
+        .read()
+        .await
+        .expect_err("should not read a method writing to storage");
+    // ANCHOR_END: read_mode
+
+    assert_eq!(counter.value, 42);
+    assert!(err.to_string().contains("use `call` instead"));
+
+    let sum = contract_methods.get(5, 6).read().await?;
+    assert_eq!(sum.value, 11);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_beginner() -> Result<()> {
     setup_program_test!(
@@ -295,6 +555,42 @@ async fn test_contract_call_fee_estimation() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contract_call_cost_can_be_estimated_at_a_given_gas_price() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: cost_estimation_at_gas_price
+    let cheap = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .estimate_transaction_cost_at_gas_price(None, 1)
+        .await?;
+    let expensive = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .estimate_transaction_cost_at_gas_price(None, 10)
+        .await?;
+    // ANCHOR_END: cost_estimation_at_gas_price
+
+    assert_eq!(cheap.gas_price, 1);
+    assert_eq!(expensive.gas_price, 10);
+    assert_eq!(cheap.gas_used, expensive.gas_used);
+    assert!(cheap.total_fee < expensive.total_fee);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn contract_call_has_same_estimated_and_used_gas() -> Result<()> {
     setup_program_test!(
@@ -761,6 +1057,57 @@ async fn test_output_variable_estimation_default_attempts() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn variable_output_policy_adds_the_needed_outputs() -> Result<()> {
+    abigen!(Contract(
+        name = "MyContract",
+        abi = "packages/fuels/tests/contracts/token_ops/out/release/token_ops-abi.json"
+    ));
+
+    let (wallets, addresses, mint_asset_id, contract_id) =
+        setup_output_variable_estimation_test().await?;
+
+    let contract_instance = MyContract::new(contract_id, wallets[0].clone());
+    let contract_methods = contract_instance.methods();
+    let amount = 1000;
+
+    let num_variable_outputs = |tx: &ScriptTransaction| {
+        tx.outputs()
+            .iter()
+            .filter(|output| matches!(output, Output::Variable { .. }))
+            .count()
+    };
+
+    for policy in [
+        VariableOutputPolicy::Exactly(3),
+        VariableOutputPolicy::EstimateMinimum,
+        VariableOutputPolicy::PerTransferInLogs,
+    ] {
+        let tx = contract_methods
+            .mint_to_addresses(amount, addresses)
+            .with_variable_output_policy(policy)
+            .build_tx()
+            .await?;
+
+        assert_eq!(num_variable_outputs(&tx), 3);
+    }
+
+    // ANCHOR: variable_output_policy
+    let _ = contract_methods
+        .mint_to_addresses(amount, addresses)
+        .with_variable_output_policy(VariableOutputPolicy::PerTransferInLogs)
+        .call()
+        .await?;
+    // ANCHOR_END: variable_output_policy
+
+    for wallet in wallets.iter() {
+        let balance = wallet.get_asset_balance(&mint_asset_id).await?;
+        assert_eq!(balance, amount);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_output_variable_estimation_multicall() -> Result<()> {
     abigen!(Contract(
@@ -850,6 +1197,12 @@ async fn test_contract_instance_get_balances() -> Result<()> {
     let random_asset_balance = contract_balances.get(random_asset_id).unwrap();
     assert_eq!(*random_asset_balance, amount);
 
+    assert_eq!(
+        contract_instance.get_asset_balance(random_asset_id).await?,
+        amount
+    );
+    assert_eq!(contract_instance.get_asset_balance(&asset_ids[0]).await?, 0);
+
     Ok(())
 }
 
@@ -1058,6 +1411,91 @@ async fn test_contract_call_with_non_default_max_input() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn transfers_can_be_attached_to_calls() -> Result<()> {
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(1), Some(DEFAULT_COIN_AMOUNT)),
+        None,
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+    let referrer = wallets.pop().unwrap();
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "MyContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "MyContract",
+            wallet = "wallet"
+        ),
+    );
+    let base_asset_id = *wallet.try_provider()?.base_asset_id();
+
+    // ANCHOR: add_transfer
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .add_transfer(referrer.address(), 1000, base_asset_id)
+        .call()
+        .await?;
+    // ANCHOR_END: add_transfer
+
+    assert_eq!(response.value, 42);
+    assert_eq!(
+        referrer.get_asset_balance(&base_asset_id).await?,
+        DEFAULT_COIN_AMOUNT + 1000
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transfers_are_reverted_with_the_call() -> Result<()> {
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(1), Some(DEFAULT_COIN_AMOUNT)),
+        None,
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+    let referrer = wallets.pop().unwrap();
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "RevertContract",
+            project = "packages/fuels/tests/contracts/revert_transaction_error"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "RevertContract",
+            wallet = "wallet"
+        ),
+    );
+    let base_asset_id = *wallet.try_provider()?.base_asset_id();
+
+    let response = contract_instance
+        .methods()
+        .make_transaction_fail(true)
+        .add_transfer(referrer.address(), 1000, base_asset_id)
+        .call()
+        .await;
+
+    assert!(matches!(
+        response,
+        Err(Error::Transaction(Reason::Reverted { revert_id, .. })) if revert_id == 128
+    ));
+    assert_eq!(
+        referrer.get_asset_balance(&base_asset_id).await?,
+        DEFAULT_COIN_AMOUNT
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_add_custom_assets() -> Result<()> {
     let initial_amount = 100_000;
@@ -1126,6 +1564,77 @@ async fn test_add_custom_assets() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn forward_multiple_assets_in_one_call() -> Result<()> {
+    let asset_ids = [
+        AssetId::zeroed(),
+        AssetId::from([1; 32]),
+        AssetId::from([2; 32]),
+    ];
+    let assets = asset_ids
+        .iter()
+        .map(|id| AssetConfig {
+            id: *id,
+            num_coins: 1,
+            coin_amount: 100_000,
+        })
+        .collect();
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new_multiple_assets(1, assets),
+        None,
+        None,
+    )
+    .await?;
+    let wallet = wallets.pop().unwrap();
+
+    setup_program_test!(
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/token_ops"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let contract_methods = contract_instance.methods();
+
+    // ANCHOR: forwarded_assets
+    let call_params = CallParameters::default()
+        .with_amount(100)
+        .with_forwarded_assets(vec![(asset_ids[1], 200), (asset_ids[2], 300)]);
+
+    let response = contract_methods
+        .get_msg_amount()
+        .call_params(call_params)?
+        .call()
+        .await?;
+    // ANCHOR_END: forwarded_assets
+
+    assert_eq!(response.value, 100);
+
+    let contract_id = contract_instance.contract_id();
+    for (asset_id, amount) in [(asset_ids[1], 200), (asset_ids[2], 300)] {
+        let balance = contract_methods
+            .get_balance(contract_id.into(), asset_id)
+            .call()
+            .await?
+            .value;
+        assert_eq!(balance, amount);
+    }
+
+    let err = contract_methods
+        .get_balance(contract_id.into(), asset_ids[1])
+        .call_params(CallParameters::default().with_forwarded_assets(vec![(asset_ids[1], 1)]))
+        .expect_err("should return error");
+    assert!(err
+        .to_string()
+        .contains("assets forwarded to non-payable method"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn contract_load_error_messages() {
     {
@@ -1502,6 +2011,55 @@ async fn can_configure_decoding_of_contract_return() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contract_instance_codec_config_is_applied() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "MyContract",
+            project = "packages/fuels/tests/contracts/needs_custom_decoder"
+        ),),
+        Deploy(
+            contract = "MyContract",
+            name = "contract_instance",
+            wallet = "wallet"
+        )
+    );
+
+    // ANCHOR: instance_codec_config
+    let strict_instance = contract_instance.with_codec_config(
+        EncoderConfig::default(),
+        DecoderConfig {
+            max_tokens: 100,
+            ..Default::default()
+        },
+    );
+    // ANCHOR_END: instance_codec_config
+
+    let error = strict_instance
+        .methods()
+        .i_return_a_1k_el_array()
+        .call()
+        .await
+        .expect_err("should fail because the output has more tokens than allowed");
+
+    assert!(error
+        .to_string()
+        .contains("token limit `100` reached while decoding"));
+
+    // the original instance keeps its own config
+    let result = contract_instance
+        .methods()
+        .i_return_a_1k_el_array()
+        .call()
+        .await?
+        .value;
+
+    assert_eq!(result, [0; 1000]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_contract_submit_and_response() -> Result<()> {
     setup_program_test!(
@@ -1863,3 +2421,292 @@ async fn test_reentrant_calls() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_proxy_deployment_and_upgrade() -> Result<()> {
+    abigen!(Contract(
+        name = "Versioned",
+        abi = "packages/fuels/tests/contracts/proxy_implementation/out/release/proxy_implementation-abi.json"
+    ));
+
+    let wallet_config = WalletsConfig::new(Some(2), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (owner, stranger) = (wallets[0].clone(), wallets[1].clone());
+
+    let implementation = |version: u64| -> Result<Contract> {
+        let configurables = VersionedConfigurables::default().with_VERSION(version)?;
+
+        Contract::load_from(
+            "tests/contracts/proxy_implementation/out/release/proxy_implementation.bin",
+            LoadConfiguration::default().with_configurables(configurables),
+        )
+    };
+    let proxy_contract = Contract::load_from(
+        "tests/contracts/proxy/out/release/proxy.bin",
+        LoadConfiguration::default(),
+    )?;
+
+    let mut proxy = implementation(1)?
+        .deploy_as_proxy(proxy_contract, owner.clone(), TxPolicies::default())
+        .await?;
+
+    assert_eq!(
+        proxy.owner().await?,
+        OwnershipState::Initialized(Identity::Address(owner.address().into()))
+    );
+
+    let instance = Versioned::new(proxy.contract_id().clone(), owner.clone());
+    let version = instance
+        .methods()
+        .version()
+        .with_contracts(&[&proxy])
+        .call()
+        .await?
+        .value;
+    assert_eq!(version, 1);
+
+    let new_implementation = implementation(2)?
+        .deploy(&owner, TxPolicies::default())
+        .await?;
+
+    let mut stranger_proxy = proxy.clone().with_account(stranger);
+    let err = stranger_proxy
+        .upgrade_to(&new_implementation)
+        .await
+        .expect_err("should fail");
+    assert!(err.to_string().contains("is not the owner of contract"));
+
+    proxy.upgrade_to(&new_implementation).await?;
+
+    let version = instance
+        .methods()
+        .version()
+        .with_contracts(&[&proxy])
+        .call()
+        .await?
+        .value;
+    assert_eq!(version, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_src20_token_metadata() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    let contract_id = Contract::load_from(
+        "tests/contracts/src20_token/out/release/src20_token.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    let token = TokenContract::new(contract_id.clone(), wallet);
+    let asset_id = contract_id.asset_id(&Bits256::zeroed());
+
+    assert_eq!(token.total_assets().await?, 1);
+    assert_eq!(
+        token.name(asset_id).await?.as_deref(),
+        Some("Fuel Test Token")
+    );
+    assert_eq!(token.symbol(asset_id).await?.as_deref(), Some("FTT"));
+    assert_eq!(token.decimals(asset_id).await?, Some(9));
+
+    let total_supply = token
+        .total_supply(asset_id)
+        .await?
+        .expect("asset is minted by the contract");
+    assert_eq!(token.format_amount(asset_id, total_supply).await?, "1.5");
+    assert_eq!(token.parse_amount(asset_id, "1.5").await?, total_supply);
+
+    let unknown_asset = AssetId::zeroed();
+    assert_eq!(token.total_supply(unknown_asset).await?, None);
+    token
+        .format_amount(unknown_asset, 1)
+        .await
+        .expect_err("should fail without decimals");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_src3_mint_and_burn() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    let contract_id = Contract::load_from(
+        "tests/contracts/src3_token/out/release/src3_token.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    let src3 = Src3Contract::new(contract_id, wallet.clone());
+    let sub_id = Bits256::zeroed();
+    let asset_id = src3.asset_id(sub_id);
+
+    let recipient = Identity::Address(wallet.address().into());
+    src3.mint(recipient, sub_id, 100).call().await?;
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 100);
+
+    src3.burn(sub_id, 40)?.call().await?;
+    assert_eq!(wallet.get_asset_balance(&asset_id).await?, 60);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_src7_asset_metadata() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+
+    let contract_id = Contract::load_from(
+        "tests/contracts/src20_token/out/release/src20_token.bin",
+        LoadConfiguration::default(),
+    )?
+    .deploy(&wallet, TxPolicies::default())
+    .await?;
+
+    let metadata = MetadataContract::new(contract_id.clone(), wallet);
+    let asset_id = contract_id.asset_id(&Bits256::zeroed());
+
+    assert_eq!(
+        metadata.description(asset_id).await?.as_deref(),
+        Some("A token used in tests")
+    );
+    assert_eq!(
+        metadata.metadata(asset_id, "image").await?,
+        Some(Metadata::Int(42))
+    );
+    assert_eq!(metadata.metadata(asset_id, "unknown").await?, None);
+    assert_eq!(metadata.description(AssetId::zeroed()).await?, None);
+
+    let err = metadata
+        .image_uri(asset_id)
+        .await
+        .expect_err("image metadata is not a string");
+    assert!(err.to_string().contains("to be a string"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_src5_ownership() -> Result<()> {
+    setup_program_test!(
+        Wallets("owner", "new_owner"),
+        Abigen(Contract(
+            name = "OwnableContract",
+            project = "packages/fuels/tests/contracts/ownable"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "OwnableContract",
+            wallet = "owner"
+        ),
+    );
+    let contract_id = contract_instance.contract_id().clone();
+
+    let ownable = Ownable::new(contract_id.clone(), owner.clone());
+    assert_eq!(ownable.owner().await?, OwnershipState::Uninitialized);
+
+    contract_instance.methods().constructor().call().await?;
+    assert!(ownable.is_owner().await?);
+
+    let not_owner = Ownable::new(contract_id.clone(), new_owner.clone());
+    not_owner
+        .renounce_ownership()
+        .await
+        .expect_err("should fail when not the owner");
+
+    let new_owner_identity = Identity::Address(new_owner.address().into());
+    ownable
+        .transfer_ownership(new_owner_identity)
+        .await?
+        .call()
+        .await?;
+    assert_eq!(
+        ownable.owner().await?,
+        OwnershipState::Initialized(new_owner_identity)
+    );
+    assert!(not_owner.is_owner().await?);
+
+    not_owner.renounce_ownership().await?.call().await?;
+    assert_eq!(ownable.owner().await?, OwnershipState::Revoked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn deploy_cost_can_be_estimated_before_deploying() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    let binary_path = "tests/contracts/contract_test/out/release/contract_test.bin";
+    let binary_len = std::fs::read(binary_path)?.len() as u64;
+
+    // ANCHOR: estimate_deploy_cost
+    let contract = Contract::load_from(binary_path, LoadConfiguration::default())?;
+
+    let cost = contract
+        .estimate_deploy_cost(provider, TxPolicies::default())
+        .await?;
+    cost.check_limits()?;
+    // ANCHOR_END: estimate_deploy_cost
+
+    assert_eq!(cost.bytecode_size, binary_len);
+    assert!(cost.fee > 0);
+    assert!(cost.tx_size > cost.bytecode_size);
+    assert!(cost.storage_slots_fee <= cost.fee);
+
+    let balance_before = wallet.get_asset_balance(provider.base_asset_id()).await?;
+    contract.deploy(&wallet, TxPolicies::default()).await?;
+    let balance_after = wallet.get_asset_balance(provider.base_asset_id()).await?;
+
+    assert!(balance_before - balance_after <= cost.fee);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn custom_instructions_are_executed_around_the_call() -> Result<()> {
+    use fuels::{
+        asm::{op, RegId},
+        tx::Receipt,
+    };
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+
+    // ANCHOR: call_prologue_epilogue
+    let response = contract_instance
+        .methods()
+        .initialize_counter(42)
+        .with_prologue(vec![op::movi(0x20, 1337)])
+        .with_epilogue(vec![op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO)])
+        .call()
+        .await?;
+    // ANCHOR_END: call_prologue_epilogue
+
+    assert_eq!(response.value, 42);
+    assert!(response
+        .receipts
+        .iter()
+        .any(|receipt| matches!(receipt, Receipt::Log { ra: 1337, .. })));
+
+    let mut multi_call_handler = MultiContractCallHandler::new(wallet.clone())
+        .with_epilogue(vec![op::log(0x20, RegId::ZERO, RegId::ZERO, RegId::ZERO)]);
+    multi_call_handler.add_call(contract_instance.methods().get_counter());
+
+    let (counter,): (u64,) = multi_call_handler.call().await?.value;
+    assert_eq!(counter, 42);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+contract;
+
+enum State {
+    Uninitialized: (),
+    Initialized: Identity,
+    Revoked: (),
+}
+
+abi SRC5 {
+    #[storage(read)]
+    fn owner() -> State;
+}
+
+abi Ownership {
+    #[storage(read, write)]
+    fn constructor();
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity);
+
+    #[storage(read, write)]
+    fn renounce_ownership();
+}
+
+storage {
+    owner: State = State::Uninitialized,
+}
+
+#[storage(read)]
+fn only_owner() {
+    let is_owner = match storage.owner.read() {
+        State::Initialized(owner) => owner == msg_sender().unwrap(),
+        _ => false,
+    };
+    require(is_owner, "not the owner");
+}
+
+impl SRC5 for Contract {
+    #[storage(read)]
+    fn owner() -> State {
+        storage.owner.read()
+    }
+}
+
+impl Ownership for Contract {
+    #[storage(read, write)]
+    fn constructor() {
+        let uninitialized = match storage.owner.read() {
+            State::Uninitialized => true,
+            _ => false,
+        };
+        require(uninitialized, "already initialized");
+
+        storage.owner.write(State::Initialized(msg_sender().unwrap()));
+    }
+
+    #[storage(read, write)]
+    fn transfer_ownership(new_owner: Identity) {
+        only_owner();
+        storage.owner.write(State::Initialized(new_owner));
+    }
+
+    #[storage(read, write)]
+    fn renounce_ownership() {
+        only_owner();
+        storage.owner.write(State::Revoked);
+    }
+}
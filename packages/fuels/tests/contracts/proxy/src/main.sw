@@ -0,0 +1,57 @@
+contract;
+
+use std::{execution::run_external, storage::storage_api::{read, write}};
+
+// Storage layout of the SRC-14 reference owned proxy, whose `SRC14` storage namespace holds the
+// target as an `Option<ContractId>` and the owner as a `State`.
+// sha256("storage_SRC14_0")
+const TARGET_KEY: b256 = 0x7bb458adc1d118713319a5baa00a2d049dd64d2916477d2688d76970c898cd55;
+// sha256("storage_SRC14_1")
+const OWNER_KEY: b256 = 0xbb79927b15d9259ea316f2ecb2297d6cc8851888a98278c0a2e03e1a091ea754;
+
+enum State {
+    Uninitialized: (),
+    Initialized: Identity,
+    Revoked: (),
+}
+
+abi OwnedProxy {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId);
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId>;
+
+    #[storage(read)]
+    fn proxy_owner() -> State;
+}
+
+impl OwnedProxy for Contract {
+    #[storage(read, write)]
+    fn set_proxy_target(new_target: ContractId) {
+        let sender = msg_sender().unwrap();
+        let is_owner = match read::<State>(OWNER_KEY, 0) {
+            Some(State::Initialized(owner)) => owner == sender,
+            _ => false,
+        };
+        require(is_owner, "only the proxy owner can change the target");
+
+        write(TARGET_KEY, 0, Some(new_target));
+    }
+
+    #[storage(read)]
+    fn proxy_target() -> Option<ContractId> {
+        read::<Option<ContractId>>(TARGET_KEY, 0).unwrap_or(None)
+    }
+
+    #[storage(read)]
+    fn proxy_owner() -> State {
+        read::<State>(OWNER_KEY, 0).unwrap_or(State::Uninitialized)
+    }
+}
+
+#[fallback]
+#[storage(read)]
+fn fallback() {
+    run_external(read::<Option<ContractId>>(TARGET_KEY, 0).unwrap().unwrap())
+}
@@ -0,0 +1,15 @@
+contract;
+
+configurable {
+    VERSION: u64 = 1,
+}
+
+abi Versioned {
+    fn version() -> u64;
+}
+
+impl Versioned for Contract {
+    fn version() -> u64 {
+        VERSION
+    }
+}
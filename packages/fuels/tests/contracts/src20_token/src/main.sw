@@ -0,0 +1,96 @@
+contract;
+
+use std::{bytes::Bytes, string::String};
+
+enum Metadata {
+    B256: b256,
+    Bytes: Bytes,
+    Int: u64,
+    String: String,
+}
+
+abi SRC20 {
+    #[storage(read)]
+    fn total_assets() -> u64;
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64>;
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String>;
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8>;
+}
+
+abi SRC7 {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata>;
+}
+
+storage {
+    total_supply: u64 = 1_500_000_000,
+}
+
+impl SRC20 for Contract {
+    #[storage(read)]
+    fn total_assets() -> u64 {
+        1
+    }
+
+    #[storage(read)]
+    fn total_supply(asset: AssetId) -> Option<u64> {
+        if asset == AssetId::default() {
+            Some(storage.total_supply.read())
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn name(asset: AssetId) -> Option<String> {
+        if asset == AssetId::default() {
+            Some(String::from_ascii_str("Fuel Test Token"))
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn symbol(asset: AssetId) -> Option<String> {
+        if asset == AssetId::default() {
+            Some(String::from_ascii_str("FTT"))
+        } else {
+            None
+        }
+    }
+
+    #[storage(read)]
+    fn decimals(asset: AssetId) -> Option<u8> {
+        if asset == AssetId::default() {
+            Some(9u8)
+        } else {
+            None
+        }
+    }
+}
+
+impl SRC7 for Contract {
+    #[storage(read)]
+    fn metadata(asset: AssetId, key: String) -> Option<Metadata> {
+        if asset != AssetId::default() {
+            return None;
+        }
+
+        if key == String::from_ascii_str("description") {
+            Some(Metadata::String(String::from_ascii_str("A token used in tests")))
+        } else if key == String::from_ascii_str("image") {
+            Some(Metadata::Int(42))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,34 @@
+contract;
+
+use std::{
+    asset::{
+        burn,
+        mint_to,
+    },
+    call_frames::msg_asset_id,
+    context::msg_amount,
+};
+
+abi SRC3 {
+    fn mint(recipient: Identity, sub_id: b256, amount: u64);
+
+    #[payable]
+    fn burn(sub_id: b256, amount: u64);
+}
+
+impl SRC3 for Contract {
+    fn mint(recipient: Identity, sub_id: b256, amount: u64) {
+        mint_to(recipient, sub_id, amount);
+    }
+
+    #[payable]
+    fn burn(sub_id: b256, amount: u64) {
+        require(msg_amount() == amount, "incorrect amount provided");
+        require(
+            msg_asset_id() == AssetId::new(ContractId::this(), sub_id),
+            "incorrect asset provided",
+        );
+
+        burn(sub_id, amount);
+    }
+}
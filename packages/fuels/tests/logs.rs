@@ -1,7 +1,8 @@
 use fuels::{
     core::codec::DecoderConfig,
     prelude::*,
-    types::{errors::transaction::Reason, Bits256, SizedAsciiString},
+    programs::{receipt_decoder::ReceiptDecoder, receipt_sink::ReceiptSink},
+    types::{errors::transaction::Reason, Bits256, DynamicValue, SizedAsciiString},
 };
 
 #[tokio::test]
@@ -1560,3 +1561,184 @@ async fn script_experimental_log() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn logs_and_calls_are_decoded_with_the_json_abi() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+    let tx_id = response.tx_id.expect("tx id is set for submitted calls");
+
+    let json_abi =
+        std::fs::read_to_string("tests/logs/contract_logs/out/release/contract_logs-abi.json")?;
+    let decoded = ReceiptDecoder::new(&json_abi)?
+        .decode_transaction(wallet.try_provider()?, &tx_id)
+        .await?;
+
+    assert_eq!(decoded.calls.len(), 1);
+    assert_eq!(decoded.calls[0].function, "produce_logs_custom_types");
+    assert_eq!(decoded.calls[0].return_value, Some(DynamicValue::Unit));
+
+    assert_eq!(decoded.logs.len(), 3);
+    let DynamicValue::Struct { fields, .. } = &decoded.logs[0].value else {
+        panic!("expected a struct, got: {:?}", decoded.logs[0].value);
+    };
+    assert_eq!(fields[0], ("field_1".to_string(), DynamicValue::Bool(true)));
+    assert_eq!(fields[2], ("field_3".to_string(), DynamicValue::U64(64)));
+    let DynamicValue::Enum { variant, .. } = &decoded.logs[1].value else {
+        panic!("expected an enum, got: {:?}", decoded.logs[1].value);
+    };
+    assert_eq!(variant, "VariantTwo");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn receipts_of_calls_are_captured_by_the_installed_sink() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let sink = ReceiptSink::new();
+    sink.install();
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_values()
+        .call()
+        .await?;
+    let simulated = contract_instance
+        .methods()
+        .produce_logs_values()
+        .simulate()
+        .await?;
+
+    ReceiptSink::uninstall();
+
+    // other tests may run while the sink is installed
+    let captured = sink.captured();
+    let call = captured
+        .iter()
+        .find(|captured| captured.tx_id == response.tx_id)
+        .expect("call should be captured");
+    assert_eq!(call.status, "success");
+    assert_eq!(call.gas_used, response.gas_used);
+    assert_eq!(call.logs, response.decode_logs().filter_succeeded());
+    let simulation = captured
+        .iter()
+        .find(|captured| captured.tx_id == simulated.tx_id)
+        .expect("simulation should be captured");
+    assert!(simulation.simulated);
+    assert!(!call.simulated);
+
+    let csv = sink.to_csv();
+    assert!(csv.contains(&response.tx_id.expect("is set").to_string()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn call_responses_are_exported_as_json() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+
+    let response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+
+    let json = response.to_json()?;
+
+    assert_eq!(json["value"]["type"], "()");
+    assert_eq!(json["gas_used"], response.gas_used);
+    assert_eq!(json["logs"][0]["type"], "TestStruct");
+    assert_eq!(json["logs"][1]["type"], "TestEnum");
+    assert_eq!(json["logs"][1]["value"]["Enum"]["variant"], "VariantTwo");
+    assert_eq!(json["receipts"]["count"], response.receipts.len());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn contract_events_are_backfilled_and_streamed() -> Result<()> {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "LogContract",
+            project = "packages/fuels/tests/logs/contract_logs"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "LogContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+
+    let first_response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+    let first_height = provider.latest_block_height().await?;
+
+    let mut events = contract_instance
+        .subscribe_events::<TestStruct>(Some(first_height), Duration::from_millis(100))?;
+
+    let (block_height, tx_id, event) = events.next().await.expect("backfilled event")?;
+    assert_eq!(block_height, first_height.into());
+    assert_eq!(Some(tx_id), first_response.tx_id);
+    assert!(event.field_1);
+    assert_eq!(event.field_3, 64);
+
+    let second_response = contract_instance
+        .methods()
+        .produce_logs_custom_types()
+        .call()
+        .await?;
+
+    let (block_height, tx_id, _) = events.next().await.expect("live event")?;
+    assert!(block_height > first_height.into());
+    assert_eq!(Some(tx_id), second_response.tx_id);
+
+    Ok(())
+}
@@ -1,11 +1,19 @@
 use std::{default::Default, str::FromStr};
 
+use fuel_asm::GTFArgs;
 use fuels::{
+    asm::{op, RegId},
     core::{
         codec::{ABIEncoder, EncoderConfig},
         traits::Tokenizable,
     },
     prelude::*,
+    programs::{
+        accounts_config::Accounts,
+        escrow::{Escrow, EscrowTerms},
+        recovery::{RecoveryPredicate, RecoveryTerms},
+        timelock::TimelockPredicate,
+    },
     types::{coin::Coin, coin_type::CoinType, input::Input, message::Message, output::Output},
 };
 
@@ -180,6 +188,125 @@ async fn spend_predicate_coins_messages_basic() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn wallets_and_predicates_can_be_used_as_dyn_accounts() -> Result<()> {
+    abigen!(Predicate(
+        name = "MyPredicate",
+        abi =
+            "packages/fuels/tests/predicates/basic_predicate/out/release/basic_predicate-abi.json"
+    ));
+
+    let predicate_data = MyPredicateEncoder::default().encode_data(4097, 4097)?;
+    let mut predicate: Predicate =
+        Predicate::load_from("tests/predicates/basic_predicate/out/release/basic_predicate.bin")?
+            .with_data(predicate_data);
+
+    let amount = 16;
+    let (provider, predicate_balance, receiver, receiver_balance, asset_id) =
+        setup_predicate_test(predicate.address(), 1, 0, amount).await?;
+    predicate.set_provider(provider.clone());
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    wallet.set_provider(provider.clone());
+    predicate
+        .transfer(
+            wallet.address(),
+            amount / 2,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    // ANCHOR: dyn_account
+    let accounts = vec![DynAccount::new(wallet), DynAccount::new(predicate)];
+
+    for account in &accounts {
+        let balance = account.get_asset_balance(&asset_id).await?;
+        account
+            .transfer(receiver.address(), balance, asset_id, TxPolicies::default())
+            .await?;
+    }
+    // ANCHOR_END: dyn_account
+
+    for account in &accounts {
+        assert_address_balance(account.address(), &provider, asset_id, 0).await;
+    }
+    assert_address_balance(
+        receiver.address(),
+        &provider,
+        asset_id,
+        receiver_balance + predicate_balance,
+    )
+    .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn accounts_can_be_loaded_from_a_config_file() -> Result<()> {
+    let config_dir = tempfile::tempdir()?;
+    for file in ["basic_predicate.bin", "basic_predicate-abi.json"] {
+        std::fs::copy(
+            format!("tests/predicates/basic_predicate/out/release/{file}"),
+            config_dir.path().join(file),
+        )?;
+    }
+
+    let phrase = "oblige salon price punch saddle immune slogan rare snap desert retire surprise";
+    std::env::set_var("ACCOUNTS_CONFIG_TEST_MNEMONIC", phrase);
+
+    // ANCHOR: accounts_config
+    let config = r#"
+        [accounts.alice]
+        type = "mnemonic"
+        mnemonic_env = "ACCOUNTS_CONFIG_TEST_MNEMONIC"
+
+        [accounts.vault]
+        type = "predicate"
+        code = "basic_predicate.bin"
+        abi = "basic_predicate-abi.json"
+        data = [{ U64 = 4097 }, { U64 = 4097 }]
+    "#;
+    // ANCHOR_END: accounts_config
+    let config_path = config_dir.path().join("accounts.toml");
+    std::fs::write(&config_path, config)?;
+
+    let vault_address =
+        Predicate::load_from("tests/predicates/basic_predicate/out/release/basic_predicate.bin")?
+            .address()
+            .clone();
+    let amount = 16;
+    let (provider, predicate_balance, _receiver, _receiver_balance, asset_id) =
+        setup_predicate_test(&vault_address, 1, 0, amount).await?;
+
+    // ANCHOR: load_accounts
+    let accounts = Accounts::load_from_toml(&config_path, provider.clone())?;
+
+    let alice = accounts.get_as::<WalletUnlocked>("alice").unwrap();
+    let vault = accounts.get("vault").unwrap();
+    // ANCHOR_END: load_accounts
+
+    assert_eq!(accounts.len(), 2);
+    assert_eq!(
+        alice.address(),
+        WalletUnlocked::new_from_mnemonic_phrase(phrase, None)?.address()
+    );
+    assert_eq!(vault.address(), &vault_address);
+
+    // spending succeeds only if the predicate data was encoded correctly
+    vault
+        .transfer(
+            alice.address(),
+            predicate_balance,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    assert_address_balance(alice.address(), &provider, asset_id, predicate_balance).await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn pay_with_predicate() -> Result<()> {
     abigen!(
@@ -1091,3 +1218,388 @@ async fn predicate_validation() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn escrow_spend_paths() -> Result<()> {
+    let wallet_config = WalletsConfig::new(Some(3), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (buyer, seller, arbiter) = (&wallets[0], &wallets[1], &wallets[2]);
+    let provider = buyer.try_provider()?.clone();
+    let asset_id = *provider.base_asset_id();
+
+    let escrow = |deadline| {
+        let terms = EscrowTerms {
+            buyer: buyer.address().clone(),
+            seller: seller.address().clone(),
+            arbiter: arbiter.address().clone(),
+            asset_id,
+            deadline,
+        };
+
+        Escrow::load_from("predicates/escrow/out/release/escrow.bin", terms)
+            .map(|escrow| escrow.with_provider(provider.clone()))
+    };
+    let escrowed_amount = 1000;
+
+    // the buyer releases the funds to the seller
+    let escrow_to_release = escrow(u32::MAX)?;
+    buyer
+        .transfer(
+            escrow_to_release.address(),
+            escrowed_amount,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    escrow_to_release
+        .release(seller.clone())
+        .await
+        .expect_err("only the buyer can release");
+
+    let seller_balance = seller.get_asset_balance(&asset_id).await?;
+    let tb = escrow_to_release.release(buyer.clone()).await?;
+    submit_predicate_spend(tb, &provider).await?;
+    assert!(seller.get_asset_balance(&asset_id).await? > seller_balance);
+    assert_eq!(
+        escrow_to_release
+            .predicate()
+            .get_asset_balance(&asset_id)
+            .await?,
+        0
+    );
+
+    // the arbiter settles in favor of the buyer
+    let escrow_to_arbitrate = escrow(u32::MAX - 1)?;
+    buyer
+        .transfer(
+            escrow_to_arbitrate.address(),
+            escrowed_amount,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    escrow_to_arbitrate
+        .arbitrate(arbiter.clone(), arbiter.address())
+        .await
+        .expect_err("the arbiter cannot keep the funds");
+
+    let tb = escrow_to_arbitrate
+        .arbitrate(arbiter.clone(), buyer.address())
+        .await?;
+    submit_predicate_spend(tb, &provider).await?;
+    assert_eq!(
+        escrow_to_arbitrate
+            .predicate()
+            .get_asset_balance(&asset_id)
+            .await?,
+        0
+    );
+
+    // anyone can refund the buyer once the deadline is reached
+    let escrow_to_refund = escrow(0)?;
+    buyer
+        .transfer(
+            escrow_to_refund.address(),
+            escrowed_amount,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let tb = escrow_to_refund.refund_after_deadline().await?;
+    submit_predicate_spend(tb, &provider).await?;
+    assert_eq!(
+        escrow_to_refund
+            .predicate()
+            .get_asset_balance(&asset_id)
+            .await?,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn escrow_refund_without_signature_cannot_be_redirected() -> Result<()> {
+    let wallet_config = WalletsConfig::new(Some(3), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (buyer, seller, attacker) = (&wallets[0], &wallets[1], &wallets[2]);
+    let provider = buyer.try_provider()?.clone();
+    let asset_id = *provider.base_asset_id();
+
+    let terms = EscrowTerms {
+        buyer: buyer.address().clone(),
+        seller: seller.address().clone(),
+        arbiter: seller.address().clone(),
+        asset_id,
+        deadline: 0,
+    };
+    let escrow = Escrow::load_from("predicates/escrow/out/release/escrow.bin", terms)?
+        .with_provider(provider.clone());
+    let escrowed_amount = 1000;
+    buyer
+        .transfer(
+            escrow.address(),
+            escrowed_amount,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+
+    // the change still goes to the buyer, but the script first sends half of the escrowed funds
+    // to the attacker through a variable output
+    let script_data = [
+        Address::from(attacker.address()).to_vec(),
+        asset_id.to_vec(),
+    ]
+    .concat();
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as u16),
+        op::movi(0x12, (escrowed_amount / 2) as u32),
+        op::movi(0x13, 1),
+        op::tro(0x10, 0x13, 0x12, 0x11),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect::<Vec<u8>>();
+
+    let tb = escrow.refund_after_deadline().await?;
+    let tx_policies = tb.tx_policies.with_script_gas_limit(1_000_000);
+    let mut tb = tb
+        .with_tx_policies(tx_policies)
+        .with_script(script)
+        .with_script_data(script_data);
+    tb.outputs_mut()
+        .push(Output::variable(Address::zeroed(), 0, AssetId::zeroed()));
+
+    let err = submit_predicate_spend(tb, &provider)
+        .await
+        .expect_err("the unsigned refund path only accepts empty scripts");
+    assert!(err.to_string().contains("PredicateVerificationFailed"));
+
+    assert_eq!(
+        escrow.predicate().get_asset_balance(&asset_id).await?,
+        escrowed_amount
+    );
+
+    Ok(())
+}
+
+async fn submit_predicate_spend(tb: ScriptTransactionBuilder, provider: &Provider) -> Result<()> {
+    let tx = tb.build(provider).await?;
+
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await?
+        .check(None)
+}
+
+#[tokio::test]
+async fn timelock_is_spendable_by_the_owner_from_the_unlock_height() -> Result<()> {
+    let wallet_config = WalletsConfig::new(Some(2), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (owner, receiver) = (&wallets[0], &wallets[1]);
+    let provider = owner.try_provider()?.clone();
+    let asset_id = *provider.base_asset_id();
+
+    let unlock_height = provider.latest_block_height().await? + 5;
+    let timelock = TimelockPredicate::until_height(
+        "predicates/timelock/out/release/timelock.bin",
+        unlock_height,
+        owner.clone(),
+    )?
+    .with_provider(provider.clone());
+
+    owner
+        .transfer(timelock.address(), 1000, asset_id, TxPolicies::default())
+        .await?;
+
+    let err = timelock
+        .transfer(receiver.address(), 500, asset_id, TxPolicies::default())
+        .await
+        .expect_err("should fail before the unlock height");
+    assert!(err
+        .to_string()
+        .contains(&format!("is locked until block height {unlock_height}")));
+
+    provider.produce_blocks(5, None).await?;
+    assert!(timelock.is_unlocked().await?);
+
+    let receiver_balance = receiver.get_asset_balance(&asset_id).await?;
+    timelock
+        .transfer(receiver.address(), 500, asset_id, TxPolicies::default())
+        .await?;
+    assert_eq!(
+        receiver.get_asset_balance(&asset_id).await?,
+        receiver_balance + 500
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recovery_predicate_owner_and_guardian_paths() -> Result<()> {
+    let wallet_config = WalletsConfig::new(Some(5), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (owner, new_owner, receiver) = (&wallets[0], &wallets[1], &wallets[2]);
+    let guardians = [wallets[3].clone(), wallets[4].clone()];
+    let provider = owner.try_provider()?.clone();
+    let asset_id = *provider.base_asset_id();
+
+    let terms = RecoveryTerms {
+        owner: owner.address().clone(),
+        guardians: guardians.iter().map(|g| g.address().clone()).collect(),
+        threshold: 2,
+        delay: 5,
+    };
+    let recovery_height = provider.latest_block_height().await? + terms.delay;
+    let recovery = RecoveryPredicate::load_from(
+        "predicates/recovery/out/release/recovery.bin",
+        terms,
+        recovery_height,
+    )?
+    .with_provider(provider.clone());
+
+    owner
+        .transfer(recovery.address(), 1000, asset_id, TxPolicies::default())
+        .await?;
+
+    // the owner spends normally
+    let owner_account = recovery.owner_account(owner.clone())?;
+    let receiver_balance = receiver.get_asset_balance(&asset_id).await?;
+    owner_account
+        .transfer(receiver.address(), 100, asset_id, TxPolicies::default())
+        .await?;
+    assert_eq!(
+        receiver.get_asset_balance(&asset_id).await?,
+        receiver_balance + 100
+    );
+
+    // the owner rotates to a new key
+    let (rotated, tb) = recovery
+        .rotate_owner(owner.clone(), new_owner.address())
+        .await?;
+    submit_predicate_spend(tb, &provider).await?;
+    assert_eq!(rotated.terms().owner, *new_owner.address());
+    assert_eq!(recovery.predicate().get_asset_balance(&asset_id).await?, 0);
+    assert!(rotated.predicate().get_asset_balance(&asset_id).await? > 0);
+
+    // the guardians recover the funds once the recovery height is reached
+    let err = rotated
+        .recover([guardians[0].clone()], owner.address())
+        .await
+        .expect_err("should require two guardians");
+    assert!(err
+        .to_string()
+        .contains("recovering requires 2 distinct guardians, got 1"));
+
+    let (_, tb) = rotated.recover(guardians.clone(), owner.address()).await?;
+    let tx = tb.build(&provider).await?;
+    provider
+        .send_transaction_and_await_commit(tx)
+        .await
+        .expect_err("should be rejected before the recovery height");
+
+    provider.produce_blocks(rotated.terms().delay, None).await?;
+    assert!(rotated.is_recoverable().await?);
+
+    let (recovered, tb) = rotated.recover(guardians, owner.address()).await?;
+    submit_predicate_spend(tb, &provider).await?;
+    assert_eq!(rotated.predicate().get_asset_balance(&asset_id).await?, 0);
+    assert!(recovered.predicate().get_asset_balance(&asset_id).await? > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn evm_controlled_account_spends_with_personal_sign_signatures() -> Result<()> {
+    use fuels::{
+        accounts::evm::{EvmControlledAccount, EvmSigner},
+        crypto::{compact_to_rsv, Message, RecoveryIdFormat, SecretKey, Signature},
+        types::Bits256,
+    };
+    use sha3::{Digest, Keccak256};
+
+    // Stands in for an Ethereum wallet
+    #[derive(Debug, Clone)]
+    struct TestEvmSigner {
+        secret_key: SecretKey,
+    }
+
+    impl TestEvmSigner {
+        fn evm_address(&self) -> Bits256 {
+            let hash = Keccak256::digest(self.secret_key.public_key().as_ref());
+
+            let mut address = [0u8; 32];
+            address[12..].copy_from_slice(&hash[12..]);
+
+            Bits256(address)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl EvmSigner for TestEvmSigner {
+        async fn personal_sign(&self, message: &[u8]) -> Result<[u8; 65]> {
+            let mut hasher = Keccak256::new();
+            hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+            hasher.update(message);
+            let hash: [u8; 32] = hasher.finalize().into();
+
+            let signature = Signature::sign(&self.secret_key, &Message::from_bytes(hash));
+
+            Ok(compact_to_rsv(&signature, RecoveryIdFormat::Legacy))
+        }
+    }
+
+    abigen!(Predicate(
+        name = "EvmPredicate",
+        abi = "packages/fuels/predicates/evm/out/release/evm-abi.json"
+    ));
+
+    let wallet_config = WalletsConfig::new(Some(2), None, None);
+    let wallets = launch_custom_provider_and_get_wallets(wallet_config, None, None).await?;
+    let (funder, receiver) = (&wallets[0], &wallets[1]);
+    let provider = funder.try_provider()?.clone();
+    let asset_id = *provider.base_asset_id();
+
+    let signer = TestEvmSigner {
+        secret_key: SecretKey::from_str(
+            "5f70feeff1f229e4a95e1056e8b4d80d0b24b565674860cc213bdb07127ce1b1",
+        )?,
+    };
+    let configurables = EvmPredicateConfigurables::default().with_SIGNER(signer.evm_address())?;
+    let predicate = Predicate::load_from("predicates/evm/out/release/evm.bin")?
+        .with_data(EvmPredicateEncoder::default().encode_data(0)?)
+        .with_configurables(configurables)
+        .with_provider(provider.clone());
+
+    funder
+        .transfer(predicate.address(), 1000, asset_id, TxPolicies::default())
+        .await?;
+
+    // a different Ethereum key cannot spend the funds
+    let other_signer = TestEvmSigner {
+        secret_key: SecretKey::from_str(
+            "976e5c3fa620092c718d852ca703b6da9e3075b9f2ecb8ed42d9f746bf26aafb",
+        )?,
+    };
+    EvmControlledAccount::new(predicate.clone(), other_signer)
+        .transfer(receiver.address(), 500, asset_id, TxPolicies::default())
+        .await
+        .expect_err("should be rejected by the predicate");
+
+    let account = EvmControlledAccount::new(predicate, signer);
+    let receiver_balance = receiver.get_asset_balance(&asset_id).await?;
+    account
+        .transfer(receiver.address(), 500, asset_id, TxPolicies::default())
+        .await?;
+    assert_eq!(
+        receiver.get_asset_balance(&asset_id).await?,
+        receiver_balance + 500
+    );
+
+    Ok(())
+}
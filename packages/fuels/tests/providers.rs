@@ -1,22 +1,28 @@
-use std::ops::Add;
+use std::{num::NonZeroUsize, ops::Add};
 
 use chrono::{DateTime, Duration, TimeZone, Utc};
 use fuel_core::chain_config::StateConfig;
 use fuels::{
-    accounts::Account,
+    accounts::{
+        provider::{L1FinalitySource, PendingSpends},
+        Account,
+    },
     client::{PageDirection, PaginationRequest},
     prelude::*,
     tx::Receipt,
     types::{
         block::Block,
         coin_type::CoinType,
+        coin_type_id::CoinTypeId,
         errors::transaction::Reason,
+        input::Input,
         message::Message,
         transaction_builders::{BuildableTransaction, ScriptTransactionBuilder},
         tx_status::TxStatus,
         Bits256,
     },
 };
+use futures::{StreamExt, TryStreamExt};
 
 #[tokio::test]
 async fn test_provider_launch_and_connect() -> Result<()> {
@@ -613,6 +619,132 @@ async fn test_parse_block_time() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_get_full_block() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let asset_id = AssetId::zeroed();
+    let coins = setup_single_asset_coins(wallet.address(), asset_id, 1, DEFAULT_COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let (tx_id, receipts) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    let block_height = provider
+        .get_transaction_by_id(&tx_id)
+        .await?
+        .unwrap()
+        .block_height
+        .unwrap();
+
+    let full_block = provider.get_full_block(block_height).await?.unwrap();
+    let full_block_by_id = provider.get_full_block(full_block.id).await?.unwrap();
+
+    assert_eq!(full_block.id, full_block_by_id.id);
+    assert_eq!(full_block.header.height, *block_height);
+    assert_eq!(
+        full_block.transactions.len(),
+        usize::from(full_block.header.transactions_count)
+    );
+
+    let (_, response) = full_block
+        .transactions
+        .into_iter()
+        .find(|(id, _)| *id == tx_id)
+        .expect("block should contain the transfer");
+    assert_eq!(response.status.take_receipts(), receipts);
+
+    assert!(provider.get_full_block(u32::MAX).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_await_confirmations() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let asset_id = AssetId::zeroed();
+    let coins = setup_single_asset_coins(wallet.address(), asset_id, 1, DEFAULT_COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    let confirmations = 3;
+
+    let confirmed =
+        provider.await_confirmations(&tx_id, confirmations, std::time::Duration::from_millis(10));
+    let produce_blocks = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        provider.produce_blocks(confirmations, None).await
+    };
+
+    let (status, _) = tokio::try_join!(confirmed, produce_blocks)?;
+
+    assert!(matches!(status, TxStatus::Success { .. }));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_await_l1_finality() -> Result<()> {
+    #[derive(Debug)]
+    struct FixedFinalizedHeight(Option<u32>);
+
+    #[async_trait::async_trait]
+    impl L1FinalitySource for FixedFinalizedHeight {
+        async fn latest_finalized_height(&self) -> Result<Option<u32>> {
+            Ok(self.0)
+        }
+
+        fn poll_interval(&self) -> std::time::Duration {
+            std::time::Duration::from_millis(10)
+        }
+    }
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let asset_id = AssetId::zeroed();
+    let coins = setup_single_asset_coins(wallet.address(), asset_id, 1, DEFAULT_COIN_AMOUNT);
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+    wallet.set_provider(provider.clone());
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            asset_id,
+            TxPolicies::default(),
+        )
+        .await?;
+    let timeout = std::time::Duration::from_millis(500);
+
+    let nothing_finalized = provider
+        .clone()
+        .with_l1_finality_source(FixedFinalizedHeight(None))
+        .await_l1_finality(&tx_id, timeout)
+        .await
+        .expect_err("should time out");
+    assert!(nothing_finalized.to_string().contains("not finalized"));
+
+    let status = provider
+        .with_l1_finality_source(FixedFinalizedHeight(Some(u32::MAX)))
+        .await_l1_finality(&tx_id, timeout)
+        .await?;
+    assert!(matches!(status, TxStatus::Success { .. }));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_get_spendable_with_exclusion() -> Result<()> {
     let coin_amount_1 = 1000;
@@ -670,6 +802,277 @@ async fn test_get_spendable_with_exclusion() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn asset_inputs_can_be_filtered_by_amount_and_exclusion() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let address = wallet.address();
+
+    let coins = [1, 1000, 500]
+        .into_iter()
+        .flat_map(|amount| setup_single_asset_coins(address, AssetId::zeroed(), 1, amount))
+        .collect::<Vec<_>>();
+    let message = given_a_message(address.clone(), 200);
+    let message_nonce = message.nonce;
+    let dust_utxo_id = coins[0].utxo_id;
+    let big_coin_utxo_id = coins[1].utxo_id;
+    let coin_utxo_id = coins[2].utxo_id;
+
+    let provider = setup_test_provider(coins, vec![message], None, None).await?;
+    wallet.set_provider(provider);
+
+    let spent_ids = |inputs: Vec<Input>| {
+        inputs
+            .iter()
+            .map(|input| match input {
+                Input::ResourceSigned { resource } => resource.id(),
+                _ => panic!("expected a signed resource"),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // ANCHOR: coin_filter
+    let filter = CoinFilter::new()
+        .with_min_amount(100)
+        .with_max_amount(600)
+        .with_excluded([CoinTypeId::Nonce(message_nonce)]);
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount_filtered(AssetId::zeroed(), 1, filter)
+        .await?;
+    // ANCHOR_END: coin_filter
+
+    assert_eq!(spent_ids(inputs), vec![CoinTypeId::UtxoId(coin_utxo_id)]);
+
+    // only the dust coin is left once the other resources are excluded
+    let filter = CoinFilter::new().with_excluded([
+        CoinTypeId::UtxoId(big_coin_utxo_id),
+        CoinTypeId::UtxoId(coin_utxo_id),
+        CoinTypeId::Nonce(message_nonce),
+    ]);
+    let ids = spent_ids(
+        wallet
+            .get_asset_inputs_for_amount_filtered(AssetId::zeroed(), 1, filter)
+            .await?,
+    );
+    assert_eq!(ids, vec![CoinTypeId::UtxoId(dust_utxo_id)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn pending_spends_are_not_selected_again() -> Result<()> {
+    // ANCHOR: pending_spends
+    #[derive(Debug, Clone, Default)]
+    struct SharedPendingSpends(std::sync::Arc<std::sync::Mutex<Vec<CoinTypeId>>>);
+
+    #[async_trait::async_trait]
+    impl PendingSpends for SharedPendingSpends {
+        async fn pending_resources(
+            &self,
+            _owner: &Bech32Address,
+            _asset_id: AssetId,
+        ) -> Result<Vec<CoinTypeId>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        async fn record_submission(
+            &self,
+            _tx_id: TxId,
+            resources: &[((Bech32Address, AssetId), Vec<CoinTypeId>)],
+        ) {
+            let mut pending = self.0.lock().unwrap();
+            for (_, ids) in resources {
+                pending.extend(ids.iter().cloned());
+            }
+        }
+    }
+    // ANCHOR_END: pending_spends
+
+    let mut wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 2, 1000);
+    let pending_coin_id = CoinTypeId::UtxoId(coins[0].utxo_id);
+    let free_coin_id = CoinTypeId::UtxoId(coins[1].utxo_id);
+
+    let pending_spends = SharedPendingSpends::default();
+    pending_spends
+        .0
+        .lock()
+        .unwrap()
+        .push(pending_coin_id.clone());
+
+    let provider = setup_test_provider(coins, vec![], None, None)
+        .await?
+        .with_pending_spends(pending_spends.clone());
+    wallet.set_provider(provider);
+
+    let resources = wallet.get_spendable_resources(AssetId::zeroed(), 1).await?;
+    assert_eq!(
+        resources.iter().map(CoinType::id).collect::<Vec<_>>(),
+        vec![free_coin_id.clone()]
+    );
+
+    wallet
+        .transfer(
+            &Bech32Address::default(),
+            100,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+    assert!(pending_spends.0.lock().unwrap().contains(&free_coin_id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_coins_for_assets_preserves_order() -> Result<()> {
+    let mut wallet = WalletUnlocked::new_random(None);
+    let (coins, asset_ids) = setup_multiple_assets_coins(wallet.address(), 3, 120, 10);
+
+    let provider = setup_test_provider(coins, vec![], None, None)
+        .await?
+        .with_pagination_concurrency(NonZeroUsize::new(2).unwrap());
+    wallet.set_provider(provider.clone());
+
+    let fetched_coins = provider
+        .get_coins_for_assets(wallet.address(), asset_ids.iter().copied())
+        .await?;
+
+    assert_eq!(fetched_coins.len(), 360);
+    let fetched_asset_ids = fetched_coins
+        .chunks(120)
+        .map(|chunk| chunk[0].asset_id)
+        .collect::<Vec<_>>();
+    assert_eq!(fetched_asset_ids, asset_ids);
+    assert!(fetched_coins
+        .chunks(120)
+        .all(|chunk| chunk.iter().all(|coin| coin.asset_id == chunk[0].asset_id)));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_messages_stream_applies_filter() -> Result<()> {
+    let wallet = WalletUnlocked::new_random(None);
+    let sender = Bech32Address::default();
+    let messages = vec![
+        setup_single_message(&sender, wallet.address(), 100, 0.into(), vec![]),
+        setup_single_message(&sender, wallet.address(), 300, 1.into(), vec![]),
+        setup_single_message(&sender, wallet.address(), 500, 2.into(), vec![1, 2, 3]),
+    ];
+    let provider = setup_test_provider(vec![], messages.clone(), None, None).await?;
+
+    let filter = MessageFilter {
+        with_data: Some(false),
+        min_amount: Some(200),
+        ..Default::default()
+    };
+    let filtered_messages: Vec<Message> = provider
+        .get_messages_stream(wallet.address(), filter)
+        .try_collect()
+        .await?;
+
+    assert_eq!(filtered_messages, vec![messages[1].clone()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_messages_skips_existing_messages() -> Result<()> {
+    let wallet = WalletUnlocked::new_random(None);
+    let sender = Bech32Address::default();
+    let messages = vec![setup_single_message(
+        &sender,
+        wallet.address(),
+        100,
+        0.into(),
+        vec![],
+    )];
+    let provider = setup_test_provider(vec![], messages, None, None).await?;
+
+    let mut subscription = Box::pin(
+        provider.subscribe_messages(wallet.address(), std::time::Duration::from_millis(100)),
+    );
+
+    // Without a relayer no message arrives after the genesis ones, so the stream stays pending.
+    let next =
+        tokio::time::timeout(std::time::Duration::from_millis(500), subscription.next()).await;
+    assert!(next.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_messages_since_da_height() -> Result<()> {
+    let wallet = WalletUnlocked::new_random(None);
+    let sender = Bech32Address::default();
+    let mut messages = vec![
+        setup_single_message(&sender, wallet.address(), 100, 0.into(), vec![]),
+        setup_single_message(&sender, wallet.address(), 300, 1.into(), vec![1, 2, 3]),
+    ];
+    messages[1].da_height = 5;
+    let provider = setup_test_provider(vec![], messages.clone(), None, None).await?;
+
+    let resumed: Vec<Message> = provider
+        .subscribe_messages_since(wallet.address(), 5, std::time::Duration::from_millis(100))
+        .take(1)
+        .try_collect()
+        .await?;
+
+    assert_eq!(resumed, vec![messages[1].clone()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subscribe_contract_balances() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(Contract(
+            name = "TestContract",
+            project = "packages/fuels/tests/contracts/contract_test"
+        )),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+    );
+    let provider = wallet.try_provider()?;
+    let contract_id = contract_instance.contract_id();
+    let base_asset_id = *provider.base_asset_id();
+
+    let mut balance_changes = Box::pin(
+        provider
+            .subscribe_contract_balances(
+                contract_id,
+                vec![base_asset_id],
+                std::time::Duration::from_millis(50),
+            )
+            .await?,
+    );
+
+    let (tx_id, _) = wallet
+        .force_transfer_to_contract(contract_id, 100, base_asset_id, TxPolicies::default())
+        .await?;
+    let change = tokio::time::timeout(
+        std::time::Duration::from_secs(10),
+        balance_changes.try_next(),
+    )
+    .await
+    .expect("the change should be yielded within the timeout")?
+    .expect("stream should not end");
+
+    assert_eq!(change.asset_id, base_asset_id);
+    assert_eq!(change.previous_balance, 0);
+    assert_eq!(change.new_balance, 100);
+    assert_eq!(change.delta(), 100);
+    assert_eq!(change.tx_ids.len(), 1);
+    assert_eq!(change.tx_ids[0].to_string(), tx_id);
+
+    Ok(())
+}
+
 fn given_a_message(address: Bech32Address, message_amount: u64) -> Message {
     setup_single_message(
         &Bech32Address::default(),
@@ -930,6 +1333,79 @@ async fn test_build_with_provider() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn tx_policies_presets_follow_the_gas_price() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let receiver = WalletUnlocked::new_random(Some(provider.clone()));
+
+    let gas_price = provider.estimate_gas_price(1).await?.gas_price;
+
+    // ANCHOR: tx_policies_presets
+    let fast = TxPolicies::fast(provider).await?;
+    let standard = TxPolicies::standard(provider).await?;
+    let economic = TxPolicies::economic(provider).await?;
+    // ANCHOR_END: tx_policies_presets
+
+    let gas_price = gas_price as f64;
+    assert_eq!(fast.tip_per_gas(), Some(gas_price * 10.0));
+    assert_eq!(standard.tip_per_gas(), Some(gas_price));
+    assert_eq!(economic.tip_per_gas(), Some(gas_price * 0.1));
+    assert!(fast.tip().is_none() && fast.max_fee().is_none() && fast.witness_limit().is_none());
+
+    for tx_policies in [fast, standard, economic] {
+        wallet
+            .transfer(
+                receiver.address(),
+                100,
+                *provider.base_asset_id(),
+                tx_policies,
+            )
+            .await?;
+    }
+
+    let receiver_balance = receiver.get_asset_balance(provider.base_asset_id()).await?;
+    assert_eq!(receiver_balance, 300);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inconsistent_tx_policies_are_rejected_when_building() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    // ANCHOR: tx_policies_validate
+    let tx_policies = TxPolicies::default().with_tip(100).with_max_fee(10);
+
+    let err = tx_policies
+        .validate(provider.consensus_parameters())
+        .expect_err("should fail");
+    // ANCHOR_END: tx_policies_validate
+    assert!(err.to_string().contains("lower than the `tip`"));
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(*provider.base_asset_id(), 100)
+        .await?;
+    let outputs =
+        wallet.get_asset_outputs_for_amount(wallet.address(), *provider.base_asset_id(), 100);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(
+        inputs,
+        outputs,
+        TxPolicies::default().with_witness_limit(8),
+    );
+    tb.add_signer(wallet.clone())?;
+
+    let err = tb.build(provider).await.expect_err("should fail");
+
+    let Error::Transaction(Reason::Builder(message)) = err else {
+        panic!("expected a builder error, got: {err}");
+    };
+    assert!(message.contains("`witness_limit` (8) is too small"));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn can_produce_blocks_with_trig_never() -> Result<()> {
     let config = NodeConfig {
@@ -964,3 +1440,191 @@ async fn can_produce_blocks_with_trig_never() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn message_proofs_are_awaited_for_at_most_the_timeout() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+    let recipient = Bech32Address::from(Address::from([1; 32]));
+
+    let (tx_id, nonce, _) = wallet
+        .withdraw_to_base_layer(&recipient, 1000, TxPolicies::default())
+        .await?;
+
+    // no block is produced on top of the withdrawal, so there is no proof yet
+    let poll_interval = std::time::Duration::from_millis(50);
+    let err = provider
+        .await_message_proof(
+            &tx_id,
+            &nonce,
+            poll_interval,
+            std::time::Duration::from_millis(500),
+        )
+        .await
+        .expect_err("should time out");
+    assert!(err.to_string().contains("was not available within"));
+
+    provider.produce_blocks(1, None).await?;
+    let proof = provider
+        .await_message_proof(
+            &tx_id,
+            &nonce,
+            poll_interval,
+            std::time::Duration::from_secs(10),
+        )
+        .await?;
+    assert_eq!(proof.recipient, recipient);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn raw_queries_reach_the_node() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    provider.produce_blocks(3, None).await?;
+
+    let query = "query($height: U32) { block(height: $height) { header { height } } }";
+    let response: serde_json::Value = provider
+        .raw_query(query, serde_json::json!({ "height": "2" }))
+        .await?;
+    assert_eq!(response["block"]["header"]["height"], "2");
+
+    let err = provider
+        .raw_query::<serde_json::Value>("query { unknownField }", ())
+        .await
+        .expect_err("should fail");
+    assert!(err.to_string().contains("unknownField"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn asset_info_is_only_requested_from_nodes_serving_it() -> Result<()> {
+    let provider = setup_test_provider(vec![], vec![], None, None).await?;
+    let node_version = provider.node_info().await?.node_version;
+
+    let err = provider
+        .asset_info(&AssetId::zeroed())
+        .await
+        .expect_err("the node doesn't serve asset details");
+
+    assert!(err.to_string().contains(&format!(
+        "served by fuel-core 0.41.0 and later, the node runs {node_version}"
+    )));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn raw_subscriptions_reach_the_node() -> Result<()> {
+    let wallet = launch_provider_and_get_wallet().await?;
+    let provider = wallet.try_provider()?;
+
+    let (tx_id, _) = wallet
+        .transfer(
+            &Bech32Address::default(),
+            1,
+            *provider.base_asset_id(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    let subscription =
+        "subscription($id: TransactionId!) { statusChange(id: $id) { type: __typename } }";
+    let variables = serde_json::json!({ "id": format!("{tx_id:#x}") });
+    let status: serde_json::Value = Box::pin(provider.raw_subscription(subscription, variables))
+        .try_next()
+        .await?
+        .expect("the node sends the final status");
+
+    assert_eq!(status["statusChange"]["type"], "SuccessStatus");
+
+    Ok(())
+}
+
+#[cfg(not(feature = "fuel-core-lib"))]
+#[tokio::test]
+async fn node_logs_can_be_captured() -> Result<()> {
+    // ANCHOR: capture_node_logs
+    let logs = NodeLogs::new();
+    // prints the logs of the node if an assertion of the test fails
+    let _print_logs = logs.print_on_panic();
+
+    let node_config = NodeConfig {
+        logs: Some(logs.clone()),
+        log_level: Some("debug".to_string()),
+        ..NodeConfig::default()
+    };
+    let provider = setup_test_provider(vec![], vec![], Some(node_config), None).await?;
+    // ANCHOR_END: capture_node_logs
+
+    provider.produce_blocks(1, None).await?;
+
+    // the output of the node is read in the background
+    let mut attempts = 50;
+    while logs.lines().is_empty() && attempts > 0 {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        attempts -= 1;
+    }
+    assert!(!logs.lines().is_empty());
+
+    Ok(())
+}
+
+#[cfg(any(not(feature = "fuel-core-lib"), feature = "rocksdb"))]
+#[test]
+fn nodes_start_from_snapshots_and_persist_their_state() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let snapshot_dir = dir.path().join("snapshot");
+    let state_dir = dir.path().join("state");
+
+    let wallet = WalletUnlocked::new_random(None);
+    let coins = setup_single_asset_coins(wallet.address(), AssetId::zeroed(), 1, 100);
+    let state_config = StateConfig {
+        coins: coins.into_iter().map(Into::into).collect(),
+        ..StateConfig::local_testnet()
+    };
+    std::fs::create_dir_all(&snapshot_dir)?;
+    write_snapshot(&snapshot_dir, ChainConfig::local_testnet(), state_config)?;
+
+    let start_node = || async {
+        // ANCHOR: node_from_snapshot
+        let node_config = NodeConfig {
+            persist_state: Some(state_dir.clone()),
+            ..NodeConfig::default()
+        };
+        let provider = setup_test_provider_from_snapshot(&snapshot_dir, Some(node_config)).await?;
+        // ANCHOR_END: node_from_snapshot
+
+        Ok::<_, Error>(provider)
+    };
+
+    // the node is only stopped when the runtime shuts down
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let provider = start_node().await?;
+        assert_eq!(
+            provider
+                .get_asset_balance(wallet.address(), AssetId::zeroed())
+                .await?,
+            100
+        );
+
+        provider.produce_blocks(3, None).await?;
+
+        Ok::<_, Error>(())
+    })?;
+
+    tokio::runtime::Runtime::new()?.block_on(async {
+        let provider = start_node().await?;
+
+        assert_eq!(provider.latest_block_height().await?, 3);
+        assert_eq!(
+            provider
+                .get_asset_balance(wallet.address(), AssetId::zeroed())
+                .await?,
+            100
+        );
+
+        Ok(())
+    })
+}
@@ -30,6 +30,31 @@ async fn main_function_arguments() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn script_bindings_from_forc_project() -> Result<()> {
+    // ANCHOR: script_from_project
+    abigen!(Script(
+        name = "MyScript",
+        project = "packages/fuels/tests/scripts/arguments"
+    ));
+    let wallet = launch_provider_and_get_wallet().await?;
+    // The path to the binary found in the project's `out` directory
+    let script_instance = MyScript::new(wallet, MY_SCRIPT_BINARY_PATH);
+    // ANCHOR_END: script_from_project
+
+    let bim = Bimbam { val: 90 };
+    let bam = SugarySnack {
+        twix: 100,
+        mars: 1000,
+    };
+
+    let result = script_instance.main(bim, bam).call().await?;
+
+    assert_eq!(result.value, Bimbam { val: 2190 });
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn script_call_has_same_estimated_and_used_gas() -> Result<()> {
     setup_program_test!(
@@ -421,3 +446,59 @@ async fn script_encoder_config_is_applied() {
         ));
     }
 }
+
+#[tokio::test]
+async fn contract_calls_can_be_performed_before_the_script() -> Result<()> {
+    setup_program_test!(
+        Wallets("wallet"),
+        Abigen(
+            Contract(
+                name = "TestContract",
+                project = "packages/fuels/tests/contracts/contract_test"
+            ),
+            Script(
+                name = "MyScript",
+                project = "packages/fuels/tests/scripts/basic_script"
+            )
+        ),
+        Deploy(
+            name = "contract_instance",
+            contract = "TestContract",
+            wallet = "wallet"
+        ),
+        LoadScript(
+            name = "script_instance",
+            script = "MyScript",
+            wallet = "wallet"
+        )
+    );
+    let contract_methods = contract_instance.methods();
+
+    // ANCHOR: script_with_contract_calls
+    let response = script_instance
+        .main(1, 2)
+        .add_contract_call(contract_methods.initialize_counter(42))
+        .add_contract_call(contract_methods.get(5, 6))
+        .call_with_contract_calls::<(u64, u64)>()
+        .await?;
+
+    let ((counter, sum), script_value) = response.value;
+    // ANCHOR_END: script_with_contract_calls
+
+    assert_eq!(counter, 42);
+    assert_eq!(sum, 11);
+    assert_eq!(script_value, "hello");
+    assert_eq!(contract_methods.get_counter().simulate().await?.value, 42);
+
+    // the calls and the script are executed in the same transaction
+    let response = script_instance
+        .main(2, 1)
+        .add_contract_call(contract_methods.increment_counter(1))
+        .simulate_with_contract_calls::<(u64,)>()
+        .await?;
+    let ((counter,), script_value) = response.value;
+    assert_eq!(counter, 43);
+    assert_eq!(script_value, "heyoo");
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use std::iter::repeat;
 
 use fuel_tx::{input::coin::CoinSigned, Bytes32, Input, Output, TxPointer, UtxoId};
-use fuels::prelude::*;
+use fuels::{
+    prelude::*,
+    types::{errors::transaction::Reason, ChainId},
+};
 
 #[tokio::test]
 async fn test_wallet_balance_api_multi_asset() -> Result<()> {
@@ -359,6 +362,191 @@ async fn setup_transfer_test(amount: u64) -> Result<(WalletUnlocked, WalletUnloc
     Ok((wallet_1, wallet_2))
 }
 
+#[tokio::test]
+async fn transfer_to_a_resolved_name() -> Result<()> {
+    let (mut wallet_1, wallet_2) = setup_transfer_test(5).await?;
+    let address_book = AddressBook::new().with_entry("treasury", wallet_2.address().clone());
+    let provider = wallet_1
+        .try_provider()?
+        .clone()
+        .with_name_resolver(address_book);
+    wallet_1.set_provider(provider);
+
+    wallet_1
+        .transfer_to("treasury", 2, AssetId::zeroed(), TxPolicies::default())
+        .await?;
+
+    let err = wallet_1
+        .transfer_to("unknown", 2, AssetId::zeroed(), TxPolicies::default())
+        .await
+        .expect_err("should fail to resolve the name");
+    assert!(err
+        .to_string()
+        .contains("no address is registered under the name `unknown`"));
+
+    assert_eq!(wallet_1.get_asset_balance(&AssetId::zeroed()).await?, 3);
+    assert_eq!(wallet_2.get_asset_balance(&AssetId::zeroed()).await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn signing_policy_daily_limit_spans_transfers() -> Result<()> {
+    use fuels::accounts::signing_policy::SigningPolicy;
+
+    let (wallet_1, wallet_2) = setup_transfer_test(1000).await?;
+    let asset_id = AssetId::zeroed();
+    let wallet_1 =
+        wallet_1.with_signing_policy(SigningPolicy::new().with_max_amount_per_day(asset_id, 100));
+    let provider = wallet_1.try_provider()?.clone();
+
+    wallet_1
+        .transfer(wallet_2.address(), 60, asset_id, TxPolicies::default())
+        .await?;
+
+    let err = wallet_1
+        .transfer(wallet_2.address(), 60, asset_id, TxPolicies::default())
+        .await
+        .expect_err("should exceed the daily limit");
+    assert!(err
+        .to_string()
+        .contains("exceeds the remaining daily limit of 40"));
+
+    // a transaction signed but not submitted yet already counts towards the limit
+    let inputs = wallet_1.get_asset_inputs_for_amount(asset_id, 30).await?;
+    let outputs = wallet_1.get_asset_outputs_for_amount(wallet_2.address(), asset_id, 30);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    wallet_1.add_witnesses(&mut tb)?;
+    wallet_1.adjust_for_fee(&mut tb, 30).await?;
+    let tx = tb.build(&provider).await?;
+
+    let err = wallet_1
+        .transfer(wallet_2.address(), 20, asset_id, TxPolicies::default())
+        .await
+        .expect_err("should exceed the daily limit");
+    assert!(err
+        .to_string()
+        .contains("exceeds the remaining daily limit of 10"));
+
+    provider.send_transaction_and_await_commit(tx).await?;
+    assert_eq!(wallet_2.get_asset_balance(&asset_id).await?, 90);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wrapped_accounts_delegate_to_the_marked_field() -> Result<()> {
+    use fuels::macros::Account;
+
+    // ANCHOR: derive_account
+    #[derive(Debug, Clone, Account)]
+    struct Treasury {
+        #[account(delegate)]
+        wallet: WalletUnlocked,
+        label: String,
+    }
+    // ANCHOR_END: derive_account
+
+    #[derive(Debug, Clone, Account)]
+    struct Labeled<A>(String, #[account(delegate)] A);
+
+    let (wallet_1, wallet_2) = setup_transfer_test(5).await?;
+    let treasury = Treasury {
+        wallet: wallet_1.clone(),
+        label: "operations".to_string(),
+    };
+    let labeled = Labeled("savings".to_string(), wallet_2.clone());
+
+    assert_eq!(treasury.label, "operations");
+    assert_eq!(labeled.0, "savings");
+    assert_eq!(treasury.address(), wallet_1.address());
+
+    treasury
+        .transfer(
+            labeled.address(),
+            2,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_eq!(wallet_1.get_asset_balance(&AssetId::zeroed()).await?, 3);
+    assert_eq!(labeled.get_asset_balance(&AssetId::zeroed()).await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_refuses_to_sign_for_the_wrong_network() -> Result<()> {
+    let (wallet_1, wallet_2) = setup_transfer_test(5).await?;
+    let chain_id = wallet_1.try_provider()?.chain_id();
+
+    // ANCHOR: expected_chain_id
+    let wallet = wallet_1.with_expected_chain_id(ChainId::from(*chain_id + 1));
+
+    let err = wallet
+        .transfer(
+            wallet_2.address(),
+            2,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await
+        .expect_err("should refuse to sign for another network");
+    // ANCHOR_END: expected_chain_id
+    assert!(matches!(
+        err,
+        Error::Transaction(Reason::WrongNetwork { expected, actual })
+            if *expected == *chain_id + 1 && actual == chain_id
+    ));
+
+    let wallet = wallet.with_expected_chain_id(chain_id);
+    wallet
+        .transfer(
+            wallet_2.address(),
+            2,
+            AssetId::zeroed(),
+            TxPolicies::default(),
+        )
+        .await?;
+
+    assert_eq!(wallet_2.get_asset_balance(&AssetId::zeroed()).await?, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn wallet_added_as_a_signer_refuses_to_sign_for_the_wrong_network() -> Result<()> {
+    let (wallet_1, wallet_2) = setup_transfer_test(5).await?;
+    let provider = wallet_1.try_provider()?.clone();
+    let chain_id = provider.chain_id();
+    let wallet = wallet_1.with_expected_chain_id(ChainId::from(*chain_id + 1));
+
+    let inputs = wallet
+        .get_asset_inputs_for_amount(AssetId::zeroed(), 2)
+        .await?;
+    let outputs = wallet.get_asset_outputs_for_amount(wallet_2.address(), AssetId::zeroed(), 2);
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(inputs, outputs, TxPolicies::default());
+    // bypasses `Account::add_witnesses`, the wallet checks the network when signing
+    tb.add_signer(wallet.clone())?;
+
+    let err = tb
+        .build(&provider)
+        .await
+        .expect_err("should refuse to sign for another network");
+
+    let Error::Transaction(Reason::SignerFailed { source, .. }) = err else {
+        panic!("expected a signer error, got: {err}");
+    };
+    assert!(matches!(
+        *source,
+        Error::Transaction(Reason::WrongNetwork { expected, actual })
+            if *expected == *chain_id + 1 && actual == chain_id
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn transfer_more_than_owned() -> Result<()> {
     const AMOUNT: u64 = 1000000;
@@ -421,6 +609,118 @@ async fn transfer_coins_of_non_base_asset() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn transfer_several_assets_in_one_transaction() -> Result<()> {
+    const AMOUNT: u64 = 10000;
+    let mut wallet_1 = WalletUnlocked::new_random(None);
+    let mut wallet_2 = WalletUnlocked::new_random(None);
+
+    let asset_a = AssetId::from([1; 32usize]);
+    let asset_b = AssetId::from([2; 32usize]);
+    let base_asset = AssetId::zeroed();
+    let coins = [asset_a, asset_b, base_asset]
+        .into_iter()
+        .flat_map(|asset_id| setup_single_asset_coins(wallet_1.address(), asset_id, 1, AMOUNT))
+        .collect();
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    wallet_1.set_provider(provider.clone());
+    wallet_2.set_provider(provider);
+
+    // ANCHOR: transfer_assets
+    let (_tx_id, _receipts) = wallet_1
+        .transfer_assets(
+            wallet_2.address(),
+            &[(asset_a, 100), (asset_b, 200), (base_asset, 300)],
+            TxPolicies::default(),
+        )
+        .await?;
+    // ANCHOR_END: transfer_assets
+
+    for (asset_id, amount) in [(asset_a, 100), (asset_b, 200), (base_asset, 300)] {
+        let coins = wallet_2.get_coins(asset_id).await?;
+        assert_eq!(coins.len(), 1);
+        assert_eq!(coins[0].amount, amount);
+    }
+    assert_eq!(wallet_1.get_asset_balance(&asset_a).await?, AMOUNT - 100);
+    assert_eq!(wallet_1.get_asset_balance(&asset_b).await?, AMOUNT - 200);
+    assert!(wallet_1.get_asset_balance(&base_asset).await? < AMOUNT - 300);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn sweep_transfers_the_whole_balance() -> Result<()> {
+    const AMOUNT: u64 = 10000;
+    let mut wallet_1 = WalletUnlocked::new_random(None);
+    let mut wallet_2 = WalletUnlocked::new_random(None);
+
+    let asset_id = AssetId::from([1; 32usize]);
+    let base_asset = AssetId::zeroed();
+    let coins = [asset_id, base_asset]
+        .into_iter()
+        .flat_map(|asset_id| setup_single_asset_coins(wallet_1.address(), asset_id, 3, AMOUNT))
+        .collect();
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    wallet_1.set_provider(provider.clone());
+    wallet_2.set_provider(provider);
+
+    // ANCHOR: sweep
+    let (_tx_id, swept_amount, _receipts) = wallet_1
+        .sweep(wallet_2.address(), asset_id, TxPolicies::default())
+        .await?;
+    // ANCHOR_END: sweep
+    assert_eq!(swept_amount, 3 * AMOUNT);
+    assert_eq!(wallet_1.get_asset_balance(&asset_id).await?, 0);
+    assert_eq!(wallet_2.get_asset_balance(&asset_id).await?, 3 * AMOUNT);
+
+    let base_balance = wallet_1.get_asset_balance(&base_asset).await?;
+    let (_tx_id, swept_amount, receipts) = wallet_1
+        .sweep(wallet_2.address(), base_asset, TxPolicies::default())
+        .await?;
+
+    let fee = base_balance - swept_amount;
+    assert!(fee > 0);
+    assert_eq!(wallet_2.get_asset_balance(&base_asset).await?, swept_amount);
+    // only the refund of the unused fee, if any, is left
+    let refund = wallet_1.get_asset_balance(&base_asset).await?;
+    assert!(refund < fee);
+    assert!(!receipts.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn max_transferable_is_the_amount_a_sweep_sends() -> Result<()> {
+    let mut wallet_1 = WalletUnlocked::new_random(None);
+    let mut wallet_2 = WalletUnlocked::new_random(None);
+
+    let base_asset = AssetId::zeroed();
+    let coins = setup_single_asset_coins(wallet_1.address(), base_asset, 2, 10000);
+
+    let provider = setup_test_provider(coins, vec![], None, None).await?;
+
+    wallet_1.set_provider(provider.clone());
+    wallet_2.set_provider(provider);
+
+    // ANCHOR: max_transferable
+    let max_amount = wallet_1
+        .max_transferable(base_asset, TxPolicies::default())
+        .await?;
+    // ANCHOR_END: max_transferable
+    assert!(max_amount < 20000);
+
+    let (_tx_id, swept_amount, _receipts) = wallet_1
+        .sweep(wallet_2.address(), base_asset, TxPolicies::default())
+        .await?;
+    assert_eq!(swept_amount, max_amount);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_transfer_with_multiple_signatures() -> Result<()> {
     let wallet_config = base_asset_wallet_config(5);
@@ -466,3 +766,47 @@ async fn test_transfer_with_multiple_signatures() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn transactions_can_be_funded_by_multiple_accounts() -> Result<()> {
+    use fuels::accounts::co_funding::CoFunding;
+
+    let wallets =
+        launch_custom_provider_and_get_wallets(base_asset_wallet_config(2), None, None).await?;
+    let (alice, bob) = (&wallets[0], &wallets[1]);
+    let provider = alice.try_provider()?;
+    let base_asset_id = *provider.base_asset_id();
+    let bob_initial_balance = bob.get_asset_balance(&base_asset_id).await?;
+
+    let receiver = WalletUnlocked::new_random(Some(provider.clone()));
+
+    // ANCHOR: co_funding
+    let amount = 150;
+    let outputs = vec![Output::coin(
+        receiver.address().into(),
+        amount,
+        base_asset_id,
+    )];
+    let mut tb = ScriptTransactionBuilder::prepare_transfer(vec![], outputs, TxPolicies::default());
+
+    // alice gives up to 100 and pays the fee, bob covers the rest
+    let contributions = CoFunding::new(base_asset_id, amount)
+        .add_contributor(alice.clone(), 100)
+        .add_contributor(bob.clone(), 100)
+        .fund(&mut tb)
+        .await?;
+
+    let tx = tb.build(provider).await?;
+    provider.send_transaction_and_await_commit(tx).await?;
+    // ANCHOR_END: co_funding
+
+    assert_eq!(contributions[0].amount, 100);
+    assert_eq!(contributions[1].amount, 50);
+    assert_eq!(receiver.get_asset_balance(&base_asset_id).await?, amount);
+    assert_eq!(
+        bob.get_asset_balance(&base_asset_id).await?,
+        bob_initial_balance - 50
+    );
+
+    Ok(())
+}